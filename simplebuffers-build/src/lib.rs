@@ -0,0 +1,89 @@
+//! Generates Rust code for SimpleBuffers schemas from a build script.
+//!
+//! Call [compile] from `build.rs` with Cargo's `OUT_DIR`, then `include!` the generated module:
+//!
+//! ```ignore
+//! // build.rs
+//! fn main() {
+//!     let out_dir = std::env::var("OUT_DIR").unwrap();
+//!     simplebuffers_build::compile("schemas/robot.sb", &out_dir).unwrap();
+//! }
+//!
+//! // src/lib.rs
+//! pub mod robot {
+//!     include!(concat!(env!("OUT_DIR"), "/robot.rs"));
+//! }
+//! ```
+//!
+//! The generated file is named after the schema file, with its extension replaced by `.rs`.
+
+use std::{fs, path::Path};
+
+use simplebuffers_codegen::{CodeGenerator, FileSystemSink, GeneratorParams};
+use simplebuffers_compiler::{CompileOptions, Diagnostic};
+use simplebuffers_rust::RustCodeGenerator;
+
+/// Formats a diagnostic on a single line, as `file:line:column: message`, without color codes.
+fn describe(diagnostic: &Diagnostic) -> String {
+    let location = [
+        diagnostic.file.clone(),
+        diagnostic.line.map(|line| line.to_string()),
+        diagnostic.column.map(|column| column.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(":");
+    if location.is_empty() {
+        diagnostic.message.clone()
+    } else {
+        format!("{}: {}", location, diagnostic.message)
+    }
+}
+
+/// Compile a schema and generate a Rust module for it in `out_dir`.
+///
+/// Cargo is told to rerun the build script when the schema changes, and warnings are passed on to
+/// Cargo with `cargo:warning`, so this should only be called from a build script. Names that are
+/// reserved in Rust are escaped (as with `--relax-reserved`) instead of rejected, so a field named
+/// `type` is accessed as `r#type`.
+///
+/// # Arguments
+///
+/// * `schema_path` - The path to the schema file, relative to the package root.
+/// * `out_dir` - The directory to write the generated module to, normally `OUT_DIR`.
+///
+/// # Returns
+///
+/// Nothing, or the errors as a human-readable string with one error per line.
+pub fn compile(schema_path: &str, out_dir: &str) -> Result<(), String> {
+    println!("cargo:rerun-if-changed={}", schema_path);
+
+    let source = fs::read_to_string(schema_path)
+        .map_err(|e| format!("Failed to read {}: {}", schema_path, e))?;
+    let mut warnings = vec![];
+    let schema = simplebuffers_compiler::compile(
+        &source,
+        schema_path,
+        &CompileOptions::default(),
+        &mut warnings,
+    )
+    .map_err(|errors| errors.iter().map(describe).collect::<Vec<_>>().join("\n"))?;
+    for warning in &warnings {
+        println!("cargo:warning={}", describe(warning));
+    }
+
+    let file_name = Path::new(schema_path)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| format!("Invalid schema path: {}", schema_path))?;
+    let params = GeneratorParams {
+        file_name: file_name.to_string(),
+        dest_dir: out_dir.to_string(),
+        additional_args: vec!["rust".to_string()],
+        relax_reserved: true,
+        source: Some(source),
+    };
+    RustCodeGenerator::new().generate(&schema, &params, &mut FileSystemSink)?;
+    Ok(())
+}
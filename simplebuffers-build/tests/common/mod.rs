@@ -0,0 +1,22 @@
+//! Helpers shared by the integration tests. Each test file declares `mod common;` and uses the
+//! helpers it needs.
+
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Returns an empty scratch directory for a test. Each test file gets its own directory, named
+/// after its test binary, so that test names only have to be unique within a file.
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    let stem = exe.file_stem().unwrap().to_str().unwrap();
+    let file = stem.rsplit_once('-').map_or(stem, |(file, _)| file);
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(file).join(name);
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -0,0 +1,82 @@
+//! Tests for generating Rust code from a build script.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+
+use common::scratch_dir;
+
+const SCHEMA: &str = "\
+enum Kind {
+    small = 0;
+    large = 300;
+}
+
+sequence Item {
+    type: Kind;
+    name: string;
+}
+";
+
+/// Writes `source` to `dir/item.sb` and returns its path.
+fn write_schema(dir: &Path, source: &str) -> String {
+    let path = dir.join("item.sb");
+    fs::write(&path, source).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn generates_includable_module() {
+    let dir = scratch_dir("includable");
+    let schema = write_schema(&dir, SCHEMA);
+    let out_dir = dir.join("out");
+    simplebuffers_build::compile(&schema, out_dir.to_str().unwrap()).unwrap();
+
+    // Reserved names are escaped instead of rejected.
+    let generated = fs::read_to_string(out_dir.join("item.rs")).unwrap();
+    assert!(generated.contains("r#type"));
+
+    let lib = dir.join("lib.rs");
+    fs::write(
+        &lib,
+        "pub mod item {\n    include!(concat!(env!(\"OUT_DIR\"), \"/item.rs\"));\n}\n",
+    )
+    .unwrap();
+    let output = Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()))
+        .env("OUT_DIR", &out_dir)
+        .arg("--edition=2021")
+        .arg("--crate-type=lib")
+        .arg("--out-dir")
+        .arg(&dir)
+        .arg(&lib)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn reports_errors_with_locations() {
+    let dir = scratch_dir("errors");
+    let schema = write_schema(&dir, "sequence Item {\n    x: Missing;\n}\n");
+    let error =
+        simplebuffers_build::compile(&schema, dir.join("out").to_str().unwrap()).unwrap_err();
+    assert!(error.starts_with(&format!("{}:2:8: ", schema)), "{}", error);
+    assert!(!error.contains('\x1b'));
+    assert!(!dir.join("out").exists());
+}
+
+#[test]
+fn reports_missing_schema() {
+    let dir = scratch_dir("missing");
+    let schema = dir.join("missing.sb");
+    let error =
+        simplebuffers_build::compile(schema.to_str().unwrap(), dir.to_str().unwrap()).unwrap_err();
+    assert!(error.starts_with(&format!("Failed to read {}", schema.display())));
+}
@@ -10,12 +10,17 @@
 //! crate-type = ["lib", "dylib"]
 //! ```
 //!
+//! Generators write their files through the [FileSink] passed to [CodeGenerator::generate], rather
+//! than creating them directly. The compiler passes a [FileSystemSink], while tests can pass a
+//! [MemorySink] to inspect the output without touching the filesystem.
+//!
 //! Once a custom generator is created, it must be registered. This can be done with the
-//! [register_generator!] macro.
+//! [register_generator!] macro, or with [register_generators!], which also lets the compiler list
+//! the generators of libraries in a plugin directory.
 //!
 //! # Example
 //!
-//! ```
+//! ```ignore
 //! // lib.rs
 //!
 //! pub struct MyCodeGenerator;
@@ -31,11 +36,17 @@
 //!     fn generate(
 //!         &mut self,
 //!         schema: &simplebuffers_core::SBSchema,
-//!         _params: &simplebuffers_codegen::GeneratorParams,
-//!     ) -> Result<(), String> {
-//!         // Custom generation code goes here...
+//!         params: &simplebuffers_codegen::GeneratorParams,
+//!         sink: &mut dyn simplebuffers_codegen::FileSink,
+//!     ) -> Result<Vec<PathBuf>, String> {
+//!         // Custom generation code goes here, returning the paths of the files it wrote...
+//!         let path = PathBuf::from(format!("{}/{}.txt", params.dest_dir, params.file_name));
+//!         sink.write_file(&path, "...")?;
+//!         Ok(vec![path])
 //!     }
 //!
+//!     // Optional. By default, no identifiers are reserved. See also
+//!     // `reserved_identifiers_apply_to` to exempt some kinds of names.
 //!     fn reserved_identifiers(
 //!         &mut self,
 //!         _params: &simplebuffers_codegen::GeneratorParams,
@@ -52,7 +63,7 @@
 //!     }
 //! }
 //!
-//! register_generator!(mygen: MyCodeGenerator);
+//! register_generators!(mygen: MyCodeGenerator);
 //! ```
 //!
 //! Assuming this generator is compiled to `my_code_generator.so`, it can be invoked with:
@@ -60,7 +71,41 @@
 //! simplebuffers-compiler --lib="my_code_generator.so" mygen "my_schema.sb"
 //! ```
 
+mod sink;
+
+use std::fmt;
+use std::path::PathBuf;
+
 pub use simplebuffers_core::SBSchema;
+pub use sink::{FileSink, FileSystemSink, MemorySink};
+
+/// A kind of named item in a schema, which [reserved identifiers](CodeGenerator::reserved_identifiers)
+/// may or may not apply to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    /// The name of a sequence.
+    Sequence,
+
+    /// The name of an enum.
+    Enum,
+
+    /// The name of an enum variant.
+    EnumVariant,
+
+    /// The name of a field of a sequence or a oneof.
+    Field,
+}
+
+impl fmt::Display for ItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ItemKind::Sequence => "Sequence",
+            ItemKind::Enum => "Enum",
+            ItemKind::EnumVariant => "Enum variant",
+            ItemKind::Field => "Field",
+        })
+    }
+}
 
 /// Parameters for code generators.
 #[derive(Debug, Clone)]
@@ -74,20 +119,34 @@ pub struct GeneratorParams {
     /// Additional arguments passed to the compiler.
     ///
     /// These are likely to be generator-specific parameters and should be parsed appropriately. The
-    /// [clap](https://docs.rs/clap/latest/clap/) library is recommended for this.
+    /// [clap](https://docs.rs/clap/latest/clap/) library is recommended for this, so that the
+    /// arguments can also be declared with [CodeGenerator::cli].
     ///
-    /// `additional_args` begins with the name of the generator being invoked, followed by a series
-    /// of arguments passed by the user.
+    /// `additional_args` is argv-style: it begins with the name of the generator being invoked,
+    /// followed by the arguments passed by the user, exactly as the shell split them. It can be
+    /// passed directly to `clap::Parser::parse_from`.
     ///
     /// # Example
     ///
-    /// ```
-    /// // simplebuffers-compiler --dstdir='src' cpp 'my_schema.sb' --headerdir='include'
+    /// ```text
+    /// // simplebuffers-compiler --dstdir='src' cpp 'my_schema.sb' --headerdir='my include'
     ///
     /// // Results in:
-    /// additional_args: "cpp --headerdir='include'"
+    /// additional_args: ["cpp", "--headerdir=my include"]
     /// ```
-    pub additional_args: String,
+    pub additional_args: Vec<String>,
+
+    /// Whether reserved identifiers are allowed in the schema.
+    ///
+    /// Normally, the compiler rejects schemas that use any of the generator's
+    /// [reserved identifiers](CodeGenerator::reserved_identifiers). If this is `true`, the check
+    /// was skipped, and generators that support it should rename conflicting identifiers instead
+    /// (for example, by appending `_`).
+    pub relax_reserved: bool,
+
+    /// The source of the schema file, or `None` if the schema was loaded from a descriptor. The
+    /// sources of imported files are not included.
+    pub source: Option<String>,
 }
 
 /// A SimpleBuffers code generator.
@@ -107,16 +166,88 @@ pub trait CodeGenerator {
     ///
     /// * `schema` - The schema to generate code for.
     /// * `params` - The parameters for the generator.
+    /// * `sink` - Where to write generated files. Generators must not create files themselves.
+    ///
+    /// # Returns
+    ///
+    /// The paths of every file that was written, so that the compiler can report them and build
+    /// systems can track them. Generators that only print to `stdout` return an empty list.
     ///
     /// # Errors
     ///
     /// A human-readable string. This will be reported to the user by the compiler, so it should be
     /// a useful message describing the cause of the issue or how it may be fixed.
-    fn generate(&mut self, schema: &SBSchema, params: &GeneratorParams) -> Result<(), String>;
+    fn generate(
+        &mut self,
+        schema: &SBSchema,
+        params: &GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String>;
 
     /// Returns a list of reserved identifiers in the generated language. The compiler will ensure
     /// that these identifiers are not used anywhere in the schema before calling `generate`.
-    fn reserved_identifiers(&mut self, params: &GeneratorParams) -> Vec<String>;
+    ///
+    /// The default implementation reserves nothing, which suits generators whose output has no
+    /// keywords (such as documentation or diagrams).
+    fn reserved_identifiers(&mut self, _params: &GeneratorParams) -> Vec<String> {
+        vec![]
+    }
+
+    /// Returns whether identifiers in the schema must match a
+    /// [reserved identifier](CodeGenerator::reserved_identifiers) exactly to conflict with it.
+    ///
+    /// By default, identifiers are compared after converting both to snake case, so `While`
+    /// conflicts with `while`. This suits generators that change the case of identifiers. A
+    /// generator that returns `true` has `While` accepted, and must escape identifiers whose
+    /// converted form is reserved itself.
+    fn reserved_identifiers_case_sensitive(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the [reserved identifiers](CodeGenerator::reserved_identifiers) apply to
+    /// names of the given kind of item.
+    ///
+    /// A generator that never uses some names as-is (for example, one that always appends `Writer`
+    /// to sequence names) can return `false` for those kinds, so that the compiler accepts them
+    /// even if they are reserved. To opt out of the check entirely, return an empty list from
+    /// `reserved_identifiers` instead.
+    ///
+    /// The default implementation returns `true` for every kind.
+    fn reserved_identifiers_apply_to(&self, _kind: ItemKind) -> bool {
+        true
+    }
+
+    /// Returns the extensions of the files this generator writes, without a leading `.` (for
+    /// example, `["hpp", "cpp"]`). Generators whose output depends on their arguments should list
+    /// every extension they may write.
+    ///
+    /// This lets tools find out what a generator produces without running it. Generators that
+    /// only print to `stdout` return an empty list, which is also the default.
+    fn output_extensions(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Returns a short, human-readable description of the generator, such as `"C++ code"`.
+    ///
+    /// The default implementation returns `"No description"`.
+    fn description(&self) -> String {
+        "No description".to_string()
+    }
+
+    /// Returns a [clap::Command] describing the generator-specific arguments, if the generator
+    /// declares them.
+    ///
+    /// When this returns a command, the compiler validates
+    /// [additional_args](GeneratorParams::additional_args) against it before calling `generate`,
+    /// reporting invalid arguments as errors, and shows the arguments in its `--help` output when
+    /// the generator is named. A generator that parses its arguments with a `clap::Parser` can
+    /// return `Some(<Cli as clap::CommandFactory>::command())`.
+    ///
+    /// The default implementation returns `None`, in which case the arguments are passed to the
+    /// generator without being checked.
+    fn cli(&self) -> Option<clap::Command> {
+        None
+    }
 }
 
 #[macro_export]
@@ -131,7 +262,7 @@ pub trait CodeGenerator {
 ///
 /// # Example
 ///
-/// ```
+/// ```ignore
 /// register_generator!(cpp: MyCppGenerator);
 /// register_generator!(rust: MyRustGenerator);
 /// register_generator!(rs: MyRustGenerator);
@@ -144,3 +275,30 @@ macro_rules! register_generator {
         }
     };
 }
+
+/// The prefix of the symbol that lists the generators of a library, as exported by
+/// [register_generators!]. The prefix is followed by the name of the crate that defines the
+/// generators, so that crates that are linked together do not export the same symbol. The symbol is
+/// a `&'static [&'static str]`.
+pub const GENERATOR_LIST_SYMBOL_PREFIX: &str = "SIMPLEBUFFERS_GENERATORS_";
+
+#[macro_export]
+/// Registers several code generators, like calling [register_generator!] for each of them, and
+/// also exports a list of their names. The compiler uses the list to show the generators of a
+/// library found with `--plugin-dir` in `--list-generators`, as long as the library is named after
+/// its crate, as Cargo names it. Since the list can only be exported once, every generator of a
+/// library must be registered in the same call.
+///
+/// # Example
+///
+/// ```ignore
+/// register_generators!(rust: MyRustGenerator, rs: MyRustGenerator);
+/// ```
+macro_rules! register_generators {
+    ($($name:ident : $generator:ty),+ $(,)?) => {
+        $($crate::register_generator!($name: $generator);)+
+
+        #[export_name = concat!("SIMPLEBUFFERS_GENERATORS_", env!("CARGO_CRATE_NAME"))]
+        pub static SIMPLEBUFFERS_GENERATORS: &[&str] = &[$(stringify!($name)),+];
+    };
+}
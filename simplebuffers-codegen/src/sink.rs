@@ -0,0 +1,108 @@
+//! Destinations for the files that generators write.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// A destination for generated files.
+///
+/// Generators write every file through a sink instead of creating it themselves, so that their
+/// output can be inspected without touching the filesystem (see [MemorySink]).
+pub trait FileSink {
+    /// Writes a text file, replacing it if it already exists. Any missing parent directories are
+    /// created.
+    ///
+    /// # Errors
+    ///
+    /// A human-readable string describing why the file could not be written.
+    fn write_file(&mut self, path: &Path, contents: &str) -> Result<(), String>;
+
+    /// Writes a binary file, replacing it if it already exists. Any missing parent directories are
+    /// created.
+    ///
+    /// The default implementation passes the contents to [write_file](FileSink::write_file) if
+    /// they are valid UTF-8, and fails otherwise.
+    ///
+    /// # Errors
+    ///
+    /// A human-readable string describing why the file could not be written.
+    fn write_bytes(&mut self, path: &Path, contents: &[u8]) -> Result<(), String> {
+        let contents = std::str::from_utf8(contents).map_err(|_| {
+            format!(
+                "Failed to write {}: the sink cannot hold binary files",
+                path.display()
+            )
+        })?;
+        self.write_file(path, contents)
+    }
+
+    /// Prints text for the user instead of writing it to a file, for generators such as the
+    /// formatter whose output is often read directly.
+    ///
+    /// The default implementation writes the text to `stdout`.
+    ///
+    /// # Errors
+    ///
+    /// A human-readable string describing why the text could not be printed.
+    fn print(&mut self, contents: &str) -> Result<(), String> {
+        let mut stdout = std::io::stdout().lock();
+        stdout
+            .write_all(contents.as_bytes())
+            .and_then(|()| stdout.flush())
+            .map_err(|e| format!("Failed to print: {}", e))
+    }
+}
+
+/// A sink that writes files to the filesystem.
+#[derive(Debug, Default)]
+pub struct FileSystemSink;
+
+impl FileSink for FileSystemSink {
+    fn write_file(&mut self, path: &Path, contents: &str) -> Result<(), String> {
+        self.write_bytes(path, contents.as_bytes())
+    }
+
+    fn write_bytes(&mut self, path: &Path, contents: &[u8]) -> Result<(), String> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        fs::write(path, contents).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+}
+
+/// A sink that keeps files in memory, keyed by their paths, and printed text in a string. This is
+/// mostly useful for testing generators.
+#[derive(Debug, Default)]
+pub struct MemorySink {
+    /// The files that were written.
+    pub files: HashMap<PathBuf, String>,
+
+    /// The text that was printed, in order.
+    pub printed: String,
+}
+
+impl MemorySink {
+    /// Creates an empty sink.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the contents of the file written to `path`, if there is one.
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<&str> {
+        self.files.get(path.as_ref()).map(String::as_str)
+    }
+}
+
+impl FileSink for MemorySink {
+    fn write_file(&mut self, path: &Path, contents: &str) -> Result<(), String> {
+        self.files.insert(path.to_path_buf(), contents.to_string());
+        Ok(())
+    }
+
+    fn print(&mut self, contents: &str) -> Result<(), String> {
+        self.printed.push_str(contents);
+        Ok(())
+    }
+}
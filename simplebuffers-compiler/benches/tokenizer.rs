@@ -0,0 +1,60 @@
+//! Benchmarks for the tokenizer and the compiler as a whole.
+//!
+//! Run with `cargo bench -p simplebuffers-compiler`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use simplebuffers_compiler::tokenizer::Tokenizer;
+
+/// Generates a schema with `count` enums and `count` sequences, which is about `14 * count` lines
+/// long. Every kind of token appears, along with comments and doc comments.
+fn large_schema(count: usize) -> String {
+    (0..count)
+        .map(|i| {
+            format!(
+                "// Declarations for item {i}.\n\
+                 \n\
+                 /// The kinds of item {i}.\n\
+                 enum Kind{i} {{\n\
+                 \x20   first = 0;\n\
+                 \x20   second = 0x1_0;\n\
+                 }}\n\
+                 \n\
+                 /// Item {i}.\n\
+                 sequence Item{i} {{\n\
+                 \x20   kind: Kind{i};\n\
+                 \x20   values: [u32; 4];\n\
+                 \x20   data: oneof {{ name: string; id: u64; }};\n\
+                 }}\n"
+            )
+        })
+        .collect()
+}
+
+fn tokenizer(c: &mut Criterion) {
+    let source = large_schema(500);
+
+    let mut group = c.benchmark_group("tokenizer");
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.bench_function("tokenize", |b| {
+        b.iter(|| {
+            let tokenizer = Tokenizer::new(black_box(&source), "bench.sb").unwrap();
+            tokenizer.collect::<Result<Vec<_>, _>>().unwrap()
+        })
+    });
+    group.bench_function("compile", |b| {
+        b.iter(|| {
+            let mut warnings = vec![];
+            simplebuffers_compiler::compile(
+                black_box(&source),
+                "bench.sb",
+                &Default::default(),
+                &mut warnings,
+            )
+            .unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, tokenizer);
+criterion_main!(benches);
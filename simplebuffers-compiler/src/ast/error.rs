@@ -1,6 +1,7 @@
 use colored::Colorize;
 use std::fmt;
 
+use crate::diagnostic::{render, Diagnose};
 use crate::tokenizer::{Token, TokenLocation, TokenType};
 
 /// An error that is returned by the AST builder.
@@ -38,40 +39,45 @@ impl<'a> AstBuilderError<'a> {
 
 impl<'a> std::error::Error for AstBuilderError<'a> {}
 
-impl<'a> fmt::Display for AstBuilderError<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<'a> Diagnose for AstBuilderError<'a> {
+    fn location(&self) -> Option<&TokenLocation<'_>> {
+        match self {
+            Self::UnexpectedToken { location, .. } => Some(location),
+            Self::UnexpectedEof { .. } => None,
+        }
+    }
+
+    fn file(&self) -> Option<&str> {
+        match self {
+            Self::UnexpectedToken { location, .. } => Some(location.file),
+            Self::UnexpectedEof { file } => Some(file),
+        }
+    }
+
+    fn message(&self) -> String {
         match self {
             Self::UnexpectedToken {
                 token_type,
                 message,
-                location,
+                ..
             } => {
+                let token = token_type.to_string().blue().bold();
                 if let Some(message) = message {
-                    write!(
-                        f,
-                        "{error_str} Unexpected token `{token}` ({message})\n{location}",
-                        error_str = "ERROR:".red().bold(),
-                        token = token_type.to_string().blue().bold(),
-                        message = message,
-                        location = location,
-                    )
+                    format!("Unexpected token `{}` ({})", token, message)
                 } else {
-                    write!(
-                        f,
-                        "{error_str} Unexpected token `{token}`\n{location}",
-                        error_str = "ERROR:".red().bold(),
-                        token = token_type.to_string().blue().bold(),
-                        location = location,
-                    )
+                    format!("Unexpected token `{}`", token)
                 }
             }
 
-            Self::UnexpectedEof { file } => write!(
-                f,
-                "{error_str} Unexpected end of file in {file}",
-                error_str = "ERROR:".red().bold(),
-                file = file.green().underline(),
-            ),
+            Self::UnexpectedEof { file } => {
+                format!("Unexpected end of file in {}", file.green().underline())
+            }
         }
     }
 }
+
+impl<'a> fmt::Display for AstBuilderError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render(self, f)
+    }
+}
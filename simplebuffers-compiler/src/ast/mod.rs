@@ -3,24 +3,34 @@
 //! [craftinginterpreters.com/parsing-expressions.html](http://craftinginterpreters.com/parsing-expressions.html)
 //!
 //! # Grammar
-//! 
-//! - file       ->  (sequence | enum)* EOF
-//! - sequence   ->  sequence" IDENTIFIER "{" (field ";")* "}"
+//!
+//! - file       ->  (package | import | alias | doc sequence | doc enum)* EOF
+//! - package    ->  "package" IDENTIFIER ("." IDENTIFIER)* ";"
+//! - import     ->  "import" STRING ";"
+//! - alias      ->  "alias" IDENTIFIER "=" type ";"
+//! - sequence   ->  "sequence" IDENTIFIER "{" (doc field ";")* (doc field)? "}"
 //! - field      ->  IDENTIFIER ":" type
-//! - enum       ->  enum" IDENTIFIER "{" (enum_entry ";")* "}"
-//! - enum_entry ->  IDENTIFIER "=" NUMBER
-//! - type       ->  IDENTIFIER | array | oneof
-//! - array      ->  "[" type "]"
-//! - oneof      ->  "oneof" "{" (field ";")* "}"
+//! - enum       ->  "enum" IDENTIFIER (":" IDENTIFIER)? "{" (doc enum_entry ";")* (doc enum_entry)? "}"
+//! - enum_entry ->  IDENTIFIER "=" (NUMBER | BOOL)
+//! - doc        ->  DOC_COMMENT*
+//! - type       ->  IDENTIFIER | array | map | oneof
+//! - array      ->  "[" type ( ";" NUMBER )? "]"
+//! - map        ->  "map" "<" type "," type ">"
+//! - oneof      ->  "oneof" "{" (doc field ";")* (doc field)? "}"
+//!
+//! Ordinary comments are not part of the grammar. They are skipped, but can be attached to the
+//! nodes around them with [AstBuilder::collect_comments].
 
 mod error;
 mod traverse;
 pub use self::error::AstBuilderError;
 pub use traverse::*;
 
-use crate::tokenizer::{Token, TokenIterator, TokenType, Tokenizer};
+use crate::diagnostic::Diagnose;
+use crate::preprocessor::Preprocessor;
+use crate::tokenizer::{Token, TokenType, Tokenizer};
 use colored::Colorize;
-use std::error::Error;
+use std::collections::HashSet;
 
 pub struct TaggedSyntaxTree<'a> {
     /// The data of this node.
@@ -28,6 +38,14 @@ pub struct TaggedSyntaxTree<'a> {
 
     /// The main token associated with this node.
     pub token: Option<Token<'a>>,
+
+    /// The doc comment attached to this node, if any.
+    pub doc: Option<String>,
+
+    /// The ordinary comments attached to this node, in source order. These are only collected if
+    /// the tree was built with [AstBuilder::collect_comments]. See there for which comments are
+    /// attached to which node.
+    pub comments: Vec<Token<'a>>,
 }
 
 impl<'a> TaggedSyntaxTree<'a> {
@@ -45,19 +63,58 @@ impl<'a> TaggedSyntaxTree<'a> {
         Self {
             data,
             token: Some(token),
+            doc: None,
+            comments: Vec::new(),
         }
     }
+
+    /// Attaches a doc comment to this node.
+    ///
+    /// # Arguments
+    ///
+    /// * `doc` - The doc comment to attach, if any.
+    ///
+    /// # Returns
+    ///
+    /// This node with the doc comment attached.
+    pub fn with_doc(mut self, doc: Option<String>) -> Self {
+        self.doc = doc;
+        self
+    }
+
+    /// Attaches comments to this node.
+    ///
+    /// # Arguments
+    ///
+    /// * `comments` - The comments to attach, in source order.
+    ///
+    /// # Returns
+    ///
+    /// This node with the comments attached.
+    pub fn with_comments(mut self, comments: Vec<Token<'a>>) -> Self {
+        self.comments = comments;
+        self
+    }
 }
 
 /// The data of a syntax tree node.
 pub enum SyntaxTree<'a> {
     File(Vec<TaggedSyntaxTree<'a>>),
+    Package(String),
+    Import(String),
+    Alias(String, Box<TaggedSyntaxTree<'a>>),
     Sequence(String, Vec<TaggedSyntaxTree<'a>>),
     Field(String, Box<TaggedSyntaxTree<'a>>),
-    Enum(String, Vec<TaggedSyntaxTree<'a>>),
+    Enum(
+        String,
+        Option<Box<TaggedSyntaxTree<'a>>>,
+        Vec<TaggedSyntaxTree<'a>>,
+    ),
     EnumEntry(String, String),
     Type(String),
     Array(Box<TaggedSyntaxTree<'a>>),
+    FixedArray(Box<TaggedSyntaxTree<'a>>, usize),
+    Map(Box<TaggedSyntaxTree<'a>>, Box<TaggedSyntaxTree<'a>>),
     OneOf(Vec<TaggedSyntaxTree<'a>>),
 }
 
@@ -69,7 +126,12 @@ impl<'a> SyntaxTree<'a> {
 
 impl<'a> From<SyntaxTree<'a>> for TaggedSyntaxTree<'a> {
     fn from(data: SyntaxTree<'a>) -> Self {
-        Self { data, token: None }
+        Self {
+            data,
+            token: None,
+            doc: None,
+            comments: Vec::new(),
+        }
     }
 }
 
@@ -78,36 +140,67 @@ pub struct AstBuilder<'a> {
     /// The name of the file being parsed.
     file: &'a str,
 
-    /// An iterator that yields tokens from the source string.
-    tokens: Box<TokenIterator<'a>>,
+    /// An iterator that yields preprocessed tokens from the source string.
+    tokens: Preprocessor<'a>,
 
     /// The current token.
     current_token: Option<Token<'a>>,
+
+    /// The comments that were skipped since they were last attached to a node, in source order.
+    comments: Vec<Token<'a>>,
+
+    /// The line of the last token that was consumed, used to find comments that follow a node on
+    /// the same line.
+    last_line: Option<usize>,
+
+    /// Whether comments are attached to nodes.
+    collect_comments: bool,
 }
 
 /// A result type for parsing. This is a convenience type alias.
-pub type AstBuildResult<'a> = Result<TaggedSyntaxTree<'a>, Box<dyn Error + 'a>>;
+pub type AstBuildResult<'a> = Result<TaggedSyntaxTree<'a>, Box<dyn Diagnose + 'a>>;
 
 impl<'a> AstBuilder<'a> {
     /// Creates an AstBuilder at the beginning of the source string. This will construct a Tokenizer
-    /// internally.
+    /// and a Preprocessor internally.
     ///
     /// # Arguments
     ///
     /// * `source` - The source string to parse.
     /// * `file` - The name of the file being parsed.
+    /// * `defines` - The flags to define for `#if` directives.
     ///
     /// # Returns
     ///
     /// An AstBuilder at the beginning of the source string.
-    pub fn new(source: &'a str, file: &'a str) -> Result<Self, Box<dyn Error + 'a>> {
-        let mut tokens = Box::new(Tokenizer::new(source, file)?);
-        let current_token = tokens.next().transpose()?;
-        Ok(Self {
+    pub fn new(
+        source: &'a str,
+        file: &'a str,
+        defines: HashSet<String>,
+    ) -> Result<Self, Box<dyn Diagnose + 'a>> {
+        let tokens = Preprocessor::new(Tokenizer::with_comments(source, file)?, defines);
+        let mut builder = Self {
             file,
             tokens,
-            current_token,
-        })
+            current_token: None,
+            comments: Vec::new(),
+            last_line: None,
+            collect_comments: false,
+        };
+        builder.advance()?;
+        Ok(builder)
+    }
+
+    /// Sets whether ordinary comments are attached to the nodes of the tree, in
+    /// [TaggedSyntaxTree::comments]. They are skipped by default.
+    ///
+    /// Definitions, fields, enum entries, packages, imports, and aliases get the comments on the
+    /// lines before them (including any among their doc comments), and a comment that follows them
+    /// on the line where they end. Comments after the last item of a sequence, enum, or oneof go to
+    /// that sequence, enum, or oneof, and comments at the end of the file go to the file.
+    pub fn collect_comments(mut self, collect: bool) -> Self {
+        self.collect_comments = collect;
+        self
     }
 
     /// Parses the source string into a syntax tree.
@@ -116,43 +209,148 @@ impl<'a> AstBuilder<'a> {
     }
 
     /// Parses the file rule.
-    /// file -> (sequence | enum)* EOF
+    /// file -> (package | import | alias | doc sequence | doc enum)* EOF
     fn parse_file(&mut self) -> AstBuildResult<'a> {
         let mut file = Vec::new();
-        while let Some(token) = &self.current_token {
-            match token.token_type {
-                TokenType::Sequence => file.push(self.parse_sequence()?),
-                TokenType::Enum => file.push(self.parse_enum()?),
-                _ => {
-                    return Err(Box::new(AstBuilderError::unexpected_token(
-                        token,
-                        Some("expected a \"sequence\" or \"enum\"".to_string()),
-                    )));
+        while self.current_token.is_some() {
+            let doc = self.parse_doc()?;
+            let comments = std::mem::take(&mut self.comments);
+            let node = match &self.current_token {
+                Some(token) => match token.token_type {
+                    TokenType::Sequence => self.parse_sequence()?.with_doc(doc),
+                    TokenType::Enum => self.parse_enum()?.with_doc(doc),
+                    // Packages, imports, and aliases cannot be documented, since they do not
+                    // appear in the compiled schema.
+                    TokenType::Package if doc.is_none() => self.parse_package()?,
+                    TokenType::Import if doc.is_none() => self.parse_import()?,
+                    TokenType::Alias if doc.is_none() => self.parse_alias()?,
+                    _ => {
+                        let expected = if doc.is_some() {
+                            "expected a \"sequence\" or \"enum\" after a doc comment"
+                        } else {
+                            "expected \"package\", \"import\", \"alias\", \"sequence\", or \"enum\""
+                        };
+                        return Err(Box::new(AstBuilderError::unexpected_token(
+                            token,
+                            Some(expected.to_string()),
+                        )));
+                    }
+                },
+                None => {
+                    return Err(Box::new(AstBuilderError::UnexpectedEof {
+                        file: self.file.to_string(),
+                    }))
                 }
+            };
+            file.push(self.attach_comments(node, comments));
+        }
+        // Whatever comments are left come after the last node.
+        let comments = std::mem::take(&mut self.comments);
+        Ok(self.attach_comments(SyntaxTree::File(file).into(), comments))
+    }
+
+    /// Parses the package rule. The package name is returned with its parts joined by dots.
+    /// package -> "package" IDENTIFIER ("." IDENTIFIER)* ";"
+    fn parse_package(&mut self) -> AstBuildResult<'a> {
+        let tag = self.expect(TokenType::Package)?;
+        let mut parts = vec![self.expect_identifier()?];
+        while matches!(
+            self.current_token,
+            Some(Token {
+                token_type: TokenType::Dot,
+                ..
+            })
+        ) {
+            self.advance()?;
+            parts.push(self.expect_identifier()?);
+        }
+        self.expect(TokenType::Semicolon)?;
+        Ok(SyntaxTree::Package(parts.join(".")).tag(tag))
+    }
+
+    /// Parses the import rule.
+    /// import -> "import" STRING ";"
+    fn parse_import(&mut self) -> AstBuildResult<'a> {
+        let tag = self.expect(TokenType::Import)?;
+        let path = match &self.current_token {
+            Some(Token {
+                token_type: TokenType::String(path),
+                ..
+            }) => path.clone(),
+            Some(token) => {
+                return Err(Box::new(AstBuilderError::unexpected_token(
+                    token,
+                    Some("expected the path of a schema file in quotes".to_string()),
+                )))
             }
+            None => {
+                return Err(Box::new(AstBuilderError::UnexpectedEof {
+                    file: self.file.to_string(),
+                }))
+            }
+        };
+        self.advance()?;
+        self.expect(TokenType::Semicolon)?;
+        Ok(SyntaxTree::Import(path).tag(tag))
+    }
+
+    /// Parses the alias rule.
+    /// alias -> "alias" IDENTIFIER "=" type ";"
+    fn parse_alias(&mut self) -> AstBuildResult<'a> {
+        self.expect(TokenType::Alias)?;
+        let (name, tag) = self.expect_identifier_with_token()?;
+        self.expect(TokenType::Equals)?;
+        let target = self.parse_type()?;
+        self.expect(TokenType::Semicolon)?;
+        Ok(SyntaxTree::Alias(name, Box::new(target)).tag(tag))
+    }
+
+    /// Parses the doc rule. Consecutive doc comments are joined with newlines, and a single leading
+    /// space is removed from each line.
+    /// doc -> DOC_COMMENT*
+    ///
+    /// # Returns
+    ///
+    /// The doc comment, or `None` if there were no doc comments.
+    fn parse_doc(&mut self) -> Result<Option<String>, Box<dyn Diagnose + 'a>> {
+        let mut lines = Vec::new();
+        while let Some(Token {
+            token_type: TokenType::DocComment(line),
+            ..
+        }) = &self.current_token
+        {
+            lines.push(line.strip_prefix(' ').unwrap_or(line).to_string());
+            self.advance()?;
         }
-        Ok(SyntaxTree::File(file).into())
+        Ok(if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        })
     }
 
     /// Parses the sequence rule.
-    /// sequence -> "sequence" IDENTIFIER "{" (field ";")* "}"
+    /// sequence -> "sequence" IDENTIFIER "{" (doc field ";")* (doc field)? "}"
     fn parse_sequence(&mut self) -> AstBuildResult<'a> {
         let tag = self.expect(TokenType::Sequence)?;
         let name = self.expect_identifier()?;
         self.expect(TokenType::OpenBrace)?;
         let mut fields = Vec::new();
         loop {
+            let doc = self.parse_doc()?;
             match &self.current_token {
                 Some(token) => match token.token_type {
                     TokenType::Identifier(_) => {
-                        fields.push(self.parse_field()?);
-                        self.expect(TokenType::Semicolon)?;
+                        let comments = std::mem::take(&mut self.comments);
+                        let field = self.parse_field()?.with_doc(doc);
+                        self.expect_separator()?;
+                        fields.push(self.attach_comments(field, comments));
                     }
-                    TokenType::CloseBrace => break,
+                    TokenType::CloseBrace if doc.is_none() => break,
                     _ => {
                         return Err(Box::new(AstBuilderError::unexpected_token(
                             token,
-                            Some("expected an identifier or \"}\"".to_string()),
+                            Some(expected_identifier(token, expected_item(&doc))),
                         )));
                     }
                 },
@@ -163,8 +361,11 @@ impl<'a> AstBuilder<'a> {
                 }
             }
         }
+        let comments = std::mem::take(&mut self.comments);
         self.expect(TokenType::CloseBrace)?;
-        Ok(SyntaxTree::Sequence(name, fields).tag(tag))
+        Ok(SyntaxTree::Sequence(name, fields)
+            .tag(tag)
+            .with_comments(comments))
     }
 
     /// Parses the field rule.
@@ -177,24 +378,40 @@ impl<'a> AstBuilder<'a> {
     }
 
     /// Parses the enum rule.
-    /// enum -> "enum" IDENTIFIER "{" (enum_entry ";")* "}"
+    /// enum -> "enum" IDENTIFIER (":" IDENTIFIER)? "{" (doc enum_entry ";")* (doc enum_entry)? "}"
     fn parse_enum(&mut self) -> AstBuildResult<'a> {
         let tag = self.expect(TokenType::Enum)?;
         let name = self.expect_identifier()?;
+        let base = if matches!(
+            self.current_token,
+            Some(Token {
+                token_type: TokenType::Colon,
+                ..
+            })
+        ) {
+            self.advance()?;
+            let (base, base_tag) = self.expect_identifier_with_token()?;
+            Some(Box::new(SyntaxTree::Type(base).tag(base_tag)))
+        } else {
+            None
+        };
         self.expect(TokenType::OpenBrace)?;
         let mut entries = Vec::new();
         loop {
+            let doc = self.parse_doc()?;
             match &self.current_token {
                 Some(token) => match token.token_type {
                     TokenType::Identifier(_) => {
-                        entries.push(self.parse_enum_entry()?);
-                        self.expect(TokenType::Semicolon)?;
+                        let comments = std::mem::take(&mut self.comments);
+                        let entry = self.parse_enum_entry()?.with_doc(doc);
+                        self.expect_separator()?;
+                        entries.push(self.attach_comments(entry, comments));
                     }
-                    TokenType::CloseBrace => break,
+                    TokenType::CloseBrace if doc.is_none() => break,
                     _ => {
                         return Err(Box::new(AstBuilderError::unexpected_token(
                             token,
-                            Some("expected an identifier or \"}\"".to_string()),
+                            Some(expected_identifier(token, expected_item(&doc))),
                         )));
                     }
                 },
@@ -205,27 +422,43 @@ impl<'a> AstBuilder<'a> {
                 }
             }
         }
+        let comments = std::mem::take(&mut self.comments);
         self.expect(TokenType::CloseBrace)?;
-        Ok(SyntaxTree::Enum(name, entries).tag(tag))
+        Ok(SyntaxTree::Enum(name, base, entries)
+            .tag(tag)
+            .with_comments(comments))
     }
 
     /// Parses the enum_entry rule.
-    /// enum_entry -> IDENTIFIER "=" NUMBER
+    /// enum_entry -> IDENTIFIER "=" (NUMBER | BOOL)
     fn parse_enum_entry(&mut self) -> AstBuildResult<'a> {
         let (name, tag) = self.expect_identifier_with_token()?;
         self.expect(TokenType::Equals)?;
-        let value = self.expect_number()?;
+        let value = self.expect_literal()?;
         Ok(SyntaxTree::EnumEntry(name, value).tag(tag))
     }
 
     /// Parses the type rule.
-    /// type -> IDENTIFIER | array | oneof
+    /// type -> IDENTIFIER | array | map | oneof
     fn parse_type(&mut self) -> AstBuildResult<'a> {
         match &self.current_token {
             Some(token) => match token.token_type {
                 TokenType::Identifier(_) => {
                     let tag = token.clone();
                     let name = self.expect_identifier()?;
+                    // `map` is only special when it is followed by `<`, so it can still be used as
+                    // a name.
+                    if name == "map"
+                        && matches!(
+                            self.current_token,
+                            Some(Token {
+                                token_type: TokenType::OpenAngle,
+                                ..
+                            })
+                        )
+                    {
+                        return self.parse_map(tag);
+                    }
                     Ok(SyntaxTree::Type(name).tag(tag))
                 }
                 TokenType::OpenBracket => self.parse_array(),
@@ -241,33 +474,76 @@ impl<'a> AstBuilder<'a> {
         }
     }
 
-    /// Parses the array rule.
-    /// array -> "[" type "]"
+    /// Parses the array rule. If a length is given, the array is a fixed-size array.
+    /// array -> "[" type ( ";" NUMBER )? "]"
     fn parse_array(&mut self) -> AstBuildResult<'a> {
         let tag = self.expect(TokenType::OpenBracket)?;
         let array_type = self.parse_type()?;
+        if matches!(
+            self.current_token,
+            Some(Token {
+                token_type: TokenType::Semicolon,
+                ..
+            })
+        ) {
+            self.advance()?;
+            let len_token = self.current_token.clone();
+            let len_literal = self.expect_number()?;
+            let len = match len_literal.replace('_', "").parse::<usize>() {
+                Ok(0) => Err("array length must be greater than 0"),
+                Ok(len) => Ok(len),
+                Err(_) => Err("array length must be a positive decimal integer"),
+            };
+            let len = len.map_err(|message| -> Box<dyn Diagnose + 'a> {
+                // The number was just consumed, so its token is known to exist.
+                Box::new(AstBuilderError::unexpected_token(
+                    len_token.as_ref().unwrap(),
+                    Some(message.to_string()),
+                ))
+            })?;
+            self.expect(TokenType::CloseBracket)?;
+            return Ok(SyntaxTree::FixedArray(Box::new(array_type), len).tag(tag));
+        }
         self.expect(TokenType::CloseBracket)?;
         Ok(SyntaxTree::Array(Box::new(array_type)).tag(tag))
     }
 
+    /// Parses the rest of the map rule, after the `map` identifier has been consumed.
+    /// map -> "map" "<" type "," type ">"
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The `map` token.
+    fn parse_map(&mut self, tag: Token<'a>) -> AstBuildResult<'a> {
+        self.expect(TokenType::OpenAngle)?;
+        let key_type = self.parse_type()?;
+        self.expect(TokenType::Comma)?;
+        let value_type = self.parse_type()?;
+        self.expect(TokenType::CloseAngle)?;
+        Ok(SyntaxTree::Map(Box::new(key_type), Box::new(value_type)).tag(tag))
+    }
+
     /// Parses the oneof rule.
-    /// oneof -> "oneof" "{" (field ";")* "}"
+    /// oneof -> "oneof" "{" (doc field ";")* (doc field)? "}"
     fn parse_oneof(&mut self) -> AstBuildResult<'a> {
         let tag = self.expect(TokenType::Oneof)?;
         self.expect(TokenType::OpenBrace)?;
         let mut fields = Vec::new();
         loop {
+            let doc = self.parse_doc()?;
             match &self.current_token {
                 Some(token) => match token.token_type {
                     TokenType::Identifier(_) => {
-                        fields.push(self.parse_field()?);
-                        self.expect(TokenType::Semicolon)?;
+                        let comments = std::mem::take(&mut self.comments);
+                        let field = self.parse_field()?.with_doc(doc);
+                        self.expect_separator()?;
+                        fields.push(self.attach_comments(field, comments));
                     }
-                    TokenType::CloseBrace => break,
+                    TokenType::CloseBrace if doc.is_none() => break,
                     _ => {
                         return Err(Box::new(AstBuilderError::unexpected_token(
                             token,
-                            Some("expected an identifier or \"}\"".to_string()),
+                            Some(expected_identifier(token, expected_item(&doc))),
                         )));
                     }
                 },
@@ -278,14 +554,58 @@ impl<'a> AstBuilder<'a> {
                 }
             }
         }
+        let comments = std::mem::take(&mut self.comments);
         self.expect(TokenType::CloseBrace)?;
-        Ok(SyntaxTree::OneOf(fields).tag(tag))
+        Ok(SyntaxTree::OneOf(fields).tag(tag).with_comments(comments))
     }
 
-    /// Advances the parser to the next token.
-    fn advance(&mut self) -> Result<(), Box<dyn Error + 'a>> {
-        self.current_token = self.tokens.next().transpose()?;
-        Ok(())
+    /// Advances the parser to the next token. Comments are set aside, to be attached to a node
+    /// later.
+    fn advance(&mut self) -> Result<(), Box<dyn Diagnose + 'a>> {
+        if let Some(token) = &self.current_token {
+            self.last_line = Some(token.location.line_num);
+        }
+        loop {
+            self.current_token = self.tokens.next().transpose()?;
+            match self.current_token.take() {
+                Some(
+                    comment @ Token {
+                        token_type: TokenType::Comment(_),
+                        ..
+                    },
+                ) => self.comments.push(comment),
+                token => {
+                    self.current_token = token;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Attaches comments to a node that was just parsed, if comments are being collected. The
+    /// comments that follow the node on the line where it ends are attached after the given ones,
+    /// which come before the node, and after the node's own comments.
+    fn attach_comments(
+        &mut self,
+        mut node: TaggedSyntaxTree<'a>,
+        before: Vec<Token<'a>>,
+    ) -> TaggedSyntaxTree<'a> {
+        let trailing = match self.last_line {
+            Some(line) => self
+                .comments
+                .iter()
+                .take_while(|c| c.location.line_num == line)
+                .count(),
+            None => 0,
+        };
+        let after: Vec<_> = self.comments.drain(..trailing).collect();
+        if self.collect_comments {
+            let inner = std::mem::take(&mut node.comments);
+            node.comments = before.into_iter().chain(inner).chain(after).collect();
+        } else {
+            node.comments.clear();
+        }
+        node
     }
 
     /// Expects the current token to be of the provided type. If it is not, an error is returned.
@@ -298,7 +618,7 @@ impl<'a> AstBuilder<'a> {
     /// # Returns
     ///
     /// The current token if it is of the provided type.
-    fn expect(&mut self, token_type: TokenType) -> Result<Token<'a>, Box<dyn Error + 'a>> {
+    fn expect(&mut self, token_type: TokenType) -> Result<Token<'a>, Box<dyn Diagnose + 'a>> {
         match &self.current_token {
             Some(token) => {
                 if token.token_type != token_type {
@@ -317,13 +637,28 @@ impl<'a> AstBuilder<'a> {
         }
     }
 
+    /// Expects the `;` after an item of a sequence, enum, or oneof. The `;` may be left out after the
+    /// last item, so a `}` is accepted as well, but it is not consumed.
+    fn expect_separator(&mut self) -> Result<(), Box<dyn Diagnose + 'a>> {
+        match &self.current_token {
+            Some(token) if token.token_type == TokenType::CloseBrace => Ok(()),
+            Some(token) if token.token_type != TokenType::Semicolon => {
+                Err(Box::new(AstBuilderError::unexpected_token(
+                    token,
+                    Some("expected \";\" or \"}\"".to_string()),
+                )))
+            }
+            _ => self.expect(TokenType::Semicolon).map(|_| ()),
+        }
+    }
+
     /// Expects the current token to be an identifier. If it is not, an error is returned. If the
     /// current token is an identifier, it is consumed and the next token is loaded.
     ///
     /// # Returns
     ///
     /// The identifier if the current token is an identifier.
-    fn expect_identifier(&mut self) -> Result<String, Box<dyn Error + 'a>> {
+    fn expect_identifier(&mut self) -> Result<String, Box<dyn Diagnose + 'a>> {
         match &self.current_token {
             Some(token) => {
                 if let TokenType::Identifier(identifier) = &token.token_type {
@@ -333,7 +668,10 @@ impl<'a> AstBuilder<'a> {
                 } else {
                     Err(Box::new(AstBuilderError::unexpected_token(
                         token,
-                        Some("expected an identifier".to_string()),
+                        Some(expected_identifier(
+                            token,
+                            "expected an identifier".to_string(),
+                        )),
                     )))
                 }
             }
@@ -350,7 +688,9 @@ impl<'a> AstBuilder<'a> {
     /// # Returns
     ///
     /// A tuple containing the identifier and the token.
-    fn expect_identifier_with_token(&mut self) -> Result<(String, Token<'a>), Box<dyn Error + 'a>> {
+    fn expect_identifier_with_token(
+        &mut self,
+    ) -> Result<(String, Token<'a>), Box<dyn Diagnose + 'a>> {
         match &self.current_token {
             Some(token) => {
                 if let TokenType::Identifier(identifier) = &token.token_type {
@@ -360,7 +700,10 @@ impl<'a> AstBuilder<'a> {
                 } else {
                     Err(Box::new(AstBuilderError::unexpected_token(
                         token,
-                        Some("expected an identifier".to_string()),
+                        Some(expected_identifier(
+                            token,
+                            "expected an identifier".to_string(),
+                        )),
                     )))
                 }
             }
@@ -377,7 +720,7 @@ impl<'a> AstBuilder<'a> {
     /// # Returns
     ///
     /// The number literal if the current token is a number literal.
-    fn expect_number(&mut self) -> Result<String, Box<dyn Error + 'a>> {
+    fn expect_number(&mut self) -> Result<String, Box<dyn Diagnose + 'a>> {
         match &self.current_token {
             Some(token) => {
                 if let TokenType::Number(number) = &token.token_type {
@@ -396,4 +739,51 @@ impl<'a> AstBuilder<'a> {
             })),
         }
     }
+
+    /// Expects a number or boolean literal. If the current token is not a literal, an error is
+    /// returned. If the current token is a literal, it is consumed and the next token is loaded.
+    ///
+    /// # Returns
+    ///
+    /// The literal as it was written, such as `0x10` or `true`.
+    fn expect_literal(&mut self) -> Result<String, Box<dyn Diagnose + 'a>> {
+        match &self.current_token {
+            Some(token) => {
+                let literal = match &token.token_type {
+                    TokenType::Number(number) => number.clone(),
+                    TokenType::Bool(value) => value.to_string(),
+                    _ => {
+                        return Err(Box::new(AstBuilderError::unexpected_token(
+                            token,
+                            Some("expected a number or \"true\" or \"false\"".to_string()),
+                        )))
+                    }
+                };
+                self.advance()?;
+                Ok(literal)
+            }
+            None => Err(Box::new(AstBuilderError::UnexpectedEof {
+                file: self.file.to_string(),
+            })),
+        }
+    }
+}
+
+/// Describes what was expected where a name should be. Boolean literals are reserved, so they get
+/// their own explanation rather than a generic one.
+fn expected_identifier(token: &Token<'_>, expected: String) -> String {
+    match token.token_type {
+        TokenType::Bool(value) => format!("\"{}\" is reserved and cannot be used as a name", value),
+        _ => expected,
+    }
+}
+
+/// Describes what was expected inside a sequence, enum, or oneof body, for use in error messages.
+/// After a doc comment, the body cannot end, since the doc comment must document something.
+fn expected_item(doc: &Option<String>) -> String {
+    if doc.is_some() {
+        "expected an identifier after a doc comment".to_string()
+    } else {
+        "expected an identifier or \"}\"".to_string()
+    }
 }
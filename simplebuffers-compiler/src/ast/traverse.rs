@@ -27,14 +27,18 @@ impl<'a> Iterator for Children<'a> {
 
         // Figure out which child is next depending on the type of the tree.
         let res = match &self.tree.data {
-            // If the tree is a file, sequence, enum, or oneof, the children are in a vector.
+            // If the tree is a file, sequence, enum, or oneof, the children are in a vector. The base
+            // type of an enum is not a child, since it can only name a primitive.
             SyntaxTree::File(vec)
             | SyntaxTree::Sequence(_, vec)
-            | SyntaxTree::Enum(_, vec)
+            | SyntaxTree::Enum(_, _, vec)
             | SyntaxTree::OneOf(vec) => vec.get(self.index),
 
-            // If the tree is a field or array, the child is a single tree.
-            SyntaxTree::Field(_, child) | SyntaxTree::Array(child) => {
+            // If the tree is a field, an alias, or an array, the child is a single tree.
+            SyntaxTree::Field(_, child)
+            | SyntaxTree::Alias(_, child)
+            | SyntaxTree::Array(child)
+            | SyntaxTree::FixedArray(child, _) => {
                 if self.index == 0 {
                     Some(child.as_ref())
                 } else {
@@ -42,8 +46,18 @@ impl<'a> Iterator for Children<'a> {
                 }
             }
 
-            // If the tree is a primitive, there are no children.
-            SyntaxTree::EnumEntry(_, _) | SyntaxTree::Type(_) => None,
+            // If the tree is a map, the children are the key type and the value type.
+            SyntaxTree::Map(key, value) => match self.index {
+                0 => Some(key.as_ref()),
+                1 => Some(value.as_ref()),
+                _ => None,
+            },
+
+            // If the tree is a primitive, a package, or an import, there are no children.
+            SyntaxTree::EnumEntry(_, _)
+            | SyntaxTree::Type(_)
+            | SyntaxTree::Package(_)
+            | SyntaxTree::Import(_) => None,
         };
 
         self.index += 1;
@@ -67,14 +81,18 @@ impl<'a> DoubleEndedIterator for Children<'a> {
 
         // Figure out which child is next depending on the type of the tree.
         let res = match &self.tree.data {
-            // If the tree is a file, sequence, enum, or oneof, the children are in a vector.
+            // If the tree is a file, sequence, enum, or oneof, the children are in a vector. The base
+            // type of an enum is not a child, since it can only name a primitive.
             SyntaxTree::File(vec)
             | SyntaxTree::Sequence(_, vec)
-            | SyntaxTree::Enum(_, vec)
+            | SyntaxTree::Enum(_, _, vec)
             | SyntaxTree::OneOf(vec) => vec.get(self.index_back),
 
-            // If the tree is a field or array, the child is a single tree.
-            SyntaxTree::Field(_, child) | SyntaxTree::Array(child) => {
+            // If the tree is a field, an alias, or an array, the child is a single tree.
+            SyntaxTree::Field(_, child)
+            | SyntaxTree::Alias(_, child)
+            | SyntaxTree::Array(child)
+            | SyntaxTree::FixedArray(child, _) => {
                 if self.index_back == 0 {
                     Some(child.as_ref())
                 } else {
@@ -82,8 +100,18 @@ impl<'a> DoubleEndedIterator for Children<'a> {
                 }
             }
 
-            // If the tree is a primitive, there are no children.
-            SyntaxTree::EnumEntry(_, _) | SyntaxTree::Type(_) => None,
+            // If the tree is a map, the children are the key type and the value type.
+            SyntaxTree::Map(key, value) => match self.index_back {
+                0 => Some(key.as_ref()),
+                1 => Some(value.as_ref()),
+                _ => None,
+            },
+
+            // If the tree is a primitive, a package, or an import, there are no children.
+            SyntaxTree::EnumEntry(_, _)
+            | SyntaxTree::Type(_)
+            | SyntaxTree::Package(_)
+            | SyntaxTree::Import(_) => None,
         };
 
         res
@@ -103,12 +131,20 @@ impl<'a> ChildrenIterator<'a> for TaggedSyntaxTree<'a> {
             index_back: match &self.data {
                 SyntaxTree::File(vec)
                 | SyntaxTree::Sequence(_, vec)
-                | SyntaxTree::Enum(_, vec)
+                | SyntaxTree::Enum(_, _, vec)
                 | SyntaxTree::OneOf(vec) => vec.len(),
 
-                SyntaxTree::Field(_, _) | SyntaxTree::Array(_) => 1,
+                SyntaxTree::Field(_, _)
+                | SyntaxTree::Alias(_, _)
+                | SyntaxTree::Array(_)
+                | SyntaxTree::FixedArray(_, _) => 1,
+
+                SyntaxTree::Map(_, _) => 2,
 
-                SyntaxTree::EnumEntry(_, _) | SyntaxTree::Type(_) => 0,
+                SyntaxTree::EnumEntry(_, _)
+                | SyntaxTree::Type(_)
+                | SyntaxTree::Package(_)
+                | SyntaxTree::Import(_) => 0,
             },
         }
     }
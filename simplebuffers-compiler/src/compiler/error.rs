@@ -1,7 +1,7 @@
-use colored::Colorize;
 use std::fmt;
 
-use crate::tokenizer::Token;
+use crate::diagnostic::{render, Diagnose};
+use crate::tokenizer::{Token, TokenLocation};
 
 #[derive(Debug)]
 pub struct CompilerError<'a> {
@@ -24,26 +24,20 @@ impl<'a> CompilerError<'a> {
 
 impl<'a> std::error::Error for CompilerError<'a> {}
 
+impl<'a> Diagnose for CompilerError<'a> {
+    fn location(&self) -> Option<&TokenLocation<'_>> {
+        self.token.as_ref().map(|token| &token.location)
+    }
+
+    fn message(&self) -> String {
+        self.message
+            .clone()
+            .unwrap_or_else(|| "Compiler error".to_string())
+    }
+}
+
 impl<'a> fmt::Display for CompilerError<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(message) = &self.message {
-            write!(
-                f,
-                "{error_str} {message}",
-                error_str = "ERROR:".red().bold(),
-                message = message,
-            )?;
-        } else {
-            write!(
-                f,
-                "{error_str} Compiler error",
-                error_str = "ERROR:".red().bold(),
-            )?;
-        }
-        if let Some(token) = &self.token {
-            write!(f, "\n{}", token.location,)
-        } else {
-            Ok(())
-        }
+        render(self, f)
     }
 }
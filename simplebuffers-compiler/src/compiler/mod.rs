@@ -2,5 +2,9 @@
 
 mod error;
 mod parse;
+mod unused;
+mod warning;
 pub use error::CompilerError;
 pub use parse::*;
+pub use unused::find_unused;
+pub use warning::CompilerWarning;
@@ -1,6 +1,6 @@
 //! Parses a [TaggedSyntaxTree] into a series of sequences and enums.
 
-use super::CompilerError;
+use super::{CompilerError, CompilerWarning};
 use crate::ast::{SyntaxTree, TaggedSyntaxTree, TreeTraversal};
 use colored::Colorize;
 use simplebuffers_core::*;
@@ -21,31 +21,63 @@ const PRIMITIVES: [(&str, Primitive); 11] = [
     ("f64", Primitive::F64),
 ];
 
-/// Determines whether a structure is a sequence or an enum.
-#[derive(Clone, Copy, PartialEq)]
-enum StructType {
+/// Determines whether a structure is a sequence, an enum, or an alias for another type.
+#[derive(Clone, Copy)]
+enum StructType<'a> {
     Sequence,
     Enum,
+
+    /// An alias, along with the type it stands for.
+    Alias(&'a TaggedSyntaxTree<'a>),
 }
 
 /// Parse a SyntaxTree into a series of sequences and enums. This will verify that all types are
 /// valid.
 ///
+/// The sequences and enums of imported schemas come first in the result, in the order they were
+/// imported. A type that is imported more than once (such as through two schemas that import the
+/// same file) is only included once.
+///
 /// # Arguments
 ///
 /// * `root` - The root of the SyntaxTree.
+/// * `imports` - The compiled schema for each `import` in the SyntaxTree, by the path given in the
+///   `import` statement.
+/// * `warnings` - A list to push any non-fatal problems to.
 ///
 /// # Returns
 ///
 /// The result of parsing the SyntaxTree or an error if the SyntaxTree is invalid.
-pub fn parse_ast<'a>(root: &'a TaggedSyntaxTree<'a>) -> Result<SBSchema, Box<CompilerError<'a>>> {
+pub fn parse_ast<'a>(
+    root: &'a TaggedSyntaxTree<'a>,
+    imports: &HashMap<String, SBSchema>,
+    warnings: &mut Vec<CompilerWarning<'a>>,
+) -> Result<SBSchema, Box<CompilerError<'a>>> {
     // make a map from strings to sequences and enums. This is used to verify that all types are
-    // valid and unique.
-    let mut struct_map: HashMap<String, StructType> = HashMap::new();
+    // valid and unique. It is only used for lookups: the result is built by walking the tree in
+    // order, so that it does not depend on hash order.
+    let mut struct_map: HashMap<String, StructType<'a>> = HashMap::new();
+    let mut imported = SBSchema {
+        package: None,
+        sequences: Vec::new(),
+        enums: Vec::new(),
+    };
     for node in root.iter_depth_first() {
         let (name, struct_type) = match &node.data {
             SyntaxTree::Sequence(name, _) => (name.clone(), StructType::Sequence),
-            SyntaxTree::Enum(name, _) => (name.clone(), StructType::Enum),
+            SyntaxTree::Enum(name, _, _) => (name.clone(), StructType::Enum),
+            SyntaxTree::Alias(name, target) => (name.clone(), StructType::Alias(target)),
+            SyntaxTree::Import(path) => {
+                let schema = imports.get(path).ok_or_else(|| {
+                    Box::new(CompilerError::new(
+                        node.token.clone(),
+                        format!("Import \"{}\" was not resolved", path),
+                    ))
+                })?;
+                merge_import(schema, &mut imported, &mut struct_map)
+                    .map_err(|message| Box::new(CompilerError::new(node.token.clone(), message)))?;
+                continue;
+            }
             _ => continue,
         };
         if let Err(message) = verify_struct_name(name.as_str(), &struct_map) {
@@ -59,6 +91,7 @@ pub fn parse_ast<'a>(root: &'a TaggedSyntaxTree<'a>) -> Result<SBSchema, Box<Com
 
     // Construct the result.
     let mut result = SBSchema {
+        package: None,
         sequences: Vec::new(),
         enums: Vec::new(),
     };
@@ -67,38 +100,149 @@ pub fn parse_ast<'a>(root: &'a TaggedSyntaxTree<'a>) -> Result<SBSchema, Box<Com
         _ => unreachable!("Root node is not a file"),
     };
 
-    // All top level nodes must be sequences or enums. Parse them.
+    check_alias_cycles(file_contents, &struct_map)?;
+
+    // All top level nodes must be packages, imports, aliases, sequences, or enums. Parse them.
     for top_level in file_contents {
         match &top_level.data {
+            // Imports were merged above, and aliases are expanded where they are used.
+            SyntaxTree::Import(_) | SyntaxTree::Alias(_, _) => {}
+            SyntaxTree::Package(package) => {
+                if let Some(existing) = &result.package {
+                    return Err(Box::new(CompilerError::new(
+                        top_level.token.clone(),
+                        format!(
+                            "The package is already declared as \"{}\"",
+                            existing.cyan().bold()
+                        ),
+                    )));
+                }
+                result.package = Some(package.clone());
+            }
             SyntaxTree::Sequence(name, fields) => {
-                result
-                    .sequences
-                    .push(parse_sequence(name.clone(), fields, &struct_map)?)
+                // An empty sequence has a size of 0, which is almost always a mistake.
+                if fields.is_empty() {
+                    warnings.push(CompilerWarning::new(
+                        top_level.token.clone(),
+                        format!("Sequence \"{}\" has no fields", name.cyan().bold()),
+                    ));
+                }
+                result.sequences.push(parse_sequence(
+                    name.clone(),
+                    top_level.doc.clone(),
+                    fields,
+                    &struct_map,
+                )?)
             }
-            SyntaxTree::Enum(name, entries) => {
-                result.enums.push(parse_enum(name.clone(), entries)?)
+            SyntaxTree::Enum(name, base, entries) => {
+                let enm = parse_enum(
+                    name.clone(),
+                    top_level.doc.clone(),
+                    base.as_deref(),
+                    entries,
+                )?;
+                // Most languages cannot represent an enum without variants.
+                if enm.variants.is_empty() {
+                    warnings.push(CompilerWarning::new(
+                        top_level.token.clone(),
+                        format!("Enum \"{}\" has no variants", name.cyan().bold()),
+                    ));
+                }
+                if let Some((index, inferred)) = widening_variant(&enm) {
+                    // A late variant widened the enum, which shifts every field after it.
+                    let variant = &enm.variants[index];
+                    warnings.push(CompilerWarning::new(
+                        entries[index].token.clone(),
+                        format!(
+                            "Enum entry \"{}\" makes enum \"{}\" {} bytes wide, while the entries \
+                             before it fit in {}. Consider declaring its base type as \"{}\" to lock \
+                             its size",
+                            format!("{}:{}", name, variant.name).cyan().bold(),
+                            name.cyan().bold(),
+                            enm.size,
+                            smallest_size(&enm, enm.variants[0].value),
+                            inferred.to_string().cyan().bold()
+                        ),
+                    ));
+                } else if enm.size > 1 && enm.base.is_none() {
+                    warnings.push(CompilerWarning::new(
+                        top_level.token.clone(),
+                        format!(
+                            "Enum \"{}\" is {} bytes wide because of the range of its values. Consider \
+                             declaring its base type explicitly, so that adding a variant cannot \
+                             change its size by accident",
+                            name.cyan().bold(),
+                            enm.size
+                        ),
+                    ));
+                }
+                result.enums.push(enm)
             }
-            _ => unreachable!("Top level node is not a sequence or enum"),
+            _ => unreachable!("Top level node is not a package, import, alias, sequence, or enum"),
         }
     }
 
-    // Inject enum size into all enum types.
-    for enm in &result.enums {
+    check_recursion(file_contents, &result.sequences)?;
+
+    // Inject enum size into all enum types. Fields may refer to imported enums.
+    for enm in result.enums.iter().chain(&imported.enums) {
         for sequence in &mut result.sequences {
             inject_enum_size_into(&enm.name, enm.size.into(), &mut sequence.fields);
         }
     }
 
+    // Static sizes are only known once enum sizes have been injected, so they are checked last.
+    check_static_sizes(file_contents, &result.sequences)?;
+
+    // Imported types are already checked, and cannot refer to types in this file.
+    imported.sequences.append(&mut result.sequences);
+    imported.enums.append(&mut result.enums);
+    result.sequences = imported.sequences;
+    result.enums = imported.enums;
+
     Ok(result)
 }
 
-/// Verifies that a struct name is not reserved and is unique.
-fn verify_struct_name(name: &str, struct_map: &HashMap<String, StructType>) -> Result<(), String> {
-    // Check if the name is reserved.
-    for (primitive_name, _) in PRIMITIVES.iter() {
-        if name == *primitive_name {
-            return Err(format!("Name \"{}\" is reserved", name));
+/// Merges the sequences and enums of an imported schema into `imported`, and adds their names to
+/// `struct_map`. Types that are identical to one that was already imported are skipped.
+///
+/// # Returns
+///
+/// Nothing, or an error if a type has the same name as a different type.
+fn merge_import(
+    schema: &SBSchema,
+    imported: &mut SBSchema,
+    struct_map: &mut HashMap<String, StructType<'_>>,
+) -> Result<(), String> {
+    for enm in &schema.enums {
+        if imported.enums.contains(enm) {
+            continue;
+        }
+        verify_struct_name(&enm.name, struct_map)?;
+        struct_map.insert(enm.name.clone(), StructType::Enum);
+        imported.enums.push(enm.clone());
+    }
+    for sequence in &schema.sequences {
+        if imported.sequences.contains(sequence) {
+            continue;
         }
+        verify_struct_name(&sequence.name, struct_map)?;
+        struct_map.insert(sequence.name.clone(), StructType::Sequence);
+        imported.sequences.push(sequence.clone());
+    }
+    Ok(())
+}
+
+/// Verifies that a struct name is not reserved and is unique.
+fn verify_struct_name(
+    name: &str,
+    struct_map: &HashMap<String, StructType<'_>>,
+) -> Result<(), String> {
+    // Check if the name is reserved. Boolean literals cannot be written as names in a schema, but
+    // they can appear in an imported descriptor.
+    let is_literal = name == "true" || name == "false";
+    if is_literal || PRIMITIVES.iter().any(|(primitive, _)| name == *primitive) {
+        return Err(format!("Name \"{}\" is reserved", name));
     }
 
     // Check if the name is already used.
@@ -115,8 +259,9 @@ fn verify_struct_name(name: &str, struct_map: &HashMap<String, StructType>) -> R
 /// Parse a sequence.
 fn parse_sequence<'a>(
     name: String,
+    doc: Option<String>,
     fields: &Vec<TaggedSyntaxTree<'a>>,
-    struct_map: &HashMap<String, StructType>,
+    struct_map: &HashMap<String, StructType<'a>>,
 ) -> Result<Sequence, Box<CompilerError<'a>>> {
     let mut res = Vec::with_capacity(fields.len());
     let mut field_names = Vec::<String>::with_capacity(fields.len());
@@ -145,6 +290,7 @@ fn parse_sequence<'a>(
                 name: field_name.clone(),
                 ty: field_type,
                 index: offset,
+                doc: field.doc.clone(),
             });
             offset += field_size;
         } else {
@@ -152,34 +298,227 @@ fn parse_sequence<'a>(
         }
     }
 
-    Ok(Sequence { name, fields: res })
+    Ok(Sequence {
+        name,
+        fields: res,
+        doc,
+    })
+}
+
+/// Verifies that the static section of every sequence fits in the 16-bit offsets used by the wire
+/// format. Offsets into the dynamic section can still overflow at runtime if a message holds too
+/// much data; that is left to the generated code to handle.
+///
+/// # Arguments
+///
+/// * `nodes` - The top level nodes of the file, used to point errors at the offending sequence.
+/// * `sequences` - The parsed sequences, in the same order as they appear in `nodes`.
+fn check_static_sizes<'a>(
+    nodes: &'a [TaggedSyntaxTree<'a>],
+    sequences: &[Sequence],
+) -> Result<(), Box<CompilerError<'a>>> {
+    let sequence_nodes = nodes
+        .iter()
+        .filter(|node| matches!(node.data, SyntaxTree::Sequence(_, _)));
+    for (sequence, node) in sequences.iter().zip(sequence_nodes) {
+        let size = sequence.static_size();
+        if size > u16::MAX as usize {
+            return Err(Box::new(CompilerError::new(
+                node.token.clone(),
+                format!(
+                    "Sequence \"{}\" has a static size of {} bytes, but offsets can only address \
+                     {} bytes. Move large fields into lists to shrink it",
+                    sequence.name.cyan().bold(),
+                    size,
+                    u16::MAX
+                ),
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Verifies that no sequence contains itself through a chain of sequence fields. Such a sequence
+/// could only be written by nesting it forever. Lists and oneofs break the chain, since a list may be
+/// empty and a oneof may hold another field.
+///
+/// # Arguments
+///
+/// * `nodes` - The top level nodes of the file, used to point errors at the offending field.
+/// * `sequences` - The parsed sequences, in the same order as they appear in `nodes`.
+fn check_recursion<'a>(
+    nodes: &'a [TaggedSyntaxTree<'a>],
+    sequences: &[Sequence],
+) -> Result<(), Box<CompilerError<'a>>> {
+    // Map each sequence to the sequences it holds directly, along with the fields that hold them.
+    let field_nodes = nodes.iter().filter_map(|node| match &node.data {
+        SyntaxTree::Sequence(_, fields) => Some(fields),
+        _ => None,
+    });
+    let mut edges: HashMap<&str, Vec<(&str, &'a TaggedSyntaxTree<'a>)>> = HashMap::new();
+    for (sequence, fields) in sequences.iter().zip(field_nodes) {
+        let children = sequence
+            .fields
+            .iter()
+            .zip(fields)
+            .filter_map(|(field, node)| match &field.ty {
+                Type::Sequence(child) => Some((child.as_str(), node)),
+                _ => None,
+            })
+            .collect();
+        edges.insert(sequence.name.as_str(), children);
+    }
+
+    // Depth-first search from every sequence, in declaration order, so that the reported cycle
+    // does not depend on hash order.
+    let mut finished = Vec::<&str>::new();
+    for sequence in sequences {
+        let mut path = Vec::new();
+        find_cycle(sequence.name.as_str(), &edges, &mut path, &mut finished)?;
+    }
+    Ok(())
+}
+
+/// Searches the sequences reachable from `name` for a cycle, returning an error for the field that
+/// closes the first one found.
+///
+/// # Arguments
+///
+/// * `name` - The sequence to search from.
+/// * `edges` - The sequences held directly by each sequence.
+/// * `path` - The sequences on the current search path.
+/// * `finished` - The sequences that have already been searched and are known to be acyclic.
+fn find_cycle<'a, 'b>(
+    name: &'b str,
+    edges: &HashMap<&'b str, Vec<(&'b str, &'a TaggedSyntaxTree<'a>)>>,
+    path: &mut Vec<&'b str>,
+    finished: &mut Vec<&'b str>,
+) -> Result<(), Box<CompilerError<'a>>> {
+    if finished.contains(&name) {
+        return Ok(());
+    }
+    path.push(name);
+    for (child, node) in edges.get(name).into_iter().flatten() {
+        if let Some(start) = path.iter().position(|seq| seq == child) {
+            let field_name = match &node.data {
+                SyntaxTree::Field(field_name, _) => field_name,
+                _ => unreachable!("Field is not a field"),
+            };
+            let cycle = path[start..]
+                .iter()
+                .chain(std::iter::once(child))
+                .map(|seq| seq.cyan().bold().to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(Box::new(CompilerError::new(
+                node.token.clone(),
+                format!(
+                    "Field \"{}\" makes sequence \"{}\" contain itself ({}). Use a list or a oneof \
+                     to break the cycle",
+                    field_name.cyan().bold(),
+                    child.cyan().bold(),
+                    cycle
+                ),
+            )));
+        }
+        find_cycle(child, edges, path, finished)?;
+    }
+    path.pop();
+    finished.push(name);
+    Ok(())
+}
+
+/// Verifies that no alias refers to itself, directly or through other aliases. Such an alias would
+/// expand forever.
+///
+/// # Arguments
+///
+/// * `nodes` - The top level nodes of the file.
+/// * `struct_map` - The sequences, enums, and aliases that are declared or imported.
+fn check_alias_cycles<'a>(
+    nodes: &'a [TaggedSyntaxTree<'a>],
+    struct_map: &HashMap<String, StructType<'a>>,
+) -> Result<(), Box<CompilerError<'a>>> {
+    // Every alias that is named anywhere in a type, including inside lists and oneofs.
+    let referenced_aliases = |target: &'a TaggedSyntaxTree<'a>| {
+        target
+            .iter_depth_first()
+            .filter_map(|node| match &node.data {
+                SyntaxTree::Type(name) => match struct_map.get(name) {
+                    Some(StructType::Alias(target)) => Some((name.as_str(), *target)),
+                    _ => None,
+                },
+                _ => None,
+            })
+    };
+
+    for node in nodes {
+        let SyntaxTree::Alias(name, target) = &node.data else {
+            continue;
+        };
+        // Depth-first search through the aliases this alias refers to.
+        let mut stack = vec![(vec![name.as_str()], target.as_ref())];
+        while let Some((path, target)) = stack.pop() {
+            for (referenced, referenced_target) in referenced_aliases(target) {
+                if referenced == name {
+                    let cycle = path
+                        .iter()
+                        .chain(std::iter::once(&referenced))
+                        .map(|alias| alias.cyan().bold().to_string())
+                        .collect::<Vec<_>>()
+                        .join(" -> ");
+                    return Err(Box::new(CompilerError::new(
+                        node.token.clone(),
+                        format!(
+                            "Alias \"{}\" refers to itself ({})",
+                            name.cyan().bold(),
+                            cycle
+                        ),
+                    )));
+                }
+                // Cycles that do not include this alias are reported for the aliases in them.
+                if !path.contains(&referenced) {
+                    let mut path = path.clone();
+                    path.push(referenced);
+                    stack.push((path, referenced_target));
+                }
+            }
+        }
+    }
+    Ok(())
 }
 
 /// Parse a type.
 fn parse_type<'a>(
     ty: &TaggedSyntaxTree<'a>,
-    struct_map: &HashMap<String, StructType>,
+    struct_map: &HashMap<String, StructType<'a>>,
 ) -> Result<Type, Box<CompilerError<'a>>> {
     match &ty.data {
-        // Type is a simple named type. This can be a primitive, sequence, or enum. Verify that the
-        // type is valid and parse it.
+        // Type is a simple named type. This can be a primitive, sequence, enum, or alias. Verify
+        // that the type is valid and parse it.
         SyntaxTree::Type(name) => {
             // Check if the type is a string.
             if name == "string" {
                 Ok(Type::String)
             }
+            // Check if the type is a byte buffer.
+            else if name == "bytes" {
+                Ok(Type::Bytes)
+            }
             // Check if the type is a sequence or enum.
             else if let Some(struct_type) = struct_map.get(name) {
                 match struct_type {
                     StructType::Sequence => Ok(Type::Sequence(name.clone())),
                     StructType::Enum => Ok(Type::Enum(name.clone(), 0)),
+                    // Aliases are checked for cycles before any types are parsed, so this ends.
+                    StructType::Alias(target) => parse_type(target, struct_map),
                 }
             }
-            // Type is not a string, sequence, or enum. Check if it is a primitive.
+            // Type is not a string, byte buffer, sequence, or enum. Check if it is a primitive.
             else if let Some(found) = PRIMITIVES.iter().find(|&x| x.0 == name) {
                 Ok(Type::Primitive(found.1.clone()))
             }
-            // Type is not a primitive, string, sequence, or enum. Error.
+            // Type is not a primitive, string, byte buffer, sequence, or enum. Error.
             else {
                 Err(Box::new(CompilerError::new(
                     ty.token.clone(),
@@ -189,10 +528,63 @@ fn parse_type<'a>(
         }
 
         // Type is an array. Parse the type of the array.
-        SyntaxTree::Array(ty) => Ok(Type::Array(Box::new(parse_type(ty, struct_map)?))),
+        SyntaxTree::Array(ty) => {
+            let element = parse_type(ty, struct_map)?;
+            reject_fixed_array(&element, ty, "a list element")?;
+            Ok(Type::Array(Box::new(element)))
+        }
+
+        // Type is a fixed-size array. Its elements are stored inline, so they must have a fixed
+        // size.
+        SyntaxTree::FixedArray(element_ty, len) => {
+            let element = parse_type(element_ty, struct_map)?;
+            match element {
+                Type::Primitive(_) | Type::Enum(_, _) => {
+                    Ok(Type::FixedArray(Box::new(element), *len))
+                }
+                _ => Err(Box::new(CompilerError::new(
+                    element_ty.token.clone(),
+                    "Fixed-size arrays can only hold primitives and enums".to_string(),
+                ))),
+            }
+        }
+
+        // Type is a map. Keys are compared when looking up entries, so they are limited to
+        // primitives, enums, and strings.
+        SyntaxTree::Map(key_ty, value_ty) => {
+            let key = parse_type(key_ty, struct_map)?;
+            if !matches!(key, Type::Primitive(_) | Type::Enum(_, _) | Type::String) {
+                return Err(Box::new(CompilerError::new(
+                    key_ty.token.clone(),
+                    "Map keys can only be primitives, enums, and strings".to_string(),
+                )));
+            }
+            let value = parse_type(value_ty, struct_map)?;
+            reject_fixed_array(&value, value_ty, "a map value")?;
+            Ok(Type::Map(Box::new(key), Box::new(value)))
+        }
 
         // Type is a oneof. Parse all the types in the oneof.
         SyntaxTree::OneOf(fields) => {
+            // A oneof without fields could never hold a value.
+            if fields.is_empty() {
+                return Err(Box::new(CompilerError::new(
+                    ty.token.clone(),
+                    "A oneof must have at least one field".to_string(),
+                )));
+            }
+            // The index of the active field must fit in the 8-bit tag.
+            if let Some(extra) = fields.get(MAX_ONEOF_FIELDS) {
+                return Err(Box::new(CompilerError::new(
+                    extra.token.clone(),
+                    format!(
+                        "A oneof can have at most {} fields, since the index of its active field \
+                         is stored in a single byte",
+                        MAX_ONEOF_FIELDS
+                    ),
+                )));
+            }
+
             let mut res = Vec::with_capacity(fields.len());
             let mut field_names = Vec::<String>::with_capacity(fields.len());
 
@@ -212,11 +604,13 @@ fn parse_type<'a>(
                     field_names.push(field_name.clone());
 
                     // Parse the field type.
-                    let field_type = parse_type(field_type, struct_map)?;
+                    let parsed_type = parse_type(field_type, struct_map)?;
+                    reject_fixed_array(&parsed_type, field_type, "a oneof field")?;
                     res.push(Field {
                         name: field_name.clone(),
-                        ty: field_type,
+                        ty: parsed_type,
                         index: i,
+                        doc: field.doc.clone(),
                     });
                 } else {
                     unreachable!("Field is not a field")
@@ -230,19 +624,88 @@ fn parse_type<'a>(
     }
 }
 
-/// Parse an enum.
+/// Returns an error if a type is a fixed-size array. Fixed-size arrays are only allowed as sequence
+/// fields, and `context` describes where the type was used instead.
+fn reject_fixed_array<'a>(
+    ty: &Type,
+    tree: &TaggedSyntaxTree<'a>,
+    context: &str,
+) -> Result<(), Box<CompilerError<'a>>> {
+    if let Type::FixedArray(_, _) = ty {
+        Err(Box::new(CompilerError::new(
+            tree.token.clone(),
+            format!("A fixed-size array cannot be used as {}", context),
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parse an integer literal, which may be negative. Literals prefixed with `0x` are parsed as
+/// hexadecimal, and literals prefixed with `0b` are parsed as binary. Like in Rust, `_` may be used
+/// to separate digits. Literals are parsed as `i128`, so that both `i64` and `u64` values can be
+/// represented.
+pub(crate) fn parse_integer(literal: &str) -> Result<i128, std::num::ParseIntError> {
+    let (sign, magnitude) = match literal.strip_prefix('-') {
+        Some(magnitude) => ("-", magnitude),
+        None => ("", literal),
+    };
+    let (digits, radix) = match magnitude.get(..2) {
+        Some("0x" | "0X") => (&magnitude[2..], 16),
+        Some("0b" | "0B") => (&magnitude[2..], 2),
+        _ => (magnitude, 10),
+    };
+    i128::from_str_radix(&format!("{}{}", sign, digits.replace('_', "")), radix)
+}
+
+/// Parse an enum. If a base type is given, every value must fit in it, and it sets the size of the
+/// enum. Otherwise, the size is inferred from the values.
 fn parse_enum<'a>(
     name: String,
+    doc: Option<String>,
+    base: Option<&TaggedSyntaxTree<'a>>,
     entries: &Vec<TaggedSyntaxTree<'a>>,
 ) -> Result<Enum, Box<CompilerError<'a>>> {
     let mut variants = Vec::<EnumVariant>::new();
 
+    // Only integers can back an enum.
+    let base = match base {
+        Some(base) => {
+            let SyntaxTree::Type(base_name) = &base.data else {
+                unreachable!("Enum base type is not a type")
+            };
+            let primitive = PRIMITIVES
+                .iter()
+                .find(|(n, _)| n == base_name)
+                .map(|(_, p)| p.clone())
+                .filter(|p| !matches!(p, Primitive::Bool | Primitive::F32 | Primitive::F64));
+            match primitive {
+                Some(primitive) => Some(primitive),
+                None => {
+                    return Err(Box::new(CompilerError::new(
+                        base.token.clone(),
+                        format!(
+                            "Base type \"{}\" for enum \"{}\" must be an integer primitive",
+                            base_name.cyan().bold(),
+                            name.cyan().bold()
+                        ),
+                    )))
+                }
+            }
+        }
+        None => None,
+    };
+
     // Parse all the entries.
-    let mut enum_size = 1;
     for entry in entries {
         if let SyntaxTree::EnumEntry(entry_name, entry_value) = &entry.data {
-            // Check if the entry value is a valid integer.
-            let parsed_value = match entry_value.parse::<u64>() {
+            // Check if the entry value is a valid integer. Boolean literals stand for 0 and 1.
+            let parsed = match entry_value.as_str() {
+                "false" => Ok(0),
+                "true" => Ok(1),
+                literal => parse_integer(literal),
+            };
+            let parsed_value = match parsed {
                 Ok(value) => value,
                 Err(e) => {
                     let full_name = format!("{}:{}", name, entry_name);
@@ -258,6 +721,37 @@ fn parse_enum<'a>(
                 }
             };
 
+            // Every enum is backed by an integer of at most 64 bits.
+            if parsed_value < i64::MIN as i128 || parsed_value > u64::MAX as i128 {
+                let full_name = format!("{}:{}", name, entry_name);
+                return Err(Box::new(CompilerError::new(
+                    entry.token.clone(),
+                    format!(
+                        "Value \"{}\" for enum entry \"{}\" is out of range. Enum values must fit \
+                         in an \"{}\" or a \"{}\"",
+                        entry_value.cyan().bold(),
+                        full_name.cyan().bold(),
+                        "i64".cyan().bold(),
+                        "u64".cyan().bold()
+                    ),
+                )));
+            }
+
+            if let Some(base) = &base {
+                if !base.fits(parsed_value) {
+                    let full_name = format!("{}:{}", name, entry_name);
+                    return Err(Box::new(CompilerError::new(
+                        entry.token.clone(),
+                        format!(
+                            "Value \"{}\" for enum entry \"{}\" does not fit in its base type \"{}\"",
+                            entry_value.cyan().bold(),
+                            full_name.cyan().bold(),
+                            base.to_string().cyan().bold()
+                        ),
+                    )));
+                }
+            }
+
             // Make sure entry does not have a duplicate name or value.
             for variant in variants.iter() {
                 if variant.name == *entry_name {
@@ -284,71 +778,145 @@ fn parse_enum<'a>(
                 }
             }
 
-            // Check if we must increase the size of the enum to accommodate this new value. We do
-            // not have to handle overflows here, since that is checked when we first call
-            // `entry_value.parse::<u64>`. If the provided value is larger than 64 bits, it would
-            // not have been parsed.
-            for (size, max_val) in [
-                (1, u8::MAX.into()),
-                (2, u16::MAX.into()),
-                (4, u32::MAX.into()),
-                (8, u64::MAX),
-            ] {
-                if enum_size > size {
-                    continue;
-                }
-                if parsed_value <= max_val {
-                    enum_size = size;
-                    break;
-                }
-            }
-
             // Add the entry to the enum.
             variants.push(EnumVariant {
                 name: entry_name.clone(),
                 value: parsed_value,
+                doc: entry.doc.clone(),
             });
         } else {
             unreachable!("Entry is not an entry")
         }
     }
 
+    // Unless a base type is declared, find the smallest integer that can hold every value. If any
+    // value is negative, the enum is backed by a signed integer. Every value fits in an `i64` or a
+    // `u64`, but a negative value and a value above `i64::MAX` do not fit in the same integer.
+    let enum_size = match &base {
+        Some(base) => base.size() as u8,
+        None => {
+            let signed = variants.iter().any(|v| v.value < 0);
+            match base_candidates(signed)
+                .iter()
+                .find(|p| variants.iter().all(|v| p.fits(v.value)))
+            {
+                Some(p) => p.size() as u8,
+                None => {
+                    // Point at the first value that is too large for a signed enum.
+                    let index = variants
+                        .iter()
+                        .position(|v| v.value > i64::MAX as i128)
+                        .unwrap_or_default();
+                    let full_name = format!("{}:{}", name, variants[index].name);
+                    return Err(Box::new(CompilerError::new(
+                        entries[index].token.clone(),
+                        format!(
+                            "Value of enum entry \"{}\" is out of range. Enum \"{}\" has negative \
+                             values, so its values must fit in an \"{}\"",
+                            full_name.cyan().bold(),
+                            name.cyan().bold(),
+                            "i64".cyan().bold()
+                        ),
+                    )));
+                }
+            }
+        }
+    };
+
     Ok(Enum {
         name,
         size: enum_size,
+        base,
         variants,
+        doc,
     })
 }
 
-/// Finds any enum fields that match the given name and injects the given size into them.
-fn inject_enum_size_into(enum_name: &str, enum_size: usize, fields: &mut [Field]) {
-    /// Injects the enum into a single Type instance. Returns the size that the field increased by
-    /// so that it can be added to `adjust_by`.
-    fn process_type(enum_name: &str, enum_size: usize, ty: &mut Type) -> usize {
-        match ty {
-            Type::Enum(found_name, found_size) => {
-                if found_name == enum_name {
-                    *found_size = enum_size;
-                    enum_size
-                } else {
-                    0
-                }
-            }
-            Type::Array(b) => {
-                process_type(enum_name, enum_size, b.as_mut());
-                0
-            }
-            Type::OneOf(subfields) => {
-                inject_enum_size_into(enum_name, enum_size, subfields);
-                0
-            }
-            _ => 0,
-        }
+/// The integer primitives that an enum without a declared base type may be backed by, from smallest
+/// to largest.
+fn base_candidates(signed: bool) -> [Primitive; 4] {
+    if signed {
+        [
+            Primitive::I8,
+            Primitive::I16,
+            Primitive::I32,
+            Primitive::I64,
+        ]
+    } else {
+        [
+            Primitive::U8,
+            Primitive::U16,
+            Primitive::U32,
+            Primitive::U64,
+        ]
+    }
+}
+
+/// Returns the size of the smallest integer backing an enum that can hold the given value.
+fn smallest_size(enm: &Enum, value: i128) -> usize {
+    base_candidates(enm.is_signed())
+        .iter()
+        .find(|p| p.fits(value))
+        .map_or(8, |p| p.size())
+}
+
+/// Finds the first variant of an enum without a declared base type that made the enum wider than
+/// its first variant requires.
+///
+/// # Returns
+///
+/// The index of the variant and the base type that was inferred for the enum, or `None` if the
+/// enum's base type is declared or no variant widened it.
+fn widening_variant(enm: &Enum) -> Option<(usize, Primitive)> {
+    if enm.base.is_some() {
+        return None;
     }
+    let first_size = smallest_size(enm, enm.variants.first()?.value);
+    let index = enm
+        .variants
+        .iter()
+        .position(|v| smallest_size(enm, v.value) > first_size)?;
+    let inferred = base_candidates(enm.is_signed())
+        .into_iter()
+        .find(|p| p.size() == enm.size as usize)?;
+    Some((index, inferred))
+}
 
+/// Finds any enum fields that match the given name and injects the given size into them. Sequence
+/// field offsets are shifted to make room for the enum. Oneof field indices are left alone, since
+/// they are tags rather than offsets.
+fn inject_enum_size_into(enum_name: &str, enum_size: usize, fields: &mut [Field]) {
     let mut adjust_by = 0;
     for field in fields {
         field.index += adjust_by;
-        adjust_by += process_type(enum_name, enum_size, &mut field.ty);
+        adjust_by += inject_enum_size_into_type(enum_name, enum_size, &mut field.ty);
+    }
+}
+
+/// Injects the enum size into a single Type instance. Returns the size that the type increased by
+/// so that following fields can be shifted.
+fn inject_enum_size_into_type(enum_name: &str, enum_size: usize, ty: &mut Type) -> usize {
+    match ty {
+        Type::Enum(found_name, found_size) if found_name == enum_name => {
+            *found_size = enum_size;
+            enum_size
+        }
+        Type::Array(b) => {
+            inject_enum_size_into_type(enum_name, enum_size, b.as_mut());
+            0
+        }
+        Type::FixedArray(b, len) => inject_enum_size_into_type(enum_name, enum_size, b) * *len,
+        Type::Map(key, value) => {
+            inject_enum_size_into_type(enum_name, enum_size, key.as_mut());
+            inject_enum_size_into_type(enum_name, enum_size, value.as_mut());
+            0
+        }
+        Type::OneOf(subfields) => {
+            for f in subfields {
+                inject_enum_size_into_type(enum_name, enum_size, &mut f.ty);
+            }
+            0
+        }
+        _ => 0,
     }
 }
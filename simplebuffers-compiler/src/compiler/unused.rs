@@ -0,0 +1,79 @@
+//! Finds sequences and enums that are never used.
+
+use super::CompilerWarning;
+use crate::ast::{SyntaxTree, TaggedSyntaxTree, TreeTraversal};
+use colored::Colorize;
+use std::collections::{HashMap, HashSet};
+
+/// Find the sequences and enums in a file that no field refers to, directly or through an alias.
+///
+/// Schemas have no explicit root, so a sequence counts as used as soon as another sequence refers
+/// to it. The messages that are sent on their own are therefore reported too. Types that are only
+/// used by an unused alias are reported, and imported types are never reported.
+///
+/// # Arguments
+///
+/// * `root` - The root of the SyntaxTree. It must already have been compiled with [super::parse_ast],
+///   so that every alias is known to resolve.
+///
+/// # Returns
+///
+/// A warning for every unused sequence or enum, pointing at its definition.
+pub fn find_unused<'a>(root: &'a TaggedSyntaxTree<'a>) -> Vec<CompilerWarning<'a>> {
+    let file_contents = match &root.data {
+        SyntaxTree::File(file) => file,
+        _ => unreachable!("Root node is not a file"),
+    };
+
+    let aliases: HashMap<&str, &TaggedSyntaxTree> = file_contents
+        .iter()
+        .filter_map(|node| match &node.data {
+            SyntaxTree::Alias(name, target) => Some((name.as_str(), target.as_ref())),
+            _ => None,
+        })
+        .collect();
+
+    // Every type named by a field, with aliases expanded.
+    let mut used = HashSet::new();
+    for node in file_contents {
+        if let SyntaxTree::Sequence(_, _) = &node.data {
+            mark_used(node, &aliases, &mut used);
+        }
+    }
+
+    file_contents
+        .iter()
+        .filter_map(|node| {
+            let (kind, name) = match &node.data {
+                SyntaxTree::Sequence(name, _) => ("Sequence", name),
+                SyntaxTree::Enum(name, _, _) => ("Enum", name),
+                _ => return None,
+            };
+            if used.contains(name.as_str()) {
+                return None;
+            }
+            Some(CompilerWarning::new(
+                node.token.clone(),
+                format!("{} \"{}\" is never used", kind, name.cyan().bold()),
+            ))
+        })
+        .collect()
+}
+
+/// Add every type named in a tree to `used`. Aliases are added along with every type they name.
+fn mark_used<'a>(
+    tree: &'a TaggedSyntaxTree<'a>,
+    aliases: &HashMap<&str, &'a TaggedSyntaxTree<'a>>,
+    used: &mut HashSet<&'a str>,
+) {
+    for node in tree.iter_depth_first() {
+        if let SyntaxTree::Type(name) = &node.data {
+            // Aliases cannot refer to themselves, so this terminates.
+            if used.insert(name.as_str()) {
+                if let Some(target) = aliases.get(name.as_str()) {
+                    mark_used(target, aliases, used);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt;
+
+use super::CompilerError;
+use crate::diagnostic::{render, Diagnose, Severity};
+use crate::tokenizer::{Token, TokenLocation};
+
+/// A non-fatal problem found while compiling a schema.
+#[derive(Debug)]
+pub struct CompilerWarning<'a> {
+    /// The token that best represents the warning location.
+    token: Option<Token<'a>>,
+
+    /// The warning message.
+    message: String,
+}
+
+impl<'a> CompilerWarning<'a> {
+    /// Generate a new `CompilerWarning` with the given token and message.
+    pub fn new(token: Option<Token<'a>>, message: String) -> CompilerWarning<'a> {
+        Self { token, message }
+    }
+
+    /// Turns the warning into an error with the same location and message, for when warnings are
+    /// treated as errors.
+    pub fn into_error(self) -> CompilerError<'a> {
+        CompilerError::new(self.token, self.message)
+    }
+}
+
+impl<'a> Diagnose for CompilerWarning<'a> {
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn location(&self) -> Option<&TokenLocation<'_>> {
+        self.token.as_ref().map(|token| &token.location)
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+impl<'a> fmt::Display for CompilerWarning<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render(self, f)
+    }
+}
@@ -0,0 +1,166 @@
+//! Structured errors and warnings.
+//!
+//! Every error and warning produced while compiling a schema implements [Diagnose]. Its `Display`
+//! implementation renders the colored, human-readable form, while [Diagnose::diagnostic] converts
+//! it into a [Diagnostic], which owns its data and can be serialized as JSON for editors and other
+//! tools.
+
+use colored::Colorize;
+use serde_json::json;
+use std::fmt;
+
+use crate::tokenizer::TokenLocation;
+
+/// How serious a diagnostic is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The schema could not be compiled.
+    Error,
+
+    /// The schema was compiled, but something in it is likely a mistake.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// An error or warning that can be reported to the user.
+pub trait Diagnose: fmt::Display {
+    /// Returns how serious the problem is.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Returns the location of the problem in the schema, if it has one.
+    fn location(&self) -> Option<&TokenLocation<'_>>;
+
+    /// Returns the file that contains the problem, if it is known. By default, this is the file of
+    /// the problem's location.
+    fn file(&self) -> Option<&str> {
+        self.location().map(|l| l.file)
+    }
+
+    /// Returns a one-line description of the problem, without the severity or the location. The
+    /// message may contain color codes.
+    fn message(&self) -> String;
+
+    /// Converts the problem into a [Diagnostic].
+    fn diagnostic(&self) -> Diagnostic {
+        let location = self.location();
+        Diagnostic {
+            severity: self.severity(),
+            file: self.file().map(str::to_string),
+            line: location.map(|l| l.line_num + 1),
+            column: location.map(|l| l.col_num + 1),
+            width: location.map(|l| l.width),
+            message: strip_colors(&self.message()),
+            rendered: self.to_string(),
+        }
+    }
+}
+
+/// Renders a problem in the human-readable format shared by every [Diagnose] implementation. This
+/// is meant to be used to implement `Display`.
+pub fn render(problem: &dyn Diagnose, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let severity = match problem.severity() {
+        Severity::Error => "ERROR:".red().bold(),
+        Severity::Warning => "WARNING:".yellow().bold(),
+    };
+    write!(f, "{} {}", severity, problem.message())?;
+    if let Some(location) = problem.location() {
+        write!(f, "\n{}", location)?;
+    }
+    Ok(())
+}
+
+/// An error or warning, detached from the schema source.
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    /// How serious the problem is.
+    pub severity: Severity,
+
+    /// The file that contains the problem, if the problem is in a file.
+    pub file: Option<String>,
+
+    /// The line of the problem (1-indexed), if it has a location.
+    pub line: Option<usize>,
+
+    /// The column of the problem (1-indexed), if it has a location.
+    pub column: Option<usize>,
+
+    /// The width of the problem in characters, if it has a location.
+    pub width: Option<usize>,
+
+    /// A one-line description of the problem, without color codes.
+    pub message: String,
+
+    /// The human-readable form of the problem, as shown by `Display`.
+    rendered: String,
+}
+
+impl Diagnostic {
+    /// Creates an error that does not have a location.
+    pub fn error(message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            file: None,
+            line: None,
+            column: None,
+            width: None,
+            rendered: format!("{} {}", "ERROR:".red().bold(), message),
+            message: strip_colors(&message),
+        }
+    }
+
+    /// Sets the file that contains the problem.
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    /// Serializes the diagnostic as a single-line JSON object with `file`, `line`, `column`,
+    /// `width`, `severity`, and `message` keys. Location keys are `null` if there is no location,
+    /// and `file` is `null` if the problem is not in a file.
+    pub fn to_json(&self) -> String {
+        json!({
+            "file": self.file,
+            "line": self.line,
+            "column": self.column,
+            "width": self.width,
+            "severity": self.severity.to_string(),
+            "message": self.message,
+        })
+        .to_string()
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rendered)
+    }
+}
+
+/// Removes ANSI color codes from a string.
+fn strip_colors(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip the escape sequence, which ends with `m`.
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
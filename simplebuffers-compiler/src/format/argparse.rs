@@ -0,0 +1,46 @@
+//! Parses generator-specific arguments contained in [GeneratorParams::additional_args].
+
+use clap::{Command, CommandFactory, Parser};
+use simplebuffers_codegen::GeneratorParams;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Struct to parse CLI args into.
+#[derive(Parser, Debug)]
+#[command(name = "SimpleBuffers Formatter")]
+#[command(version = VERSION)]
+#[command(about = "Format a SimpleBuffers schema in the canonical style.")]
+struct Cli {
+    /// Write the formatted schema to `<dstdir>/<file name>.sb` instead of printing it.
+    #[arg(long)]
+    write: bool,
+
+    /// Sort enum variants by value instead of keeping their original order.
+    #[arg(long)]
+    sort_enums: bool,
+}
+
+/// A struct that holds generator-specific arguments for the formatter.
+#[derive(Debug)]
+pub(crate) struct FormatGeneratorParams {
+    /// Whether to write the formatted schema to a file instead of printing it.
+    pub write: bool,
+
+    /// Whether to sort enum variants by value.
+    pub sort_enums: bool,
+}
+
+/// Returns the command that describes the generator-specific arguments, so the compiler can
+/// show them in its help output and validate them before generating.
+pub(crate) fn command() -> Command {
+    Cli::command()
+}
+
+/// Parse generator-specific arguments from the compiler-provided argument list.
+pub(crate) fn parse_args(generator_params: &GeneratorParams) -> FormatGeneratorParams {
+    let cli = Cli::parse_from(&generator_params.additional_args);
+    FormatGeneratorParams {
+        write: cli.write,
+        sort_enums: cli.sort_enums,
+    }
+}
@@ -0,0 +1,601 @@
+//! Lays out the tokens of a schema as lines, and renders the lines in the canonical style.
+//!
+//! The layout works on the tokens themselves, including comments and directives, so nothing but
+//! whitespace changes. The only token that is ever added is the optional `;` after the last item
+//! of a block.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::compiler::parse_integer;
+use crate::tokenizer::{Token, TokenType};
+
+/// The indentation of one block.
+const INDENT: &str = "    ";
+
+/// What a line holds, which decides how blank lines are placed around it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LineKind {
+    /// A statement, or part of one.
+    Code,
+
+    /// A line that ends by opening a block with `{`.
+    Open,
+
+    /// A line that starts by closing a block with `}`.
+    Close,
+
+    /// A comment or doc comment on its own line.
+    Comment,
+
+    /// A preprocessor directive.
+    Directive,
+
+    /// An empty line.
+    Blank,
+}
+
+/// A line of the formatted schema.
+#[derive(Debug)]
+pub(crate) struct Line {
+    kind: LineKind,
+
+    /// How many levels the line is indented.
+    depth: usize,
+
+    /// The block that the line is in, or `None` at the top level. Lines are aligned with the other
+    /// lines of their block.
+    block: Option<usize>,
+
+    /// The block that the line opens, if it is an [LineKind::Open] line.
+    opens: Option<usize>,
+
+    /// Whether the line starts a sequence or enum, which are separated from the items around them
+    /// by blank lines.
+    declaration: bool,
+
+    /// The text of the line, without indentation or a trailing comment.
+    code: String,
+
+    /// Where the head of the line (`name:` of a field, or `name` of an enum entry) ends in
+    /// [Line::code]. The heads of a block are padded to the same width.
+    split: Option<usize>,
+
+    /// A comment that follows the code on the same line, without its leading `//`.
+    comment: Option<String>,
+}
+
+impl Line {
+    /// Returns an empty line.
+    fn blank() -> Self {
+        Self {
+            kind: LineKind::Blank,
+            depth: 0,
+            block: None,
+            opens: None,
+            declaration: false,
+            code: String::new(),
+            split: None,
+            comment: None,
+        }
+    }
+
+    /// Returns the head of an aligned line and the rest of it.
+    fn parts(&self) -> Option<(&str, &str)> {
+        self.split.map(|split| {
+            (
+                self.code[..split].trim_end(),
+                self.code[split..].trim_start(),
+            )
+        })
+    }
+}
+
+/// A block that has been opened but not closed yet.
+struct Block {
+    id: usize,
+
+    /// The statement that opened the block, which continues after it is closed.
+    statement: Statement,
+
+    /// The bracket depth of the statement that opened the block.
+    bracket_depth: usize,
+}
+
+/// A statement that has been started but not ended yet.
+#[derive(Clone, Copy)]
+struct Statement {
+    /// The line where the statement starts.
+    first_line: usize,
+
+    /// The last line that holds part of the statement.
+    last_line: usize,
+
+    /// Whether the statement declares a sequence or enum, which ends with its block.
+    declaration: bool,
+
+    /// Whether the statement declares an enum.
+    is_enum: bool,
+}
+
+/// An `#if` whose `#endif` has not been reached yet.
+#[derive(Clone, Copy)]
+struct Conditional {
+    /// The block depth at the `#if`, which every branch must end at.
+    depth: usize,
+
+    /// Whether a statement was in progress at the `#if`.
+    in_statement: bool,
+}
+
+/// Splits a stream of tokens into lines.
+#[derive(Default)]
+pub(crate) struct Layout {
+    lines: Vec<Line>,
+
+    /// Whether the last line is complete, so that the next token starts a new line.
+    line_done: bool,
+
+    /// The token before the next one on the last line, used to decide whether to add a space.
+    prev: Option<TokenType>,
+
+    /// The source line of the previous token.
+    prev_line_num: Option<usize>,
+
+    /// Whether the next token is the flag of an `#if`.
+    directive_arg: bool,
+
+    blocks: Vec<Block>,
+    next_block: usize,
+    enum_blocks: HashSet<usize>,
+    statement: Option<Statement>,
+
+    /// How many `[` and `<` are open in the current statement.
+    bracket_depth: usize,
+
+    conditionals: Vec<Conditional>,
+}
+
+impl Layout {
+    /// Lays out a stream of tokens.
+    ///
+    /// # Errors
+    ///
+    /// A message if the branches of an `#if` open or close blocks differently, so that the blocks
+    /// cannot be indented.
+    pub(crate) fn new(tokens: &[Token]) -> Result<Self, String> {
+        let mut layout = Self::default();
+        let mut tokens = tokens.iter().peekable();
+        while let Some(token) = tokens.next() {
+            let line_num = token.location.line_num;
+            match &token.token_type {
+                TokenType::Comment(text) => {
+                    let trailing = layout.prev_line_num == Some(line_num);
+                    match layout.lines.last_mut() {
+                        Some(line) if trailing => line.comment = Some(text.trim_end().to_string()),
+                        _ => {
+                            layout.start_line(LineKind::Comment, line_num);
+                            layout.push_text(&format!("//{}", text.trim_end()));
+                        }
+                    }
+                    layout.line_done = true;
+                }
+                TokenType::DocComment(text) => {
+                    layout.start_line(LineKind::Comment, line_num);
+                    layout.push_text(&format!("///{}", text.trim_end()));
+                    layout.line_done = true;
+                }
+                TokenType::Directive(directive) => {
+                    layout.directive(directive, line_num)?;
+                }
+                TokenType::OpenBrace => {
+                    if tokens
+                        .next_if(|next| next.token_type == TokenType::CloseBrace)
+                        .is_some()
+                    {
+                        layout.push_token(&token.token_type, line_num);
+                        layout.push_text("}");
+                        layout.prev = Some(TokenType::CloseBrace);
+                        layout.after_block(layout.statement);
+                    } else {
+                        layout.push_token(&token.token_type, line_num);
+                        layout.open_block();
+                    }
+                }
+                TokenType::CloseBrace => layout.close_block(line_num)?,
+                TokenType::Semicolon if layout.bracket_depth == 0 => {
+                    match layout.statement.take() {
+                        Some(statement) => layout.lines[statement.last_line].code.push(';'),
+                        None => layout.push_token(&token.token_type, line_num),
+                    }
+                    layout.line_done = true;
+                }
+                token_type => {
+                    if layout.directive_arg {
+                        layout.directive_arg = false;
+                        layout.push_text(&format!(" {}", token_type));
+                        layout.line_done = true;
+                    } else {
+                        layout.push_token(token_type, line_num);
+                    }
+                }
+            }
+            layout.prev_line_num = Some(line_num);
+        }
+        Ok(layout)
+    }
+
+    /// Returns the lines, with the entries of every enum sorted by value if `sort_enums` is set.
+    pub(crate) fn into_lines(self, sort_enums: bool) -> Vec<Line> {
+        if sort_enums {
+            sort_enum_entries(self.lines, &self.enum_blocks)
+        } else {
+            self.lines
+        }
+    }
+
+    /// Starts a new line, after a blank line if there is one in the source. Lines that continue a
+    /// statement are indented one level deeper.
+    fn start_line(&mut self, kind: LineKind, line_num: usize) {
+        if let Some(prev_line_num) = self.prev_line_num {
+            if line_num > prev_line_num + 1 {
+                self.push_line(LineKind::Blank, 0);
+            }
+        }
+        let continues = kind != LineKind::Close && self.statement.is_some();
+        self.push_line(kind, self.blocks.len() + usize::from(continues));
+    }
+
+    fn push_line(&mut self, kind: LineKind, depth: usize) {
+        self.lines.push(Line {
+            kind,
+            depth,
+            block: self.blocks.last().map(|block| block.id),
+            ..Line::blank()
+        });
+        self.line_done = false;
+        self.prev = None;
+    }
+
+    /// Appends text to the last line as it is.
+    fn push_text(&mut self, text: &str) {
+        self.lines
+            .last_mut()
+            .expect("text is pushed after a line is started")
+            .code
+            .push_str(text);
+    }
+
+    /// Appends a token to the current statement, starting a new line or statement if needed.
+    fn push_token(&mut self, token_type: &TokenType, line_num: usize) {
+        if self.line_done || self.lines.is_empty() {
+            self.start_line(LineKind::Code, line_num);
+        }
+        let index = self.lines.len() - 1;
+        let statement = self.statement.get_or_insert(Statement {
+            first_line: index,
+            last_line: index,
+            declaration: matches!(token_type, TokenType::Sequence | TokenType::Enum),
+            is_enum: *token_type == TokenType::Enum,
+        });
+        statement.last_line = index;
+        let statement = *statement;
+
+        let line = &mut self.lines[index];
+        if statement.first_line == index && line.code.is_empty() {
+            line.declaration = statement.declaration;
+        }
+        if self
+            .prev
+            .as_ref()
+            .is_some_and(|prev| spaced(prev, token_type, statement.is_enum))
+        {
+            line.code.push(' ');
+        }
+
+        // The heads of fields and enum entries are aligned, but not those of top-level statements
+        // such as aliases.
+        let aligned = !self.blocks.is_empty()
+            && self.bracket_depth == 0
+            && statement.first_line == index
+            && line.split.is_none();
+        match token_type {
+            TokenType::Equals if aligned => line.split = Some(line.code.len()),
+            TokenType::Colon if aligned => line.split = Some(line.code.len() + 1),
+            TokenType::OpenBracket | TokenType::OpenAngle => self.bracket_depth += 1,
+            TokenType::CloseBracket | TokenType::CloseAngle => {
+                self.bracket_depth = self.bracket_depth.saturating_sub(1)
+            }
+            _ => {}
+        }
+        line.code.push_str(&token_type.to_string());
+        self.prev = Some(token_type.clone());
+    }
+
+    /// Opens a block at the end of the last line.
+    fn open_block(&mut self) {
+        let id = self.next_block;
+        self.next_block += 1;
+        let statement = self
+            .statement
+            .take()
+            .expect("a block is opened by a statement");
+        if statement.is_enum {
+            self.enum_blocks.insert(id);
+        }
+
+        let line = self.lines.last_mut().expect("a block is opened on a line");
+        line.kind = LineKind::Open;
+        line.opens = Some(id);
+        self.blocks.push(Block {
+            id,
+            statement,
+            bracket_depth: std::mem::take(&mut self.bracket_depth),
+        });
+        self.line_done = true;
+    }
+
+    /// Closes the innermost block on a new line, ending its last item with `;` if needed.
+    fn close_block(&mut self, line_num: usize) -> Result<(), String> {
+        if let Some(statement) = self.statement.take() {
+            self.lines[statement.last_line].code.push(';');
+        }
+        let block = self.blocks.pop().ok_or_else(|| {
+            format!(
+                "Cannot format a `}}` on line {} that closes a block opened in another `#if` \
+                 branch",
+                line_num + 1
+            )
+        })?;
+        self.bracket_depth = block.bracket_depth;
+        self.start_line(LineKind::Close, line_num);
+        self.push_text("}");
+        self.prev = Some(TokenType::CloseBrace);
+        self.after_block(Some(block.statement));
+        Ok(())
+    }
+
+    /// Continues the statement that a block was opened by, after the block is closed. Sequences
+    /// and enums end with their block, but oneofs are followed by `;` or `]`.
+    fn after_block(&mut self, statement: Option<Statement>) {
+        let index = self.lines.len() - 1;
+        self.statement = statement
+            .filter(|statement| !statement.declaration)
+            .map(|statement| Statement {
+                last_line: index,
+                ..statement
+            });
+        self.line_done = self.statement.is_none();
+    }
+
+    /// Puts a directive on its own line. The flag of an `#if` follows it on the same line.
+    fn directive(&mut self, directive: &str, line_num: usize) -> Result<(), String> {
+        match directive {
+            "#if" => self.conditionals.push(Conditional {
+                depth: self.blocks.len(),
+                in_statement: self.statement.is_some(),
+            }),
+            _ => {
+                let conditional = match directive {
+                    "#endif" => self.conditionals.pop(),
+                    _ => self.conditionals.last().copied(),
+                };
+                if let Some(conditional) = conditional {
+                    if conditional.depth != self.blocks.len() {
+                        return Err(format!(
+                            "Cannot format the `#if` branch that ends on line {}, because it \
+                             opens or closes a block without closing or opening it again",
+                            line_num + 1
+                        ));
+                    }
+                    // End a statement that the branch started, so the next branch starts afresh.
+                    if !conditional.in_statement && self.bracket_depth == 0 {
+                        if let Some(statement) = self.statement.take() {
+                            if !statement.declaration {
+                                self.lines[statement.last_line].code.push(';');
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        self.start_line(LineKind::Directive, line_num);
+        self.push_text(directive);
+        self.directive_arg = directive == "#if";
+        self.line_done = !self.directive_arg;
+        Ok(())
+    }
+}
+
+/// Returns whether a space goes between two tokens on the same line.
+fn spaced(prev: &TokenType, next: &TokenType, enum_header: bool) -> bool {
+    match (prev, next) {
+        (TokenType::OpenBracket | TokenType::OpenAngle | TokenType::Dot, _) => false,
+        (
+            _,
+            TokenType::Semicolon
+            | TokenType::Comma
+            | TokenType::CloseBracket
+            | TokenType::OpenAngle
+            | TokenType::CloseAngle
+            | TokenType::Dot,
+        ) => false,
+        // The base type of an enum is set apart, unlike the type of a field.
+        (_, TokenType::Colon) => enum_header,
+        _ => true,
+    }
+}
+
+/// Sorts the entries of every enum by value. Comments before an entry move with it, and entries
+/// are not moved past directives. Blank lines between the sorted entries are removed.
+fn sort_enum_entries(lines: Vec<Line>, enum_blocks: &HashSet<usize>) -> Vec<Line> {
+    let mut sorted = Vec::with_capacity(lines.len());
+    let mut lines = lines.into_iter().peekable();
+    while let Some(line) = lines.next() {
+        let block = line.opens.filter(|block| enum_blocks.contains(block));
+        sorted.push(line);
+        let Some(block) = block else {
+            continue;
+        };
+
+        let mut units: Vec<(i128, Vec<Line>)> = Vec::new();
+        let mut pending = Vec::new();
+        while let Some(line) = lines.next_if(|line| line.block == Some(block)) {
+            match line.kind {
+                LineKind::Blank => {}
+                LineKind::Directive => {
+                    flush_units(&mut sorted, &mut units, &mut pending);
+                    sorted.push(line);
+                }
+                LineKind::Code if line.split.is_some() => {
+                    let value = line
+                        .parts()
+                        .map_or(i128::MAX, |(_, tail)| entry_value(tail));
+                    pending.push(line);
+                    units.push((value, std::mem::take(&mut pending)));
+                }
+                // The rest of an entry that was split over several lines.
+                LineKind::Code if pending.is_empty() && !units.is_empty() => {
+                    units.last_mut().unwrap().1.push(line);
+                }
+                _ => pending.push(line),
+            }
+        }
+        flush_units(&mut sorted, &mut units, &mut pending);
+    }
+    sorted
+}
+
+/// Appends sorted entries and the lines that follow them.
+fn flush_units(sorted: &mut Vec<Line>, units: &mut Vec<(i128, Vec<Line>)>, rest: &mut Vec<Line>) {
+    units.sort_by_key(|(value, _)| *value);
+    sorted.extend(units.drain(..).flat_map(|(_, lines)| lines));
+    sorted.append(rest);
+}
+
+/// Returns the value of an enum entry from the part of its line after the name. Entries whose
+/// value cannot be read are sorted last.
+fn entry_value(tail: &str) -> i128 {
+    let value = tail.trim_start_matches('=').trim_end_matches(';').trim();
+    match value {
+        "true" => 1,
+        "false" => 0,
+        _ => parse_integer(value).unwrap_or(i128::MAX),
+    }
+}
+
+/// Renders lines as source. Blank lines are kept where the source had them, at most one at a time,
+/// except at the start and end of blocks and the file. Sequences and enums are always separated
+/// from the items around them by a blank line.
+pub(crate) fn render(lines: Vec<Line>) -> String {
+    let lines = separate_declarations(collapse_blank_lines(lines));
+
+    let mut widths = HashMap::<usize, usize>::new();
+    for line in &lines {
+        if let (Some(block), Some((head, _))) = (line.block, line.parts()) {
+            let width = widths.entry(block).or_default();
+            *width = (*width).max(head.chars().count());
+        }
+    }
+
+    let mut source = String::new();
+    for line in &lines {
+        if line.kind != LineKind::Blank {
+            source.push_str(&INDENT.repeat(line.depth));
+            match (line.block, line.parts()) {
+                (Some(block), Some((head, tail))) => source.push_str(&format!(
+                    "{:<width$} {}",
+                    head,
+                    tail,
+                    width = widths[&block]
+                )),
+                _ => source.push_str(&line.code),
+            }
+            if let Some(comment) = &line.comment {
+                source.push_str(&format!(" //{}", comment));
+            }
+        }
+        source.push('\n');
+    }
+    source
+}
+
+/// Removes repeated blank lines, and blank lines at the start and end of blocks and the file.
+fn collapse_blank_lines(lines: Vec<Line>) -> Vec<Line> {
+    let mut collapsed: Vec<Line> = Vec::with_capacity(lines.len());
+    for line in lines {
+        let prev = collapsed.last().map(|prev| prev.kind);
+        match line.kind {
+            LineKind::Blank if matches!(prev, None | Some(LineKind::Blank | LineKind::Open)) => {
+                continue
+            }
+            LineKind::Close if prev == Some(LineKind::Blank) => {
+                collapsed.pop();
+            }
+            _ => {}
+        }
+        collapsed.push(line);
+    }
+    if collapsed
+        .last()
+        .is_some_and(|line| line.kind == LineKind::Blank)
+    {
+        collapsed.pop();
+    }
+    collapsed
+}
+
+/// Adds a blank line between top-level items where one of them is a sequence or enum. Comments
+/// directly before an item belong to it. No blank line is added right after `#if` or `#else`.
+fn separate_declarations(lines: Vec<Line>) -> Vec<Line> {
+    let mut blank_before = vec![false; lines.len()];
+    // Whether the previous top-level item declares a sequence or enum, or `None` if there is no
+    // item to separate the next one from.
+    let mut prev_item: Option<bool> = None;
+    let mut in_block = false;
+    for (index, line) in lines.iter().enumerate() {
+        if in_block {
+            if line.kind == LineKind::Close && line.depth == 0 {
+                in_block = false;
+                prev_item = Some(true);
+            }
+            continue;
+        }
+        if line.depth > 0 || line.kind == LineKind::Blank {
+            continue;
+        }
+
+        let prev = index.checked_sub(1).map(|prev| &lines[prev]);
+        let starts_item = prev.map_or(true, |prev| prev.kind != LineKind::Comment);
+        let declaration = lines[index..]
+            .iter()
+            .find(|line| line.kind != LineKind::Comment)
+            .is_some_and(|item| item.depth == 0 && item.declaration);
+        let separable = match line.kind {
+            LineKind::Directive => line.code.starts_with("#if"),
+            _ => true,
+        };
+        blank_before[index] = starts_item
+            && separable
+            && prev_item.is_some_and(|prev_item| prev_item || declaration)
+            && prev.is_some_and(|prev| prev.kind != LineKind::Blank);
+
+        match line.kind {
+            LineKind::Code => prev_item = Some(line.declaration),
+            LineKind::Open => in_block = true,
+            LineKind::Directive if line.code == "#endif" => {
+                prev_item = Some(prev_item.unwrap_or(false))
+            }
+            LineKind::Directive => prev_item = None,
+            _ => {}
+        }
+    }
+
+    let mut separated = Vec::with_capacity(lines.len());
+    for (line, blank) in lines.into_iter().zip(blank_before) {
+        if blank {
+            separated.push(Line::blank());
+        }
+        separated.push(line);
+    }
+    separated
+}
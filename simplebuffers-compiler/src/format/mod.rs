@@ -0,0 +1,119 @@
+//! Formats schemas in the canonical style.
+//!
+//! [format_source] rewrites the source of a schema token by token, so comments, imports, aliases,
+//! `#if` regions, and the spelling of numbers are kept, and only whitespace changes.
+//! [FormatCodeGenerator] makes it available as the `format` generator. By default, the result is
+//! printed, so that editors can format a schema on save. With `--write`, it is written to
+//! `{file_name}.sb` in the destination directory instead.
+
+mod argparse;
+mod layout;
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use argparse::{command, parse_args};
+use layout::{render, Layout};
+use simplebuffers_codegen::{CodeGenerator, FileSink, GeneratorParams};
+use simplebuffers_core::SBSchema;
+
+use crate::ast::AstBuilder;
+use crate::diagnostic::{Diagnose, Diagnostic};
+use crate::tokenizer::Tokenizer;
+
+/// Format the source of a schema in the canonical style.
+///
+/// The schema must parse, but it is not compiled, so imports do not need to be found. Regions
+/// excluded by `#if` are formatted too, but only checked by the tokenizer.
+///
+/// # Arguments
+///
+/// * `source` - The contents of the schema file.
+/// * `file_name` - The name of the schema file, used in error messages.
+/// * `sort_enum_variants` - Whether to sort the entries of every enum by value.
+///
+/// # Returns
+///
+/// The formatted source, or a list of errors.
+pub fn format_source(
+    source: &str,
+    file_name: &str,
+    sort_enum_variants: bool,
+) -> Result<String, Vec<Diagnostic>> {
+    AstBuilder::new(source, file_name, HashSet::new())
+        .map_err(|e| vec![e.diagnostic()])?
+        .parse()
+        .map_err(|e| vec![e.diagnostic()])?;
+
+    let tokens = Tokenizer::with_comments(source, file_name)
+        .and_then(|tokenizer| tokenizer.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| vec![e.diagnostic()])?;
+    let layout = Layout::new(&tokens)
+        .map_err(|message| vec![Diagnostic::error(message).in_file(file_name)])?;
+    Ok(render(layout.into_lines(sort_enum_variants)))
+}
+
+#[derive(Debug)]
+pub struct FormatCodeGenerator;
+
+impl CodeGenerator for FormatCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &SBSchema,
+        params: &GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        let args = parse_args(params);
+        let formatted = match &params.source {
+            Some(source) => {
+                let file_name = format!("{}.sb", params.file_name);
+                format_source(source, &file_name, args.sort_enums).map_err(|errors| {
+                    errors
+                        .iter()
+                        .map(|e| e.message.clone())
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                })?
+            }
+            // Descriptors have no comments or directives to lose, but writing one out as a schema
+            // could replace a schema that has them.
+            None if args.write => {
+                return Err(
+                    "`--write` cannot be used with a descriptor, because the schema \
+                         source is needed to format it"
+                        .to_string(),
+                )
+            }
+            None => schema.to_source(args.sort_enums),
+        };
+
+        if !args.write {
+            sink.print(&formatted)?;
+            return Ok(vec![]);
+        }
+
+        let path = PathBuf::from(&params.dest_dir).join(format!("{}.sb", params.file_name));
+        sink.write_file(&path, &formatted)?;
+
+        Ok(vec![path])
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["sb".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "Formatted schema source (printed unless --write is given)".to_string()
+    }
+
+    fn cli(&self) -> Option<clap::Command> {
+        Some(command())
+    }
+}
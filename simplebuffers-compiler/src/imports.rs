@@ -0,0 +1,254 @@
+//! Resolves and compiles imported schema files.
+
+use crate::ast::{AstBuilder, SyntaxTree, TaggedSyntaxTree};
+use crate::compiler::{find_unused, parse_ast, CompilerError};
+use crate::diagnostic::{Diagnose, Diagnostic};
+use simplebuffers_core::SBSchema;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Find the file referenced by an import.
+///
+/// The import is first resolved relative to the directory of the importing file, and then relative
+/// to each include directory in the order they were given. Absolute imports are used as-is.
+///
+/// # Arguments
+///
+/// * `import` - The path given in the import statement.
+/// * `importing_dir` - The directory of the file that contains the import.
+/// * `include_dirs` - Additional directories to search, as given with `--include-dir`.
+///
+/// # Returns
+///
+/// The path to the imported file, or an error that lists every directory that was searched.
+pub fn resolve_import(
+    import: &str,
+    importing_dir: &Path,
+    include_dirs: &[PathBuf],
+) -> Result<PathBuf, String> {
+    let import_path = Path::new(import);
+    if import_path.is_absolute() {
+        return if import_path.is_file() {
+            Ok(import_path.to_path_buf())
+        } else {
+            Err(format!("Could not find import \"{}\"", import))
+        };
+    }
+
+    let search_dirs: Vec<&Path> = std::iter::once(importing_dir)
+        .chain(include_dirs.iter().map(PathBuf::as_path))
+        .collect();
+    search_dirs
+        .iter()
+        .map(|dir| dir.join(import_path))
+        .find(|candidate| candidate.is_file())
+        .ok_or_else(|| {
+            format!(
+                "Could not find import \"{}\". Searched in: {}",
+                import,
+                search_dirs
+                    .iter()
+                    .map(|dir| dir.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+}
+
+/// Check that every include directory exists.
+///
+/// # Arguments
+///
+/// * `include_dirs` - The directories given with `--include-dir`.
+///
+/// # Returns
+///
+/// The include directories as paths, or an error naming the first one that is not a directory.
+pub fn check_include_dirs(include_dirs: &[String]) -> Result<Vec<PathBuf>, String> {
+    include_dirs
+        .iter()
+        .map(|dir| {
+            let path = PathBuf::from(dir);
+            if path.is_dir() {
+                Ok(path)
+            } else {
+                Err(format!("Include directory '{}' does not exist", dir))
+            }
+        })
+        .collect()
+}
+
+/// Compiles a schema and every schema it imports, directly or indirectly.
+pub(crate) struct Importer<'d> {
+    /// The flags that are defined for `#if` directives, in every file.
+    defines: HashSet<String>,
+
+    /// The directories to search for imports after the directory of the importing file.
+    include_dirs: &'d [PathBuf],
+
+    /// The files that are being compiled, from the outermost to the innermost import, as their
+    /// canonical paths and the names used in messages. An import of one of these is circular.
+    stack: Vec<(PathBuf, String)>,
+
+    /// Files that have already been compiled, by canonical path, so each file is only compiled
+    /// once even if it is imported several times.
+    compiled: HashMap<PathBuf, SBSchema>,
+
+    /// Whether to warn about unused sequences and enums in the outermost file.
+    warn_unused: bool,
+
+    /// Whether to treat warnings as errors.
+    strict: bool,
+}
+
+impl<'d> Importer<'d> {
+    /// Creates an importer.
+    ///
+    /// # Arguments
+    ///
+    /// * `defines` - The flags that are defined for `#if` directives.
+    /// * `include_dirs` - The directories to search for imports after the directory of the
+    ///   importing file.
+    pub(crate) fn new(defines: HashSet<String>, include_dirs: &'d [PathBuf]) -> Self {
+        Self {
+            defines,
+            include_dirs,
+            stack: Vec::new(),
+            compiled: HashMap::new(),
+            warn_unused: false,
+            strict: false,
+        }
+    }
+
+    /// Sets whether to warn about sequences and enums that are never used. Only the outermost file
+    /// is checked, since imported files are often shared and only partly used.
+    pub(crate) fn warn_unused(mut self, warn_unused: bool) -> Self {
+        self.warn_unused = warn_unused;
+        self
+    }
+
+    /// Sets whether to treat warnings as errors, in every file.
+    pub(crate) fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Compiles a schema, after compiling the schemas it imports. The result is not validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The contents of the schema file.
+    /// * `file_name` - The path of the schema file. Imports are resolved relative to its directory.
+    /// * `warnings` - A list to push any warnings onto, from this file and every imported file.
+    ///
+    /// # Returns
+    ///
+    /// The compiled schema, which includes the imported types, or a list of errors.
+    pub(crate) fn compile(
+        &mut self,
+        source: &str,
+        file_name: &str,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<SBSchema, Vec<Diagnostic>> {
+        // Schemas read from stdin have no canonical path, and cannot be imported.
+        let canonical = fs::canonicalize(file_name).ok();
+        if let Some(path) = &canonical {
+            self.stack.push((path.clone(), file_name.to_string()));
+        }
+        let result = self.compile_file(source, file_name, warnings);
+        if canonical.is_some() {
+            self.stack.pop();
+        }
+        result
+    }
+
+    /// Compiles a schema whose path is already on the stack.
+    fn compile_file(
+        &mut self,
+        source: &str,
+        file_name: &str,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<SBSchema, Vec<Diagnostic>> {
+        let mut parser = AstBuilder::new(source, file_name, self.defines.clone())
+            .map_err(|e| vec![e.diagnostic()])?;
+        let ast = parser.parse().map_err(|e| vec![e.diagnostic()])?;
+
+        let importing_dir = match Path::new(file_name).parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let mut imports = HashMap::new();
+        if let SyntaxTree::File(nodes) = &ast.data {
+            for node in nodes {
+                if let SyntaxTree::Import(import) = &node.data {
+                    let schema = self.import(node, import, importing_dir, warnings)?;
+                    imports.insert(import.clone(), schema);
+                }
+            }
+        }
+
+        let mut compiler_warnings = vec![];
+        let schema = parse_ast(&ast, &imports, &mut compiler_warnings);
+        // Imported files are always on the stack below the outermost file, which is not on the
+        // stack at all if it was read from stdin.
+        if schema.is_ok() && self.warn_unused && self.stack.len() <= 1 {
+            compiler_warnings.extend(find_unused(&ast));
+        }
+        if self.strict && !compiler_warnings.is_empty() {
+            let mut errors: Vec<Diagnostic> = compiler_warnings
+                .into_iter()
+                .map(|w| w.into_error().diagnostic())
+                .collect();
+            if let Err(e) = schema {
+                errors.push(e.diagnostic());
+            }
+            return Err(errors);
+        }
+        warnings.extend(compiler_warnings.iter().map(|w| w.diagnostic()));
+        schema.map_err(|e| vec![e.diagnostic()])
+    }
+
+    /// Resolves and compiles a single import.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The import statement, used to point errors at it.
+    /// * `import` - The path given in the import statement.
+    /// * `importing_dir` - The directory of the file that contains the import.
+    /// * `warnings` - A list to push any warnings onto.
+    fn import(
+        &mut self,
+        node: &TaggedSyntaxTree,
+        import: &str,
+        importing_dir: &Path,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<SBSchema, Vec<Diagnostic>> {
+        let error =
+            |message: String| vec![CompilerError::new(node.token.clone(), message).diagnostic()];
+
+        let path = resolve_import(import, importing_dir, self.include_dirs).map_err(error)?;
+        let canonical = fs::canonicalize(&path)
+            .map_err(|e| error(format!("Failed to resolve {}: {}", path.display(), e)))?;
+        if let Some(start) = self.stack.iter().position(|(p, _)| *p == canonical) {
+            let cycle = self.stack[start..]
+                .iter()
+                .map(|(_, name)| name.as_str())
+                .chain(std::iter::once(self.stack[start].1.as_str()))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(error(format!("Circular import: {}", cycle)));
+        }
+        if let Some(schema) = self.compiled.get(&canonical) {
+            return Ok(schema.clone());
+        }
+
+        let file_name = path.to_string_lossy().to_string();
+        let source = fs::read_to_string(&path)
+            .map_err(|e| error(format!("Failed to read {}: {}", file_name, e)))?;
+        // Errors in the imported file are reported in that file.
+        let schema = self.compile(&source, &file_name, warnings)?;
+        self.compiled.insert(canonical, schema.clone());
+        Ok(schema)
+    }
+}
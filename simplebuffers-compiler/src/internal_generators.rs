@@ -1,10 +1,10 @@
 //! Generates a list of all generators being bundled with the compiler. See [get_internal_generator]
-//! for more information.
+//! for more information, and [internal_generator_names] to list them.
 
 use simplebuffers_codegen::CodeGenerator;
 
 macro_rules! register_internal_generators {
-    ($($name:literal : $generator:ty),*) => {
+    ($([$name:literal $(, $alias:literal)*] : $generator:ty),* $(,)?) => {
         #[doc = concat!(
             "Matches bundled generators by name.\n\n",
             "# Arguments\n\n",
@@ -12,24 +12,42 @@ macro_rules! register_internal_generators {
             "# Returns\n\n",
             "If found, `Some(<boxed pointer to the matching generator>)`. Otherwise, `None`.\n\n",
             "# Bundled Generators\n\n",
-            "| Name | Implementation |\n",
-            "| ---- | -------------- |\n",
-            $("| ", $name, " | [`", stringify!($generator), "`] |\n",)*
+            "| Names | Implementation |\n",
+            "| ----- | -------------- |\n",
+            $("| ", $name, $(", ", $alias,)* " | [`", stringify!($generator), "`] |\n",)*
         )]
 
         pub(crate) fn get_internal_generator(name: &str) -> Option<Box<dyn CodeGenerator>> {
             match (name) {
                 $(
-                    $name => Some(Box::new(<$generator as CodeGenerator>::new())),
+                    $name $(| $alias)* => Some(Box::new(<$generator as CodeGenerator>::new())),
                 )*
                 _ => None
             }
         }
+
+        /// Returns the names of all bundled generators, in the order they are registered. Generators
+        /// with aliases are listed once per name.
+        pub(crate) fn internal_generator_names() -> &'static [&'static str] {
+            &[$($name, $($alias,)*)*]
+        }
     };
 }
 
 register_internal_generators!(
-    "sanitycheck": simplebuffers_sanitycheck::SanityCheckCodeGenerator,
-    "c++": simplebuffers_cpp::CPPCodeGenerator,
-    "cpp": simplebuffers_cpp::CPPCodeGenerator
+    ["sanitycheck"]: simplebuffers_sanitycheck::SanityCheckCodeGenerator,
+    ["c++", "cpp"]: simplebuffers_cpp::CPPCodeGenerator,
+    ["swift"]: simplebuffers_swift::SwiftCodeGenerator,
+    ["java"]: simplebuffers_java::JavaCodeGenerator,
+    ["csharp", "cs"]: simplebuffers_csharp::CSharpCodeGenerator,
+    ["kotlin", "kt"]: simplebuffers_kotlin::KotlinCodeGenerator,
+    ["zig"]: simplebuffers_zig::ZigCodeGenerator,
+    ["graphql", "gql"]: simplebuffers_graphql::GraphQLCodeGenerator,
+    ["flatbuffers", "fbs"]: simplebuffers_flatbuffers::FlatBuffersCodeGenerator,
+    ["python", "py"]: simplebuffers_python::PythonCodeGenerator,
+    ["rust", "rs"]: simplebuffers_rust::RustCodeGenerator,
+    ["dot"]: simplebuffers_dot::DotCodeGenerator,
+    ["proto"]: simplebuffers_proto::ProtoCodeGenerator,
+    ["format"]: simplebuffers_compiler::format::FormatCodeGenerator,
+    ["testvectors"]: simplebuffers_testvectors::TestVectorsCodeGenerator,
 );
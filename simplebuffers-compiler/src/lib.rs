@@ -0,0 +1,150 @@
+//! The SimpleBuffers schema compiler.
+//!
+//! Schemas are compiled in several stages:
+//!
+//! 1. The [tokenizer] splits the source into tokens. [tokenize] exposes them on their own, with
+//!    their locations, for tools such as syntax highlighters.
+//! 2. The [preprocessor] evaluates `#if`/`#else`/`#endif` directives, dropping tokens that are
+//!    excluded by the defined flags.
+//! 3. The [ast] module ([AstBuilder]) builds a tagged syntax tree from the remaining tokens.
+//! 4. The [compiler] module ([parse_ast]) turns the syntax tree into a [SBSchema]. The [imports]
+//!    module runs these stages for every imported file first, so their types can be merged in.
+//!
+//! [compile] runs every stage and validates the result, with [CompileOptions] for defines, include
+//! directories, and checks. [compile_str] does the same with the default options. A schema can
+//! also be loaded from a JSON descriptor with [load_descriptor], skipping the stages above. Errors
+//! and warnings from every stage implement [Diagnose]. [format_source] rewrites a schema in the canonical style without compiling it. Code
+//! generation is not part of this library; see the `simplebuffers-codegen` crate.
+
+pub mod ast;
+pub mod compiler;
+pub mod diagnostic;
+pub mod format;
+pub mod imports;
+pub mod preprocessor;
+pub mod tokenizer;
+
+pub use ast::{AstBuilder, AstBuilderError};
+pub use compiler::{parse_ast, CompilerError, CompilerWarning};
+pub use diagnostic::{Diagnose, Diagnostic, Severity};
+pub use format::format_source;
+pub use preprocessor::PreprocessorError;
+pub use tokenizer::{tokenize, Token, TokenLocation, TokenType, Tokenizer, TokenizerError};
+
+use imports::Importer;
+use simplebuffers_core::SBSchema;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+/// Options for [compile].
+#[derive(Clone, Debug, Default)]
+pub struct CompileOptions {
+    /// The flags that are defined for `#if` directives, in every file.
+    pub defines: HashSet<String>,
+
+    /// Additional directories to search for imported schemas, after the directory of the importing
+    /// file.
+    pub include_dirs: Vec<PathBuf>,
+
+    /// Warn about sequences and enums in the schema file that are never used. See
+    /// [compiler::find_unused].
+    pub warn_unused: bool,
+
+    /// Treat warnings as errors.
+    pub strict: bool,
+
+    /// Accept a schema that defines no sequences or enums, including imported ones. Otherwise,
+    /// such a schema is rejected, since generating code for it would only produce empty files.
+    pub allow_empty: bool,
+
+    /// Reorder the fields of every sequence to reduce padding in native structs. This changes the
+    /// wire layout. See [SBSchema::optimize_field_order].
+    pub optimize_layout: bool,
+}
+
+/// Compile a schema with the given options and validate the result. Imports are resolved relative
+/// to the directory of the importing file, and then relative to each of
+/// [include_dirs](CompileOptions::include_dirs) in order.
+///
+/// # Arguments
+///
+/// * `source` - The contents of the schema file.
+/// * `file_name` - The path of the schema file, used in error messages and to resolve imports.
+/// * `options` - The options to compile with.
+/// * `warnings` - A list to push any warnings onto.
+///
+/// # Returns
+///
+/// The compiled schema, including every imported type, or a list of errors.
+pub fn compile(
+    source: &str,
+    file_name: &str,
+    options: &CompileOptions,
+    warnings: &mut Vec<Diagnostic>,
+) -> Result<SBSchema, Vec<Diagnostic>> {
+    let mut schema = Importer::new(options.defines.clone(), &options.include_dirs)
+        .warn_unused(options.warn_unused)
+        .strict(options.strict)
+        .compile(source, file_name, warnings)?;
+    if options.optimize_layout {
+        schema.optimize_field_order();
+    }
+    if !options.allow_empty && schema.sequences.is_empty() && schema.enums.is_empty() {
+        return Err(vec![Diagnostic::error(
+            "Schema contains no definitions".to_string(),
+        )
+        .in_file(file_name)]);
+    }
+    schema.validate().map_err(|errors| {
+        errors
+            .iter()
+            .map(|e| Diagnostic::error(format!("Invalid schema: {}", e)).in_file(file_name))
+            .collect::<Vec<_>>()
+    })?;
+    Ok(schema)
+}
+
+/// Load a schema from a JSON descriptor, such as one written by `--emit-descriptor`, and validate
+/// it. Descriptors may be written by other tools, so they are validated as strictly as compiled
+/// schemas.
+///
+/// # Arguments
+///
+/// * `source` - The contents of the descriptor file.
+/// * `file_name` - The name of the descriptor file, used in error messages.
+///
+/// # Returns
+///
+/// The loaded schema, or a list of errors.
+pub fn load_descriptor(source: &str, file_name: &str) -> Result<SBSchema, Vec<Diagnostic>> {
+    let schema: SBSchema = serde_json::from_str(source).map_err(|e| {
+        vec![Diagnostic::error(format!("Invalid descriptor: {}", e)).in_file(file_name)]
+    })?;
+    schema.validate().map_err(|errors| {
+        errors
+            .iter()
+            .map(|e| Diagnostic::error(format!("Invalid schema: {}", e)).in_file(file_name))
+            .collect::<Vec<_>>()
+    })?;
+    Ok(schema)
+}
+
+/// Compile a schema with no flags defined, discarding any warnings.
+///
+/// # Arguments
+///
+/// * `source` - The contents of the schema file.
+/// * `file_name` - The name of the schema file, used in error messages.
+///
+/// # Returns
+///
+/// The compiled schema, or the formatted errors joined by newlines.
+pub fn compile_str(source: &str, file_name: &str) -> Result<SBSchema, String> {
+    compile(source, file_name, &CompileOptions::default(), &mut vec![]).map_err(|errors| {
+        errors
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
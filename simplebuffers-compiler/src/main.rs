@@ -3,22 +3,38 @@
 //! This implementation is temporary. A more complete version will be written sometime in the
 //! future.
 
-mod ast;
-mod compiler;
 mod internal_generators;
 mod reserved_identifiers;
-mod tokenizer;
 
-use clap::Parser;
-use internal_generators::get_internal_generator;
+use clap::{error::ErrorKind, CommandFactory, FromArgMatches, Parser, ValueEnum};
+use internal_generators::{get_internal_generator, internal_generator_names};
 use libloading::{Library, Symbol};
 use reserved_identifiers::check_reserved;
-use simplebuffers_codegen::{CodeGenerator, GeneratorParams};
-use simplebuffers_core::SBSchema;
-use std::{path::Path, process::ExitCode};
+use simplebuffers_codegen::{
+    CodeGenerator, FileSystemSink, GeneratorParams, GENERATOR_LIST_SYMBOL_PREFIX,
+};
+use simplebuffers_compiler::{
+    imports::check_include_dirs, CompileOptions, Diagnose, Diagnostic, Severity,
+};
+use simplebuffers_core::{diff::diff, SBSchema};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// How errors and warnings are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ErrorFormat {
+    /// Colored text with the surrounding lines of the schema.
+    Human,
+
+    /// One JSON object per line, printed to stderr.
+    Json,
+}
+
 /// A struct that holds CLI parameters.
 #[derive(Parser, Debug)]
 #[command(name = "SimpleBuffers Compiler")]
@@ -29,7 +45,14 @@ struct Cli {
     #[arg(short, long)]
     lib: Option<String>,
 
-    /// The directory where your SimpleBuffers schema lives.
+    /// A directory of custom libraries to search for the requested generator. Libraries are
+    /// searched in alphabetical order, and take precedence over bundled generators. With
+    /// `--list-generators`, the generators of these libraries are listed too.
+    #[arg(long)]
+    plugin_dir: Option<String>,
+
+    /// The directory where your SimpleBuffers schema lives. The schema path is resolved relative to
+    /// this directory unless it is absolute, and imported schemas are also searched for in it.
     #[arg(short, long)]
     srcdir: Option<String>,
 
@@ -37,70 +60,564 @@ struct Cli {
     #[arg(short, long)]
     dstdir: Option<String>,
 
+    /// An additional directory to search for imported schemas. Directories are searched in order,
+    /// after the directory of the importing file and `srcdir`. Can be given more than once.
+    #[arg(short = 'I', long = "include-dir")]
+    include_dir: Vec<String>,
+
+    /// A flag to define for `#if` directives in the schema. Can be given more than once.
+    #[arg(short = 'D', long = "define")]
+    define: Vec<String>,
+
+    /// Allow reserved identifiers in the schema. Generators that support it will rename
+    /// conflicting identifiers instead of the compiler rejecting the schema.
+    #[arg(long)]
+    relax_reserved: bool,
+
+    /// The maximum number of errors to print. Set to 0 to print every error.
+    #[arg(long, default_value_t = 20)]
+    max_errors: usize,
+
+    /// How to print errors and warnings.
+    #[arg(long, value_enum, default_value_t = ErrorFormat::Human)]
+    error_format: ErrorFormat,
+
+    /// Warn about sequences and enums that nothing refers to. Since schemas have no explicit root,
+    /// the sequences that are sent on their own are reported as well.
+    #[arg(long)]
+    warn_unused: bool,
+
+    /// Treat warnings as errors, such as those for empty sequences and enums.
+    #[arg(long)]
+    strict: bool,
+
+    /// Accept a schema that defines no sequences or enums, instead of failing. Generators will
+    /// write files with nothing in them.
+    #[arg(long)]
+    allow_empty: bool,
+
+    /// Reorder the fields of every sequence, largest alignment first, to reduce padding in native
+    /// structs. This changes the wire layout, so it must be used for every program that shares the
+    /// schema.
+    #[arg(long)]
+    optimize_layout: bool,
+
+    /// Compare the schema to an older version of it, given as a schema file or a JSON descriptor.
+    /// Every change is printed, and the compiler fails before running the generator if any of them
+    /// break wire compatibility.
+    #[arg(long, value_name = "OLD_SCHEMA")]
+    diff: Option<String>,
+
+    /// Print how many sequences, enums, oneofs, and fields the schema has, and the smallest and
+    /// largest static size of its sequences.
+    #[arg(long)]
+    stats: bool,
+
+    /// Check the schema without generating any code. The schema is still checked for the
+    /// generator's reserved identifiers.
+    #[arg(long)]
+    check: bool,
+
+    /// Write the parsed schema to a JSON descriptor at this path, which can be read back to
+    /// reconstruct the schema.
+    #[arg(long, value_name = "PATH")]
+    emit_descriptor: Option<String>,
+
+    /// Read the schema from a JSON descriptor (see `--emit-descriptor`) instead of parsing it. This
+    /// is implied when the schema file ends in `.json`.
+    #[arg(long)]
+    from_descriptor: bool,
+
+    /// The base name of generated files. Defaults to the name of the schema file without its
+    /// extension, or `stdin` if the schema is read from stdin.
+    #[arg(long)]
+    name: Option<String>,
+
+    /// List the bundled generators, with what they write, and exit.
+    #[arg(long)]
+    list_generators: bool,
+
     /// The name of the code generator to use.
-    generator: String,
+    #[arg(required_unless_present = "list_generators")]
+    generator: Option<String>,
 
-    /// The SimpleBuffers file to parse.
-    file: String,
+    /// The SimpleBuffers file to parse. Use `-` to read the schema from stdin.
+    #[arg(required_unless_present = "list_generators")]
+    file: Option<String>,
 
     /// Additional arguments that are specific to the code generator.
     #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
     generator_args: Vec<String>,
 }
 
-/// Load a generator from a shared library and run it.
+/// Check the generator-specific arguments against the command the generator declares with
+/// [CodeGenerator::cli]. Generators that do not declare one are not checked.
+///
+/// If the arguments ask for help or the version, it is printed and the compiler exits.
+fn check_generator_args(
+    generator: &dyn CodeGenerator,
+    params: &GeneratorParams,
+) -> Result<(), Vec<Diagnostic>> {
+    let Some(command) = generator.cli() else {
+        return Ok(());
+    };
+    match command.try_get_matches_from(&params.additional_args) {
+        Ok(_) => Ok(()),
+        Err(e) if matches!(e.kind(), ErrorKind::DisplayHelp | ErrorKind::DisplayVersion) => {
+            e.exit()
+        }
+        Err(e) => {
+            // Only keep the first line of clap's message, without its `error: ` prefix. The usage
+            // that follows describes the generator as if it were a standalone program.
+            let rendered = e.render().to_string();
+            let message = rendered.lines().next().unwrap_or_default();
+            Err(vec![Diagnostic::error(format!(
+                "Invalid arguments for generator `{}`: {}",
+                params.additional_args[0],
+                message.strip_prefix("error: ").unwrap_or(message)
+            ))])
+        }
+    }
+}
+
+/// Run a generator: check its arguments, check the schema against its reserved identifiers (unless
+/// they are relaxed), and generate code. If `check` is set, no code is generated. `source_name` is
+/// the file that reserved identifier errors are reported in.
+///
+/// # Returns
+///
+/// The paths of the files the generator wrote.
+fn run_generator(
+    generator: &mut dyn CodeGenerator,
+    schema: &SBSchema,
+    params: &GeneratorParams,
+    check: bool,
+    source_name: &str,
+) -> Result<Vec<PathBuf>, Vec<Diagnostic>> {
+    check_generator_args(generator, params)?;
+    if !params.relax_reserved {
+        check_reserved(
+            schema,
+            &generator.reserved_identifiers(params),
+            generator.reserved_identifiers_case_sensitive(),
+            |kind| generator.reserved_identifiers_apply_to(kind),
+        )
+        .map_err(|errors| {
+            errors
+                .iter()
+                .map(|e| e.diagnostic().in_file(source_name))
+                .collect::<Vec<_>>()
+        })?;
+    }
+    if check {
+        return Ok(vec![]);
+    }
+    generator
+        .generate(schema, params, &mut FileSystemSink)
+        .map_err(|e| vec![Diagnostic::error(format!("Generator error: {}", e))])
+}
+
+/// Load a generator from a shared library and run it with [run_generator].
+///
+/// # Returns
+///
+/// The paths of the files the generator wrote.
 fn run_from_lib(
     schema: &SBSchema,
     params: &GeneratorParams,
     path: &str,
     gen_name: &str,
-) -> Result<(), String> {
-    let loaded_lib =
-        unsafe { Library::new(path).map_err(|_| format!("Failed to load library at '{}'", path)) }?;
+    check: bool,
+    source_name: &str,
+) -> Result<Vec<PathBuf>, Vec<Diagnostic>> {
+    let loaded_lib = unsafe {
+        Library::new(path).map_err(|_| {
+            vec![Diagnostic::error(format!(
+                "Failed to load library at '{}'",
+                path
+            ))]
+        })
+    }?;
     let loaded_constructor: Symbol<fn() -> Box<dyn CodeGenerator>> = unsafe {
-        loaded_lib
-            .get(gen_name.as_bytes())
-            .map_err(|_| format!("Failed to load generator from '{}'", path))?
+        loaded_lib.get(gen_name.as_bytes()).map_err(|_| {
+            vec![Diagnostic::error(format!(
+                "Failed to load generator from '{}'",
+                path
+            ))]
+        })?
     };
     let mut generator = loaded_constructor();
-    check_reserved(schema, &generator.reserved_identifiers(params))
-        .map_err(|e| format!("{}", e))?;
-    generator
-        .generate(schema, params)
-        .map_err(|e| format!("GENERATOR ERROR: {}", e))
+    run_generator(generator.as_mut(), schema, params, check, source_name)
+}
+
+/// Find the shared libraries in a plugin directory.
+///
+/// # Returns
+///
+/// The paths of the libraries, in alphabetical order.
+fn plugin_libraries(dir: &str) -> Result<Vec<PathBuf>, Vec<Diagnostic>> {
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        vec![Diagnostic::error(format!(
+            "Failed to read plugin directory '{}': {}",
+            dir, e
+        ))]
+    })?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.is_file()
+                && path.extension().and_then(|ext| ext.to_str()) == Some(env::consts::DLL_EXTENSION)
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Search a directory for a shared library that exports the requested generator.
+///
+/// # Returns
+///
+/// The path to the first library (in alphabetical order) that exports the generator, or `None` if
+/// no library does. Files that cannot be loaded as libraries are skipped.
+fn find_in_plugin_dir(dir: &str, gen_name: &str) -> Result<Option<PathBuf>, Vec<Diagnostic>> {
+    for path in plugin_libraries(dir)? {
+        let Ok(lib) = (unsafe { Library::new(&path) }) else {
+            continue;
+        };
+        let found = unsafe {
+            lib.get::<fn() -> Box<dyn CodeGenerator>>(gen_name.as_bytes())
+                .is_ok()
+        };
+        if found {
+            return Ok(Some(path));
+        }
+    }
+    Ok(None)
+}
+
+/// Read the names of the generators that a library lists with `register_generators!`. The list is
+/// exported under the name of the library's crate, which is taken from the file name.
+///
+/// # Returns
+///
+/// The names of the generators, or `None` if the library does not list them.
+fn plugin_generator_names(lib: &Library, path: &Path) -> Option<Vec<String>> {
+    let stem = path.file_stem()?.to_str()?;
+    let crate_name = stem.strip_prefix(env::consts::DLL_PREFIX).unwrap_or(stem);
+    let symbol = format!("{}{}", GENERATOR_LIST_SYMBOL_PREFIX, crate_name);
+    unsafe {
+        let names = lib.get::<*const &[&str]>(symbol.as_bytes()).ok()?;
+        Some((**names).iter().map(|name| name.to_string()).collect())
+    }
 }
 
-/// Search for a generator bundled with the SimpleBuffers compiler and run it if found.
-fn run_internal(schema: &SBSchema, params: &GeneratorParams, gen_name: &str) -> Result<(), String> {
+/// Search for a generator bundled with the SimpleBuffers compiler and run it with [run_generator]
+/// if found.
+///
+/// # Returns
+///
+/// The paths of the files the generator wrote.
+fn run_internal(
+    schema: &SBSchema,
+    params: &GeneratorParams,
+    gen_name: &str,
+    check: bool,
+    source_name: &str,
+) -> Result<Vec<PathBuf>, Vec<Diagnostic>> {
     if let Some(mut generator) = get_internal_generator(gen_name) {
-        check_reserved(schema, &generator.reserved_identifiers(params))
-            .map_err(|e| format!("{}", e))?;
-        generator
-            .generate(schema, params)
-            .map_err(|e| format!("GENERATOR ERROR: {}", e))
+        run_generator(generator.as_mut(), schema, params, check, source_name)
+    } else {
+        Err(vec![Diagnostic::error(format!(
+            "No generators found for target {}",
+            gen_name
+        ))])
+    }
+}
+
+/// Find the schema file. Relative paths are resolved against `srcdir`, if it is given.
+fn resolve_schema_path(srcdir: Option<&str>, file: &str) -> PathBuf {
+    match srcdir {
+        Some(dir) if !Path::new(file).is_absolute() => Path::new(dir).join(file),
+        _ => PathBuf::from(file),
+    }
+}
+
+/// Write a schema to a JSON descriptor file.
+fn write_descriptor(schema: &SBSchema, path: &str) -> Result<(), Vec<Diagnostic>> {
+    let json = serde_json::to_string_pretty(schema).map_err(|e| {
+        vec![Diagnostic::error(format!(
+            "Failed to serialize schema: {}",
+            e
+        ))]
+    })?;
+    std::fs::write(path, json + "\n").map_err(|e| {
+        vec![Diagnostic::error(format!(
+            "Failed to write {}: {}",
+            path, e
+        ))]
+    })
+}
+
+/// Compare a schema to an older version of it and print every change.
+///
+/// # Arguments
+///
+/// * `old_path` - The path of the old version, as a schema file or a JSON descriptor. Its warnings
+///   are not printed.
+/// * `new` - The new version of the schema.
+/// * `options` - The options to compile the old version with.
+///
+/// # Returns
+///
+/// An error if any change breaks wire compatibility.
+fn diff_schemas(
+    old_path: &Path,
+    new: &SBSchema,
+    options: &CompileOptions,
+) -> Result<(), Vec<Diagnostic>> {
+    let old_name = old_path.to_string_lossy();
+    let raw_old = std::fs::read_to_string(old_path)
+        .map_err(|_| vec![Diagnostic::error(format!("Failed to read '{}'", old_name))])?;
+    let old = if old_path.extension().is_some_and(|ext| ext == "json") {
+        simplebuffers_compiler::load_descriptor(&raw_old, &old_name)?
     } else {
-        Err(format!("No generators found for target {}", gen_name))
+        simplebuffers_compiler::compile(&raw_old, &old_name, options, &mut vec![])?
+    };
+
+    let changes = diff(&old, new);
+    if changes.is_empty() {
+        println!("No changes compared to '{}'", old_name);
+    }
+    for change in &changes {
+        println!("{}", change);
+    }
+    match changes.iter().filter(|c| c.breaking).count() {
+        0 => Ok(()),
+        breaking => Err(vec![Diagnostic::error(format!(
+            "Found {} breaking change{} compared to '{}'",
+            breaking,
+            if breaking == 1 { "" } else { "s" },
+            old_name
+        ))]),
+    }
+}
+
+/// Print the statistics of a schema.
+fn print_stats(schema: &SBSchema) {
+    let stats = schema.stats();
+    println!("Schema statistics:");
+    println!("  Sequences:   {}", stats.sequences);
+    println!("  Enums:       {}", stats.enums);
+    println!("  Oneofs:      {}", stats.oneofs);
+    println!("  Fields:      {}", stats.fields);
+    match (stats.min_static_size, stats.max_static_size) {
+        (Some(min), Some(max)) => println!("  Static size: {} to {} bytes", min, max),
+        _ => println!("  Static size: no sequences"),
     }
 }
 
-fn main_impl() -> Result<(), String> {
-    let cli = Cli::parse();
-    let generator_args = format!("{} {}", cli.generator.clone(), cli.generator_args.join(" "));
+/// Print an error or warning. Human-readable warnings go to stderr so that they do not mix with
+/// generators that print to stdout, while errors go to stdout. JSON diagnostics always go to stderr.
+fn print_diagnostic(diagnostic: &Diagnostic, format: ErrorFormat) {
+    match (format, diagnostic.severity) {
+        (ErrorFormat::Json, _) => eprintln!("{}", diagnostic.to_json()),
+        (ErrorFormat::Human, Severity::Warning) => eprintln!("{}", diagnostic),
+        (ErrorFormat::Human, Severity::Error) => println!("{}", diagnostic),
+    }
+}
 
-    let raw_schema = std::fs::read_to_string(cli.file.clone())
-        .map_err(|_| format!("Failed to read '{}'", cli.file))?;
-    let mut parser =
-        ast::AstBuilder::new(raw_schema.as_str(), "test").map_err(|_| "Failed to create parser")?;
-    let ast = parser.parse().map_err(|e| e.to_string())?;
-    let schema = compiler::parse_ast(&ast).map_err(|e| e.to_string())?;
+/// Print a list of errors, stopping after `max_errors` of them. A value of 0 prints every error.
+fn print_errors(errors: &[Diagnostic], max_errors: usize, format: ErrorFormat) {
+    let shown = if max_errors == 0 {
+        errors.len()
+    } else {
+        errors.len().min(max_errors)
+    };
+    for e in &errors[..shown] {
+        print_diagnostic(e, format);
+    }
 
-    let filename = {
-        let ostr = Path::new(&cli.file)
-            .file_stem()
-            .ok_or("Path to schema file is invalid")?;
-        let raw_str = ostr
-            .to_str()
-            .ok_or("Path to schema file contains invalid Unicode")?;
+    // The summary is not a diagnostic, so it is left out of JSON output.
+    let hidden = errors.len() - shown;
+    if hidden > 0 && format == ErrorFormat::Human {
+        println!(
+            "... and {} more error{}.",
+            hidden,
+            if hidden == 1 { "" } else { "s" }
+        );
+    }
+}
+
+/// Print the files a generator wrote. Nothing is printed if no files were written, so that the
+/// output of generators that print to stdout is left alone.
+fn print_written(paths: &[PathBuf]) {
+    match paths {
+        [] => {}
+        [path] => println!("Wrote 1 file: {}", path.display()),
+        _ => {
+            println!("Wrote {} files:", paths.len());
+            for path in paths {
+                println!("  {}", path.display());
+            }
+        }
+    }
+}
+
+/// Print the name, description, and output file extensions of every bundled generator, and of
+/// the generators listed by the libraries in the plugin directory.
+fn list_generators(plugin_dir: Option<&str>) -> Result<(), Vec<Diagnostic>> {
+    let names = internal_generator_names();
+    let width = names.iter().map(|name| name.len()).max().unwrap_or(0);
+
+    println!("Bundled generators:");
+    for name in names {
+        if let Some(generator) = get_internal_generator(name) {
+            print_generator(name, generator.as_ref(), width);
+        }
+    }
+
+    let Some(dir) = plugin_dir else {
+        return Ok(());
+    };
+    for path in plugin_libraries(dir)? {
+        let Ok(lib) = (unsafe { Library::new(&path) }) else {
+            continue;
+        };
+        println!();
+        let Some(names) = plugin_generator_names(&lib, &path) else {
+            println!(
+                "'{}' does not list its generators (see `register_generators!`)",
+                path.display()
+            );
+            continue;
+        };
+        println!("Generators in '{}':", path.display());
+        let width = names.iter().map(|name| name.len()).max().unwrap_or(0);
+        for name in &names {
+            let constructor = unsafe { lib.get::<fn() -> Box<dyn CodeGenerator>>(name.as_bytes()) };
+            if let Ok(constructor) = constructor {
+                print_generator(name, constructor().as_ref(), width);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Print a generator for `--list-generators`, with what it writes. Names are padded to `width`.
+fn print_generator(name: &str, generator: &dyn CodeGenerator, width: usize) {
+    let extensions = generator
+        .output_extensions()
+        .iter()
+        .map(|ext| format!(".{}", ext))
+        .collect::<Vec<_>>();
+    if extensions.is_empty() {
+        println!("  {:<width$}  {}", name, generator.description());
+    } else {
+        println!(
+            "  {:<width$}  {} [{}]",
+            name,
+            generator.description(),
+            extensions.join(", ")
+        );
+    }
+}
+
+fn main_impl(cli: Cli) -> Result<(), Vec<Diagnostic>> {
+    if cli.list_generators {
+        return list_generators(cli.plugin_dir.as_deref());
+    }
+
+    // Clap requires both of these unless `--list-generators` is given.
+    let (Some(generator), Some(file)) = (cli.generator, cli.file) else {
+        unreachable!("the generator and schema file are required");
+    };
+
+    let generator_args = std::iter::once(generator.clone())
+        .chain(cli.generator_args.iter().cloned())
+        .collect();
+    // Imports are searched for in `srcdir` before the include directories.
+    let include_dirs: Vec<PathBuf> = cli
+        .srcdir
+        .iter()
+        .map(PathBuf::from)
+        .chain(check_include_dirs(&cli.include_dir).map_err(|e| vec![Diagnostic::error(e)])?)
+        .collect();
+
+    let from_stdin = file == "-";
+    let schema_path = resolve_schema_path(cli.srcdir.as_deref(), &file);
+    let schema_path = schema_path.to_string_lossy();
+    let raw_schema = if from_stdin {
+        std::io::read_to_string(std::io::stdin()).map_err(|e| {
+            vec![Diagnostic::error(format!(
+                "Failed to read schema from stdin: {}",
+                e
+            ))]
+        })?
+    } else {
+        std::fs::read_to_string(schema_path.as_ref()).map_err(|_| {
+            vec![Diagnostic::error(format!(
+                "Failed to read '{}'",
+                schema_path
+            ))]
+        })?
+    };
+    let source_name = if from_stdin { "<stdin>" } else { &schema_path };
+    let is_descriptor = cli.from_descriptor
+        || Path::new(schema_path.as_ref())
+            .extension()
+            .is_some_and(|ext| ext == "json");
+    let options = CompileOptions {
+        defines: cli.define.iter().cloned().collect(),
+        include_dirs,
+        warn_unused: cli.warn_unused,
+        strict: cli.strict,
+        allow_empty: cli.allow_empty,
+        optimize_layout: cli.optimize_layout,
+    };
+    let schema = if is_descriptor {
+        let mut schema = simplebuffers_compiler::load_descriptor(&raw_schema, source_name)?;
+        if options.optimize_layout {
+            schema.optimize_field_order();
+        }
+        schema
+    } else {
+        let mut warnings = vec![];
+        let result =
+            simplebuffers_compiler::compile(&raw_schema, source_name, &options, &mut warnings);
+        for warning in &warnings {
+            print_diagnostic(warning, cli.error_format);
+        }
+        result?
+    };
+
+    if let Some(path) = &cli.emit_descriptor {
+        write_descriptor(&schema, path)?;
+    }
+
+    if let Some(old) = &cli.diff {
+        let old_path = resolve_schema_path(cli.srcdir.as_deref(), old);
+        diff_schemas(&old_path, &schema, &options)?;
+    }
+
+    if cli.stats {
+        print_stats(&schema);
+    }
+
+    let filename = if let Some(name) = cli.name {
+        name
+    } else if from_stdin {
+        "stdin".to_string()
+    } else {
+        let ostr = Path::new(schema_path.as_ref()).file_stem().ok_or_else(|| {
+            vec![Diagnostic::error(
+                "Path to schema file is invalid".to_string(),
+            )]
+        })?;
+        let raw_str = ostr.to_str().ok_or_else(|| {
+            vec![Diagnostic::error(
+                "Path to schema file contains invalid Unicode".to_string(),
+            )]
+        })?;
         raw_str.to_string()
     };
 
@@ -108,18 +625,80 @@ fn main_impl() -> Result<(), String> {
         file_name: filename,
         dest_dir: cli.dstdir.unwrap_or("./".to_string()),
         additional_args: generator_args,
+        relax_reserved: cli.relax_reserved,
+        source: (!is_descriptor).then_some(raw_schema),
     };
 
-    if let Some(lib_path) = cli.lib {
-        run_from_lib(&schema, &generator_params, &lib_path, &cli.generator)
+    let written = if let Some(lib_path) = cli.lib {
+        run_from_lib(
+            &schema,
+            &generator_params,
+            &lib_path,
+            &generator,
+            cli.check,
+            source_name,
+        )
+    } else if let Some(lib_path) = match &cli.plugin_dir {
+        Some(dir) => find_in_plugin_dir(dir, &generator)?,
+        None => None,
+    } {
+        let lib_path = lib_path.to_string_lossy();
+        eprintln!("Using generator `{}` from '{}'", generator, lib_path);
+        run_from_lib(
+            &schema,
+            &generator_params,
+            &lib_path,
+            &generator,
+            cli.check,
+            source_name,
+        )
     } else {
-        run_internal(&schema, &generator_params, &cli.generator)
-    }
+        run_internal(
+            &schema,
+            &generator_params,
+            &generator,
+            cli.check,
+            source_name,
+        )
+    }?;
+
+    print_written(&written);
+    Ok(())
+}
+
+/// Build the compiler's command. If a bundled generator that declares its arguments is named on
+/// the command line, they are added to the end of the help output.
+fn command() -> clap::Command {
+    let command = Cli::command();
+    let Some((name, generator_command)) = env::args().skip(1).find_map(|arg| {
+        get_internal_generator(&arg)
+            .and_then(|generator| generator.cli())
+            .map(|generator_command| (arg, generator_command))
+    }) else {
+        return command;
+    };
+
+    let generator_help = generator_command
+        .disable_help_flag(true)
+        .disable_version_flag(true)
+        .about(None)
+        .override_usage(format!(
+            "simplebuffers-compiler [OPTIONS] {} <FILE> [GENERATOR_ARGS]...",
+            name
+        ))
+        .render_help();
+    command.after_help(format!(
+        "Arguments for the `{}` generator, given after <FILE>:\n\n{}",
+        name, generator_help
+    ))
 }
 
 fn main() -> ExitCode {
-    if let Err(e) = main_impl() {
-        println!("{}", e);
+    let cli = Cli::from_arg_matches(&command().get_matches()).unwrap_or_else(|e| e.exit());
+    let max_errors = cli.max_errors;
+    let error_format = cli.error_format;
+    if let Err(errors) = main_impl(cli) {
+        print_errors(&errors, max_errors, error_format);
         ExitCode::FAILURE
     } else {
         ExitCode::SUCCESS
@@ -0,0 +1,79 @@
+use colored::Colorize;
+use std::fmt;
+
+use crate::diagnostic::{render, Diagnose};
+use crate::tokenizer::TokenLocation;
+
+/// An error that is returned by the preprocessor.
+#[derive(Debug)]
+pub enum PreprocessorError<'a> {
+    /// A directive that the preprocessor does not understand.
+    UnknownDirective {
+        /// The directive, including the leading `#`.
+        directive: String,
+
+        /// The location of the directive.
+        location: TokenLocation<'a>,
+    },
+
+    /// An `#if` that is not followed by the name of a flag.
+    MissingFlag {
+        /// The location of the `#if`.
+        location: TokenLocation<'a>,
+    },
+
+    /// An `#else` or `#endif` without a matching `#if`, or a second `#else` for the same `#if`.
+    UnmatchedDirective {
+        /// The directive, including the leading `#`.
+        directive: String,
+
+        /// The location of the directive.
+        location: TokenLocation<'a>,
+    },
+
+    /// An `#if` that is never closed with an `#endif`.
+    UnterminatedIf {
+        /// The location of the opening `#if`.
+        location: TokenLocation<'a>,
+    },
+}
+
+impl<'a> std::error::Error for PreprocessorError<'a> {}
+
+impl<'a> Diagnose for PreprocessorError<'a> {
+    fn location(&self) -> Option<&TokenLocation<'_>> {
+        match self {
+            Self::UnknownDirective { location, .. }
+            | Self::MissingFlag { location }
+            | Self::UnmatchedDirective { location, .. }
+            | Self::UnterminatedIf { location } => Some(location),
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::UnknownDirective { directive, .. } => {
+                format!("Unknown directive `{}`", directive.blue().bold())
+            }
+            Self::MissingFlag { .. } => {
+                format!("Expected a flag name after `{}`", "#if".blue().bold())
+            }
+            Self::UnmatchedDirective { directive, .. } => format!(
+                "`{}` does not have a matching `{}`",
+                directive.blue().bold(),
+                "#if".blue().bold(),
+            ),
+            Self::UnterminatedIf { .. } => format!(
+                "`{}` is never closed with `{}`",
+                "#if".blue().bold(),
+                "#endif".blue().bold(),
+            ),
+        }
+    }
+}
+
+impl<'a> fmt::Display for PreprocessorError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render(self, f)
+    }
+}
@@ -0,0 +1,148 @@
+//! Includes or excludes regions of a token stream based on flags.
+//!
+//! # Directives
+//!
+//! - `#if FLAG` starts a region that is only included if `FLAG` is defined.
+//! - `#else` starts a region that is only included if the flag of the matching `#if` is not
+//!   defined.
+//! - `#endif` ends the region started by the matching `#if`.
+//!
+//! Regions can be nested. Flags are defined on the command line with `-D FLAG`.
+
+mod error;
+pub use self::error::PreprocessorError;
+
+use crate::diagnostic::Diagnose;
+use crate::tokenizer::{Token, TokenType, Tokenizer};
+use std::collections::HashSet;
+
+/// An `#if` that has not been closed yet.
+struct OpenIf<'a> {
+    /// The `#if` token, used to report unterminated regions.
+    token: Token<'a>,
+
+    /// Whether the flag of the `#if` is defined.
+    condition: bool,
+
+    /// Whether the `#else` of this region has been reached.
+    in_else: bool,
+}
+
+impl<'a> OpenIf<'a> {
+    /// Returns whether the tokens currently in this region are included.
+    fn is_active(&self) -> bool {
+        self.condition != self.in_else
+    }
+}
+
+/// A token iterator that removes the tokens in inactive regions, along with all directives.
+pub struct Preprocessor<'a> {
+    /// The tokens to preprocess.
+    tokens: Tokenizer<'a>,
+
+    /// The flags that are defined.
+    defines: HashSet<String>,
+
+    /// The `#if` directives that are currently open, innermost last.
+    open_ifs: Vec<OpenIf<'a>>,
+}
+
+impl<'a> Preprocessor<'a> {
+    /// Creates a Preprocessor over a stream of tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens to preprocess.
+    /// * `defines` - The flags that are defined.
+    pub fn new(tokens: Tokenizer<'a>, defines: HashSet<String>) -> Self {
+        Self {
+            tokens,
+            defines,
+            open_ifs: Vec::new(),
+        }
+    }
+
+    /// Returns whether tokens at the current position are included.
+    fn is_active(&self) -> bool {
+        self.open_ifs.iter().all(OpenIf::is_active)
+    }
+
+    /// Handles a directive token, updating the stack of open regions.
+    fn handle_directive(
+        &mut self,
+        token: Token<'a>,
+        directive: &str,
+    ) -> Result<(), Box<dyn Diagnose + 'a>> {
+        match directive {
+            "#if" => {
+                let flag = match self.tokens.pop()? {
+                    Some(Token {
+                        token_type: TokenType::Identifier(flag),
+                        ..
+                    }) => flag,
+                    _ => {
+                        return Err(Box::new(PreprocessorError::MissingFlag {
+                            location: token.location,
+                        }))
+                    }
+                };
+                self.open_ifs.push(OpenIf {
+                    condition: self.defines.contains(&flag),
+                    token,
+                    in_else: false,
+                });
+            }
+            "#else" => match self.open_ifs.last_mut() {
+                Some(open) if !open.in_else => open.in_else = true,
+                _ => {
+                    return Err(Box::new(PreprocessorError::UnmatchedDirective {
+                        directive: directive.to_string(),
+                        location: token.location,
+                    }))
+                }
+            },
+            "#endif" => {
+                if self.open_ifs.pop().is_none() {
+                    return Err(Box::new(PreprocessorError::UnmatchedDirective {
+                        directive: directive.to_string(),
+                        location: token.location,
+                    }));
+                }
+            }
+            _ => {
+                return Err(Box::new(PreprocessorError::UnknownDirective {
+                    directive: directive.to_string(),
+                    location: token.location,
+                }))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the next included token, or `None` at the end of the stream.
+    fn pop(&mut self) -> Result<Option<Token<'a>>, Box<dyn Diagnose + 'a>> {
+        while let Some(token) = self.tokens.pop()? {
+            if let TokenType::Directive(directive) = &token.token_type {
+                let directive = directive.clone();
+                self.handle_directive(token, &directive)?;
+            } else if self.is_active() {
+                return Ok(Some(token));
+            }
+        }
+
+        match self.open_ifs.pop() {
+            Some(open) => Err(Box::new(PreprocessorError::UnterminatedIf {
+                location: open.token.location,
+            })),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'a> Iterator for Preprocessor<'a> {
+    type Item = Result<Token<'a>, Box<dyn Diagnose + 'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pop().transpose()
+    }
+}
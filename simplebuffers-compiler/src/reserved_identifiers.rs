@@ -3,36 +3,17 @@
 use colored::Colorize;
 use convert_case::{Case, Casing};
 use itertools::Itertools;
+use simplebuffers_codegen::ItemKind;
+use simplebuffers_compiler::diagnostic::{render, Diagnose};
+use simplebuffers_compiler::tokenizer::TokenLocation;
 use simplebuffers_core::{Field, SBSchema, Type};
-use std::fmt::{self};
-
-/// The target of an error that occurred while checking for reserved identifiers.
-enum ReserveCheckErrorTarget {
-    Sequence,
-    Enum,
-    EnumVar,
-    Field,
-}
-
-impl fmt::Display for ReserveCheckErrorTarget {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                ReserveCheckErrorTarget::Sequence => "Sequence",
-                ReserveCheckErrorTarget::Enum => "Enum",
-                ReserveCheckErrorTarget::EnumVar => "Enum variant",
-                ReserveCheckErrorTarget::Field => "Field",
-            }
-        )
-    }
-}
+use std::collections::HashMap;
+use std::fmt;
 
 /// A struct to hold errors that occur during the check. The error contains a context stack, so it
 /// can provide the full path to an erroneous field.
 pub(super) struct ReserveCheckError<'n, 'm> {
-    target: ReserveCheckErrorTarget,
+    target: ItemKind,
     name_stack: Vec<String>,
     name: &'n str,
     matched: &'m str,
@@ -40,7 +21,7 @@ pub(super) struct ReserveCheckError<'n, 'm> {
 
 impl<'n, 'm> ReserveCheckError<'n, 'm> {
     /// Creates a new ReserveCheckError with an empty name stack.
-    fn new(target: ReserveCheckErrorTarget, name: &'n str, matched: &'m str) -> Self {
+    fn new(target: ItemKind, name: &'n str, matched: &'m str) -> Self {
         Self {
             target,
             name_stack: vec![],
@@ -56,22 +37,22 @@ impl<'n, 'm> ReserveCheckError<'n, 'm> {
     }
 }
 
-impl<'n, 'm> fmt::Display for ReserveCheckError<'n, 'm> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<'n, 'm> Diagnose for ReserveCheckError<'n, 'm> {
+    fn location(&self) -> Option<&TokenLocation<'_>> {
+        None
+    }
+
+    fn message(&self) -> String {
         if self.name_stack.is_empty() {
-            write!(
-                f,
-                "{} {} `{}` matches reserved keyword `{}`",
-                "ERROR:".red().bold(),
+            format!(
+                "{} `{}` matches reserved keyword `{}`",
                 self.target,
                 self.name.cyan(),
                 self.matched.blue().italic()
             )
         } else {
-            write!(
-                f,
-                "{} {} `{}{}{}` matches reserved keyword `{}`",
-                "ERROR:".red().bold(),
+            format!(
+                "{} `{}{}{}` matches reserved keyword `{}`",
                 self.target,
                 self.name_stack.iter().rev().join("::").cyan(),
                 "::".cyan(),
@@ -82,26 +63,82 @@ impl<'n, 'm> fmt::Display for ReserveCheckError<'n, 'm> {
     }
 }
 
+impl<'n, 'm> fmt::Display for ReserveCheckError<'n, 'm> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render(self, f)
+    }
+}
+
+/// Reserved identifiers, keyed by the form that identifiers in the schema are compared in.
+struct ReservedSet<'m> {
+    identifiers: HashMap<String, &'m String>,
+    case_sensitive: bool,
+}
+
+impl<'m> ReservedSet<'m> {
+    /// Creates a set of reserved identifiers. Unless `case_sensitive` is set, identifiers are
+    /// compared after converting them to snake case, so each reserved identifier is only converted
+    /// once.
+    fn new(reserved: &'m [String], case_sensitive: bool) -> Self {
+        let mut set = Self {
+            identifiers: HashMap::new(),
+            case_sensitive,
+        };
+        // Reversed, so that the first of several reserved identifiers with the same form is kept.
+        for r in reserved.iter().rev() {
+            set.identifiers.insert(set.key(r), r);
+        }
+        set
+    }
+
+    /// Returns the form an identifier is compared in.
+    fn key(&self, name: &str) -> String {
+        if self.case_sensitive {
+            name.to_string()
+        } else {
+            name.to_case(Case::Snake)
+        }
+    }
+
+    /// Returns the reserved identifier that an identifier matches, if any.
+    fn find_match(&self, name: &str) -> Option<&'m String> {
+        self.identifiers.get(&self.key(name)).copied()
+    }
+}
+
 /// Check if any reserved identifiers appear in a schema.
+///
+/// # Arguments
+///
+/// * `schema` - The schema to check.
+/// * `reserved` - The reserved identifiers.
+/// * `case_sensitive` - Whether identifiers must match a reserved identifier exactly. Otherwise,
+///   they are compared after converting both to snake case.
+/// * `applies_to` - Whether the reserved identifiers apply to names of a kind of item. Names of
+///   other kinds are not checked.
+///
+/// # Returns
+///
+/// Every identifier in the schema that matches a reserved identifier, if there are any.
 pub(super) fn check_reserved<'n, 'm>(
     schema: &'n SBSchema,
     reserved: &'m [String],
-) -> Result<(), ReserveCheckError<'n, 'm>> {
-    // Check if two identifiers match after adjusting case.
-    fn find_match<'a>(name: &str, reserved: &'a [String]) -> Option<&'a String> {
-        reserved
-            .iter()
-            .find(|r| name.to_case(Case::Snake) == r.to_case(Case::Snake))
-    }
+    case_sensitive: bool,
+    applies_to: impl Fn(ItemKind) -> bool,
+) -> Result<(), Vec<ReserveCheckError<'n, 'm>>> {
+    let reserved = ReservedSet::new(reserved, case_sensitive);
+    let check_fields = applies_to(ItemKind::Field);
 
-    // Recursive function to check if a field contains a reserved identifier.
+    // Recursive function to find the reserved identifiers in a field.
     fn check_field<'n, 'm>(
         field: &'n Field,
-        reserved: &'m [String],
-    ) -> Result<(), ReserveCheckError<'n, 'm>> {
-        if let Some(matched) = find_match(&field.name, reserved) {
-            return Err(ReserveCheckError::new(
-                ReserveCheckErrorTarget::Field,
+        reserved: &ReservedSet<'m>,
+        check_fields: bool,
+    ) -> Vec<ReserveCheckError<'n, 'm>> {
+        let mut errors = vec![];
+        if let Some(matched) = reserved.find_match(&field.name).filter(|_| check_fields) {
+            errors.push(ReserveCheckError::new(
+                ItemKind::Field,
                 &field.name,
                 matched,
             ));
@@ -109,47 +146,61 @@ pub(super) fn check_reserved<'n, 'm>(
 
         if let Type::OneOf(subfields) = &field.ty {
             for f in subfields {
-                check_field(f, reserved).map_err(|e| e.bubble(field.name.clone()))?;
+                errors.extend(
+                    check_field(f, reserved, check_fields)
+                        .into_iter()
+                        .map(|e| e.bubble(field.name.clone())),
+                );
             }
         }
 
-        Ok(())
+        errors
     }
 
+    let check_enums = applies_to(ItemKind::Enum);
+    let check_variants = applies_to(ItemKind::EnumVariant);
+    let check_sequences = applies_to(ItemKind::Sequence);
+    let mut errors = vec![];
+
     // Check all enums.
     for enm in &schema.enums {
-        if let Some(matched) = find_match(&enm.name, reserved) {
-            return Err(ReserveCheckError::new(
-                ReserveCheckErrorTarget::Enum,
-                &enm.name,
-                matched,
-            ));
+        if let Some(matched) = reserved.find_match(&enm.name).filter(|_| check_enums) {
+            errors.push(ReserveCheckError::new(ItemKind::Enum, &enm.name, matched));
         }
         for variant in &enm.variants {
-            if let Some(matched) = find_match(&variant.name, reserved) {
-                return Err(ReserveCheckError::new(
-                    ReserveCheckErrorTarget::EnumVar,
-                    &variant.name,
-                    matched,
-                )
-                .bubble(enm.name.clone()));
+            if let Some(matched) = reserved
+                .find_match(&variant.name)
+                .filter(|_| check_variants)
+            {
+                errors.push(
+                    ReserveCheckError::new(ItemKind::EnumVariant, &variant.name, matched)
+                        .bubble(enm.name.clone()),
+                );
             }
         }
     }
 
     // Check all sequences.
     for seq in &schema.sequences {
-        if let Some(matched) = find_match(&seq.name, reserved) {
-            return Err(ReserveCheckError::new(
-                ReserveCheckErrorTarget::Sequence,
+        if let Some(matched) = reserved.find_match(&seq.name).filter(|_| check_sequences) {
+            errors.push(ReserveCheckError::new(
+                ItemKind::Sequence,
                 &seq.name,
                 matched,
             ));
         }
         for field in &seq.fields {
-            check_field(field, reserved).map_err(|e| e.bubble(seq.name.clone()))?;
+            errors.extend(
+                check_field(field, &reserved, check_fields)
+                    .into_iter()
+                    .map(|e| e.bubble(seq.name.clone())),
+            );
         }
     }
 
-    Ok(())
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
 }
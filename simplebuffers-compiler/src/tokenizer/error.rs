@@ -2,6 +2,7 @@ use colored::Colorize;
 use std::fmt;
 
 use super::TokenLocation;
+use crate::diagnostic::{render, Diagnose};
 
 /// An error that occured during tokenization.
 #[derive(Debug)]
@@ -21,29 +22,37 @@ impl<'a> TokenizerError<'a> {
 
 impl<'a> std::error::Error for TokenizerError<'a> {}
 
-impl<'a> fmt::Display for TokenizerError<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<'a> From<TokenizerError<'a>> for Box<dyn Diagnose + 'a> {
+    fn from(error: TokenizerError<'a>) -> Self {
+        Box::new(error)
+    }
+}
+
+impl<'a> Diagnose for TokenizerError<'a> {
+    fn location(&self) -> Option<&TokenLocation<'_>> {
+        Some(&self.location)
+    }
+
+    fn message(&self) -> String {
         if let Some(line_text) = self.location.line_text {
             // Get the string from of the offending token. As of now this is only a single
-            // character, but it may be more in the future.
-            let start_idx = self.location.col_num;
-            let end_idx = self.location.col_num + self.location.width;
-            let token_str = &line_text[start_idx..end_idx];
-
-            write!(
-                f,
-                "{error_str} Invalid character `{token_str}`\n{location}",
-                error_str = "ERROR:".red().bold(),
-                token_str = token_str.blue().bold(),
-                location = self.location
-            )
+            // character, but it may be more in the future. The location is in characters, which
+            // may be several bytes long.
+            let token_str: String = line_text
+                .chars()
+                .skip(self.location.col_num)
+                .take(self.location.width)
+                .collect();
+
+            format!("Invalid character `{}`", token_str.blue().bold())
         } else {
-            write!(
-                f,
-                "{error_str} Invalid character\n{location}",
-                error_str = "ERROR:".red().bold(),
-                location = self.location
-            )
+            "Invalid character".to_string()
         }
     }
 }
+
+impl<'a> fmt::Display for TokenizerError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render(self, f)
+    }
+}
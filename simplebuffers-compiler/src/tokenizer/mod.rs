@@ -2,82 +2,161 @@
 //!
 //! # Rules
 //!
-//! These are the rules that the tokenizer follows, in order.
+//! The tokenizer scans the source one token at a time, choosing a rule based on the first
+//! character of the token.
 //!
 //! - Ignore whitespace
-//! - Ignore comments
-//! - Capture `oneof` keyword
-//! - Capture `enum` keyword
-//! - Capture `{`
-//! - Capture `}`
-//! - Capture `[`
-//! - Capture `]`
-//! - Capture `:`
-//! - Capture `;`
-//! - Capture `=`
-//! - Capture numbers
-//! - Capture identifiers
+//! - Capture doc comments (`///`, but not `////`), up to the end of the line
+//! - Ignore comments (`//`), up to the end of the line, unless the tokenizer was created with
+//!   [Tokenizer::with_comments]. Comments must end with a line break, which may be `\n` or `\r\n`.
+//! - Capture `{`, `}`, `[`, `]`, `<`, `>`, `:`, `;`, `,`, `=`, and `.`
+//! - Capture preprocessor directives (`#` followed by lowercase letters, such as `#if`)
+//! - Capture numbers, which may be negative. Hexadecimal and binary numbers start with `0x` or
+//!   `0b`, and decimal numbers may have a fractional part. All numbers may contain `_`.
+//! - Capture identifiers, which start with a letter or `_` and contain letters, digits, and `_`.
+//!   Letters and digits may be any Unicode characters with the `XID_Start` and `XID_Continue`
+//!   properties, so identifiers such as `naïve` and `名前` are allowed. The identifiers
+//!   `sequence`, `oneof`, and `enum` are captured as keywords, and `true` and `false` are captured
+//!   as boolean literals.
 
 mod error;
 pub use self::error::TokenizerError;
 
 use colored::Colorize;
-use regex::Regex;
-use regex_macro::regex;
 use std::fmt;
+use unicode_xid::UnicodeXID;
 
-#[cfg(use_lazy_static)]
-use lazy_static::lazy_static;
-
-#[cfg(not(use_lazy_static))]
-use std::sync::LazyLock;
-
-type OptionalTokenGenerator = Option<fn(String) -> TokenType>;
-
-#[cfg(use_lazy_static)]
-lazy_static! {
-    /// A list of regex sequences matched to tokens.
-    #[deprecated = "Update rustc to 1.80 or newer."]
-    static ref TOKEN_MAP: [(&'static Regex, OptionalTokenGenerator); 14] = [
-        (regex!(r"^\s+"), None), // Ignore whitespace
-        (regex!(r"^//.*?(\r|\n|\r\n)"), None), // Ignore comments
-        (regex!(r"^sequence"), Some(|_| TokenType::Sequence)), // Capture sequence keyword
-        (regex!(r"^oneof"), Some(|_| TokenType::Oneof)), // Capture oneof keyword
-        (regex!(r"^enum"), Some(|_| TokenType::Enum)), // Capture enum keyword
-        (regex!(r"^\{"), Some(|_| TokenType::OpenBrace)), // Capture opening brace
-        (regex!(r"^\}"), Some(|_| TokenType::CloseBrace)), // Capture closing brace
-        (regex!(r"^\["), Some(|_| TokenType::OpenBracket)), // Capture opening bracket
-        (regex!(r"^\]"), Some(|_| TokenType::CloseBracket)), // Capture closing bracket
-        (regex!(r"^:"), Some(|_| TokenType::Colon)), // Capture colon
-        (regex!(r"^;"), Some(|_| TokenType::Semicolon)), // Capture semicolon
-        (regex!(r"^="), Some(|_| TokenType::Equals)), // Capture equals sign
-        (regex!(r"^[0-9_]+(?:\.[0-9_]+)?"), Some(TokenType::Number)), // Capture numbers
-        (regex!(r"^[a-zA-Z_][a-zA-Z0-9_]*"), Some(TokenType::Identifier)), // Capture identifiers
-    ];
+/// Returns the length in bytes of the longest prefix of `s` whose characters all match `pred`.
+fn prefix_len(s: &str, pred: impl Fn(char) -> bool) -> usize {
+    s.find(|c: char| !pred(c)).unwrap_or(s.len())
 }
 
-/// A list of regex sequences matched to tokens.
-#[cfg(not(use_lazy_static))]
-#[allow(clippy::incompatible_msrv)]
-static TOKEN_MAP: LazyLock<[(&'static Regex, OptionalTokenGenerator); 14]> = LazyLock::new(|| {
-    use TokenType as TT;
-    [
-        (regex!(r"^\s+"), None),                               // Ignore whitespace
-        (regex!(r"^//.*?(\r|\n|\r\n)"), None),                 // Ignore comments
-        (regex!(r"^sequence"), Some(|_| TT::Sequence)),        // Capture sequence keyword
-        (regex!(r"^oneof"), Some(|_| TT::Oneof)),              // Capture oneof keyword
-        (regex!(r"^enum"), Some(|_| TT::Enum)),                // Capture enum keyword
-        (regex!(r"^\{"), Some(|_| TT::OpenBrace)),             // Capture opening brace
-        (regex!(r"^\}"), Some(|_| TT::CloseBrace)),            // Capture closing brace
-        (regex!(r"^\["), Some(|_| TT::OpenBracket)),           // Capture opening bracket
-        (regex!(r"^\]"), Some(|_| TT::CloseBracket)),          // Capture closing bracket
-        (regex!(r"^:"), Some(|_| TT::Colon)),                  // Capture colon
-        (regex!(r"^;"), Some(|_| TT::Semicolon)),              // Capture semicolon
-        (regex!(r"^="), Some(|_| TT::Equals)),                 // Capture equals sign
-        (regex!(r"^[0-9_]+(?:\.[0-9_]+)?"), Some(TT::Number)), // Capture numbers
-        (regex!(r"^[a-zA-Z_][a-zA-Z0-9_]*"), Some(TT::Identifier)), // Capture identifiers
-    ]
-});
+/// Returns whether a character can start an identifier.
+fn is_identifier_start(c: char) -> bool {
+    c.is_xid_start() || c == '_'
+}
+
+/// Returns whether a character can appear in an identifier after the first character.
+fn is_identifier_char(c: char) -> bool {
+    c.is_xid_continue()
+}
+
+/// Scans a comment or doc comment at the start of `source`, which begins with `//`. The comment
+/// ends at the first `\r` or `\n`, and includes the line break that ends it (both characters of a
+/// `\r\n` line break).
+///
+/// # Returns
+///
+/// The length of the comment in bytes, and its token. Returns `None` if the comment is not
+/// followed by a line break.
+fn scan_comment(source: &str) -> Option<(usize, TokenType)> {
+    let end = source.find(['\r', '\n'])?;
+    let line_break = if source[end..].starts_with("\r\n") {
+        2
+    } else {
+        1
+    };
+    let is_doc = source.starts_with("///") && !source[3..].starts_with('/');
+    let token = if is_doc {
+        TokenType::DocComment(source[3..end].to_string())
+    } else {
+        TokenType::Comment(source[2..end].to_string())
+    };
+    Some((end + line_break, token))
+}
+
+/// Scans a number at the start of `source`, which begins with `-` or a digit.
+///
+/// # Returns
+///
+/// The length of the number in bytes, or `None` if `source` does not start with a number.
+fn scan_number(source: &str) -> Option<usize> {
+    let sign = usize::from(source.starts_with('-'));
+    let body = &source[sign..];
+
+    // Hexadecimal and binary numbers.
+    if body.starts_with('0') && body[1..].starts_with(['x', 'X', 'b', 'B']) {
+        return Some(sign + 2 + prefix_len(&body[2..], |c| c.is_ascii_alphanumeric() || c == '_'));
+    }
+
+    // Decimal numbers, with an optional fractional part.
+    let is_digit = |c: char| c.is_ascii_digit() || c == '_';
+    let integer = prefix_len(body, is_digit);
+    if integer == 0 {
+        return None;
+    }
+    let fraction = match body[integer..].strip_prefix('.') {
+        Some(rest) if prefix_len(rest, is_digit) > 0 => 1 + prefix_len(rest, is_digit),
+        _ => 0,
+    };
+    Some(sign + integer + fraction)
+}
+
+/// Scans the token at the start of `source`.
+///
+/// # Returns
+///
+/// The length of the token in bytes, and the token, which is `None` for whitespace. Returns `None`
+/// if `source` does not start with a valid token.
+fn scan(source: &str) -> Option<(usize, Option<TokenType>)> {
+    let first = source.chars().next()?;
+    let punctuation = match first {
+        '{' => Some(TokenType::OpenBrace),
+        '}' => Some(TokenType::CloseBrace),
+        '[' => Some(TokenType::OpenBracket),
+        ']' => Some(TokenType::CloseBracket),
+        '<' => Some(TokenType::OpenAngle),
+        '>' => Some(TokenType::CloseAngle),
+        ':' => Some(TokenType::Colon),
+        ';' => Some(TokenType::Semicolon),
+        ',' => Some(TokenType::Comma),
+        '=' => Some(TokenType::Equals),
+        '.' => Some(TokenType::Dot),
+        _ => None,
+    };
+    if punctuation.is_some() {
+        return Some((1, punctuation));
+    }
+
+    match first {
+        c if c.is_whitespace() => Some((prefix_len(source, char::is_whitespace), None)),
+        '/' if source.starts_with("//") => {
+            scan_comment(source).map(|(len, token)| (len, Some(token)))
+        }
+        '"' => {
+            // Strings cannot contain escapes or span lines.
+            let len = 1 + prefix_len(&source[1..], |c| !matches!(c, '"' | '\r' | '\n'));
+            source[len..].starts_with('"').then(|| {
+                let token = TokenType::String(source[1..len].to_string());
+                (len + 1, Some(token))
+            })
+        }
+        '#' => {
+            let len = 1 + prefix_len(&source[1..], |c| c.is_ascii_lowercase());
+            (len > 1).then(|| (len, Some(TokenType::Directive(source[..len].to_string()))))
+        }
+        '-' | '0'..='9' => {
+            let len = scan_number(source)?;
+            Some((len, Some(TokenType::Number(source[..len].to_string()))))
+        }
+        c if is_identifier_start(c) => {
+            let len = prefix_len(source, is_identifier_char);
+            let token = match &source[..len] {
+                "sequence" => TokenType::Sequence,
+                "oneof" => TokenType::Oneof,
+                "enum" => TokenType::Enum,
+                "package" => TokenType::Package,
+                "import" => TokenType::Import,
+                "alias" => TokenType::Alias,
+                "true" => TokenType::Bool(true),
+                "false" => TokenType::Bool(false),
+                ident => TokenType::Identifier(ident.to_string()),
+            };
+            Some((len, Some(token)))
+        }
+        _ => None,
+    }
+}
 
 /// A specific token type and associated data.
 #[derive(Clone, Debug, PartialEq)]
@@ -85,14 +164,26 @@ pub enum TokenType {
     Sequence,
     Oneof,
     Enum,
+    Package,
+    Import,
+    Alias,
     OpenBrace,
     CloseBrace,
     OpenBracket,
     CloseBracket,
+    OpenAngle,
+    CloseAngle,
     Colon,
     Semicolon,
+    Comma,
     Equals,
+    Dot,
+    DocComment(String),
+    Comment(String),
+    Directive(String),
     Number(String),
+    Bool(bool),
+    String(String),
     Identifier(String),
 }
 
@@ -103,15 +194,27 @@ impl TokenType {
             TokenType::Sequence => "sequence".len(),
             TokenType::Oneof => "oneof".len(),
             TokenType::Enum => "enum".len(),
+            TokenType::Package => "package".len(),
+            TokenType::Import => "import".len(),
+            TokenType::Alias => "alias".len(),
             TokenType::OpenBrace => "{".len(),
             TokenType::CloseBrace => "}".len(),
             TokenType::OpenBracket => "[".len(),
             TokenType::CloseBracket => "]".len(),
+            TokenType::OpenAngle => "<".len(),
+            TokenType::CloseAngle => ">".len(),
             TokenType::Colon => ":".len(),
             TokenType::Semicolon => ";".len(),
+            TokenType::Comma => ",".len(),
             TokenType::Equals => "=".len(),
-            TokenType::Number(val) => val.len(),
-            TokenType::Identifier(val) => val.len(),
+            TokenType::Dot => ".".len(),
+            TokenType::DocComment(val) => "///".len() + val.chars().count(),
+            TokenType::Comment(val) => "//".len() + val.chars().count(),
+            TokenType::Directive(val) => val.chars().count(),
+            TokenType::Number(val) => val.chars().count(),
+            TokenType::Bool(val) => val.to_string().len(),
+            TokenType::String(val) => val.chars().count() + 2,
+            TokenType::Identifier(val) => val.chars().count(),
         }
     }
 }
@@ -123,14 +226,26 @@ impl fmt::Display for TokenType {
             TokenType::Sequence => write!(f, "sequence"),
             TokenType::Oneof => write!(f, "oneof"),
             TokenType::Enum => write!(f, "enum"),
+            TokenType::Package => write!(f, "package"),
+            TokenType::Import => write!(f, "import"),
+            TokenType::Alias => write!(f, "alias"),
             TokenType::OpenBrace => write!(f, "{{"),
             TokenType::CloseBrace => write!(f, "}}"),
             TokenType::OpenBracket => write!(f, "["),
             TokenType::CloseBracket => write!(f, "]"),
+            TokenType::OpenAngle => write!(f, "<"),
+            TokenType::CloseAngle => write!(f, ">"),
             TokenType::Colon => write!(f, ":"),
             TokenType::Semicolon => write!(f, ";"),
+            TokenType::Comma => write!(f, ","),
             TokenType::Equals => write!(f, "="),
+            TokenType::Dot => write!(f, "."),
+            TokenType::DocComment(val) => write!(f, "///{}", val),
+            TokenType::Comment(val) => write!(f, "//{}", val),
+            TokenType::Directive(val) => write!(f, "{}", val),
             TokenType::Number(val) => write!(f, "{}", val),
+            TokenType::Bool(val) => write!(f, "{}", val),
+            TokenType::String(val) => write!(f, "\"{}\"", val),
             TokenType::Identifier(val) => write!(f, "{}", val),
         }
     }
@@ -253,6 +368,12 @@ pub struct Token<'a> {
 }
 
 /// A tokenizer that lazily tokenizes a string.
+///
+/// Tokenizers implement `Iterator<Item = Result<Token, TokenizerError>>`, so tokens can be consumed
+/// one at a time. Whitespace is skipped, as are comments other than doc comments unless the
+/// tokenizer was created with [Tokenizer::with_comments]. The iterator stops after the first
+/// error. Every token carries its [TokenLocation], which is enough to classify and position it in
+/// tools such as syntax highlighters. Use [tokenize] to collect every token at once.
 pub struct Tokenizer<'a> {
     /// The source string to tokenize.
     source: &'a str,
@@ -284,6 +405,9 @@ pub struct Tokenizer<'a> {
 
     /// The next line of text. This is used to display context when an error occurs.
     next_line_text: Option<&'a str>,
+
+    /// Whether comments are returned as tokens instead of being skipped.
+    keep_comments: bool,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -293,7 +417,29 @@ impl<'a> Tokenizer<'a> {
     ///
     /// * `source` - The source string to tokenize.
     /// * `file` - The name of the file being tokenized.
-    pub fn new(source: &'a str, file: &'a str) -> Result<Self, String> {
+    pub fn new(source: &'a str, file: &'a str) -> Result<Self, TokenizerError<'a>> {
+        Self::create(source, file, false)
+    }
+
+    /// Returns a Tokenizer at the beginning of the source string that returns ordinary comments as
+    /// [TokenType::Comment] tokens instead of skipping them. The text of a comment is everything
+    /// after its leading `//`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source string to tokenize.
+    /// * `file` - The name of the file being tokenized.
+    pub fn with_comments(source: &'a str, file: &'a str) -> Result<Self, TokenizerError<'a>> {
+        Self::create(source, file, true)
+    }
+
+    /// Returns a Tokenizer at the beginning of the source string, which skips comments unless
+    /// `keep_comments` is set.
+    fn create(
+        source: &'a str,
+        file: &'a str,
+        keep_comments: bool,
+    ) -> Result<Self, TokenizerError<'a>> {
         let mut lines_iter = source.lines();
         let first_line = lines_iter.next();
         let second_line = lines_iter.next();
@@ -309,8 +455,9 @@ impl<'a> Tokenizer<'a> {
             prev_line_text: None,
             line_text: first_line,
             next_line_text: second_line,
+            keep_comments,
         };
-        tokenizer.advance(true).map_err(|err| err.to_string())?;
+        tokenizer.advance(true)?;
 
         Ok(tokenizer)
     }
@@ -331,83 +478,77 @@ impl<'a> Tokenizer<'a> {
     /// # Arguments
     ///
     /// * `first` - Whether or not this is the first call to `advance`. If this is true, the current
-    ///             token will be ignored.
+    ///   token will be ignored.
     fn advance(&mut self, first: bool) -> Result<(), TokenizerError<'a>> {
         // Check if the tokenizer has reached the end of the source string
         if self.next_token.is_none() && !first {
             return Ok(());
         }
-        if self.cursor >= self.source.len() {
-            self.next_token = None;
-            return Ok(());
-        }
 
-        // Iterate through patterns and try to match them
-        let sliced = &self.source[self.cursor..];
-        for (re, token_fn) in TOKEN_MAP.iter() {
-            match re.find(sliced) {
-                // Pattern doesn't match, continue to next pattern
-                None => continue,
-
-                // Pattern matches, create token
-                Some(val) => {
-                    self.cursor += val.end();
-
-                    // If there is a token function, call it and set the next token
-                    if let Some(token_fn) = token_fn {
-                        let token_type = token_fn(val.as_str().to_string());
-                        self.next_token = Some(Token {
-                            token_type: token_type.clone(),
-                            location: TokenLocation {
-                                file: self.file,
-                                line_num: self.line_num,
-                                col_num: self.col_num,
-                                width: token_type.width(),
-                                prev_line_text: self.prev_line_text,
-                                line_text: self.line_text,
-                                next_line_text: self.next_line_text,
-                            },
-                        });
-                    }
-
-                    // Count newlines and columns
-                    for c in val.as_str().chars() {
-                        if c == '\n' {
-                            self.line_num += 1;
-                            self.col_num = 0;
-                            self.prev_line_text = self.line_text;
-                            self.line_text = self.next_line_text;
-                            self.next_line_text = self.lines_iter.next();
-                        } else {
-                            self.col_num += 1;
-                        }
-                    }
-
-                    // If there is no token function, advance to the next token
-                    if token_fn.is_none() {
-                        self.advance(true)?;
-                    }
-
-                    return Ok(());
+        // Skip whitespace and comments until a token is found
+        loop {
+            if self.cursor >= self.source.len() {
+                self.next_token = None;
+                return Ok(());
+            }
+
+            let Some((len, token_type)) = scan(&self.source[self.cursor..]) else {
+                // If no token can be scanned, return an error
+                self.next_token = None;
+                return Err(TokenizerError::new(&TokenLocation {
+                    file: self.file,
+                    line_num: self.line_num,
+                    col_num: self.col_num,
+                    width: 1,
+                    prev_line_text: self.prev_line_text,
+                    line_text: self.line_text,
+                    next_line_text: self.next_line_text,
+                }));
+            };
+
+            // Set the next token, located at the start of the scanned text
+            let keep_comments = self.keep_comments;
+            let token_type =
+                token_type.filter(|t| keep_comments || !matches!(t, TokenType::Comment(_)));
+            let found = token_type.is_some();
+            if let Some(token_type) = token_type {
+                self.next_token = Some(Token {
+                    location: TokenLocation {
+                        file: self.file,
+                        line_num: self.line_num,
+                        col_num: self.col_num,
+                        width: token_type.width(),
+                        prev_line_text: self.prev_line_text,
+                        line_text: self.line_text,
+                        next_line_text: self.next_line_text,
+                    },
+                    token_type,
+                });
+            }
+
+            // Count newlines and columns. The `\r` of a `\r\n` line break is counted as a column, but
+            // the `\n` that follows resets the column, so both line endings are tracked the same way.
+            let text = &self.source[self.cursor..self.cursor + len];
+            self.cursor += len;
+            for c in text.chars() {
+                if c == '\n' {
+                    self.line_num += 1;
+                    self.col_num = 0;
+                    self.prev_line_text = self.line_text;
+                    self.line_text = self.next_line_text;
+                    self.next_line_text = self.lines_iter.next();
+                } else {
+                    self.col_num += 1;
                 }
             }
-        }
 
-        // If no patterns match, return an error
-        self.next_token = None;
-        Err(TokenizerError::new(&TokenLocation {
-            file: self.file,
-            line_num: self.line_num,
-            col_num: self.col_num,
-            width: 1,
-            prev_line_text: self.prev_line_text,
-            line_text: self.line_text,
-            next_line_text: self.next_line_text,
-        }))
+            if found {
+                return Ok(());
+            }
+        }
     }
 }
 
-pub type TokenIterator<'a> = dyn Iterator<Item = Result<Token<'a>, TokenizerError<'a>>> + 'a;
 impl<'a> Iterator for Tokenizer<'a> {
     type Item = Result<Token<'a>, TokenizerError<'a>>;
 
@@ -415,3 +556,17 @@ impl<'a> Iterator for Tokenizer<'a> {
         self.pop().transpose()
     }
 }
+
+/// Tokenize a whole string.
+///
+/// # Arguments
+///
+/// * `source` - The source string to tokenize.
+/// * `file` - The name of the file being tokenized, used in token locations.
+///
+/// # Returns
+///
+/// Every token in the source, in order, or the first error.
+pub fn tokenize<'a>(source: &'a str, file: &'a str) -> Result<Vec<Token<'a>>, TokenizerError<'a>> {
+    Tokenizer::new(source, file)?.collect()
+}
@@ -0,0 +1,66 @@
+//! Tests for type aliases.
+
+use simplebuffers_compiler::compile_str;
+use simplebuffers_core::{Primitive, Type};
+
+#[test]
+fn aliases_expand_to_their_targets() {
+    let schema = compile_str(
+        "sequence Event {\n    at: Timestamp;\n    tags: Tags;\n    color: Shade;\n}\n\
+         alias Timestamp = u64;\n\
+         alias Tags = [Name];\n\
+         alias Name = string;\n\
+         alias Shade = Color;\n\
+         enum Color {\n    red = 0;\n}\n",
+        "test.sb",
+    )
+    .unwrap();
+    let types: Vec<&Type> = schema.sequences[0].fields.iter().map(|f| &f.ty).collect();
+    assert_eq!(
+        types,
+        [
+            &Type::Primitive(Primitive::U64),
+            &Type::Array(Box::new(Type::String)),
+            &Type::Enum("Color".to_string(), 1),
+        ]
+    );
+
+    // Field offsets use the size of the expanded type.
+    let offsets: Vec<usize> = schema.sequences[0].fields.iter().map(|f| f.index).collect();
+    assert_eq!(offsets, [0, 8, 12]);
+}
+
+#[test]
+fn rejects_names_that_are_taken() {
+    let error = compile_str("sequence A {}\nalias A = u8;\n", "test.sb").unwrap_err();
+    assert!(error.contains("already exists"), "{}", error);
+    assert!(error.contains("test.sb:2:7"), "{}", error);
+
+    let error = compile_str("alias u8 = u16;\n", "test.sb").unwrap_err();
+    assert!(error.contains("is reserved"), "{}", error);
+}
+
+#[test]
+fn rejects_cycles() {
+    let error = compile_str("alias A = B;\nalias B = A;\n", "test.sb").unwrap_err();
+    assert!(error.contains("Alias \"A\" refers to itself"), "{}", error);
+
+    // Cycles through lists and oneofs would also expand forever.
+    let error = compile_str("alias A = [oneof {\n    a: A;\n}];\n", "test.sb").unwrap_err();
+    assert!(error.contains("refers to itself"), "{}", error);
+}
+
+#[test]
+fn rejects_unknown_targets() {
+    let error = compile_str(
+        "alias A = Missing;\nsequence S {\n    a: A;\n}\n",
+        "test.sb",
+    )
+    .unwrap_err();
+    assert!(
+        error.contains("\"Missing\" is not a valid type"),
+        "{}",
+        error
+    );
+    assert!(error.contains("test.sb:1:11"), "{}", error);
+}
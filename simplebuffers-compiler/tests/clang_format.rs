@@ -0,0 +1,80 @@
+//! Tests for the C++ generator's `--clang-format`. A stand-in `clang-format` script is put on the
+//! `PATH`, so these tests do not depend on the real `clang-format` being installed.
+#![cfg(unix)]
+
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::process::{Command, Output};
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+/// A stand-in for `clang-format` that prefixes its input with its arguments.
+const FAKE_CLANG_FORMAT: &str = "#!/bin/sh\necho \"// $*\"\ncat\n";
+
+/// Generates C++ code with `--clang-format` into `dir/out`, with the given `PATH`.
+fn generate(dir: &Path, path: &OsStr) -> Output {
+    let schema = dir.join("point.sb");
+    fs::write(&schema, "sequence Point { x: u16; y: u16; }\n").unwrap();
+    fs::create_dir_all(dir.join("out")).unwrap();
+    Command::new(COMPILER)
+        .env("PATH", path)
+        .arg("--dstdir")
+        .arg(dir.join("out"))
+        .arg("cpp")
+        .arg(&schema)
+        .arg("--clang-format")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn formats_generated_code_through_stdin() {
+    let dir = scratch_dir("fake");
+    let bin = dir.join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    let script = bin.join("clang-format");
+    fs::write(&script, FAKE_CLANG_FORMAT).unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let mut path = OsString::from(&bin);
+    path.push(":");
+    path.push(env::var_os("PATH").unwrap_or_default());
+    let output = generate(&dir, &path);
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+
+    for file in ["point.hpp", "point.cpp"] {
+        let path = dir.join("out").join(file);
+        let contents = fs::read_to_string(&path).unwrap();
+        let first_line = contents.lines().next().unwrap();
+        assert_eq!(
+            first_line,
+            format!("// --style=file --assume-filename={}", path.display())
+        );
+        assert!(contents.contains("PointWriter"));
+    }
+
+    // The corelib is not generated, so it is not formatted.
+    let corelib = fs::read_to_string(dir.join("out").join("simplebuffers.hpp")).unwrap();
+    assert!(!corelib.starts_with("// --style"));
+}
+
+#[test]
+fn warns_without_clang_format() {
+    let dir = scratch_dir("missing");
+    let empty = dir.join("empty");
+    fs::create_dir_all(&empty).unwrap();
+
+    let output = generate(&dir, empty.as_os_str());
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("WARNING: `clang-format` could not be run"));
+    assert!(fs::read_to_string(dir.join("out").join("point.hpp"))
+        .unwrap()
+        .contains("PointWriter"));
+}
@@ -0,0 +1,300 @@
+//! Tests for the compiler's command-line interface.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+#[test]
+fn list_generators_does_not_need_a_schema() {
+    let output = Command::new(COMPILER)
+        .arg("--list-generators")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Bundled generators:\n"));
+    for name in ["sanitycheck", "c++", "cpp", "rust", "testvectors"] {
+        assert!(
+            stdout
+                .lines()
+                .any(|l| l.split_whitespace().next() == Some(name)),
+            "`{}` is not listed:\n{}",
+            name,
+            stdout
+        );
+    }
+    assert!(
+        stdout.contains("[.hpp, .cpp, .cmake]"),
+        "unexpected output:\n{}",
+        stdout
+    );
+    assert!(stdout.contains("Parsed schema, printed for debugging\n"));
+}
+
+#[test]
+fn generator_and_schema_are_required_otherwise() {
+    let output = Command::new(COMPILER).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("<GENERATOR>"));
+}
+
+/// Copies the Graphviz generator library, as a plugin, into a scratch directory. Returns `None` if
+/// the library has not been built.
+fn plugin_dir(name: &str) -> Option<PathBuf> {
+    let file = format!(
+        "{}simplebuffers_dot.{}",
+        std::env::consts::DLL_PREFIX,
+        std::env::consts::DLL_EXTENSION
+    );
+    let lib = Path::new(COMPILER).parent().unwrap().join(&file);
+    if !lib.exists() {
+        eprintln!("skipping: '{}' has not been built", lib.display());
+        return None;
+    }
+    let dir = scratch_dir(name);
+    fs::copy(&lib, dir.join(&file)).unwrap();
+    Some(dir)
+}
+
+#[test]
+fn list_generators_includes_plugins() {
+    let Some(dir) = plugin_dir("list_plugins") else {
+        return;
+    };
+    let output = Command::new(COMPILER)
+        .arg("--list-generators")
+        .arg("--plugin-dir")
+        .arg(&dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (_, plugins) = stdout
+        .split_once("\nGenerators in '")
+        .unwrap_or_else(|| panic!("plugins are not listed:\n{}", stdout));
+    assert!(
+        plugins.contains("\n  dot  Graphviz diagram of the schema [.dot]\n"),
+        "unexpected output:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn plugin_message_goes_to_stderr() {
+    let Some(dir) = plugin_dir("plugin_message") else {
+        return;
+    };
+    let schema = dir.join("point.sb");
+    fs::write(&schema, "sequence Point { x: u16; y: u16; }\n").unwrap();
+    let output = Command::new(COMPILER)
+        .arg("dot")
+        .arg(&schema)
+        .arg("--plugin-dir")
+        .arg(&dir)
+        .arg("-o")
+        .arg(dir.join("out"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Using generator `dot` from '"),
+        "unexpected stderr:\n{}",
+        stderr
+    );
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("Using generator"));
+}
+
+#[test]
+fn aliases_select_the_same_generator() {
+    let dir = scratch_dir("aliases");
+    let schema = dir.join("point.sb");
+    fs::write(&schema, "sequence Point { x: u16; y: u16; }\n").unwrap();
+    for name in ["python", "py"] {
+        let output = Command::new(COMPILER)
+            .arg("-d")
+            .arg(dir.join(name))
+            .arg(name)
+            .arg(&schema)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    let python = fs::read(dir.join("python").join("point.py")).unwrap();
+    let py = fs::read(dir.join("py").join("point.py")).unwrap();
+    assert_eq!(python, py);
+}
+
+#[test]
+fn reports_written_files() {
+    let dir = scratch_dir("written");
+    let schema = dir.join("point.sb");
+    fs::write(&schema, "sequence Point { x: u16; y: u16; }\n").unwrap();
+
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg("csharp")
+        .arg(&schema)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        format!(
+            "Wrote 2 files:\n  {}/SimpleBuffers.cs\n  {}/point.cs\n",
+            dir.display(),
+            dir.display()
+        )
+    );
+
+    // Nothing is written when only checking.
+    let output = Command::new(COMPILER)
+        .arg("--check")
+        .arg("csharp")
+        .arg(&schema)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn help_shows_generator_arguments() {
+    let output = Command::new(COMPILER)
+        .args(["cpp", "--help"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Arguments for the `cpp` generator, given after <FILE>:"));
+    assert!(stdout.contains("--headerdir <HEADERDIR>"));
+    assert!(stdout.contains("--string-type <STRING_TYPE>"));
+
+    // Without a generator, only the compiler's own arguments are shown.
+    let output = Command::new(COMPILER).arg("--help").output().unwrap();
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("--headerdir"));
+}
+
+#[test]
+fn rejects_invalid_generator_arguments() {
+    let dir = scratch_dir("invalid_args");
+    let schema = dir.join("point.sb");
+    fs::write(&schema, "sequence Point { x: u16; y: u16; }\n").unwrap();
+
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg("cpp")
+        .arg(&schema)
+        .arg("--string-type=wide")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "ERROR: Invalid arguments for generator `cpp`: invalid value 'wide' for \
+         '--string-type <STRING_TYPE>'\n"
+    );
+    assert!(!dir.join("point.hpp").exists());
+}
+
+#[test]
+fn empty_schemas_need_allow_empty() {
+    let dir = scratch_dir("empty");
+    let schema = dir.join("empty.sb");
+    fs::write(&schema, "// Nothing yet.\npackage robot;\n").unwrap();
+
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg("cpp")
+        .arg(&schema)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Schema contains no definitions"));
+    assert!(!dir.join("empty.hpp").exists());
+
+    let output = Command::new(COMPILER)
+        .arg("--allow-empty")
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg("cpp")
+        .arg(&schema)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    assert!(dir.join("empty.hpp").exists());
+}
+
+#[test]
+fn sanitycheck_writes_to_output_file() {
+    let dir = scratch_dir("sanitycheck_output");
+    let schema = dir.join("point.sb");
+    fs::write(&schema, "sequence Point { x: u16; y: u16; }\n").unwrap();
+    let printed = Command::new(COMPILER)
+        .arg("sanitycheck")
+        .arg(&schema)
+        .arg("--json")
+        .output()
+        .unwrap();
+    assert!(printed.status.success());
+
+    let output = dir.join("point.json");
+    let written = Command::new(COMPILER)
+        .arg("sanitycheck")
+        .arg(&schema)
+        .arg("--json")
+        .arg("--output")
+        .arg(&output)
+        .output()
+        .unwrap();
+    assert!(written.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&written.stdout),
+        format!("Wrote 1 file: {}\n", output.display())
+    );
+    assert_eq!(fs::read(&output).unwrap(), printed.stdout);
+}
+
+#[test]
+fn stats_are_printed_after_parsing() {
+    let dir = scratch_dir("stats");
+    let schema = dir.join("stats.sb");
+    fs::write(
+        &schema,
+        "enum Kind { A = 0; }\n\n\
+         sequence Point { x: u16; y: u16; z: u32; }\n\n\
+         sequence Shape { kind: Kind; body: oneof { point: Point; radius: f32; }; }\n",
+    )
+    .unwrap();
+
+    let output = Command::new(COMPILER)
+        .args(["--stats", "--check", "cpp"])
+        .arg(&schema)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Schema statistics:\n  Sequences:   2\n  Enums:       1\n  Oneofs:      1\n  \
+         Fields:      7\n  Static size: 4 to 8 bytes\n"
+    );
+}
@@ -0,0 +1,114 @@
+//! Tests for the CMake script written by the C++ generator's `--emit-cmake`.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+const SCHEMA: &str = "sequence Point { x: u16; y: u16; }\n";
+
+/// Generates C++ code for [SCHEMA] into `dir/src` and `dir/include`, and returns the CMake script.
+fn generate(dir: &Path, args: &[&str]) -> String {
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::create_dir_all(dir.join("include")).unwrap();
+    let schema = dir.join("point.sb");
+    fs::write(&schema, SCHEMA).unwrap();
+
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(dir.join("src"))
+        .arg("cpp")
+        .arg(&schema)
+        .arg(format!("--headerdir={}", dir.join("include").display()))
+        .arg("--emit-cmake")
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("point.cmake"));
+    fs::read_to_string(dir.join("src").join("point.cmake")).unwrap()
+}
+
+#[test]
+fn static_library() {
+    let dir = scratch_dir("static");
+    let cmake = generate(&dir, &[]);
+    assert!(cmake.contains("add_library(simplebuffers_point STATIC\n"));
+    assert!(cmake.contains("\"${CMAKE_CURRENT_LIST_DIR}/point.cpp\""));
+    assert!(cmake.contains(
+        "target_include_directories(simplebuffers_point PUBLIC \
+         \"${CMAKE_CURRENT_LIST_DIR}/../include\")"
+    ));
+    assert!(cmake.contains("target_compile_features(simplebuffers_point PUBLIC cxx_std_11)"));
+}
+
+#[test]
+fn header_only_interface_library() {
+    let dir = scratch_dir("header_only");
+    let cmake = generate(&dir, &["--header-only", "--string-type=std"]);
+    assert!(cmake.contains("add_library(simplebuffers_point INTERFACE)"));
+    assert!(!cmake.contains("point.cpp"));
+    assert!(cmake.contains("target_compile_features(simplebuffers_point INTERFACE cxx_std_17)"));
+}
+
+#[test]
+fn project_builds_with_cmake() {
+    let dir = scratch_dir("project");
+    generate(&dir, &[]);
+    fs::write(
+        dir.join("CMakeLists.txt"),
+        "cmake_minimum_required(VERSION 3.10)\n\
+         project(point CXX)\n\
+         include(src/point.cmake)\n\
+         add_executable(main main.cpp)\n\
+         target_link_libraries(main simplebuffers_point)\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("main.cpp"),
+        "#include \"point.hpp\"\n\
+         int main() {\n\
+         \x20   uint8_t buffer[16];\n\
+         \x20   simplebuffers_point::PointWriter writer(1, 2);\n\
+         \x20   return writer.write(buffer, sizeof(buffer)) > 0 ? 0 : 1;\n\
+         }\n",
+    )
+    .unwrap();
+
+    let build = dir.join("build");
+    let configure = match Command::new("cmake")
+        .arg("-S")
+        .arg(&dir)
+        .arg("-B")
+        .arg(&build)
+        .output()
+    {
+        Ok(output) => output,
+        Err(_) => {
+            eprintln!("Skipping CMake build: `cmake` is not available");
+            return;
+        }
+    };
+    assert!(
+        configure.status.success(),
+        "{}",
+        String::from_utf8_lossy(&configure.stderr)
+    );
+    let output = Command::new("cmake")
+        .arg("--build")
+        .arg(&build)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
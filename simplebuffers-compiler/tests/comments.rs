@@ -0,0 +1,94 @@
+//! Tests for collecting ordinary comments into the syntax tree.
+
+use std::collections::HashSet;
+
+use simplebuffers_compiler::ast::{SyntaxTree, TaggedSyntaxTree};
+use simplebuffers_compiler::{AstBuilder, TokenType};
+
+const SCHEMA: &str = "\
+// About the file.
+
+// About Point.
+/// A point.
+sequence Point { // Opens Point.
+    // About x.
+    x: f32; // After x.
+    y: f32 // After y.
+    // Inside Point.
+}
+
+enum Color {
+    red = 0; // After red.
+}
+
+// At the end.
+";
+
+/// Parses `source`, panicking on errors.
+fn parse(source: &str, collect: bool) -> TaggedSyntaxTree<'_> {
+    let builder = match AstBuilder::new(source, "test.sb", HashSet::new()) {
+        Ok(builder) => builder,
+        Err(e) => panic!("{}", e.to_string()),
+    };
+    match builder.collect_comments(collect).parse() {
+        Ok(tree) => tree,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+/// The text of every comment attached to `node`.
+fn comments(node: &TaggedSyntaxTree) -> Vec<String> {
+    node.comments
+        .iter()
+        .map(|c| match &c.token_type {
+            TokenType::Comment(text) => text.trim().to_string(),
+            other => panic!("expected a comment, got {:?}", other),
+        })
+        .collect()
+}
+
+/// The top-level nodes of a file.
+fn items<'t, 'a>(file: &'t TaggedSyntaxTree<'a>) -> &'t [TaggedSyntaxTree<'a>] {
+    match &file.data {
+        SyntaxTree::File(items) => items,
+        _ => panic!("expected a file"),
+    }
+}
+
+#[test]
+fn comments_are_attached_to_nearby_nodes() {
+    let file = parse(SCHEMA, true);
+    assert_eq!(comments(&file), ["At the end."]);
+
+    let items = items(&file);
+    let point = &items[0];
+    assert_eq!(
+        comments(point),
+        ["About the file.", "About Point.", "Inside Point."]
+    );
+    assert_eq!(point.doc.as_deref(), Some("A point."));
+    let fields = match &point.data {
+        SyntaxTree::Sequence(_, fields) => fields,
+        _ => panic!("expected a sequence"),
+    };
+    assert_eq!(
+        comments(&fields[0]),
+        ["Opens Point.", "About x.", "After x."]
+    );
+    assert_eq!(comments(&fields[1]), ["After y."]);
+
+    let entries = match &items[1].data {
+        SyntaxTree::Enum(_, _, entries) => entries,
+        _ => panic!("expected an enum"),
+    };
+    assert_eq!(comments(&entries[0]), ["After red."]);
+}
+
+#[test]
+fn comments_are_skipped_by_default() {
+    let file = parse(SCHEMA, false);
+    assert!(file.comments.is_empty());
+    for item in items(&file) {
+        assert!(item.comments.is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+//! Helpers shared by the integration tests. Each test file declares `mod common;` and uses the
+//! helpers it needs.
+
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The path to the compiler binary.
+pub const COMPILER: &str = env!("CARGO_BIN_EXE_simplebuffers-compiler");
+
+/// Returns an empty scratch directory for a test. Each test file gets its own directory, named
+/// after its test binary, so that test names only have to be unique within a file.
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    let stem = exe.file_stem().unwrap().to_str().unwrap();
+    let file = stem.rsplit_once('-').map_or(stem, |(file, _)| file);
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(file).join(name);
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Runs a command and panics with its output if it fails.
+pub fn run(command: &mut Command) {
+    let output = command
+        .output()
+        .unwrap_or_else(|e| panic!("failed to run {:?}: {}", command, e));
+    assert!(
+        output.status.success(),
+        "{:?} failed:\n{}{}",
+        command,
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Returns the tool named by the environment variable `var`, falling back to `default`, or `None`
+/// if it cannot be run.
+pub fn tool(var: &str, default: &str) -> Option<String> {
+    let tool = env::var(var).unwrap_or_else(|_| default.to_string());
+    if Command::new(&tool).arg("--version").output().is_err() {
+        eprintln!("Skipping: `{}` is not available", tool);
+        return None;
+    }
+    Some(tool)
+}
+
+/// Returns the C++ compiler to use (`CXX`, falling back to `g++`), or `None` if it is not
+/// available.
+pub fn cxx() -> Option<String> {
+    tool("CXX", "g++")
+}
@@ -0,0 +1,161 @@
+//! Cross-implementation conformance tests.
+//!
+//! The messages in `tests/conformance/messages.json` are encoded with the reference Rust codec (via
+//! the `testvectors` generator) and compared against the golden files in
+//! `tests/conformance/golden`. If a C++ compiler is available, the same messages are also written
+//! with the generated C++ writers (see `tests/conformance/driver.cpp`) and compared against the
+//! same golden files. Likewise, if `rustc` is available, the messages are written with the
+//! generated Rust writers (see `tests/conformance/driver.rs`), which also read them back.
+//!
+//! The C++ compiler is taken from the `CXX` environment variable, falling back to `g++`, and the Rust
+//! compiler from `RUSTC`, falling back to `rustc`. If either cannot be run, its half is skipped.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+mod common;
+
+use common::{cxx, run, scratch_dir, tool, COMPILER};
+
+/// Returns the directory containing the conformance fixtures.
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("conformance")
+}
+
+/// Returns the names of every golden file, without the `.bin` extension.
+fn golden_names() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(fixtures().join("golden"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "bin"))
+        .map(|path| path.file_stem().unwrap().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    assert!(!names.is_empty(), "no golden files found");
+    names
+}
+
+/// Asserts that every golden file has an identical counterpart in `dir`.
+fn assert_matches_golden(dir: &Path, implementation: &str) {
+    for name in golden_names() {
+        let file_name = format!("{}.bin", name);
+        let expected = fs::read(fixtures().join("golden").join(&file_name)).unwrap();
+        let actual = fs::read(dir.join(&file_name))
+            .unwrap_or_else(|e| panic!("{} did not write `{}`: {}", implementation, file_name, e));
+        assert_eq!(
+            actual, expected,
+            "{} output for `{}` does not match the golden bytes",
+            implementation, name
+        );
+    }
+}
+
+#[test]
+fn rust_codec_matches_golden() {
+    let out = scratch_dir("rust");
+    run(Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&out)
+        .arg("testvectors")
+        .arg(fixtures().join("conformance.sb"))
+        .arg("--input")
+        .arg(fixtures().join("messages.json")));
+
+    assert_matches_golden(&out, "The Rust codec");
+}
+
+/// Generates C++ code for the conformance schema, compiles the driver against it, and checks the
+/// driver's output against the golden files.
+///
+/// # Arguments
+///
+/// * `cxx` - The C++ compiler to use.
+/// * `name` - A unique name for this build, used for scratch directories.
+/// * `generator_args` - Extra arguments for the C++ generator.
+fn check_cpp(cxx: &str, name: &str, generator_args: &[&str]) {
+    let build = scratch_dir(name);
+    run(Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&build)
+        .arg("cpp")
+        .arg(fixtures().join("conformance.sb"))
+        .args(generator_args));
+
+    let mut compile = Command::new(cxx);
+    compile
+        .arg("-std=c++17")
+        .arg("-I")
+        .arg(&build)
+        .arg("-o")
+        .arg(build.join("driver"))
+        .arg(fixtures().join("driver.cpp"));
+    let source = build.join("conformance.cpp");
+    if source.exists() {
+        compile.arg(source);
+    }
+    run(&mut compile);
+
+    let out = scratch_dir(&format!("{}_out", name));
+    run(Command::new(build.join("driver")).arg(&out));
+
+    assert_matches_golden(&out, "The C++ writer");
+}
+
+#[test]
+fn cpp_writer_matches_golden() {
+    if let Some(cxx) = cxx() {
+        check_cpp(&cxx, "cpp", &[]);
+    }
+}
+
+#[test]
+fn cpp_header_only_writer_matches_golden() {
+    if let Some(cxx) = cxx() {
+        check_cpp(&cxx, "cpp_header_only", &["--header-only"]);
+    }
+}
+
+#[test]
+fn cpp_pragma_once_writer_matches_golden() {
+    if let Some(cxx) = cxx() {
+        check_cpp(&cxx, "cpp_pragma_once", &["--pragma-once"]);
+    }
+}
+
+#[test]
+fn rust_writer_matches_golden() {
+    let Some(rustc) = tool("RUSTC", "rustc") else {
+        return;
+    };
+
+    let build = scratch_dir("rust_writer");
+    run(Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&build)
+        .arg("rust")
+        .arg(fixtures().join("conformance.sb")));
+
+    // The generated module must be warning-free on its own.
+    run(Command::new(&rustc)
+        .args(["--edition", "2021", "--crate-type", "lib", "-D", "warnings"])
+        .arg("--out-dir")
+        .arg(&build)
+        .arg(build.join("conformance.rs")));
+
+    // The driver declares `mod conformance;`, so it has to sit next to the generated module.
+    fs::copy(fixtures().join("driver.rs"), build.join("driver.rs")).unwrap();
+    run(Command::new(&rustc)
+        .args(["--edition", "2021", "-A", "dead_code"])
+        .arg("-o")
+        .arg(build.join("driver"))
+        .arg(build.join("driver.rs")));
+
+    let out = scratch_dir("rust_writer_out");
+    run(Command::new(build.join("driver")).arg(&out));
+
+    assert_matches_golden(&out, "The Rust writer");
+}
@@ -0,0 +1,147 @@
+// Writes every message in messages.json with the generated C++ writers. Each message is written to
+// `<output directory>/<name>.bin`, so it can be compared byte-for-byte with the reference encoder.
+
+#include <cstdio>
+#include <string>
+
+#include "conformance.hpp"
+
+using namespace simplebuffers_conformance;
+using simplebuffers::BytesWriter;
+using simplebuffers::FixedArrayWriter;
+using simplebuffers::ListWriter;
+
+static std::string out_dir;
+
+static bool save(const char* name, const simplebuffers::SimpleBufferWriter& message) {
+    uint8_t buf[1024];
+    int32_t len = message.write(buf, sizeof(buf));
+    if (len < 0) {
+        fprintf(stderr, "Failed to write message `%s`\n", name);
+        return false;
+    }
+
+    std::string path = out_dir + "/" + name + ".bin";
+    FILE* file = fopen(path.c_str(), "wb");
+    if (file == nullptr) {
+        fprintf(stderr, "Failed to open `%s`\n", path.c_str());
+        return false;
+    }
+    fwrite(buf, 1, len, file);
+    fclose(file);
+    return true;
+}
+
+static bool write_full() {
+    PointWriter points[] = {PointWriter(1, 2), PointWriter(3, 4)};
+    const char* names[] = {"a", "bc", ""};
+    Small smalls[] = {Small::A, Small::B, Small::B};
+    uint8_t row0[] = {1, 2, 3};
+    uint8_t row2[] = {255};
+    ListWriter<uint8_t> grid[] = {ListWriter<uint8_t>(row0, 3), ListWriter<uint8_t>(nullptr, 0),
+                                  ListWriter<uint8_t>(row2, 1)};
+    const char* text = "oneof text";
+    uint16_t number0 = 65535;
+    const char* word = "word";
+    uint16_t number2 = 7;
+    MessageWriter::ChoicesWriter choices[] = {MessageWriter::ChoicesWriter::number(&number0),
+                                              MessageWriter::ChoicesWriter::word(&word),
+                                              MessageWriter::ChoicesWriter::number(&number2)};
+
+    MessageWriter message(true, Small::B, Wide::HIGH, 18446744073709551615ull, -123456, 3.25,
+                          "conformance", PointWriter(-1, 2),
+                          LabeledWriter("label", PointWriter(300, -300)),
+                          ListWriter<PointWriter>(points, 2), ListWriter<const char*>(names, 3),
+                          ListWriter<Small>(smalls, 3), ListWriter<ListWriter<uint8_t>>(grid, 3),
+                          MessageWriter::BodyWriter::text(&text),
+                          ListWriter<MessageWriter::ChoicesWriter>(choices, 3));
+    return save("full", message);
+}
+
+static bool write_empty() {
+    Wide wide = Wide::LOW;
+    MessageWriter message(false, Small::A, Wide::LOW, 0, 0, 0.0, "", PointWriter(0, 0),
+                          LabeledWriter("", PointWriter(0, 0)),
+                          ListWriter<PointWriter>(nullptr, 0), ListWriter<const char*>(nullptr, 0),
+                          ListWriter<Small>(nullptr, 0), ListWriter<ListWriter<uint8_t>>(nullptr, 0),
+                          MessageWriter::BodyWriter::wide(&wide),
+                          ListWriter<MessageWriter::ChoicesWriter>(nullptr, 0));
+    return save("empty", message);
+}
+
+static bool write_nested_oneof() {
+    PointWriter points[] = {PointWriter(-5, 5)};
+    const char* names[] = {"only"};
+    Small smalls[] = {Small::B};
+    uint8_t row0[] = {9};
+    ListWriter<uint8_t> grid[] = {ListWriter<uint8_t>(row0, 1)};
+    uint32_t value = 4000000000;
+    MessageWriter::BodyWriter::NestedWriter nested =
+        MessageWriter::BodyWriter::NestedWriter::value(&value);
+    const char* word = "";
+    MessageWriter::ChoicesWriter choices[] = {MessageWriter::ChoicesWriter::word(&word)};
+
+    MessageWriter message(true, Small::A, Wide::LOW, 1, -1, -0.5, "nested", PointWriter(10, 20),
+                          LabeledWriter("nested label", PointWriter(30, 40)),
+                          ListWriter<PointWriter>(points, 1), ListWriter<const char*>(names, 1),
+                          ListWriter<Small>(smalls, 1), ListWriter<ListWriter<uint8_t>>(grid, 1),
+                          MessageWriter::BodyWriter::nested(&nested),
+                          ListWriter<MessageWriter::ChoicesWriter>(choices, 1));
+    return save("nested_oneof", message);
+}
+
+static bool write_oneof_sequence() {
+    const char* names[] = {"one", "two"};
+    ListWriter<uint8_t> grid[] = {ListWriter<uint8_t>(nullptr, 0), ListWriter<uint8_t>(nullptr, 0)};
+    PointWriter point(7, 8);
+    uint16_t number0 = 1;
+    uint16_t number1 = 2;
+    MessageWriter::ChoicesWriter choices[] = {MessageWriter::ChoicesWriter::number(&number0),
+                                              MessageWriter::ChoicesWriter::number(&number1)};
+
+    MessageWriter message(false, Small::B, Wide::HIGH, 42, 2147483647, 1e100, "point",
+                          PointWriter(32767, -32768), LabeledWriter("x", PointWriter(1, 1)),
+                          ListWriter<PointWriter>(nullptr, 0), ListWriter<const char*>(names, 2),
+                          ListWriter<Small>(nullptr, 0), ListWriter<ListWriter<uint8_t>>(grid, 2),
+                          MessageWriter::BodyWriter::point(&point),
+                          ListWriter<MessageWriter::ChoicesWriter>(choices, 2));
+    return save("oneof_sequence", message);
+}
+
+static bool write_fixed_array() {
+    FixedWriter fixed(FixedArrayWriter<uint8_t, 4>{{0xde, 0xad, 0xbe, 0xef}}, 513,
+                      FixedArrayWriter<float, 2>{{1.5f, -2.0f}},
+                      FixedArrayWriter<Wide, 2>{{Wide::HIGH, Wide::LOW}},
+                      FixedArrayWriter<bool, 3>{{true, false, true}}, -2);
+    return save("fixed_array", fixed);
+}
+
+static bool write_bytes() {
+    const uint8_t data[] = {0, 1, 0, 255};
+    const uint8_t chunk1[] = {7};
+    const uint8_t chunk2[] = {1, 2};
+    BytesWriter chunks[] = {BytesWriter(nullptr, 0), BytesWriter(chunk1, 1),
+                            BytesWriter(chunk2, 2)};
+    const uint8_t raw_data[] = {0, 42};
+    BytesWriter raw(raw_data, 2);
+
+    BlobsWriter blobs(BytesWriter(data, 4), ListWriter<BytesWriter>(chunks, 3),
+                      BlobsWriter::PayloadWriter::raw(&raw));
+    return save("bytes", blobs);
+}
+
+int main(int argc, char** argv) {
+    if (argc != 2) {
+        fprintf(stderr, "Usage: %s <output directory>\n", argv[0]);
+        return 2;
+    }
+    out_dir = argv[1];
+
+    bool ok = write_full();
+    ok = write_empty() && ok;
+    ok = write_nested_oneof() && ok;
+    ok = write_oneof_sequence() && ok;
+    ok = write_fixed_array() && ok;
+    ok = write_bytes() && ok;
+    return ok ? 0 : 1;
+}
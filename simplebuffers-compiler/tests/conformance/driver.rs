@@ -0,0 +1,226 @@
+// Writes every message in messages.json with the generated Rust writers. Each message is written to
+// `<output directory>/<name>.bin`, so it can be compared byte-for-byte with the reference encoder.
+// Every message is also read back with the generated readers and checked against what was written.
+
+mod conformance;
+
+use conformance::*;
+use std::{env, fs, process};
+
+fn save(out_dir: &str, name: &str, encode: impl Fn(&mut [u8]) -> Result<usize, rt::Error>) {
+    let mut buf = [0u8; 1024];
+    let len = encode(&mut buf).unwrap_or_else(|e| panic!("Failed to write `{}`: {}", name, e));
+    fs::write(format!("{}/{}.bin", out_dir, name), &buf[..len]).unwrap();
+}
+
+fn write_full(out_dir: &str) {
+    let points = [PointWriter { x: 1, y: 2 }, PointWriter { x: 3, y: 4 }];
+    let names = ["a", "bc", ""];
+    let smalls = [Small::A, Small::B, Small::B];
+    let grid: [&[u8]; 3] = [&[1, 2, 3], &[], &[255]];
+    let choices = [
+        MessageChoicesWriter::Number(65535),
+        MessageChoicesWriter::Word("word"),
+        MessageChoicesWriter::Number(7),
+    ];
+    let message = MessageWriter {
+        flag: true,
+        small: Small::B,
+        wide: Wide::High,
+        count: 18446744073709551615,
+        delta: -123456,
+        ratio: 3.25,
+        name: "conformance",
+        origin: PointWriter { x: -1, y: 2 },
+        labeled: LabeledWriter {
+            label: "label",
+            point: PointWriter { x: 300, y: -300 },
+        },
+        points: &points,
+        names: &names,
+        smalls: &smalls,
+        grid: &grid,
+        body: MessageBodyWriter::Text("oneof text"),
+        choices: &choices,
+    };
+    save(out_dir, "full", |buf| message.encode(buf));
+
+    // Read the message back.
+    let mut buf = [0u8; 1024];
+    let len = message.encode(&mut buf).unwrap();
+    let reader = MessageReader::new(&buf[..len]);
+    assert!(reader.flag());
+    assert_eq!(reader.small(), Some(Small::B));
+    assert_eq!(reader.wide(), Some(Wide::High));
+    assert_eq!(reader.count(), u64::MAX);
+    assert_eq!(reader.delta(), -123456);
+    assert_eq!(reader.ratio(), 3.25);
+    assert_eq!(reader.name(), "conformance");
+    assert_eq!(reader.origin().x(), -1);
+    assert_eq!(reader.labeled().label(), "label");
+    assert_eq!(reader.labeled().point().y(), -300);
+    assert_eq!(reader.points().len(), 2);
+    assert_eq!(reader.points().get(1).x(), 3);
+    assert_eq!(reader.names().iter().collect::<Vec<_>>(), names);
+    assert_eq!(
+        reader.smalls().iter().collect::<Vec<_>>(),
+        [Some(Small::A), Some(Small::B), Some(Small::B)]
+    );
+    let grid_read: Vec<Vec<u8>> = reader.grid().iter().map(|row| row.iter().collect()).collect();
+    assert_eq!(grid_read, [vec![1, 2, 3], vec![], vec![255]]);
+    assert!(matches!(reader.body(), Some(MessageBodyReader::Text("oneof text"))));
+    let choices_read = reader.choices();
+    assert!(matches!(choices_read.get(0), Some(MessageChoicesReader::Number(65535))));
+    assert!(matches!(choices_read.get(1), Some(MessageChoicesReader::Word("word"))));
+    assert!(matches!(choices_read.get(2), Some(MessageChoicesReader::Number(7))));
+}
+
+fn write_empty(out_dir: &str) {
+    let message = MessageWriter {
+        flag: false,
+        small: Small::A,
+        wide: Wide::Low,
+        count: 0,
+        delta: 0,
+        ratio: 0.0,
+        name: "",
+        origin: PointWriter { x: 0, y: 0 },
+        labeled: LabeledWriter {
+            label: "",
+            point: PointWriter { x: 0, y: 0 },
+        },
+        points: &[],
+        names: &[],
+        smalls: &[],
+        grid: &[],
+        body: MessageBodyWriter::Wide(Wide::Low),
+        choices: &[],
+    };
+    save(out_dir, "empty", |buf| message.encode(buf));
+}
+
+fn write_nested_oneof(out_dir: &str) {
+    let message = MessageWriter {
+        flag: true,
+        small: Small::A,
+        wide: Wide::Low,
+        count: 1,
+        delta: -1,
+        ratio: -0.5,
+        name: "nested",
+        origin: PointWriter { x: 10, y: 20 },
+        labeled: LabeledWriter {
+            label: "nested label",
+            point: PointWriter { x: 30, y: 40 },
+        },
+        points: &[PointWriter { x: -5, y: 5 }],
+        names: &["only"],
+        smalls: &[Small::B],
+        grid: &[&[9]],
+        body: MessageBodyWriter::Nested(MessageBodyNestedWriter::Value(4000000000)),
+        choices: &[MessageChoicesWriter::Word("")],
+    };
+    save(out_dir, "nested_oneof", |buf| message.encode(buf));
+
+    let mut buf = [0u8; 1024];
+    let len = message.encode(&mut buf).unwrap();
+    let reader = MessageReader::new(&buf[..len]);
+    assert!(matches!(
+        reader.body(),
+        Some(MessageBodyReader::Nested(Some(
+            MessageBodyNestedReader::Value(4000000000)
+        )))
+    ));
+}
+
+fn write_oneof_sequence(out_dir: &str) {
+    let message = MessageWriter {
+        flag: false,
+        small: Small::B,
+        wide: Wide::High,
+        count: 42,
+        delta: 2147483647,
+        ratio: 1e100,
+        name: "point",
+        origin: PointWriter { x: 32767, y: -32768 },
+        labeled: LabeledWriter {
+            label: "x",
+            point: PointWriter { x: 1, y: 1 },
+        },
+        points: &[],
+        names: &["one", "two"],
+        smalls: &[],
+        grid: &[&[], &[]],
+        body: MessageBodyWriter::Point(PointWriter { x: 7, y: 8 }),
+        choices: &[
+            MessageChoicesWriter::Number(1),
+            MessageChoicesWriter::Number(2),
+        ],
+    };
+    save(out_dir, "oneof_sequence", |buf| message.encode(buf));
+
+    let mut buf = [0u8; 1024];
+    let len = message.encode(&mut buf).unwrap();
+    match MessageReader::new(&buf[..len]).body() {
+        Some(MessageBodyReader::Point(point)) => assert_eq!((point.x(), point.y()), (7, 8)),
+        _ => panic!("Expected a point"),
+    }
+}
+
+fn write_fixed_array(out_dir: &str) {
+    let fixed = FixedWriter {
+        id: [0xde, 0xad, 0xbe, 0xef],
+        before: 513,
+        pos: [1.5, -2.0],
+        wides: [Wide::High, Wide::Low],
+        flags: [true, false, true],
+        after: -2,
+    };
+    save(out_dir, "fixed_array", |buf| fixed.encode(buf));
+
+    let mut buf = [0u8; 1024];
+    let len = fixed.encode(&mut buf).unwrap();
+    let reader = FixedReader::new(&buf[..len]);
+    assert_eq!(reader.id().iter().collect::<Vec<_>>(), [0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(reader.before(), 513);
+    assert_eq!(reader.pos().get(1), -2.0);
+    assert_eq!(reader.wides().get(0), Some(Wide::High));
+    assert_eq!(reader.flags().iter().collect::<Vec<_>>(), [true, false, true]);
+    assert_eq!(reader.after(), -2);
+}
+
+fn write_bytes(out_dir: &str) {
+    let blobs = BlobsWriter {
+        data: &[0, 1, 0, 255],
+        chunks: &[&[], &[7], &[1, 2]],
+        payload: BlobsPayloadWriter::Raw(&[0, 42]),
+    };
+    save(out_dir, "bytes", |buf| blobs.encode(buf));
+
+    let mut buf = [0u8; 1024];
+    let len = blobs.encode(&mut buf).unwrap();
+    let reader = BlobsReader::new(&buf[..len]);
+    assert_eq!(reader.data(), [0, 1, 0, 255]);
+    assert_eq!(reader.chunks().get(2), [1, 2]);
+    assert!(matches!(reader.payload(), Some(BlobsPayloadReader::Raw([0, 42]))));
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 2 {
+        eprintln!("Usage: {} <output directory>", args[0]);
+        process::exit(2);
+    }
+    let out_dir = &args[1];
+
+    write_full(out_dir);
+    write_empty(out_dir);
+    write_nested_oneof(out_dir);
+    write_oneof_sequence(out_dir);
+    write_fixed_array(out_dir);
+    write_bytes(out_dir);
+
+    // A buffer that is too small is reported rather than overflowed.
+    let point = PointWriter { x: 1, y: 2 };
+    assert_eq!(point.encode(&mut [0u8; 3]), Err(rt::Error::NoSpaceLeft));
+}
@@ -0,0 +1,122 @@
+//! Compiles and runs the C++ test programs in `test/cpp`.
+//!
+//! Each program has a schema (`<name>.sb`) and a driver (`<name>.cpp`) that writes and reads
+//! messages with the generated code. The schema is run through both the header and source
+//! generators, and the driver is compiled against the output, so these tests catch generated code
+//! that does not compile as well as code that behaves incorrectly.
+//!
+//! The C++ compiler is taken from the `CXX` environment variable, falling back to `g++`. If it
+//! cannot be run, the tests are skipped.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+mod common;
+
+use common::{cxx, run, scratch_dir, COMPILER};
+
+/// Returns the directory containing the C++ test programs.
+fn programs_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("test")
+        .join("cpp")
+}
+
+/// Generates C++ code for a test program's schema, then compiles and runs the program.
+///
+/// # Arguments
+///
+/// * `name` - The name of the program, without an extension.
+/// * `compiler_args` - Extra arguments for the SimpleBuffers compiler.
+/// * `generator_args` - Extra arguments for the C++ generator.
+fn check_program(name: &str, compiler_args: &[&str], generator_args: &[&str]) {
+    let Some(cxx) = cxx() else {
+        return;
+    };
+    let build = scratch_dir(name);
+    run(Command::new(COMPILER)
+        .args(compiler_args)
+        .arg("--dstdir")
+        .arg(&build)
+        .arg("cpp")
+        .arg(programs_dir().join(format!("{}.sb", name)))
+        .args(generator_args));
+
+    let program = build.join(name);
+    run(Command::new(cxx)
+        .arg("-std=c++17")
+        .arg("-I")
+        .arg(&build)
+        .arg("-o")
+        .arg(&program)
+        .arg(programs_dir().join(format!("{}.cpp", name)))
+        .arg(build.join(format!("{}.cpp", name))));
+    run(&mut Command::new(program));
+}
+
+#[test]
+fn forward_ref() {
+    check_program("forward_ref", &[], &[]);
+}
+
+#[test]
+fn reserved() {
+    check_program("reserved", &["--relax-reserved"], &[]);
+}
+
+#[test]
+fn move_semantics() {
+    check_program("move", &[], &[]);
+}
+
+#[test]
+fn fixed_array() {
+    check_program("fixed_array", &[], &[]);
+}
+
+#[test]
+fn bytes() {
+    check_program("bytes", &[], &[]);
+}
+
+#[test]
+fn oneof_array() {
+    check_program("oneof_array", &[], &[]);
+}
+
+#[test]
+fn std_strings() {
+    check_program("std_strings", &[], &["--string-type=std"]);
+}
+
+#[test]
+fn equality() {
+    check_program("equality", &[], &["--emit-equality"]);
+}
+
+#[test]
+fn ostream() {
+    check_program("ostream", &[], &["--emit-ostream"]);
+}
+
+#[test]
+fn nested_lists() {
+    check_program("nested_lists", &[], &[]);
+}
+
+#[test]
+fn map() {
+    check_program("map", &[], &[]);
+}
+
+#[test]
+fn enum_names() {
+    check_program("enum_names", &[], &["--emit-enum-names"]);
+}
+
+#[test]
+fn big_endian() {
+    check_program("big_endian", &[], &["--endianness=big"]);
+}
@@ -0,0 +1,47 @@
+//! Builds and runs the C# round-trip program in `test/csharp`.
+//!
+//! The schema (`roundtrip.sb`) is run through the C# generator, and the project (`RoundTrip.csproj`
+//! and `Program.cs`) is built together with the output, so this test catches generated code that
+//! does not compile as well as code that behaves incorrectly.
+//!
+//! The .NET SDK is taken from the `DOTNET` environment variable, falling back to `dotnet`. If it
+//! cannot be run, the test is skipped.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+mod common;
+
+use common::{run, scratch_dir, tool, COMPILER};
+
+/// Returns the directory containing the C# test program.
+fn program_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("test")
+        .join("csharp")
+}
+
+#[test]
+fn roundtrip() {
+    let Some(dotnet) = tool("DOTNET", "dotnet") else {
+        return;
+    };
+    // The project builds every source file in its directory, so it is copied next to the
+    // generated code instead of built in place.
+    let build = scratch_dir("roundtrip");
+    for file in ["RoundTrip.csproj", "Program.cs"] {
+        fs::copy(program_dir().join(file), build.join(file)).unwrap();
+    }
+    run(Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(build.join("Generated"))
+        .arg("csharp")
+        .arg(program_dir().join("roundtrip.sb")));
+
+    run(Command::new(dotnet)
+        .arg("run")
+        .arg("--project")
+        .arg(build.join("RoundTrip.csproj")));
+}
@@ -0,0 +1,289 @@
+//! Tests for `--emit-descriptor` and `--from-descriptor`.
+
+use std::fs;
+use std::process::Command;
+
+use simplebuffers_compiler::{compile_str, load_descriptor};
+use simplebuffers_core::{SBSchema, Type};
+
+mod common;
+
+use common::{run, scratch_dir, COMPILER};
+
+const SCHEMA: &str = "\
+/// A color.
+enum Color {
+    red = 0;
+    /// Darker than red.
+    maroon = -1;
+}
+
+sequence Shape {
+    name: string;
+    points: [Point];
+    corners: [u8; 4];
+    blob: bytes;
+    color: Color;
+    style: oneof {
+        fill: Color;
+        pattern: oneof {
+            stripes: u16;
+            dots: [f32];
+        };
+    };
+}
+
+/// A point.
+sequence Point {
+    x: i16;
+    y: i16;
+}
+";
+
+/// Compiles `SCHEMA` with `--emit-descriptor` and returns the descriptor's contents.
+fn emit_descriptor(name: &str) -> String {
+    let dir = scratch_dir(name);
+    let schema_path = dir.join(format!("{}.sb", name));
+    let descriptor_path = dir.join(format!("{}.json", name));
+    fs::write(&schema_path, SCHEMA).unwrap();
+
+    run(Command::new(COMPILER)
+        .arg("--check")
+        .arg("--emit-descriptor")
+        .arg(&descriptor_path)
+        .arg("cpp")
+        .arg(&schema_path));
+    fs::read_to_string(descriptor_path).unwrap()
+}
+
+#[test]
+fn descriptor_round_trips() {
+    let json = emit_descriptor("round_trip");
+    let schema: SBSchema = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string_pretty(&schema).unwrap() + "\n", json);
+    assert_eq!(schema, compile_str(SCHEMA, "shapes.sb").unwrap());
+}
+
+#[test]
+fn descriptor_matches_parsed_schema() {
+    let schema: SBSchema = serde_json::from_str(&emit_descriptor("parsed")).unwrap();
+    assert_eq!(schema.enums[0].name, "Color");
+    assert_eq!(schema.enums[0].variants[1].value, -1);
+    assert_eq!(
+        schema.enums[0].variants[1].doc.as_deref(),
+        Some("Darker than red.")
+    );
+
+    let shape = &schema.sequences[0];
+    assert_eq!(shape.name, "Shape");
+    assert!(matches!(&shape.fields[2].ty, Type::FixedArray(_, 4)));
+    assert!(matches!(&shape.fields[3].ty, Type::Bytes));
+    let Type::OneOf(style) = &shape.fields[5].ty else {
+        panic!("style is not a oneof");
+    };
+    assert!(matches!(&style[1].ty, Type::OneOf(pattern) if pattern.len() == 2));
+    assert_eq!(schema.sequences[1].doc.as_deref(), Some("A point."));
+}
+
+#[test]
+fn generates_same_code_from_descriptor() {
+    let dir = scratch_dir("generate");
+    let schema_path = dir.join("shapes.sb");
+    let descriptor_path = dir.join("shapes.json");
+    fs::write(&schema_path, SCHEMA).unwrap();
+    for (input, out) in [
+        (&schema_path, "from_schema"),
+        (&descriptor_path, "from_descriptor"),
+    ] {
+        fs::create_dir_all(dir.join(out)).unwrap();
+        run(Command::new(COMPILER)
+            .arg("--emit-descriptor")
+            .arg(&descriptor_path)
+            .arg("--dstdir")
+            .arg(dir.join(out))
+            .arg("cpp")
+            .arg(input));
+    }
+
+    for file in ["shapes.hpp", "shapes.cpp"] {
+        assert_eq!(
+            fs::read_to_string(dir.join("from_schema").join(file)).unwrap(),
+            fs::read_to_string(dir.join("from_descriptor").join(file)).unwrap(),
+            "{} differs",
+            file
+        );
+    }
+}
+
+#[test]
+fn from_descriptor_flag_reads_any_extension() {
+    let dir = scratch_dir("flag");
+    let descriptor_path = dir.join("shapes.desc");
+    fs::write(&descriptor_path, emit_descriptor("flag_source")).unwrap();
+    run(Command::new(COMPILER)
+        .arg("--check")
+        .arg("--from-descriptor")
+        .arg("cpp")
+        .arg(&descriptor_path));
+}
+
+/// Loads a descriptor and returns the messages of its errors.
+fn descriptor_errors(json: &str) -> Vec<String> {
+    load_descriptor(json, "test.json")
+        .expect_err("invalid descriptor was loaded")
+        .iter()
+        .map(|e| e.message.clone())
+        .collect()
+}
+
+#[test]
+fn rejects_malformed_descriptor() {
+    let errors = descriptor_errors(r#"{"sequences": []}"#);
+    assert!(errors[0].contains("Invalid descriptor"), "{:?}", errors);
+}
+
+#[test]
+fn rejects_duplicate_names() {
+    let errors = descriptor_errors(
+        r#"{
+            "sequences": [
+                {"name": "A", "fields": [], "doc": null},
+                {"name": "A", "fields": [], "doc": null}
+            ],
+            "enums": [
+                {"name": "E", "size": 1, "doc": null, "variants": [
+                    {"name": "x", "value": 0, "doc": null},
+                    {"name": "x", "value": 1, "doc": null}
+                ]}
+            ]
+        }"#,
+    );
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors[0].contains("Type `A`"), "{:?}", errors);
+    assert!(errors[1].contains("Variant `E::x`"), "{:?}", errors);
+}
+
+#[test]
+fn rejects_invalid_enum_sizes() {
+    let errors = descriptor_errors(
+        r#"{
+            "sequences": [
+                {"name": "A", "fields": [
+                    {"name": "e", "ty": {"enum": ["Small", 2]}, "index": 0, "doc": null},
+                    {"name": "s", "ty": {"sequence": "Missing"}, "index": 2, "doc": null}
+                ], "doc": null}
+            ],
+            "enums": [
+                {"name": "Small", "size": 1, "doc": null, "variants": [
+                    {"name": "big", "value": 300, "doc": null}
+                ]},
+                {"name": "Odd", "size": 3, "doc": null, "variants": []}
+            ]
+        }"#,
+    );
+    assert_eq!(errors.len(), 4, "{:?}", errors);
+    assert!(
+        errors[0].contains("`Small::big` has value 300"),
+        "{:?}",
+        errors
+    );
+    assert!(errors[1].contains("`Odd` has a size of 3"), "{:?}", errors);
+    assert!(
+        errors[2].contains("has size 2, but enum `Small` has size 1"),
+        "{:?}",
+        errors
+    );
+    assert!(
+        errors[3].contains("unknown sequence `Missing`"),
+        "{:?}",
+        errors
+    );
+}
+
+#[test]
+fn rejects_misplaced_fields() {
+    let errors = descriptor_errors(
+        r#"{
+            "sequences": [
+                {"name": "Overlap", "fields": [
+                    {"name": "a", "ty": {"primitive": "u16"}, "index": 0, "doc": null},
+                    {"name": "b", "ty": {"primitive": "u8"}, "index": 1, "doc": null}
+                ], "doc": null},
+                {"name": "Gap", "fields": [
+                    {"name": "a", "ty": {"primitive": "u8"}, "index": 0, "doc": null},
+                    {"name": "b", "ty": {"primitive": "u8"}, "index": 2, "doc": null}
+                ], "doc": null}
+            ],
+            "enums": []
+        }"#,
+    );
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(
+        errors[0].contains("`Overlap::b` has offset 1, which overlaps"),
+        "{:?}",
+        errors
+    );
+    assert!(
+        errors[1].contains("`Gap::b` has offset 2, which leaves a gap"),
+        "{:?}",
+        errors
+    );
+}
+
+#[test]
+fn rejects_oversized_sequences_and_oneofs() {
+    let oneof_fields = (0..257)
+        .map(|i| {
+            format!(
+                r#"{{"name": "f{}", "ty": {{"primitive": "u8"}}, "index": {}, "doc": null}}"#,
+                i, i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let errors = descriptor_errors(&format!(
+        r#"{{
+            "sequences": [
+                {{"name": "Huge", "fields": [
+                    {{"name": "a", "ty": {{"fixed_array": [{{"primitive": "u8"}}, 70000]}},
+                     "index": 0, "doc": null}}
+                ], "doc": null}},
+                {{"name": "Wide", "fields": [
+                    {{"name": "o", "ty": {{"oneof": [{}]}}, "index": 0, "doc": null}}
+                ], "doc": null}}
+            ],
+            "enums": []
+        }}"#,
+        oneof_fields
+    ));
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(
+        errors[0].contains("`Huge` has a static size of 70000 bytes"),
+        "{:?}",
+        errors
+    );
+    assert!(
+        errors[1].contains("`Wide::o` has 257 fields"),
+        "{:?}",
+        errors
+    );
+}
+
+#[test]
+fn rejects_recursive_sequences() {
+    let errors = descriptor_errors(
+        r#"{
+            "sequences": [
+                {"name": "A", "fields": [
+                    {"name": "b", "ty": {"sequence": "B"}, "index": 0, "doc": null}
+                ], "doc": null},
+                {"name": "B", "fields": [
+                    {"name": "a", "ty": {"sequence": "A"}, "index": 0, "doc": null}
+                ], "doc": null}
+            ],
+            "enums": []
+        }"#,
+    );
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].contains("`A` -> `B` -> `A`"), "{:?}", errors);
+}
@@ -0,0 +1,146 @@
+//! Tests that generated files are byte-for-byte identical across runs.
+//!
+//! Each run is a separate process, so any output that depends on the iteration order of a
+//! `HashMap` or `HashSet` would differ between runs, since every process uses different hash keys.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+/// A schema that every bundled generator accepts.
+const SCHEMA: &str = "\
+package robot.messages;
+
+/// Joints of the arm.
+enum Joint : u8 {
+    shoulder = 0;
+    elbow = 1;
+    wrist = 2;
+}
+
+enum Status {
+    idle = 0;
+    moving = 1;
+}
+
+/// A command sent to the robot.
+sequence Request {
+    id: u32;
+    joint: Joint;
+    payload: oneof {
+        setup: Setup;
+        targets: [Target];
+        name: string;
+    };
+}
+
+sequence Setup {
+    status: Status;
+    labels: [string];
+}
+
+sequence Target {
+    joint: Joint;
+    angle: f32;
+    speed: f64;
+}
+";
+
+/// The output of one run of a generator.
+#[derive(Debug, PartialEq)]
+struct Generated {
+    /// What the compiler printed, with the output directory replaced by `<dstdir>`.
+    stdout: String,
+
+    /// The contents of every file that was written, by file name.
+    files: BTreeMap<String, Vec<u8>>,
+}
+
+/// Runs a generator over `schema` into a new directory.
+fn generate(name: &str, generator: &str, schema: &Path, args: &[&Path]) -> Generated {
+    let dir = scratch_dir(name);
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg(generator)
+        .arg(schema)
+        .args(args)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "`{}` failed:\n{}",
+        generator,
+        stdout
+    );
+
+    let files = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            (name, fs::read(&path).unwrap())
+        })
+        .collect();
+    Generated {
+        stdout: stdout.replace(&dir.to_string_lossy().into_owned(), "<dstdir>"),
+        files,
+    }
+}
+
+/// Returns the name of every bundled generator, including aliases.
+fn generator_names() -> Vec<String> {
+    let output = Command::new(COMPILER)
+        .arg("--list-generators")
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split_whitespace().next())
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn every_generator_is_deterministic() {
+    let dir = scratch_dir("schema");
+    let schema = dir.join("robot.sb");
+    fs::write(&schema, SCHEMA).unwrap();
+
+    for generator in generator_names() {
+        // Test vectors need messages to encode, so they are checked separately.
+        if generator == "testvectors" {
+            continue;
+        }
+        let first = generate(&format!("{}_first", generator), &generator, &schema, &[]);
+        let second = generate(&format!("{}_second", generator), &generator, &schema, &[]);
+        assert!(
+            !first.files.is_empty() || !first.stdout.is_empty(),
+            "`{}` generated nothing",
+            generator
+        );
+        assert_eq!(first, second, "`{}` is not deterministic", generator);
+    }
+}
+
+#[test]
+fn testvectors_are_deterministic() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("conformance");
+    let schema = fixtures.join("conformance.sb");
+    let messages = fixtures.join("messages.json");
+    let args = [Path::new("--input"), &messages];
+
+    let first = generate("testvectors_first", "testvectors", &schema, &args);
+    let second = generate("testvectors_second", "testvectors", &schema, &args);
+    assert!(!first.files.is_empty());
+    assert_eq!(first, second);
+}
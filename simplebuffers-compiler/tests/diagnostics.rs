@@ -0,0 +1,96 @@
+//! Tests for `--error-format json`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::Value;
+
+const COMPILER: &str = env!("CARGO_BIN_EXE_simplebuffers-compiler");
+
+/// Writes `schema` to a scratch file and returns its path.
+fn write_schema(name: &str, schema: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("diagnostics");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(format!("{}.sb", name));
+    fs::write(&path, schema).unwrap();
+    path
+}
+
+/// Checks `schema` with JSON diagnostics and returns every diagnostic that was printed.
+fn diagnostics(name: &str, schema: &str) -> Vec<Value> {
+    let path = write_schema(name, schema);
+    let output = Command::new(COMPILER)
+        .args(["--check", "--error-format", "json", "cpp"])
+        .arg(&path)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stderr)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn syntax_error_has_location() {
+    let found = diagnostics("syntax_error", "sequence A {\n    x: [u8,\n}\n");
+    assert_eq!(found.len(), 1);
+    let error = &found[0];
+    assert!(error["file"].as_str().unwrap().ends_with("syntax_error.sb"));
+    assert_eq!(error["severity"], "error");
+    assert_eq!(error["line"], 2);
+    assert_eq!(error["column"], 11);
+    assert_eq!(error["width"], 1);
+    assert!(!error["message"].as_str().unwrap().contains('\x1b'));
+}
+
+#[test]
+fn warnings_are_reported() {
+    let found = diagnostics("warning", "enum Wide {\n    big = 300;\n}\n");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0]["severity"], "warning");
+    assert_eq!(found[0]["line"], 1);
+}
+
+#[test]
+fn errors_without_location_have_null_position() {
+    let found = diagnostics("reserved", "sequence A {\n    class: u8;\n}\n");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0]["severity"], "error");
+    assert!(found[0]["line"].is_null());
+    assert!(found[0]["message"].as_str().unwrap().contains("class"));
+}
+
+#[test]
+fn every_reserved_identifier_is_reported() {
+    let found = diagnostics(
+        "reserved_all",
+        "enum int {\n    auto = 0;\n}\n\nsequence A {\n    class: u8;\n    choice: oneof {\n        \
+         delete: u8;\n        ok: u8;\n    };\n}\n",
+    );
+    let messages: Vec<&str> = found
+        .iter()
+        .map(|d| d["message"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "Enum `int` matches reserved keyword `int`",
+            "Enum variant `int::auto` matches reserved keyword `auto`",
+            "Field `A::class` matches reserved keyword `class`",
+            "Field `A::choice::delete` matches reserved keyword `delete`",
+        ]
+    );
+}
+
+#[test]
+fn boolean_literals_cannot_be_names() {
+    let found = diagnostics("boolean_name", "sequence A {\n    true: u8;\n}\n");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0]["line"], 2);
+    assert!(found[0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("\"true\" is reserved and cannot be used as a name"));
+}
@@ -0,0 +1,153 @@
+//! Tests for comparing two versions of a schema.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use simplebuffers_compiler::compile_str;
+use simplebuffers_core::diff::{diff, Change};
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+/// Compiles both versions of a schema and compares them.
+fn changes(old: &str, new: &str) -> Vec<Change> {
+    diff(
+        &compile_str(old, "old.sb").unwrap(),
+        &compile_str(new, "new.sb").unwrap(),
+    )
+}
+
+/// Formats changes the way the compiler prints them.
+fn describe(changes: &[Change]) -> Vec<String> {
+    changes.iter().map(|c| c.to_string()).collect()
+}
+
+const OLD: &str = "\
+enum Color {
+    red = 0;
+    green = 1;
+}
+
+sequence Point {
+    x: u8;
+    y: u8;
+}
+
+sequence Message {
+    color: Color;
+    points: [Point];
+    payload: oneof {
+        a: u8;
+        b: Point;
+    };
+}
+";
+
+#[test]
+fn identical_schemas_have_no_changes() {
+    assert!(changes(OLD, OLD).is_empty());
+}
+
+#[test]
+fn renames_are_compatible() {
+    let new = OLD
+        .replace("Color", "Colour")
+        .replace("Point", "Position")
+        .replace("color:", "colour:")
+        .replace("green", "lime");
+    assert_eq!(
+        describe(&changes(OLD, &new)),
+        [
+            "compatible: Sequence `Point` was renamed to `Position`",
+            "compatible: Field `Message::color` was renamed to `colour`",
+            "compatible: Enum `Color` was renamed to `Colour`",
+            "compatible: Variant `Color::green` was renamed to `lime`",
+        ]
+    );
+}
+
+#[test]
+fn layout_changes_are_breaking() {
+    let new = OLD
+        .replace("    y: u8;\n", "    y: u16;\n")
+        .replace("        b: Point;\n", "        b: Point;\n        c: u8;\n")
+        .replace("    };\n}", "    };\n    extra: u8;\n}");
+    assert_eq!(
+        describe(&changes(OLD, &new)),
+        [
+            "breaking: `Point::y` changed type from `u8` to `u16`",
+            "compatible: Oneof field `Message::payload.c` was added",
+            "breaking: Field `Message::extra` was added, which changes the size of `Message`",
+        ]
+    );
+
+    let new = OLD.replace("    x: u8;\n", "");
+    assert_eq!(
+        describe(&changes(OLD, &new)),
+        [
+            "breaking: Field `Point::x` was renamed to `y`",
+            "breaking: Field `Point::y` was removed",
+        ]
+        .map(|s| s.replace(
+            "breaking: Field `Point::x` was renamed",
+            "compatible: Field `Point::x` was renamed"
+        ))
+    );
+}
+
+#[test]
+fn enum_changes() {
+    let new = OLD.replace("green = 1;", "green = 300;\n    blue = 2;");
+    assert_eq!(
+        describe(&changes(OLD, &new)),
+        [
+            "breaking: Field `Message::points` moved from offset 1 to 2",
+            "breaking: Field `Message::payload` moved from offset 5 to 6",
+            "breaking: Enum `Color` grew from 1 to 2 bytes",
+            "breaking: Variant `Color::green` changed value from 1 to 300",
+            "compatible: Variant `Color::blue` was added",
+        ]
+    );
+
+    let new = OLD.replace("    green = 1;\n", "");
+    assert_eq!(
+        describe(&changes(OLD, &new)),
+        ["breaking: Variant `Color::green` was removed"]
+    );
+}
+
+#[test]
+fn cli_fails_on_breaking_changes() {
+    let dir = scratch_dir("cli");
+    let old = dir.join("old.sb");
+    let new = dir.join("new.sb");
+    fs::write(&old, OLD).unwrap();
+    fs::write(&new, OLD.replace("y: u8;", "y: u16;")).unwrap();
+
+    let run = |new: &Path| {
+        Command::new(COMPILER)
+            .arg("--diff")
+            .arg(&old)
+            .arg("--check")
+            .arg("sanitycheck")
+            .arg(new)
+            .output()
+            .unwrap()
+    };
+
+    let output = run(&new);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success());
+    assert!(stdout.contains("breaking: `Point::y` changed type from `u8` to `u16`"));
+    assert!(
+        stdout.contains("Found 1 breaking change compared to"),
+        "{}",
+        stdout
+    );
+
+    let output = run(&old);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("No changes compared to"));
+}
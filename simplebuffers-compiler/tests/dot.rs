@@ -0,0 +1,101 @@
+//! Tests for the Graphviz (`dot`) generator.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+const SCHEMA: &str = "\
+/// A color.
+enum Color {
+    red = 0;
+    green = 1;
+}
+
+sequence Shape {
+    name: string;
+    points: [Point];
+    color: Color;
+    style: oneof {
+        fill: Color;
+        pattern: oneof {
+            stripes: u16;
+            dots: [Point];
+        };
+    };
+}
+
+sequence Point {
+    x: i16;
+    y: i16;
+}
+";
+
+/// Generates a graph for [SCHEMA] and returns it.
+fn generate(name: &str) -> (PathBuf, String) {
+    let dir = scratch_dir(name);
+    let schema = dir.join("shapes.sb");
+    fs::write(&schema, SCHEMA).unwrap();
+
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg("dot")
+        .arg(&schema)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "compiler failed:\n{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+
+    let path = dir.join("shapes.dot");
+    let graph = fs::read_to_string(&path).unwrap();
+    (path, graph)
+}
+
+#[test]
+fn draws_sequences_enums_and_references() {
+    let (_, graph) = generate("references");
+
+    assert!(graph.contains("digraph \"shapes\" {"));
+    assert!(graph.contains(
+        "\"Color\" [label=\"{enum Color|red = 0\\lgreen = 1\\l}\", style=filled, \
+         fillcolor=\"#fff2cc\", tooltip=\"A color.\"];"
+    ));
+    assert!(graph.contains("\"Point\" [label=\"{Point|x: i16\\ly: i16\\l}\"];"));
+    assert!(graph.contains("\"Shape\" -> \"Point\" [label=\"points\"];"));
+    assert!(graph.contains("\"Shape\" -> \"Color\" [label=\"color\"];"));
+}
+
+#[test]
+fn draws_oneofs_as_clusters() {
+    let (_, graph) = generate("oneofs");
+
+    assert!(graph.contains("subgraph \"cluster_Shape.style\" {"));
+    assert!(graph.contains("subgraph \"cluster_Shape.style.pattern\" {"));
+    assert!(graph.contains("\"Shape.style.fill\" -> \"Color\" [label=\"fill\"];"));
+    assert!(graph.contains("\"Shape.style.pattern.dots\" -> \"Point\" [label=\"dots\"];"));
+    assert!(graph.contains(
+        "\"Shape\" -> \"Shape.style.fill\" [label=\"style\", lhead=\"cluster_Shape.style\", \
+         style=dashed];"
+    ));
+}
+
+/// Renders the graph with Graphviz, if it is installed, to make sure it is valid.
+#[test]
+fn graph_is_valid_dot() {
+    let (path, _) = generate("valid");
+    match Command::new("dot").arg("-Tsvg").arg(&path).output() {
+        Ok(output) => assert!(
+            output.status.success(),
+            "dot rejected the graph:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ),
+        Err(_) => eprintln!("Skipping Graphviz check: `dot` is not available"),
+    }
+}
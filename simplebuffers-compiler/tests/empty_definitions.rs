@@ -0,0 +1,39 @@
+//! Tests for warnings about empty sequences and enums, and for `--strict`.
+
+use simplebuffers_compiler::{compile, CompileOptions, Severity};
+
+const SCHEMA: &str = "sequence Empty {}\n\nenum Nothing {}\n\nsequence Full {\n    x: u8;\n}\n";
+
+#[test]
+fn empty_definitions_are_warnings() {
+    let mut warnings = vec![];
+    compile(SCHEMA, "test.sb", &CompileOptions::default(), &mut warnings).unwrap();
+    assert_eq!(warnings.len(), 2, "{:#?}", warnings);
+    assert_eq!(warnings[0].severity, Severity::Warning);
+    assert!(warnings[0]
+        .to_string()
+        .contains("Sequence \"Empty\" has no fields"));
+    assert!(warnings[0].to_string().contains("test.sb:1:1"));
+    assert!(warnings[1]
+        .to_string()
+        .contains("Enum \"Nothing\" has no variants"));
+    assert!(warnings[1].to_string().contains("test.sb:3:1"));
+}
+
+#[test]
+fn strict_turns_warnings_into_errors() {
+    let options = CompileOptions {
+        strict: true,
+        ..Default::default()
+    };
+    let mut warnings = vec![];
+    let errors = compile(SCHEMA, "test.sb", &options, &mut warnings).unwrap_err();
+    assert!(warnings.is_empty());
+    assert_eq!(errors.len(), 2, "{:#?}", errors);
+    assert!(errors.iter().all(|e| e.severity == Severity::Error));
+    assert!(errors[0].to_string().contains("ERROR: Sequence \"Empty\""));
+
+    // Schemas without warnings still compile.
+    let schema = "sequence Full {\n    x: u8;\n}\n";
+    assert!(compile(schema, "test.sb", &options, &mut warnings).is_ok());
+}
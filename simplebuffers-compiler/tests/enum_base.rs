@@ -0,0 +1,90 @@
+//! Tests for enums with a declared base type.
+
+use simplebuffers_compiler::{compile, compile_str, CompileOptions};
+use simplebuffers_core::{Primitive, Type};
+
+#[test]
+fn base_type_sets_size() {
+    let source = "enum Flags : u16 {\n    a = 1;\n}\n\nenum Level : i32 {\n    low = 1;\n}\n\n\
+                  sequence S {\n    flags: Flags;\n    level: Level;\n    x:     u8;\n}\n";
+    let schema = compile_str(source, "test.sb").unwrap();
+    assert_eq!(schema.enums[0].size, 2);
+    assert_eq!(schema.enums[0].base, Some(Primitive::U16));
+    assert!(!schema.enums[0].is_signed());
+    assert_eq!(schema.enums[1].size, 4);
+    assert!(schema.enums[1].is_signed());
+
+    // Fields and offsets use the declared size rather than the inferred one.
+    let fields = &schema.sequences[0].fields;
+    assert_eq!(fields[0].ty, Type::Enum("Flags".to_string(), 2));
+    let offsets: Vec<usize> = fields.iter().map(|f| f.index).collect();
+    assert_eq!(offsets, [0, 2, 6]);
+
+    // The base type is kept when formatting.
+    assert_eq!(schema.to_source(false), source);
+}
+
+#[test]
+fn inferred_size_is_unchanged() {
+    let schema = compile_str("enum Small {\n    a = 1;\n}\n", "test.sb").unwrap();
+    assert_eq!(schema.enums[0].size, 1);
+    assert_eq!(schema.enums[0].base, None);
+}
+
+#[test]
+fn rejects_values_that_do_not_fit() {
+    let error = compile_str("enum A : u8 {\n    a = 1;\n    b = 300;\n}\n", "test.sb").unwrap_err();
+    assert!(
+        error.contains("Value \"300\" for enum entry \"A:b\" does not fit in its base type \"u8\""),
+        "{}",
+        error
+    );
+    assert!(error.contains("test.sb:3:5"), "{}", error);
+
+    let error = compile_str("enum A : u32 {\n    a = -1;\n}\n", "test.sb").unwrap_err();
+    assert!(error.contains("does not fit"), "{}", error);
+}
+
+#[test]
+fn rejects_non_integer_base_types() {
+    for base in ["f32", "bool", "string", "Other"] {
+        let error = compile_str(
+            &format!("enum A : {} {{\n    a = 1;\n}}\n", base),
+            "test.sb",
+        )
+        .unwrap_err();
+        assert!(error.contains("must be an integer primitive"), "{}", error);
+        assert!(error.contains("test.sb:1:10"), "{}", error);
+    }
+}
+
+/// Compiles a schema and returns its warnings.
+fn warnings(source: &str) -> Vec<String> {
+    let mut warnings = vec![];
+    compile(source, "test.sb", &CompileOptions::default(), &mut warnings).unwrap();
+    warnings.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn warns_when_a_later_entry_widens_the_enum() {
+    let warnings = warnings("enum Kind {\n    a = 1;\n    b = 2;\n    c = 70000;\n    d = 3;\n}\n");
+    assert_eq!(warnings.len(), 1, "{:#?}", warnings);
+    assert!(
+        warnings[0]
+            .contains("makes enum \"Kind\" 4 bytes wide, while the entries before it fit in 1"),
+        "{}",
+        warnings[0]
+    );
+    assert!(warnings[0].contains("\"u32\""), "{}", warnings[0]);
+    assert!(warnings[0].contains("test.sb:4:5"), "{}", warnings[0]);
+
+    // Negative values make the suggested base type signed.
+    let warnings = self::warnings("enum Kind {\n    a = -1;\n    b = 200;\n}\n");
+    assert!(warnings[0].contains("\"i16\""), "{}", warnings[0]);
+}
+
+#[test]
+fn declared_base_type_silences_size_warnings() {
+    assert!(warnings("enum Kind : u32 {\n    a = 1;\n    c = 70000;\n}\n").is_empty());
+    assert!(warnings("enum Kind {\n    a = 1;\n    b = 2;\n}\n").is_empty());
+}
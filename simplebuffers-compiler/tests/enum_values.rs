@@ -0,0 +1,116 @@
+//! Tests for parsing enum values.
+//!
+//! Each test compiles a small schema with the `sanitycheck` generator and inspects the parsed
+//! schema through its `--json` output.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use serde_json::Value;
+
+const COMPILER: &str = env!("CARGO_BIN_EXE_simplebuffers-compiler");
+
+/// Runs the `sanitycheck` generator with `--json` on `schema`.
+fn run(name: &str, schema: &str) -> Output {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("enum_values");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(format!("{}.sb", name));
+    fs::write(&path, schema).unwrap();
+
+    Command::new(COMPILER)
+        .arg("-d")
+        .arg(&dir)
+        .arg("sanitycheck")
+        .arg(&path)
+        .arg("--json")
+        .output()
+        .unwrap()
+}
+
+/// Compiles `schema` and returns the parsed schema as JSON. Panics if compilation fails.
+fn compile(name: &str, schema: &str) -> Value {
+    let output = run(name, schema);
+    assert!(
+        output.status.success(),
+        "compilation failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+/// Compiles `schema`, which must be rejected, and returns the output. Human-readable errors are
+/// printed to stdout.
+fn compile_error(name: &str, schema: &str) -> String {
+    let output = run(name, schema);
+    assert!(!output.status.success(), "compilation succeeded");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// Returns the values of the variants of the first enum in `schema`.
+fn variant_values(schema: &Value) -> Vec<i64> {
+    schema["enums"][0]["variants"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|variant| variant["value"].as_i64().unwrap())
+        .collect()
+}
+
+#[test]
+fn digit_separators_are_ignored() {
+    let schema = compile(
+        "digit_separators",
+        "enum Big { a = 1_000_000; b = 0xFF_FF; c = 0b1010_0101; }\n",
+    );
+    assert_eq!(
+        variant_values(&schema),
+        vec![1_000_000, 0xFFFF, 0b1010_0101]
+    );
+    assert_eq!(schema["enums"][0]["size"], 4);
+}
+
+#[test]
+fn negative_values_are_signed() {
+    let schema = compile(
+        "negative_values",
+        "enum Temp { freezing = -40; zero = 0; hot = 127; }\n\
+         enum Wide { low = -129; high = 5; }\n",
+    );
+    assert_eq!(variant_values(&schema), vec![-40, 0, 127]);
+    assert_eq!(schema["enums"][0]["size"], 1);
+    assert_eq!(schema["enums"][1]["size"], 2);
+}
+
+#[test]
+fn boolean_literals_are_zero_and_one() {
+    let schema = compile("booleans", "enum Answer { no = false; yes = true; }\n");
+    assert_eq!(variant_values(&schema), vec![0, 1]);
+}
+
+#[test]
+fn u64_max_is_accepted() {
+    let schema = compile(
+        "u64_max",
+        "enum Declared : u64 { zero = 0; max = 0xFFFF_FFFF_FFFF_FFFF; }\n\
+         enum Inferred { zero = 0; max = 18446744073709551615; }\n",
+    );
+    for enm in 0..2 {
+        let variants = &schema["enums"][enm]["variants"];
+        assert_eq!(variants[1]["value"].as_u64(), Some(u64::MAX));
+        assert_eq!(schema["enums"][enm]["size"], 8);
+    }
+}
+
+#[test]
+fn values_beyond_64_bits_are_rejected() {
+    let output = compile_error("beyond_u64", "enum Huge { a = 0x1_0000_0000_0000_0000; }\n");
+    assert!(output.contains("is out of range"), "{}", output);
+
+    let output = compile_error(
+        "beyond_i64",
+        "enum Mixed { low = -1; high = 0xFFFF_FFFF_FFFF_FFFF; }\n",
+    );
+    assert!(output.contains("is out of range"), "{}", output);
+    assert!(output.contains("Mixed:high"), "{}", output);
+}
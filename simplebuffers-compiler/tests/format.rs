@@ -0,0 +1,345 @@
+//! Tests for [SBSchema::to_source], [format_source], and the `format` generator.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use simplebuffers_codegen::{CodeGenerator, GeneratorParams, MemorySink};
+use simplebuffers_compiler::format::FormatCodeGenerator;
+use simplebuffers_compiler::{compile, compile_str, format_source, CompileOptions};
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+const MESSY: &str = "\
+// Requests to the arm.
+sequence Request{
+  id:u32;
+    /// What to do.
+  payload : oneof { init: Init;
+      /// Move somewhere.
+      move_to_position: [MoveTo]; };
+}
+sequence Init {}
+sequence MoveTo { angle: f32; ids: [u8;4]; }
+/// Robot joints.
+///
+/// Sorted by distance from the base.
+enum Joint { wrist = 0x2; base = 0; elbow = 1; }
+";
+
+const FORMATTED: &str = "\
+/// Robot joints.
+///
+/// Sorted by distance from the base.
+enum Joint {
+    wrist = 2;
+    base  = 0;
+    elbow = 1;
+}
+
+sequence Request {
+    id:      u32;
+    /// What to do.
+    payload: oneof {
+        init:             Init;
+        /// Move somewhere.
+        move_to_position: [MoveTo];
+    };
+}
+
+sequence Init {}
+
+sequence MoveTo {
+    angle: f32;
+    ids:   [u8; 4];
+}
+";
+
+/// [MESSY] formatted from its source, which keeps comments, the order of declarations, and the
+/// spelling of numbers.
+const FORMATTED_SOURCE: &str = "\
+// Requests to the arm.
+sequence Request {
+    id:      u32;
+    /// What to do.
+    payload: oneof {
+        init:             Init;
+        /// Move somewhere.
+        move_to_position: [MoveTo];
+    };
+}
+
+sequence Init {}
+
+sequence MoveTo {
+    angle: f32;
+    ids:   [u8; 4];
+}
+
+/// Robot joints.
+///
+/// Sorted by distance from the base.
+enum Joint {
+    wrist = 0x2;
+    base  = 0;
+    elbow = 1;
+}
+";
+
+#[test]
+fn formats_in_canonical_style() {
+    let schema = compile_str(MESSY, "messy.sb").unwrap();
+    assert_eq!(schema.to_source(false), FORMATTED);
+}
+
+#[test]
+fn formatting_round_trips() {
+    let schema = compile_str(MESSY, "messy.sb").unwrap();
+    let formatted = compile_str(&schema.to_source(false), "formatted.sb").unwrap();
+    assert_eq!(formatted, schema);
+
+    // Formatting is idempotent.
+    assert_eq!(formatted.to_source(false), FORMATTED);
+
+    let conformance = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("conformance")
+        .join("conformance.sb");
+    let schema = compile_str(&fs::read_to_string(conformance).unwrap(), "conformance.sb").unwrap();
+    assert_eq!(
+        compile_str(&schema.to_source(false), "formatted.sb").unwrap(),
+        schema
+    );
+}
+
+#[test]
+fn sorts_enum_variants() {
+    let schema = compile_str(MESSY, "messy.sb").unwrap();
+    assert!(schema
+        .to_source(true)
+        .contains("enum Joint {\n    base  = 0;\n    elbow = 1;\n    wrist = 2;\n}"));
+}
+
+#[test]
+fn formats_empty_schema() {
+    let options = CompileOptions {
+        allow_empty: true,
+        ..Default::default()
+    };
+    let schema = compile("// Nothing here.\n", "empty.sb", &options, &mut vec![]).unwrap();
+    assert_eq!(schema.to_source(false), "");
+    assert_eq!(
+        format_source("// Nothing here.  \n\n", "empty.sb", false).unwrap(),
+        "// Nothing here.\n"
+    );
+    assert_eq!(format_source("", "empty.sb", false).unwrap(), "");
+}
+
+#[test]
+fn format_generator_prints_or_writes() {
+    let dir = scratch_dir("generator");
+    let schema = dir.join("messy.sb");
+    fs::write(&schema, MESSY).unwrap();
+
+    let output = Command::new(COMPILER)
+        .arg("format")
+        .arg(&schema)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), FORMATTED_SOURCE);
+
+    // `--write` replaces the schema when the destination is the schema's own directory.
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg("format")
+        .arg(&schema)
+        .arg("--write")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        format!("Wrote 1 file: {}\n", schema.display())
+    );
+    assert_eq!(fs::read_to_string(&schema).unwrap(), FORMATTED_SOURCE);
+}
+
+#[test]
+fn format_generator_refuses_to_write_descriptors() {
+    let dir = scratch_dir("descriptor");
+    let schema = dir.join("messy.sb");
+    let descriptor = dir.join("messy.json");
+    fs::write(&schema, MESSY).unwrap();
+    let output = Command::new(COMPILER)
+        .arg("--emit-descriptor")
+        .arg(&descriptor)
+        .arg("format")
+        .arg(&schema)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Descriptors are printed from the parsed schema, but never written over a schema.
+    let output = Command::new(COMPILER)
+        .arg("format")
+        .arg(&descriptor)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), FORMATTED);
+
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg("format")
+        .arg(&descriptor)
+        .arg("--write")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("`--write` cannot be used"));
+    assert_eq!(fs::read_to_string(&schema).unwrap(), MESSY);
+}
+
+#[test]
+fn format_generator_prints_through_the_sink() {
+    let schema = compile_str(MESSY, "messy.sb").unwrap();
+    let params = GeneratorParams {
+        file_name: "messy".to_string(),
+        dest_dir: "out".to_string(),
+        additional_args: vec!["format".to_string()],
+        relax_reserved: false,
+        source: Some(MESSY.to_string()),
+    };
+    let mut sink = MemorySink::new();
+    let written = FormatCodeGenerator::new()
+        .generate(&schema, &params, &mut sink)
+        .unwrap();
+    assert!(written.is_empty());
+    assert!(sink.files.is_empty());
+    assert_eq!(sink.printed, FORMATTED_SOURCE);
+}
+
+#[test]
+fn formats_source_in_canonical_style() {
+    let formatted = format_source(MESSY, "messy.sb", false).unwrap();
+    assert_eq!(formatted, FORMATTED_SOURCE);
+
+    // Formatting is idempotent, and does not change the schema.
+    assert_eq!(
+        format_source(&formatted, "formatted.sb", false).unwrap(),
+        formatted
+    );
+    assert_eq!(
+        compile_str(&formatted, "formatted.sb").unwrap(),
+        compile_str(MESSY, "messy.sb").unwrap()
+    );
+
+    let conformance = fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("conformance")
+            .join("conformance.sb"),
+    )
+    .unwrap();
+    let formatted = format_source(&conformance, "conformance.sb", false).unwrap();
+    assert_eq!(
+        compile_str(&formatted, "formatted.sb").unwrap(),
+        compile_str(&conformance, "conformance.sb").unwrap()
+    );
+}
+
+#[test]
+fn formatting_source_keeps_everything_but_whitespace() {
+    let source = "\
+// Header.
+package robot.arm ;
+import \"common.sb\";
+
+
+alias Ids=[u8;4];
+sequence Request{
+  id:u32; // The request ID.
+#if DEBUG
+  debug_name: string;
+#else
+  spare: u8;
+#endif
+  lookup: map<string,Ids>
+}
+enum Mode : u8 { off = 0x0; on = 0b1 }
+";
+    let formatted = "\
+// Header.
+package robot.arm;
+import \"common.sb\";
+
+alias Ids = [u8; 4];
+
+sequence Request {
+    id:         u32; // The request ID.
+    #if DEBUG
+    debug_name: string;
+    #else
+    spare:      u8;
+    #endif
+    lookup:     map<string, Ids>;
+}
+
+enum Mode : u8 {
+    off = 0x0;
+    on  = 0b1;
+}
+";
+    assert_eq!(
+        format_source(source, "request.sb", false).unwrap(),
+        formatted
+    );
+    assert_eq!(
+        format_source(formatted, "request.sb", false).unwrap(),
+        formatted
+    );
+}
+
+#[test]
+fn sorts_enum_entries_in_source() {
+    let source = "\
+enum Joint {
+    /// The wrist.
+    wrist = 0x2;
+    base = 0; // The base.
+#if ELBOW
+    elbow = 1;
+#endif
+    hand = -1;
+}
+";
+    let sorted = "\
+enum Joint {
+    base  = 0; // The base.
+    /// The wrist.
+    wrist = 0x2;
+    #if ELBOW
+    elbow = 1;
+    #endif
+    hand  = -1;
+}
+";
+    assert_eq!(format_source(source, "joint.sb", true).unwrap(), sorted);
+}
+
+#[test]
+fn rejects_uneven_if_branches() {
+    let source = "#if A\nsequence S {\n#else\nsequence T {\n#endif\n    x: u8;\n}\n";
+    let errors = format_source(source, "uneven.sb", false).unwrap_err();
+    assert!(errors[0]
+        .message
+        .contains("`#if` branch that ends on line 3"));
+
+    let errors = format_source("sequence S { x u8; }", "invalid.sb", false).unwrap_err();
+    assert!(errors[0].message.contains("Unexpected token"));
+}
@@ -0,0 +1,206 @@
+//! Tests for importing schema files.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use simplebuffers_compiler::CompileOptions;
+use simplebuffers_core::{SBSchema, Type};
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+/// Writes each `(name, source)` pair to a file in `dir`.
+fn write_files(dir: &Path, files: &[(&str, &str)]) {
+    for (name, source) in files {
+        let path = dir.join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, source).unwrap();
+    }
+}
+
+/// Compiles `dir/main.sb`, searching `include_dirs` for imports.
+fn compile(dir: &Path, include_dirs: &[PathBuf]) -> Result<SBSchema, String> {
+    let path = dir.join("main.sb");
+    let source = fs::read_to_string(&path).unwrap();
+    let options = CompileOptions {
+        include_dirs: include_dirs.to_vec(),
+        ..Default::default()
+    };
+    simplebuffers_compiler::compile(&source, path.to_str().unwrap(), &options, &mut vec![]).map_err(
+        |errors| {
+            errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        },
+    )
+}
+
+const COMMON: &str =
+    "enum Level {\n    low = 0;\n    high = 1000;\n}\n\nsequence Header {\n    id: u32;\n}\n";
+
+#[test]
+fn imported_types_can_be_referenced() {
+    let dir = scratch_dir("referenced");
+    write_files(
+        &dir,
+        &[
+            ("common.sb", COMMON),
+            (
+                "main.sb",
+                "import \"common.sb\";\n\nsequence Message {\n    header: Header;\n    level: Level;\n}\n",
+            ),
+        ],
+    );
+    let schema = compile(&dir, &[]).unwrap();
+
+    // Imported types come first.
+    let names: Vec<&str> = schema.sequences.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["Header", "Message"]);
+    assert_eq!(schema.enums[0].name, "Level");
+    assert_eq!(
+        schema.sequences[1].fields[1].ty,
+        Type::Enum("Level".to_string(), 2)
+    );
+}
+
+#[test]
+fn imports_are_searched_in_include_dirs() {
+    let dir = scratch_dir("include_dirs");
+    write_files(
+        &dir,
+        &[
+            ("shared/common.sb", COMMON),
+            (
+                "main.sb",
+                "import \"common.sb\";\nsequence Message {\n    header: Header;\n}\n",
+            ),
+        ],
+    );
+    let error = compile(&dir, &[]).unwrap_err();
+    assert!(
+        error.contains("Could not find import \"common.sb\""),
+        "{}",
+        error
+    );
+
+    assert!(compile(&dir, &[dir.join("shared")]).is_ok());
+}
+
+#[test]
+fn imports_are_searched_in_srcdir() {
+    let dir = scratch_dir("srcdir");
+    write_files(
+        &dir,
+        &[
+            ("common.sb", COMMON),
+            (
+                "schemas/main.sb",
+                "import \"common.sb\";\nsequence Message {\n    header: Header;\n}\n",
+            ),
+        ],
+    );
+    let output = Command::new(COMPILER)
+        .arg("--srcdir")
+        .arg(&dir)
+        .arg("--check")
+        .arg("sanitycheck")
+        .arg("schemas/main.sb")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn shared_imports_are_merged_once() {
+    let dir = scratch_dir("diamond");
+    write_files(
+        &dir,
+        &[
+            ("common.sb", COMMON),
+            (
+                "a.sb",
+                "import \"common.sb\";\nsequence A {\n    header: Header;\n}\n",
+            ),
+            (
+                "b.sb",
+                "import \"common.sb\";\nsequence B {\n    level: Level;\n}\n",
+            ),
+            ("main.sb", "import \"a.sb\";\nimport \"b.sb\";\n"),
+        ],
+    );
+    let schema = compile(&dir, &[]).unwrap();
+    let names: Vec<&str> = schema.sequences.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["Header", "A", "B"]);
+    assert_eq!(schema.enums.len(), 1);
+}
+
+#[test]
+fn rejects_duplicate_names_across_files() {
+    let dir = scratch_dir("duplicate");
+    write_files(
+        &dir,
+        &[
+            ("common.sb", COMMON),
+            (
+                "main.sb",
+                "import \"common.sb\";\n\nsequence Header {\n    id: u8;\n}\n",
+            ),
+        ],
+    );
+    let error = compile(&dir, &[]).unwrap_err();
+    assert!(error.contains("already exists"), "{}", error);
+    assert!(error.contains("main.sb:3:1"), "{}", error);
+}
+
+#[test]
+fn rejects_circular_imports() {
+    let dir = scratch_dir("circular");
+    write_files(
+        &dir,
+        &[
+            ("main.sb", "import \"a.sb\";\n"),
+            ("a.sb", "import \"b.sb\";\n"),
+            ("b.sb", "import \"a.sb\";\n"),
+        ],
+    );
+    let error = compile(&dir, &[]).unwrap_err();
+    let a = dir.join("a.sb");
+    let b = dir.join("b.sb");
+    assert!(
+        error.contains(&format!(
+            "Circular import: {} -> {} -> {}",
+            a.display(),
+            b.display(),
+            a.display()
+        )),
+        "{}",
+        error
+    );
+    assert!(error.contains(&format!("{}:1:1", b.display())), "{}", error);
+}
+
+#[test]
+fn errors_in_imported_files_point_at_them() {
+    let dir = scratch_dir("imported_error");
+    write_files(
+        &dir,
+        &[
+            ("common.sb", "sequence Header {\n    id: Missing;\n}\n"),
+            ("main.sb", "import \"common.sb\";\n"),
+        ],
+    );
+    let error = compile(&dir, &[]).unwrap_err();
+    assert!(
+        error.contains(&format!("{}:2:9", dir.join("common.sb").display())),
+        "{}",
+        error
+    );
+}
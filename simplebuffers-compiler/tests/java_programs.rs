@@ -0,0 +1,62 @@
+//! Compiles and runs the Java round-trip program in `test/java`.
+//!
+//! The schema (`roundtrip.sb`) is run through the Java generator, and the driver
+//! (`RoundTrip.java`) is compiled together with the output, so this test catches generated code
+//! that does not compile as well as code that behaves incorrectly.
+//!
+//! The Java compiler and runtime are taken from the `JAVAC` and `JAVA` environment variables,
+//! falling back to `javac` and `java`. If either cannot be run, the test is skipped.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+mod common;
+
+use common::{run, scratch_dir, tool, COMPILER};
+
+/// Returns the directory containing the Java test program.
+fn program_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("test")
+        .join("java")
+}
+
+/// Returns every `.java` file under `dir`.
+fn java_sources(dir: &Path) -> Vec<PathBuf> {
+    let mut sources = vec![];
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            sources.extend(java_sources(&path));
+        } else if path.extension().is_some_and(|ext| ext == "java") {
+            sources.push(path);
+        }
+    }
+    sources
+}
+
+#[test]
+fn roundtrip() {
+    let (Some(javac), Some(java)) = (tool("JAVAC", "javac"), tool("JAVA", "java")) else {
+        return;
+    };
+    let build = scratch_dir("roundtrip");
+    let generated = build.join("generated");
+    run(Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&generated)
+        .arg("java")
+        .arg(program_dir().join("roundtrip.sb"))
+        .args(["--package", "roundtrip"]));
+
+    let classes = build.join("classes");
+    run(Command::new(javac)
+        .args(["-encoding", "UTF-8", "-Werror"])
+        .arg("-d")
+        .arg(&classes)
+        .args(java_sources(&generated))
+        .arg(program_dir().join("RoundTrip.java")));
+    run(Command::new(java).arg("-cp").arg(&classes).arg("RoundTrip"));
+}
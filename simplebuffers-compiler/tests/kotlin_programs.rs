@@ -0,0 +1,48 @@
+//! Compiles and runs the Kotlin round-trip program in `test/kotlin`.
+//!
+//! The schema (`roundtrip.sb`) is run through the Kotlin generator, and the driver (`RoundTrip.kt`)
+//! is compiled together with the output, so this test catches generated code that does not compile
+//! as well as code that behaves incorrectly.
+//!
+//! The Kotlin compiler and the Java runtime are taken from the `KOTLINC` and `JAVA` environment
+//! variables, falling back to `kotlinc` and `java`. If either cannot be run, the test is skipped.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+mod common;
+
+use common::{run, scratch_dir, tool, COMPILER};
+
+/// Returns the directory containing the Kotlin test program.
+fn program_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("test")
+        .join("kotlin")
+}
+
+#[test]
+fn roundtrip() {
+    let (Some(kotlinc), Some(java)) = (tool("KOTLINC", "kotlinc"), tool("JAVA", "java")) else {
+        return;
+    };
+    let build = scratch_dir("roundtrip");
+    run(Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&build)
+        .arg("kotlin")
+        .arg(program_dir().join("roundtrip.sb"))
+        .args(["--package", "roundtrip"]));
+
+    let generated = build.join("roundtrip");
+    let jar = build.join("roundtrip.jar");
+    run(Command::new(kotlinc)
+        .arg(generated.join("SimpleBuffers.kt"))
+        .arg(generated.join("roundtrip.kt"))
+        .arg(program_dir().join("RoundTrip.kt"))
+        .arg("-include-runtime")
+        .arg("-d")
+        .arg(&jar));
+    run(Command::new(java).arg("-cp").arg(&jar).arg("RoundTripKt"));
+}
@@ -0,0 +1,161 @@
+//! Tests for using the compiler as a library.
+
+use simplebuffers_compiler::{compile, compile_str, CompileOptions};
+
+#[test]
+fn compile_str_returns_schema() {
+    let schema = compile_str(
+        "enum Color { red = 0; green = 1; }\nsequence Point { x: u16; color: Color; }\n",
+        "point.sb",
+    )
+    .unwrap();
+    assert_eq!(schema.enums[0].name, "Color");
+    assert_eq!(schema.sequences[0].name, "Point");
+    assert_eq!(schema.sequences[0].fields[1].index, 2);
+}
+
+#[test]
+fn compile_str_reports_errors() {
+    let error = compile_str("sequence Point { x: Missing; }\n", "point.sb")
+        .expect_err("schema with an unknown type compiled");
+    assert!(error.contains("Missing"), "unexpected error: {}", error);
+    assert!(error.contains("point.sb"), "unexpected error: {}", error);
+}
+
+#[test]
+fn compile_str_rejects_recursive_sequences() {
+    let error = compile_str(
+        "sequence A { x: u8; b: B; }\nsequence B { a: A; }\n",
+        "cycle.sb",
+    )
+    .expect_err("recursive sequence compiled");
+    assert!(
+        error.contains("cycle.sb:2:14"),
+        "unexpected error: {}",
+        error
+    );
+    assert!(
+        error.contains("contain itself"),
+        "unexpected error: {}",
+        error
+    );
+
+    let error = compile_str("sequence Node { child: Node; }\n", "node.sb")
+        .expect_err("self-referencing sequence compiled");
+    assert!(
+        error.contains("node.sb:1:17"),
+        "unexpected error: {}",
+        error
+    );
+}
+
+#[test]
+fn compile_str_allows_recursion_through_lists_and_oneofs() {
+    compile_str(
+        "sequence Node {\n  children: [Node];\n  next: oneof { node: Node; none: u8; };\n}\n",
+        "tree.sb",
+    )
+    .unwrap();
+}
+
+#[test]
+fn compile_str_rejects_oversized_sequences() {
+    compile_str("sequence Big { data: [u8; 65535]; }\n", "big.sb").unwrap();
+
+    let error = compile_str(
+        "enum Wide { a = 0; b = 256; }\nsequence Big { data: [u8; 65534]; wide: Wide; }\n",
+        "big.sb",
+    )
+    .expect_err("oversized sequence compiled");
+    assert!(error.contains("big.sb:2:1"), "unexpected error: {}", error);
+    assert!(error.contains("65536 bytes"), "unexpected error: {}", error);
+}
+
+#[test]
+fn compile_str_allows_leaving_out_the_last_semicolon() {
+    let with = "enum E { a = 0; b = 1; }\nsequence S { e: E; o: oneof { x: u8; y: u16; }; }\n";
+    let without = "enum E { a = 0; b = 1 }\nsequence S { e: E; o: oneof { x: u8; y: u16 } }\n";
+    assert_eq!(
+        compile_str(with, "with.sb").unwrap(),
+        compile_str(without, "without.sb").unwrap()
+    );
+}
+
+#[test]
+fn compile_str_requires_semicolons_between_items() {
+    for source in [
+        "sequence S { a: u8 b: u8 }\n",
+        "enum E { a = 0 b = 1 }\n",
+        "sequence S { o: oneof { a: u8 b: u8 } }\n",
+    ] {
+        let error = compile_str(source, "missing.sb").expect_err(source);
+        assert!(
+            error.contains("expected \";\" or \"}\""),
+            "unexpected error: {}",
+            error
+        );
+    }
+}
+
+#[test]
+fn compile_str_rejects_empty_oneofs() {
+    let error = compile_str(
+        "sequence S {\n    a: u8;\n    b: [oneof {}];\n}\n",
+        "oneof.sb",
+    )
+    .expect_err("empty oneof compiled");
+    assert!(
+        error.contains("A oneof must have at least one field"),
+        "unexpected error: {}",
+        error
+    );
+    assert!(
+        error.contains("oneof.sb:3:9"),
+        "unexpected error: {}",
+        error
+    );
+}
+
+#[test]
+fn compile_str_rejects_oneofs_with_too_many_fields() {
+    let oneof = |count: usize| {
+        let fields: String = (0..count)
+            .map(|i| format!("        f{}: u8;\n", i))
+            .collect();
+        format!("sequence S {{\n    a: oneof {{\n{}    }};\n}}\n", fields)
+    };
+
+    compile_str(&oneof(256), "oneof.sb").expect("oneof with 256 fields did not compile");
+    let error = compile_str(&oneof(257), "oneof.sb").expect_err("oneof with 257 fields compiled");
+    assert!(
+        error.contains("A oneof can have at most 256 fields"),
+        "unexpected error: {}",
+        error
+    );
+    // The error points at the 257th field.
+    assert!(
+        error.contains("oneof.sb:259:9"),
+        "unexpected error: {}",
+        error
+    );
+}
+
+#[test]
+fn optimize_layout_reorders_fields() {
+    let source = "sequence Point { tag: u8; x: f32; name: string; }\n";
+    let options = CompileOptions {
+        optimize_layout: true,
+        ..Default::default()
+    };
+    let schema = compile(source, "point.sb", &options, &mut vec![]).unwrap();
+    let layout: Vec<(&str, usize)> = schema.sequences[0]
+        .fields
+        .iter()
+        .map(|f| (f.name.as_str(), f.index))
+        .collect();
+    assert_eq!(layout, [("x", 0), ("name", 4), ("tag", 6)]);
+
+    // The declared order is kept by default.
+    let schema = compile_str(source, "point.sb").unwrap();
+    assert_eq!(schema.sequences[0].fields[0].name, "tag");
+}
@@ -0,0 +1,205 @@
+//! Tests for map types.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+use simplebuffers_compiler::compile_str;
+use simplebuffers_core::codec::{decode, encode, Value};
+use simplebuffers_core::{Primitive, Type};
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+const SCHEMA: &str = "\
+enum Color {
+    red = 0;
+    green = 1;
+}
+
+sequence Point {
+    x: i16;
+    y: i16;
+}
+
+sequence Scene {
+    counts: map<string, u32>;
+    points: map<Color, Point>;
+    id: u8;
+}
+";
+
+/// Runs a generator on a schema and returns the compiler's output and the directory it wrote to.
+fn generate(name: &str, generator: &str, schema: &str) -> (Output, PathBuf) {
+    let dir = scratch_dir(name);
+    let path = dir.join("schema.sb");
+    fs::write(&path, schema).unwrap();
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg(generator)
+        .arg(&path)
+        .output()
+        .unwrap();
+    (output, dir)
+}
+
+/// Builds a map entry for the codec.
+fn entry(key: Value, value: Value) -> Value {
+    Value::Object(vec![("key".to_string(), key), ("value".to_string(), value)])
+}
+
+#[test]
+fn parses_maps() {
+    let schema = compile_str(SCHEMA, "test.sb").unwrap();
+    let scene = &schema.sequences[1];
+    assert_eq!(
+        scene.fields[0].ty,
+        Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::Primitive(Primitive::U32))
+        )
+    );
+    assert_eq!(
+        scene.fields[1].ty,
+        Type::Map(
+            Box::new(Type::Enum("Color".to_string(), 1)),
+            Box::new(Type::Sequence("Point".to_string()))
+        )
+    );
+
+    // Maps take four bytes in their sequence, like lists.
+    let offsets: Vec<usize> = scene.fields.iter().map(|f| f.index).collect();
+    assert_eq!(offsets, [0, 4, 8]);
+
+    assert!(schema
+        .to_source(false)
+        .contains("counts: map<string, u32>;"));
+}
+
+#[test]
+fn map_is_still_a_valid_name() {
+    let schema = compile_str("sequence map {\n    map: u8;\n}\n", "test.sb").unwrap();
+    assert_eq!(schema.sequences[0].name, "map");
+    assert_eq!(schema.sequences[0].fields[0].name, "map");
+}
+
+#[test]
+fn rejects_invalid_keys() {
+    let error = compile_str(
+        "sequence A {\n    x: u8;\n}\nsequence B {\n    m: map<A, u8>;\n}\n",
+        "test.sb",
+    )
+    .unwrap_err();
+    assert!(
+        error.contains("Map keys can only be primitives, enums, and strings"),
+        "{}",
+        error
+    );
+    assert!(error.contains("test.sb:5:12"), "{}", error);
+
+    let error = compile_str("sequence B {\n    m: map<[u8], u8>;\n}\n", "test.sb").unwrap_err();
+    assert!(error.contains("Map keys can only be"), "{}", error);
+}
+
+#[test]
+fn rejects_fixed_array_values() {
+    let error = compile_str("sequence B {\n    m: map<u8, [u8; 4]>;\n}\n", "test.sb").unwrap_err();
+    assert!(error.contains("a map value"), "{}", error);
+}
+
+#[test]
+fn codec_round_trip() {
+    let schema = compile_str(SCHEMA, "test.sb").unwrap();
+    let point = |x: i64, y: i64| {
+        Value::Object(vec![
+            ("x".to_string(), Value::Int(x)),
+            ("y".to_string(), Value::Int(y)),
+        ])
+    };
+    let value = Value::Object(vec![
+        (
+            "counts".to_string(),
+            Value::List(vec![
+                entry(Value::String("a".to_string()), Value::UInt(1)),
+                entry(Value::String("bc".to_string()), Value::UInt(70000)),
+            ]),
+        ),
+        (
+            "points".to_string(),
+            Value::List(vec![entry(
+                Value::String("green".to_string()),
+                point(-1, 2),
+            )]),
+        ),
+        ("id".to_string(), Value::UInt(9)),
+    ]);
+
+    let data = encode(&schema, "Scene", &value).unwrap();
+    let decoded = decode(&schema, "Scene", &data).unwrap();
+    assert_eq!(decoded.get("id"), Some(&Value::UInt(9)));
+    assert_eq!(decoded.get("counts"), value.get("counts"));
+    assert_eq!(decoded.get("points"), value.get("points"));
+
+    let error = encode(
+        &schema,
+        "Scene",
+        &Value::Object(vec![
+            ("counts".to_string(), Value::List(vec![Value::UInt(1)])),
+            ("points".to_string(), Value::List(vec![])),
+            ("id".to_string(), Value::UInt(0)),
+        ]),
+    )
+    .unwrap_err();
+    assert!(error.contains("counts[0]"), "{}", error);
+    assert!(error.contains("map entry"), "{}", error);
+}
+
+#[test]
+fn unsupported_generators_reject_maps() {
+    let (output, _) = generate("rust", "rust", SCHEMA);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stdout)
+            .contains("The Rust generator does not support maps"),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn cpp_rejects_oneof_values() {
+    let (output, _) = generate(
+        "cpp_oneof",
+        "cpp",
+        "sequence A {\n    m: map<u8, oneof {\n        a: u8;\n    }>;\n}\n",
+    );
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stdout)
+            .contains("does not support oneofs as map values (field `m`)"),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn proto_uses_native_maps() {
+    let (output, dir) = generate("proto", "proto", SCHEMA);
+    assert!(output.status.success());
+    let proto = fs::read_to_string(dir.join("schema.proto")).unwrap();
+    assert!(
+        proto.contains("map<string, uint32> counts = 1;"),
+        "{}",
+        proto
+    );
+
+    // Enum keys are not allowed in native maps.
+    assert!(proto.contains("message PointsEntry {"), "{}", proto);
+    assert!(
+        proto.contains("repeated PointsEntry points = 2;"),
+        "{}",
+        proto
+    );
+}
@@ -0,0 +1,74 @@
+//! Tests for `package` declarations.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use simplebuffers_compiler::compile_str;
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+const SCHEMA: &str = "package robot.messages;\n\nsequence Point {\n    x: u16;\n}\n";
+
+/// Generates C++ code for [SCHEMA] into `dir` and returns the header.
+fn generate_cpp(dir: &Path, args: &[&str]) -> String {
+    let schema = dir.join("point.sb");
+    fs::write(&schema, SCHEMA).unwrap();
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(dir)
+        .arg("cpp")
+        .arg(&schema)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    fs::read_to_string(dir.join("point.hpp")).unwrap()
+}
+
+#[test]
+fn parses_package() {
+    let schema = compile_str(SCHEMA, "point.sb").unwrap();
+    assert_eq!(schema.package.as_deref(), Some("robot.messages"));
+    assert_eq!(schema.to_source(false), SCHEMA);
+
+    let schema = compile_str("sequence Point {\n    x: u16;\n}\n", "point.sb").unwrap();
+    assert_eq!(schema.package, None);
+}
+
+#[test]
+fn rejects_second_package() {
+    let error = compile_str("package a;\npackage b;\n", "test.sb").unwrap_err();
+    assert!(error.contains("already declared"), "{}", error);
+    assert!(error.contains("test.sb:2:1"), "{}", error);
+}
+
+#[test]
+fn rejects_documented_package() {
+    let error = compile_str("/// Doc\npackage a;\n", "test.sb").unwrap_err();
+    assert!(error.contains("after a doc comment"), "{}", error);
+}
+
+#[test]
+fn cpp_namespace_defaults_to_package() {
+    let dir = scratch_dir("cpp_default");
+    let header = generate_cpp(&dir, &[]);
+    assert!(header.contains("namespace robot {\nnamespace messages {\n"));
+    assert!(header.contains("} // namespace messages\n} // namespace robot"));
+    let source = fs::read_to_string(dir.join("point.cpp")).unwrap();
+    assert!(source.contains("namespace robot {\nnamespace messages {\n"));
+}
+
+#[test]
+fn cpp_namespace_option_overrides_package() {
+    let dir = scratch_dir("cpp_option");
+    let header = generate_cpp(&dir, &["--namespace", "app::wire"]);
+    assert!(header.contains("namespace app {\nnamespace wire {\n"));
+    assert!(!header.contains("namespace robot"));
+}
@@ -0,0 +1,98 @@
+//! Tests for the Protocol Buffers (`proto`) generator.
+
+use std::fs;
+use std::process::{Command, Output};
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+/// Runs the `proto` generator on a schema and returns the compiler's output and the generated
+/// file, if one was written.
+fn generate(name: &str, schema: &str) -> (Output, Option<String>) {
+    let dir = scratch_dir(name);
+    let path = dir.join("schema.sb");
+    fs::write(&path, schema).unwrap();
+
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg("proto")
+        .arg(&path)
+        .output()
+        .unwrap();
+    let proto = fs::read_to_string(dir.join("schema.proto")).ok();
+    (output, proto)
+}
+
+#[test]
+fn maps_sequences_enums_and_oneofs() {
+    let (output, proto) = generate(
+        "mapping",
+        "\
+/// A joint.
+enum Joint {
+    j1 = 1;
+    j2 = 2;
+}
+
+sequence Request {
+    id: u16;
+    joints: [Joint];
+    grid: [[u8]];
+    payload: oneof {
+        id: u32;
+        label: string;
+        names: [string];
+    };
+}
+",
+    );
+    assert!(output.status.success());
+    let proto = proto.unwrap();
+
+    assert!(proto.contains("syntax = \"proto3\";"));
+    assert!(proto.contains(
+        "// A joint.\nenum Joint {\n  JOINT_UNSPECIFIED = 0;\n  JOINT_J_1 = 1;\n  JOINT_J_2 = 2;\n}"
+    ));
+    assert!(proto.contains("  uint32 id = 1;  // u16\n"));
+    assert!(proto.contains("  repeated Joint joints = 2;\n"));
+    assert!(proto.contains("  repeated GridItem grid = 3;\n"));
+
+    // Oneof members are numbered after the fields before them, and are prefixed so that they do
+    // not clash with the fields of the message.
+    assert!(proto.contains(
+        "  oneof payload {\n    uint32 payload_id = 4;\n    string payload_label = 5;\n    \
+         PayloadNames payload_names = 6;\n  }\n"
+    ));
+    assert!(proto.contains("  message PayloadNames {\n    repeated string values = 1;\n  }\n"));
+    assert!(proto.contains("  message GridItem {\n    repeated uint32 values = 1;  // u8\n  }\n"));
+}
+
+#[test]
+fn keeps_existing_zero_values_first() {
+    let (output, proto) = generate(
+        "zero",
+        "enum Level {\n    high = 2;\n    low = -1;\n    off = 0;\n}\n",
+    );
+    assert!(output.status.success());
+    assert!(proto
+        .unwrap()
+        .contains("enum Level {\n  LEVEL_OFF = 0;\n  LEVEL_HIGH = 2;\n  LEVEL_LOW = -1;\n}"));
+}
+
+#[test]
+fn rejects_enum_values_outside_int32() {
+    let (output, proto) = generate("int32", "enum Big {\n    huge = 3000000000;\n}\n");
+    assert!(!output.status.success());
+    assert!(proto.is_none());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains(
+            "Variant `huge` of enum `Big` has the value 3000000000, which does not fit in a \
+             Protocol Buffers enum"
+        ),
+        "unexpected output:\n{}",
+        stdout
+    );
+}
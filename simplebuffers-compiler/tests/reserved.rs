@@ -0,0 +1,112 @@
+//! Tests for the reserved identifier check.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+mod common;
+
+use common::{scratch_dir, COMPILER};
+
+/// Writes `schema` to `dir` and runs a generator over it.
+fn run(dir: &Path, generator: &str, schema: &str) -> Output {
+    let path = dir.join("schema.sb");
+    fs::write(&path, schema).unwrap();
+    Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(dir)
+        .arg(generator)
+        .arg(&path)
+        .output()
+        .unwrap()
+}
+
+const CAPITALIZED: &str = "sequence Loop {\n    While: u8;\n}\n";
+
+#[test]
+fn cpp_matches_case_sensitively() {
+    let dir = scratch_dir("cpp_case");
+    let output = run(&dir, "cpp", CAPITALIZED);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+
+    // The field still becomes a keyword in snake case, so it is escaped.
+    let header = fs::read_to_string(dir.join("schema.hpp")).unwrap();
+    assert!(header.contains("while_()"), "{}", header);
+
+    let output = run(&dir, "cpp", "sequence Loop {\n    while: u8;\n}\n");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn other_generators_match_case_insensitively() {
+    let dir = scratch_dir("java_case");
+    let output = run(&dir, "java", CAPITALIZED);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("matches reserved keyword"));
+}
+
+#[test]
+fn cpp_does_not_reserve_sequence_names() {
+    let schema = "sequence class {\n    x: u8;\n}\n";
+
+    let dir = scratch_dir("cpp_sequence");
+    let output = run(&dir, "cpp", schema);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let header = fs::read_to_string(dir.join("schema.hpp")).unwrap();
+    assert!(header.contains("class ClassWriter"), "{}", header);
+
+    // Fields are still checked.
+    let output = run(&dir, "cpp", "sequence Loop {\n    class: u8;\n}\n");
+    assert!(!output.status.success());
+
+    let dir = scratch_dir("java_sequence");
+    let output = run(&dir, "java", schema);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn graphql_accepts_test_schema() {
+    let dir = scratch_dir("graphql_test_schema");
+    let schema = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("test")
+        .join("test.sb");
+    let output = Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&dir)
+        .arg("graphql")
+        .arg(schema)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn graphql_matches_type_names_case_sensitively() {
+    let dir = scratch_dir("graphql_case");
+    let output = run(&dir, "graphql", "sequence String {\n    x: u8;\n}\n");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("matches reserved keyword"));
+
+    // The name only becomes a built-in type in Pascal case, so it is escaped.
+    let output = run(&dir, "graphql", "sequence string {\n    x: u8;\n}\n");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let generated = fs::read_to_string(dir.join("schema.graphql")).unwrap();
+    assert!(generated.contains("type String_ {"), "{}", generated);
+}
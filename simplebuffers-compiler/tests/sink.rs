@@ -0,0 +1,82 @@
+//! Tests for the file sinks that generators write through.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use simplebuffers_codegen::{CodeGenerator, FileSink, FileSystemSink, GeneratorParams, MemorySink};
+use simplebuffers_compiler::compile_str;
+use simplebuffers_cpp::CPPCodeGenerator;
+
+const SCHEMA: &str =
+    "sequence Message {\n    items: [oneof {\n        a: u8;\n        b: string;\n    }];\n}\n";
+
+/// Returns the parameters for a generator that writes to `dest_dir`.
+fn params(generator: &str, dest_dir: &str) -> GeneratorParams {
+    GeneratorParams {
+        file_name: "schema".to_string(),
+        dest_dir: dest_dir.to_string(),
+        additional_args: vec![generator.to_string()],
+        relax_reserved: false,
+        source: None,
+    }
+}
+
+#[test]
+fn memory_sink_keeps_cpp_output_off_disk() {
+    let dest_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("sink/never_created");
+    let schema = compile_str(SCHEMA, "schema.sb").unwrap();
+    let mut sink = MemorySink::new();
+    let written = CPPCodeGenerator::new()
+        .generate(
+            &schema,
+            &params("cpp", dest_dir.to_str().unwrap()),
+            &mut sink,
+        )
+        .unwrap();
+
+    assert!(!dest_dir.exists());
+    assert_eq!(written.len(), 3, "{:?}", written);
+    for path in &written {
+        assert!(
+            sink.get(path).is_some(),
+            "{} was not written",
+            path.display()
+        );
+    }
+
+    // Lists of oneofs are read with the oneof's reader, which reads its tag from its own data.
+    let source = sink.get(dest_dir.join("schema.cpp")).unwrap();
+    assert!(
+        source.contains("simplebuffers::ListReader<MessageReader::ItemsReader"),
+        "{}",
+        source
+    );
+    assert!(
+        source.contains("tag_ = static_cast<Tag>(simplebuffers::read_field<uint8_t>(data_ptr_))"),
+        "{}",
+        source
+    );
+}
+
+#[test]
+fn file_system_sink_creates_directories() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("sink/file_system");
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    let path: PathBuf = dir.join("nested/file.txt");
+    FileSystemSink.write_file(&path, "contents").unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "contents");
+}
+
+#[test]
+fn memory_sink_rejects_binary_files() {
+    let mut sink = MemorySink::new();
+    sink.write_bytes(Path::new("text.bin"), b"text").unwrap();
+    assert_eq!(sink.get("text.bin"), Some("text"));
+
+    let error = sink
+        .write_bytes(Path::new("binary.bin"), &[0xff, 0xfe])
+        .unwrap_err();
+    assert!(error.contains("binary.bin"), "{}", error);
+}
@@ -0,0 +1,44 @@
+//! Compiles and runs the Swift round-trip program in `test/swift`.
+//!
+//! The schema (`roundtrip.sb`) is run through the Swift generator, and the driver (`main.swift`) is
+//! compiled together with the output, so this test catches generated code that does not compile as
+//! well as code that behaves incorrectly.
+//!
+//! The Swift compiler is taken from the `SWIFTC` environment variable, falling back to `swiftc`. If
+//! it cannot be run, the test is skipped.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+mod common;
+
+use common::{run, scratch_dir, tool, COMPILER};
+
+/// Returns the directory containing the Swift test program.
+fn program_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("test")
+        .join("swift")
+}
+
+#[test]
+fn roundtrip() {
+    let Some(swiftc) = tool("SWIFTC", "swiftc") else {
+        return;
+    };
+    let build = scratch_dir("roundtrip");
+    run(Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(&build)
+        .arg("swift")
+        .arg(program_dir().join("roundtrip.sb")));
+
+    let program = build.join("roundtrip");
+    run(Command::new(swiftc)
+        .arg("-o")
+        .arg(&program)
+        .arg(build.join("roundtrip.swift"))
+        .arg(program_dir().join("main.swift")));
+    run(&mut Command::new(program));
+}
@@ -0,0 +1,282 @@
+//! Tests for the tokenizer.
+
+use simplebuffers_compiler::tokenize as tokenize_all;
+use simplebuffers_compiler::tokenizer::{TokenType, Tokenizer};
+
+/// Tokenizes `source`, panicking on errors.
+fn tokenize(source: &str) -> Vec<TokenType> {
+    Tokenizer::new(source, "test.sb")
+        .unwrap()
+        .map(|token| token.unwrap().token_type)
+        .collect()
+}
+
+/// Tokenizes `source` until an error, and returns the line and column of the error (0-indexed).
+fn error_position(source: &str) -> (usize, usize) {
+    let error = match Tokenizer::new(source, "test.sb") {
+        Ok(mut tokenizer) => tokenizer.find_map(|token| token.err()).unwrap(),
+        Err(e) => e,
+    };
+    (error.location.line_num, error.location.col_num)
+}
+
+fn ident(name: &str) -> TokenType {
+    TokenType::Identifier(name.to_string())
+}
+
+fn number(value: &str) -> TokenType {
+    TokenType::Number(value.to_string())
+}
+
+#[test]
+fn keywords_are_whole_identifiers() {
+    assert_eq!(
+        tokenize("sequence sequences enum enumeration oneof oneofs package _ _id true falsey"),
+        [
+            TokenType::Sequence,
+            ident("sequences"),
+            TokenType::Enum,
+            ident("enumeration"),
+            TokenType::Oneof,
+            ident("oneofs"),
+            TokenType::Package,
+            ident("_"),
+            ident("_id"),
+            TokenType::Bool(true),
+            ident("falsey"),
+        ]
+    );
+}
+
+#[test]
+fn numbers() {
+    assert_eq!(
+        tokenize("0 -12 1_000 0xFF_ff -0b101 1.5 12ab"),
+        [
+            number("0"),
+            number("-12"),
+            number("1_000"),
+            number("0xFF_ff"),
+            number("-0b101"),
+            number("1.5"),
+            number("12"),
+            ident("ab"),
+        ]
+    );
+}
+
+#[test]
+fn comments_and_punctuation() {
+    assert_eq!(
+        tokenize("/// Doc\n//// Not doc\n// Comment\r\n#if x{}[]<>:;,=.\n///\n"),
+        [
+            TokenType::DocComment(" Doc".to_string()),
+            TokenType::Directive("#if".to_string()),
+            ident("x"),
+            TokenType::OpenBrace,
+            TokenType::CloseBrace,
+            TokenType::OpenBracket,
+            TokenType::CloseBracket,
+            TokenType::OpenAngle,
+            TokenType::CloseAngle,
+            TokenType::Colon,
+            TokenType::Semicolon,
+            TokenType::Comma,
+            TokenType::Equals,
+            TokenType::Dot,
+            TokenType::DocComment(String::new()),
+        ]
+    );
+}
+
+#[test]
+fn token_locations() {
+    let tokens: Vec<_> = Tokenizer::new("enum\n  /// Doc\n  x = 1;", "test.sb")
+        .unwrap()
+        .map(|token| {
+            let location = token.unwrap().location;
+            (location.line_num, location.col_num, location.width)
+        })
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            (0, 0, 4),
+            (1, 2, 7),
+            (2, 2, 1),
+            (2, 4, 1),
+            (2, 6, 1),
+            (2, 7, 1)
+        ]
+    );
+}
+
+#[test]
+fn invalid_tokens() {
+    assert_eq!(error_position("x: 1$;"), (0, 4));
+    assert_eq!(error_position("enum\n  - 1"), (1, 2));
+    assert_eq!(error_position("# x"), (0, 0));
+    assert_eq!(error_position("x €"), (0, 2));
+    // Comments must end with a line break.
+    assert_eq!(error_position("x // end"), (0, 2));
+    // Strings must end on the line they start on.
+    assert_eq!(error_position("import \"a.sb\n\";"), (0, 7));
+}
+
+#[test]
+fn strings() {
+    assert_eq!(
+        tokenize("import \"dir/a b.sb\"; \"\""),
+        [
+            TokenType::Import,
+            TokenType::String("dir/a b.sb".to_string()),
+            TokenType::Semicolon,
+            TokenType::String(String::new()),
+        ]
+    );
+}
+
+/// Tokenizes `source` and returns every token with its location, panicking on errors.
+fn tokenize_with_locations(source: &str) -> Vec<(TokenType, usize, usize, usize, Option<&str>)> {
+    Tokenizer::new(source, "test.sb")
+        .unwrap()
+        .map(|token| {
+            let token = token.unwrap();
+            let location = token.location;
+            (
+                token.token_type,
+                location.line_num,
+                location.col_num,
+                location.width,
+                location.line_text,
+            )
+        })
+        .collect()
+}
+
+const LF_SOURCE: &str = "// Comment\n/// Doc\nenum E {\n    a = 1;\n}\n";
+
+#[test]
+fn crlf_is_tracked_like_lf() {
+    let crlf = LF_SOURCE.replace('\n', "\r\n");
+    assert_eq!(
+        tokenize_with_locations(&crlf),
+        tokenize_with_locations(LF_SOURCE)
+    );
+}
+
+#[test]
+fn crlf_errors_point_at_the_same_column() {
+    let lf = "enum E {\n    a = 1$;\n}\n";
+    let crlf = lf.replace('\n', "\r\n");
+    let render = |source: &str| -> String {
+        let error = Tokenizer::new(source, "test.sb")
+            .unwrap()
+            .find_map(|token| token.err())
+            .unwrap();
+        assert_eq!((error.location.line_num, error.location.col_num), (1, 9));
+        error.to_string()
+    };
+    assert_eq!(render(&crlf), render(lf));
+}
+
+#[test]
+fn unicode_identifiers() {
+    assert_eq!(
+        tokenize("naïve: 名前; _über2"),
+        [
+            ident("naïve"),
+            TokenType::Colon,
+            ident("名前"),
+            TokenType::Semicolon,
+            ident("_über2"),
+        ]
+    );
+}
+
+#[test]
+fn invalid_character_after_multibyte_characters() {
+    let error = Tokenizer::new("名前: u8 🙂;", "test.sb")
+        .unwrap()
+        .find_map(|token| token.err())
+        .unwrap();
+    assert_eq!((error.location.line_num, error.location.col_num), (0, 7));
+    assert!(error.to_string().contains("🙂"));
+}
+
+#[test]
+fn arrow_width_counts_characters() {
+    // `Größe` is not defined, so the error points at it.
+    let error = simplebuffers_compiler::compile_str("sequence A {\n    x: Größe;\n}\n", "test.sb")
+        .unwrap_err();
+    let arrow = error
+        .lines()
+        .find(|line| line.contains('^'))
+        .unwrap()
+        .chars()
+        .filter(|&c| c == '^')
+        .count();
+    assert_eq!(arrow, "Größe".chars().count(), "{}", error);
+}
+
+#[test]
+fn tokenize_collects_tokens_with_locations() {
+    let tokens = tokenize_all("/// Doc\nenum E {}\n", "test.sb").unwrap();
+    let found: Vec<_> = tokens
+        .iter()
+        .map(|t| {
+            let location = &t.location;
+            (
+                t.token_type.clone(),
+                location.line_num,
+                location.col_num,
+                location.width,
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (TokenType::DocComment(" Doc".to_string()), 0, 0, 7),
+            (TokenType::Enum, 1, 0, 4),
+            (ident("E"), 1, 5, 1),
+            (TokenType::OpenBrace, 1, 7, 1),
+            (TokenType::CloseBrace, 1, 8, 1),
+        ]
+    );
+    assert!(tokens.iter().all(|t| t.location.file == "test.sb"));
+
+    let error = tokenize_all("enum E {}\n$", "test.sb").unwrap_err();
+    assert_eq!((error.location.line_num, error.location.col_num), (1, 0));
+}
+
+#[test]
+fn comments_are_kept_on_request() {
+    let tokens: Vec<_> = Tokenizer::with_comments("// note\nenum E {} // after\n", "test.sb")
+        .unwrap()
+        .map(|token| {
+            let token = token.unwrap();
+            (
+                token.token_type,
+                token.location.line_num,
+                token.location.col_num,
+                token.location.width,
+            )
+        })
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            (TokenType::Comment(" note".to_string()), 0, 0, 7),
+            (TokenType::Enum, 1, 0, 4),
+            (ident("E"), 1, 5, 1),
+            (TokenType::OpenBrace, 1, 7, 1),
+            (TokenType::CloseBrace, 1, 8, 1),
+            (TokenType::Comment(" after".to_string()), 1, 10, 8),
+        ]
+    );
+    assert_eq!(
+        TokenType::Comment(" note".to_string()).to_string(),
+        "// note"
+    );
+}
@@ -0,0 +1,85 @@
+//! Tests for warning about unused sequences and enums.
+
+use std::fs;
+
+use simplebuffers_compiler::{compile, CompileOptions};
+
+mod common;
+
+use common::scratch_dir;
+
+/// Compiles `source` and returns the warnings as strings.
+fn warnings(source: &str, file_name: &str, warn_unused: bool) -> Vec<String> {
+    let options = CompileOptions {
+        warn_unused,
+        ..Default::default()
+    };
+    let mut warnings = vec![];
+    compile(source, file_name, &options, &mut warnings).unwrap();
+    warnings.iter().map(|w| w.to_string()).collect()
+}
+
+const SCHEMA: &str = "\
+enum Unused {
+    a = 0;
+}
+
+enum Color {
+    red = 0;
+}
+
+alias Shade = Color;
+alias Dead = Point;
+
+sequence Point {
+    x: u8;
+}
+
+sequence Message {
+    colors: [Shade];
+    payload: oneof {
+        inner: Inner;
+    };
+}
+
+sequence Inner {
+    x: u8;
+}
+";
+
+#[test]
+fn reports_unreferenced_definitions() {
+    let warnings = warnings(SCHEMA, "test.sb", true);
+    assert_eq!(warnings.len(), 3, "{:#?}", warnings);
+    assert!(warnings[0].contains("Enum \"Unused\" is never used"));
+    assert!(warnings[0].contains("test.sb:1:1"));
+
+    // Types that are only used by an unused alias are unused.
+    assert!(warnings[1].contains("Sequence \"Point\" is never used"));
+    assert!(warnings[1].contains("test.sb:12:1"));
+
+    // There is no explicit root, so top-level messages are reported too.
+    assert!(warnings[2].contains("Sequence \"Message\" is never used"));
+}
+
+#[test]
+fn off_by_default() {
+    assert!(warnings(SCHEMA, "test.sb", false).is_empty());
+}
+
+#[test]
+fn imported_types_are_not_reported() {
+    let dir = scratch_dir("imported");
+    fs::write(
+        dir.join("common.sb"),
+        "sequence Header {\n    id: u8;\n}\nenum Level {\n    low = 0;\n}\n",
+    )
+    .unwrap();
+    let main = dir.join("main.sb");
+    let source = "import \"common.sb\";\nsequence Message {\n    header: Header;\n}\n";
+    fs::write(&main, source).unwrap();
+
+    let warnings = warnings(source, main.to_str().unwrap(), true);
+    assert_eq!(warnings.len(), 1, "{:#?}", warnings);
+    assert!(warnings[0].contains("Sequence \"Message\" is never used"));
+}
@@ -0,0 +1,47 @@
+//! Builds and runs the Zig round-trip test in `test/zig`.
+//!
+//! The schema (`roundtrip.sb`) is run through the Zig generator, and the test (`roundtrip.zig`) is
+//! built together with the output, so this test catches generated code that does not compile as
+//! well as code that behaves incorrectly.
+//!
+//! The Zig compiler is taken from the `ZIG` environment variable, falling back to `zig`. If it
+//! cannot be run, the test is skipped.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+mod common;
+
+use common::{run, scratch_dir, tool, COMPILER};
+
+/// Returns the directory containing the Zig test program.
+fn program_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("test")
+        .join("zig")
+}
+
+#[test]
+fn roundtrip() {
+    let Some(zig) = tool("ZIG", "zig") else {
+        return;
+    };
+    // The test imports `Generated/roundtrip.zig`, so it is copied next to the generated code.
+    let build = scratch_dir("roundtrip");
+    fs::copy(
+        program_dir().join("roundtrip.zig"),
+        build.join("roundtrip.zig"),
+    )
+    .unwrap();
+    run(Command::new(COMPILER)
+        .arg("--dstdir")
+        .arg(build.join("Generated"))
+        .arg("zig")
+        .arg(program_dir().join("roundtrip.sb")));
+
+    run(Command::new(zig)
+        .arg("test")
+        .arg(build.join("roundtrip.zig")));
+}
@@ -0,0 +1,760 @@
+//! A schema-driven encoder and decoder.
+//!
+//! This module is the reference implementation of the SimpleBuffers wire format. It encodes and
+//! decodes messages using only an [SBSchema], without generating any code. Its output matches the
+//! C++ library byte-for-byte when the C++ library runs on a little-endian machine, since the C++
+//! library copies integers in native byte order.
+
+use std::cell::Cell;
+
+use crate::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+
+/// The deepest that sequences may be nested in a decoded message. Offsets in a message can point
+/// back at data that contains them, so without a limit, a malformed message could be decoded
+/// forever.
+pub const MAX_DEPTH: usize = 128;
+
+/// A dynamically-typed value, shaped like a JSON value.
+///
+/// Values map to schema types as follows:
+///
+/// - `bool` fields are [Value::Bool].
+/// - Integer fields are [Value::UInt] or [Value::Int]. Either may be used when encoding, as long as
+///   the number fits in the field.
+/// - Float fields are [Value::Float]. Integers are also accepted when encoding.
+/// - Strings are [Value::String].
+/// - Byte buffers are [Value::List] with an integer from 0 to 255 for every byte.
+/// - Enums are [Value::String] with the name of a variant. [Value::UInt] and [Value::Int] are also
+///   accepted, and are produced when decoding a value that does not match any variant.
+/// - Arrays are [Value::List]. Fixed-size arrays must have exactly as many elements as their
+///   length.
+/// - Maps are [Value::List] with a [Value::Object] for every entry, which has a `key` and a `value`
+///   entry. Entries are kept in order, and are not checked for duplicate keys.
+/// - Sequences are [Value::Object] with an entry for every field, in any order.
+/// - Oneofs are [Value::Object] with exactly one entry, naming the active field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    UInt(u64),
+    Int(i64),
+    Float(f64),
+    String(String),
+    List(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Looks up an entry of an object by name. Returns `None` if this is not an object or if the
+    /// entry does not exist.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Returns a short description of the kind of value, for use in error messages.
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Bool(_) => "a boolean",
+            Value::UInt(_) | Value::Int(_) => "an integer",
+            Value::Float(_) => "a float",
+            Value::String(_) => "a string",
+            Value::List(_) => "a list",
+            Value::Object(_) => "an object",
+        }
+    }
+}
+
+/// Where a value is stored. This determines how sequences are laid out.
+#[derive(Clone, Copy)]
+enum Context {
+    /// The value is a field of a sequence. Sequences are stored behind a 16-bit offset.
+    Slot,
+
+    /// The value is a list element or the target of a oneof. Sequences are stored inline.
+    Element,
+}
+
+/// Looks up a sequence by name.
+fn find_sequence<'a>(schema: &'a SBSchema, name: &str) -> Result<&'a Sequence, String> {
+    schema
+        .sequences
+        .iter()
+        .find(|s| s.name == name)
+        .ok_or_else(|| format!("Unknown sequence `{}`", name))
+}
+
+/// Looks up an enum by name and checks that the given size is one that an enum can have.
+fn find_enum<'a>(
+    schema: &'a SBSchema,
+    name: &str,
+    size: usize,
+    path: &str,
+) -> Result<&'a Enum, String> {
+    if !(1..=8).contains(&size) {
+        return Err(format!(
+            "{}: enum `{}` has size {}, which is not between 1 and 8 bytes",
+            path, name, size
+        ));
+    }
+    schema
+        .enums
+        .iter()
+        .find(|e| e.name == name)
+        .ok_or_else(|| format!("{}: unknown enum `{}`", path, name))
+}
+
+/// Returns the numeric value of an enum variant, given either its name or its value.
+fn enum_value(data: &Enum, value: &Value, path: &str) -> Result<i128, String> {
+    match value {
+        Value::String(s) => data
+            .variants
+            .iter()
+            .find(|v| &v.name == s)
+            .map(|v| v.value)
+            .ok_or_else(|| format!("{}: enum `{}` has no variant `{}`", path, data.name, s)),
+        Value::UInt(n) => Ok(*n as i128),
+        Value::Int(n) => Ok(*n as i128),
+        _ => Err(format!(
+            "{}: expected a variant name or an integer for enum `{}`",
+            path, data.name
+        )),
+    }
+}
+
+/// Returns the number of bytes a type takes up as a list element or oneof target.
+fn element_size(schema: &SBSchema, ty: &Type) -> Result<usize, String> {
+    match ty {
+        Type::Sequence(s) => Ok(find_sequence(schema, s)?.static_size()),
+        _ => Ok(ty.size()),
+    }
+}
+
+/// Returns the number of bytes a map entry takes up. The key is stored first, followed by the
+/// value, both as list elements.
+fn entry_size(schema: &SBSchema, key: &Type, value: &Type) -> Result<usize, String> {
+    Ok(element_size(schema, key)? + element_size(schema, value)?)
+}
+
+//                                                                                                //
+// ========================================== Encoding ========================================== //
+//                                                                                                //
+
+/// Encodes a value as a message of the given sequence.
+///
+/// # Arguments
+///
+/// * `schema` - The schema that contains the sequence.
+/// * `sequence_name` - The name of the sequence to encode.
+/// * `value` - The value to encode. See [Value] for how values map to schema types.
+///
+/// # Returns
+///
+/// The encoded message, or a description of why the value could not be encoded. Descriptions
+/// start with the path of the offending value.
+pub fn encode(schema: &SBSchema, sequence_name: &str, value: &Value) -> Result<Vec<u8>, String> {
+    let seq = find_sequence(schema, sequence_name)?;
+    let mut encoder = Encoder {
+        schema,
+        buf: vec![0; seq.static_size()],
+    };
+    encoder.write_sequence(seq, value, 0, sequence_name)?;
+    Ok(encoder.buf)
+}
+
+/// Serializes values into a buffer. Static data is written at fixed positions, and dynamic data is
+/// appended to the end of the buffer in the order it is written.
+struct Encoder<'a> {
+    schema: &'a SBSchema,
+    buf: Vec<u8>,
+}
+
+impl<'a> Encoder<'a> {
+    /// Reserves `count` zeroed bytes at the end of the buffer and stores the offset to them
+    /// (relative to `base`) at `slot`. Returns the position of the reserved bytes.
+    fn reserve(
+        &mut self,
+        slot: usize,
+        base: usize,
+        count: usize,
+        path: &str,
+    ) -> Result<usize, String> {
+        let start = self.buf.len();
+        let offset = u16::try_from(start - base)
+            .map_err(|_| format!("{}: offset {} does not fit in 16 bits", path, start - base))?;
+        self.buf.resize(start + count, 0);
+        self.write_bytes(slot, &offset.to_le_bytes());
+        Ok(start)
+    }
+
+    /// Writes raw bytes at `pos`.
+    fn write_bytes(&mut self, pos: usize, bytes: &[u8]) {
+        self.buf[pos..pos + bytes.len()].copy_from_slice(bytes);
+    }
+
+    /// Writes the static data of a sequence at `pos`.
+    fn write_sequence(
+        &mut self,
+        seq: &Sequence,
+        value: &Value,
+        pos: usize,
+        path: &str,
+    ) -> Result<(), String> {
+        let entries = match value {
+            Value::Object(entries) => entries,
+            _ => {
+                return Err(format!(
+                    "{}: expected an object for sequence `{}`, found {}",
+                    path,
+                    seq.name,
+                    value.kind()
+                ))
+            }
+        };
+        if let Some((unknown, _)) = entries
+            .iter()
+            .find(|(k, _)| !seq.fields.iter().any(|f| &f.name == k))
+        {
+            return Err(format!(
+                "{}: sequence `{}` has no field `{}`",
+                path, seq.name, unknown
+            ));
+        }
+
+        for field in &seq.fields {
+            let field_path = format!("{}.{}", path, field.name);
+            let field_value = value
+                .get(&field.name)
+                .ok_or_else(|| format!("{}: missing field", field_path))?;
+            self.write_value(
+                &field.ty,
+                Context::Slot,
+                field_value,
+                pos + field.index,
+                &field_path,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes a value of the given type at `pos`.
+    fn write_value(
+        &mut self,
+        ty: &Type,
+        ctx: Context,
+        value: &Value,
+        pos: usize,
+        path: &str,
+    ) -> Result<(), String> {
+        match ty {
+            Type::Primitive(p) => {
+                let bytes = primitive_bytes(p, value, path)?;
+                self.write_bytes(pos, &bytes);
+            }
+            Type::Enum(name, size) => {
+                let data = find_enum(self.schema, name, *size, path)?;
+                let number = enum_value(data, value, path)?;
+                let bits = 8 * *size as u32;
+                let (min, max) = if data.is_signed() {
+                    (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+                } else {
+                    (0, (1i128 << bits) - 1)
+                };
+                if !(min..=max).contains(&number) {
+                    return Err(format!(
+                        "{}: value {} does not fit in enum `{}`",
+                        path, number, name
+                    ));
+                }
+                self.write_bytes(pos, &number.to_le_bytes()[..*size]);
+            }
+            Type::String => {
+                let s = match value {
+                    Value::String(s) => s,
+                    _ => {
+                        return Err(format!(
+                            "{}: expected a string, found {}",
+                            path,
+                            value.kind()
+                        ))
+                    }
+                };
+                if s.contains('\0') {
+                    return Err(format!("{}: strings cannot contain null characters", path));
+                }
+                let start = self.reserve(pos, pos, s.len() + 1, path)?;
+                self.write_bytes(start, s.as_bytes());
+            }
+            Type::Bytes => {
+                let bytes = byte_values(value, path)?;
+                let len = u16::try_from(bytes.len())
+                    .map_err(|_| format!("{}: byte buffer has more than 65535 bytes", path))?;
+                let start = self.reserve(pos, pos, 2 + bytes.len(), path)?;
+                self.write_bytes(start, &len.to_le_bytes());
+                self.write_bytes(start + 2, &bytes);
+            }
+            Type::Array(element) => {
+                let values = match value {
+                    Value::List(values) => values,
+                    _ => return Err(format!("{}: expected a list, found {}", path, value.kind())),
+                };
+                let len = u16::try_from(values.len())
+                    .map_err(|_| format!("{}: list has more than 65535 elements", path))?;
+                self.write_bytes(pos, &len.to_le_bytes());
+                let stride = element_size(self.schema, element)?;
+                let start = self.reserve(pos + 2, pos, stride * values.len(), path)?;
+                for (i, v) in values.iter().enumerate() {
+                    self.write_value(
+                        element,
+                        Context::Element,
+                        v,
+                        start + i * stride,
+                        &format!("{}[{}]", path, i),
+                    )?;
+                }
+            }
+            Type::FixedArray(element, len) => {
+                let values = match value {
+                    Value::List(values) => values,
+                    _ => return Err(format!("{}: expected a list, found {}", path, value.kind())),
+                };
+                if values.len() != *len {
+                    return Err(format!(
+                        "{}: expected {} elements, found {}",
+                        path,
+                        len,
+                        values.len()
+                    ));
+                }
+                let stride = element.size();
+                for (i, v) in values.iter().enumerate() {
+                    self.write_value(
+                        element,
+                        Context::Element,
+                        v,
+                        pos + i * stride,
+                        &format!("{}[{}]", path, i),
+                    )?;
+                }
+            }
+            Type::Map(key, val) => {
+                let entries = match value {
+                    Value::List(entries) => entries,
+                    _ => return Err(format!("{}: expected a list, found {}", path, value.kind())),
+                };
+                let len = u16::try_from(entries.len())
+                    .map_err(|_| format!("{}: map has more than 65535 entries", path))?;
+                self.write_bytes(pos, &len.to_le_bytes());
+                let key_size = element_size(self.schema, key)?;
+                let stride = entry_size(self.schema, key, val)?;
+                let start = self.reserve(pos + 2, pos, stride * entries.len(), path)?;
+                for (i, entry) in entries.iter().enumerate() {
+                    let entry_path = format!("{}[{}]", path, i);
+                    let (k, v) = match (entry, entry.get("key"), entry.get("value")) {
+                        (Value::Object(e), Some(k), Some(v)) if e.len() == 2 => (k, v),
+                        _ => {
+                            return Err(format!(
+                                "{}: expected an object with a `key` and a `value` for a map entry",
+                                entry_path
+                            ))
+                        }
+                    };
+                    let entry_pos = start + i * stride;
+                    self.write_value(
+                        key,
+                        Context::Element,
+                        k,
+                        entry_pos,
+                        &format!("{}.key", entry_path),
+                    )?;
+                    self.write_value(
+                        val,
+                        Context::Element,
+                        v,
+                        entry_pos + key_size,
+                        &format!("{}.value", entry_path),
+                    )?;
+                }
+            }
+            Type::Sequence(name) => {
+                let seq = find_sequence(self.schema, name)?;
+                let target = match ctx {
+                    Context::Slot => self.reserve(pos, pos, seq.static_size(), path)?,
+                    Context::Element => pos,
+                };
+                self.write_sequence(seq, value, target, path)?;
+            }
+            Type::OneOf(subfields) => self.write_oneof(subfields, value, pos, path)?,
+        }
+        Ok(())
+    }
+
+    /// Writes a oneof at `pos`. The tag is written at `pos`, and the active field is written to
+    /// the end of the buffer.
+    fn write_oneof(
+        &mut self,
+        subfields: &[Field],
+        value: &Value,
+        pos: usize,
+        path: &str,
+    ) -> Result<(), String> {
+        let (name, inner) = match value {
+            Value::Object(entries) if entries.len() == 1 => (&entries[0].0, &entries[0].1),
+            _ => {
+                return Err(format!(
+                    "{}: expected an object with exactly one entry for a oneof",
+                    path
+                ))
+            }
+        };
+        let field = subfields
+            .iter()
+            .find(|f| &f.name == name)
+            .ok_or_else(|| format!("{}: oneof has no field `{}`", path, name))?;
+        let tag = u8::try_from(field.index)
+            .map_err(|_| format!("{}: oneof tag {} does not fit in 8 bits", path, field.index))?;
+
+        self.write_bytes(pos, &[tag]);
+        let size = element_size(self.schema, &field.ty)?;
+        let target = self.reserve(pos + 1, pos, size, path)?;
+        self.write_value(
+            &field.ty,
+            Context::Element,
+            inner,
+            target,
+            &format!("{}.{}", path, name),
+        )
+    }
+}
+
+/// Returns the bytes of a byte buffer value, which must be a list of integers from 0 to 255.
+fn byte_values(value: &Value, path: &str) -> Result<Vec<u8>, String> {
+    let values = match value {
+        Value::List(values) => values,
+        _ => return Err(format!("{}: expected a list, found {}", path, value.kind())),
+    };
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            match v {
+                Value::UInt(b) => u8::try_from(*b).ok(),
+                Value::Int(b) => u8::try_from(*b).ok(),
+                _ => None,
+            }
+            .ok_or_else(|| format!("{}[{}]: expected a byte, found {:?}", path, i, v))
+        })
+        .collect()
+}
+
+/// Returns the little-endian encoding of a primitive value.
+fn primitive_bytes(p: &Primitive, value: &Value, path: &str) -> Result<Vec<u8>, String> {
+    let out_of_range = || format!("{}: value {:?} does not fit in {}", path, value, p);
+    let integer = || match value {
+        Value::UInt(n) => Ok(*n as i128),
+        Value::Int(n) => Ok(*n as i128),
+        _ => Err(format!(
+            "{}: expected an integer, found {}",
+            path,
+            value.kind()
+        )),
+    };
+    let float = || match value {
+        Value::Float(f) => Ok(*f),
+        Value::UInt(n) => Ok(*n as f64),
+        Value::Int(n) => Ok(*n as f64),
+        _ => Err(format!(
+            "{}: expected a number, found {}",
+            path,
+            value.kind()
+        )),
+    };
+
+    Ok(match p {
+        Primitive::Bool => match value {
+            Value::Bool(b) => vec![*b as u8],
+            _ => {
+                return Err(format!(
+                    "{}: expected a boolean, found {}",
+                    path,
+                    value.kind()
+                ))
+            }
+        },
+        Primitive::U8 => u8::try_from(integer()?)
+            .map_err(|_| out_of_range())?
+            .to_le_bytes()
+            .to_vec(),
+        Primitive::U16 => u16::try_from(integer()?)
+            .map_err(|_| out_of_range())?
+            .to_le_bytes()
+            .to_vec(),
+        Primitive::U32 => u32::try_from(integer()?)
+            .map_err(|_| out_of_range())?
+            .to_le_bytes()
+            .to_vec(),
+        Primitive::U64 => u64::try_from(integer()?)
+            .map_err(|_| out_of_range())?
+            .to_le_bytes()
+            .to_vec(),
+        Primitive::I8 => i8::try_from(integer()?)
+            .map_err(|_| out_of_range())?
+            .to_le_bytes()
+            .to_vec(),
+        Primitive::I16 => i16::try_from(integer()?)
+            .map_err(|_| out_of_range())?
+            .to_le_bytes()
+            .to_vec(),
+        Primitive::I32 => i32::try_from(integer()?)
+            .map_err(|_| out_of_range())?
+            .to_le_bytes()
+            .to_vec(),
+        Primitive::I64 => i64::try_from(integer()?)
+            .map_err(|_| out_of_range())?
+            .to_le_bytes()
+            .to_vec(),
+        Primitive::F32 => (float()? as f32).to_le_bytes().to_vec(),
+        Primitive::F64 => float()?.to_le_bytes().to_vec(),
+    })
+}
+
+//                                                                                                //
+// ========================================== Decoding ========================================== //
+//                                                                                                //
+
+/// Decodes a message of the given sequence.
+///
+/// # Arguments
+///
+/// * `schema` - The schema that contains the sequence.
+/// * `sequence_name` - The name of the sequence to decode.
+/// * `data` - The encoded message.
+///
+/// # Returns
+///
+/// The decoded value, or a description of why the message could not be decoded. Sequences are
+/// decoded into objects with their fields in schema order. Messages that nest sequences more than
+/// [MAX_DEPTH] levels deep are rejected, as are messages that contain more sequences than they have
+/// bytes, which is only possible if offsets point at the same data more than once.
+pub fn decode(schema: &SBSchema, sequence_name: &str, data: &[u8]) -> Result<Value, String> {
+    let seq = find_sequence(schema, sequence_name)?;
+    let decoder = Decoder {
+        schema,
+        buf: data,
+        depth: Cell::new(0),
+        sequences: Cell::new(0),
+    };
+    decoder.read_sequence(seq, 0, sequence_name)
+}
+
+/// Deserializes values from a buffer, checking every access against the bounds of the buffer.
+struct Decoder<'a> {
+    schema: &'a SBSchema,
+    buf: &'a [u8],
+
+    /// The number of sequences that are currently being read.
+    depth: Cell<usize>,
+
+    /// The number of sequences with at least one byte of static data that have been read.
+    sequences: Cell<usize>,
+}
+
+impl<'a> Decoder<'a> {
+    /// Returns `len` bytes starting at `pos`.
+    fn bytes(&self, pos: usize, len: usize, path: &str) -> Result<&'a [u8], String> {
+        self.buf.get(pos..pos + len).ok_or_else(|| {
+            format!(
+                "{}: {} bytes at position {} are out of bounds (buffer is {} bytes)",
+                path,
+                len,
+                pos,
+                self.buf.len()
+            )
+        })
+    }
+
+    /// Reads a little-endian unsigned integer of `len` bytes at `pos`.
+    fn read_uint(&self, pos: usize, len: usize, path: &str) -> Result<u64, String> {
+        let mut bytes = [0u8; 8];
+        bytes[..len].copy_from_slice(self.bytes(pos, len, path)?);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    /// Follows the 16-bit offset stored at `slot`, relative to `base`.
+    fn follow(&self, slot: usize, base: usize, path: &str) -> Result<usize, String> {
+        Ok(base + self.read_uint(slot, 2, path)? as usize)
+    }
+
+    /// Reads the static data of a sequence at `pos`.
+    fn read_sequence(&self, seq: &Sequence, pos: usize, path: &str) -> Result<Value, String> {
+        if self.depth.get() >= MAX_DEPTH {
+            return Err(format!(
+                "{}: sequences are nested more than {} levels deep",
+                path, MAX_DEPTH
+            ));
+        }
+        // Every sequence with static data takes up its own bytes, so a message that contains more
+        // of them than it has bytes must point at the same data more than once. Decoding such a
+        // message could take exponentially long, even if it is not nested too deeply.
+        if seq.static_size() > 0 {
+            self.sequences.set(self.sequences.get() + 1);
+            if self.sequences.get() > self.buf.len() {
+                return Err(format!(
+                    "{}: message contains more sequences than it has bytes, so offsets must point \
+                     at the same data more than once",
+                    path
+                ));
+            }
+        }
+        self.depth.set(self.depth.get() + 1);
+        let entries = seq
+            .fields
+            .iter()
+            .map(|field| {
+                let field_path = format!("{}.{}", path, field.name);
+                let value =
+                    self.read_value(&field.ty, Context::Slot, pos + field.index, &field_path)?;
+                Ok((field.name.clone(), value))
+            })
+            .collect::<Result<Vec<_>, String>>();
+        self.depth.set(self.depth.get() - 1);
+        Ok(Value::Object(entries?))
+    }
+
+    /// Reads a value of the given type at `pos`.
+    fn read_value(&self, ty: &Type, ctx: Context, pos: usize, path: &str) -> Result<Value, String> {
+        Ok(match ty {
+            Type::Primitive(p) => self.read_primitive(p, pos, path)?,
+            Type::Enum(name, size) => {
+                let data = find_enum(self.schema, name, *size, path)?;
+                let number = self.read_uint(pos, *size, path)?;
+                // Signed enums are sign-extended from their size.
+                let shift = 64 - 8 * *size as u32;
+                let signed = (number << shift) as i64 >> shift;
+                let value = if data.is_signed() {
+                    signed as i128
+                } else {
+                    number as i128
+                };
+                match data.variants.iter().find(|v| v.value == value) {
+                    Some(v) => Value::String(v.name.clone()),
+                    None if data.is_signed() => Value::Int(signed),
+                    None => Value::UInt(number),
+                }
+            }
+            Type::String => {
+                let start = self.follow(pos, pos, path)?;
+                let rest = self.bytes(start, self.buf.len().saturating_sub(start), path)?;
+                let len = rest
+                    .iter()
+                    .position(|b| *b == 0)
+                    .ok_or_else(|| format!("{}: string is not null-terminated", path))?;
+                let s = std::str::from_utf8(&rest[..len])
+                    .map_err(|e| format!("{}: string is not valid UTF-8: {}", path, e))?;
+                Value::String(s.to_string())
+            }
+            Type::Bytes => {
+                let start = self.follow(pos, pos, path)?;
+                let len = self.read_uint(start, 2, path)? as usize;
+                let bytes = self.bytes(start + 2, len, path)?;
+                Value::List(bytes.iter().map(|b| Value::UInt(*b as u64)).collect())
+            }
+            Type::Array(element) => {
+                let len = self.read_uint(pos, 2, path)? as usize;
+                let start = self.follow(pos + 2, pos, path)?;
+                let stride = element_size(self.schema, element)?;
+                let mut values = Vec::with_capacity(len);
+                for i in 0..len {
+                    values.push(self.read_value(
+                        element,
+                        Context::Element,
+                        start + i * stride,
+                        &format!("{}[{}]", path, i),
+                    )?);
+                }
+                Value::List(values)
+            }
+            Type::FixedArray(element, len) => {
+                let stride = element.size();
+                let mut values = Vec::with_capacity(*len);
+                for i in 0..*len {
+                    values.push(self.read_value(
+                        element,
+                        Context::Element,
+                        pos + i * stride,
+                        &format!("{}[{}]", path, i),
+                    )?);
+                }
+                Value::List(values)
+            }
+            Type::Map(key, value) => {
+                let len = self.read_uint(pos, 2, path)? as usize;
+                let start = self.follow(pos + 2, pos, path)?;
+                let key_size = element_size(self.schema, key)?;
+                let stride = entry_size(self.schema, key, value)?;
+                let mut entries = Vec::with_capacity(len);
+                for i in 0..len {
+                    let entry_path = format!("{}[{}]", path, i);
+                    let entry_pos = start + i * stride;
+                    let k = self.read_value(
+                        key,
+                        Context::Element,
+                        entry_pos,
+                        &format!("{}.key", entry_path),
+                    )?;
+                    let v = self.read_value(
+                        value,
+                        Context::Element,
+                        entry_pos + key_size,
+                        &format!("{}.value", entry_path),
+                    )?;
+                    entries.push(Value::Object(vec![
+                        ("key".to_string(), k),
+                        ("value".to_string(), v),
+                    ]));
+                }
+                Value::List(entries)
+            }
+            Type::Sequence(name) => {
+                let seq = find_sequence(self.schema, name)?;
+                let target = match ctx {
+                    Context::Slot => self.follow(pos, pos, path)?,
+                    Context::Element => pos,
+                };
+                self.read_sequence(seq, target, path)?
+            }
+            Type::OneOf(subfields) => {
+                let tag = self.read_uint(pos, 1, path)? as usize;
+                let field = subfields
+                    .iter()
+                    .find(|f| f.index == tag)
+                    .ok_or_else(|| format!("{}: unknown oneof tag {}", path, tag))?;
+                let target = self.follow(pos + 1, pos, path)?;
+                let value = self.read_value(
+                    &field.ty,
+                    Context::Element,
+                    target,
+                    &format!("{}.{}", path, field.name),
+                )?;
+                Value::Object(vec![(field.name.clone(), value)])
+            }
+        })
+    }
+
+    /// Reads a primitive value at `pos`.
+    fn read_primitive(&self, p: &Primitive, pos: usize, path: &str) -> Result<Value, String> {
+        let size = p.size();
+        let raw = self.read_uint(pos, size, path)?;
+        Ok(match p {
+            Primitive::Bool => Value::Bool(raw != 0),
+            Primitive::U8 | Primitive::U16 | Primitive::U32 | Primitive::U64 => Value::UInt(raw),
+            Primitive::I8 => Value::Int(raw as u8 as i8 as i64),
+            Primitive::I16 => Value::Int(raw as u16 as i16 as i64),
+            Primitive::I32 => Value::Int(raw as u32 as i32 as i64),
+            Primitive::I64 => Value::Int(raw as i64),
+            Primitive::F32 => Value::Float(f32::from_bits(raw as u32) as f64),
+            Primitive::F64 => Value::Float(f64::from_bits(raw)),
+        })
+    }
+}
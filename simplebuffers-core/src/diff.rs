@@ -0,0 +1,353 @@
+//! Compares two versions of a schema to find changes that break wire compatibility.
+//!
+//! Names are never written to the wire, so renaming a sequence, field, enum, or variant does not
+//! break compatibility, but changing the layout of a sequence does. Since sequences are stored
+//! inline in lists and their readers assume their full size, adding a field to a sequence is a
+//! breaking change too. Adding a variant to an enum or a oneof is not, although older readers will
+//! not recognize it.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::{Enum, EnumVariant, Field, SBSchema, Sequence, Type};
+
+/// A difference between two versions of a schema.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    /// Whether data written with one version can be misread with the other.
+    pub breaking: bool,
+
+    /// A description of the change, such as "Field `Point::y` was removed".
+    pub description: String,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = if self.breaking {
+            "breaking"
+        } else {
+            "compatible"
+        };
+        write!(f, "{}: {}", kind, self.description)
+    }
+}
+
+/// Compare two versions of a schema.
+///
+/// Sequences and enums are matched by name. One that was removed while another with an identical
+/// layout was added is reported as renamed. Fields are matched by position, and enum variants by
+/// name or, failing that, by value.
+///
+/// # Arguments
+///
+/// * `old` - The old version of the schema.
+/// * `new` - The new version of the schema.
+///
+/// # Returns
+///
+/// Every change, with sequences before enums. Doc comments and the package are ignored.
+pub fn diff(old: &SBSchema, new: &SBSchema) -> Vec<Change> {
+    let sequence_renames = find_renames(&old.sequences, &new.sequences, |s| &s.name, same_layout);
+    let enum_renames = find_renames(&old.enums, &new.enums, |e| &e.name, same_values);
+    let mut differ = Differ {
+        renames: sequence_renames
+            .iter()
+            .chain(&enum_renames)
+            .map(|(old, new)| (old.as_str(), new.as_str()))
+            .collect(),
+        changes: Vec::new(),
+    };
+
+    for seq in &old.sequences {
+        if let Some(new_seq) = differ.find_new("Sequence", &seq.name, &new.sequences, |s| &s.name) {
+            differ.fields(&seq.name, &seq.fields, &new_seq.fields);
+        }
+    }
+    differ.added("Sequence", &old.sequences, &new.sequences, |s| &s.name);
+
+    for enm in &old.enums {
+        if let Some(new_enum) = differ.find_new("Enum", &enm.name, &new.enums, |e| &e.name) {
+            differ.variants(enm, new_enum);
+        }
+    }
+    differ.added("Enum", &old.enums, &new.enums, |e| &e.name);
+
+    differ.changes
+}
+
+/// Whether two sequences have the same fields, apart from their names.
+fn same_layout(a: &Sequence, b: &Sequence) -> bool {
+    a.fields.len() == b.fields.len()
+        && a.fields
+            .iter()
+            .zip(&b.fields)
+            .all(|(a, b)| a.ty == b.ty && a.index == b.index)
+}
+
+/// Whether two enums have the same size and values, apart from their names.
+fn same_values(a: &Enum, b: &Enum) -> bool {
+    a.size == b.size
+        && a.is_signed() == b.is_signed()
+        && a.variants.len() == b.variants.len()
+        && a.variants
+            .iter()
+            .all(|v| b.variants.iter().any(|w| w.value == v.value))
+}
+
+/// Pair up items that only exist in the old version with items that only exist in the new version
+/// and are the same apart from their name. Each item is paired at most once.
+///
+/// # Returns
+///
+/// The old and new name of every renamed item.
+fn find_renames<'s, T>(
+    old: &'s [T],
+    new: &'s [T],
+    name: impl Fn(&T) -> &String,
+    same: impl Fn(&T, &T) -> bool,
+) -> Vec<(&'s String, &'s String)> {
+    let old_names: HashSet<&String> = old.iter().map(&name).collect();
+    let new_names: HashSet<&String> = new.iter().map(&name).collect();
+    let mut renames: Vec<(&String, &String)> = Vec::new();
+    for old_item in old.iter().filter(|i| !new_names.contains(name(i))) {
+        let candidate = new.iter().find(|new_item| {
+            !old_names.contains(name(new_item))
+                && !renames.iter().any(|(_, n)| *n == name(new_item))
+                && same(old_item, new_item)
+        });
+        if let Some(new_item) = candidate {
+            renames.push((name(old_item), name(new_item)));
+        }
+    }
+    renames
+}
+
+/// Collects the changes between two versions of a schema.
+struct Differ<'s> {
+    /// The new name of every renamed sequence and enum, by its old name.
+    renames: HashMap<&'s str, &'s str>,
+
+    /// The changes found so far.
+    changes: Vec<Change>,
+}
+
+impl<'s> Differ<'s> {
+    /// Records a change that breaks wire compatibility.
+    fn breaking(&mut self, description: String) {
+        self.changes.push(Change {
+            breaking: true,
+            description,
+        });
+    }
+
+    /// Records a change that keeps wire compatibility.
+    fn compatible(&mut self, description: String) {
+        self.changes.push(Change {
+            breaking: false,
+            description,
+        });
+    }
+
+    /// Find the new version of a sequence or enum, by its name or the name it was renamed to. A
+    /// rename is recorded, as is a removal if there is no new version.
+    fn find_new<'n, T>(
+        &mut self,
+        kind: &str,
+        name: &str,
+        new: &'n [T],
+        name_of: impl Fn(&T) -> &String,
+    ) -> Option<&'n T> {
+        let new_name = match self.renames.get(name) {
+            Some(&new_name) => {
+                self.compatible(format!("{} `{}` was renamed to `{}`", kind, name, new_name));
+                new_name
+            }
+            None => name,
+        };
+        let found = new.iter().find(|item| name_of(item) == new_name);
+        if found.is_none() {
+            self.breaking(format!("{} `{}` was removed", kind, name));
+        }
+        found
+    }
+
+    /// Records every sequence or enum in the new version that is neither in the old version nor the
+    /// new name of a renamed one.
+    fn added<T>(&mut self, kind: &str, old: &[T], new: &[T], name: impl Fn(&T) -> &String) {
+        for item in new {
+            let item_name = name(item);
+            let renamed = self.renames.values().any(|n| n == item_name);
+            if !renamed && !old.iter().any(|o| name(o) == item_name) {
+                self.compatible(format!("{} `{}` was added", kind, item_name));
+            }
+        }
+    }
+
+    /// Compare the fields of a sequence by position.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The name of the sequence, used in descriptions.
+    /// * `old` - The fields in the old version.
+    /// * `new` - The fields in the new version.
+    fn fields(&mut self, path: &str, old: &[Field], new: &[Field]) {
+        for (old_field, new_field) in old.iter().zip(new) {
+            let field_path = format!("{}::{}", path, old_field.name);
+            if old_field.name != new_field.name {
+                self.compatible(format!(
+                    "Field `{}` was renamed to `{}`",
+                    field_path, new_field.name
+                ));
+            }
+            let type_changed = self.types(&field_path, &old_field.ty, &new_field.ty);
+            // A field that moves because an earlier field changed size is reported as well, unless
+            // it changed type itself.
+            if !type_changed && old_field.index != new_field.index {
+                self.breaking(format!(
+                    "Field `{}` moved from offset {} to {}",
+                    field_path, old_field.index, new_field.index
+                ));
+            }
+        }
+        for field in old.iter().skip(new.len()) {
+            self.breaking(format!("Field `{}::{}` was removed", path, field.name));
+        }
+        for field in new.iter().skip(old.len()) {
+            self.breaking(format!(
+                "Field `{}::{}` was added, which changes the size of `{}`",
+                path, field.name, path
+            ));
+        }
+    }
+
+    /// Compare the fields of a oneof by index.
+    fn oneof(&mut self, path: &str, old: &[Field], new: &[Field]) {
+        for (old_field, new_field) in old.iter().zip(new) {
+            let field_path = format!("{}.{}", path, old_field.name);
+            if old_field.name != new_field.name {
+                self.compatible(format!(
+                    "Oneof field `{}` was renamed to `{}`",
+                    field_path, new_field.name
+                ));
+            }
+            self.types(&field_path, &old_field.ty, &new_field.ty);
+        }
+        for field in old.iter().skip(new.len()) {
+            self.breaking(format!("Oneof field `{}.{}` was removed", path, field.name));
+        }
+        for field in new.iter().skip(old.len()) {
+            self.compatible(format!("Oneof field `{}.{}` was added", path, field.name));
+        }
+    }
+
+    /// Compare two versions of a type. Sequences and enums are compared by name, taking renames
+    /// into account, since changes to them are reported separately.
+    ///
+    /// # Returns
+    ///
+    /// Whether the type itself changed. Changes inside a oneof do not count.
+    fn types(&mut self, path: &str, old: &Type, new: &Type) -> bool {
+        let changed = match (old, new) {
+            (Type::Sequence(old_name), Type::Sequence(new_name))
+            | (Type::Enum(old_name, _), Type::Enum(new_name, _)) => {
+                old_name != new_name
+                    && self.renames.get(old_name.as_str()) != Some(&new_name.as_str())
+            }
+            (Type::Array(old_element), Type::Array(new_element)) => {
+                return self.types(&format!("{}[]", path), old_element, new_element)
+            }
+            (Type::FixedArray(old_element, old_len), Type::FixedArray(new_element, new_len))
+                if old_len == new_len =>
+            {
+                return self.types(&format!("{}[]", path), old_element, new_element)
+            }
+            (Type::Map(old_key, old_value), Type::Map(new_key, new_value)) => {
+                return self.types(&format!("{}.key", path), old_key, new_key)
+                    || self.types(&format!("{}.value", path), old_value, new_value)
+            }
+            (Type::OneOf(old_fields), Type::OneOf(new_fields)) => {
+                self.oneof(path, old_fields, new_fields);
+                return false;
+            }
+            _ => old != new,
+        };
+        if changed {
+            self.breaking(format!(
+                "`{}` changed type from `{}` to `{}`",
+                path, old, new
+            ));
+        }
+        changed
+    }
+
+    /// Compare two versions of an enum with the same name.
+    fn variants(&mut self, old: &Enum, new: &Enum) {
+        if old.size != new.size {
+            let verb = if new.size > old.size {
+                "grew"
+            } else {
+                "shrank"
+            };
+            self.breaking(format!(
+                "Enum `{}` {} from {} to {} bytes",
+                old.name, verb, old.size, new.size
+            ));
+        } else if old.is_signed() != new.is_signed() {
+            self.breaking(format!(
+                "Enum `{}` changed from {} to {}",
+                old.name,
+                signedness(old),
+                signedness(new)
+            ));
+        }
+
+        let find = |variants: &[EnumVariant], name: &str| -> Option<i128> {
+            variants.iter().find(|v| v.name == name).map(|v| v.value)
+        };
+        // A variant was renamed if its value now belongs to a variant that did not exist before.
+        let renamed_to = |variant: &EnumVariant| {
+            new.variants
+                .iter()
+                .find(|v| v.value == variant.value && find(&old.variants, &v.name).is_none())
+        };
+
+        for variant in &old.variants {
+            let path = format!("{}::{}", old.name, variant.name);
+            match find(&new.variants, &variant.name) {
+                Some(value) if value != variant.value => self.breaking(format!(
+                    "Variant `{}` changed value from {} to {}",
+                    path, variant.value, value
+                )),
+                Some(_) => {}
+                None => match renamed_to(variant) {
+                    Some(renamed) => self.compatible(format!(
+                        "Variant `{}` was renamed to `{}`",
+                        path, renamed.name
+                    )),
+                    None => self.breaking(format!("Variant `{}` was removed", path)),
+                },
+            }
+        }
+        for variant in &new.variants {
+            let is_rename = old.variants.iter().any(|v| {
+                find(&new.variants, &v.name).is_none()
+                    && renamed_to(v).map_or(false, |r| r.name == variant.name)
+            });
+            if find(&old.variants, &variant.name).is_none() && !is_rename {
+                self.compatible(format!(
+                    "Variant `{}::{}` was added",
+                    old.name, variant.name
+                ));
+            }
+        }
+    }
+}
+
+/// Describes whether an enum is signed.
+fn signedness(enm: &Enum) -> &'static str {
+    if enm.is_signed() {
+        "signed"
+    } else {
+        "unsigned"
+    }
+}
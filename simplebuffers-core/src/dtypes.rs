@@ -1,7 +1,12 @@
 use std::fmt;
 
 /// A fully parsed SimpleBuffers schema.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SBSchema {
+    /// The package declared with `package`, such as `robot.messages`, if any.
+    pub package: Option<String>,
+
     /// The sequences in the SyntaxTree.
     pub sequences: Vec<Sequence>,
 
@@ -10,16 +15,22 @@ pub struct SBSchema {
 }
 
 /// A sequence in a schema.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sequence {
     /// The name of the sequence.
     pub name: String,
 
     /// The fields of the sequence.
     pub fields: Vec<Field>,
+
+    /// The doc comment attached to the sequence, if any.
+    pub doc: Option<String>,
 }
 
 /// A field in a sequence.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Field {
     /// The name of the field.
     pub name: String,
@@ -30,10 +41,15 @@ pub struct Field {
     /// The index of the field. For sequences, this is the offset in bytes from the start of the
     /// sequence. For oneofs, this is the index of the field in the oneof.
     pub index: usize,
+
+    /// The doc comment attached to the field, if any.
+    pub doc: Option<String>,
 }
 
 /// A type in a field.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Type {
     /// A primitive type.
     Primitive(Primitive),
@@ -47,15 +63,30 @@ pub enum Type {
     /// An array type.
     Array(Box<Type>),
 
+    /// A fixed-size array type. This contains the element type and the number of elements. The
+    /// elements are stored inline, so the element type must have a fixed size (a primitive or an
+    /// enum).
+    FixedArray(Box<Type>, usize),
+
+    /// A map type. This contains the key type and the value type. Maps are stored like lists of
+    /// key/value pairs, and keys must be primitives, enums, or strings.
+    Map(Box<Type>, Box<Type>),
+
     /// A string type.
     String,
 
+    /// A byte buffer. Unlike a string, it stores its length and may contain null bytes.
+    Bytes,
+
     /// A oneof type. This is a type that can be one of several types.
+    #[cfg_attr(feature = "serde", serde(rename = "oneof"))]
     OneOf(Vec<Field>),
 }
 
 /// A primitive type.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Primitive {
     Bool,
     I8,
@@ -70,26 +101,114 @@ pub enum Primitive {
     F64,
 }
 
+/// The range of values that a primitive can hold. Both bounds are inclusive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PrimitiveRange {
+    /// The range of an integer type. Booleans are treated as integers from 0 to 1.
+    Integer { min: i128, max: u128 },
+
+    /// The finite range of a floating-point type.
+    Float { min: f64, max: f64 },
+}
+
 /// An enum in a schema.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Enum {
     /// The name of the enum.
     pub name: String,
 
-    /// The size of the enum, in bytes. This matches the smallest data type that can fully represent
-    /// the enum and should not be greater than 8 (equivalent to a u64).
+    /// The size of the enum, in bytes. Unless a base type is declared, this matches the smallest
+    /// data type that can fully represent the enum and should not be greater than 8 (equivalent to
+    /// a u64 or an i64). If any variant is negative, the enum is signed (see [Enum::is_signed]).
     pub size: u8,
 
+    /// The integer type declared with `enum Name : type`, if any. If set, `size` is its size, and
+    /// it decides whether the enum is signed.
+    pub base: Option<Primitive>,
+
     /// The variants of the enum.
     pub variants: Vec<EnumVariant>,
+
+    /// The doc comment attached to the enum, if any.
+    pub doc: Option<String>,
 }
 
 /// A variant of an enum.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumVariant {
     /// The name of the variant.
     pub name: String,
 
-    /// The value of the variant.
-    pub value: u64,
+    /// The value of the variant. Every value fits in an `i64` or a `u64`, depending on whether the
+    /// enum is signed.
+    pub value: i128,
+
+    /// The doc comment attached to the variant, if any.
+    pub doc: Option<String>,
+}
+
+impl SBSchema {
+    /// Returns whether a field of any sequence is a fixed-size array. Only the fields themselves
+    /// are checked, not list elements or the fields of oneofs.
+    pub fn uses_fixed_arrays(&self) -> bool {
+        self.sequences
+            .iter()
+            .flat_map(|s| &s.fields)
+            .any(|f| matches!(f.ty, Type::FixedArray(_, _)))
+    }
+
+    /// Returns whether the schema holds a byte buffer anywhere: as a field, as a list element, or
+    /// in a oneof.
+    pub fn uses_bytes(&self) -> bool {
+        let mut found = false;
+        for field in self.sequences.iter().flat_map(|s| &s.fields) {
+            crate::walk_types(&field.ty, &mut |ty| found |= matches!(ty, Type::Bytes));
+        }
+        found
+    }
+
+    /// Returns whether the schema holds a map anywhere, including as a list element or in a oneof.
+    /// Many target languages have no map type that fits the wire format, so their generators
+    /// reject these schemas up front.
+    pub fn uses_maps(&self) -> bool {
+        let mut found = false;
+        for field in self.sequences.iter().flat_map(|s| &s.fields) {
+            crate::walk_types(&field.ty, &mut |ty| found |= matches!(ty, Type::Map(_, _)));
+        }
+        found
+    }
+}
+
+impl Enum {
+    /// Returns whether the enum is backed by a signed integer, which is the case if its base type is
+    /// signed or, if it has no base type, if any of its variants are negative.
+    pub fn is_signed(&self) -> bool {
+        match &self.base {
+            Some(base) => matches!(
+                base,
+                Primitive::I8 | Primitive::I16 | Primitive::I32 | Primitive::I64
+            ),
+            None => self.variants.iter().any(|v| v.value < 0),
+        }
+    }
+}
+
+impl Sequence {
+    /// Get the size of the sequence in bytes. This is the sum of the fixed sizes of its fields, and
+    /// does not account for any dynamic data that is added to the end of the structure.
+    pub fn static_size(&self) -> usize {
+        self.fields.iter().map(|f| f.ty.size()).sum()
+    }
+}
+
+impl Field {
+    /// Get the offset of the first byte after the field, relative to the start of its sequence. This
+    /// is only meaningful for fields of a sequence, since the index of a oneof field is not an offset.
+    pub fn end_offset(&self) -> usize {
+        self.index + self.ty.size()
+    }
 }
 
 impl Type {
@@ -102,10 +221,57 @@ impl Type {
             Self::Sequence(_) => 2, // 16-bit offset to actual sequence.
             Self::Enum(_, s) => *s, // Size depends on enum values.
             Self::Array(_) => 4,    // 16-bit array length + 16-bit offset to actual array.
+            Self::FixedArray(t, n) => n * t.size(), // Elements are stored inline.
+            Self::Map(_, _) => 4,   // 16-bit entry count + 16-bit offset to actual entries.
             Self::String => 2,      // 16-bit offset.
+            Self::Bytes => 2,       // 16-bit offset to a length-prefixed buffer.
             Self::OneOf(_) => 3,    // 8-bit index + 16-bit offset to actual field.
         }
     }
+
+    /// Returns whether the type stores data in the dynamic section of a message, after the fixed
+    /// size fields. This is the case for sequences, lists, maps, strings, byte buffers, and oneofs.
+    pub fn is_dynamic(&self) -> bool {
+        matches!(
+            self,
+            Self::Sequence(_)
+                | Self::Array(_)
+                | Self::Map(_, _)
+                | Self::String
+                | Self::Bytes
+                | Self::OneOf(_)
+        )
+    }
+
+    /// Get the element type of a list or fixed-size array, or `None` for any other type.
+    pub fn inner(&self) -> Option<&Type> {
+        match self {
+            Self::Array(t) | Self::FixedArray(t, _) => Some(t),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    /// Formats the type the way it is written in a schema. Oneofs are written on one line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Primitive(p) => write!(f, "{}", p),
+            Self::Sequence(name) | Self::Enum(name, _) => write!(f, "{}", name),
+            Self::Array(t) => write!(f, "[{}]", t),
+            Self::FixedArray(t, n) => write!(f, "[{}; {}]", t, n),
+            Self::Map(k, v) => write!(f, "map<{}, {}>", k, v),
+            Self::String => write!(f, "string"),
+            Self::Bytes => write!(f, "bytes"),
+            Self::OneOf(fields) => {
+                write!(f, "oneof {{")?;
+                for field in fields {
+                    write!(f, " {}: {};", field.name, field.ty)?;
+                }
+                write!(f, " }}")
+            }
+        }
+    }
 }
 
 impl Primitive {
@@ -125,6 +291,84 @@ impl Primitive {
             Primitive::F64 => 8,
         }
     }
+
+    /// Get the range of values that the primitive can hold.
+    pub fn range(&self) -> PrimitiveRange {
+        use PrimitiveRange::{Float, Integer};
+        match self {
+            Primitive::Bool => Integer { min: 0, max: 1 },
+            Primitive::I8 => Integer {
+                min: i8::MIN.into(),
+                max: i8::MAX as u128,
+            },
+            Primitive::I16 => Integer {
+                min: i16::MIN.into(),
+                max: i16::MAX as u128,
+            },
+            Primitive::I32 => Integer {
+                min: i32::MIN.into(),
+                max: i32::MAX as u128,
+            },
+            Primitive::I64 => Integer {
+                min: i64::MIN.into(),
+                max: i64::MAX as u128,
+            },
+            Primitive::U8 => Integer {
+                min: 0,
+                max: u8::MAX.into(),
+            },
+            Primitive::U16 => Integer {
+                min: 0,
+                max: u16::MAX.into(),
+            },
+            Primitive::U32 => Integer {
+                min: 0,
+                max: u32::MAX.into(),
+            },
+            Primitive::U64 => Integer {
+                min: 0,
+                max: u64::MAX.into(),
+            },
+            Primitive::F32 => Float {
+                min: f32::MIN.into(),
+                max: f32::MAX.into(),
+            },
+            Primitive::F64 => Float {
+                min: f64::MIN,
+                max: f64::MAX,
+            },
+        }
+    }
+
+    /// Check whether an integer can be stored in the primitive without losing its value. Float
+    /// types accept any integer within their finite range, even if it is rounded when stored.
+    pub fn fits(&self, value: i128) -> bool {
+        match self.range() {
+            PrimitiveRange::Integer { min, max } => {
+                value >= min && (value < 0 || value as u128 <= max)
+            }
+            PrimitiveRange::Float { min, max } => {
+                let value = value as f64;
+                value >= min && value <= max
+            }
+        }
+    }
+
+    /// Check whether a floating-point number can be stored in the primitive. Integer types only
+    /// accept whole numbers within their range. Float types accept infinities, NaN, and any finite
+    /// number within their finite range.
+    pub fn fits_float(&self, value: f64) -> bool {
+        match self.range() {
+            // `max as f64` may round up (e.g. `u64::MAX` becomes 2^64), so compare against the
+            // first value past the range instead.
+            PrimitiveRange::Integer { min, max } => {
+                value.fract() == 0.0 && value >= min as f64 && value < max as f64 + 1.0
+            }
+            PrimitiveRange::Float { min, max } => {
+                !value.is_finite() || (value >= min && value <= max)
+            }
+        }
+    }
 }
 
 impl fmt::Display for Primitive {
@@ -0,0 +1,142 @@
+//! Formats a schema as canonical SimpleBuffers source.
+
+use crate::{Enum, EnumVariant, Field, SBSchema, Sequence, Type};
+
+/// The indentation used for each level of nesting.
+const INDENT: &str = "    ";
+
+impl SBSchema {
+    /// Formats the schema as SimpleBuffers source, in a canonical style. Parsing the result gives
+    /// back the same schema.
+    ///
+    /// The package declaration comes first, then enums, followed by sequences, each in the order
+    /// they are stored in the schema. Blocks are indented by four spaces, and the types of fields
+    /// and the values of enum variants are aligned within each block. Doc comments are kept, but
+    /// anything that is not part of the parsed schema is lost: regular comments, imports (their
+    /// declarations are inlined), aliases (they are expanded), and preprocessor directives. Enum
+    /// values are written in decimal.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort_enum_variants` - Whether to sort enum variants by value instead of keeping their
+    ///   original order.
+    pub fn to_source(&self, sort_enum_variants: bool) -> String {
+        let blocks: Vec<String> = self
+            .package
+            .iter()
+            .map(|package| format!("package {};", package))
+            .chain(
+                self.enums
+                    .iter()
+                    .map(|e| format_enum(e, sort_enum_variants)),
+            )
+            .chain(self.sequences.iter().map(format_sequence))
+            .collect();
+
+        let mut source = blocks.join("\n\n");
+        if !source.is_empty() {
+            source.push('\n');
+        }
+        source
+    }
+}
+
+/// Formats a doc comment as `///` lines at the given indentation, or returns an empty string if
+/// there is no doc comment.
+fn format_doc(doc: &Option<String>, indent: &str) -> String {
+    let Some(doc) = doc else {
+        return String::new();
+    };
+    doc.split('\n')
+        .map(|line| match line {
+            "" => format!("{indent}///\n"),
+            _ => format!("{indent}/// {line}\n"),
+        })
+        .collect()
+}
+
+/// Formats an enum.
+fn format_enum(data: &Enum, sort_variants: bool) -> String {
+    let mut variants: Vec<&EnumVariant> = data.variants.iter().collect();
+    if sort_variants {
+        variants.sort_by_key(|v| v.value);
+    }
+
+    let doc = format_doc(&data.doc, "");
+    let header = match &data.base {
+        Some(base) => format!("enum {} : {}", data.name, base),
+        None => format!("enum {}", data.name),
+    };
+    if variants.is_empty() {
+        return format!("{doc}{header} {{}}");
+    }
+
+    let width = variants
+        .iter()
+        .map(|v| v.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let variants: String = variants
+        .iter()
+        .map(|v| {
+            format!(
+                "{}{INDENT}{:<width$} = {};\n",
+                format_doc(&v.doc, INDENT),
+                v.name,
+                v.value
+            )
+        })
+        .collect();
+    format!("{doc}{header} {{\n{variants}}}")
+}
+
+/// Formats a sequence.
+fn format_sequence(seq: &Sequence) -> String {
+    let doc = format_doc(&seq.doc, "");
+    if seq.fields.is_empty() {
+        return format!("{doc}sequence {} {{}}", seq.name);
+    }
+    format!(
+        "{doc}sequence {} {{\n{}}}",
+        seq.name,
+        format_fields(&seq.fields, INDENT)
+    )
+}
+
+/// Formats the fields of a sequence or oneof, one per line, at the given indentation. Field types
+/// are aligned to the longest field name.
+fn format_fields(fields: &[Field], indent: &str) -> String {
+    let width = fields
+        .iter()
+        .map(|f| f.name.chars().count() + 1)
+        .max()
+        .unwrap_or(0);
+
+    fields
+        .iter()
+        .map(|f| {
+            format!(
+                "{}{indent}{:<width$} {};\n",
+                format_doc(&f.doc, indent),
+                format!("{}:", f.name),
+                format_type(&f.ty, indent)
+            )
+        })
+        .collect()
+}
+
+/// Formats a type. Oneofs span several lines, with their fields indented one level deeper than
+/// `indent` and the closing brace at `indent`.
+fn format_type(ty: &Type, indent: &str) -> String {
+    match ty {
+        Type::Array(t) => format!("[{}]", format_type(t, indent)),
+        Type::FixedArray(t, len) => format!("[{}; {}]", format_type(t, indent), len),
+        Type::Map(k, v) => format!("map<{}, {}>", k, format_type(v, indent)),
+        Type::OneOf(subfields) if subfields.is_empty() => "oneof {}".to_string(),
+        Type::OneOf(subfields) => format!(
+            "oneof {{\n{}{indent}}}",
+            format_fields(subfields, &format!("{indent}{INDENT}"))
+        ),
+        _ => ty.to_string(),
+    }
+}
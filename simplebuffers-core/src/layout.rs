@@ -0,0 +1,37 @@
+//! Reorders the fields of sequences for generators that map sequences to native structs.
+
+use crate::{SBSchema, Type};
+
+impl SBSchema {
+    /// Reorder the fields of every sequence so that fields with the largest alignment come first,
+    /// and recompute their offsets. Native structs that mirror the sequences then need less
+    /// padding. Fields with the same alignment keep their relative order, and oneof fields are
+    /// left alone, since their indices are not offsets.
+    ///
+    /// This changes the wire layout of any sequence whose fields move, so data written before the
+    /// change cannot be read after it. It applies to every sequence in the schema, including
+    /// imported ones, so that all code generated from the schema agrees on the layout.
+    pub fn optimize_field_order(&mut self) {
+        for seq in &mut self.sequences {
+            seq.fields
+                .sort_by_key(|f| std::cmp::Reverse(alignment(&f.ty)));
+            let mut offset = 0;
+            for field in &mut seq.fields {
+                field.index = offset;
+                offset = field.end_offset();
+            }
+        }
+    }
+}
+
+/// Get the alignment in bytes that a type would need as a member of a native struct. Offsets and
+/// lengths are 16 bits wide, and a oneof starts with its 8-bit index.
+fn alignment(ty: &Type) -> usize {
+    match ty {
+        Type::Primitive(p) => p.size(),
+        Type::Enum(_, size) => *size,
+        Type::FixedArray(element, _) => alignment(element),
+        Type::Sequence(_) | Type::Array(_) | Type::Map(_, _) | Type::String | Type::Bytes => 2,
+        Type::OneOf(_) => 1,
+    }
+}
@@ -1,5 +1,16 @@
 //! A collection of common data types that are used by both the compiler and code generators.
 
+pub mod codec;
+pub mod diff;
 mod dtypes;
+mod format;
+mod layout;
+mod order;
+mod stats;
+mod validate;
+mod visit;
 
 pub use dtypes::*;
+pub use stats::SchemaStats;
+pub use validate::MAX_ONEOF_FIELDS;
+pub use visit::*;
@@ -0,0 +1,97 @@
+//! Orders sequences for generators that cannot refer to a type before it is defined.
+
+use crate::{SBSchema, Sequence, Type};
+
+impl SBSchema {
+    /// Get the sequences of the schema in an order where each sequence comes after every sequence
+    /// it holds by value. A sequence is held by value when it is the type of a field, or of a field
+    /// of a oneof. Sequences held in lists are not considered, since lists are always stored behind
+    /// a level of indirection. Otherwise, sequences keep the order in which they are declared.
+    ///
+    /// # Returns
+    ///
+    /// The ordered sequences, or an error describing a cycle of sequences that hold each other by
+    /// value, which cannot be ordered.
+    pub fn sequences_in_dependency_order(&self) -> Result<Vec<&Sequence>, String> {
+        self.order_sequences(true)
+    }
+
+    /// Like [SBSchema::sequences_in_dependency_order], but only sequences that are the type of a
+    /// field are considered, not sequences in oneofs. This suits generators that store the fields of
+    /// oneofs behind pointers. Since the compiler rejects sequences that contain themselves through
+    /// their fields, this only fails for schemas that were not validated.
+    pub fn sequences_in_field_dependency_order(&self) -> Result<Vec<&Sequence>, String> {
+        self.order_sequences(false)
+    }
+
+    /// Get the sequences of the schema in dependency order.
+    ///
+    /// # Arguments
+    ///
+    /// * `through_oneofs` - Whether sequences in oneofs are dependencies.
+    fn order_sequences(&self, through_oneofs: bool) -> Result<Vec<&Sequence>, String> {
+        let mut ordered = Vec::with_capacity(self.sequences.len());
+        for sequence in &self.sequences {
+            let mut path = vec![];
+            self.visit_in_dependency_order(sequence, through_oneofs, &mut path, &mut ordered)?;
+        }
+        Ok(ordered)
+    }
+
+    /// Push a sequence to `ordered` after the sequences it depends on.
+    ///
+    /// # Arguments
+    ///
+    /// * `sequence` - The sequence to visit.
+    /// * `through_oneofs` - Whether sequences in oneofs are dependencies.
+    /// * `path` - The names of the sequences that are currently being visited, used to find cycles.
+    /// * `ordered` - The sequences that have been ordered so far.
+    fn visit_in_dependency_order<'a>(
+        &'a self,
+        sequence: &'a Sequence,
+        through_oneofs: bool,
+        path: &mut Vec<&'a str>,
+        ordered: &mut Vec<&'a Sequence>,
+    ) -> Result<(), String> {
+        if ordered.iter().any(|s| s.name == sequence.name) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|name| *name == sequence.name) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(&sequence.name);
+            return Err(format!(
+                "Sequences `{}` hold each other by value, so they cannot be ordered",
+                cycle.join("` -> `")
+            ));
+        }
+
+        path.push(&sequence.name);
+        let mut dependencies = vec![];
+        for field in &sequence.fields {
+            value_dependencies(&field.ty, through_oneofs, &mut dependencies);
+        }
+        for name in dependencies {
+            if let Some(dependency) = self.sequences.iter().find(|s| s.name == name) {
+                self.visit_in_dependency_order(dependency, through_oneofs, path, ordered)?;
+            }
+        }
+        path.pop();
+
+        ordered.push(sequence);
+        Ok(())
+    }
+}
+
+/// Push the names of the sequences that a type holds by value to `dependencies`. Sequences in
+/// oneofs are only included if `through_oneofs` is set.
+fn value_dependencies<'a>(ty: &'a Type, through_oneofs: bool, dependencies: &mut Vec<&'a str>) {
+    match ty {
+        Type::Sequence(name) => dependencies.push(name),
+        Type::OneOf(subfields) if through_oneofs => {
+            for field in subfields {
+                value_dependencies(&field.ty, through_oneofs, dependencies);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,56 @@
+//! Summarizes the contents of a schema, for tools that estimate message sizes.
+
+use crate::{walk_fields, walk_types, SBSchema, Type};
+
+/// Counts of the items in a schema, and the range of static sizes of its sequences.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaStats {
+    /// The number of sequences.
+    pub sequences: usize,
+
+    /// The number of enums.
+    pub enums: usize,
+
+    /// The number of oneofs, including oneofs nested in other oneofs, lists, and maps.
+    pub oneofs: usize,
+
+    /// The number of fields of every sequence, including the fields of any oneofs they contain.
+    pub fields: usize,
+
+    /// The smallest [static size](crate::Sequence::static_size) of any sequence, or `None` if the
+    /// schema has no sequences.
+    pub min_static_size: Option<usize>,
+
+    /// The largest [static size](crate::Sequence::static_size) of any sequence, or `None` if the
+    /// schema has no sequences.
+    pub max_static_size: Option<usize>,
+}
+
+impl SBSchema {
+    /// Count the sequences, enums, oneofs, and fields of the schema, and find the range of static
+    /// sizes of its sequences. Static sizes do not include dynamic data, so they are the smallest
+    /// size that a message can have.
+    pub fn stats(&self) -> SchemaStats {
+        let mut stats = SchemaStats {
+            sequences: self.sequences.len(),
+            enums: self.enums.len(),
+            ..SchemaStats::default()
+        };
+
+        for sequence in &self.sequences {
+            walk_fields(sequence, &mut |_| stats.fields += 1);
+            for field in &sequence.fields {
+                walk_types(&field.ty, &mut |ty| {
+                    if let Type::OneOf(_) = ty {
+                        stats.oneofs += 1;
+                    }
+                });
+            }
+        }
+
+        let sizes = self.sequences.iter().map(|s| s.static_size());
+        stats.min_static_size = sizes.clone().min();
+        stats.max_static_size = sizes.max();
+        stats
+    }
+}
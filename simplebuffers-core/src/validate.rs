@@ -0,0 +1,289 @@
+//! Checks the invariants that code generators rely on.
+
+use std::collections::HashSet;
+
+use crate::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+
+/// The most fields a oneof can have, since its tag is stored in a single byte.
+pub const MAX_ONEOF_FIELDS: usize = 256;
+
+impl SBSchema {
+    /// Check that the schema is internally consistent. Schemas produced by the compiler always
+    /// are, but schemas built by hand may not be.
+    ///
+    /// The following invariants are checked:
+    ///
+    /// - Sequence and enum names are unique, as are the names of the fields of each sequence or
+    ///   oneof and the names of the variants of each enum.
+    /// - Every enum has a size of 1, 2, 4, or 8 bytes that can hold all of its variants, and that
+    ///   matches its base type if it has one.
+    /// - Every sequence and enum that a field refers to exists, and enum fields have the size of
+    ///   their enum.
+    /// - Sequence fields are laid out back to back, as checked by [Sequence::validate_layout].
+    /// - The static data of every sequence fits in the 16-bit offsets of the wire format.
+    /// - No sequence contains itself through a chain of sequence fields, since it could only be
+    ///   written by nesting it forever. Lists and oneofs break the chain.
+    /// - Every oneof has between 1 and [MAX_ONEOF_FIELDS] fields, and its fields have the indices
+    ///   `0..n`, in order.
+    /// - Fixed-size arrays are only used as sequence fields, are not empty, and hold primitives or
+    ///   enums.
+    /// - Map keys are primitives, enums, or strings.
+    ///
+    /// # Returns
+    ///
+    /// A description of every violated invariant, if there are any.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = vec![];
+
+        let mut type_names = HashSet::new();
+        for name in self
+            .sequences
+            .iter()
+            .map(|s| &s.name)
+            .chain(self.enums.iter().map(|e| &e.name))
+        {
+            if !type_names.insert(name) {
+                errors.push(format!("Type `{}` is defined more than once", name));
+            }
+        }
+
+        for e in &self.enums {
+            validate_enum(e, &mut errors);
+        }
+
+        for seq in &self.sequences {
+            check_unique(
+                seq.fields.iter().map(|f| &f.name),
+                &seq.name,
+                "Field",
+                &mut errors,
+            );
+            if let Err(e) = seq.validate_layout() {
+                errors.push(e);
+            }
+            if seq.static_size() > u16::MAX as usize {
+                errors.push(format!(
+                    "Sequence `{}` has a static size of {} bytes, but offsets can only address {} \
+                     bytes",
+                    seq.name,
+                    seq.static_size(),
+                    u16::MAX
+                ));
+            }
+            for field in &seq.fields {
+                validate_type(
+                    self,
+                    &field.ty,
+                    &format!("{}::{}", seq.name, field.name),
+                    &mut errors,
+                );
+            }
+        }
+
+        // Oneofs store their fields behind pointers, so only sequence fields can form a cycle.
+        if let Err(e) = self.sequences_in_field_dependency_order() {
+            errors.push(e);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl Sequence {
+    /// Check that the fields of the sequence are laid out back to back. The first field must be at
+    /// offset 0, and every other field must start where the previous one ends, since the wire format
+    /// has no padding and readers find the end of the sequence by adding up the sizes of its fields.
+    ///
+    /// # Returns
+    ///
+    /// A description of the first field that is out of place, if there is one.
+    pub fn validate_layout(&self) -> Result<(), String> {
+        let mut expected = 0;
+        for field in &self.fields {
+            if field.index < expected {
+                return Err(format!(
+                    "Field `{}::{}` has offset {}, which overlaps the previous field (expected {})",
+                    self.name, field.name, field.index, expected
+                ));
+            }
+            if field.index > expected {
+                return Err(format!(
+                    "Field `{}::{}` has offset {}, which leaves a gap after the previous field \
+                     (expected {})",
+                    self.name, field.name, field.index, expected
+                ));
+            }
+            expected = field.end_offset();
+        }
+        Ok(())
+    }
+}
+
+/// Check that an enum's size matches its base type and can hold all of its variants, and that its
+/// variant names are unique, pushing any problems to `errors`.
+fn validate_enum(e: &Enum, errors: &mut Vec<String>) {
+    check_unique(
+        e.variants.iter().map(|v| &v.name),
+        &e.name,
+        "Variant",
+        errors,
+    );
+
+    let backing = match (e.size, e.is_signed()) {
+        (1, false) => Primitive::U8,
+        (2, false) => Primitive::U16,
+        (4, false) => Primitive::U32,
+        (8, false) => Primitive::U64,
+        (1, true) => Primitive::I8,
+        (2, true) => Primitive::I16,
+        (4, true) => Primitive::I32,
+        (8, true) => Primitive::I64,
+        (size, _) => {
+            errors.push(format!(
+                "Enum `{}` has a size of {} (expected 1, 2, 4, or 8)",
+                e.name, size
+            ));
+            return;
+        }
+    };
+    if let Some(base) = &e.base {
+        if *base != backing {
+            errors.push(format!(
+                "Enum `{}` has a base type of {} but is backed by {}",
+                e.name, base, backing
+            ));
+        }
+    }
+    for variant in &e.variants {
+        if !backing.fits(variant.value) {
+            errors.push(format!(
+                "Variant `{}::{}` has value {}, which does not fit in the enum's size of {}",
+                e.name, variant.name, variant.value, e.size
+            ));
+        }
+    }
+}
+
+/// Check that no name appears twice, pushing a problem to `errors` for each duplicate.
+///
+/// # Arguments
+///
+/// * `names` - The names to check.
+/// * `parent` - The name of the item that the names belong to.
+/// * `kind` - What the names are, for error messages (such as `Field`).
+/// * `errors` - A list to push any problems onto.
+fn check_unique<'a>(
+    names: impl Iterator<Item = &'a String>,
+    parent: &str,
+    kind: &str,
+    errors: &mut Vec<String>,
+) {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name) {
+            errors.push(format!(
+                "{} `{}::{}` is defined more than once",
+                kind, parent, name
+            ));
+        }
+    }
+}
+
+/// Check the references, oneofs, and fixed-size arrays contained in a type, pushing any problems to
+/// `errors`.
+fn validate_type(schema: &SBSchema, ty: &Type, path: &str, errors: &mut Vec<String>) {
+    match ty {
+        Type::Sequence(name) if !schema.sequences.iter().any(|s| &s.name == name) => {
+            errors.push(format!(
+                "Field `{}` refers to an unknown sequence `{}`",
+                path, name
+            ));
+        }
+        Type::Enum(name, size) => match schema.enums.iter().find(|e| &e.name == name) {
+            None => errors.push(format!(
+                "Field `{}` refers to an unknown enum `{}`",
+                path, name
+            )),
+            Some(e) if e.size as usize != *size => errors.push(format!(
+                "Field `{}` has size {}, but enum `{}` has size {}",
+                path, size, name, e.size
+            )),
+            Some(_) => {}
+        },
+        Type::Array(element) => {
+            reject_fixed_array(element, path, errors);
+            validate_type(schema, element, path, errors);
+        }
+        Type::FixedArray(element, len) => {
+            if *len == 0 {
+                errors.push(format!("Fixed-size array `{}` has a length of 0", path));
+            }
+            if !matches!(element.as_ref(), Type::Primitive(_) | Type::Enum(_, _)) {
+                errors.push(format!(
+                    "Fixed-size array `{}` holds a type other than a primitive or an enum",
+                    path
+                ));
+            }
+            validate_type(schema, element, path, errors);
+        }
+        Type::Map(key, value) => {
+            if !matches!(
+                key.as_ref(),
+                Type::Primitive(_) | Type::Enum(_, _) | Type::String
+            ) {
+                errors.push(format!(
+                    "Map `{}` has a key type other than a primitive, an enum, or a string",
+                    path
+                ));
+            }
+            reject_fixed_array(value, path, errors);
+            validate_type(schema, key, path, errors);
+            validate_type(schema, value, path, errors);
+        }
+        Type::OneOf(subfields) => validate_oneof(schema, subfields, path, errors),
+        _ => {}
+    }
+}
+
+/// Check that a type used as a list element, map value, or oneof field is not a fixed-size array,
+/// pushing a problem to `errors` if it is.
+fn reject_fixed_array(ty: &Type, path: &str, errors: &mut Vec<String>) {
+    if let Type::FixedArray(_, _) = ty {
+        errors.push(format!(
+            "Fixed-size array `{}` is not a sequence field",
+            path
+        ));
+    }
+}
+
+/// Check that a oneof has between 1 and [MAX_ONEOF_FIELDS] fields, and that its fields have unique
+/// names and the indices `0..n`, pushing any problems to `errors`.
+fn validate_oneof(schema: &SBSchema, subfields: &[Field], path: &str, errors: &mut Vec<String>) {
+    if subfields.is_empty() {
+        errors.push(format!("Oneof `{}` has no fields", path));
+    }
+    if subfields.len() > MAX_ONEOF_FIELDS {
+        errors.push(format!(
+            "Oneof `{}` has {} fields (at most {} are allowed)",
+            path,
+            subfields.len(),
+            MAX_ONEOF_FIELDS
+        ));
+    }
+    check_unique(subfields.iter().map(|f| &f.name), path, "Field", errors);
+    for (expected, field) in subfields.iter().enumerate() {
+        if field.index != expected {
+            errors.push(format!(
+                "Oneof field `{}::{}` has index {} (expected {})",
+                path, field.name, field.index, expected
+            ));
+        }
+        let field_path = format!("{}::{}", path, field.name);
+        reject_fixed_array(&field.ty, &field_path, errors);
+        validate_type(schema, &field.ty, &field_path, errors);
+    }
+}
@@ -0,0 +1,60 @@
+//! Utilities for walking the types and fields of a schema.
+
+use crate::{Field, Sequence, Type};
+
+/// Call `f` on a type and every type nested inside it, in depth-first order. A type is visited
+/// before the types it contains: the element type of a list or fixed-size array comes after the
+/// array, the key and value types of a map come after the map, and the types of a oneof's fields
+/// come after the oneof, in the order they are declared.
+///
+/// Sequence and enum types are not expanded, since they only refer to other parts of the schema.
+///
+/// # Arguments
+///
+/// * `ty` - The type to walk.
+/// * `f` - The function to call on each type.
+pub fn walk_types<F: FnMut(&Type)>(ty: &Type, f: &mut F) {
+    f(ty);
+    match ty {
+        Type::Array(element) | Type::FixedArray(element, _) => walk_types(element, f),
+        Type::Map(key, value) => {
+            walk_types(key, f);
+            walk_types(value, f);
+        }
+        Type::OneOf(subfields) => {
+            for field in subfields {
+                walk_types(&field.ty, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Call `f` on every field of a sequence, including the fields of any oneofs it contains. Fields
+/// are visited in the order they are declared, and a oneof field is visited before its own fields.
+///
+/// # Arguments
+///
+/// * `sequence` - The sequence whose fields to walk.
+/// * `f` - The function to call on each field.
+pub fn walk_fields<F: FnMut(&Field)>(sequence: &Sequence, f: &mut F) {
+    fn walk<F: FnMut(&Field)>(field: &Field, f: &mut F) {
+        f(field);
+
+        // A oneof may be the element type of a list or the value type of a map, so look through
+        // them to find it.
+        let mut ty = &field.ty;
+        while let Type::Array(element) | Type::FixedArray(element, _) | Type::Map(_, element) = ty {
+            ty = element;
+        }
+        if let Type::OneOf(subfields) = ty {
+            for subfield in subfields {
+                walk(subfield, f);
+            }
+        }
+    }
+
+    for field in &sequence.fields {
+        walk(field, f);
+    }
+}
@@ -0,0 +1,224 @@
+use simplebuffers_core::codec::{decode, encode, Value, MAX_DEPTH};
+use simplebuffers_core::{Enum, EnumVariant, Field, Primitive, SBSchema, Sequence, Type};
+
+fn field(name: &str, ty: Type, index: usize) -> Field {
+    Field {
+        name: name.to_string(),
+        ty,
+        index,
+        doc: None,
+    }
+}
+
+/// Returns a sequence with its fields at running offsets.
+fn sequence(name: &str, fields: Vec<(&str, Type)>) -> Sequence {
+    let mut offset = 0;
+    let fields = fields
+        .into_iter()
+        .map(|(name, ty)| {
+            let f = field(name, ty, offset);
+            offset = f.end_offset();
+            f
+        })
+        .collect();
+    Sequence {
+        name: name.to_string(),
+        fields,
+        doc: None,
+    }
+}
+
+fn variant(name: &str, value: i128) -> EnumVariant {
+    EnumVariant {
+        name: name.to_string(),
+        value,
+        doc: None,
+    }
+}
+
+fn enm(name: &str, size: u8, variants: Vec<EnumVariant>) -> Enum {
+    Enum {
+        name: name.to_string(),
+        size,
+        base: None,
+        variants,
+        doc: None,
+    }
+}
+
+fn object(entries: Vec<(&str, Value)>) -> Value {
+    Value::Object(
+        entries
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect(),
+    )
+}
+
+/// Returns a schema that uses every kind of type.
+fn message_schema() -> SBSchema {
+    SBSchema {
+        package: None,
+        sequences: vec![
+            sequence(
+                "Point",
+                vec![
+                    ("x", Type::Primitive(Primitive::U16)),
+                    ("y", Type::Primitive(Primitive::I32)),
+                ],
+            ),
+            sequence(
+                "Message",
+                vec![
+                    ("id", Type::Primitive(Primitive::U32)),
+                    ("name", Type::String),
+                    ("origin", Type::Sequence("Point".to_string())),
+                    (
+                        "path",
+                        Type::Array(Box::new(Type::Sequence("Point".to_string()))),
+                    ),
+                    ("mode", Type::Enum("Mode".to_string(), 1)),
+                    ("mask", Type::Enum("Mask".to_string(), 8)),
+                    ("payload", Type::Bytes),
+                    (
+                        "weights",
+                        Type::FixedArray(Box::new(Type::Primitive(Primitive::F32)), 2),
+                    ),
+                    (
+                        "body",
+                        Type::OneOf(vec![
+                            field("ping", Type::Primitive(Primitive::U8), 0),
+                            field("text", Type::String, 1),
+                        ]),
+                    ),
+                ],
+            ),
+        ],
+        enums: vec![
+            enm("Mode", 1, vec![variant("off", 0), variant("on", 1)]),
+            enm(
+                "Mask",
+                8,
+                vec![variant("none", 0), variant("all", u64::MAX as i128)],
+            ),
+        ],
+    }
+}
+
+fn message() -> Value {
+    let point = |x, y| object(vec![("x", Value::UInt(x)), ("y", Value::Int(y))]);
+    object(vec![
+        ("id", Value::UInt(7)),
+        ("name", Value::String("hello".to_string())),
+        ("origin", point(1, -1)),
+        ("path", Value::List(vec![point(2, -2), point(3, -3)])),
+        ("mode", Value::String("on".to_string())),
+        ("mask", Value::String("all".to_string())),
+        (
+            "payload",
+            Value::List(vec![Value::UInt(0), Value::UInt(255)]),
+        ),
+        (
+            "weights",
+            Value::List(vec![Value::Float(0.5), Value::Float(-2.0)]),
+        ),
+        (
+            "body",
+            object(vec![("text", Value::String("pong".to_string()))]),
+        ),
+    ])
+}
+
+#[test]
+fn round_trips() {
+    let schema = message_schema();
+    let encoded = encode(&schema, "Message", &message()).unwrap();
+    assert_eq!(decode(&schema, "Message", &encoded).unwrap(), message());
+}
+
+#[test]
+fn rejects_truncated_buffers() {
+    let schema = message_schema();
+    let encoded = encode(&schema, "Message", &message()).unwrap();
+    for len in 0..encoded.len() {
+        assert!(
+            decode(&schema, "Message", &encoded[..len]).is_err(),
+            "decoding the first {} of {} bytes succeeded",
+            len,
+            encoded.len()
+        );
+    }
+}
+
+#[test]
+fn rejects_self_referential_offsets() {
+    // A node whose list of children starts at the node itself. The buffer is padded, so that it
+    // has more bytes than the decoder can nest sequences.
+    let schema = SBSchema {
+        package: None,
+        sequences: vec![sequence(
+            "Node",
+            vec![(
+                "children",
+                Type::Array(Box::new(Type::Sequence("Node".to_string()))),
+            )],
+        )],
+        enums: vec![],
+    };
+    let mut buf = vec![1, 0, 0, 0];
+    buf.resize(1024, 0);
+    let err = decode(&schema, "Node", &buf).unwrap_err();
+    assert!(
+        err.contains(&format!("more than {} levels deep", MAX_DEPTH)),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn rejects_shared_offsets() {
+    // A chain of nodes where both lists of every node point at the next node. Decoding it would
+    // visit the last node 2^20 times.
+    let schema = SBSchema {
+        package: None,
+        sequences: vec![sequence(
+            "Tree",
+            vec![
+                (
+                    "left",
+                    Type::Array(Box::new(Type::Sequence("Tree".to_string()))),
+                ),
+                (
+                    "right",
+                    Type::Array(Box::new(Type::Sequence("Tree".to_string()))),
+                ),
+            ],
+        )],
+        enums: vec![],
+    };
+    let mut buf = vec![];
+    for _ in 0..20 {
+        buf.extend_from_slice(&[1, 0, 8, 0, 1, 0, 4, 0]);
+    }
+    buf.extend_from_slice(&[0; 8]);
+
+    let err = decode(&schema, "Tree", &buf).unwrap_err();
+    assert!(err.contains("more sequences than it has bytes"), "{}", err);
+}
+
+#[test]
+fn rejects_invalid_enum_sizes() {
+    for size in [0, 9] {
+        let schema = SBSchema {
+            package: None,
+            sequences: vec![sequence(
+                "Flags",
+                vec![("mode", Type::Enum("Mode".to_string(), size))],
+            )],
+            enums: vec![enm("Mode", size as u8, vec![variant("off", 0)])],
+        };
+        let value = object(vec![("mode", Value::String("off".to_string()))]);
+        assert!(encode(&schema, "Flags", &value).is_err());
+        assert!(decode(&schema, "Flags", &[0; 16]).is_err());
+    }
+}
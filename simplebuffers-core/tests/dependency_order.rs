@@ -0,0 +1,116 @@
+use simplebuffers_core::{Field, Primitive, SBSchema, Sequence, Type};
+
+fn field(name: &str, ty: Type) -> Field {
+    Field {
+        name: name.to_string(),
+        ty,
+        index: 0,
+        doc: None,
+    }
+}
+
+fn sequence(name: &str, fields: Vec<Field>) -> Sequence {
+    Sequence {
+        name: name.to_string(),
+        fields,
+        doc: None,
+    }
+}
+
+fn seq(name: &str) -> Type {
+    Type::Sequence(name.to_string())
+}
+
+fn names(schema: &SBSchema) -> Vec<&str> {
+    schema
+        .sequences_in_dependency_order()
+        .unwrap()
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect()
+}
+
+#[test]
+fn dependencies_come_first() {
+    let schema = SBSchema {
+        package: None,
+        sequences: vec![
+            sequence("Message", vec![field("header", seq("Header"))]),
+            sequence(
+                "Header",
+                vec![field(
+                    "source",
+                    Type::OneOf(vec![
+                        field("id", Type::Primitive(Primitive::U32)),
+                        field("address", seq("Address")),
+                    ]),
+                )],
+            ),
+            sequence("Unrelated", vec![]),
+            sequence("Address", vec![field("host", Type::String)]),
+        ],
+        enums: vec![],
+    };
+    assert_eq!(
+        names(&schema),
+        ["Address", "Header", "Message", "Unrelated"]
+    );
+}
+
+#[test]
+fn lists_do_not_create_dependencies() {
+    let schema = SBSchema {
+        package: None,
+        sequences: vec![
+            sequence(
+                "Tree",
+                vec![field("children", Type::Array(Box::new(seq("Tree"))))],
+            ),
+            sequence(
+                "Forest",
+                vec![field("trees", Type::Array(Box::new(seq("Tree"))))],
+            ),
+        ],
+        enums: vec![],
+    };
+    assert_eq!(names(&schema), ["Tree", "Forest"]);
+}
+
+#[test]
+fn cycles_are_reported() {
+    let schema = SBSchema {
+        package: None,
+        sequences: vec![
+            sequence("A", vec![field("b", seq("B"))]),
+            sequence(
+                "B",
+                vec![field("a", Type::OneOf(vec![field("a", seq("A"))]))],
+            ),
+        ],
+        enums: vec![],
+    };
+    let error = schema.sequences_in_dependency_order().unwrap_err();
+    assert!(error.contains("`A` -> `B` -> `A`"), "{}", error);
+}
+
+#[test]
+fn field_order_ignores_oneofs() {
+    let schema = SBSchema {
+        package: None,
+        sequences: vec![
+            sequence("A", vec![field("b", seq("B"))]),
+            sequence(
+                "B",
+                vec![field("a", Type::OneOf(vec![field("a", seq("A"))]))],
+            ),
+        ],
+        enums: vec![],
+    };
+    let names: Vec<&str> = schema
+        .sequences_in_field_dependency_order()
+        .unwrap()
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(names, ["B", "A"]);
+}
@@ -0,0 +1,99 @@
+use simplebuffers_core::{Field, Primitive, SBSchema, Sequence, Type};
+
+fn field(name: &str, ty: Type) -> Field {
+    Field {
+        name: name.to_string(),
+        ty,
+        index: 0,
+        doc: None,
+    }
+}
+
+fn prim(p: Primitive) -> Type {
+    Type::Primitive(p)
+}
+
+/// Returns a schema with one sequence, with its fields at running offsets.
+fn schema(fields: Vec<Field>) -> SBSchema {
+    let mut offset = 0;
+    let fields = fields
+        .into_iter()
+        .map(|f| {
+            let f = Field { index: offset, ..f };
+            offset = f.end_offset();
+            f
+        })
+        .collect();
+    SBSchema {
+        package: None,
+        sequences: vec![Sequence {
+            name: "Reading".to_string(),
+            fields,
+            doc: None,
+        }],
+        enums: vec![],
+    }
+}
+
+fn layout(schema: &SBSchema) -> Vec<(&str, usize)> {
+    schema.sequences[0]
+        .fields
+        .iter()
+        .map(|f| (f.name.as_str(), f.index))
+        .collect()
+}
+
+#[test]
+fn largest_alignment_comes_first() {
+    let mut schema = schema(vec![
+        field("flag", prim(Primitive::Bool)),
+        field("label", Type::String),
+        field("count", prim(Primitive::U32)),
+        field("choice", Type::OneOf(vec![field("a", prim(Primitive::U8))])),
+        field("kind", Type::Enum("Kind".to_string(), 2)),
+        field("time", prim(Primitive::F64)),
+        field(
+            "samples",
+            Type::FixedArray(Box::new(prim(Primitive::I16)), 3),
+        ),
+    ]);
+    let size = schema.sequences[0].static_size();
+    schema.optimize_field_order();
+
+    assert_eq!(
+        layout(&schema),
+        [
+            ("time", 0),
+            ("count", 8),
+            ("label", 12),
+            ("kind", 14),
+            ("samples", 16),
+            ("flag", 22),
+            ("choice", 23),
+        ]
+    );
+    assert_eq!(schema.sequences[0].static_size(), size);
+    assert_eq!(schema.sequences[0].validate_layout(), Ok(()));
+
+    // Oneof fields keep their indices.
+    match &schema.sequences[0].fields[6].ty {
+        Type::OneOf(choice) => assert_eq!(choice[0].index, 0),
+        ty => panic!("expected the oneof last, found {}", ty),
+    }
+}
+
+#[test]
+fn optimizing_is_idempotent() {
+    let mut once = schema(vec![
+        field("a", prim(Primitive::U8)),
+        field("b", prim(Primitive::U16)),
+        field("c", prim(Primitive::U8)),
+        field("d", prim(Primitive::U16)),
+    ]);
+    once.optimize_field_order();
+    assert_eq!(layout(&once), [("b", 0), ("d", 2), ("a", 4), ("c", 5)]);
+
+    let mut twice = once.clone();
+    twice.optimize_field_order();
+    assert_eq!(twice, once);
+}
@@ -0,0 +1,78 @@
+use simplebuffers_core::{Primitive, PrimitiveRange};
+
+#[test]
+fn u8_range() {
+    assert_eq!(
+        Primitive::U8.range(),
+        PrimitiveRange::Integer { min: 0, max: 255 }
+    );
+    assert!(Primitive::U8.fits(0));
+    assert!(Primitive::U8.fits(255));
+    assert!(!Primitive::U8.fits(256));
+    assert!(!Primitive::U8.fits(-1));
+    assert!(Primitive::U8.fits_float(255.0));
+    assert!(!Primitive::U8.fits_float(255.5));
+    assert!(!Primitive::U8.fits_float(256.0));
+}
+
+#[test]
+fn i8_range() {
+    assert_eq!(
+        Primitive::I8.range(),
+        PrimitiveRange::Integer {
+            min: -128,
+            max: 127
+        }
+    );
+    assert!(Primitive::I8.fits(-128));
+    assert!(Primitive::I8.fits(127));
+    assert!(!Primitive::I8.fits(-129));
+    assert!(!Primitive::I8.fits(128));
+    assert!(Primitive::I8.fits_float(-128.0));
+    assert!(!Primitive::I8.fits_float(-128.5));
+}
+
+#[test]
+fn u64_range() {
+    assert_eq!(
+        Primitive::U64.range(),
+        PrimitiveRange::Integer {
+            min: 0,
+            max: u64::MAX.into()
+        }
+    );
+    assert!(Primitive::U64.fits(u64::MAX.into()));
+    assert!(!Primitive::U64.fits(i128::from(u64::MAX) + 1));
+    assert!(!Primitive::U64.fits(-1));
+    // 2^64 is exactly representable as a float, but is one past the maximum.
+    assert!(!Primitive::U64.fits_float(18446744073709551616.0));
+    assert!(Primitive::U64.fits_float(9223372036854775808.0));
+}
+
+#[test]
+fn f32_range() {
+    assert_eq!(
+        Primitive::F32.range(),
+        PrimitiveRange::Float {
+            min: f32::MIN.into(),
+            max: f32::MAX.into()
+        }
+    );
+    assert!(Primitive::F32.fits(i128::from(u64::MAX)));
+    assert!(Primitive::F32.fits_float(f32::MAX.into()));
+    assert!(Primitive::F32.fits_float(f32::MIN.into()));
+    assert!(!Primitive::F32.fits_float(f64::MAX));
+    assert!(!Primitive::F32.fits_float(-1e39));
+    assert!(Primitive::F32.fits_float(f64::INFINITY));
+    assert!(Primitive::F32.fits_float(f64::NAN));
+}
+
+#[test]
+fn bool_range() {
+    assert_eq!(
+        Primitive::Bool.range(),
+        PrimitiveRange::Integer { min: 0, max: 1 }
+    );
+    assert!(Primitive::Bool.fits(1));
+    assert!(!Primitive::Bool.fits(2));
+}
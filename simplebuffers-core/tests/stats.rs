@@ -0,0 +1,70 @@
+use simplebuffers_core::{Field, Primitive, SBSchema, SchemaStats, Sequence, Type};
+
+fn field(name: &str, ty: Type) -> Field {
+    Field {
+        name: name.to_string(),
+        ty,
+        index: 0,
+        doc: None,
+    }
+}
+
+fn sequence(name: &str, fields: Vec<Field>) -> Sequence {
+    Sequence {
+        name: name.to_string(),
+        fields,
+        doc: None,
+    }
+}
+
+#[test]
+fn counts_nested_oneofs_and_fields() {
+    let schema = SBSchema {
+        package: None,
+        sequences: vec![
+            sequence(
+                "Message",
+                vec![
+                    field("id", Type::Primitive(Primitive::U32)),
+                    field(
+                        "body",
+                        Type::OneOf(vec![
+                            field("ping", Type::Primitive(Primitive::U8)),
+                            field(
+                                "batch",
+                                Type::Array(Box::new(Type::OneOf(vec![field(
+                                    "a",
+                                    Type::Primitive(Primitive::U8),
+                                )]))),
+                            ),
+                        ]),
+                    ),
+                ],
+            ),
+            sequence("Point", vec![field("x", Type::Primitive(Primitive::I16))]),
+        ],
+        enums: vec![],
+    };
+
+    assert_eq!(
+        schema.stats(),
+        SchemaStats {
+            sequences: 2,
+            enums: 0,
+            oneofs: 2,
+            fields: 6,
+            min_static_size: Some(2),
+            max_static_size: Some(7),
+        }
+    );
+}
+
+#[test]
+fn sizes_are_none_without_sequences() {
+    let schema = SBSchema {
+        package: None,
+        sequences: vec![],
+        enums: vec![],
+    };
+    assert_eq!(schema.stats(), SchemaStats::default());
+}
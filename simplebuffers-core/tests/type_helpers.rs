@@ -0,0 +1,84 @@
+use simplebuffers_core::{Field, Primitive, Sequence, Type};
+
+fn field(name: &str, ty: Type) -> Field {
+    Field {
+        name: name.to_string(),
+        ty,
+        index: 0,
+        doc: None,
+    }
+}
+
+#[test]
+fn display_matches_schema_syntax() {
+    assert_eq!(Type::Primitive(Primitive::U32).to_string(), "u32");
+    assert_eq!(Type::Sequence("Point".to_string()).to_string(), "Point");
+    assert_eq!(Type::Enum("Color".to_string(), 1).to_string(), "Color");
+    assert_eq!(Type::String.to_string(), "string");
+    assert_eq!(Type::Bytes.to_string(), "bytes");
+    assert_eq!(
+        Type::Array(Box::new(Type::Array(Box::new(Type::String)))).to_string(),
+        "[[string]]"
+    );
+    assert_eq!(
+        Type::FixedArray(Box::new(Type::Primitive(Primitive::F32)), 3).to_string(),
+        "[f32; 3]"
+    );
+    assert_eq!(
+        Type::OneOf(vec![
+            field("id", Type::Primitive(Primitive::U8)),
+            field("names", Type::Array(Box::new(Type::String))),
+        ])
+        .to_string(),
+        "oneof { id: u8; names: [string]; }"
+    );
+}
+
+#[test]
+fn is_dynamic() {
+    assert!(Type::Sequence("Point".to_string()).is_dynamic());
+    assert!(Type::Array(Box::new(Type::Primitive(Primitive::U8))).is_dynamic());
+    assert!(Type::String.is_dynamic());
+    assert!(Type::Bytes.is_dynamic());
+    assert!(Type::OneOf(vec![]).is_dynamic());
+    assert!(!Type::Primitive(Primitive::U8).is_dynamic());
+    assert!(!Type::Enum("Color".to_string(), 1).is_dynamic());
+    assert!(!Type::FixedArray(Box::new(Type::Primitive(Primitive::U8)), 4).is_dynamic());
+}
+
+#[test]
+fn inner() {
+    let list = Type::Array(Box::new(Type::String));
+    assert!(matches!(list.inner(), Some(Type::String)));
+    let fixed = Type::FixedArray(Box::new(Type::Primitive(Primitive::U8)), 4);
+    assert!(matches!(
+        fixed.inner(),
+        Some(Type::Primitive(Primitive::U8))
+    ));
+    assert!(Type::String.inner().is_none());
+}
+
+#[test]
+fn sequence_layout() {
+    let seq = Sequence {
+        name: "Reading".to_string(),
+        fields: vec![
+            field("id", Type::Primitive(Primitive::U32)),
+            Field {
+                index: 4,
+                ..field("label", Type::String)
+            },
+            Field {
+                index: 6,
+                ..field(
+                    "samples",
+                    Type::FixedArray(Box::new(Type::Primitive(Primitive::I16)), 3),
+                )
+            },
+        ],
+        doc: None,
+    };
+    let ends: Vec<usize> = seq.fields.iter().map(Field::end_offset).collect();
+    assert_eq!(ends, [4, 6, 12]);
+    assert_eq!(seq.static_size(), 12);
+}
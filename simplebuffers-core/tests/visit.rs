@@ -0,0 +1,68 @@
+use simplebuffers_core::{walk_fields, walk_types, Field, Primitive, Sequence, Type};
+
+fn field(name: &str, ty: Type) -> Field {
+    Field {
+        name: name.to_string(),
+        ty,
+        index: 0,
+        doc: None,
+    }
+}
+
+#[test]
+fn walk_types_visits_in_depth_first_order() {
+    let ty = Type::Array(Box::new(Type::OneOf(vec![
+        field("a", Type::Primitive(Primitive::U8)),
+        field("b", Type::Array(Box::new(Type::String))),
+        field("c", Type::Sequence("Other".to_string())),
+    ])));
+
+    let mut visited = vec![];
+    walk_types(&ty, &mut |t| {
+        visited.push(match t {
+            Type::Array(_) => "array",
+            Type::OneOf(_) => "oneof",
+            Type::Primitive(_) => "primitive",
+            Type::String => "string",
+            Type::Sequence(_) => "sequence",
+            _ => "other",
+        })
+    });
+    assert_eq!(
+        visited,
+        ["array", "oneof", "primitive", "array", "string", "sequence"]
+    );
+}
+
+#[test]
+fn walk_fields_visits_oneof_fields() {
+    let sequence = Sequence {
+        name: "Message".to_string(),
+        fields: vec![
+            field("id", Type::Primitive(Primitive::U32)),
+            field(
+                "payload",
+                Type::OneOf(vec![
+                    field("text", Type::String),
+                    field(
+                        "nested",
+                        Type::OneOf(vec![field("flag", Type::Primitive(Primitive::Bool))]),
+                    ),
+                ]),
+            ),
+            field(
+                "items",
+                Type::Array(Box::new(Type::OneOf(vec![field("data", Type::Bytes)]))),
+            ),
+            field("tail", Type::String),
+        ],
+        doc: None,
+    };
+
+    let mut visited = vec![];
+    walk_fields(&sequence, &mut |f| visited.push(f.name.clone()));
+    assert_eq!(
+        visited,
+        ["id", "payload", "text", "nested", "flag", "items", "data", "tail"]
+    );
+}
@@ -4,6 +4,34 @@
 #include <cstdint>
 #include <cstring>
 
+// Standard library strings are supported when `std::string_view` is available (C++17).
+#ifndef SIMPLEBUFFERS_STD_STRINGS
+#if defined(__has_include)
+#if __has_include(<string_view>) && (__cplusplus >= 201703L || _MSVC_LANG >= 201703L)
+#define SIMPLEBUFFERS_STD_STRINGS 1
+#endif
+#endif
+#endif
+
+#ifdef SIMPLEBUFFERS_STD_STRINGS
+#include <optional>
+#include <string>
+#include <string_view>
+#endif
+
+// Readers can be printed to a `std::ostream` when `<ostream>` is available.
+#ifndef SIMPLEBUFFERS_OSTREAM
+#if defined(__has_include)
+#if __has_include(<ostream>)
+#define SIMPLEBUFFERS_OSTREAM 1
+#endif
+#endif
+#endif
+
+#ifdef SIMPLEBUFFERS_OSTREAM
+#include <ostream>
+#endif
+
 namespace simplebuffers {
 
 //                                                                                                //
@@ -25,6 +53,28 @@ namespace simplebuffers {
 #endif
 #endif
 
+// The byte order of the wire format. Data is little-endian unless code generated with
+// `--endianness=big` defines this as 1 before including the core library. Both ends of a connection
+// must use the same byte order.
+#ifndef SIMPLEBUFFERS_BIG_ENDIAN_WIRE
+#define SIMPLEBUFFERS_BIG_ENDIAN_WIRE 0
+#endif
+
+// How multi-byte values are converted to and from the wire: 0 if the host byte order matches the
+// wire, so values are copied as-is, 1 if it is reversed, so values are byte-swapped, and -1 if the
+// host byte order is unknown, so values are assembled one byte at a time.
+#if IS_BIG_ENDIAN == -1
+#define SIMPLEBUFFERS_BYTE_ORDER -1
+#elif IS_BIG_ENDIAN == SIMPLEBUFFERS_BIG_ENDIAN_WIRE
+#define SIMPLEBUFFERS_BYTE_ORDER 0
+#else
+#define SIMPLEBUFFERS_BYTE_ORDER 1
+#endif
+
+// The position on the wire of byte `i` (counting from the least significant byte) of an `n`-byte
+// value.
+#define SIMPLEBUFFERS_WIRE_BYTE(i, n) (SIMPLEBUFFERS_BIG_ENDIAN_WIRE ? (n) - 1 - (i) : (i))
+
 inline uint16_t byteswap16(uint16_t value) {
 #if defined(__cpp_lib_byteswap)
     return std::byteswap(value);
@@ -87,7 +137,7 @@ class SimpleBufferWriter {
      *
      * @return The static size of the object.
      */
-    virtual uint16_t static_size() const = 0;
+    virtual uint16_t static_size() const noexcept = 0;
 
     /**
      * @brief Writes a component to the destination buffer.
@@ -143,6 +193,29 @@ class SimpleBufferWriter {
     }
 };
 
+//                                                                                                //
+// ======================================== BytesWriter ========================================= //
+//                                                                                                //
+
+/**
+ * @brief A byte buffer to write to a simple buffer.
+ *
+ * Unlike a string, a byte buffer is written with its length, so it may contain null bytes.
+ */
+class BytesWriter {
+   public:
+    /**
+     * @brief Constructs a BytesWriter object.
+     *
+     * @param val Pointer to the bytes.
+     * @param len Number of bytes.
+     */
+    BytesWriter(const uint8_t* val, uint16_t len) : val(val), len(len) {}
+
+    const uint8_t* val;
+    uint16_t len;
+};
+
 //                                                                                                //
 // ====================================== Get static size ======================================= //
 //                                                                                                //
@@ -167,7 +240,11 @@ inline uint16_t get_static_size(const float val) { return 4; }
 inline uint16_t get_static_size(const double val) { return 8; }
 inline uint16_t get_static_size(const bool val) { return 1; }
 inline uint16_t get_static_size(const SimpleBufferWriter& val) { return val.static_size(); }
-inline uint16_t get_static_size(char* const& val) { return 2; }
+inline uint16_t get_static_size(const char* const& val) { return 2; }
+#ifdef SIMPLEBUFFERS_STD_STRINGS
+inline uint16_t get_static_size(const std::string& val) { return 2; }
+#endif
+inline uint16_t get_static_size(const BytesWriter& val) { return 2; }
 
 //                                                                                                //
 // ======================================== Write field ========================================= //
@@ -197,109 +274,109 @@ inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn
 
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
                             const uint16_t& val) {
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(dest, &val, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     uint16_t sval = byteswap16(val);
     memcpy(dest, &sval, sizeof(sval));
 #else
-    dest[0] = val & 0xFF;
-    dest[1] = val >> 8;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(0, 2)] = val & 0xFF;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(1, 2)] = val >> 8;
 #endif
     return dyn_cursor;
 }
 
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
                             const int16_t& val) {
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(dest, &val, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     uint16_t sval = byteswap16(val);
     memcpy(dest, &sval, sizeof(sval));
 #else
-    dest[0] = val & 0xFF;
-    dest[1] = val >> 8;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(0, 2)] = val & 0xFF;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(1, 2)] = val >> 8;
 #endif
     return dyn_cursor;
 }
 
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
                             const uint32_t& val) {
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(dest, &val, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     uint32_t sval = byteswap32(val);
     memcpy(dest, &sval, sizeof(sval));
 #else
-    dest[0] = val & 0xFF;
-    dest[1] = val >> 8;
-    dest[2] = val >> 16;
-    dest[3] = val >> 24;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(0, 4)] = val & 0xFF;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(1, 4)] = val >> 8;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(2, 4)] = val >> 16;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(3, 4)] = val >> 24;
 #endif
     return dyn_cursor;
 }
 
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
                             const int32_t& val) {
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(dest, &val, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     uint32_t sval = byteswap32(val);
     memcpy(dest, &sval, sizeof(sval));
 #else
-    dest[0] = val & 0xFF;
-    dest[1] = val >> 8;
-    dest[2] = val >> 16;
-    dest[3] = val >> 24;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(0, 4)] = val & 0xFF;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(1, 4)] = val >> 8;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(2, 4)] = val >> 16;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(3, 4)] = val >> 24;
 #endif
     return dyn_cursor;
 }
 
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
                             const uint64_t& val) {
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(dest, &val, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     uint64_t sval = byteswap64(val);
     memcpy(dest, &sval, sizeof(sval));
 #else
-    dest[0] = val & 0xFF;
-    dest[1] = val >> 8;
-    dest[2] = val >> 16;
-    dest[3] = val >> 24;
-    dest[4] = val >> 32;
-    dest[5] = val >> 40;
-    dest[6] = val >> 48;
-    dest[7] = val >> 56;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(0, 8)] = val & 0xFF;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(1, 8)] = val >> 8;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(2, 8)] = val >> 16;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(3, 8)] = val >> 24;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(4, 8)] = val >> 32;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(5, 8)] = val >> 40;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(6, 8)] = val >> 48;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(7, 8)] = val >> 56;
 #endif
     return dyn_cursor;
 }
 
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
                             const int64_t& val) {
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(dest, &val, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     uint64_t sval = byteswap64(val);
     memcpy(dest, &sval, sizeof(sval));
 #else
-    dest[0] = val & 0xFF;
-    dest[1] = val >> 8;
-    dest[2] = val >> 16;
-    dest[3] = val >> 24;
-    dest[4] = val >> 32;
-    dest[5] = val >> 40;
-    dest[6] = val >> 48;
-    dest[7] = val >> 56;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(0, 8)] = val & 0xFF;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(1, 8)] = val >> 8;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(2, 8)] = val >> 16;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(3, 8)] = val >> 24;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(4, 8)] = val >> 32;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(5, 8)] = val >> 40;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(6, 8)] = val >> 48;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(7, 8)] = val >> 56;
 #endif
     return dyn_cursor;
 }
 
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
                             const float& val) {
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(dest, &val, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     uint32_t val_int;
     memcpy(&val_int, &val, sizeof(val));
     val_int = byteswap32(val_int);
@@ -307,19 +384,19 @@ inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn
 #else
     uint32_t val_int;
     memcpy(&val_int, &val, sizeof(uint32_t));
-    dest[0] = val_int & 0xFF;
-    dest[1] = val_int >> 8;
-    dest[2] = val_int >> 16;
-    dest[3] = val_int >> 24;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(0, 4)] = val_int & 0xFF;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(1, 4)] = val_int >> 8;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(2, 4)] = val_int >> 16;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(3, 4)] = val_int >> 24;
 #endif
     return dyn_cursor;
 }
 
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
                             const double& val) {
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(dest, &val, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     uint64_t val_int;
     memcpy(&val_int, &val, sizeof(val));
     val_int = byteswap64(val_int);
@@ -327,14 +404,14 @@ inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn
 #else
     uint64_t val_int;
     memcpy(&val_int, &val, sizeof(uint64_t));
-    dest[0] = val_int & 0xFF;
-    dest[1] = val_int >> 8;
-    dest[2] = val_int >> 16;
-    dest[3] = val_int >> 24;
-    dest[4] = val_int >> 32;
-    dest[5] = val_int >> 40;
-    dest[6] = val_int >> 48;
-    dest[7] = val_int >> 56;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(0, 8)] = val_int & 0xFF;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(1, 8)] = val_int >> 8;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(2, 8)] = val_int >> 16;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(3, 8)] = val_int >> 24;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(4, 8)] = val_int >> 32;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(5, 8)] = val_int >> 40;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(6, 8)] = val_int >> 48;
+    dest[SIMPLEBUFFERS_WIRE_BYTE(7, 8)] = val_int >> 56;
 #endif
     return dyn_cursor;
 }
@@ -346,7 +423,7 @@ inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn
 }
 
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
-                            char* const& val) {
+                            const char* const& val) {
     uint16_t str_len = strlen(val);
     if (dyn_cursor + str_len + 1 > dest_end) return nullptr;  // +1 for null terminator
 
@@ -361,11 +438,60 @@ inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn
     return dyn_cursor;
 }
 
+#ifdef SIMPLEBUFFERS_STD_STRINGS
+// Strings are null-terminated, so only the characters before the first null are written.
+inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
+                            const std::string& val) {
+    return write_field(dest, dest_end, dyn_cursor, val.c_str());
+}
+#endif
+
+inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
+                            const BytesWriter& val) {
+    if (dyn_cursor + 2 + val.len > dest_end) return nullptr;  // +2 for the length
+
+    // Write the data offset to the static section of the buffer.
+    uint16_t offset = dyn_cursor - dest;
+    write_field(dest, dest_end, dyn_cursor, offset);
+
+    // Write the length and the bytes to the dynamic section of the buffer.
+    write_field(dyn_cursor, dest_end, dyn_cursor, val.len);
+    if (val.len > 0) memcpy(dyn_cursor + 2, val.val, val.len);
+    dyn_cursor += 2 + val.len;
+
+    return dyn_cursor;
+}
+
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
                             const SimpleBufferWriter& val) {
     return val.write_component(dest, dest_end, dyn_cursor);
 }
 
+/**
+ * @brief Writes a nested sequence field to the destination buffer.
+ *
+ * The sequence's static data is placed in the dynamic section of the buffer, and a 16-bit offset
+ * to it is written to `dest`.
+ *
+ * @param[out] dest The destination to write static data to.
+ * @param[in] dest_end The end of the destination buffer.
+ * @param[out] dyn_cursor The dynamic cursor for writing variable-length fields.
+ * @param[in] val The sequence to write.
+ * @return A pointer to the end of the dynamic data written to the buffer, or `nullptr` if the
+ *         buffer was too small.
+ */
+inline uint8_t* write_sequence_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
+                                     const SimpleBufferWriter& val) {
+    uint16_t static_size = val.static_size();
+    if (dyn_cursor + static_size > dest_end) return nullptr;
+
+    // Write the offset to the static section of the buffer.
+    uint16_t offset = dyn_cursor - dest;
+    write_field(dest, dest_end, dyn_cursor, offset);
+
+    return val.write_component(dyn_cursor, dest_end, dyn_cursor + static_size);
+}
+
 /**
  * @brief Writes a OneOf field to the destination buffer.
  *
@@ -400,8 +526,9 @@ uint8_t* write_oneof_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_
  *
  * @note Unlike most of SimpleBuffers, this class does *not* inherit from SimpleBufferWriter and
  *       cannot be used to directly serialize data. A priv::ListWriterImpl, which does inherit from
- *       SimpleBufferWriter, must be constructed using data from this ListWriter after properly
- *       casting enums to their underlying serialization type.
+ *       SimpleBufferWriter, must be constructed using data from this ListWriter. Generated code
+ *       defines `get_static_size` and `write_field` for each enum, so lists of enums are written
+ *       element by element as their underlying serialization type.
  *
  * @tparam T The type of the array elements.
  */
@@ -414,9 +541,9 @@ class ListWriter {
      * @param val Pointer to the array.
      * @param len Length of the array.
      */
-    ListWriter(T* const val, uint16_t len) : val(val), len(len) {}
+    ListWriter(T* val, uint16_t len) : val(val), len(len) {}
 
-    T* const val;
+    T* val;
     uint16_t len;
 };
 
@@ -442,7 +569,7 @@ class ListWriterImpl : public SimpleBufferWriter {
      */
     ListWriterImpl(T* const val, uint16_t len) : val_(val), len_(len) {}
 
-    uint16_t static_size() const override { return 4; }
+    uint16_t static_size() const noexcept override { return 4; }
 
     uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end,
                              uint8_t* dyn_cursor = nullptr) const override {
@@ -530,6 +657,171 @@ inline uint8_t* ListWriterImpl<int8_t>::write_data_(uint8_t* dest, const uint8_t
 
 }  // namespace priv
 
+/**
+ * @brief Returns the static size of a nested list, which is always 4 bytes: 2 bytes for the length
+ *        and 2 bytes for the offset.
+ *
+ * @tparam T The type of the array elements.
+ * @param val The list to calculate the static size for.
+ * @return The static size of the list (4).
+ */
+template <typename T>
+uint16_t get_static_size(const ListWriter<T>& val) {
+    return 4;
+}
+
+/**
+ * @brief Writes a nested list (a list that is an element of another list) to the destination
+ *        buffer.
+ *
+ * @tparam T The type of the array elements.
+ * @param[out] dest The destination to write static data to.
+ * @param[in] dest_end The end of the destination buffer.
+ * @param[out] dyn_cursor The dynamic cursor for writing variable-length fields.
+ * @param[in] val The list to write.
+ * @return A pointer to the end of the dynamic data written to the buffer, or `nullptr` if the
+ *         buffer was too small.
+ */
+template <typename T>
+uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
+                     const ListWriter<T>& val) {
+    return priv::ListWriterImpl<T>(val.val, val.len).write_component(dest, dest_end, dyn_cursor);
+}
+
+//                                                                                                //
+// ===================================== FixedArrayWriter ======================================= //
+//                                                                                                //
+
+/**
+ * @brief A fixed-size array to write to a simple buffer.
+ *
+ * Unlike a ListWriter, the elements are owned by this object and are written inline, without an
+ * offset or a length.
+ *
+ * @tparam T The type of the array elements.
+ * @tparam N The number of elements.
+ */
+template <typename T, uint16_t N>
+struct FixedArrayWriter {
+    T val[N];
+
+    T& operator[](uint16_t idx) noexcept { return val[idx]; }
+    const T& operator[](uint16_t idx) const noexcept { return val[idx]; }
+};
+
+/**
+ * @brief Writes a fixed-size array to the destination buffer, casting each element to the type it
+ *        is serialized as. This is used for arrays of enums.
+ *
+ * @tparam WRI The type to write each element as.
+ * @tparam T The type of the array elements.
+ * @tparam N The number of elements.
+ * @param[out] dest The destination to write static data to.
+ * @param[in] dest_end The end of the destination buffer.
+ * @param[out] dyn_cursor The dynamic cursor for writing variable-length fields.
+ * @param[in] val The array to write.
+ * @return A pointer to the end of the dynamic data written to the buffer, or `nullptr` if the
+ *         buffer was too small.
+ */
+template <typename WRI, typename T, uint16_t N>
+uint8_t* write_fixed_array(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
+                           const FixedArrayWriter<T, N>& val) {
+    for (uint16_t i = 0; i < N; ++i) {
+        dyn_cursor = write_field(dest + i * sizeof(WRI), dest_end, dyn_cursor,
+                                 static_cast<WRI>(val.val[i]));
+        if (dyn_cursor == nullptr) return nullptr;
+    }
+    return dyn_cursor;
+}
+
+/**
+ * @brief Writes a fixed-size array of primitives to the destination buffer.
+ *
+ * @tparam T The type of the array elements.
+ * @tparam N The number of elements.
+ * @param[out] dest The destination to write static data to.
+ * @param[in] dest_end The end of the destination buffer.
+ * @param[out] dyn_cursor The dynamic cursor for writing variable-length fields.
+ * @param[in] val The array to write.
+ * @return A pointer to the end of the dynamic data written to the buffer, or `nullptr` if the
+ *         buffer was too small.
+ */
+template <typename T, uint16_t N>
+uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
+                     const FixedArrayWriter<T, N>& val) {
+    return write_fixed_array<T>(dest, dest_end, dyn_cursor, val);
+}
+
+//                                                                                                //
+// ========================================= MapWriter ========================================== //
+//                                                                                                //
+
+/**
+ * @brief A key/value pair to write to a map.
+ *
+ * @tparam K The type of the key.
+ * @tparam V The type of the value.
+ */
+template <typename K, typename V>
+struct MapEntry {
+    K key;
+    V value;
+};
+
+/**
+ * @brief A class for writing maps to a simple buffer.
+ *
+ * A map is written like a list of entries, where each entry holds its key followed by its value.
+ * Entries are written in the order they are given, and are not checked for duplicate keys.
+ *
+ * @tparam K The type of the keys.
+ * @tparam V The type of the values.
+ * @tparam KWRI The type to write each key as. Enums are cast to their underlying serialization
+ *              type; other keys are written as-is.
+ * @tparam VWRI The type to write each value as.
+ */
+template <typename K, typename V, typename KWRI = const K&, typename VWRI = const V&>
+class MapWriter : public SimpleBufferWriter {
+   public:
+    /**
+     * @brief Constructs a MapWriter object.
+     *
+     * @param val Pointer to the entries.
+     * @param len Number of entries.
+     */
+    MapWriter(MapEntry<K, V>* val, uint16_t len) : val(val), len(len) {}
+
+    uint16_t static_size() const noexcept override { return 4; }
+
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end,
+                             uint8_t* dyn_cursor) const override {
+        uint16_t offset = dyn_cursor - dest;
+        write_field(dest, dest_end, dyn_cursor, len);
+        write_field(dest + 2, dest_end, dyn_cursor, offset);
+        if (len == 0) return dyn_cursor;
+
+        uint16_t key_size = get_static_size(static_cast<KWRI>(val[0].key));
+        uint16_t entry_size = key_size + get_static_size(static_cast<VWRI>(val[0].value));
+        if (dyn_cursor + entry_size * len > dest_end) return nullptr;
+
+        // The entries are written first, and their dynamic data is written after them.
+        uint8_t* entry = dyn_cursor;
+        dyn_cursor += entry_size * len;
+        for (uint16_t i = 0; i < len; ++i) {
+            dyn_cursor = write_field(entry, dest_end, dyn_cursor, static_cast<KWRI>(val[i].key));
+            if (dyn_cursor == nullptr) return nullptr;
+            dyn_cursor = write_field(entry + key_size, dest_end, dyn_cursor,
+                                     static_cast<VWRI>(val[i].value));
+            if (dyn_cursor == nullptr) return nullptr;
+            entry += entry_size;
+        }
+        return dyn_cursor;
+    }
+
+    MapEntry<K, V>* val;
+    uint16_t len;
+};
+
 //                                                                                                //
 // ======================================== OneOfWriter ========================================= //
 //                                                                                                //
@@ -546,7 +838,7 @@ class OneOfWriter : public SimpleBufferWriter {
      *
      * @return The static size of the OneOf structure (3);
      */
-    uint16_t static_size() const override { return 3; }
+    uint16_t static_size() const noexcept override { return 3; }
 };
 
 //                                                                                                //
@@ -569,7 +861,7 @@ class SimpleBufferReader {
      *
      * @return Pointer to the component in the data buffer.
      */
-    const uint8_t* data() const { return data_ptr_; }
+    const uint8_t* data() const noexcept { return data_ptr_; }
 
     /**
      * @brief Returns the static size of the object.
@@ -581,7 +873,7 @@ class SimpleBufferReader {
      *
      * @return The static size of the object.
      */
-    virtual uint16_t static_size() const = 0;
+    virtual uint16_t static_size() const noexcept = 0;
 
    protected:
     const uint8_t* data_ptr_;
@@ -627,14 +919,14 @@ template <>
 inline uint16_t read_field<>(const uint8_t* src, uint16_t idx) {
     src += idx * 2;
     uint16_t val = 0;
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(&val, src, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     memcpy(&val, src, sizeof(val));
     val = byteswap16(val);
 #else
-    val |= src[0] << 0;
-    val |= src[1] << 8;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(0, 2)] << 0;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(1, 2)] << 8;
 #endif
     return val;
 }
@@ -648,14 +940,14 @@ template <>
 inline int16_t read_field<>(const uint8_t* src, uint16_t idx) {
     src += idx * 2;
     int16_t val = 0;
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(&val, src, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     memcpy(&val, src, sizeof(val));
     val = byteswap16(val);
 #else
-    val |= src[0] << 0;
-    val |= src[1] << 8;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(0, 2)] << 0;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(1, 2)] << 8;
 #endif
     return val;
 }
@@ -669,16 +961,16 @@ template <>
 inline uint32_t read_field<>(const uint8_t* src, uint16_t idx) {
     src += idx * 4;
     uint32_t val = 0;
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(&val, src, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     memcpy(&val, src, sizeof(val));
     val = byteswap32(val);
 #else
-    val |= src[0] << 0;
-    val |= src[1] << 8;
-    val |= src[2] << 16;
-    val |= src[3] << 24;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(0, 4)] << 0;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(1, 4)] << 8;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(2, 4)] << 16;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(3, 4)] << 24;
 #endif
     return val;
 }
@@ -692,16 +984,16 @@ template <>
 inline int32_t read_field<>(const uint8_t* src, uint16_t idx) {
     src += idx * 4;
     int32_t val = 0;
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(&val, src, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     memcpy(&val, src, sizeof(val));
     val = byteswap32(val);
 #else
-    val |= src[0] << 0;
-    val |= src[1] << 8;
-    val |= src[2] << 16;
-    val |= src[3] << 24;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(0, 4)] << 0;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(1, 4)] << 8;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(2, 4)] << 16;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(3, 4)] << 24;
 #endif
     return val;
 }
@@ -715,20 +1007,20 @@ template <>
 inline uint64_t read_field<>(const uint8_t* src, uint16_t idx) {
     src += idx * 8;
     uint64_t val = 0;
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(&val, src, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     memcpy(&val, src, sizeof(val));
     val = byteswap64(val);
 #else
-    val |= src[0] << 0;
-    val |= src[1] << 8;
-    val |= src[2] << 16;
-    val |= src[3] << 24;
-    val |= (uint64_t)src[4] << 32;
-    val |= (uint64_t)src[5] << 40;
-    val |= (uint64_t)src[6] << 48;
-    val |= (uint64_t)src[7] << 56;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(0, 8)] << 0;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(1, 8)] << 8;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(2, 8)] << 16;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(3, 8)] << 24;
+    val |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(4, 8)] << 32;
+    val |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(5, 8)] << 40;
+    val |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(6, 8)] << 48;
+    val |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(7, 8)] << 56;
 #endif
     return val;
 }
@@ -742,20 +1034,20 @@ template <>
 inline int64_t read_field<>(const uint8_t* src, uint16_t idx) {
     src += idx * 8;
     int64_t val = 0;
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(&val, src, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     memcpy(&val, src, sizeof(val));
     val = byteswap32(val);
 #else
-    val |= src[0] << 0;
-    val |= src[1] << 8;
-    val |= src[2] << 16;
-    val |= src[3] << 24;
-    val |= (uint64_t)src[4] << 32;
-    val |= (uint64_t)src[5] << 40;
-    val |= (uint64_t)src[6] << 48;
-    val |= (uint64_t)src[7] << 56;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(0, 8)] << 0;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(1, 8)] << 8;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(2, 8)] << 16;
+    val |= src[SIMPLEBUFFERS_WIRE_BYTE(3, 8)] << 24;
+    val |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(4, 8)] << 32;
+    val |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(5, 8)] << 40;
+    val |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(6, 8)] << 48;
+    val |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(7, 8)] << 56;
 #endif
     return val;
 }
@@ -769,19 +1061,19 @@ template <>
 inline float read_field<float>(const uint8_t* src, uint16_t idx) {
     src += idx * 4;
     float val;
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(&val, src, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     uint32_t val_int;
     memcpy(&val_int, src, sizeof(val));
     val_int = byteswap32(val_int);
     memcpy(&val, &val_int, sizeof(val));
 #else
     uint32_t val_int = 0;
-    val_int |= src[0] << 0;
-    val_int |= src[1] << 8;
-    val_int |= src[2] << 16;
-    val_int |= src[3] << 24;
+    val_int |= src[SIMPLEBUFFERS_WIRE_BYTE(0, 4)] << 0;
+    val_int |= src[SIMPLEBUFFERS_WIRE_BYTE(1, 4)] << 8;
+    val_int |= src[SIMPLEBUFFERS_WIRE_BYTE(2, 4)] << 16;
+    val_int |= src[SIMPLEBUFFERS_WIRE_BYTE(3, 4)] << 24;
     memcpy(&val, &val_int, sizeof(float));
 #endif
     return val;
@@ -796,23 +1088,23 @@ template <>
 inline double read_field<double>(const uint8_t* src, uint16_t idx) {
     src += idx * 8;
     double val;
-#if IS_BIG_ENDIAN == 0
+#if SIMPLEBUFFERS_BYTE_ORDER == 0
     memcpy(&val, src, sizeof(val));
-#elif IS_BIG_ENDIAN == 1
+#elif SIMPLEBUFFERS_BYTE_ORDER == 1
     uint32_t val_int;
     memcpy(&val_int, src, sizeof(val));
     val_int = byteswap64(val_int);
     memcpy(&val, &val_int, sizeof(val));
 #else
     uint32_t val_int = 0;
-    val_int |= src[0] << 0;
-    val_int |= src[1] << 8;
-    val_int |= src[2] << 16;
-    val_int |= src[3] << 24;
-    val_int |= (uint64_t)src[4] << 32;
-    val_int |= (uint64_t)src[5] << 40;
-    val_int |= (uint64_t)src[6] << 48;
-    val_int |= (uint64_t)src[7] << 56;
+    val_int |= src[SIMPLEBUFFERS_WIRE_BYTE(0, 8)] << 0;
+    val_int |= src[SIMPLEBUFFERS_WIRE_BYTE(1, 8)] << 8;
+    val_int |= src[SIMPLEBUFFERS_WIRE_BYTE(2, 8)] << 16;
+    val_int |= src[SIMPLEBUFFERS_WIRE_BYTE(3, 8)] << 24;
+    val_int |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(4, 8)] << 32;
+    val_int |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(5, 8)] << 40;
+    val_int |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(6, 8)] << 48;
+    val_int |= (uint64_t)src[SIMPLEBUFFERS_WIRE_BYTE(7, 8)] << 56;
     memcpy(&val, &val_int, sizeof(double));
 #endif
     return val;
@@ -838,10 +1130,47 @@ inline bool read_field<>(const uint8_t* src, uint16_t idx) {
  */
 template <>
 inline const char* read_field(const uint8_t* src, uint16_t idx) {
-    const uint16_t offset = read_field<uint16_t>(src, idx);
+    // The offset is relative to the element, not to the start of the array.
+    src += idx * 2;
+    const uint16_t offset = read_field<uint16_t>(src);
     return reinterpret_cast<const char*>(src + offset);
 }
 
+#ifdef SIMPLEBUFFERS_STD_STRINGS
+/**
+ * @brief Reads a string field from a buffer as a `std::string_view`.
+ *
+ * @param src The destination to read data from.
+ * @param[in] idx An index that can be used to address a later contiguous instance of this
+ *                Reader. Used when instantiating from an array.
+ */
+template <>
+inline std::string_view read_field(const uint8_t* src, uint16_t idx) {
+    return std::string_view(read_field<const char*>(src, idx));
+}
+#endif
+
+//                                                                                                //
+// ========================================== Equality ========================================== //
+//                                                                                                //
+
+/**
+ * @brief Compares two values read from a simple buffer.
+ *
+ * Strings are compared by their contents rather than by address. Other values are compared with
+ * `operator==`.
+ *
+ * @param a The first value.
+ * @param b The second value.
+ * @return Whether the values are equal.
+ */
+template <typename T>
+inline bool values_equal(const T& a, const T& b) {
+    return a == b;
+}
+
+inline bool values_equal(const char* a, const char* b) { return strcmp(a, b) == 0; }
+
 //                                                                                                //
 // ======================================== ListReader ========================================= //
 //                                                                                                //
@@ -855,15 +1184,21 @@ inline const char* read_field(const uint8_t* src, uint16_t idx) {
 template <typename RET, typename REA = RET>
 class ListReader : public SimpleBufferReader {
    public:
+    /**
+     * Construct an empty Reader object. This is returned when a oneof does not hold a list.
+     */
+    ListReader() : SimpleBufferReader(nullptr), array_len_(0), array_content_(nullptr) {}
+
     /**
      * Construct a new Reader object.
      *
      * @param[in] data_ptr A pointer to this component's location in a data buffer.
-     * @param[in] idx
+     * @param[in] idx An index that can be used to address a later contiguous instance of this
+     *                Reader. Used when instantiating from an array.
      */
     ListReader(const uint8_t* data_ptr, size_t idx) : SimpleBufferReader(data_ptr + 4 * idx) {
-        array_len_ = read_field<uint16_t>(data_ptr);
-        array_content_ = data_ptr + read_field<uint16_t>(data_ptr + 2);
+        array_len_ = read_field<uint16_t>(data_ptr_);
+        array_content_ = data_ptr_ + read_field<uint16_t>(data_ptr_ + 2);
     }
 
     /**
@@ -871,7 +1206,7 @@ class ListReader : public SimpleBufferReader {
      *
      * @return The length of the array.
      */
-    uint16_t len() const { return array_len_; }
+    uint16_t len() const noexcept { return array_len_; }
 
     /**
      * Read the value at index `idx` from the array.
@@ -895,6 +1230,22 @@ class ListReader : public SimpleBufferReader {
      */
     RET operator[](uint16_t idx) const { return read(idx); }
 
+    /**
+     * Compare two arrays element by element.
+     *
+     * @param[in] other The array to compare with.
+     * @return Whether the arrays have the same length and equal elements.
+     */
+    bool operator==(const ListReader& other) const noexcept {
+        if (array_len_ != other.array_len_) return false;
+        for (uint16_t i = 0; i < array_len_; ++i) {
+            if (!values_equal(read(i), other.read(i))) return false;
+        }
+        return true;
+    }
+
+    bool operator!=(const ListReader& other) const noexcept { return !(*this == other); }
+
     /**
      * @brief Returns the static size of the object.
      *
@@ -905,13 +1256,294 @@ class ListReader : public SimpleBufferReader {
      *
      * @return The static size of the object.
      */
-    uint16_t static_size() const override { return 4; }
+    uint16_t static_size() const noexcept override { return 4; }
 
    protected:
     uint16_t array_len_;
     const uint8_t* array_content_;
 };
 
+//                                                                                                //
+// ===================================== FixedArrayReader ======================================= //
+//                                                                                                //
+
+/**
+ * @brief A class for reading fixed-size arrays from a simple buffer. The elements are stored
+ *        inline.
+ *
+ * @tparam RET The type to return (cast from read).
+ * @tparam N The number of elements.
+ * @tparam REA The type to read from the buffer.
+ */
+template <typename RET, uint16_t N, typename REA = RET>
+class FixedArrayReader : public SimpleBufferReader {
+   public:
+    /**
+     * Construct a new Reader object.
+     *
+     * @param[in] data_ptr A pointer to the first element in a data buffer.
+     */
+    FixedArrayReader(const uint8_t* data_ptr) : SimpleBufferReader(data_ptr) {}
+
+    /**
+     * Get the number of elements in the array.
+     *
+     * @return The length of the array.
+     */
+    constexpr uint16_t len() const noexcept { return N; }
+
+    /**
+     * Read the value at index `idx` from the array.
+     *
+     * This function does not check bounds and has undefined behavior if an out-of-bounds index is
+     * given.
+     *
+     * @param[in] idx The index to read.
+     * @return The value read from the array.
+     */
+    RET read(uint16_t idx) const { return static_cast<RET>(read_field<REA>(data_ptr_, idx)); }
+
+    /**
+     * Read the value at index `idx` from the array.
+     *
+     * This function does not check bounds and has undefined behavior if an out-of-bounds index is
+     * given.
+     *
+     * @param[in] idx The index to read.
+     * @return The value read from the array.
+     */
+    RET operator[](uint16_t idx) const { return read(idx); }
+
+    /**
+     * Compare two arrays element by element.
+     *
+     * @param[in] other The array to compare with.
+     * @return Whether the arrays have equal elements.
+     */
+    bool operator==(const FixedArrayReader& other) const noexcept {
+        for (uint16_t i = 0; i < N; ++i) {
+            if (!values_equal(read(i), other.read(i))) return false;
+        }
+        return true;
+    }
+
+    bool operator!=(const FixedArrayReader& other) const noexcept { return !(*this == other); }
+
+    /**
+     * @brief Returns the static size of the object, which is the size of every element.
+     *
+     * @return The static size of the object.
+     */
+    uint16_t static_size() const noexcept override { return N * sizeof(REA); }
+};
+
+//                                                                                                //
+// ========================================= MapReader ========================================== //
+//                                                                                                //
+
+/**
+ * @brief A class for reading maps from a simple buffer.
+ *
+ * Entries are stored in the order they were written. Looking up a key searches the entries in
+ * order, so the first entry with a matching key is found.
+ *
+ * @tparam KRET The type of the keys to return (cast from read).
+ * @tparam VRET The type of the values to return (cast from read).
+ * @tparam KEY_SIZE The number of bytes each key takes up in an entry.
+ * @tparam VALUE_SIZE The number of bytes each value takes up in an entry.
+ * @tparam KREA The type of the keys to read from the buffer.
+ * @tparam VREA The type of the values to read from the buffer.
+ */
+template <typename KRET, typename VRET, uint16_t KEY_SIZE, uint16_t VALUE_SIZE,
+          typename KREA = KRET, typename VREA = VRET>
+class MapReader : public SimpleBufferReader {
+   public:
+    /**
+     * Construct an empty Reader object. This is returned when a oneof does not hold a map.
+     */
+    MapReader() : SimpleBufferReader(nullptr), map_len_(0), map_content_(nullptr) {}
+
+    /**
+     * Construct a new Reader object.
+     *
+     * @param[in] data_ptr A pointer to this component's location in a data buffer.
+     * @param[in] idx An index that can be used to address a later contiguous instance of this
+     *                Reader. Used when instantiating from an array.
+     */
+    MapReader(const uint8_t* data_ptr, size_t idx) : SimpleBufferReader(data_ptr + 4 * idx) {
+        map_len_ = read_field<uint16_t>(data_ptr_);
+        map_content_ = data_ptr_ + read_field<uint16_t>(data_ptr_ + 2);
+    }
+
+    /**
+     * Get the number of entries in the map.
+     *
+     * @return The number of entries.
+     */
+    uint16_t len() const noexcept { return map_len_; }
+
+    /**
+     * Read the key of the entry at index `idx`.
+     *
+     * This function does not check bounds and has undefined behavior if an out-of-bounds index is
+     * given.
+     *
+     * @param[in] idx The index of the entry.
+     * @return The key of the entry.
+     */
+    KRET key(uint16_t idx) const { return static_cast<KRET>(read_field<KREA>(entry_(idx))); }
+
+    /**
+     * Read the value of the entry at index `idx`.
+     *
+     * This function does not check bounds and has undefined behavior if an out-of-bounds index is
+     * given.
+     *
+     * @param[in] idx The index of the entry.
+     * @return The value of the entry.
+     */
+    VRET value(uint16_t idx) const {
+        return static_cast<VRET>(read_field<VREA>(entry_(idx) + KEY_SIZE));
+    }
+
+    /**
+     * Find the first entry with a given key.
+     *
+     * @param[in] key The key to look for.
+     * @return The index of the entry, or `len()` if no entry has the key.
+     */
+    uint16_t find(const KRET& key) const {
+        for (uint16_t i = 0; i < map_len_; ++i) {
+            if (values_equal(this->key(i), key)) return i;
+        }
+        return map_len_;
+    }
+
+    /**
+     * Check whether any entry has a given key.
+     *
+     * @param[in] key The key to look for.
+     * @return Whether the key is in the map.
+     */
+    bool contains(const KRET& key) const { return find(key) != map_len_; }
+
+    /**
+     * Compare two maps entry by entry. Maps with the same entries in a different order are not
+     * equal.
+     *
+     * @param[in] other The map to compare with.
+     * @return Whether the maps have the same length and equal entries.
+     */
+    bool operator==(const MapReader& other) const noexcept {
+        if (map_len_ != other.map_len_) return false;
+        for (uint16_t i = 0; i < map_len_; ++i) {
+            if (!values_equal(key(i), other.key(i))) return false;
+            if (!values_equal(value(i), other.value(i))) return false;
+        }
+        return true;
+    }
+
+    bool operator!=(const MapReader& other) const noexcept { return !(*this == other); }
+
+    /**
+     * @brief Returns the static size of the object, which is the size of the entry count and the
+     *        offset to the entries.
+     *
+     * @return The static size of the object (4).
+     */
+    uint16_t static_size() const noexcept override { return 4; }
+
+   protected:
+    /**
+     * Get a pointer to the entry at index `idx`.
+     *
+     * @param[in] idx The index of the entry.
+     * @return A pointer to the key of the entry.
+     */
+    const uint8_t* entry_(uint16_t idx) const {
+        return map_content_ + idx * (KEY_SIZE + VALUE_SIZE);
+    }
+
+    uint16_t map_len_;
+    const uint8_t* map_content_;
+};
+
+//                                                                                                //
+// ======================================== BytesReader ========================================= //
+//                                                                                                //
+
+/**
+ * @brief A class for reading byte buffers from a simple buffer.
+ */
+class BytesReader : public SimpleBufferReader {
+   public:
+    /**
+     * Construct an empty Reader object. This is returned when a oneof does not hold a byte buffer.
+     */
+    BytesReader() : SimpleBufferReader(nullptr), val_(nullptr), len_(0) {}
+
+    /**
+     * Construct a new Reader object.
+     *
+     * @param[in] data_ptr A pointer to this component's location in a data buffer.
+     * @param[in] idx An index that can be used to address a later contiguous instance of this
+     *                Reader. Used when instantiating from an array.
+     */
+    BytesReader(const uint8_t* data_ptr, size_t idx) : SimpleBufferReader(data_ptr + 2 * idx) {
+        const uint8_t* content = data_ptr_ + read_field<uint16_t>(data_ptr_);
+        len_ = read_field<uint16_t>(content);
+        val_ = content + 2;
+    }
+
+    /**
+     * Get the number of bytes in the buffer.
+     *
+     * @return The length of the buffer.
+     */
+    uint16_t len() const noexcept { return len_; }
+
+    /**
+     * Get a pointer to the bytes. The bytes are not null-terminated.
+     *
+     * @return A pointer to the first byte.
+     */
+    const uint8_t* val() const noexcept { return val_; }
+
+    /**
+     * Read the byte at index `idx`.
+     *
+     * This function does not check bounds and has undefined behavior if an out-of-bounds index is
+     * given.
+     *
+     * @param[in] idx The index to read.
+     * @return The byte at the index.
+     */
+    uint8_t operator[](uint16_t idx) const { return val_[idx]; }
+
+    /**
+     * Compare the contents of two byte buffers.
+     *
+     * @param[in] other The byte buffer to compare with.
+     * @return Whether the buffers have the same length and contents.
+     */
+    bool operator==(const BytesReader& other) const noexcept {
+        return len_ == other.len_ && (len_ == 0 || memcmp(val_, other.val_, len_) == 0);
+    }
+
+    bool operator!=(const BytesReader& other) const noexcept { return !(*this == other); }
+
+    /**
+     * @brief Returns the static size of the object, which is the size of the offset to the bytes.
+     *
+     * @return The static size of the object (2).
+     */
+    uint16_t static_size() const noexcept override { return 2; }
+
+   protected:
+    const uint8_t* val_;
+    uint16_t len_;
+};
+
 //                                                                                                //
 // ======================================== OneOf Reader ======================================== //
 //                                                                                                //
@@ -941,12 +1573,117 @@ class OneOfReader : public SimpleBufferReader {
      *
      * @return The static size of the object.
      */
-    uint16_t static_size() const override { return 4; }
+    uint16_t static_size() const noexcept override { return 4; }
 
    protected:
     const uint8_t* val_ptr_;
 };
 
+#ifdef SIMPLEBUFFERS_OSTREAM
+
+//                                                                                                //
+// ========================================== Printing ========================================== //
+//                                                                                                //
+
+/**
+ * @brief Prints a string as a quoted, escaped string literal.
+ *
+ * @param[out] os The stream to print to.
+ * @param[in] val The characters to print.
+ * @param[in] len The number of characters to print.
+ */
+inline void print_string(std::ostream& os, const char* val, size_t len) {
+    const char* hex = "0123456789abcdef";
+    os << '"';
+    for (size_t i = 0; i < len; ++i) {
+        const unsigned char c = static_cast<unsigned char>(val[i]);
+        if (c == '"' || c == '\\') {
+            os << '\\' << c;
+        } else if (c == '\n') {
+            os << "\\n";
+        } else if (c == '\t') {
+            os << "\\t";
+        } else if (c < 0x20) {
+            os << "\\u00" << hex[c >> 4] << hex[c & 0xf];
+        } else {
+            os << c;
+        }
+    }
+    os << '"';
+}
+
+/**
+ * @brief Prints a value read from a simple buffer.
+ *
+ * Numbers are printed in decimal, booleans as `true` or `false`, and strings as quoted string
+ * literals. Lists, fixed-size arrays, and byte buffers are printed as comma-separated elements in
+ * square brackets, and maps as comma-separated `key: value` pairs in braces. Anything else is
+ * printed with `operator<<`.
+ *
+ * @param[out] os The stream to print to.
+ * @param[in] val The value to print.
+ */
+template <typename T>
+inline void print_value(std::ostream& os, const T& val) {
+    os << val;
+}
+
+inline void print_value(std::ostream& os, uint8_t val) { os << static_cast<unsigned>(val); }
+inline void print_value(std::ostream& os, int8_t val) { os << static_cast<int>(val); }
+inline void print_value(std::ostream& os, bool val) { os << (val ? "true" : "false"); }
+inline void print_value(std::ostream& os, const char* val) { print_string(os, val, strlen(val)); }
+
+#ifdef SIMPLEBUFFERS_STD_STRINGS
+inline void print_value(std::ostream& os, std::string_view val) {
+    print_string(os, val.data(), val.size());
+}
+#endif
+
+template <typename RET, typename REA>
+inline void print_value(std::ostream& os, const ListReader<RET, REA>& val) {
+    os << '[';
+    for (uint16_t i = 0; i < val.len(); ++i) {
+        if (i > 0) os << ", ";
+        print_value(os, val.read(i));
+    }
+    os << ']';
+}
+
+template <typename RET, uint16_t N, typename REA>
+inline void print_value(std::ostream& os, const FixedArrayReader<RET, N, REA>& val) {
+    os << '[';
+    for (uint16_t i = 0; i < N; ++i) {
+        if (i > 0) os << ", ";
+        print_value(os, val.read(i));
+    }
+    os << ']';
+}
+
+template <typename KRET, typename VRET, uint16_t KEY_SIZE, uint16_t VALUE_SIZE, typename KREA,
+          typename VREA>
+inline void print_value(std::ostream& os,
+                        const MapReader<KRET, VRET, KEY_SIZE, VALUE_SIZE, KREA, VREA>& val) {
+    os << '{';
+    for (uint16_t i = 0; i < val.len(); ++i) {
+        if (i > 0) os << ", ";
+        print_value(os, val.key(i));
+        os << ": ";
+        print_value(os, val.value(i));
+    }
+    os << '}';
+}
+
+inline void print_value(std::ostream& os, const BytesReader& val) {
+    os << '[';
+    for (uint16_t i = 0; i < val.len(); ++i) {
+        if (i > 0) os << ", ";
+        os << static_cast<unsigned>(val[i]);
+    }
+    os << ']';
+}
+
+#endif
+
 }  // namespace simplebuffers
 
 #endif  // SIMPLEBUFFERS__SIMPLEBUFFERS__ZACHDACHAMPION__HPP
\ No newline at end of file
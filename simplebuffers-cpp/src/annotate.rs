@@ -9,6 +9,8 @@ use simplebuffers_core::SBSchema;
 use simplebuffers_core::Sequence;
 use simplebuffers_core::Type;
 
+use crate::argparse::StringType;
+
 #[derive(Debug)]
 pub(crate) struct CppSchema {
     pub sequences: Vec<CppSequence>,
@@ -24,8 +26,14 @@ pub(crate) struct CppEnum {
     /// The size, in bytes, of the enum.
     pub size: u8,
 
-    /// The variants of the enum in the form (name, value).
-    pub variants: Vec<(String, u64)>,
+    /// Whether the enum has negative variants.
+    pub signed: bool,
+
+    /// The variants of the enum in the form (name, value, doc comment).
+    pub variants: Vec<(String, i128, Option<String>)>,
+
+    /// The doc comment of the enum.
+    pub doc: Option<String>,
 }
 
 /// A sequence, annotated and adjusted for C++ conventions.
@@ -39,6 +47,9 @@ pub(crate) struct CppSequence {
 
     /// The size of the sequence in bytes.
     pub size: usize,
+
+    /// The doc comment of the sequence.
+    pub doc: Option<String>,
 }
 
 /// A field, annotated and adjusted for C++ conventions.
@@ -52,6 +63,9 @@ pub(crate) struct CppSequenceField {
 
     /// The position of the field in the sequence.
     pub pos: usize,
+
+    /// The doc comment of the field.
+    pub doc: Option<String>,
 }
 
 /// A field, annotated and adjusted for C++ conventions.
@@ -71,6 +85,9 @@ pub(crate) struct CppOneOfField {
 
     /// The index of the field in the oneof.
     pub index: usize,
+
+    /// The doc comment of the field.
+    pub doc: Option<String>,
 }
 
 /// A oneof, annotated and adjusted for C++ conventions.
@@ -90,6 +107,12 @@ pub(crate) enum CppType {
     Sequence(String),
     Enum(String, usize),
     Array(Box<CppType>),
+    FixedArray(Box<CppType>, usize),
+    /// A map, with the key type, the value type, and the number of bytes the key and the value
+    /// each take up in an entry.
+    Map(Box<CppType>, Box<CppType>, usize, usize),
+    String(StringType),
+    Bytes,
     OneOf(CppOneOf),
 }
 
@@ -112,24 +135,79 @@ impl SizeToType for usize {
 impl CppEnum {
     /// Returns the C++ type that corresponds with this enum. This is used as the base of a C++
     /// enum class, so we prefer fast data types over minimal ones.
+    ///
+    /// Signed enums are the exception: they are read from the wire as unsigned integers of the
+    /// enum's size, so their base must have exactly that size for negative values to convert
+    /// correctly.
     pub(crate) fn size_to_type(&self) -> &str {
-        match self.size {
-            1 => "uint_fast8_t",
-            2 => "uint_fast16_t",
-            4 => "uint_fast32_t",
-            8 => "uint_fast64_t",
+        match (self.size, self.signed) {
+            (1, false) => "uint_fast8_t",
+            (2, false) => "uint_fast16_t",
+            (4, false) => "uint_fast32_t",
+            (8, false) => "uint_fast64_t",
+            (1, true) => "int8_t",
+            (2, true) => "int16_t",
+            (4, true) => "int32_t",
+            (8, true) => "int64_t",
             _ => panic!("Invalid size {} for enum {}", self.size, self.name),
         }
     }
 }
 
+impl CppType {
+    /// Returns the type that a map writer casts a key or value of this type to before writing it.
+    /// Enums are cast to their underlying type, and everything else is passed by reference.
+    fn write_cast_type(&self) -> String {
+        match self {
+            CppType::Enum(_, size) => size.to_type().to_string(),
+            _ => format!("{} const&", self.to_writer_string()),
+        }
+    }
+
+    /// Returns the type that a map reader reads a key or value of this type as. Enums are read as
+    /// their underlying type, and everything else is read as-is.
+    fn read_type(&self) -> String {
+        match self {
+            CppType::Enum(_, size) => size.to_type().to_string(),
+            _ => self.to_reader_string(),
+        }
+    }
+
+    /// Returns the oneof defined by this type, if any. Oneofs may be defined as the element type of
+    /// an array, so arrays are searched recursively.
+    fn oneof(&self) -> Option<&CppOneOf> {
+        match self {
+            CppType::OneOf(o) => Some(o),
+            CppType::Array(t) => t.oneof(),
+            _ => None,
+        }
+    }
+}
+
 impl CppSequence {
     /// Return an iterator over all of the oneof fields contained within this sequence.
     pub(crate) fn oneofs(&self) -> impl DoubleEndedIterator<Item = &CppOneOf> {
-        self.fields.iter().filter_map(|f| match &f.ty {
-            CppType::OneOf(o) => Some(o),
-            _ => None,
-        })
+        self.fields.iter().filter_map(|f| f.ty.oneof())
+    }
+
+    /// Returns every oneof within this sequence, including oneofs nested in other oneofs, along with
+    /// the fully-qualified name of its reader class (e.g. `SequenceReader::OneOfReader`). Nested
+    /// oneofs are returned before the oneofs that contain them.
+    pub(crate) fn nested_oneof_readers(&self) -> Vec<(String, &CppOneOf)> {
+        fn visit<'a>(prefix: &str, oneof: &'a CppOneOf, out: &mut Vec<(String, &'a CppOneOf)>) {
+            let name = format!("{}::{}", prefix, oneof.to_reader_string());
+            for sub_oneof in oneof.oneofs() {
+                visit(&name, sub_oneof, out);
+            }
+            out.push((name, oneof));
+        }
+
+        let prefix = self.to_reader_string();
+        let mut out = vec![];
+        for oneof in self.oneofs() {
+            visit(&prefix, oneof, &mut out);
+        }
+        out
     }
 }
 
@@ -149,25 +227,11 @@ impl CppSequenceField {
                 },
                 self.name
             ),
-            CppType::Array(b) => match **b {
-                CppType::Enum(_, size) => format!(
-                    "simplebuffers::priv::ListWriterImpl<{sizetype}>(reinterpret_cast<{sizetype}* const>({name}.val), {name}.len)",
-                    sizetype = match size {
-                        1 => "uint8_t",
-                        2 => "uint16_t",
-                        4 => "uint32_t",
-                        8 => "uint64_t",
-                        _ => panic!("Invalid size {} for enum {}", size, self.name),
-                    },
-                    name = self.name
-                ),
-                _ => {
-                    format!(
-                        "simplebuffers::priv::ListWriterImpl<{dtype}>({name}.val, {name}.len)",
-                        dtype=b.to_writer_string(),
-                        name = self.name)
-                    }
-            },
+            CppType::Array(b) => format!(
+                "simplebuffers::priv::ListWriterImpl<{}>({name}.val, {name}.len)",
+                b.to_writer_string(),
+                name = self.name
+            ),
             _ => self.name.to_string(),
         }
     }
@@ -176,10 +240,7 @@ impl CppSequenceField {
 impl CppOneOf {
     /// Return an iterator over all of the oneof fields contained within this oneof.
     pub(crate) fn oneofs(&self) -> impl DoubleEndedIterator<Item = &CppOneOf> {
-        self.fields.iter().filter_map(|f| match &f.ty {
-            CppType::OneOf(o) => Some(o),
-            _ => None,
-        })
+        self.fields.iter().filter_map(|f| f.ty.oneof())
     }
 }
 
@@ -199,25 +260,11 @@ impl CppOneOfField {
                 },
                 self.name
             ),
-            CppType::Array(b) => match **b {
-                CppType::Enum(_, size) => format!(
-                    "simplebuffers::priv::ListWriterImpl<{sizetype}>(reinterpret_cast<{sizetype}* const>(*value.{name}.val), *value.{name}.len)",
-                    sizetype = match size {
-                        1 => "uint8_t",
-                        2 => "uint16_t",
-                        4 => "uint32_t",
-                        8 => "uint64_t",
-                        _ => panic!("Invalid size {} for enum {}", size, self.name),
-                    },
-                    name = self.name
-                ),
-                _ => {
-                format!(
-                    "simplebuffers::priv::ListWriterImpl<{dtype}>(*value.{name}.val, *value.{name}.len)",
-                    dtype=b.to_writer_string(),
-                    name = self.name)
-                }
-            },
+            CppType::Array(b) => format!(
+                "simplebuffers::priv::ListWriterImpl<{}>(value_.{name}->val, value_.{name}->len)",
+                b.to_writer_string(),
+                name = self.name
+            ),
             _ => format!("*value_.{}", self.name),
         }
     }
@@ -242,6 +289,34 @@ impl ToReaderWriterString for CppType {
             CppType::Array(t) => {
                 format!("simplebuffers::ListWriter<{}>", t.to_writer_string())
             }
+            CppType::FixedArray(t, len) => {
+                format!(
+                    "simplebuffers::FixedArrayWriter<{}, {}>",
+                    t.to_writer_string(),
+                    len
+                )
+            }
+            CppType::Map(k, v, _, _) => {
+                // Enums are written as their underlying type, so the cast types must be given.
+                if matches!(**k, CppType::Enum(_, _)) || matches!(**v, CppType::Enum(_, _)) {
+                    format!(
+                        "simplebuffers::MapWriter<{}, {}, {}, {}>",
+                        k.to_writer_string(),
+                        v.to_writer_string(),
+                        k.write_cast_type(),
+                        v.write_cast_type()
+                    )
+                } else {
+                    format!(
+                        "simplebuffers::MapWriter<{}, {}>",
+                        k.to_writer_string(),
+                        v.to_writer_string()
+                    )
+                }
+            }
+            CppType::String(StringType::Char) => "const char*".to_string(),
+            CppType::String(StringType::Std) => "std::string".to_string(),
+            CppType::Bytes => "simplebuffers::BytesWriter".to_string(),
             CppType::OneOf(o) => format!("{}Writer", o.name).to_case(Case::Pascal),
         }
     }
@@ -262,6 +337,41 @@ impl ToReaderWriterString for CppType {
                     format!("simplebuffers::ListReader<{}>", t.to_reader_string())
                 }
             }
+            CppType::FixedArray(t, len) => {
+                if let CppType::Enum(_, size) = t.as_ref() {
+                    format!(
+                        "simplebuffers::FixedArrayReader<{}, {}, {}>",
+                        t.to_reader_string(),
+                        len,
+                        size.to_type()
+                    )
+                } else {
+                    format!(
+                        "simplebuffers::FixedArrayReader<{}, {}>",
+                        t.to_reader_string(),
+                        len
+                    )
+                }
+            }
+            CppType::Map(k, v, key_size, value_size) => {
+                let mut params = vec![
+                    k.to_reader_string(),
+                    v.to_reader_string(),
+                    key_size.to_string(),
+                    value_size.to_string(),
+                ];
+                // Enums are read as their underlying type, so the read types must be given.
+                if let CppType::Enum(_, size) = v.as_ref() {
+                    params.push(k.read_type());
+                    params.push(size.to_type().to_string());
+                } else if let CppType::Enum(_, _) = k.as_ref() {
+                    params.push(k.read_type());
+                }
+                format!("simplebuffers::MapReader<{}>", params.join(", "))
+            }
+            CppType::String(StringType::Char) => "const char*".to_string(),
+            CppType::String(StringType::Std) => "std::string_view".to_string(),
+            CppType::Bytes => "simplebuffers::BytesReader".to_string(),
             CppType::OneOf(o) => format!("{}Reader", o.name).to_case(Case::Pascal),
         }
     }
@@ -289,10 +399,58 @@ impl ToReaderWriterString for CppOneOf {
 
 /// Take a schema and annotate it for use with C++. This will adjust naming to match C++ convention,
 /// and will add extra data that is necessary for C++ code generation.
-pub(crate) fn annotate_schema(schema: &SBSchema) -> CppSchema {
-    CppSchema {
-        sequences: schema.sequences.iter().map(annotate_sequence).collect(),
-        enums: schema.enums.iter().map(annotate_enum).collect(),
+///
+/// Sequences are sorted so that they can be defined in the order they are returned. A sequence
+/// writer stores nested sequence writers by value, so those must be fully defined first. Oneofs
+/// only store pointers, which work with the forward declarations that are emitted before any
+/// definitions.
+///
+/// # Arguments
+///
+/// * `schema` - The schema to annotate.
+/// * `reserved` - Identifiers to escape with [escape_identifier].
+/// * `string_type` - The C++ types to use for string fields.
+///
+/// # Returns
+///
+/// The annotated schema, or an error if the sequences contain each other through their fields.
+pub(crate) fn annotate_schema(
+    schema: &SBSchema,
+    reserved: &[String],
+    string_type: StringType,
+) -> Result<CppSchema, String> {
+    Ok(CppSchema {
+        sequences: schema
+            .sequences_in_field_dependency_order()?
+            .into_iter()
+            .map(|s| annotate_sequence(schema, s, reserved, string_type))
+            .collect(),
+        enums: schema
+            .enums
+            .iter()
+            .map(|e| annotate_enum(e, reserved))
+            .collect(),
+    })
+}
+
+/// Append `_` to a name if it matches a reserved identifier. Names are compared after converting
+/// both to snake case, which catches every name whose case was changed into a reserved identifier
+/// (such as the field `While`, which becomes `while`), even though the compiler's check is
+/// case-sensitive.
+///
+/// Only names that appear in the output as-is are escaped. Sequence and oneof names are always
+/// followed by `Writer` or `Reader`, so they cannot conflict.
+///
+/// # Arguments
+///
+/// * `name` - The name to escape, already converted to the case used in the output.
+/// * `reserved` - The identifiers to escape.
+pub(crate) fn escape_identifier(name: String, reserved: &[String]) -> String {
+    let snake = name.to_case(Case::Snake);
+    if reserved.iter().any(|r| r.to_case(Case::Snake) == snake) {
+        format!("{}_", name)
+    } else {
+        name
     }
 }
 
@@ -301,19 +459,28 @@ pub(crate) fn annotate_schema(schema: &SBSchema) -> CppSchema {
 /// # Arguments
 ///
 /// * `original` - The enum to annotate.
+/// * `reserved` - Identifiers to escape.
 ///
 /// # Returns
 ///
 /// An enum, formatted for C++ code generation.
-fn annotate_enum(original: &Enum) -> CppEnum {
+fn annotate_enum(original: &Enum, reserved: &[String]) -> CppEnum {
     CppEnum {
-        name: original.name.to_case(Case::Pascal),
+        name: escape_identifier(original.name.to_case(Case::Pascal), reserved),
         size: original.size,
+        signed: original.is_signed(),
         variants: original
             .variants
             .iter()
-            .map(|v| (v.name.to_case(Case::UpperSnake), v.value))
+            .map(|v| {
+                (
+                    escape_identifier(v.name.to_case(Case::UpperSnake), reserved),
+                    v.value,
+                    v.doc.clone(),
+                )
+            })
             .collect(),
+        doc: original.doc.clone(),
     }
 }
 
@@ -321,41 +488,62 @@ fn annotate_enum(original: &Enum) -> CppEnum {
 ///
 /// # Arguments
 ///
-/// * `name_stack` - The current stack of namespaces.
+/// * `schema` - The schema that contains the sequence.
 /// * `seq` - The sequence to annotate.
+/// * `reserved` - Identifiers to escape.
+/// * `string_type` - The C++ types to use for string fields.
 ///
 /// # Returns
 ///
 /// A sequence, formatted for C++ code generation.
-fn annotate_sequence(seq: &Sequence) -> CppSequence {
+fn annotate_sequence(
+    schema: &SBSchema,
+    seq: &Sequence,
+    reserved: &[String],
+    string_type: StringType,
+) -> CppSequence {
     let name = seq.name.to_case(Case::Pascal);
-    let size = seq.fields.iter().fold(0, |acc, f| acc + f.ty.size());
+    let size = seq.static_size();
 
     let fields = seq
         .fields
         .iter()
         .map(|f| CppSequenceField {
-            name: f.name.to_case(Case::Snake),
-            ty: annotate_type(&f.ty, f.name.as_str()),
+            name: escape_identifier(f.name.to_case(Case::Snake), reserved),
+            ty: annotate_type(schema, &f.ty, f.name.as_str(), reserved, string_type),
             pos: f.index,
+            doc: f.doc.clone(),
         })
         .collect();
 
-    CppSequence { name, fields, size }
+    CppSequence {
+        name,
+        fields,
+        size,
+        doc: seq.doc.clone(),
+    }
 }
 
 /// Annotate a Type.
 ///
 /// # Arguments
 ///
-/// * `name_stack` - The current stack of namespaces.
+/// * `schema` - The schema that contains the type, used to find the size of nested sequences.
 /// * `ty` - The type to annotate.
 /// * `field_name` - The name of the field that the type is associated with.
+/// * `reserved` - Identifiers to escape.
+/// * `string_type` - The C++ types to use for string fields.
 ///
 /// # Returns
 ///
 /// An annotated CppType.
-fn annotate_type(ty: &Type, field_name: &str) -> CppType {
+fn annotate_type(
+    schema: &SBSchema,
+    ty: &Type,
+    field_name: &str,
+    reserved: &[String],
+    string_type: StringType,
+) -> CppType {
     match ty {
         Type::Primitive(p) => CppType::Primitive(match p {
             Primitive::Bool => "bool",
@@ -371,10 +559,43 @@ fn annotate_type(ty: &Type, field_name: &str) -> CppType {
             Primitive::F64 => "double",
         }),
         Type::Sequence(s) => CppType::Sequence(s.to_case(Case::Pascal)),
-        Type::Enum(e, s) => CppType::Enum(e.to_case(Case::Pascal), *s),
-        Type::Array(t) => CppType::Array(Box::new(annotate_type(t, field_name))),
-        Type::String => CppType::Primitive("const char*"),
-        Type::OneOf(o) => CppType::OneOf(annotate_oneof(o, field_name)),
+        Type::Enum(e, s) => CppType::Enum(escape_identifier(e.to_case(Case::Pascal), reserved), *s),
+        Type::Array(t) => CppType::Array(Box::new(annotate_type(
+            schema,
+            t,
+            field_name,
+            reserved,
+            string_type,
+        ))),
+        Type::FixedArray(t, len) => CppType::FixedArray(
+            Box::new(annotate_type(schema, t, field_name, reserved, string_type)),
+            *len,
+        ),
+        Type::Map(k, v) => CppType::Map(
+            Box::new(annotate_type(schema, k, field_name, reserved, string_type)),
+            Box::new(annotate_type(schema, v, field_name, reserved, string_type)),
+            entry_size(schema, k),
+            entry_size(schema, v),
+        ),
+        Type::String => CppType::String(string_type),
+        Type::Bytes => CppType::Bytes,
+        Type::OneOf(o) => {
+            CppType::OneOf(annotate_oneof(schema, o, field_name, reserved, string_type))
+        }
+    }
+}
+
+/// Returns the number of bytes a key or value of a map takes up in an entry. Entries are stored
+/// like list elements, so sequences are stored inline.
+fn entry_size(schema: &SBSchema, ty: &Type) -> usize {
+    match ty {
+        Type::Sequence(name) => schema
+            .sequences
+            .iter()
+            .find(|s| &s.name == name)
+            .map(Sequence::static_size)
+            .expect("sequences referenced by a validated schema exist"),
+        _ => ty.size(),
     }
 }
 
@@ -382,24 +603,33 @@ fn annotate_type(ty: &Type, field_name: &str) -> CppType {
 ///
 /// # Arguments
 ///
-/// * `name_stack` - The current stack of namespaces.
+/// * `schema` - The schema that contains the oneof.
 /// * `subfields` - A list of the oneof's fields.
 /// * `field_name` - The name of the field holding the oneof.
+/// * `reserved` - Identifiers to escape.
+/// * `string_type` - The C++ types to use for string fields.
 ///
 /// # Returns
 ///
 /// An annotated CppOneOf.
-fn annotate_oneof(subfields: &[Field], field_name: &str) -> CppOneOf {
+fn annotate_oneof(
+    schema: &SBSchema,
+    subfields: &[Field],
+    field_name: &str,
+    reserved: &[String],
+    string_type: StringType,
+) -> CppOneOf {
     let name = field_name.to_case(Case::Pascal);
 
     let fields = subfields
         .iter()
         .map(|f| CppOneOfField {
-            name: f.name.to_case(Case::Snake),
-            tag: f.name.to_case(Case::UpperSnake),
-            constructor: f.name.to_case(Case::Snake),
-            ty: annotate_type(&f.ty, f.name.as_str()),
+            name: escape_identifier(f.name.to_case(Case::Snake), reserved),
+            tag: escape_identifier(f.name.to_case(Case::UpperSnake), reserved),
+            constructor: escape_identifier(f.name.to_case(Case::Snake), reserved),
+            ty: annotate_type(schema, &f.ty, f.name.as_str(), reserved, string_type),
             index: f.index,
+            doc: f.doc.clone(),
         })
         .collect();
 
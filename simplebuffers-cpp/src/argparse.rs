@@ -1,10 +1,32 @@
 //! Parses generator-specific arguments contained in [GeneratorParams::additional_args].
 
-use clap::Parser;
+use clap::{Command, CommandFactory, Parser, ValueEnum};
 use simplebuffers_codegen::GeneratorParams;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The C++ types used for string fields.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum StringType {
+    /// Null-terminated `const char*` strings, which do not require the standard library.
+    #[default]
+    Char,
+
+    /// Writers accept `std::string` and readers return `std::string_view`. Requires C++17.
+    Std,
+}
+
+/// The byte order of multi-byte values on the wire.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Endianness {
+    /// Least significant byte first, like every other generator.
+    #[default]
+    Little,
+
+    /// Most significant byte first.
+    Big,
+}
+
 /// Struct to parse CLI args into.
 #[derive(Parser, Debug)]
 #[command(name = "SimpleBuffers C++ Code Generator")]
@@ -15,6 +37,52 @@ struct Cli {
     /// If `headerdir` is not specified, header files will be written to `dstdir` as well.
     #[arg(long)]
     headerdir: Option<String>,
+
+    /// Pass the generated files through `clang-format` before writing them. A path to a
+    /// `.clang-format` file may be given; otherwise, the style file is discovered from the output
+    /// directories.
+    #[arg(long = "clang-format", num_args = 0..=1, require_equals = true, default_missing_value = "")]
+    clang_format: Option<String>,
+
+    /// Generate a single header file that contains the method definitions and the core library,
+    /// instead of a separate source file.
+    #[arg(long)]
+    header_only: bool,
+
+    /// Generate `operator==` and `operator!=` for readers, which compare every field by value.
+    #[arg(long)]
+    emit_equality: bool,
+
+    /// Generate `operator<<` for enums and readers, which prints messages in a JSON-like form.
+    #[arg(long)]
+    emit_ostream: bool,
+
+    /// Generate `to_string` and `from_string` for enums, which convert between variants and their
+    /// names. Requires C++17.
+    #[arg(long)]
+    emit_enum_names: bool,
+
+    /// Use `#pragma once` in generated headers instead of include guards.
+    #[arg(long)]
+    pragma_once: bool,
+
+    /// The C++ types to use for string fields.
+    #[arg(long = "string-type", value_enum, default_value_t = StringType::Char)]
+    string_type: StringType,
+
+    /// The byte order of numbers, enums, offsets, and lengths on the wire. Both ends of a
+    /// connection must use the same byte order.
+    #[arg(long, value_enum, default_value_t = Endianness::Little)]
+    endianness: Endianness,
+
+    /// Write `<dstdir>/<file name>.cmake`, which defines a library target for the generated files.
+    #[arg(long)]
+    emit_cmake: bool,
+
+    /// The namespace to generate code in, such as `robot::messages`. Defaults to the schema's
+    /// package, or to `simplebuffers_<file name>` if the schema does not declare one.
+    #[arg(long)]
+    namespace: Option<String>,
 }
 
 /// A struct that holds generator-specific arguments for the C++ generator.
@@ -23,15 +91,74 @@ pub(crate) struct CppGeneratorParams {
     /// The directory to write generated header files to.
     pub header_dir: String,
 
+    /// Whether to format generated files with `clang-format`. If this contains a non-empty string,
+    /// it is the path of the `.clang-format` style file to use.
+    pub clang_format: Option<String>,
+
+    /// Whether to generate a single header file instead of a header and a source file.
+    pub header_only: bool,
+
+    /// Whether to generate equality operators for readers.
+    pub emit_equality: bool,
+
+    /// Whether to generate stream operators for enums and readers.
+    pub emit_ostream: bool,
+
+    /// Whether to generate functions that convert enums to and from their names.
+    pub emit_enum_names: bool,
+
+    /// Whether to use `#pragma once` instead of include guards in generated headers.
+    pub pragma_once: bool,
+
+    /// The C++ types to use for string fields.
+    pub string_type: StringType,
+
+    /// The byte order of multi-byte values on the wire.
+    pub endianness: Endianness,
+
+    /// Whether to write a CMake script that defines a library target.
+    pub emit_cmake: bool,
+
+    /// The namespace to generate code in, with nested namespaces separated by `::`.
+    pub namespace: String,
+
     /// The global compiler parameters.
     pub global: GeneratorParams,
 }
 
-/// Parse generator-specific arguments from an input string.
-pub(crate) fn parse_args(generator_params: &GeneratorParams) -> CppGeneratorParams {
-    let cli = Cli::parse_from(generator_params.additional_args.split_ascii_whitespace());
+/// Returns the command that describes the generator-specific arguments, so the compiler can
+/// show them in its help output and validate them before generating.
+pub(crate) fn command() -> Command {
+    Cli::command()
+}
+
+/// Parse generator-specific arguments from the compiler-provided argument list.
+///
+/// # Arguments
+///
+/// * `generator_params` - The global compiler parameters.
+/// * `package` - The package declared by the schema, which is used as the default namespace.
+pub(crate) fn parse_args(
+    generator_params: &GeneratorParams,
+    package: Option<&str>,
+) -> CppGeneratorParams {
+    let cli = Cli::parse_from(&generator_params.additional_args);
+    let namespace = cli.namespace.unwrap_or_else(|| match package {
+        Some(package) => package.replace('.', "::"),
+        None => format!("simplebuffers_{}", generator_params.file_name),
+    });
     CppGeneratorParams {
         header_dir: cli.headerdir.unwrap_or(generator_params.dest_dir.clone()),
+        clang_format: cli.clang_format,
+        header_only: cli.header_only,
+        emit_equality: cli.emit_equality,
+        emit_ostream: cli.emit_ostream,
+        emit_enum_names: cli.emit_enum_names,
+        pragma_once: cli.pragma_once,
+        string_type: cli.string_type,
+        endianness: cli.endianness,
+        emit_cmake: cli.emit_cmake,
+        namespace,
         global: generator_params.clone(),
     }
 }
@@ -0,0 +1,85 @@
+//! Formats generated code with an external `clang-format` executable.
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+    thread,
+};
+
+/// The name of the `clang-format` executable. It is expected to be on the user's `PATH`.
+const CLANG_FORMAT: &str = "clang-format";
+
+/// Formats generated code with `clang-format` before it is written.
+///
+/// The code is passed to `clang-format` through stdin, as if it were the file it will be written
+/// to, so style files are discovered from the output directories. If `clang-format` cannot be
+/// found, a warning is printed and the code is left untouched. This keeps formatting an optional
+/// convenience rather than a hard dependency of the generator.
+///
+/// # Arguments
+///
+/// * `files` - The paths the code will be written to, and the code, which is replaced with the
+///   formatted code.
+/// * `style_file` - The path to a `.clang-format` file. If this is empty, `clang-format` will
+///   discover the style file itself by searching the parent directories of each file.
+///
+/// # Errors
+///
+/// A human-readable string if `clang-format` was found but failed to format a file.
+pub(crate) fn clang_format(files: &mut [(String, String)], style_file: &str) -> Result<(), String> {
+    let style = if style_file.is_empty() {
+        "--style=file".to_string()
+    } else {
+        format!("--style=file:{}", style_file)
+    };
+
+    for (path, code) in files.iter_mut() {
+        let mut child = match Command::new(CLANG_FORMAT)
+            .arg(&style)
+            .arg(format!("--assume-filename={}", path))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(_) => {
+                eprintln!(
+                    "WARNING: `{}` could not be run; generated files were not formatted",
+                    CLANG_FORMAT
+                );
+                return Ok(());
+            }
+        };
+
+        // Write the code from another thread, so that `clang-format` cannot block on a full output
+        // pipe while the code is still being written.
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        let input = code.as_bytes();
+        let output = thread::scope(|scope| {
+            let writer = scope.spawn(move || stdin.write_all(input));
+            let output = child.wait_with_output();
+            // A write error means `clang-format` exited early, which its status reports.
+            let _ = writer.join();
+            output
+        })
+        .map_err(|e| format!("Failed to run `{}`: {}", CLANG_FORMAT, e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "`{}` failed to format {}: {}",
+                CLANG_FORMAT,
+                path,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        *code = String::from_utf8(output.stdout).map_err(|_| {
+            format!(
+                "`{}` produced invalid UTF-8 while formatting {}",
+                CLANG_FORMAT, path
+            )
+        })?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! Generates a CMake script that defines a library target for the generated files.
+
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use indent::indent_by;
+use indoc::formatdoc;
+
+use crate::argparse::{CppGeneratorParams, StringType};
+
+/// Returns `path` relative to `base`. If the paths have no common root (such as paths on different
+/// drives), `path` is returned unchanged. Both paths must be canonical.
+fn relative_path(path: &Path, base: &Path) -> PathBuf {
+    let path_components: Vec<Component> = path.components().collect();
+    let base_components: Vec<Component> = base.components().collect();
+    let common = path_components
+        .iter()
+        .zip(&base_components)
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common == 0 {
+        return path.to_path_buf();
+    }
+
+    base_components[common..]
+        .iter()
+        .map(|_| Component::ParentDir)
+        .chain(path_components[common..].iter().copied())
+        .collect()
+}
+
+/// Generates a CMake script that defines the `simplebuffers_{file_name}` library target. The script
+/// is written to the destination directory, and refers to the generated files relative to itself,
+/// so the output directories can be moved together.
+///
+/// With `--header-only`, the target is an `INTERFACE` library. Otherwise, it is a `STATIC` library
+/// built from the generated source file.
+///
+/// # Arguments
+///
+/// * `params` - The generator parameters. The generated files must already have been written.
+///
+/// # Errors
+///
+/// A human-readable string if the output directories cannot be resolved.
+pub(crate) fn generate_cmake(params: &CppGeneratorParams) -> Result<String, String> {
+    let canonical =
+        |dir: &str| fs::canonicalize(dir).map_err(|e| format!("Failed to resolve {}: {}", dir, e));
+    let header_dir = relative_path(
+        &canonical(&params.header_dir)?,
+        &canonical(&params.global.dest_dir)?,
+    );
+    let header_dir = match header_dir.to_string_lossy().replace('\\', "/") {
+        path if path.is_empty() => "${CMAKE_CURRENT_LIST_DIR}".to_string(),
+        path if header_dir.is_absolute() => path,
+        path => format!("${{CMAKE_CURRENT_LIST_DIR}}/{}", path),
+    };
+
+    let name = &params.global.file_name;
+    let target = format!("simplebuffers_{}", name);
+    let standard = match params.string_type {
+        StringType::Char => "cxx_std_11",
+        StringType::Std => "cxx_std_17",
+    };
+
+    let library = if params.header_only {
+        formatdoc! {
+            r#"
+            add_library({target} INTERFACE)
+            target_include_directories({target} INTERFACE "{header_dir}")
+            target_compile_features({target} INTERFACE {standard})"#
+        }
+    } else {
+        formatdoc! {
+            r#"
+            add_library({target} STATIC
+              "${{CMAKE_CURRENT_LIST_DIR}}/{name}.cpp"
+              "{header_dir}/{name}.hpp"
+              "{header_dir}/simplebuffers.hpp"
+            )
+            target_include_directories({target} PUBLIC "{header_dir}")
+            target_compile_features({target} PUBLIC {standard})"#
+        }
+    };
+
+    Ok(formatdoc! {
+        r#"
+        # This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+        #
+        # Defines the library target `{target}` for the generated files. Add it to a
+        # project with `include()`, then link against it with `target_link_libraries()`.
+
+        if(NOT TARGET {target})
+          {library}
+        endif()
+        "#,
+        library = indent_by(2, library)
+    })
+}
@@ -4,8 +4,13 @@ use crate::annotate::CppEnum;
 use crate::annotate::CppOneOf;
 use crate::annotate::CppSchema;
 use crate::annotate::CppSequence;
+use crate::annotate::SizeToType;
 use crate::annotate::ToReaderWriterString;
 use crate::argparse::CppGeneratorParams;
+use crate::argparse::Endianness;
+use crate::argparse::StringType;
+use crate::sourcegen::{generate_definitions, namespace_blocks};
+use crate::CORELIB;
 use indent::indent_by;
 use indoc::formatdoc;
 use itertools::Itertools;
@@ -25,18 +30,33 @@ use itertools::Itertools;
 ///
 /// The code for a header file, as a String.
 pub(crate) fn generate_header(params: &CppGeneratorParams, schema: &CppSchema) -> String {
-    // Generate the name of the include guards.
-    let include_guards = format!(
-        "SIMPLEBUFFERS_GENERATED__{}_HPP",
-        params.global.file_name.to_uppercase()
-    );
+    // Generate the include guards, or `#pragma once` if requested.
+    let (guard_begin, guard_end) = if params.pragma_once {
+        ("#pragma once".to_string(), String::new())
+    } else {
+        let include_guards = format!(
+            "SIMPLEBUFFERS_GENERATED__{}_HPP",
+            params.global.file_name.to_uppercase()
+        );
+        (
+            format!("#ifndef {include_guards}\n#define {include_guards}"),
+            "\n\n#endif".to_string(),
+        )
+    };
 
-    // Generate namespace name.
-    let namespace = format!("simplebuffers_{}", params.global.file_name);
+    // Generate namespace blocks.
+    let (namespace_begin, namespace_end) = namespace_blocks(&params.namespace);
 
     // Generate enum definitions.
     let enum_definitions = schema.enums.iter().map(define_enum).join("\n\n");
 
+    // Declare name conversions for enums.
+    let enum_name_declarations = if params.emit_enum_names {
+        declare_enum_names(schema)
+    } else {
+        String::new()
+    };
+
     // Generate forward declarations for sequence writers.
     let writer_forward_declarations = schema
         .sequences
@@ -62,21 +82,77 @@ pub(crate) fn generate_header(params: &CppGeneratorParams, schema: &CppSchema) -
     let sequence_reader_definitions = schema
         .sequences
         .iter()
-        .map(define_sequence_reader)
+        .map(|s| define_sequence_reader(s, params.emit_equality))
         .join("\n\n");
 
+    // In header-only mode, method definitions follow the class definitions.
+    let definitions = if params.header_only {
+        generate_definitions(params, schema, true)
+    } else {
+        String::new()
+    };
+
+    // Declare stream operators for enums and readers.
+    let printer_declarations = if params.emit_ostream {
+        declare_printers(schema)
+    } else {
+        String::new()
+    };
+
+    // Some options need standard library features that the core library only enables when they are
+    // available, so fail early with a clear message if they are missing.
+    let mut requirements = vec![];
+    if params.string_type == StringType::Std {
+        requirements.push((
+            "SIMPLEBUFFERS_STD_STRINGS",
+            "--string-type=std requires C++17",
+        ));
+    }
+    if params.emit_ostream {
+        requirements.push(("SIMPLEBUFFERS_OSTREAM", "--emit-ostream requires <ostream>"));
+    }
+    if params.emit_enum_names {
+        requirements.push((
+            "SIMPLEBUFFERS_STD_STRINGS",
+            "--emit-enum-names requires C++17",
+        ));
+    }
+    let requirement_checks = requirements
+        .iter()
+        .map(|(feature, message)| {
+            formatdoc! {
+                r#"
+                #ifndef {feature}
+                #error "Code generated with {message}"
+                #endif"#
+            }
+        })
+        .chain(std::iter::once(check_byte_order(params.endianness)))
+        .join("\n");
+
+    // Big-endian code selects the byte order of the core library before including it.
+    let byte_order_define = match params.endianness {
+        Endianness::Little => "",
+        Endianness::Big => {
+            "#ifndef SIMPLEBUFFERS_BIG_ENDIAN_WIRE\n#define SIMPLEBUFFERS_BIG_ENDIAN_WIRE 1\n#endif\n"
+        }
+    };
+
     // Generate the full header file.
-    formatdoc! {
+    let header = formatdoc! {
         r#"
-        #ifndef {include_guards}
-        #define {include_guards}
+        {guard_begin}
 
-        #include "simplebuffers.hpp"
+        {byte_order_define}#include "simplebuffers.hpp"
 
-        namespace {namespace} {{
+        {requirement_checks}
+
+        {namespace_begin}
 
         {enum_definitions}
 
+        {enum_name_declarations}
+
         {writer_forward_declarations}
 
         {sequence_writer_definitions}
@@ -85,11 +161,43 @@ pub(crate) fn generate_header(params: &CppGeneratorParams, schema: &CppSchema) -
 
         {sequence_reader_definitions}
 
-        }} // namespace {namespace}
+        {printer_declarations}
+
+        {definitions}
 
+        {namespace_end}{guard_end}"#
+    }
+    .replace("\n\n\n", "\n");
+
+    // In header-only mode, the core library is inlined so that the header has no dependencies. This
+    // happens after blank lines are collapsed, so that the core library is copied unchanged.
+    if params.header_only {
+        header.replacen("#include \"simplebuffers.hpp\"", CORELIB.trim_end(), 1)
+    } else {
+        header
+    }
+}
+
+/// Generates the C++ code that checks that the core library was included with the byte order the
+/// code was generated for. The core library is shared by all generated code, so code generated
+/// for different byte orders cannot be used together.
+fn check_byte_order(endianness: Endianness) -> String {
+    let (condition, message) = match endianness {
+        Endianness::Little => (
+            "SIMPLEBUFFERS_BIG_ENDIAN_WIRE",
+            "Code generated for little-endian data cannot be used with code generated with --endianness=big",
+        ),
+        Endianness::Big => (
+            "!SIMPLEBUFFERS_BIG_ENDIAN_WIRE",
+            "Code generated with --endianness=big cannot be used with code generated for little-endian data",
+        ),
+    };
+    formatdoc! {
+        r#"
+        #if {condition}
+        #error "{message}"
         #endif"#
     }
-    .replace("\n\n\n", "\n")
 }
 
 //                                                                                                //
@@ -108,16 +216,29 @@ fn define_enum(data: &CppEnum) -> String {
     let variants = data
         .variants
         .iter()
-        .map(|v| format!("{} = {}", v.0, v.1))
+        .map(|v| format!("{}{} = {}", doc_comment(&v.2), v.0, enum_literal(v.1)))
         .join(",\n");
 
-    // Generate the full enum code.
+    // Generate the enum's doc comment.
+    let doc = doc_comment(&data.doc);
+
+    // The type the enum is written as, which may be narrower than its base type.
+    let wire_type = (data.size as usize).to_type().to_string();
+
+    // Generate the full enum code. Lists write their elements with `get_static_size` and
+    // `write_field`, which find the overloads for the enum through argument-dependent lookup.
     formatdoc! {
         r"
-        enum class {name} : {dtype} {{
+        {doc}enum class {name} : {dtype} {{
             {variants}
-        }};",
-        variants = indent_by(4, variants)
+        }};
+
+        inline uint16_t get_static_size({name}) {{ return {size}; }}
+        inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor, {name} val) {{
+            return simplebuffers::write_field(dest, dest_end, dyn_cursor, static_cast<{wire_type}>(val));
+        }}",
+        variants = indent_by(4, variants),
+        size = data.size
     }
 }
 
@@ -150,27 +271,41 @@ fn define_sequence_writer(seq: &CppSequence) -> String {
         let members = seq
             .fields
             .iter()
-            .map(|f| format!("{} {};", f.ty.to_writer_string(), f.name))
+            .map(|f| {
+                format!(
+                    "{}{} {};",
+                    doc_comment(&f.doc),
+                    f.ty.to_writer_string(),
+                    f.name
+                )
+            })
             .join("\n");
 
+        // Generate copy and move constructors and assignment operators.
+        let copy_and_move = declare_copy_and_move(&class_name);
+
         // Generate class body.
         formatdoc! {
             r"
             {oneofs}
 
             {class_name}({param_list});
+            {copy_and_move}
 
             {members}
 
-            uint16_t static_size() const override;
+            uint16_t static_size() const noexcept override;
             uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;"
         }
     };
 
+    // Generate the sequence's doc comment.
+    let doc = doc_comment(&seq.doc);
+
     // Generate full class code.
     formatdoc! {
         r"
-        class {class_name} : public simplebuffers::SimpleBufferWriter {{
+        {doc}class {class_name} : public simplebuffers::SimpleBufferWriter {{
            public:
             {body}
         }};",
@@ -215,7 +350,8 @@ fn define_oneof_writer(oneof: &CppOneOf) -> String {
             .iter()
             .map(|f| {
                 format!(
-                    "static {} {}({}* val);",
+                    "{}static {} {}({}* val);",
+                    doc_comment(&f.doc),
                     class_name,
                     f.constructor,
                     f.ty.to_writer_string()
@@ -223,6 +359,9 @@ fn define_oneof_writer(oneof: &CppOneOf) -> String {
             })
             .join("\n");
 
+        // Generate copy and move constructors and assignment operators.
+        let copy_and_move = declare_copy_and_move(&class_name);
+
         // Generate public body.
         formatdoc! {
             r"
@@ -238,6 +377,8 @@ fn define_oneof_writer(oneof: &CppOneOf) -> String {
             
             {constructors}
 
+            {copy_and_move}
+
             uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;",
             tags = indent_by(4, tags),
             values = indent_by(4, values)
@@ -271,8 +412,9 @@ fn forward_declare_sequence_reader(seq: &CppSequence) -> String {
     format!("class {case_corrected_name};")
 }
 
-/// Generates the C++ code for defining sequence readers.
-fn define_sequence_reader(seq: &CppSequence) -> String {
+/// Generates the C++ code for defining sequence readers. If `emit_equality` is set, equality
+/// operators are declared for the reader and its oneofs.
+fn define_sequence_reader(seq: &CppSequence, emit_equality: bool) -> String {
     // The full name of the sequence writer class, in the form "SequenceReader".
     let class_name = seq.to_reader_string();
 
@@ -283,7 +425,8 @@ fn define_sequence_reader(seq: &CppSequence) -> String {
             .iter()
             .map(|f| {
                 format!(
-                    "{ty} {name}() const;",
+                    "{doc}{ty} {name}() const noexcept;",
+                    doc = doc_comment(&f.doc),
                     ty = f.ty.to_reader_string(),
                     name = f.name
                 )
@@ -292,7 +435,14 @@ fn define_sequence_reader(seq: &CppSequence) -> String {
 
         // Generate class definitions of any oneof fields contained in the sequence. These are
         // subclasses of this sequence class.
-        let oneofs = seq.oneofs().map(define_oneof_reader).join("\n\n");
+        let oneofs = seq
+            .oneofs()
+            .map(|o| define_oneof_reader(o, emit_equality))
+            .join("\n\n");
+
+        // Generate copy and move constructors and assignment operators.
+        let copy_and_move =
+            declare_copy_and_move(&class_name) + &declare_equality(&class_name, emit_equality);
 
         // Generate class body.
         formatdoc! {
@@ -301,15 +451,19 @@ fn define_sequence_reader(seq: &CppSequence) -> String {
             {oneofs}
 
             {class_name}(const uint8_t* data_ptr, size_t idx = 0);
-            uint16_t static_size() const override;
+            {copy_and_move}
+            uint16_t static_size() const noexcept override;
             {fields}"
         }
     };
 
+    // Generate the sequence's doc comment.
+    let doc = doc_comment(&seq.doc);
+
     // Generate full class code.
     formatdoc! {
         r"
-        class {class_name} : public simplebuffers::SimpleBufferReader {{
+        {doc}class {class_name} : public simplebuffers::SimpleBufferReader {{
             {body}
         }};",
         body = indent_by(4, body.trim())
@@ -319,7 +473,7 @@ fn define_sequence_reader(seq: &CppSequence) -> String {
 /// Generates the C++ code for defining a oneof reader. This should be written as a subclass of a
 /// sequence reader. Because oneofs can contain other oneofs as fields, we must recursively define
 /// any oneof reader we find.
-fn define_oneof_reader(oneof: &CppOneOf) -> String {
+fn define_oneof_reader(oneof: &CppOneOf, emit_equality: bool) -> String {
     // The full name of the oneof reader class, in the form "OneOfReader".
     let class_name = oneof.to_reader_string();
 
@@ -328,7 +482,10 @@ fn define_oneof_reader(oneof: &CppOneOf) -> String {
     let public_body = {
         // Generate class definitions of any oneof fields. These are subclasses of this oneof class
         // and are generated recursively.
-        let oneofs = oneof.oneofs().map(define_oneof_reader).join("\n\n");
+        let oneofs = oneof
+            .oneofs()
+            .map(|o| define_oneof_reader(o, emit_equality))
+            .join("\n\n");
 
         // Generate a list of tags for the fields. These are members of the `Tag` enum class.
         let tags = oneof
@@ -342,9 +499,20 @@ fn define_oneof_reader(oneof: &CppOneOf) -> String {
         let fields = oneof
             .fields
             .iter()
-            .map(|f| format!("{} {}() const;", f.ty.to_reader_string(), f.name))
+            .map(|f| {
+                format!(
+                    "{}{} {}() const noexcept;",
+                    doc_comment(&f.doc),
+                    f.ty.to_reader_string(),
+                    f.name
+                )
+            })
             .join("\n");
 
+        // Generate copy and move constructors and assignment operators.
+        let copy_and_move =
+            declare_copy_and_move(&class_name) + &declare_equality(&class_name, emit_equality);
+
         formatdoc! {
             r"
             {oneofs}
@@ -354,7 +522,8 @@ fn define_oneof_reader(oneof: &CppOneOf) -> String {
             }};
             
             {class_name}(const uint8_t* data_ptr, size_t idx = 0);
-            Tag tag() const;
+            {copy_and_move}
+            Tag tag() const noexcept;
             {fields}",
             tags = indent_by(4, tags)
         }
@@ -372,3 +541,123 @@ fn define_oneof_reader(oneof: &CppOneOf) -> String {
         public_body = indent_by(4, public_body.trim())
     }
 }
+
+//                                                                                                //
+// ================================ Generate Printer Components ================================= //
+//                                                                                                //
+
+/// Generates the C++ code for declaring stream operators for every enum, sequence reader, and
+/// oneof reader.
+fn declare_printers(schema: &CppSchema) -> String {
+    let enums = schema.enums.iter().map(|e| {
+        format!(
+            "std::ostream& operator<<(std::ostream& os, {} value);",
+            e.name
+        )
+    });
+    let readers = schema.sequences.iter().flat_map(|seq| {
+        seq.nested_oneof_readers()
+            .into_iter()
+            .map(|(name, _)| name)
+            .chain(std::iter::once(seq.to_reader_string()))
+            .map(|name| {
+                format!("std::ostream& operator<<(std::ostream& os, const {name}& reader);")
+            })
+            .collect_vec()
+    });
+    enums.chain(readers).join("\n")
+}
+
+//                                                                                                //
+// =============================== Generate Enum Name Components ================================ //
+//                                                                                                //
+
+/// Generates the C++ code for declaring `to_string` and `from_string` for every enum. Since
+/// `from_string` only differs by its return type, it is a function template that is specialized for
+/// each enum. If there are no enums, an empty string is returned.
+fn declare_enum_names(schema: &CppSchema) -> String {
+    if schema.enums.is_empty() {
+        return String::new();
+    }
+    let declarations = schema
+        .enums
+        .iter()
+        .map(|e| {
+            let name = &e.name;
+            formatdoc! {
+                r"
+                const char* to_string({name} value) noexcept;
+                template <>
+                std::optional<{name}> from_string<{name}>(std::string_view name) noexcept;"
+            }
+        })
+        .join("\n");
+    formatdoc! {
+        r"
+        template <typename E>
+        std::optional<E> from_string(std::string_view name) noexcept;
+
+        {declarations}"
+    }
+}
+
+//                                                                                                //
+// ================================= Generate Shared Components ================================= //
+//                                                                                                //
+
+/// Generates the C++ literal for an enum value. The minimum 64-bit value cannot be written as a
+/// negated literal, since the literal itself would be out of range. Values above the maximum
+/// 64-bit signed value need a suffix, since unsuffixed decimal literals are never unsigned.
+fn enum_literal(value: i128) -> String {
+    if value == i64::MIN as i128 {
+        "INT64_MIN".to_string()
+    } else if value > i64::MAX as i128 {
+        format!("{}ULL", value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Generates a Doxygen comment block from a doc comment, followed by a newline. If there is no doc
+/// comment, an empty string is returned.
+fn doc_comment(doc: &Option<String>) -> String {
+    match doc {
+        None => String::new(),
+        Some(doc) => {
+            // A `*/` in the comment would end the block early.
+            let lines = doc
+                .replace("*/", "* /")
+                .lines()
+                .map(|line| format!(" * {}", line).trim_end().to_string())
+                .join("\n");
+            format!("/**\n{}\n */\n", lines)
+        }
+    }
+}
+
+/// Generates the C++ code for declaring explicitly-defaulted copy and move constructors and
+/// assignment operators. Readers and writers only hold pointers and values, so moving them never
+/// throws.
+fn declare_copy_and_move(class_name: &str) -> String {
+    formatdoc! {
+        r"
+        {class_name}(const {class_name}&) = default;
+        {class_name}({class_name}&&) noexcept = default;
+        {class_name}& operator=(const {class_name}&) = default;
+        {class_name}& operator=({class_name}&&) noexcept = default;"
+    }
+}
+
+/// Generates the C++ code for declaring equality operators for a reader, preceded by a newline. If
+/// `emit_equality` is not set, an empty string is returned.
+fn declare_equality(class_name: &str, emit_equality: bool) -> String {
+    if !emit_equality {
+        return String::new();
+    }
+    formatdoc! {
+        r"
+
+        bool operator==(const {class_name}& other) const noexcept;
+        bool operator!=(const {class_name}& other) const noexcept;"
+    }
+}
@@ -15,7 +15,14 @@
 //!    definitions, forward declarations, and class definitions for sequence writers and readers.
 //!
 //! 4. **Source Generation**: The `sourcegen` module generates C++ source files, implementing the
-//!    methods declared in the header files.
+//!    methods declared in the header files. With `--header-only`, these definitions are marked
+//!    `inline` and placed in the header instead, along with the core library.
+//!
+//! 5. **Formatting**: If requested, the `clangformat` module passes the generated files through
+//!    `clang-format` before they are written, so that they match a project's formatting rules.
+//!
+//! 6. **Build Integration**: If requested, the `cmakegen` module generates a CMake script that
+//!    defines a library target for the generated files.
 //!
 //! The generator creates separate writer and reader classes for each sequence and oneof in the
 //! schema. It also handles nested structures and generates appropriate code for serialization and
@@ -23,17 +30,41 @@
 
 mod annotate;
 mod argparse;
+mod clangformat;
+mod cmakegen;
 mod headergen;
 mod sourcegen;
 
-use std::{fs::File, io::Write};
+use std::path::PathBuf;
 
 use annotate::annotate_schema;
-use argparse::parse_args;
+use argparse::{command, parse_args, CppGeneratorParams};
+use clangformat::clang_format;
+use cmakegen::generate_cmake;
 use headergen::generate_header;
-use simplebuffers_codegen::CodeGenerator;
+use simplebuffers_codegen::{CodeGenerator, FileSink, ItemKind};
+use simplebuffers_core::{walk_types, Type};
 use sourcegen::generate_source;
 
+/// The SimpleBuffers core library, which generated code depends on.
+pub(crate) const CORELIB: &str = include_str!("../corelib/simplebuffers.hpp");
+
+/// Writes the CMake script from [generate_cmake] to `{dest_dir}/{file_name}.cmake`. This must be
+/// called after the other files are written.
+///
+/// # Returns
+///
+/// The path of the CMake script.
+fn write_cmake(params: &CppGeneratorParams, sink: &mut dyn FileSink) -> Result<PathBuf, String> {
+    let cmake = generate_cmake(params)?;
+    let cmake_path = PathBuf::from(format!(
+        "{}/{}.cmake",
+        params.global.dest_dir, params.global.file_name
+    ));
+    sink.write_file(&cmake_path, &cmake)?;
+    Ok(cmake_path)
+}
+
 #[derive(Debug)]
 pub struct CPPCodeGenerator;
 
@@ -49,50 +80,71 @@ impl CodeGenerator for CPPCodeGenerator {
         &mut self,
         schema: &simplebuffers_core::SBSchema,
         params: &simplebuffers_codegen::GeneratorParams,
-    ) -> Result<(), String> {
-        let generator_params = parse_args(params);
-        let annotated = annotate_schema(schema);
-
-        // Generate files.
-        let header = generate_header(&generator_params, &annotated);
-        let source = generate_source(&generator_params, &annotated);
-
-        // Write header file.
-        {
-            let mut header_file = File::create(format!(
-                "{}/{}.hpp",
-                generator_params.header_dir, generator_params.global.file_name
-            ))
-            .expect("Failed to open header file");
-            header_file
-                .write_all(header.as_bytes())
-                .expect("Failed to write header file.");
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        // Oneof classes are nested in the class of the field that holds them, which a map value has
+        // no name for.
+        for field in schema.sequences.iter().flat_map(|s| &s.fields) {
+            let mut oneof_value = false;
+            walk_types(&field.ty, &mut |ty| {
+                if let Type::Map(_, value) = ty {
+                    walk_types(value, &mut |t| oneof_value |= matches!(t, Type::OneOf(_)));
+                }
+            });
+            if oneof_value {
+                return Err(format!(
+                    "The C++ generator does not support oneofs as map values (field `{}`)",
+                    field.name
+                ));
+            }
         }
 
-        // Write source file.
-        {
-            let mut source_file = File::create(format!(
+        let generator_params = parse_args(params, schema.package.as_deref());
+        // Identifiers are escaped even if reserved identifiers were not allowed, since the check is
+        // case-sensitive and identifiers such as `While` become reserved when their case changes.
+        let escaped = self.reserved_identifiers(params);
+        let annotated = annotate_schema(schema, &escaped, generator_params.string_type)?;
+
+        // Generate files. A header-only build contains the definitions and the corelib, so it has
+        // no source file.
+        let header_path = format!(
+            "{}/{}.hpp",
+            generator_params.header_dir, generator_params.global.file_name
+        );
+        let mut files = vec![(header_path, generate_header(&generator_params, &annotated))];
+        if !generator_params.header_only {
+            let source_path = format!(
                 "{}/{}.cpp",
                 generator_params.global.dest_dir, generator_params.global.file_name
-            ))
-            .expect("Failed to open header file");
-            source_file
-                .write_all(source.as_bytes())
-                .expect("Failed to write header file.");
+            );
+            files.push((source_path, generate_source(&generator_params, &annotated)));
         }
 
-        // Copy corelib to header directory.
-        {
-            let corelib = include_str!("../corelib/simplebuffers.hpp");
-            let mut corelib_file =
-                File::create(format!("{}/simplebuffers.hpp", generator_params.header_dir))
-                    .expect("Failed to open corelib header file");
-            corelib_file
-                .write_all(corelib.as_bytes())
-                .expect("Failed to write header file.");
+        // Format generated files before they are written.
+        if let Some(style_file) = &generator_params.clang_format {
+            clang_format(&mut files, style_file)?;
         }
 
-        Ok(())
+        // Write generated files.
+        let mut written = vec![];
+        for (path, contents) in files {
+            let path = PathBuf::from(path);
+            sink.write_file(&path, &contents)?;
+            written.push(path);
+        }
+
+        // Copy corelib to header directory. It is left unformatted, since it is not generated.
+        if !generator_params.header_only {
+            let corelib_path =
+                PathBuf::from(format!("{}/simplebuffers.hpp", generator_params.header_dir));
+            sink.write_file(&corelib_path, CORELIB)?;
+            written.push(corelib_path);
+        }
+
+        if generator_params.emit_cmake {
+            written.push(write_cmake(&generator_params, sink)?);
+        }
+        Ok(written)
     }
 
     fn reserved_identifiers(
@@ -203,4 +255,28 @@ impl CodeGenerator for CPPCodeGenerator {
         .map(|s| s.to_string())
         .collect()
     }
+
+    fn reserved_identifiers_case_sensitive(&self) -> bool {
+        true
+    }
+
+    fn reserved_identifiers_apply_to(&self, kind: ItemKind) -> bool {
+        // Sequence names are always followed by `Writer` or `Reader`, so they cannot conflict.
+        kind != ItemKind::Sequence
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        ["hpp", "cpp", "cmake"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn description(&self) -> String {
+        "C++ code (header and source, or header-only)".to_string()
+    }
+
+    fn cli(&self) -> Option<clap::Command> {
+        Some(command())
+    }
 }
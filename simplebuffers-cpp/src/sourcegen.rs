@@ -1,5 +1,6 @@
 //! Generates a C++ source file.
 
+use crate::annotate::CppEnum;
 use crate::annotate::CppOneOf;
 use crate::annotate::CppOneOfField;
 use crate::annotate::CppSchema;
@@ -9,6 +10,7 @@ use crate::annotate::CppType;
 use crate::annotate::SizeToType;
 use crate::annotate::ToReaderWriterString;
 use crate::argparse::CppGeneratorParams;
+use crate::argparse::StringType;
 use indent::indent_by;
 use indoc::formatdoc;
 use itertools::Itertools;
@@ -31,34 +33,106 @@ pub(crate) fn generate_source(params: &CppGeneratorParams, schema: &CppSchema) -
     // Generate the name of the associated header file.
     let header_name = format!("{}.hpp", params.global.file_name);
 
-    // Generate namespace name.
-    let namespace = format!("simplebuffers_{}", params.global.file_name);
+    // Generate namespace blocks.
+    let (namespace_begin, namespace_end) = namespace_blocks(&params.namespace);
+
+    // Generate method definitions.
+    let definitions = generate_definitions(params, schema, false);
+
+    // Generate the full source file.
+    formatdoc! {
+        r#"
+        #include "{header_name}"
+
+        {namespace_begin}
+
+        {definitions}
+
+        {namespace_end}"#
+    }
+}
+
+/// Generates the lines that open and close a namespace. Nested namespaces (such as `a::b`) are
+/// opened one at a time, since nested namespace definitions require C++17.
+///
+/// # Arguments
+///
+/// * `namespace` - The namespace, with nested namespaces separated by `::`.
+///
+/// # Returns
+///
+/// The lines that open the namespace, and the lines that close it.
+pub(crate) fn namespace_blocks(namespace: &str) -> (String, String) {
+    let parts: Vec<&str> = namespace.split("::").collect();
+    let begin = parts
+        .iter()
+        .map(|part| format!("namespace {} {{", part))
+        .join("\n");
+    let end = parts
+        .iter()
+        .rev()
+        .map(|part| format!("}} // namespace {}", part))
+        .join("\n");
+    (begin, end)
+}
+
+/// Generates the definitions of every method declared in the header file, without any includes or
+/// namespaces.
+///
+/// # Arguments
+///
+/// * `params` - Generator params.
+/// * `schema` - The schema to generate from.
+/// * `inline` - Whether to mark every definition `inline`. This is required when definitions are
+///   placed in a header file, so that including it in multiple translation units does not violate
+///   the one definition rule.
+///
+/// # Returns
+///
+/// The definitions, as a String.
+pub(crate) fn generate_definitions(
+    params: &CppGeneratorParams,
+    schema: &CppSchema,
+    inline: bool,
+) -> String {
+    let inline = if inline { "inline " } else { "" };
 
     // Generate full implementations for sequence writers.
     let sequence_writers = schema
         .sequences
         .iter()
-        .map(impl_sequence_writer)
+        .map(|s| impl_sequence_writer(s, inline))
         .join("\n\n");
 
     let sequence_readers = schema
         .sequences
         .iter()
-        .map(impl_sequence_reader)
+        .map(|s| impl_sequence_reader(s, inline, params.emit_equality))
         .join("\n\n");
 
-    // Generate the full source file.
-    formatdoc! {
-        r#"
-        #include "{header_name}"
+    // Generate name conversions for enums.
+    let enum_names = if params.emit_enum_names {
+        impl_enum_names(schema, inline)
+    } else {
+        String::new()
+    };
 
-        namespace {namespace} {{
+    // Generate stream operators for enums and readers.
+    let printers = if params.emit_ostream {
+        impl_printers(schema, inline)
+    } else {
+        String::new()
+    };
 
+    formatdoc! {
+        r#"
         {sequence_writers}
 
         {sequence_readers}
-        
-        }} // namespace {namespace}"#
+
+        {enum_names}
+
+        {printers}"#
     }
     .replace("\n\n\n", "\n")
 }
@@ -68,7 +142,7 @@ pub(crate) fn generate_source(params: &CppGeneratorParams, schema: &CppSchema) -
 //                                                                                                //
 
 /// Generates the C++ code for implementing a sequence writer.
-fn impl_sequence_writer(sequence: &CppSequence) -> String {
+fn impl_sequence_writer(sequence: &CppSequence, inline: &str) -> String {
     // The full name of the sequence writer class, in the form "SequenceWriter".
     let class_name = sequence.to_writer_string();
 
@@ -92,33 +166,32 @@ fn impl_sequence_writer(sequence: &CppSequence) -> String {
     // Get the size of the sequence's static data.
     let static_size = sequence.size;
 
-    // Generate code that writes fields to the buffer.
+    // Generate code that writes fields to the buffer. Nested sequences are written behind an
+    // offset; all other fields are written in place. Fixed-size arrays of enums are written as
+    // their underlying type.
     let write_fields = sequence
         .fields
         .iter()
-        .enumerate()
-        .map(|(i, f)| {
-            if i < sequence.fields.len() - 1 {
-                // Not last field, increment dest.
-                formatdoc! {r"
-                    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, {cast});
-                    if (dyn_cursor == nullptr) return nullptr;
-                    dest += simplebuffers::get_static_size({cast});",
-                    cast = f.cast()
-                }
-            } else {
-                // Last field, don't need to increment dest.
-                formatdoc! {r"
-                    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, {cast});
-                    if (dyn_cursor == nullptr) return nullptr;",
-                    cast = f.cast()
-                }
+        .map(|f| {
+            let write_fn = match &f.ty {
+                CppType::Sequence(_) => "write_sequence_field".to_string(),
+                CppType::FixedArray(t, _) => match t.as_ref() {
+                    CppType::Enum(_, size) => format!("write_fixed_array<{}>", size.to_type()),
+                    _ => "write_field".to_string(),
+                },
+                _ => "write_field".to_string(),
+            };
+            formatdoc! {r"
+                dyn_cursor = simplebuffers::{write_fn}(dest + {pos}, dest_end, dyn_cursor, {cast});
+                if (dyn_cursor == nullptr) return nullptr;",
+                pos = f.pos,
+                cast = f.cast()
             }
         })
         .join("\n");
 
     // Generate all implementation code for oneof fields in this sequence.
-    let oneofs = impl_oneof_writers(sequence);
+    let oneofs = impl_oneof_writers(sequence, inline);
 
     // Generate sequence code.
     // TODO: Find out if we should be comparing to `static_size` or `static_size - 1`.
@@ -126,12 +199,12 @@ fn impl_sequence_writer(sequence: &CppSequence) -> String {
         r"
         {section_comment}
 
-        {class_name}::{class_name}({param_list}):
+        {inline}{class_name}::{class_name}({param_list}):
             {init_list} {{}}
 
-        uint16_t {class_name}::static_size() const {{ return {static_size}; }}
+        {inline}uint16_t {class_name}::static_size() const noexcept {{ return {static_size}; }}
         
-        uint8_t* {class_name}::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {{
+        {inline}uint8_t* {class_name}::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {{
             if (dest_end - dest < {static_size}) return nullptr;
             {write_fields}
             return dyn_cursor;
@@ -149,7 +222,7 @@ fn impl_sequence_writer(sequence: &CppSequence) -> String {
 /// # Returns
 ///
 /// A string with code for all of the sequence's oneof fields.
-fn impl_oneof_writers(sequence: &CppSequence) -> String {
+fn impl_oneof_writers(sequence: &CppSequence, inline: &str) -> String {
     enum Visitor<'a> {
         Visit(&'a CppOneOf),
         PopName,
@@ -165,7 +238,7 @@ fn impl_oneof_writers(sequence: &CppSequence) -> String {
                 name_stack.push(oneof.to_writer_string());
                 let full_name = name_stack.join("::");
                 generated += &format!("{}\n\n", section_comment(&full_name));
-                generated += &format!("{}\n\n", visit_oneof_writer(oneof, &name_stack));
+                generated += &format!("{}\n\n", visit_oneof_writer(oneof, &name_stack, inline));
                 visit_stack.push(Visitor::PopName);
                 for sub_oneof in oneof.oneofs().rev() {
                     visit_stack.push(Visitor::Visit(sub_oneof));
@@ -181,7 +254,7 @@ fn impl_oneof_writers(sequence: &CppSequence) -> String {
     generated
 }
 
-fn visit_oneof_writer(oneof: &CppOneOf, name_stack: &[String]) -> String {
+fn visit_oneof_writer(oneof: &CppOneOf, name_stack: &[String], inline: &str) -> String {
     // The full name of the oneof writer class, in the form "namespace::SequenceWriter".
     let class_name = name_stack
         .last()
@@ -193,7 +266,7 @@ fn visit_oneof_writer(oneof: &CppOneOf, name_stack: &[String]) -> String {
         .iter()
         .map(|f| {
             formatdoc! {"
-                {full_class_name} {full_class_name}::{constructor}({field_type}* val) {{
+                {inline}{full_class_name} {full_class_name}::{constructor}({field_type}* val) {{
                     Value v;
                     v.{name} = val;
                     return {class_name}(Tag::{tag}, v);
@@ -224,7 +297,7 @@ fn visit_oneof_writer(oneof: &CppOneOf, name_stack: &[String]) -> String {
     formatdoc! {r"
         {public_constructors}
         
-        uint8_t* {full_class_name}::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {{
+        {inline}uint8_t* {full_class_name}::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {{
             switch (tag_) {{
                 {switch_cases}
                 default:
@@ -232,7 +305,7 @@ fn visit_oneof_writer(oneof: &CppOneOf, name_stack: &[String]) -> String {
             }}
         }}
         
-        {full_class_name}::{class_name}(Tag tag, Value value) : tag_(tag), value_(value) {{}}",
+        {inline}{full_class_name}::{class_name}(Tag tag, Value value) : tag_(tag), value_(value) {{}}",
         public_constructors = public_constructors,
         switch_cases = indent_by(8, switch_cases),
     }
@@ -260,8 +333,30 @@ fn section_comment(label: &str) -> String {
 // ================================= Generate Reader Components ================================= //
 //                                                                                                //
 
-/// Generates the C++ code for implementing a sequence reader.
-fn impl_sequence_reader(sequence: &CppSequence) -> String {
+/// Generates the template arguments of a `ListReader` with elements of the given type. Oneof
+/// readers are nested in the class of the reader that defines them, so they are qualified with its
+/// name, and enums are read as their underlying type. Elements that are lists themselves are handled
+/// recursively.
+///
+/// # Arguments
+///
+/// * `element` - The type of the list's elements.
+/// * `namespace` - The name of the reader class that defines the list's field.
+fn list_reader_args(element: &CppType, namespace: &str) -> String {
+    match element {
+        CppType::OneOf(o) => format!("{namespace}::{}", o.to_reader_string()),
+        CppType::Enum(_, size) => format!("{}, {}", element.to_reader_string(), size.to_type()),
+        CppType::Array(t) => format!(
+            "simplebuffers::ListReader<{}>",
+            list_reader_args(t, namespace)
+        ),
+        _ => element.to_reader_string(),
+    }
+}
+
+/// Generates the C++ code for implementing a sequence reader. If `emit_equality` is set, equality
+/// operators are implemented for the reader and its oneofs.
+fn impl_sequence_reader(sequence: &CppSequence, inline: &str, emit_equality: bool) -> String {
     // The full name of the sequence reader class, in the form "SequenceReader".
     let class_name = sequence.to_reader_string();
 
@@ -275,11 +370,43 @@ fn impl_sequence_reader(sequence: &CppSequence) -> String {
     let field_accessors = sequence
         .fields
         .iter()
-        .map(|f| impl_sequence_field_reader(sequence.to_reader_string().as_str(), f))
+        .map(|f| impl_sequence_field_reader(sequence.to_reader_string().as_str(), f, inline))
         .join("\n");
 
+    // Generate equality operators, which compare every field.
+    let equality = if emit_equality {
+        let comparisons = if sequence.fields.is_empty() {
+            "true".to_string()
+        } else {
+            sequence
+                .fields
+                .iter()
+                .map(|f| {
+                    format!(
+                        "simplebuffers::values_equal(this->{name}(), other.{name}())",
+                        name = f.name
+                    )
+                })
+                .join(" &&\n           ")
+        };
+        formatdoc! {
+            r"
+            {inline}bool {class_name}::operator==(const {class_name}& other) const noexcept {{
+                return {comparisons};
+            }}
+
+            {inline}bool {class_name}::operator!=(const {class_name}& other) const noexcept {{
+                return !(*this == other);
+            }}
+
+            "
+        }
+    } else {
+        String::new()
+    };
+
     // Generate all implementation code for oneof fields in this sequence.
-    let oneofs = impl_oneof_readers(sequence);
+    let oneofs = impl_oneof_readers(sequence, inline, emit_equality);
 
     // Generate sequence code.
     // TODO: Find out if we should be comparing to `static_size` or `static_size - 1`.
@@ -287,17 +414,17 @@ fn impl_sequence_reader(sequence: &CppSequence) -> String {
         r"
         {section_comment}
 
-        {class_name}::{class_name}(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + {static_size} * idx) {{}}
+        {inline}{class_name}::{class_name}(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + {static_size} * idx) {{}}
 
-        uint16_t {class_name}::static_size() const {{ return {static_size}; }}
+        {inline}uint16_t {class_name}::static_size() const noexcept {{ return {static_size}; }}
         
         {field_accessors}
         
-        {oneofs}",
+        {equality}{oneofs}",
     }
 }
 
-fn impl_sequence_field_reader(namespace: &str, field: &CppSequenceField) -> String {
+fn impl_sequence_field_reader(namespace: &str, field: &CppSequenceField, inline: &str) -> String {
     // Position of the field in the sequence.
     let pos = field.pos;
 
@@ -309,11 +436,11 @@ fn impl_sequence_field_reader(namespace: &str, field: &CppSequenceField) -> Stri
 
     // Function to execute to access the data.
     match &field.ty {
-        CppType::Primitive(p) => {
+        CppType::Primitive(_) | CppType::String(_) => {
             formatdoc! {
                 r"
-                {type_name} {namespace}::{name}() const {{
-                    return simplebuffers::read_field<{p}>(data_ptr_ + {pos});
+                {inline}{type_name} {namespace}::{name}() const noexcept {{
+                    return simplebuffers::read_field<{type_name}>(data_ptr_ + {pos});
                 }}"
             }
         }
@@ -321,8 +448,9 @@ fn impl_sequence_field_reader(namespace: &str, field: &CppSequenceField) -> Stri
         CppType::Sequence(_) => {
             formatdoc! {
                 r"
-                {type_name} {namespace}::{name}() const {{
-                    return {type_name}(data_ptr_ + {pos}, 0);
+                {inline}{type_name} {namespace}::{name}() const noexcept {{
+                    const uint16_t offset = simplebuffers::read_field<uint16_t>(data_ptr_ + {pos});
+                    return {type_name}(data_ptr_ + {pos} + offset, 0);
                 }}"
             }
         }
@@ -331,37 +459,40 @@ fn impl_sequence_field_reader(namespace: &str, field: &CppSequenceField) -> Stri
             let dtype = size.to_type();
             formatdoc! {
                 r"
-                {type_name} {namespace}::{name}() const {{
+                {inline}{type_name} {namespace}::{name}() const noexcept {{
                     return static_cast<{type_name}>(simplebuffers::read_field<{dtype}>(data_ptr_ + {pos}));
                 }}"
             }
         }
 
         CppType::Array(t) => {
-            let template_type = match t.as_ref() {
-                CppType::OneOf(o) => {
-                    format!(
-                        "{namespace}::{oneof_name}",
-                        oneof_name = o.to_reader_string()
-                    )
-                }
-                CppType::Enum(_, size) => {
-                    format!(
-                        "{ret}, {read}",
-                        ret = t.to_reader_string(),
-                        read = size.to_type()
-                    )
-                }
-                _ => t.to_reader_string(),
-            };
+            let template_type = list_reader_args(t, namespace);
             formatdoc! {
                 r"
-                simplebuffers::ListReader<{template_type}> {namespace}::{name}() const {{
+                {inline}simplebuffers::ListReader<{template_type}> {namespace}::{name}() const noexcept {{
                     return simplebuffers::ListReader<{template_type}>(static_cast<const uint8_t*>(data_ptr_ + {pos}), 0);
                 }}"
             }
         }
 
+        CppType::FixedArray(_, _) => {
+            formatdoc! {
+                r"
+                {inline}{type_name} {namespace}::{name}() const noexcept {{
+                    return {type_name}(data_ptr_ + {pos});
+                }}"
+            }
+        }
+
+        CppType::Bytes | CppType::Map(_, _, _, _) => {
+            formatdoc! {
+                r"
+                {inline}{type_name} {namespace}::{name}() const noexcept {{
+                    return {type_name}(data_ptr_ + {pos}, 0);
+                }}"
+            }
+        }
+
         CppType::OneOf(o) => {
             let full_type_name = format!(
                 "{namespace}::{oneof_name}",
@@ -369,7 +500,7 @@ fn impl_sequence_field_reader(namespace: &str, field: &CppSequenceField) -> Stri
             );
             formatdoc! {
                 r"
-                {full_type_name} {namespace}::{name}() const {{
+                {inline}{full_type_name} {namespace}::{name}() const noexcept {{
                     return {type_name}(static_cast<const uint8_t*>(data_ptr_ + {pos}), 0);
                 }}"
             }
@@ -377,7 +508,7 @@ fn impl_sequence_field_reader(namespace: &str, field: &CppSequenceField) -> Stri
     }
 }
 
-fn impl_oneof_readers(sequence: &CppSequence) -> String {
+fn impl_oneof_readers(sequence: &CppSequence, inline: &str, emit_equality: bool) -> String {
     enum Visitor<'a> {
         Visit(&'a CppOneOf),
         PopName,
@@ -393,7 +524,10 @@ fn impl_oneof_readers(sequence: &CppSequence) -> String {
                 name_stack.push(oneof.to_reader_string());
                 let full_name = name_stack.join("::");
                 generated += &format!("{}\n\n", section_comment(&full_name));
-                generated += &format!("{}\n\n", visit_oneof_reader(oneof, &name_stack));
+                generated += &format!(
+                    "{}\n\n",
+                    visit_oneof_reader(oneof, &name_stack, inline, emit_equality)
+                );
                 visit_stack.push(Visitor::PopName);
                 for sub_oneof in oneof.oneofs().rev() {
                     visit_stack.push(Visitor::Visit(sub_oneof));
@@ -409,7 +543,12 @@ fn impl_oneof_readers(sequence: &CppSequence) -> String {
     generated
 }
 
-fn visit_oneof_reader(oneof: &CppOneOf, name_stack: &[String]) -> String {
+fn visit_oneof_reader(
+    oneof: &CppOneOf,
+    name_stack: &[String],
+    inline: &str,
+    emit_equality: bool,
+) -> String {
     // The full name of the oneof reader class, in the form "namespace::SequenceReader".
     let class_name = name_stack
         .last()
@@ -419,25 +558,59 @@ fn visit_oneof_reader(oneof: &CppOneOf, name_stack: &[String]) -> String {
     let fields = oneof
         .fields
         .iter()
-        .map(|f| impl_oneof_field_reader(&full_class_name, f))
+        .map(|f| impl_oneof_field_reader(&full_class_name, f, inline))
         .join("\n\n");
 
+    // Generate equality operators. Oneofs are only equal if they hold the same field, so the tags
+    // are compared before the values.
+    let equality = if emit_equality {
+        let cases = oneof
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "case Tag::{tag}:\n    return simplebuffers::values_equal(this->{name}(), other.{name}());",
+                    tag = f.tag,
+                    name = f.name
+                )
+            })
+            .join("\n");
+        let operators = formatdoc! {
+            r"
+            {inline}bool {full_class_name}::operator==(const {full_class_name}& other) const noexcept {{
+                if (tag_ != other.tag_) return false;
+                switch (tag_) {{
+                    {cases}
+                }}
+                return false;
+            }}
+
+            {inline}bool {full_class_name}::operator!=(const {full_class_name}& other) const noexcept {{
+                return !(*this == other);
+            }}",
+            cases = indent_by(8, cases)
+        };
+        format!("\n\n{operators}")
+    } else {
+        String::new()
+    };
+
     formatdoc! {r"
-        {full_class_name}::{class_name}(const uint8_t* data_ptr, size_t idx) : OneOfReader(data_ptr, idx) {{
-            const uint16_t offset = simplebuffers::read_field<uint16_t>(data_ptr + 1);
-            tag_ = static_cast<Tag>(simplebuffers::read_field<uint8_t>(data_ptr));
-            val_ptr_ = data_ptr + offset;
+        {inline}{full_class_name}::{class_name}(const uint8_t* data_ptr, size_t idx) : OneOfReader(data_ptr, idx) {{
+            const uint16_t offset = simplebuffers::read_field<uint16_t>(data_ptr_ + 1);
+            tag_ = static_cast<Tag>(simplebuffers::read_field<uint8_t>(data_ptr_));
+            val_ptr_ = data_ptr_ + offset;
         }}
         
-        {full_class_name}::Tag {full_class_name}::tag() const {{
+        {inline}{full_class_name}::Tag {full_class_name}::tag() const noexcept {{
             return tag_;
         }}
         
-        {fields}"
+        {fields}{equality}"
     }
 }
 
-fn impl_oneof_field_reader(namespace: &str, field: &CppOneOfField) -> String {
+fn impl_oneof_field_reader(namespace: &str, field: &CppOneOfField, inline: &str) -> String {
     // Name of the field.
     let name = field.name.as_str();
 
@@ -449,17 +622,18 @@ fn impl_oneof_field_reader(namespace: &str, field: &CppOneOfField) -> String {
 
     // Function to execute to access the data.
     match &field.ty {
-        CppType::Primitive(p) => {
-            let null_val = match *p {
-                "const char*" => "\"\\0\"",
-                "bool" => "false",
+        CppType::Primitive(_) | CppType::String(_) => {
+            let null_val = match &field.ty {
+                CppType::String(StringType::Char) => "\"\\0\"",
+                CppType::String(StringType::Std) => "std::string_view()",
+                CppType::Primitive("bool") => "false",
                 _ => "0",
             };
             formatdoc! {
                 r"
-                {type_name} {namespace}::{name}() const {{
+                {inline}{type_name} {namespace}::{name}() const noexcept {{
                     if (tag_ != Tag::{tag}) return {null_val};
-                    return simplebuffers::read_field<{p}>(val_ptr_);
+                    return simplebuffers::read_field<{type_name}>(val_ptr_);
                 }}"
             }
         }
@@ -467,7 +641,7 @@ fn impl_oneof_field_reader(namespace: &str, field: &CppOneOfField) -> String {
         CppType::Sequence(_) => {
             formatdoc! {
                 r"
-                {type_name} {namespace}::{name}() const {{
+                {inline}{type_name} {namespace}::{name}() const noexcept {{
                     return {type_name}(val_ptr_, 0);
                 }}"
             }
@@ -477,7 +651,7 @@ fn impl_oneof_field_reader(namespace: &str, field: &CppOneOfField) -> String {
             let dtype = size.to_type();
             formatdoc! {
                 r"
-                {type_name} {namespace}::{name}() const {{
+                {inline}{type_name} {namespace}::{name}() const noexcept {{
                     if (tag_ != Tag::{tag}) return static_cast<{type_name}>(0);
                     return static_cast<{type_name}>(simplebuffers::read_field<{dtype}>(val_ptr_));
                 }}"
@@ -485,27 +659,24 @@ fn impl_oneof_field_reader(namespace: &str, field: &CppOneOfField) -> String {
         }
 
         CppType::Array(t) => {
-            let template_type = match t.as_ref() {
-                CppType::OneOf(o) => {
-                    format!(
-                        "{namespace}::{oneof_name}",
-                        oneof_name = o.to_reader_string()
-                    )
-                }
-                CppType::Enum(_, size) => {
-                    format!(
-                        "{ret}, {read}",
-                        ret = t.to_reader_string(),
-                        read = size.to_type()
-                    )
-                }
-                _ => t.to_reader_string(),
-            };
+            let template_type = list_reader_args(t, namespace);
+            formatdoc! {
+                r"
+                {inline}simplebuffers::ListReader<{template_type}> {namespace}::{name}() const noexcept {{
+                    if (tag_ != Tag::{tag}) return simplebuffers::ListReader<{template_type}>();
+                    return simplebuffers::ListReader<{template_type}>(static_cast<const uint8_t*>(val_ptr_), 0);
+                }}"
+            }
+        }
+
+        CppType::FixedArray(_, _) => unreachable!("fixed-size arrays cannot be oneof fields"),
+
+        CppType::Bytes | CppType::Map(_, _, _, _) => {
             formatdoc! {
                 r"
-                simplebuffers::ListReader<{template_type}> {namespace}::{name}() const {{
-                    if (tag_ != Tag::{tag}) return simplebuffers::ListReader<{template_type}>(nullptr, 0);
-                    return simplebuffers::ListReader<{template_type}>(static_cast<const uint8_t*>(val_ptr_, 0);
+                {inline}{type_name} {namespace}::{name}() const noexcept {{
+                    if (tag_ != Tag::{tag}) return {type_name}();
+                    return {type_name}(val_ptr_, 0);
                 }}"
             }
         }
@@ -517,7 +688,7 @@ fn impl_oneof_field_reader(namespace: &str, field: &CppOneOfField) -> String {
             );
             formatdoc! {
                 r"
-                {full_type_name} {namespace}::{name}() const {{
+                {inline}{full_type_name} {namespace}::{name}() const noexcept {{
                     if (tag_ != Tag::{tag}) return {type_name}(nullptr, 0);
                     return {type_name}(val_ptr_, 0);
                 }}"
@@ -525,3 +696,166 @@ fn impl_oneof_field_reader(namespace: &str, field: &CppOneOfField) -> String {
         }
     }
 }
+
+//                                                                                                //
+// =============================== Generate Enum Name Components ================================ //
+//                                                                                                //
+
+/// Generates the C++ code for converting every enum to and from the names of its variants.
+fn impl_enum_names(schema: &CppSchema, inline: &str) -> String {
+    if schema.enums.is_empty() {
+        return String::new();
+    }
+    format!(
+        "{}\n\n{}",
+        section_comment("Enum names"),
+        schema
+            .enums
+            .iter()
+            .map(|e| impl_enum_name(e, inline))
+            .join("\n\n")
+    )
+}
+
+/// Generates the C++ code for converting an enum to and from the names of its variants. Values
+/// that do not match a variant have no name, so `to_string` returns `nullptr` for them, and names
+/// that do not match a variant make `from_string` return `std::nullopt`.
+fn impl_enum_name(data: &CppEnum, inline: &str) -> String {
+    let name = &data.name;
+    let cases = data
+        .variants
+        .iter()
+        .map(|(variant, _, _)| format!("case {name}::{variant}:\n    return \"{variant}\";"))
+        .join("\n");
+    let comparisons = data
+        .variants
+        .iter()
+        .map(|(variant, _, _)| format!("if (name == \"{variant}\") return {name}::{variant};"))
+        .join("\n");
+    formatdoc! {
+        r"
+        {inline}const char* to_string({name} value) noexcept {{
+            switch (value) {{
+                {cases}
+            }}
+            return nullptr;
+        }}
+
+        template <>
+        {inline}std::optional<{name}> from_string<{name}>(std::string_view name) noexcept {{
+            {comparisons}
+            return std::nullopt;
+        }}",
+        cases = indent_by(8, cases),
+        comparisons = indent_by(4, comparisons)
+    }
+}
+
+//                                                                                                //
+// ================================ Generate Printer Components ================================= //
+//                                                                                                //
+
+/// Generates the C++ code for implementing stream operators for every enum, sequence reader, and
+/// oneof reader.
+fn impl_printers(schema: &CppSchema, inline: &str) -> String {
+    let enums = schema.enums.iter().map(|e| impl_enum_printer(e, inline));
+    let readers = schema.sequences.iter().flat_map(|seq| {
+        seq.nested_oneof_readers()
+            .into_iter()
+            .map(|(name, oneof)| impl_oneof_printer(&name, oneof, inline))
+            .chain(std::iter::once(impl_sequence_printer(seq, inline)))
+            .collect_vec()
+    });
+    format!(
+        "{}\n\n{}",
+        section_comment("Stream operators"),
+        enums.chain(readers).join("\n\n")
+    )
+}
+
+/// Generates the C++ code for printing an enum. Variants are printed as quoted names, and unknown
+/// values are printed as numbers.
+fn impl_enum_printer(data: &CppEnum, inline: &str) -> String {
+    let name = &data.name;
+    let dtype = data.size_to_type();
+    let cases = data
+        .variants
+        .iter()
+        .map(|(variant, _, _)| {
+            format!("case {name}::{variant}:\n    return os << \"\\\"{variant}\\\"\";")
+        })
+        .join("\n");
+    formatdoc! {
+        r"
+        {inline}std::ostream& operator<<(std::ostream& os, {name} value) {{
+            switch (value) {{
+                {cases}
+            }}
+            return os << +static_cast<{dtype}>(value);
+        }}",
+        cases = indent_by(8, cases)
+    }
+}
+
+/// Generates the C++ code for printing a sequence reader as an object containing every field.
+fn impl_sequence_printer(sequence: &CppSequence, inline: &str) -> String {
+    let class_name = sequence.to_reader_string();
+    let fields = sequence
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            formatdoc! {
+                r#"
+                os << "{separator}\"{name}\": ";
+                simplebuffers::print_value(os, reader.{name}());"#,
+                separator = if i == 0 { "{" } else { ", " },
+                name = f.name
+            }
+        })
+        .join("\n");
+    let end = if sequence.fields.is_empty() {
+        "{}"
+    } else {
+        "}"
+    };
+    formatdoc! {
+        r#"
+        {inline}std::ostream& operator<<(std::ostream& os, const {class_name}& reader) {{
+            {fields}
+            return os << "{end}";
+        }}"#,
+        fields = indent_by(4, fields)
+    }
+}
+
+/// Generates the C++ code for printing a oneof reader as an object containing only the field that
+/// the oneof holds.
+fn impl_oneof_printer(full_class_name: &str, oneof: &CppOneOf, inline: &str) -> String {
+    let cases = oneof
+        .fields
+        .iter()
+        .map(|f| {
+            formatdoc! {
+                r#"
+                case {full_class_name}::Tag::{tag}:
+                    os << "\"{name}\": ";
+                    simplebuffers::print_value(os, reader.{name}());
+                    break;"#,
+                tag = f.tag,
+                name = f.name
+            }
+        })
+        .join("\n");
+    formatdoc! {
+        r#"
+        {inline}std::ostream& operator<<(std::ostream& os, const {full_class_name}& reader) {{
+            os << "{{";
+            switch (reader.tag()) {{
+                {cases}
+            }}
+            return os << "}}";
+        }}"#,
+        cases = indent_by(8, cases)
+    }
+}
@@ -0,0 +1,11 @@
+//! Golden tests for the C++ generator. See `simplebuffers-golden` for how they work.
+
+use simplebuffers_cpp::CPPCodeGenerator;
+use simplebuffers_golden::{golden_tests, Layout};
+
+golden_tests! {
+    generator: CPPCodeGenerator,
+    args: ["cpp"],
+    layout: Layout::Flat,
+    cases: [primitives, enums, nested, oneofs, arrays, oneof_arrays],
+}
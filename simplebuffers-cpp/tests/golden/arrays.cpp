@@ -0,0 +1,93 @@
+#include "arrays.hpp"
+
+namespace simplebuffers_arrays {
+
+/*
+ * ArraysWriter
+ */
+
+ArraysWriter::ArraysWriter(simplebuffers::ListWriter<uint16_t> numbers, simplebuffers::ListWriter<const char*> words, simplebuffers::ListWriter<Color> colors, simplebuffers::ListWriter<PointWriter> points, simplebuffers::ListWriter<simplebuffers::ListWriter<int32_t>> matrix, simplebuffers::FixedArrayWriter<float, 3> fixed):
+    numbers(numbers), words(words), colors(colors), points(points), matrix(matrix), fixed(fixed) {}
+
+uint16_t ArraysWriter::static_size() const noexcept { return 32; }
+
+uint8_t* ArraysWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    if (dest_end - dest < 32) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, simplebuffers::priv::ListWriterImpl<uint16_t>(numbers.val, numbers.len));
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 4, dest_end, dyn_cursor, simplebuffers::priv::ListWriterImpl<const char*>(words.val, words.len));
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 8, dest_end, dyn_cursor, simplebuffers::priv::ListWriterImpl<Color>(colors.val, colors.len));
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 12, dest_end, dyn_cursor, simplebuffers::priv::ListWriterImpl<PointWriter>(points.val, points.len));
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 16, dest_end, dyn_cursor, simplebuffers::priv::ListWriterImpl<simplebuffers::ListWriter<int32_t>>(matrix.val, matrix.len));
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 20, dest_end, dyn_cursor, fixed);
+    if (dyn_cursor == nullptr) return nullptr;
+    return dyn_cursor;
+}
+
+/*
+ * PointWriter
+ */
+
+PointWriter::PointWriter(int16_t x, int16_t y):
+    x(x), y(y) {}
+
+uint16_t PointWriter::static_size() const noexcept { return 4; }
+
+uint8_t* PointWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    if (dest_end - dest < 4) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, x);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 2, dest_end, dyn_cursor, y);
+    if (dyn_cursor == nullptr) return nullptr;
+    return dyn_cursor;
+}
+
+/*
+ * ArraysReader
+ */
+
+ArraysReader::ArraysReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 32 * idx) {}
+
+uint16_t ArraysReader::static_size() const noexcept { return 32; }
+
+simplebuffers::ListReader<uint16_t> ArraysReader::numbers() const noexcept {
+    return simplebuffers::ListReader<uint16_t>(static_cast<const uint8_t*>(data_ptr_ + 0), 0);
+}
+simplebuffers::ListReader<const char*> ArraysReader::words() const noexcept {
+    return simplebuffers::ListReader<const char*>(static_cast<const uint8_t*>(data_ptr_ + 4), 0);
+}
+simplebuffers::ListReader<Color, uint8_t> ArraysReader::colors() const noexcept {
+    return simplebuffers::ListReader<Color, uint8_t>(static_cast<const uint8_t*>(data_ptr_ + 8), 0);
+}
+simplebuffers::ListReader<PointReader> ArraysReader::points() const noexcept {
+    return simplebuffers::ListReader<PointReader>(static_cast<const uint8_t*>(data_ptr_ + 12), 0);
+}
+simplebuffers::ListReader<simplebuffers::ListReader<int32_t>> ArraysReader::matrix() const noexcept {
+    return simplebuffers::ListReader<simplebuffers::ListReader<int32_t>>(static_cast<const uint8_t*>(data_ptr_ + 16), 0);
+}
+simplebuffers::FixedArrayReader<float, 3> ArraysReader::fixed() const noexcept {
+    return simplebuffers::FixedArrayReader<float, 3>(data_ptr_ + 20);
+}
+
+/*
+ * PointReader
+ */
+
+PointReader::PointReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 4 * idx) {}
+
+uint16_t PointReader::static_size() const noexcept { return 4; }
+
+int16_t PointReader::x() const noexcept {
+    return simplebuffers::read_field<int16_t>(data_ptr_ + 0);
+}
+int16_t PointReader::y() const noexcept {
+    return simplebuffers::read_field<int16_t>(data_ptr_ + 2);
+}
+
+
+
+} // namespace simplebuffers_arrays
\ No newline at end of file
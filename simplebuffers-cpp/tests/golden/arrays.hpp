@@ -0,0 +1,92 @@
+#ifndef SIMPLEBUFFERS_GENERATED__ARRAYS_HPP
+#define SIMPLEBUFFERS_GENERATED__ARRAYS_HPP
+
+#include "simplebuffers.hpp"
+
+#if SIMPLEBUFFERS_BIG_ENDIAN_WIRE
+#error "Code generated for little-endian data cannot be used with code generated with --endianness=big"
+#endif
+
+namespace simplebuffers_arrays {
+
+enum class Color : uint_fast8_t {
+    RED = 0,
+    GREEN = 1
+};
+
+inline uint16_t get_static_size(Color) { return 1; }
+inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor, Color val) {
+    return simplebuffers::write_field(dest, dest_end, dyn_cursor, static_cast<uint8_t>(val));
+}
+
+class ArraysWriter;
+class PointWriter;
+
+class ArraysWriter : public simplebuffers::SimpleBufferWriter {
+   public:
+    ArraysWriter(simplebuffers::ListWriter<uint16_t> numbers, simplebuffers::ListWriter<const char*> words, simplebuffers::ListWriter<Color> colors, simplebuffers::ListWriter<PointWriter> points, simplebuffers::ListWriter<simplebuffers::ListWriter<int32_t>> matrix, simplebuffers::FixedArrayWriter<float, 3> fixed);
+    ArraysWriter(const ArraysWriter&) = default;
+    ArraysWriter(ArraysWriter&&) noexcept = default;
+    ArraysWriter& operator=(const ArraysWriter&) = default;
+    ArraysWriter& operator=(ArraysWriter&&) noexcept = default;
+
+    simplebuffers::ListWriter<uint16_t> numbers;
+    simplebuffers::ListWriter<const char*> words;
+    simplebuffers::ListWriter<Color> colors;
+    simplebuffers::ListWriter<PointWriter> points;
+    simplebuffers::ListWriter<simplebuffers::ListWriter<int32_t>> matrix;
+    simplebuffers::FixedArrayWriter<float, 3> fixed;
+
+    uint16_t static_size() const noexcept override;
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+};
+
+class PointWriter : public simplebuffers::SimpleBufferWriter {
+   public:
+    PointWriter(int16_t x, int16_t y);
+    PointWriter(const PointWriter&) = default;
+    PointWriter(PointWriter&&) noexcept = default;
+    PointWriter& operator=(const PointWriter&) = default;
+    PointWriter& operator=(PointWriter&&) noexcept = default;
+
+    int16_t x;
+    int16_t y;
+
+    uint16_t static_size() const noexcept override;
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+};
+
+class ArraysReader;
+class PointReader;
+
+class ArraysReader : public simplebuffers::SimpleBufferReader {
+    public:
+    ArraysReader(const uint8_t* data_ptr, size_t idx = 0);
+    ArraysReader(const ArraysReader&) = default;
+    ArraysReader(ArraysReader&&) noexcept = default;
+    ArraysReader& operator=(const ArraysReader&) = default;
+    ArraysReader& operator=(ArraysReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    simplebuffers::ListReader<uint16_t> numbers() const noexcept;
+    simplebuffers::ListReader<const char*> words() const noexcept;
+    simplebuffers::ListReader<Color, uint8_t> colors() const noexcept;
+    simplebuffers::ListReader<PointReader> points() const noexcept;
+    simplebuffers::ListReader<simplebuffers::ListReader<int32_t>> matrix() const noexcept;
+    simplebuffers::FixedArrayReader<float, 3> fixed() const noexcept;
+};
+
+class PointReader : public simplebuffers::SimpleBufferReader {
+    public:
+    PointReader(const uint8_t* data_ptr, size_t idx = 0);
+    PointReader(const PointReader&) = default;
+    PointReader(PointReader&&) noexcept = default;
+    PointReader& operator=(const PointReader&) = default;
+    PointReader& operator=(PointReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    int16_t x() const noexcept;
+    int16_t y() const noexcept;
+};
+
+} // namespace simplebuffers_arrays
+
+#endif
\ No newline at end of file
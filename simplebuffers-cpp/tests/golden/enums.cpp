@@ -0,0 +1,45 @@
+#include "enums.hpp"
+
+namespace simplebuffers_enums {
+
+/*
+ * EnumsWriter
+ */
+
+EnumsWriter::EnumsWriter(Small small, Signed_ signed_, Wide wide):
+    small(small), signed_(signed_), wide(wide) {}
+
+uint16_t EnumsWriter::static_size() const noexcept { return 6; }
+
+uint8_t* EnumsWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    if (dest_end - dest < 6) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, static_cast<uint8_t>(small));
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 1, dest_end, dyn_cursor, static_cast<uint8_t>(signed_));
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 2, dest_end, dyn_cursor, static_cast<uint32_t>(wide));
+    if (dyn_cursor == nullptr) return nullptr;
+    return dyn_cursor;
+}
+
+/*
+ * EnumsReader
+ */
+
+EnumsReader::EnumsReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 6 * idx) {}
+
+uint16_t EnumsReader::static_size() const noexcept { return 6; }
+
+Small EnumsReader::small() const noexcept {
+    return static_cast<Small>(simplebuffers::read_field<uint8_t>(data_ptr_ + 0));
+}
+Signed_ EnumsReader::signed_() const noexcept {
+    return static_cast<Signed_>(simplebuffers::read_field<uint8_t>(data_ptr_ + 1));
+}
+Wide EnumsReader::wide() const noexcept {
+    return static_cast<Wide>(simplebuffers::read_field<uint32_t>(data_ptr_ + 2));
+}
+
+
+
+} // namespace simplebuffers_enums
\ No newline at end of file
@@ -0,0 +1,77 @@
+#ifndef SIMPLEBUFFERS_GENERATED__ENUMS_HPP
+#define SIMPLEBUFFERS_GENERATED__ENUMS_HPP
+
+#include "simplebuffers.hpp"
+
+#if SIMPLEBUFFERS_BIG_ENDIAN_WIRE
+#error "Code generated for little-endian data cannot be used with code generated with --endianness=big"
+#endif
+
+namespace simplebuffers_enums {
+
+enum class Small : uint_fast8_t {
+    A = 0,
+    B = 1
+};
+
+inline uint16_t get_static_size(Small) { return 1; }
+inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor, Small val) {
+    return simplebuffers::write_field(dest, dest_end, dyn_cursor, static_cast<uint8_t>(val));
+}
+
+enum class Signed_ : int8_t {
+    LOW = -1,
+    HIGH = 1
+};
+
+inline uint16_t get_static_size(Signed_) { return 1; }
+inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor, Signed_ val) {
+    return simplebuffers::write_field(dest, dest_end, dyn_cursor, static_cast<uint8_t>(val));
+}
+
+enum class Wide : uint_fast32_t {
+    FIRST = 0,
+    LAST = 100000
+};
+
+inline uint16_t get_static_size(Wide) { return 4; }
+inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor, Wide val) {
+    return simplebuffers::write_field(dest, dest_end, dyn_cursor, static_cast<uint32_t>(val));
+}
+
+class EnumsWriter;
+
+class EnumsWriter : public simplebuffers::SimpleBufferWriter {
+   public:
+    EnumsWriter(Small small, Signed_ signed_, Wide wide);
+    EnumsWriter(const EnumsWriter&) = default;
+    EnumsWriter(EnumsWriter&&) noexcept = default;
+    EnumsWriter& operator=(const EnumsWriter&) = default;
+    EnumsWriter& operator=(EnumsWriter&&) noexcept = default;
+
+    Small small;
+    Signed_ signed_;
+    Wide wide;
+
+    uint16_t static_size() const noexcept override;
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+};
+
+class EnumsReader;
+
+class EnumsReader : public simplebuffers::SimpleBufferReader {
+    public:
+    EnumsReader(const uint8_t* data_ptr, size_t idx = 0);
+    EnumsReader(const EnumsReader&) = default;
+    EnumsReader(EnumsReader&&) noexcept = default;
+    EnumsReader& operator=(const EnumsReader&) = default;
+    EnumsReader& operator=(EnumsReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    Small small() const noexcept;
+    Signed_ signed_() const noexcept;
+    Wide wide() const noexcept;
+};
+
+} // namespace simplebuffers_enums
+
+#endif
\ No newline at end of file
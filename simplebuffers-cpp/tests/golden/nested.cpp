@@ -0,0 +1,108 @@
+#include "nested.hpp"
+
+namespace simplebuffers_nested {
+
+/*
+ * PointWriter
+ */
+
+PointWriter::PointWriter(int16_t x, int16_t y):
+    x(x), y(y) {}
+
+uint16_t PointWriter::static_size() const noexcept { return 4; }
+
+uint8_t* PointWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    if (dest_end - dest < 4) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, x);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 2, dest_end, dyn_cursor, y);
+    if (dyn_cursor == nullptr) return nullptr;
+    return dyn_cursor;
+}
+
+/*
+ * InnerWriter
+ */
+
+InnerWriter::InnerWriter(PointWriter point, const char* label):
+    point(point), label(label) {}
+
+uint16_t InnerWriter::static_size() const noexcept { return 4; }
+
+uint8_t* InnerWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    if (dest_end - dest < 4) return nullptr;
+    dyn_cursor = simplebuffers::write_sequence_field(dest + 0, dest_end, dyn_cursor, point);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 2, dest_end, dyn_cursor, label);
+    if (dyn_cursor == nullptr) return nullptr;
+    return dyn_cursor;
+}
+
+/*
+ * OuterWriter
+ */
+
+OuterWriter::OuterWriter(uint32_t id, InnerWriter inner):
+    id(id), inner(inner) {}
+
+uint16_t OuterWriter::static_size() const noexcept { return 6; }
+
+uint8_t* OuterWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    if (dest_end - dest < 6) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, id);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_sequence_field(dest + 4, dest_end, dyn_cursor, inner);
+    if (dyn_cursor == nullptr) return nullptr;
+    return dyn_cursor;
+}
+
+/*
+ * PointReader
+ */
+
+PointReader::PointReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 4 * idx) {}
+
+uint16_t PointReader::static_size() const noexcept { return 4; }
+
+int16_t PointReader::x() const noexcept {
+    return simplebuffers::read_field<int16_t>(data_ptr_ + 0);
+}
+int16_t PointReader::y() const noexcept {
+    return simplebuffers::read_field<int16_t>(data_ptr_ + 2);
+}
+
+/*
+ * InnerReader
+ */
+
+InnerReader::InnerReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 4 * idx) {}
+
+uint16_t InnerReader::static_size() const noexcept { return 4; }
+
+PointReader InnerReader::point() const noexcept {
+    const uint16_t offset = simplebuffers::read_field<uint16_t>(data_ptr_ + 0);
+    return PointReader(data_ptr_ + 0 + offset, 0);
+}
+const char* InnerReader::label() const noexcept {
+    return simplebuffers::read_field<const char*>(data_ptr_ + 2);
+}
+
+/*
+ * OuterReader
+ */
+
+OuterReader::OuterReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 6 * idx) {}
+
+uint16_t OuterReader::static_size() const noexcept { return 6; }
+
+uint32_t OuterReader::id() const noexcept {
+    return simplebuffers::read_field<uint32_t>(data_ptr_ + 0);
+}
+InnerReader OuterReader::inner() const noexcept {
+    const uint16_t offset = simplebuffers::read_field<uint16_t>(data_ptr_ + 4);
+    return InnerReader(data_ptr_ + 4 + offset, 0);
+}
+
+
+
+} // namespace simplebuffers_nested
\ No newline at end of file
@@ -0,0 +1,103 @@
+#ifndef SIMPLEBUFFERS_GENERATED__NESTED_HPP
+#define SIMPLEBUFFERS_GENERATED__NESTED_HPP
+
+#include "simplebuffers.hpp"
+
+#if SIMPLEBUFFERS_BIG_ENDIAN_WIRE
+#error "Code generated for little-endian data cannot be used with code generated with --endianness=big"
+#endif
+
+namespace simplebuffers_nested {
+
+class PointWriter;
+class InnerWriter;
+class OuterWriter;
+
+class PointWriter : public simplebuffers::SimpleBufferWriter {
+   public:
+    PointWriter(int16_t x, int16_t y);
+    PointWriter(const PointWriter&) = default;
+    PointWriter(PointWriter&&) noexcept = default;
+    PointWriter& operator=(const PointWriter&) = default;
+    PointWriter& operator=(PointWriter&&) noexcept = default;
+
+    int16_t x;
+    int16_t y;
+
+    uint16_t static_size() const noexcept override;
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+};
+
+class InnerWriter : public simplebuffers::SimpleBufferWriter {
+   public:
+    InnerWriter(PointWriter point, const char* label);
+    InnerWriter(const InnerWriter&) = default;
+    InnerWriter(InnerWriter&&) noexcept = default;
+    InnerWriter& operator=(const InnerWriter&) = default;
+    InnerWriter& operator=(InnerWriter&&) noexcept = default;
+
+    PointWriter point;
+    const char* label;
+
+    uint16_t static_size() const noexcept override;
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+};
+
+class OuterWriter : public simplebuffers::SimpleBufferWriter {
+   public:
+    OuterWriter(uint32_t id, InnerWriter inner);
+    OuterWriter(const OuterWriter&) = default;
+    OuterWriter(OuterWriter&&) noexcept = default;
+    OuterWriter& operator=(const OuterWriter&) = default;
+    OuterWriter& operator=(OuterWriter&&) noexcept = default;
+
+    uint32_t id;
+    InnerWriter inner;
+
+    uint16_t static_size() const noexcept override;
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+};
+
+class PointReader;
+class InnerReader;
+class OuterReader;
+
+class PointReader : public simplebuffers::SimpleBufferReader {
+    public:
+    PointReader(const uint8_t* data_ptr, size_t idx = 0);
+    PointReader(const PointReader&) = default;
+    PointReader(PointReader&&) noexcept = default;
+    PointReader& operator=(const PointReader&) = default;
+    PointReader& operator=(PointReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    int16_t x() const noexcept;
+    int16_t y() const noexcept;
+};
+
+class InnerReader : public simplebuffers::SimpleBufferReader {
+    public:
+    InnerReader(const uint8_t* data_ptr, size_t idx = 0);
+    InnerReader(const InnerReader&) = default;
+    InnerReader(InnerReader&&) noexcept = default;
+    InnerReader& operator=(const InnerReader&) = default;
+    InnerReader& operator=(InnerReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    PointReader point() const noexcept;
+    const char* label() const noexcept;
+};
+
+class OuterReader : public simplebuffers::SimpleBufferReader {
+    public:
+    OuterReader(const uint8_t* data_ptr, size_t idx = 0);
+    OuterReader(const OuterReader&) = default;
+    OuterReader(OuterReader&&) noexcept = default;
+    OuterReader& operator=(const OuterReader&) = default;
+    OuterReader& operator=(OuterReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    uint32_t id() const noexcept;
+    InnerReader inner() const noexcept;
+};
+
+} // namespace simplebuffers_nested
+
+#endif
\ No newline at end of file
@@ -0,0 +1,199 @@
+#include "oneof_arrays.hpp"
+
+namespace simplebuffers_oneof_arrays {
+
+/*
+ * BatchWriter
+ */
+
+BatchWriter::BatchWriter(simplebuffers::ListWriter<EventsWriter> events, NestedWriter nested):
+    events(events), nested(nested) {}
+
+uint16_t BatchWriter::static_size() const noexcept { return 7; }
+
+uint8_t* BatchWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    if (dest_end - dest < 7) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, simplebuffers::priv::ListWriterImpl<EventsWriter>(events.val, events.len));
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 4, dest_end, dyn_cursor, nested);
+    if (dyn_cursor == nullptr) return nullptr;
+    return dyn_cursor;
+}
+
+/*
+ * BatchWriter::EventsWriter
+ */
+
+BatchWriter::EventsWriter BatchWriter::EventsWriter::id(uint8_t* val) {
+    Value v;
+    v.id = val;
+    return EventsWriter(Tag::ID, v);
+}
+
+BatchWriter::EventsWriter BatchWriter::EventsWriter::name(const char** val) {
+    Value v;
+    v.name = val;
+    return EventsWriter(Tag::NAME, v);
+}
+
+uint8_t* BatchWriter::EventsWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    switch (tag_) {
+        case Tag::ID:
+            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 0, *value_.id);
+        case Tag::NAME:
+            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 1, *value_.name);
+        default:
+            return nullptr;
+    }
+}
+
+BatchWriter::EventsWriter::EventsWriter(Tag tag, Value value) : tag_(tag), value_(value) {}
+/*
+ * BatchWriter::NestedWriter
+ */
+
+BatchWriter::NestedWriter BatchWriter::NestedWriter::empty(uint8_t* val) {
+    Value v;
+    v.empty = val;
+    return NestedWriter(Tag::EMPTY, v);
+}
+
+BatchWriter::NestedWriter BatchWriter::NestedWriter::events(simplebuffers::ListWriter<EventsWriter>* val) {
+    Value v;
+    v.events = val;
+    return NestedWriter(Tag::EVENTS, v);
+}
+
+uint8_t* BatchWriter::NestedWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    switch (tag_) {
+        case Tag::EMPTY:
+            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 0, *value_.empty);
+        case Tag::EVENTS:
+            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 1, simplebuffers::priv::ListWriterImpl<EventsWriter>(value_.events->val, value_.events->len));
+        default:
+            return nullptr;
+    }
+}
+
+BatchWriter::NestedWriter::NestedWriter(Tag tag, Value value) : tag_(tag), value_(value) {}
+
+/*
+ * BatchWriter::NestedWriter::EventsWriter
+ */
+
+BatchWriter::NestedWriter::EventsWriter BatchWriter::NestedWriter::EventsWriter::small(uint8_t* val) {
+    Value v;
+    v.small = val;
+    return EventsWriter(Tag::SMALL, v);
+}
+
+BatchWriter::NestedWriter::EventsWriter BatchWriter::NestedWriter::EventsWriter::large(uint64_t* val) {
+    Value v;
+    v.large = val;
+    return EventsWriter(Tag::LARGE, v);
+}
+
+uint8_t* BatchWriter::NestedWriter::EventsWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    switch (tag_) {
+        case Tag::SMALL:
+            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 0, *value_.small);
+        case Tag::LARGE:
+            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 1, *value_.large);
+        default:
+            return nullptr;
+    }
+}
+
+BatchWriter::NestedWriter::EventsWriter::EventsWriter(Tag tag, Value value) : tag_(tag), value_(value) {}
+
+/*
+ * BatchReader
+ */
+
+BatchReader::BatchReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 7 * idx) {}
+
+uint16_t BatchReader::static_size() const noexcept { return 7; }
+
+simplebuffers::ListReader<BatchReader::EventsReader> BatchReader::events() const noexcept {
+    return simplebuffers::ListReader<BatchReader::EventsReader>(static_cast<const uint8_t*>(data_ptr_ + 0), 0);
+}
+BatchReader::NestedReader BatchReader::nested() const noexcept {
+    return NestedReader(static_cast<const uint8_t*>(data_ptr_ + 4), 0);
+}
+
+/*
+ * BatchReader::EventsReader
+ */
+
+BatchReader::EventsReader::EventsReader(const uint8_t* data_ptr, size_t idx) : OneOfReader(data_ptr, idx) {
+    const uint16_t offset = simplebuffers::read_field<uint16_t>(data_ptr_ + 1);
+    tag_ = static_cast<Tag>(simplebuffers::read_field<uint8_t>(data_ptr_));
+    val_ptr_ = data_ptr_ + offset;
+}
+
+BatchReader::EventsReader::Tag BatchReader::EventsReader::tag() const noexcept {
+    return tag_;
+}
+
+uint8_t BatchReader::EventsReader::id() const noexcept {
+    if (tag_ != Tag::ID) return 0;
+    return simplebuffers::read_field<uint8_t>(val_ptr_);
+}
+
+const char* BatchReader::EventsReader::name() const noexcept {
+    if (tag_ != Tag::NAME) return "\0";
+    return simplebuffers::read_field<const char*>(val_ptr_);
+}
+/*
+ * BatchReader::NestedReader
+ */
+
+BatchReader::NestedReader::NestedReader(const uint8_t* data_ptr, size_t idx) : OneOfReader(data_ptr, idx) {
+    const uint16_t offset = simplebuffers::read_field<uint16_t>(data_ptr_ + 1);
+    tag_ = static_cast<Tag>(simplebuffers::read_field<uint8_t>(data_ptr_));
+    val_ptr_ = data_ptr_ + offset;
+}
+
+BatchReader::NestedReader::Tag BatchReader::NestedReader::tag() const noexcept {
+    return tag_;
+}
+
+uint8_t BatchReader::NestedReader::empty() const noexcept {
+    if (tag_ != Tag::EMPTY) return 0;
+    return simplebuffers::read_field<uint8_t>(val_ptr_);
+}
+
+simplebuffers::ListReader<BatchReader::NestedReader::EventsReader> BatchReader::NestedReader::events() const noexcept {
+    if (tag_ != Tag::EVENTS) return simplebuffers::ListReader<BatchReader::NestedReader::EventsReader>();
+    return simplebuffers::ListReader<BatchReader::NestedReader::EventsReader>(static_cast<const uint8_t*>(val_ptr_), 0);
+}
+
+/*
+ * BatchReader::NestedReader::EventsReader
+ */
+
+BatchReader::NestedReader::EventsReader::EventsReader(const uint8_t* data_ptr, size_t idx) : OneOfReader(data_ptr, idx) {
+    const uint16_t offset = simplebuffers::read_field<uint16_t>(data_ptr_ + 1);
+    tag_ = static_cast<Tag>(simplebuffers::read_field<uint8_t>(data_ptr_));
+    val_ptr_ = data_ptr_ + offset;
+}
+
+BatchReader::NestedReader::EventsReader::Tag BatchReader::NestedReader::EventsReader::tag() const noexcept {
+    return tag_;
+}
+
+uint8_t BatchReader::NestedReader::EventsReader::small() const noexcept {
+    if (tag_ != Tag::SMALL) return 0;
+    return simplebuffers::read_field<uint8_t>(val_ptr_);
+}
+
+uint64_t BatchReader::NestedReader::EventsReader::large() const noexcept {
+    if (tag_ != Tag::LARGE) return 0;
+    return simplebuffers::read_field<uint64_t>(val_ptr_);
+}
+
+
+
+
+
+} // namespace simplebuffers_oneof_arrays
\ No newline at end of file
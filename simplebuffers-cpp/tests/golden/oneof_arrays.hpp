@@ -0,0 +1,192 @@
+#ifndef SIMPLEBUFFERS_GENERATED__ONEOF_ARRAYS_HPP
+#define SIMPLEBUFFERS_GENERATED__ONEOF_ARRAYS_HPP
+
+#include "simplebuffers.hpp"
+
+#if SIMPLEBUFFERS_BIG_ENDIAN_WIRE
+#error "Code generated for little-endian data cannot be used with code generated with --endianness=big"
+#endif
+
+namespace simplebuffers_oneof_arrays {
+
+class BatchWriter;
+
+class BatchWriter : public simplebuffers::SimpleBufferWriter {
+   public:
+    class EventsWriter : public simplebuffers::OneOfWriter {
+       public:
+        enum class Tag : uint8_t {
+            ID = 0,
+            NAME = 1
+        };
+
+        union Value {
+            uint8_t* id;
+            const char** name;
+        };
+
+        static EventsWriter id(uint8_t* val);
+        static EventsWriter name(const char** val);
+
+        EventsWriter(const EventsWriter&) = default;
+        EventsWriter(EventsWriter&&) noexcept = default;
+        EventsWriter& operator=(const EventsWriter&) = default;
+        EventsWriter& operator=(EventsWriter&&) noexcept = default;
+
+        uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+
+       protected:
+        EventsWriter(Tag tag, Value value);
+
+        Tag tag_;
+        Value value_;
+    };
+
+    class NestedWriter : public simplebuffers::OneOfWriter {
+       public:
+        class EventsWriter : public simplebuffers::OneOfWriter {
+           public:
+            enum class Tag : uint8_t {
+                SMALL = 0,
+                LARGE = 1
+            };
+
+            union Value {
+                uint8_t* small;
+                uint64_t* large;
+            };
+
+            static EventsWriter small(uint8_t* val);
+            static EventsWriter large(uint64_t* val);
+
+            EventsWriter(const EventsWriter&) = default;
+            EventsWriter(EventsWriter&&) noexcept = default;
+            EventsWriter& operator=(const EventsWriter&) = default;
+            EventsWriter& operator=(EventsWriter&&) noexcept = default;
+
+            uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+
+           protected:
+            EventsWriter(Tag tag, Value value);
+
+            Tag tag_;
+            Value value_;
+        };
+
+        enum class Tag : uint8_t {
+            EMPTY = 0,
+            EVENTS = 1
+        };
+
+        union Value {
+            uint8_t* empty;
+            simplebuffers::ListWriter<EventsWriter>* events;
+        };
+
+        static NestedWriter empty(uint8_t* val);
+        static NestedWriter events(simplebuffers::ListWriter<EventsWriter>* val);
+
+        NestedWriter(const NestedWriter&) = default;
+        NestedWriter(NestedWriter&&) noexcept = default;
+        NestedWriter& operator=(const NestedWriter&) = default;
+        NestedWriter& operator=(NestedWriter&&) noexcept = default;
+
+        uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+
+       protected:
+        NestedWriter(Tag tag, Value value);
+
+        Tag tag_;
+        Value value_;
+    };
+
+    BatchWriter(simplebuffers::ListWriter<EventsWriter> events, NestedWriter nested);
+    BatchWriter(const BatchWriter&) = default;
+    BatchWriter(BatchWriter&&) noexcept = default;
+    BatchWriter& operator=(const BatchWriter&) = default;
+    BatchWriter& operator=(BatchWriter&&) noexcept = default;
+
+    simplebuffers::ListWriter<EventsWriter> events;
+    NestedWriter nested;
+
+    uint16_t static_size() const noexcept override;
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+};
+
+class BatchReader;
+
+class BatchReader : public simplebuffers::SimpleBufferReader {
+    public:
+    class EventsReader : public simplebuffers::OneOfReader {
+       public:
+        enum class Tag : uint8_t {
+            ID = 0,
+            NAME = 1
+        };
+
+        EventsReader(const uint8_t* data_ptr, size_t idx = 0);
+        EventsReader(const EventsReader&) = default;
+        EventsReader(EventsReader&&) noexcept = default;
+        EventsReader& operator=(const EventsReader&) = default;
+        EventsReader& operator=(EventsReader&&) noexcept = default;
+        Tag tag() const noexcept;
+        uint8_t id() const noexcept;
+        const char* name() const noexcept;
+
+       protected:
+        Tag tag_;
+    };
+
+    class NestedReader : public simplebuffers::OneOfReader {
+       public:
+        class EventsReader : public simplebuffers::OneOfReader {
+           public:
+            enum class Tag : uint8_t {
+                SMALL = 0,
+                LARGE = 1
+            };
+
+            EventsReader(const uint8_t* data_ptr, size_t idx = 0);
+            EventsReader(const EventsReader&) = default;
+            EventsReader(EventsReader&&) noexcept = default;
+            EventsReader& operator=(const EventsReader&) = default;
+            EventsReader& operator=(EventsReader&&) noexcept = default;
+            Tag tag() const noexcept;
+            uint8_t small() const noexcept;
+            uint64_t large() const noexcept;
+
+           protected:
+            Tag tag_;
+        };
+
+        enum class Tag : uint8_t {
+            EMPTY = 0,
+            EVENTS = 1
+        };
+
+        NestedReader(const uint8_t* data_ptr, size_t idx = 0);
+        NestedReader(const NestedReader&) = default;
+        NestedReader(NestedReader&&) noexcept = default;
+        NestedReader& operator=(const NestedReader&) = default;
+        NestedReader& operator=(NestedReader&&) noexcept = default;
+        Tag tag() const noexcept;
+        uint8_t empty() const noexcept;
+        simplebuffers::ListReader<EventsReader> events() const noexcept;
+
+       protected:
+        Tag tag_;
+    };
+
+    BatchReader(const uint8_t* data_ptr, size_t idx = 0);
+    BatchReader(const BatchReader&) = default;
+    BatchReader(BatchReader&&) noexcept = default;
+    BatchReader& operator=(const BatchReader&) = default;
+    BatchReader& operator=(BatchReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    simplebuffers::ListReader<EventsReader> events() const noexcept;
+    NestedReader nested() const noexcept;
+};
+
+} // namespace simplebuffers_oneof_arrays
+
+#endif
\ No newline at end of file
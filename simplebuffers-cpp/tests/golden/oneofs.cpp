@@ -0,0 +1,140 @@
+#include "oneofs.hpp"
+
+namespace simplebuffers_oneofs {
+
+/*
+ * RequestWriter
+ */
+
+RequestWriter::RequestWriter(uint16_t id, BodyWriter body):
+    id(id), body(body) {}
+
+uint16_t RequestWriter::static_size() const noexcept { return 5; }
+
+uint8_t* RequestWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    if (dest_end - dest < 5) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, id);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 2, dest_end, dyn_cursor, body);
+    if (dyn_cursor == nullptr) return nullptr;
+    return dyn_cursor;
+}
+
+/*
+ * RequestWriter::BodyWriter
+ */
+
+RequestWriter::BodyWriter RequestWriter::BodyWriter::ping(uint8_t* val) {
+    Value v;
+    v.ping = val;
+    return BodyWriter(Tag::PING, v);
+}
+
+RequestWriter::BodyWriter RequestWriter::BodyWriter::echo(const char** val) {
+    Value v;
+    v.echo = val;
+    return BodyWriter(Tag::ECHO, v);
+}
+
+RequestWriter::BodyWriter RequestWriter::BodyWriter::target(TargetWriter* val) {
+    Value v;
+    v.target = val;
+    return BodyWriter(Tag::TARGET, v);
+}
+
+uint8_t* RequestWriter::BodyWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    switch (tag_) {
+        case Tag::PING:
+            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 0, *value_.ping);
+        case Tag::ECHO:
+            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 1, *value_.echo);
+        case Tag::TARGET:
+            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 2, *value_.target);
+        default:
+            return nullptr;
+    }
+}
+
+RequestWriter::BodyWriter::BodyWriter(Tag tag, Value value) : tag_(tag), value_(value) {}
+
+
+/*
+ * TargetWriter
+ */
+
+TargetWriter::TargetWriter(float x, float y):
+    x(x), y(y) {}
+
+uint16_t TargetWriter::static_size() const noexcept { return 8; }
+
+uint8_t* TargetWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    if (dest_end - dest < 8) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, x);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 4, dest_end, dyn_cursor, y);
+    if (dyn_cursor == nullptr) return nullptr;
+    return dyn_cursor;
+}
+
+/*
+ * RequestReader
+ */
+
+RequestReader::RequestReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 5 * idx) {}
+
+uint16_t RequestReader::static_size() const noexcept { return 5; }
+
+uint16_t RequestReader::id() const noexcept {
+    return simplebuffers::read_field<uint16_t>(data_ptr_ + 0);
+}
+RequestReader::BodyReader RequestReader::body() const noexcept {
+    return BodyReader(static_cast<const uint8_t*>(data_ptr_ + 2), 0);
+}
+
+/*
+ * RequestReader::BodyReader
+ */
+
+RequestReader::BodyReader::BodyReader(const uint8_t* data_ptr, size_t idx) : OneOfReader(data_ptr, idx) {
+    const uint16_t offset = simplebuffers::read_field<uint16_t>(data_ptr_ + 1);
+    tag_ = static_cast<Tag>(simplebuffers::read_field<uint8_t>(data_ptr_));
+    val_ptr_ = data_ptr_ + offset;
+}
+
+RequestReader::BodyReader::Tag RequestReader::BodyReader::tag() const noexcept {
+    return tag_;
+}
+
+uint8_t RequestReader::BodyReader::ping() const noexcept {
+    if (tag_ != Tag::PING) return 0;
+    return simplebuffers::read_field<uint8_t>(val_ptr_);
+}
+
+const char* RequestReader::BodyReader::echo() const noexcept {
+    if (tag_ != Tag::ECHO) return "\0";
+    return simplebuffers::read_field<const char*>(val_ptr_);
+}
+
+TargetReader RequestReader::BodyReader::target() const noexcept {
+    return TargetReader(val_ptr_, 0);
+}
+
+
+/*
+ * TargetReader
+ */
+
+TargetReader::TargetReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 8 * idx) {}
+
+uint16_t TargetReader::static_size() const noexcept { return 8; }
+
+float TargetReader::x() const noexcept {
+    return simplebuffers::read_field<float>(data_ptr_ + 0);
+}
+float TargetReader::y() const noexcept {
+    return simplebuffers::read_field<float>(data_ptr_ + 4);
+}
+
+
+
+} // namespace simplebuffers_oneofs
\ No newline at end of file
@@ -0,0 +1,128 @@
+#ifndef SIMPLEBUFFERS_GENERATED__ONEOFS_HPP
+#define SIMPLEBUFFERS_GENERATED__ONEOFS_HPP
+
+#include "simplebuffers.hpp"
+
+#if SIMPLEBUFFERS_BIG_ENDIAN_WIRE
+#error "Code generated for little-endian data cannot be used with code generated with --endianness=big"
+#endif
+
+namespace simplebuffers_oneofs {
+
+class RequestWriter;
+class TargetWriter;
+
+class RequestWriter : public simplebuffers::SimpleBufferWriter {
+   public:
+    class BodyWriter : public simplebuffers::OneOfWriter {
+       public:
+        enum class Tag : uint8_t {
+            PING = 0,
+            ECHO = 1,
+            TARGET = 2
+        };
+
+        union Value {
+            uint8_t* ping;
+            const char** echo;
+            TargetWriter* target;
+        };
+
+        static BodyWriter ping(uint8_t* val);
+        static BodyWriter echo(const char** val);
+        static BodyWriter target(TargetWriter* val);
+
+        BodyWriter(const BodyWriter&) = default;
+        BodyWriter(BodyWriter&&) noexcept = default;
+        BodyWriter& operator=(const BodyWriter&) = default;
+        BodyWriter& operator=(BodyWriter&&) noexcept = default;
+
+        uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+
+       protected:
+        BodyWriter(Tag tag, Value value);
+
+        Tag tag_;
+        Value value_;
+    };
+
+    RequestWriter(uint16_t id, BodyWriter body);
+    RequestWriter(const RequestWriter&) = default;
+    RequestWriter(RequestWriter&&) noexcept = default;
+    RequestWriter& operator=(const RequestWriter&) = default;
+    RequestWriter& operator=(RequestWriter&&) noexcept = default;
+
+    uint16_t id;
+    BodyWriter body;
+
+    uint16_t static_size() const noexcept override;
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+};
+
+class TargetWriter : public simplebuffers::SimpleBufferWriter {
+   public:
+    TargetWriter(float x, float y);
+    TargetWriter(const TargetWriter&) = default;
+    TargetWriter(TargetWriter&&) noexcept = default;
+    TargetWriter& operator=(const TargetWriter&) = default;
+    TargetWriter& operator=(TargetWriter&&) noexcept = default;
+
+    float x;
+    float y;
+
+    uint16_t static_size() const noexcept override;
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+};
+
+class RequestReader;
+class TargetReader;
+
+class RequestReader : public simplebuffers::SimpleBufferReader {
+    public:
+    class BodyReader : public simplebuffers::OneOfReader {
+       public:
+        enum class Tag : uint8_t {
+            PING = 0,
+            ECHO = 1,
+            TARGET = 2
+        };
+
+        BodyReader(const uint8_t* data_ptr, size_t idx = 0);
+        BodyReader(const BodyReader&) = default;
+        BodyReader(BodyReader&&) noexcept = default;
+        BodyReader& operator=(const BodyReader&) = default;
+        BodyReader& operator=(BodyReader&&) noexcept = default;
+        Tag tag() const noexcept;
+        uint8_t ping() const noexcept;
+        const char* echo() const noexcept;
+        TargetReader target() const noexcept;
+
+       protected:
+        Tag tag_;
+    };
+
+    RequestReader(const uint8_t* data_ptr, size_t idx = 0);
+    RequestReader(const RequestReader&) = default;
+    RequestReader(RequestReader&&) noexcept = default;
+    RequestReader& operator=(const RequestReader&) = default;
+    RequestReader& operator=(RequestReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    uint16_t id() const noexcept;
+    BodyReader body() const noexcept;
+};
+
+class TargetReader : public simplebuffers::SimpleBufferReader {
+    public:
+    TargetReader(const uint8_t* data_ptr, size_t idx = 0);
+    TargetReader(const TargetReader&) = default;
+    TargetReader(TargetReader&&) noexcept = default;
+    TargetReader& operator=(const TargetReader&) = default;
+    TargetReader& operator=(TargetReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    float x() const noexcept;
+    float y() const noexcept;
+};
+
+} // namespace simplebuffers_oneofs
+
+#endif
\ No newline at end of file
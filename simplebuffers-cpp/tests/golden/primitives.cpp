@@ -0,0 +1,90 @@
+#include "primitives.hpp"
+
+namespace simplebuffers_primitives {
+
+/*
+ * PrimitivesWriter
+ */
+
+PrimitivesWriter::PrimitivesWriter(uint8_t a, uint16_t b, uint32_t c, uint64_t d, int8_t e, int16_t f, int32_t g, int64_t h, float i, double j, bool k, const char* name):
+    a(a), b(b), c(c), d(d), e(e), f(f), g(g), h(h), i(i), j(j), k(k), name(name) {}
+
+uint16_t PrimitivesWriter::static_size() const noexcept { return 45; }
+
+uint8_t* PrimitivesWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
+    if (dest_end - dest < 45) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, a);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 1, dest_end, dyn_cursor, b);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 3, dest_end, dyn_cursor, c);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 7, dest_end, dyn_cursor, d);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 15, dest_end, dyn_cursor, e);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 16, dest_end, dyn_cursor, f);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 18, dest_end, dyn_cursor, g);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 22, dest_end, dyn_cursor, h);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 30, dest_end, dyn_cursor, i);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 34, dest_end, dyn_cursor, j);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 42, dest_end, dyn_cursor, k);
+    if (dyn_cursor == nullptr) return nullptr;
+    dyn_cursor = simplebuffers::write_field(dest + 43, dest_end, dyn_cursor, name);
+    if (dyn_cursor == nullptr) return nullptr;
+    return dyn_cursor;
+}
+
+/*
+ * PrimitivesReader
+ */
+
+PrimitivesReader::PrimitivesReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 45 * idx) {}
+
+uint16_t PrimitivesReader::static_size() const noexcept { return 45; }
+
+uint8_t PrimitivesReader::a() const noexcept {
+    return simplebuffers::read_field<uint8_t>(data_ptr_ + 0);
+}
+uint16_t PrimitivesReader::b() const noexcept {
+    return simplebuffers::read_field<uint16_t>(data_ptr_ + 1);
+}
+uint32_t PrimitivesReader::c() const noexcept {
+    return simplebuffers::read_field<uint32_t>(data_ptr_ + 3);
+}
+uint64_t PrimitivesReader::d() const noexcept {
+    return simplebuffers::read_field<uint64_t>(data_ptr_ + 7);
+}
+int8_t PrimitivesReader::e() const noexcept {
+    return simplebuffers::read_field<int8_t>(data_ptr_ + 15);
+}
+int16_t PrimitivesReader::f() const noexcept {
+    return simplebuffers::read_field<int16_t>(data_ptr_ + 16);
+}
+int32_t PrimitivesReader::g() const noexcept {
+    return simplebuffers::read_field<int32_t>(data_ptr_ + 18);
+}
+int64_t PrimitivesReader::h() const noexcept {
+    return simplebuffers::read_field<int64_t>(data_ptr_ + 22);
+}
+float PrimitivesReader::i() const noexcept {
+    return simplebuffers::read_field<float>(data_ptr_ + 30);
+}
+double PrimitivesReader::j() const noexcept {
+    return simplebuffers::read_field<double>(data_ptr_ + 34);
+}
+bool PrimitivesReader::k() const noexcept {
+    return simplebuffers::read_field<bool>(data_ptr_ + 42);
+}
+const char* PrimitivesReader::name() const noexcept {
+    return simplebuffers::read_field<const char*>(data_ptr_ + 43);
+}
+
+
+
+} // namespace simplebuffers_primitives
\ No newline at end of file
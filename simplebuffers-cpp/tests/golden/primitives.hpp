@@ -0,0 +1,65 @@
+#ifndef SIMPLEBUFFERS_GENERATED__PRIMITIVES_HPP
+#define SIMPLEBUFFERS_GENERATED__PRIMITIVES_HPP
+
+#include "simplebuffers.hpp"
+
+#if SIMPLEBUFFERS_BIG_ENDIAN_WIRE
+#error "Code generated for little-endian data cannot be used with code generated with --endianness=big"
+#endif
+
+namespace simplebuffers_primitives {
+
+class PrimitivesWriter;
+
+class PrimitivesWriter : public simplebuffers::SimpleBufferWriter {
+   public:
+    PrimitivesWriter(uint8_t a, uint16_t b, uint32_t c, uint64_t d, int8_t e, int16_t f, int32_t g, int64_t h, float i, double j, bool k, const char* name);
+    PrimitivesWriter(const PrimitivesWriter&) = default;
+    PrimitivesWriter(PrimitivesWriter&&) noexcept = default;
+    PrimitivesWriter& operator=(const PrimitivesWriter&) = default;
+    PrimitivesWriter& operator=(PrimitivesWriter&&) noexcept = default;
+
+    uint8_t a;
+    uint16_t b;
+    uint32_t c;
+    uint64_t d;
+    int8_t e;
+    int16_t f;
+    int32_t g;
+    int64_t h;
+    float i;
+    double j;
+    bool k;
+    const char* name;
+
+    uint16_t static_size() const noexcept override;
+    uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
+};
+
+class PrimitivesReader;
+
+class PrimitivesReader : public simplebuffers::SimpleBufferReader {
+    public:
+    PrimitivesReader(const uint8_t* data_ptr, size_t idx = 0);
+    PrimitivesReader(const PrimitivesReader&) = default;
+    PrimitivesReader(PrimitivesReader&&) noexcept = default;
+    PrimitivesReader& operator=(const PrimitivesReader&) = default;
+    PrimitivesReader& operator=(PrimitivesReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    uint8_t a() const noexcept;
+    uint16_t b() const noexcept;
+    uint32_t c() const noexcept;
+    uint64_t d() const noexcept;
+    int8_t e() const noexcept;
+    int16_t f() const noexcept;
+    int32_t g() const noexcept;
+    int64_t h() const noexcept;
+    float i() const noexcept;
+    double j() const noexcept;
+    bool k() const noexcept;
+    const char* name() const noexcept;
+};
+
+} // namespace simplebuffers_primitives
+
+#endif
\ No newline at end of file
@@ -0,0 +1,122 @@
+using System;
+using System.Buffers.Binary;
+using System.Collections.Generic;
+using System.Text;
+
+namespace SimpleBuffers
+{
+    /// <summary>
+    /// Low-level helpers used by generated readers.
+    /// </summary>
+    /// <remarks>
+    /// All multi-byte values are stored in little-endian byte order. Offsets to dynamic data are
+    /// unsigned 16-bit values relative to the position of the field that holds them.
+    /// </remarks>
+    public static class Runtime
+    {
+        public static bool ReadBool(ReadOnlySpan<byte> buf, int pos) => buf[pos] != 0;
+        public static byte ReadU8(ReadOnlySpan<byte> buf, int pos) => buf[pos];
+        public static ushort ReadU16(ReadOnlySpan<byte> buf, int pos) => BinaryPrimitives.ReadUInt16LittleEndian(buf.Slice(pos));
+        public static uint ReadU32(ReadOnlySpan<byte> buf, int pos) => BinaryPrimitives.ReadUInt32LittleEndian(buf.Slice(pos));
+        public static ulong ReadU64(ReadOnlySpan<byte> buf, int pos) => BinaryPrimitives.ReadUInt64LittleEndian(buf.Slice(pos));
+        public static sbyte ReadI8(ReadOnlySpan<byte> buf, int pos) => unchecked((sbyte)buf[pos]);
+        public static short ReadI16(ReadOnlySpan<byte> buf, int pos) => BinaryPrimitives.ReadInt16LittleEndian(buf.Slice(pos));
+        public static int ReadI32(ReadOnlySpan<byte> buf, int pos) => BinaryPrimitives.ReadInt32LittleEndian(buf.Slice(pos));
+        public static long ReadI64(ReadOnlySpan<byte> buf, int pos) => BinaryPrimitives.ReadInt64LittleEndian(buf.Slice(pos));
+        public static float ReadF32(ReadOnlySpan<byte> buf, int pos) => BitConverter.Int32BitsToSingle(ReadI32(buf, pos));
+        public static double ReadF64(ReadOnlySpan<byte> buf, int pos) => BitConverter.Int64BitsToDouble(ReadI64(buf, pos));
+
+        /// <summary>Follows the 16-bit offset stored at <paramref name="pos"/>.</summary>
+        public static int Follow(ReadOnlySpan<byte> buf, int pos) => pos + ReadU16(buf, pos);
+
+        /// <summary>Reads a null-terminated string whose offset is stored at <paramref name="pos"/>.</summary>
+        public static string ReadString(ReadOnlySpan<byte> buf, int pos)
+        {
+            var bytes = buf.Slice(Follow(buf, pos));
+            var len = bytes.IndexOf((byte)0);
+            return Encoding.UTF8.GetString(len < 0 ? bytes : bytes.Slice(0, len));
+        }
+    }
+
+    /// <summary>
+    /// A growable buffer that generated writers serialize into.
+    /// </summary>
+    public sealed class Builder
+    {
+        private byte[] _buf;
+        private int _size;
+
+        /// <summary>Creates a builder with <paramref name="staticSize"/> zeroed bytes reserved for the root.</summary>
+        public Builder(int staticSize)
+        {
+            _buf = new byte[Math.Max(64, staticSize)];
+            _size = staticSize;
+        }
+
+        /// <summary>The number of bytes written so far.</summary>
+        public int Size => _size;
+
+        /// <summary>
+        /// Reserves <paramref name="count"/> zeroed bytes at the end of the buffer and stores the offset to
+        /// them (relative to <paramref name="basePos"/>) at <paramref name="slot"/>. Returns the position of
+        /// the reserved bytes.
+        /// </summary>
+        public int Reserve(int slot, int basePos, int count)
+        {
+            var start = _size;
+            if (_size + count > _buf.Length)
+            {
+                Array.Resize(ref _buf, Math.Max(_size + count, _buf.Length * 2));
+            }
+            _size += count;
+            WriteU16(slot, (ushort)(start - basePos));
+            return start;
+        }
+
+        public void WriteBool(int pos, bool value) => _buf[pos] = (byte)(value ? 1 : 0);
+        public void WriteU8(int pos, byte value) => _buf[pos] = value;
+        public void WriteU16(int pos, ushort value) => BinaryPrimitives.WriteUInt16LittleEndian(_buf.AsSpan(pos), value);
+        public void WriteU32(int pos, uint value) => BinaryPrimitives.WriteUInt32LittleEndian(_buf.AsSpan(pos), value);
+        public void WriteU64(int pos, ulong value) => BinaryPrimitives.WriteUInt64LittleEndian(_buf.AsSpan(pos), value);
+        public void WriteI8(int pos, sbyte value) => _buf[pos] = unchecked((byte)value);
+        public void WriteI16(int pos, short value) => BinaryPrimitives.WriteInt16LittleEndian(_buf.AsSpan(pos), value);
+        public void WriteI32(int pos, int value) => BinaryPrimitives.WriteInt32LittleEndian(_buf.AsSpan(pos), value);
+        public void WriteI64(int pos, long value) => BinaryPrimitives.WriteInt64LittleEndian(_buf.AsSpan(pos), value);
+        public void WriteF32(int pos, float value) => WriteI32(pos, BitConverter.SingleToInt32Bits(value));
+        public void WriteF64(int pos, double value) => WriteI64(pos, BitConverter.DoubleToInt64Bits(value));
+
+        /// <summary>Writes a null-terminated string to the end of the buffer and stores its offset at <paramref name="pos"/>.</summary>
+        public void WriteString(int pos, string value)
+        {
+            var bytes = Encoding.UTF8.GetBytes(value);
+            var start = Reserve(pos, pos, bytes.Length + 1);
+            bytes.CopyTo(_buf, start);
+        }
+
+        /// <summary>Writes a list header at <paramref name="pos"/> and the list elements to the end of the buffer.</summary>
+        public void WriteList<T>(int pos, IReadOnlyList<T> values, int stride, Action<int, T> writeElement)
+        {
+            WriteU16(pos, (ushort)values.Count);
+            var start = Reserve(pos + 2, pos, values.Count * stride);
+            for (var i = 0; i < values.Count; i++)
+            {
+                writeElement(start + i * stride, values[i]);
+            }
+        }
+
+        /// <summary>Returns a copy of the bytes written so far.</summary>
+        public byte[] ToArray() => _buf.AsSpan(0, _size).ToArray();
+    }
+
+    /// <summary>
+    /// A component that can be serialized into a <see cref="Builder"/>.
+    /// </summary>
+    public interface IWriter
+    {
+        /// <summary>The size of the component's static data.</summary>
+        int StaticSize { get; }
+
+        /// <summary>Writes static data at <paramref name="pos"/> and dynamic data at the end of the builder.</summary>
+        void WriteComponent(Builder b, int pos);
+    }
+}
@@ -0,0 +1,46 @@
+//! Parses generator-specific arguments contained in [GeneratorParams::additional_args].
+
+use clap::{Command, CommandFactory, Parser};
+use convert_case::{Case, Casing};
+use simplebuffers_codegen::GeneratorParams;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Struct to parse CLI args into.
+#[derive(Parser, Debug)]
+#[command(name = "SimpleBuffers C# Code Generator")]
+#[command(version = VERSION)]
+#[command(about = "Generate C# code from a SimpleBuffers schema.")]
+struct Cli {
+    /// The C# namespace to generate types in (for example, `Example.Robot`). If this is not
+    /// specified, a namespace is derived from the schema file name.
+    #[arg(long)]
+    namespace: Option<String>,
+}
+
+/// A struct that holds generator-specific arguments for the C# generator.
+#[derive(Debug)]
+pub(crate) struct CSharpGeneratorParams {
+    /// The C# namespace to generate types in.
+    pub namespace: String,
+
+    /// The global compiler parameters.
+    pub global: GeneratorParams,
+}
+
+/// Returns the command that describes the generator-specific arguments, so the compiler can
+/// show them in its help output and validate them before generating.
+pub(crate) fn command() -> Command {
+    Cli::command()
+}
+
+/// Parse generator-specific arguments from the compiler-provided argument list.
+pub(crate) fn parse_args(generator_params: &GeneratorParams) -> CSharpGeneratorParams {
+    let cli = Cli::parse_from(&generator_params.additional_args);
+    CSharpGeneratorParams {
+        namespace: cli.namespace.unwrap_or_else(|| {
+            format!("simplebuffers_{}", generator_params.file_name).to_case(Case::Pascal)
+        }),
+        global: generator_params.clone(),
+    }
+}
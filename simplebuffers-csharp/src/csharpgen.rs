@@ -0,0 +1,628 @@
+//! Generates a C# source file.
+
+use convert_case::{Case, Casing};
+use indent::indent_by;
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+use std::collections::{HashMap, HashSet};
+
+/// Where a value is stored. This determines how sequences are laid out.
+#[derive(Clone, Copy)]
+enum Context {
+    /// The value is a field of a sequence. Sequences are stored behind a 16-bit offset.
+    Slot,
+
+    /// The value is a list element or the target of a oneof. Sequences are stored inline.
+    Element,
+}
+
+/// Information about the schema that is needed while generating code.
+struct CSharpGen<'a> {
+    /// The static size of every sequence, by name.
+    sequence_sizes: HashMap<&'a str, usize>,
+
+    /// The names of the enums that are signed (see [Enum::is_signed]).
+    signed_enums: HashSet<&'a str>,
+}
+
+//                                                                                                //
+// ======================================= Main Function ======================================== //
+//                                                                                                //
+
+/// Generates a C# source file from a given schema.
+///
+/// # Arguments
+///
+/// * `namespace` - The C# namespace to generate types in.
+/// * `schema` - The schema to generate from.
+///
+/// # Returns
+///
+/// The code for a C# source file, as a String.
+pub(crate) fn generate_csharp(namespace: &str, schema: &SBSchema) -> String {
+    let gen = CSharpGen {
+        sequence_sizes: schema
+            .sequences
+            .iter()
+            .map(|s| (s.name.as_str(), s.static_size()))
+            .collect(),
+        signed_enums: schema
+            .enums
+            .iter()
+            .filter(|e| e.is_signed())
+            .map(|e| e.name.as_str())
+            .collect(),
+    };
+
+    let declarations = schema
+        .enums
+        .iter()
+        .map(define_enum)
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| gen.define_sequence_writer(s)),
+        )
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| gen.define_sequence_reader(s)),
+        )
+        .join("\n\n");
+
+    formatdoc! {
+        r#"
+        // This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+        using System;
+        using System.Collections.Generic;
+        using SimpleBuffers;
+
+        namespace {namespace}
+        {{
+            {declarations}
+        }}
+        "#,
+        declarations = indent_by(4, declarations),
+    }
+}
+
+/// Returns the source of the runtime that generated files depend on.
+pub(crate) fn runtime() -> String {
+    format!(
+        "// This file was generated by the SimpleBuffers compiler. Do not edit it manually.\n\n{}\n",
+        include_str!("../corelib/SimpleBuffers.cs").trim()
+    )
+}
+
+/// Returns the C# type that matches a primitive, along with the suffix of the runtime methods
+/// that read and write it.
+fn primitive_type(p: &Primitive) -> (&'static str, &'static str) {
+    match p {
+        Primitive::Bool => ("bool", "Bool"),
+        Primitive::U8 => ("byte", "U8"),
+        Primitive::U16 => ("ushort", "U16"),
+        Primitive::U32 => ("uint", "U32"),
+        Primitive::U64 => ("ulong", "U64"),
+        Primitive::I8 => ("sbyte", "I8"),
+        Primitive::I16 => ("short", "I16"),
+        Primitive::I32 => ("int", "I32"),
+        Primitive::I64 => ("long", "I64"),
+        Primitive::F32 => ("float", "F32"),
+        Primitive::F64 => ("double", "F64"),
+    }
+}
+
+/// Returns the C# type that stores an enum of the given size and signedness, along with the suffix
+/// of the runtime methods that read and write it.
+fn enum_underlying_type(size: usize, signed: bool) -> (&'static str, &'static str) {
+    match (size, signed) {
+        (1, false) => ("byte", "U8"),
+        (2, false) => ("ushort", "U16"),
+        (4, false) => ("uint", "U32"),
+        (8, false) => ("ulong", "U64"),
+        (1, true) => ("sbyte", "I8"),
+        (2, true) => ("short", "I16"),
+        (4, true) => ("int", "I32"),
+        (8, true) => ("long", "I64"),
+        _ => panic!("Cannot convert size {} to C# type", size),
+    }
+}
+
+/// Returns the name of the writer class for a sequence.
+fn writer_name(seq_name: &str) -> String {
+    format!("{}Writer", seq_name).to_case(Case::Pascal)
+}
+
+/// Returns the name of the reader struct for a sequence.
+fn reader_name(seq_name: &str) -> String {
+    format!("{}Reader", seq_name).to_case(Case::Pascal)
+}
+
+/// Returns the path used to name types nested in a field.
+fn field_path(prefix: &str, field_name: &str) -> String {
+    format!("{}{}", prefix, field_name.to_case(Case::Pascal))
+}
+
+/// Generates the C# code for defining an enum.
+fn define_enum(data: &Enum) -> String {
+    let name = data.name.to_case(Case::Pascal);
+    let (underlying, _) = enum_underlying_type(data.size.into(), data.is_signed());
+    let variants = data
+        .variants
+        .iter()
+        .map(|v| format!("{} = {},", v.name.to_case(Case::Pascal), v.value))
+        .join("\n");
+
+    formatdoc! {
+        r"
+        public enum {name} : {underlying}
+        {{
+            {variants}
+        }}",
+        variants = indent_by(4, variants)
+    }
+}
+
+/// Wraps the members of a type declaration in braces.
+fn declare(header: &str, body: &str) -> String {
+    formatdoc! {
+        r"
+        {header}
+        {{
+            {body}
+        }}",
+        body = indent_by(4, body.trim())
+    }
+}
+
+impl<'a> CSharpGen<'a> {
+    /// Returns the number of bytes a type takes up as a list element or oneof target.
+    fn element_size(&self, ty: &Type) -> usize {
+        match ty {
+            Type::Sequence(s) => self.sequence_sizes[s.as_str()],
+            _ => ty.size(),
+        }
+    }
+
+    //                                                                                            //
+    // =============================== Generate Writer Components =============================== //
+    //                                                                                            //
+
+    /// Returns the C# type used to write a value. `path` names the types nested in the value.
+    fn writer_type(&self, ty: &Type, path: &str) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p).0.to_string(),
+            Type::Sequence(s) => writer_name(s),
+            Type::Enum(e, _) => e.to_case(Case::Pascal),
+            Type::Array(t) => format!(
+                "IReadOnlyList<{}>",
+                self.writer_type(t, &format!("{path}Item"))
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "string".to_string(),
+            Type::OneOf(_) => format!("{path}OneOf"),
+        }
+    }
+
+    /// Returns a statement that writes `value` at `pos` using the builder `b`. `depth` is used to
+    /// give lambda parameters unique names.
+    fn write_stmt(&self, ty: &Type, ctx: Context, value: &str, pos: &str, depth: usize) -> String {
+        match ty {
+            Type::Primitive(p) => format!("b.Write{}({pos}, {value});", primitive_type(p).1),
+            Type::Enum(e, size) => {
+                let (underlying, suffix) =
+                    enum_underlying_type(*size, self.signed_enums.contains(e.as_str()));
+                format!("b.Write{suffix}({pos}, ({underlying}){value});")
+            }
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("b.WriteString({pos}, {value});"),
+            Type::Array(t) => formatdoc! {
+                r"
+                b.WriteList({pos}, {value}, {stride}, (p{depth}, e{depth}) =>
+                {{
+                    {write}
+                }});",
+                stride = self.element_size(t),
+                write = indent_by(
+                    4,
+                    self.write_stmt(
+                        t,
+                        Context::Element,
+                        &format!("e{depth}"),
+                        &format!("p{depth}"),
+                        depth + 1
+                    )
+                ),
+            },
+            Type::Sequence(s) => match ctx {
+                Context::Slot => format!(
+                    "{value}.WriteComponent(b, b.Reserve({pos}, {pos}, {size}));",
+                    size = self.sequence_sizes[s.as_str()],
+                ),
+                Context::Element => format!("{value}.WriteComponent(b, {pos});"),
+            },
+            Type::OneOf(_) => format!("{value}.WriteComponent(b, {pos});"),
+        }
+    }
+
+    /// Returns the definitions of the types nested in a value that is written.
+    fn writer_nested_types(&self, ty: &Type, path: &str) -> Vec<String> {
+        match ty {
+            Type::Array(t) => self.writer_nested_types(t, &format!("{path}Item")),
+            Type::OneOf(subfields) => {
+                let mut types = vec![self.define_oneof_writer(path, subfields)];
+                for f in subfields {
+                    types.extend(self.writer_nested_types(&f.ty, &field_path(path, &f.name)));
+                }
+                types
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Generates the C# code for defining a sequence writer.
+    fn define_sequence_writer(&self, seq: &Sequence) -> String {
+        let class_name = writer_name(&seq.name);
+        let static_size = self.sequence_sizes[seq.name.as_str()];
+
+        let typed_fields = seq
+            .fields
+            .iter()
+            .map(|f| (f, self.writer_type(&f.ty, &field_path("", &f.name))))
+            .collect_vec();
+
+        let members = typed_fields
+            .iter()
+            .map(|(f, ty)| {
+                format!(
+                    "public {ty} {} {{ get; set; }}",
+                    f.name.to_case(Case::Pascal)
+                )
+            })
+            .join("\n");
+
+        let param_list = typed_fields
+            .iter()
+            .map(|(f, ty)| format!("{ty} {}", f.name.to_case(Case::Camel)))
+            .join(", ");
+
+        let init_list = seq
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{} = {};",
+                    f.name.to_case(Case::Pascal),
+                    f.name.to_case(Case::Camel)
+                )
+            })
+            .join("\n");
+
+        let write_fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                self.write_stmt(
+                    &f.ty,
+                    Context::Slot,
+                    &f.name.to_case(Case::Pascal),
+                    &format!("pos + {}", f.index),
+                    1,
+                )
+            })
+            .join("\n");
+
+        let nested = seq
+            .fields
+            .iter()
+            .flat_map(|f| self.writer_nested_types(&f.ty, &field_path("", &f.name)))
+            .join("\n\n");
+
+        let body = formatdoc! {
+            r"
+            public const int StaticSize = {static_size};
+
+            {members}
+
+            public {class_name}({param_list})
+            {{
+                {init_list}
+            }}
+
+            int IWriter.StaticSize => StaticSize;
+
+            public void WriteComponent(Builder b, int pos)
+            {{
+                {write_fields}
+            }}
+
+            public byte[] Encode()
+            {{
+                var b = new Builder(StaticSize);
+                WriteComponent(b, 0);
+                return b.ToArray();
+            }}
+
+            {nested}",
+            init_list = indent_by(4, init_list),
+            write_fields = indent_by(4, write_fields),
+        };
+
+        declare(
+            &format!("public sealed class {class_name} : IWriter"),
+            &body,
+        )
+    }
+
+    /// Generates the C# code for defining a oneof writer. Oneofs are written as an abstract class
+    /// with one sealed subclass per field.
+    fn define_oneof_writer(&self, path: &str, subfields: &[Field]) -> String {
+        let name = format!("{path}OneOf");
+
+        let cases = subfields
+            .iter()
+            .map(|f| {
+                let case_name = format!("{}Case", f.name.to_case(Case::Pascal));
+                let body = formatdoc! {
+                    r"
+                    public {ty} Value {{ get; set; }}
+
+                    public {case_name}({ty} value)
+                    {{
+                        Value = value;
+                    }}
+
+                    public override void WriteComponent(Builder b, int pos)
+                    {{
+                        b.WriteU8(pos, {index});
+                        var p = b.Reserve(pos + 1, pos, {size});
+                        {write}
+                    }}",
+                    ty = self.writer_type(&f.ty, &field_path(path, &f.name)),
+                    index = f.index,
+                    size = self.element_size(&f.ty),
+                    write = indent_by(4, self.write_stmt(&f.ty, Context::Element, "Value", "p", 1)),
+                };
+                declare(&format!("public sealed class {case_name} : {name}"), &body)
+            })
+            .join("\n\n");
+
+        let body = formatdoc! {
+            r"
+            private {name}()
+            {{
+            }}
+
+            public abstract void WriteComponent(Builder b, int pos);
+
+            {cases}"
+        };
+
+        declare(&format!("public abstract class {name}"), &body)
+    }
+
+    //                                                                                            //
+    // =============================== Generate Reader Components =============================== //
+    //                                                                                            //
+
+    /// Returns the C# type returned when reading a value. `path` names the types nested in the
+    /// value.
+    fn reader_type(&self, ty: &Type, path: &str) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p).0.to_string(),
+            Type::Sequence(s) => reader_name(s),
+            Type::Enum(e, _) => e.to_case(Case::Pascal),
+            Type::Array(_) => format!("{path}List"),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "string".to_string(),
+            Type::OneOf(_) => format!("{path}OneOf"),
+        }
+    }
+
+    /// Returns an expression that reads a value of the given type at `pos` in `_buf`.
+    fn read_expr(&self, ty: &Type, ctx: Context, pos: &str, path: &str) -> String {
+        match ty {
+            Type::Primitive(p) => format!("Runtime.Read{}(_buf, {pos})", primitive_type(p).1),
+            Type::Enum(e, size) => format!(
+                "({})Runtime.Read{}(_buf, {pos})",
+                e.to_case(Case::Pascal),
+                enum_underlying_type(*size, self.signed_enums.contains(e.as_str())).1
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("Runtime.ReadString(_buf, {pos})"),
+            Type::Array(_) => format!("new {path}List(_buf, {pos})"),
+            Type::Sequence(s) => match ctx {
+                Context::Slot => {
+                    format!("new {}(_buf, Runtime.Follow(_buf, {pos}))", reader_name(s))
+                }
+                Context::Element => format!("new {}(_buf, {pos})", reader_name(s)),
+            },
+            Type::OneOf(_) => format!("new {path}OneOf(_buf, {pos})"),
+        }
+    }
+
+    /// Returns the definitions of the types nested in a value that is read.
+    fn reader_nested_types(&self, ty: &Type, path: &str) -> Vec<String> {
+        match ty {
+            Type::Array(t) => {
+                let mut types = vec![self.define_list_reader(t, path)];
+                types.extend(self.reader_nested_types(t, &format!("{path}Item")));
+                types
+            }
+            Type::OneOf(subfields) => {
+                let mut types = vec![self.define_oneof_reader(path, subfields)];
+                for f in subfields {
+                    types.extend(self.reader_nested_types(&f.ty, &field_path(path, &f.name)));
+                }
+                types
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Generates the C# code for defining a sequence reader.
+    fn define_sequence_reader(&self, seq: &Sequence) -> String {
+        let class_name = reader_name(&seq.name);
+        let static_size = self.sequence_sizes[seq.name.as_str()];
+
+        let fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                let path = field_path("", &f.name);
+                format!(
+                    "public {ty} {name} => {read};",
+                    ty = self.reader_type(&f.ty, &path),
+                    name = f.name.to_case(Case::Pascal),
+                    read =
+                        self.read_expr(&f.ty, Context::Slot, &format!("_pos + {}", f.index), &path),
+                )
+            })
+            .join("\n");
+
+        let nested = seq
+            .fields
+            .iter()
+            .flat_map(|f| self.reader_nested_types(&f.ty, &field_path("", &f.name)))
+            .join("\n\n");
+
+        let body = formatdoc! {
+            r"
+            public const int StaticSize = {static_size};
+
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _pos;
+
+            public {class_name}(ReadOnlySpan<byte> buf, int pos = 0)
+            {{
+                _buf = buf;
+                _pos = pos;
+            }}
+
+            {fields}
+
+            {nested}"
+        };
+
+        declare(&format!("public readonly ref struct {class_name}"), &body)
+    }
+
+    /// Generates the C# code for defining a list reader. Lists of each element type get their own
+    /// struct, since `ref struct` readers cannot be used as generic arguments.
+    fn define_list_reader(&self, element: &Type, path: &str) -> String {
+        let name = format!("{path}List");
+        let element_path = format!("{path}Item");
+
+        let body = formatdoc! {
+            r"
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _start;
+
+            public {name}(ReadOnlySpan<byte> buf, int pos)
+            {{
+                _buf = buf;
+                Count = Runtime.ReadU16(buf, pos);
+                _start = pos + Runtime.ReadU16(buf, pos + 2);
+            }}
+
+            public int Count {{ get; }}
+
+            public {ty} this[int index]
+            {{
+                get
+                {{
+                    if ((uint)index >= (uint)Count)
+                    {{
+                        throw new IndexOutOfRangeException();
+                    }}
+                    var pos = _start + index * {stride};
+                    return {read};
+                }}
+            }}",
+            ty = self.reader_type(element, &element_path),
+            stride = self.element_size(element),
+            read = self.read_expr(element, Context::Element, "pos", &element_path),
+        };
+
+        declare(&format!("public readonly ref struct {name}"), &body)
+    }
+
+    /// Generates the C# code for defining a oneof reader. The active field is exposed through
+    /// `Tag`, and each field can be read with a `TryGet` method.
+    fn define_oneof_reader(&self, path: &str, subfields: &[Field]) -> String {
+        let name = format!("{path}OneOf");
+
+        let variants = subfields
+            .iter()
+            .map(|f| format!("{} = {},", f.name.to_case(Case::Pascal), f.index))
+            .join("\n");
+
+        let getters = subfields
+            .iter()
+            .map(|f| {
+                let variant = f.name.to_case(Case::Pascal);
+                let field_path = field_path(path, &f.name);
+                formatdoc! {
+                    r"
+                    public bool TryGet{variant}(out {ty} value)
+                    {{
+                        if (Tag != Variant.{variant})
+                        {{
+                            value = default;
+                            return false;
+                        }}
+                        var pos = _pos + Runtime.ReadU16(_buf, _pos + 1);
+                        value = {read};
+                        return true;
+                    }}",
+                    ty = self.reader_type(&f.ty, &field_path),
+                    read = self.read_expr(&f.ty, Context::Element, "pos", &field_path),
+                }
+            })
+            .join("\n\n");
+
+        let body = formatdoc! {
+            r"
+            public enum Variant : byte
+            {{
+                {variants}
+            }}
+
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _pos;
+
+            public {name}(ReadOnlySpan<byte> buf, int pos)
+            {{
+                _buf = buf;
+                _pos = pos;
+            }}
+
+            public Variant Tag => (Variant)_buf[_pos];
+
+            {getters}",
+            variants = indent_by(4, variants),
+        };
+
+        declare(&format!("public readonly ref struct {name}"), &body)
+    }
+}
@@ -0,0 +1,188 @@
+//! C# code generator.
+//!
+//! This module implements C# code generation for SimpleBuffers schemas. It produces two files:
+//!
+//! - `SimpleBuffers.cs`, a small runtime in the `SimpleBuffers` namespace with helpers for reading
+//!   and writing little-endian values. It is shared by every generated schema.
+//! - `{file_name}.cs`, which contains the types generated from the schema.
+//!
+//! The generated file contains:
+//!
+//! - A C# `enum` for every schema enum, whose underlying type matches the enum's size.
+//! - A writer class for every sequence, which serializes into a `byte[]`.
+//! - A reader `ref struct` for every sequence, which reads fields lazily from a
+//!   `ReadOnlySpan<byte>`.
+//!
+//! Oneofs are written as an abstract class with one sealed subclass per field, and read as a
+//! `ref struct` that exposes the active field through a `Tag` and one `TryGet` method per field.
+//! Because `ref struct`s cannot be generic arguments, every list field gets its own reader struct.
+//!
+//! Types are generated in the namespace given by `--namespace`, or in a namespace derived from the
+//! schema file name (for example, `SimplebuffersMySchema`).
+
+mod argparse;
+mod csharpgen;
+
+use std::path::PathBuf;
+
+use argparse::{command, parse_args};
+use csharpgen::{generate_csharp, runtime};
+use simplebuffers_codegen::{register_generator, CodeGenerator, FileSink};
+
+#[derive(Debug)]
+pub struct CSharpCodeGenerator;
+
+impl CodeGenerator for CSharpCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        if schema.uses_fixed_arrays() {
+            return Err("The C# generator does not support fixed-size arrays".to_string());
+        }
+        if schema.uses_bytes() {
+            return Err("The C# generator does not support byte buffers".to_string());
+        }
+        if schema.uses_maps() {
+            return Err("The C# generator does not support maps".to_string());
+        }
+
+        let params = parse_args(params);
+
+        if params.namespace.split('.').any(|segment| {
+            segment.is_empty() || !segment.chars().all(|c| c.is_alphanumeric() || c == '_')
+        }) {
+            return Err(format!("Invalid C# namespace: {}", params.namespace));
+        }
+
+        let runtime_path = PathBuf::from(format!("{}/SimpleBuffers.cs", params.global.dest_dir));
+        sink.write_file(&runtime_path, &runtime())?;
+        let source_path = PathBuf::from(format!(
+            "{}/{}.cs",
+            params.global.dest_dir, params.global.file_name
+        ));
+        sink.write_file(&source_path, &generate_csharp(&params.namespace, schema))?;
+
+        Ok(vec![runtime_path, source_path])
+    }
+
+    fn reserved_identifiers(
+        &mut self,
+        _params: &simplebuffers_codegen::GeneratorParams,
+    ) -> Vec<String> {
+        vec![
+            // Names used by the generated code.
+            "SimpleBuffers",
+            "Runtime",
+            "Builder",
+            "IWriter",
+            "StaticSize",
+            "WriteComponent",
+            "Encode",
+            // Keywords.
+            "abstract",
+            "as",
+            "base",
+            "bool",
+            "break",
+            "byte",
+            "case",
+            "catch",
+            "char",
+            "checked",
+            "class",
+            "const",
+            "continue",
+            "decimal",
+            "default",
+            "delegate",
+            "do",
+            "double",
+            "else",
+            "enum",
+            "event",
+            "explicit",
+            "extern",
+            "false",
+            "finally",
+            "fixed",
+            "float",
+            "for",
+            "foreach",
+            "goto",
+            "if",
+            "implicit",
+            "in",
+            "int",
+            "interface",
+            "internal",
+            "is",
+            "lock",
+            "long",
+            "namespace",
+            "new",
+            "null",
+            "object",
+            "operator",
+            "out",
+            "override",
+            "params",
+            "private",
+            "protected",
+            "public",
+            "readonly",
+            "ref",
+            "return",
+            "sbyte",
+            "sealed",
+            "short",
+            "sizeof",
+            "stackalloc",
+            "static",
+            "string",
+            "struct",
+            "switch",
+            "this",
+            "throw",
+            "true",
+            "try",
+            "typeof",
+            "uint",
+            "ulong",
+            "unchecked",
+            "unsafe",
+            "ushort",
+            "using",
+            "virtual",
+            "void",
+            "volatile",
+            "while",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["cs".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "C# code and the SimpleBuffers runtime".to_string()
+    }
+
+    fn cli(&self) -> Option<clap::Command> {
+        Some(command())
+    }
+}
+
+register_generator!(csharp: CSharpCodeGenerator);
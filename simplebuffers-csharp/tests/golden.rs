@@ -0,0 +1,11 @@
+//! Golden tests for the C# generator. See `simplebuffers-golden` for how they work.
+
+use simplebuffers_csharp::CSharpCodeGenerator;
+use simplebuffers_golden::{golden_tests, Layout};
+
+golden_tests! {
+    generator: CSharpCodeGenerator,
+    args: ["csharp"],
+    layout: Layout::Flat,
+    cases: [primitives, enums, nested, oneofs, lists, oneof_arrays, signed_enums],
+}
@@ -0,0 +1,76 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+using System;
+using System.Collections.Generic;
+using SimpleBuffers;
+
+namespace SimplebuffersEnums
+{
+    public enum Small : byte
+    {
+        A = 0,
+        B = 1,
+    }
+
+    public enum Signed : sbyte
+    {
+        Low = -1,
+        High = 1,
+    }
+
+    public enum Wide : uint
+    {
+        First = 0,
+        Last = 100000,
+    }
+
+    public sealed class EnumsWriter : IWriter
+    {
+        public const int StaticSize = 6;
+
+        public Small Small { get; set; }
+        public Signed Signed { get; set; }
+        public Wide Wide { get; set; }
+
+        public EnumsWriter(Small small, Signed signed, Wide wide)
+        {
+            Small = small;
+            Signed = signed;
+            Wide = wide;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            b.WriteU8(pos + 0, (byte)Small);
+            b.WriteI8(pos + 1, (sbyte)Signed);
+            b.WriteU32(pos + 2, (uint)Wide);
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+    }
+
+    public readonly ref struct EnumsReader
+    {
+        public const int StaticSize = 6;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public EnumsReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public Small Small => (Small)Runtime.ReadU8(_buf, _pos + 0);
+        public Signed Signed => (Signed)Runtime.ReadI8(_buf, _pos + 1);
+        public Wide Wide => (Wide)Runtime.ReadU32(_buf, _pos + 2);
+    }
+}
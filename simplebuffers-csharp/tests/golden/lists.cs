@@ -0,0 +1,304 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+using System;
+using System.Collections.Generic;
+using SimpleBuffers;
+
+namespace SimplebuffersLists
+{
+    public enum Color : byte
+    {
+        Red = 0,
+        Green = 1,
+    }
+
+    public sealed class ListsWriter : IWriter
+    {
+        public const int StaticSize = 20;
+
+        public IReadOnlyList<ushort> Numbers { get; set; }
+        public IReadOnlyList<string> Words { get; set; }
+        public IReadOnlyList<Color> Colors { get; set; }
+        public IReadOnlyList<PointWriter> Points { get; set; }
+        public IReadOnlyList<IReadOnlyList<int>> Matrix { get; set; }
+
+        public ListsWriter(IReadOnlyList<ushort> numbers, IReadOnlyList<string> words, IReadOnlyList<Color> colors, IReadOnlyList<PointWriter> points, IReadOnlyList<IReadOnlyList<int>> matrix)
+        {
+            Numbers = numbers;
+            Words = words;
+            Colors = colors;
+            Points = points;
+            Matrix = matrix;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            b.WriteList(pos + 0, Numbers, 2, (p1, e1) =>
+            {
+                b.WriteU16(p1, e1);
+            });
+            b.WriteList(pos + 4, Words, 2, (p1, e1) =>
+            {
+                b.WriteString(p1, e1);
+            });
+            b.WriteList(pos + 8, Colors, 1, (p1, e1) =>
+            {
+                b.WriteU8(p1, (byte)e1);
+            });
+            b.WriteList(pos + 12, Points, 4, (p1, e1) =>
+            {
+                e1.WriteComponent(b, p1);
+            });
+            b.WriteList(pos + 16, Matrix, 4, (p1, e1) =>
+            {
+                b.WriteList(p1, e1, 4, (p2, e2) =>
+                {
+                    b.WriteI32(p2, e2);
+                });
+            });
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+    }
+
+    public sealed class PointWriter : IWriter
+    {
+        public const int StaticSize = 4;
+
+        public short X { get; set; }
+        public short Y { get; set; }
+
+        public PointWriter(short x, short y)
+        {
+            X = x;
+            Y = y;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            b.WriteI16(pos + 0, X);
+            b.WriteI16(pos + 2, Y);
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+    }
+
+    public readonly ref struct ListsReader
+    {
+        public const int StaticSize = 20;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public ListsReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public NumbersList Numbers => new NumbersList(_buf, _pos + 0);
+        public WordsList Words => new WordsList(_buf, _pos + 4);
+        public ColorsList Colors => new ColorsList(_buf, _pos + 8);
+        public PointsList Points => new PointsList(_buf, _pos + 12);
+        public MatrixList Matrix => new MatrixList(_buf, _pos + 16);
+
+        public readonly ref struct NumbersList
+        {
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _start;
+
+            public NumbersList(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                Count = Runtime.ReadU16(buf, pos);
+                _start = pos + Runtime.ReadU16(buf, pos + 2);
+            }
+
+            public int Count { get; }
+
+            public ushort this[int index]
+            {
+                get
+                {
+                    if ((uint)index >= (uint)Count)
+                    {
+                        throw new IndexOutOfRangeException();
+                    }
+                    var pos = _start + index * 2;
+                    return Runtime.ReadU16(_buf, pos);
+                }
+            }
+        }
+
+        public readonly ref struct WordsList
+        {
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _start;
+
+            public WordsList(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                Count = Runtime.ReadU16(buf, pos);
+                _start = pos + Runtime.ReadU16(buf, pos + 2);
+            }
+
+            public int Count { get; }
+
+            public string this[int index]
+            {
+                get
+                {
+                    if ((uint)index >= (uint)Count)
+                    {
+                        throw new IndexOutOfRangeException();
+                    }
+                    var pos = _start + index * 2;
+                    return Runtime.ReadString(_buf, pos);
+                }
+            }
+        }
+
+        public readonly ref struct ColorsList
+        {
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _start;
+
+            public ColorsList(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                Count = Runtime.ReadU16(buf, pos);
+                _start = pos + Runtime.ReadU16(buf, pos + 2);
+            }
+
+            public int Count { get; }
+
+            public Color this[int index]
+            {
+                get
+                {
+                    if ((uint)index >= (uint)Count)
+                    {
+                        throw new IndexOutOfRangeException();
+                    }
+                    var pos = _start + index * 1;
+                    return (Color)Runtime.ReadU8(_buf, pos);
+                }
+            }
+        }
+
+        public readonly ref struct PointsList
+        {
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _start;
+
+            public PointsList(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                Count = Runtime.ReadU16(buf, pos);
+                _start = pos + Runtime.ReadU16(buf, pos + 2);
+            }
+
+            public int Count { get; }
+
+            public PointReader this[int index]
+            {
+                get
+                {
+                    if ((uint)index >= (uint)Count)
+                    {
+                        throw new IndexOutOfRangeException();
+                    }
+                    var pos = _start + index * 4;
+                    return new PointReader(_buf, pos);
+                }
+            }
+        }
+
+        public readonly ref struct MatrixList
+        {
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _start;
+
+            public MatrixList(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                Count = Runtime.ReadU16(buf, pos);
+                _start = pos + Runtime.ReadU16(buf, pos + 2);
+            }
+
+            public int Count { get; }
+
+            public MatrixItemList this[int index]
+            {
+                get
+                {
+                    if ((uint)index >= (uint)Count)
+                    {
+                        throw new IndexOutOfRangeException();
+                    }
+                    var pos = _start + index * 4;
+                    return new MatrixItemList(_buf, pos);
+                }
+            }
+        }
+
+        public readonly ref struct MatrixItemList
+        {
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _start;
+
+            public MatrixItemList(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                Count = Runtime.ReadU16(buf, pos);
+                _start = pos + Runtime.ReadU16(buf, pos + 2);
+            }
+
+            public int Count { get; }
+
+            public int this[int index]
+            {
+                get
+                {
+                    if ((uint)index >= (uint)Count)
+                    {
+                        throw new IndexOutOfRangeException();
+                    }
+                    var pos = _start + index * 4;
+                    return Runtime.ReadI32(_buf, pos);
+                }
+            }
+        }
+    }
+
+    public readonly ref struct PointReader
+    {
+        public const int StaticSize = 4;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public PointReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public short X => Runtime.ReadI16(_buf, _pos + 0);
+        public short Y => Runtime.ReadI16(_buf, _pos + 2);
+    }
+}
@@ -0,0 +1,146 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+using System;
+using System.Collections.Generic;
+using SimpleBuffers;
+
+namespace SimplebuffersNested
+{
+    public sealed class OuterWriter : IWriter
+    {
+        public const int StaticSize = 6;
+
+        public uint Id { get; set; }
+        public InnerWriter Inner { get; set; }
+
+        public OuterWriter(uint id, InnerWriter inner)
+        {
+            Id = id;
+            Inner = inner;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            b.WriteU32(pos + 0, Id);
+            Inner.WriteComponent(b, b.Reserve(pos + 4, pos + 4, 4));
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+    }
+
+    public sealed class InnerWriter : IWriter
+    {
+        public const int StaticSize = 4;
+
+        public PointWriter Point { get; set; }
+        public string Label { get; set; }
+
+        public InnerWriter(PointWriter point, string label)
+        {
+            Point = point;
+            Label = label;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            Point.WriteComponent(b, b.Reserve(pos + 0, pos + 0, 4));
+            b.WriteString(pos + 2, Label);
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+    }
+
+    public sealed class PointWriter : IWriter
+    {
+        public const int StaticSize = 4;
+
+        public short X { get; set; }
+        public short Y { get; set; }
+
+        public PointWriter(short x, short y)
+        {
+            X = x;
+            Y = y;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            b.WriteI16(pos + 0, X);
+            b.WriteI16(pos + 2, Y);
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+    }
+
+    public readonly ref struct OuterReader
+    {
+        public const int StaticSize = 6;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public OuterReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public uint Id => Runtime.ReadU32(_buf, _pos + 0);
+        public InnerReader Inner => new InnerReader(_buf, Runtime.Follow(_buf, _pos + 4));
+    }
+
+    public readonly ref struct InnerReader
+    {
+        public const int StaticSize = 4;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public InnerReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public PointReader Point => new PointReader(_buf, Runtime.Follow(_buf, _pos + 0));
+        public string Label => Runtime.ReadString(_buf, _pos + 2);
+    }
+
+    public readonly ref struct PointReader
+    {
+        public const int StaticSize = 4;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public PointReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public short X => Runtime.ReadI16(_buf, _pos + 0);
+        public short Y => Runtime.ReadI16(_buf, _pos + 2);
+    }
+}
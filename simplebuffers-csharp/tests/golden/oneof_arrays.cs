@@ -0,0 +1,377 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+using System;
+using System.Collections.Generic;
+using SimpleBuffers;
+
+namespace SimplebuffersOneofArrays
+{
+    public sealed class BatchWriter : IWriter
+    {
+        public const int StaticSize = 7;
+
+        public IReadOnlyList<EventsItemOneOf> Events { get; set; }
+        public NestedOneOf Nested { get; set; }
+
+        public BatchWriter(IReadOnlyList<EventsItemOneOf> events, NestedOneOf nested)
+        {
+            Events = events;
+            Nested = nested;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            b.WriteList(pos + 0, Events, 3, (p1, e1) =>
+            {
+                e1.WriteComponent(b, p1);
+            });
+            Nested.WriteComponent(b, pos + 4);
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+
+        public abstract class EventsItemOneOf
+        {
+            private EventsItemOneOf()
+            {
+            }
+
+            public abstract void WriteComponent(Builder b, int pos);
+
+            public sealed class IdCase : EventsItemOneOf
+            {
+                public byte Value { get; set; }
+
+                public IdCase(byte value)
+                {
+                    Value = value;
+                }
+
+                public override void WriteComponent(Builder b, int pos)
+                {
+                    b.WriteU8(pos, 0);
+                    var p = b.Reserve(pos + 1, pos, 1);
+                    b.WriteU8(p, Value);
+                }
+            }
+
+            public sealed class NameCase : EventsItemOneOf
+            {
+                public string Value { get; set; }
+
+                public NameCase(string value)
+                {
+                    Value = value;
+                }
+
+                public override void WriteComponent(Builder b, int pos)
+                {
+                    b.WriteU8(pos, 1);
+                    var p = b.Reserve(pos + 1, pos, 2);
+                    b.WriteString(p, Value);
+                }
+            }
+        }
+
+        public abstract class NestedOneOf
+        {
+            private NestedOneOf()
+            {
+            }
+
+            public abstract void WriteComponent(Builder b, int pos);
+
+            public sealed class EmptyCase : NestedOneOf
+            {
+                public byte Value { get; set; }
+
+                public EmptyCase(byte value)
+                {
+                    Value = value;
+                }
+
+                public override void WriteComponent(Builder b, int pos)
+                {
+                    b.WriteU8(pos, 0);
+                    var p = b.Reserve(pos + 1, pos, 1);
+                    b.WriteU8(p, Value);
+                }
+            }
+
+            public sealed class EventsCase : NestedOneOf
+            {
+                public IReadOnlyList<NestedEventsItemOneOf> Value { get; set; }
+
+                public EventsCase(IReadOnlyList<NestedEventsItemOneOf> value)
+                {
+                    Value = value;
+                }
+
+                public override void WriteComponent(Builder b, int pos)
+                {
+                    b.WriteU8(pos, 1);
+                    var p = b.Reserve(pos + 1, pos, 4);
+                    b.WriteList(p, Value, 3, (p1, e1) =>
+                    {
+                        e1.WriteComponent(b, p1);
+                    });
+                }
+            }
+        }
+
+        public abstract class NestedEventsItemOneOf
+        {
+            private NestedEventsItemOneOf()
+            {
+            }
+
+            public abstract void WriteComponent(Builder b, int pos);
+
+            public sealed class SmallCase : NestedEventsItemOneOf
+            {
+                public byte Value { get; set; }
+
+                public SmallCase(byte value)
+                {
+                    Value = value;
+                }
+
+                public override void WriteComponent(Builder b, int pos)
+                {
+                    b.WriteU8(pos, 0);
+                    var p = b.Reserve(pos + 1, pos, 1);
+                    b.WriteU8(p, Value);
+                }
+            }
+
+            public sealed class LargeCase : NestedEventsItemOneOf
+            {
+                public ulong Value { get; set; }
+
+                public LargeCase(ulong value)
+                {
+                    Value = value;
+                }
+
+                public override void WriteComponent(Builder b, int pos)
+                {
+                    b.WriteU8(pos, 1);
+                    var p = b.Reserve(pos + 1, pos, 8);
+                    b.WriteU64(p, Value);
+                }
+            }
+        }
+    }
+
+    public readonly ref struct BatchReader
+    {
+        public const int StaticSize = 7;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public BatchReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public EventsList Events => new EventsList(_buf, _pos + 0);
+        public NestedOneOf Nested => new NestedOneOf(_buf, _pos + 4);
+
+        public readonly ref struct EventsList
+        {
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _start;
+
+            public EventsList(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                Count = Runtime.ReadU16(buf, pos);
+                _start = pos + Runtime.ReadU16(buf, pos + 2);
+            }
+
+            public int Count { get; }
+
+            public EventsItemOneOf this[int index]
+            {
+                get
+                {
+                    if ((uint)index >= (uint)Count)
+                    {
+                        throw new IndexOutOfRangeException();
+                    }
+                    var pos = _start + index * 3;
+                    return new EventsItemOneOf(_buf, pos);
+                }
+            }
+        }
+
+        public readonly ref struct EventsItemOneOf
+        {
+            public enum Variant : byte
+            {
+                Id = 0,
+                Name = 1,
+            }
+
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _pos;
+
+            public EventsItemOneOf(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                _pos = pos;
+            }
+
+            public Variant Tag => (Variant)_buf[_pos];
+
+            public bool TryGetId(out byte value)
+            {
+                if (Tag != Variant.Id)
+                {
+                    value = default;
+                    return false;
+                }
+                var pos = _pos + Runtime.ReadU16(_buf, _pos + 1);
+                value = Runtime.ReadU8(_buf, pos);
+                return true;
+            }
+
+            public bool TryGetName(out string value)
+            {
+                if (Tag != Variant.Name)
+                {
+                    value = default;
+                    return false;
+                }
+                var pos = _pos + Runtime.ReadU16(_buf, _pos + 1);
+                value = Runtime.ReadString(_buf, pos);
+                return true;
+            }
+        }
+
+        public readonly ref struct NestedOneOf
+        {
+            public enum Variant : byte
+            {
+                Empty = 0,
+                Events = 1,
+            }
+
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _pos;
+
+            public NestedOneOf(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                _pos = pos;
+            }
+
+            public Variant Tag => (Variant)_buf[_pos];
+
+            public bool TryGetEmpty(out byte value)
+            {
+                if (Tag != Variant.Empty)
+                {
+                    value = default;
+                    return false;
+                }
+                var pos = _pos + Runtime.ReadU16(_buf, _pos + 1);
+                value = Runtime.ReadU8(_buf, pos);
+                return true;
+            }
+
+            public bool TryGetEvents(out NestedEventsList value)
+            {
+                if (Tag != Variant.Events)
+                {
+                    value = default;
+                    return false;
+                }
+                var pos = _pos + Runtime.ReadU16(_buf, _pos + 1);
+                value = new NestedEventsList(_buf, pos);
+                return true;
+            }
+        }
+
+        public readonly ref struct NestedEventsList
+        {
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _start;
+
+            public NestedEventsList(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                Count = Runtime.ReadU16(buf, pos);
+                _start = pos + Runtime.ReadU16(buf, pos + 2);
+            }
+
+            public int Count { get; }
+
+            public NestedEventsItemOneOf this[int index]
+            {
+                get
+                {
+                    if ((uint)index >= (uint)Count)
+                    {
+                        throw new IndexOutOfRangeException();
+                    }
+                    var pos = _start + index * 3;
+                    return new NestedEventsItemOneOf(_buf, pos);
+                }
+            }
+        }
+
+        public readonly ref struct NestedEventsItemOneOf
+        {
+            public enum Variant : byte
+            {
+                Small = 0,
+                Large = 1,
+            }
+
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _pos;
+
+            public NestedEventsItemOneOf(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                _pos = pos;
+            }
+
+            public Variant Tag => (Variant)_buf[_pos];
+
+            public bool TryGetSmall(out byte value)
+            {
+                if (Tag != Variant.Small)
+                {
+                    value = default;
+                    return false;
+                }
+                var pos = _pos + Runtime.ReadU16(_buf, _pos + 1);
+                value = Runtime.ReadU8(_buf, pos);
+                return true;
+            }
+
+            public bool TryGetLarge(out ulong value)
+            {
+                if (Tag != Variant.Large)
+                {
+                    value = default;
+                    return false;
+                }
+                var pos = _pos + Runtime.ReadU16(_buf, _pos + 1);
+                value = Runtime.ReadU64(_buf, pos);
+                return true;
+            }
+        }
+    }
+}
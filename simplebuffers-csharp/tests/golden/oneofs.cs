@@ -0,0 +1,217 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+using System;
+using System.Collections.Generic;
+using SimpleBuffers;
+
+namespace SimplebuffersOneofs
+{
+    public sealed class RequestWriter : IWriter
+    {
+        public const int StaticSize = 5;
+
+        public ushort Id { get; set; }
+        public BodyOneOf Body { get; set; }
+
+        public RequestWriter(ushort id, BodyOneOf body)
+        {
+            Id = id;
+            Body = body;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            b.WriteU16(pos + 0, Id);
+            Body.WriteComponent(b, pos + 2);
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+
+        public abstract class BodyOneOf
+        {
+            private BodyOneOf()
+            {
+            }
+
+            public abstract void WriteComponent(Builder b, int pos);
+
+            public sealed class PingCase : BodyOneOf
+            {
+                public byte Value { get; set; }
+
+                public PingCase(byte value)
+                {
+                    Value = value;
+                }
+
+                public override void WriteComponent(Builder b, int pos)
+                {
+                    b.WriteU8(pos, 0);
+                    var p = b.Reserve(pos + 1, pos, 1);
+                    b.WriteU8(p, Value);
+                }
+            }
+
+            public sealed class EchoCase : BodyOneOf
+            {
+                public string Value { get; set; }
+
+                public EchoCase(string value)
+                {
+                    Value = value;
+                }
+
+                public override void WriteComponent(Builder b, int pos)
+                {
+                    b.WriteU8(pos, 1);
+                    var p = b.Reserve(pos + 1, pos, 2);
+                    b.WriteString(p, Value);
+                }
+            }
+
+            public sealed class TargetCase : BodyOneOf
+            {
+                public TargetWriter Value { get; set; }
+
+                public TargetCase(TargetWriter value)
+                {
+                    Value = value;
+                }
+
+                public override void WriteComponent(Builder b, int pos)
+                {
+                    b.WriteU8(pos, 2);
+                    var p = b.Reserve(pos + 1, pos, 8);
+                    Value.WriteComponent(b, p);
+                }
+            }
+        }
+    }
+
+    public sealed class TargetWriter : IWriter
+    {
+        public const int StaticSize = 8;
+
+        public float X { get; set; }
+        public float Y { get; set; }
+
+        public TargetWriter(float x, float y)
+        {
+            X = x;
+            Y = y;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            b.WriteF32(pos + 0, X);
+            b.WriteF32(pos + 4, Y);
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+    }
+
+    public readonly ref struct RequestReader
+    {
+        public const int StaticSize = 5;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public RequestReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public ushort Id => Runtime.ReadU16(_buf, _pos + 0);
+        public BodyOneOf Body => new BodyOneOf(_buf, _pos + 2);
+
+        public readonly ref struct BodyOneOf
+        {
+            public enum Variant : byte
+            {
+                Ping = 0,
+                Echo = 1,
+                Target = 2,
+            }
+
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _pos;
+
+            public BodyOneOf(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                _pos = pos;
+            }
+
+            public Variant Tag => (Variant)_buf[_pos];
+
+            public bool TryGetPing(out byte value)
+            {
+                if (Tag != Variant.Ping)
+                {
+                    value = default;
+                    return false;
+                }
+                var pos = _pos + Runtime.ReadU16(_buf, _pos + 1);
+                value = Runtime.ReadU8(_buf, pos);
+                return true;
+            }
+
+            public bool TryGetEcho(out string value)
+            {
+                if (Tag != Variant.Echo)
+                {
+                    value = default;
+                    return false;
+                }
+                var pos = _pos + Runtime.ReadU16(_buf, _pos + 1);
+                value = Runtime.ReadString(_buf, pos);
+                return true;
+            }
+
+            public bool TryGetTarget(out TargetReader value)
+            {
+                if (Tag != Variant.Target)
+                {
+                    value = default;
+                    return false;
+                }
+                var pos = _pos + Runtime.ReadU16(_buf, _pos + 1);
+                value = new TargetReader(_buf, pos);
+                return true;
+            }
+        }
+    }
+
+    public readonly ref struct TargetReader
+    {
+        public const int StaticSize = 8;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public TargetReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public float X => Runtime.ReadF32(_buf, _pos + 0);
+        public float Y => Runtime.ReadF32(_buf, _pos + 4);
+    }
+}
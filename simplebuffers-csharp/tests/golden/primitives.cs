@@ -0,0 +1,94 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+using System;
+using System.Collections.Generic;
+using SimpleBuffers;
+
+namespace SimplebuffersPrimitives
+{
+    public sealed class PrimitivesWriter : IWriter
+    {
+        public const int StaticSize = 45;
+
+        public byte A { get; set; }
+        public ushort B { get; set; }
+        public uint C { get; set; }
+        public ulong D { get; set; }
+        public sbyte E { get; set; }
+        public short F { get; set; }
+        public int G { get; set; }
+        public long H { get; set; }
+        public float I { get; set; }
+        public double J { get; set; }
+        public bool K { get; set; }
+        public string Name { get; set; }
+
+        public PrimitivesWriter(byte a, ushort b, uint c, ulong d, sbyte e, short f, int g, long h, float i, double j, bool k, string name)
+        {
+            A = a;
+            B = b;
+            C = c;
+            D = d;
+            E = e;
+            F = f;
+            G = g;
+            H = h;
+            I = i;
+            J = j;
+            K = k;
+            Name = name;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            b.WriteU8(pos + 0, A);
+            b.WriteU16(pos + 1, B);
+            b.WriteU32(pos + 3, C);
+            b.WriteU64(pos + 7, D);
+            b.WriteI8(pos + 15, E);
+            b.WriteI16(pos + 16, F);
+            b.WriteI32(pos + 18, G);
+            b.WriteI64(pos + 22, H);
+            b.WriteF32(pos + 30, I);
+            b.WriteF64(pos + 34, J);
+            b.WriteBool(pos + 42, K);
+            b.WriteString(pos + 43, Name);
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+    }
+
+    public readonly ref struct PrimitivesReader
+    {
+        public const int StaticSize = 45;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public PrimitivesReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public byte A => Runtime.ReadU8(_buf, _pos + 0);
+        public ushort B => Runtime.ReadU16(_buf, _pos + 1);
+        public uint C => Runtime.ReadU32(_buf, _pos + 3);
+        public ulong D => Runtime.ReadU64(_buf, _pos + 7);
+        public sbyte E => Runtime.ReadI8(_buf, _pos + 15);
+        public short F => Runtime.ReadI16(_buf, _pos + 16);
+        public int G => Runtime.ReadI32(_buf, _pos + 18);
+        public long H => Runtime.ReadI64(_buf, _pos + 22);
+        public float I => Runtime.ReadF32(_buf, _pos + 30);
+        public double J => Runtime.ReadF64(_buf, _pos + 34);
+        public bool K => Runtime.ReadBool(_buf, _pos + 42);
+        public string Name => Runtime.ReadString(_buf, _pos + 43);
+    }
+}
@@ -0,0 +1,101 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+using System;
+using System.Collections.Generic;
+using SimpleBuffers;
+
+namespace SimplebuffersSignedEnums
+{
+    public enum Temp : sbyte
+    {
+        Freezing = -40,
+        Zero = 0,
+    }
+
+    public enum Offset : int
+    {
+        Behind = -100000,
+        Ahead = 100000,
+    }
+
+    public sealed class ReadingWriter : IWriter
+    {
+        public const int StaticSize = 9;
+
+        public Temp Temp { get; set; }
+        public Offset Offset { get; set; }
+        public IReadOnlyList<Temp> History { get; set; }
+
+        public ReadingWriter(Temp temp, Offset offset, IReadOnlyList<Temp> history)
+        {
+            Temp = temp;
+            Offset = offset;
+            History = history;
+        }
+
+        int IWriter.StaticSize => StaticSize;
+
+        public void WriteComponent(Builder b, int pos)
+        {
+            b.WriteI8(pos + 0, (sbyte)Temp);
+            b.WriteI32(pos + 1, (int)Offset);
+            b.WriteList(pos + 5, History, 1, (p1, e1) =>
+            {
+                b.WriteI8(p1, (sbyte)e1);
+            });
+        }
+
+        public byte[] Encode()
+        {
+            var b = new Builder(StaticSize);
+            WriteComponent(b, 0);
+            return b.ToArray();
+        }
+    }
+
+    public readonly ref struct ReadingReader
+    {
+        public const int StaticSize = 9;
+
+        private readonly ReadOnlySpan<byte> _buf;
+        private readonly int _pos;
+
+        public ReadingReader(ReadOnlySpan<byte> buf, int pos = 0)
+        {
+            _buf = buf;
+            _pos = pos;
+        }
+
+        public Temp Temp => (Temp)Runtime.ReadI8(_buf, _pos + 0);
+        public Offset Offset => (Offset)Runtime.ReadI32(_buf, _pos + 1);
+        public HistoryList History => new HistoryList(_buf, _pos + 5);
+
+        public readonly ref struct HistoryList
+        {
+            private readonly ReadOnlySpan<byte> _buf;
+            private readonly int _start;
+
+            public HistoryList(ReadOnlySpan<byte> buf, int pos)
+            {
+                _buf = buf;
+                Count = Runtime.ReadU16(buf, pos);
+                _start = pos + Runtime.ReadU16(buf, pos + 2);
+            }
+
+            public int Count { get; }
+
+            public Temp this[int index]
+            {
+                get
+                {
+                    if ((uint)index >= (uint)Count)
+                    {
+                        throw new IndexOutOfRangeException();
+                    }
+                    var pos = _start + index * 1;
+                    return (Temp)Runtime.ReadI8(_buf, pos);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Generates a Graphviz graph.
+
+use indent::indent_by;
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{walk_types, Enum, Field, SBSchema, Sequence, Type};
+
+/// Generates a Graphviz graph from a given SimpleBuffers schema.
+///
+/// # Arguments
+///
+/// * `schema` - The schema to generate from.
+/// * `name` - The name of the graph.
+///
+/// # Returns
+///
+/// The graph, in the DOT language, as a String.
+pub(crate) fn generate_dot(schema: &SBSchema, name: &str) -> String {
+    // Edges are collected separately and written after every node. Graphviz adds the endpoints of
+    // an edge to the subgraph the edge is written in, so an edge written inside a oneof cluster
+    // would pull the sequence it points to into that cluster.
+    let mut edges = vec![];
+    let nodes = schema
+        .enums
+        .iter()
+        .map(define_enum)
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| define_sequence(s, &mut edges)),
+        )
+        .collect_vec();
+
+    let body = [nodes.join("\n"), edges.join("\n")]
+        .into_iter()
+        .filter(|section| !section.is_empty())
+        .join("\n\n");
+
+    formatdoc! {
+        r#"
+        // This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+        digraph {name} {{
+            rankdir=LR;
+            compound=true;
+            node [shape=record, fontname="Helvetica"];
+            edge [fontname="Helvetica", fontsize=10];
+
+            {body}
+        }}
+        "#,
+        name = quote(name),
+        body = indent_by(4, body)
+    }
+}
+
+/// Quotes a string as a DOT identifier.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Returns the attributes shared by every node, which is currently only a tooltip with the doc
+/// comment.
+fn doc_attributes(doc: &Option<String>) -> String {
+    match doc {
+        Some(doc) => format!(", tooltip={}", quote(doc)),
+        None => String::new(),
+    }
+}
+
+/// Returns how a type is shown in a node. Oneofs are drawn as clusters, so their fields are left
+/// out. Angle brackets mark ports in record labels, so the ones around map types are escaped.
+fn type_label(ty: &Type) -> String {
+    match ty {
+        Type::Array(t) => format!("[{}]", type_label(t)),
+        Type::FixedArray(t, len) => format!("[{}; {}]", type_label(t), len),
+        Type::Map(k, v) => format!("map\\<{}, {}\\>", k, type_label(v)),
+        Type::OneOf(_) => "oneof".to_string(),
+        _ => ty.to_string(),
+    }
+}
+
+/// Returns the label of a field, as shown in a node.
+fn field_label(field: &Field) -> String {
+    format!("{}: {}", field.name, type_label(&field.ty))
+}
+
+/// Generates the node for an enum, which lists its variants.
+fn define_enum(data: &Enum) -> String {
+    let variants = data
+        .variants
+        .iter()
+        .map(|v| format!("{} = {}\\l", v.name, v.value))
+        .join("");
+
+    format!(
+        "{} [label=\"{{enum {}|{}}}\", style=filled, fillcolor=\"#fff2cc\"{}];",
+        quote(&data.name),
+        data.name,
+        variants,
+        doc_attributes(&data.doc)
+    )
+}
+
+/// Generates the node for a sequence, which lists its fields, followed by the clusters of its
+/// oneofs. The edges that leave the sequence are added to `edges`.
+fn define_sequence(seq: &Sequence, edges: &mut Vec<String>) -> String {
+    let fields = seq
+        .fields
+        .iter()
+        .map(|f| format!("{}\\l", field_label(f)))
+        .join("");
+    let label = if fields.is_empty() {
+        seq.name.clone()
+    } else {
+        format!("{{{}|{}}}", seq.name, fields)
+    };
+
+    let mut lines = vec![format!(
+        "{} [label=\"{}\"{}];",
+        quote(&seq.name),
+        label,
+        doc_attributes(&seq.doc)
+    )];
+    for f in &seq.fields {
+        lines.extend(define_field(&seq.name, f, &seq.name, edges));
+    }
+    lines.join("\n")
+}
+
+/// Finds the sequences, enums, and oneofs that a field refers to. The sequences and enums become
+/// edges from `from`, and a oneof becomes a cluster with one node per oneof field.
+///
+/// # Arguments
+///
+/// * `from` - The node that the field belongs to.
+/// * `field` - The field.
+/// * `path` - The path used to name nodes nested in the field.
+/// * `edges` - The list to add edges to.
+///
+/// # Returns
+///
+/// The lines that define the field's cluster, if it has one.
+fn define_field(from: &str, field: &Field, path: &str, edges: &mut Vec<String>) -> Vec<String> {
+    let mut oneof = None;
+    walk_types(&field.ty, &mut |ty| {
+        // The types inside a oneof belong to its fields, which are handled below.
+        if oneof.is_some() {
+            return;
+        }
+        match ty {
+            Type::Sequence(name) | Type::Enum(name, _) => edges.push(format!(
+                "{} -> {} [label={}];",
+                quote(from),
+                quote(name),
+                quote(&field.name)
+            )),
+            Type::OneOf(subfields) => oneof = Some(subfields.clone()),
+            _ => {}
+        }
+    });
+    let Some(subfields) = oneof else {
+        return vec![];
+    };
+
+    let path = format!("{}.{}", path, field.name);
+    let cluster = format!("cluster_{}", path);
+    // Graphviz does not draw empty clusters, so an empty oneof gets a placeholder node. The edge to
+    // the cluster points at its first node and is clipped at the cluster's border.
+    let mut lines = vec![];
+    let target = match subfields.first() {
+        Some(f) => format!("{}.{}", path, f.name),
+        None => {
+            lines.push(format!(
+                "{} [label=\"empty\", shape=plaintext];",
+                quote(&path)
+            ));
+            path.clone()
+        }
+    };
+    edges.push(format!(
+        "{} -> {} [label={}, lhead={}, style=dashed];",
+        quote(from),
+        quote(&target),
+        quote(&field.name),
+        quote(&cluster)
+    ));
+
+    for f in &subfields {
+        let node = format!("{}.{}", path, f.name);
+        lines.push(format!(
+            "{} [label=\"{}\"{}];",
+            quote(&node),
+            field_label(f),
+            doc_attributes(&f.doc)
+        ));
+        lines.extend(define_field(&node, f, &path, edges));
+    }
+
+    let cluster_lines = formatdoc! {
+        r#"
+        subgraph {cluster} {{
+            label={label};
+            style=dashed;
+            {lines}
+        }}"#,
+        cluster = quote(&cluster),
+        label = quote(&field_label(field)),
+        lines = indent_by(4, lines.join("\n"))
+    };
+    vec![cluster_lines]
+}
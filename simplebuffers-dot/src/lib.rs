@@ -0,0 +1,54 @@
+//! Graphviz schema visualizer.
+//!
+//! This module writes a Graphviz graph (`{file_name}.dot`) that shows how the parts of a schema
+//! refer to each other. It does not generate any serialization code. The graph can be rendered with
+//! `dot -Tpng {file_name}.dot -o {file_name}.png`.
+//!
+//! - Every sequence and enum becomes a node that lists its fields or variants.
+//! - Every field that refers to a sequence or an enum, directly or through an array, becomes an
+//!   edge labeled with the field name.
+//! - Every oneof becomes a cluster with one node per oneof field. The oneof fields are connected to
+//!   the sequences and enums they refer to in the same way.
+//!
+//! Every identifier in the graph is quoted, so no names are reserved.
+
+mod dotgen;
+
+use std::path::PathBuf;
+
+use dotgen::generate_dot;
+use simplebuffers_codegen::{register_generators, CodeGenerator, FileSink};
+
+#[derive(Debug)]
+pub struct DotCodeGenerator;
+
+impl CodeGenerator for DotCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        let path = PathBuf::from(&params.dest_dir).join(format!("{}.dot", params.file_name));
+        sink.write_file(&path, &generate_dot(schema, &params.file_name))?;
+
+        Ok(vec![path])
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["dot".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "Graphviz diagram of the schema".to_string()
+    }
+}
+
+register_generators!(dot: DotCodeGenerator);
@@ -0,0 +1,284 @@
+//! Generates a FlatBuffers schema.
+
+use std::collections::HashSet;
+
+use convert_case::{Case, Casing};
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+
+/// Generates a FlatBuffers schema from a given SimpleBuffers schema.
+///
+/// # Arguments
+///
+/// * `schema` - The schema to generate from.
+///
+/// # Returns
+///
+/// The FlatBuffers schema, as a String.
+pub(crate) fn generate_fbs(schema: &SBSchema) -> String {
+    let declarations = schema
+        .enums
+        .iter()
+        .map(define_enum)
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .flat_map(|s| define_sequence(schema, s)),
+        )
+        .join("\n\n");
+
+    formatdoc! {
+        r"
+        // This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+        {declarations}
+
+        {roots}
+        ",
+        roots = root_candidates(schema)
+    }
+}
+
+/// Returns the FlatBuffers type that matches a primitive.
+fn primitive_type(p: &Primitive) -> &'static str {
+    match p {
+        Primitive::Bool => "bool",
+        Primitive::U8 => "ubyte",
+        Primitive::U16 => "ushort",
+        Primitive::U32 => "uint",
+        Primitive::U64 => "ulong",
+        Primitive::I8 => "byte",
+        Primitive::I16 => "short",
+        Primitive::I32 => "int",
+        Primitive::I64 => "long",
+        Primitive::F32 => "float",
+        Primitive::F64 => "double",
+    }
+}
+
+/// Returns the FlatBuffers base type of an enum with the given size and signedness.
+fn enum_base_type(size: usize, signed: bool) -> &'static str {
+    match (size, signed) {
+        (1, false) => "ubyte",
+        (2, false) => "ushort",
+        (4, false) => "uint",
+        (8, false) => "ulong",
+        (1, true) => "byte",
+        (2, true) => "short",
+        (4, true) => "int",
+        (8, true) => "long",
+        _ => panic!("Cannot convert size {} to FlatBuffers type", size),
+    }
+}
+
+/// Returns the path used to name the types nested in a field.
+fn field_path(prefix: &str, field_name: &str) -> String {
+    format!("{}{}", prefix, field_name.to_case(Case::Pascal))
+}
+
+/// Returns the FlatBuffers type of a field, or `None` if FlatBuffers cannot represent it. `path`
+/// names the unions nested in the field.
+fn field_type(ty: &Type, path: &str) -> Option<String> {
+    match ty {
+        Type::Primitive(p) => Some(primitive_type(p).to_string()),
+        Type::Sequence(s) => Some(s.to_case(Case::Pascal)),
+        Type::Enum(e, _) => Some(e.to_case(Case::Pascal)),
+        // Vectors cannot hold vectors, and byte buffers are written as vectors.
+        Type::Array(t) => match t.as_ref() {
+            Type::Array(_) | Type::Bytes => None,
+            _ => field_type(t, &format!("{path}Item")).map(|t| format!("[{t}]")),
+        },
+        // Tables cannot hold fixed-size arrays, so they are written as vectors.
+        Type::FixedArray(t, _) => field_type(t, &format!("{path}Item")).map(|t| format!("[{t}]")),
+        Type::String => Some("string".to_string()),
+        Type::Bytes => Some("[ubyte]".to_string()),
+        // FlatBuffers has no maps.
+        Type::Map(_, _) => None,
+        Type::OneOf(_) => Some(path.to_string()),
+    }
+}
+
+/// Returns the text used to describe a type in comments.
+fn describe_type(ty: &Type) -> String {
+    match ty {
+        Type::Primitive(p) => primitive_type(p).to_string(),
+        Type::Sequence(s) | Type::Enum(s, _) => s.clone(),
+        Type::Array(t) => format!("[{}]", describe_type(t)),
+        Type::FixedArray(t, len) => format!("[{}; {}]", describe_type(t), len),
+        Type::Map(k, v) => format!("map<{}, {}>", describe_type(k), describe_type(v)),
+        Type::String => "string".to_string(),
+        Type::Bytes => "bytes".to_string(),
+        Type::OneOf(_) => "oneof".to_string(),
+    }
+}
+
+/// Generates the fields of a table.
+fn define_fields(schema: &SBSchema, fields: &[Field], path: &str) -> String {
+    fields
+        .iter()
+        .map(|f| {
+            let name = f.name.to_case(Case::Snake);
+            match field_type(&f.ty, &field_path(path, &f.name)) {
+                Some(ty) => format!("  {name}: {ty}{};", enum_default(schema, &f.ty)),
+                None => format!(
+                    "  // TODO: {name}: {} has no FlatBuffers equivalent (nested vectors and maps \
+                     are not supported).",
+                    describe_type(&f.ty)
+                ),
+            }
+        })
+        .join("\n")
+}
+
+/// Returns the default value for a field of the given type. FlatBuffers fields default to zero, so
+/// enum fields need an explicit default if zero is not one of the enum's values.
+fn enum_default(schema: &SBSchema, ty: &Type) -> String {
+    let Type::Enum(name, _) = ty else {
+        return String::new();
+    };
+    let Some(data) = schema.enums.iter().find(|e| &e.name == name) else {
+        return String::new();
+    };
+    if data.variants.iter().any(|v| v.value == 0) {
+        return String::new();
+    }
+    match data.variants.iter().min_by_key(|v| v.value) {
+        Some(v) => format!(" = {}", v.name.to_case(Case::Pascal)),
+        None => String::new(),
+    }
+}
+
+/// Generates the FlatBuffers code for defining an enum. FlatBuffers requires enum values to be
+/// listed in ascending order.
+fn define_enum(data: &Enum) -> String {
+    let variants = data
+        .variants
+        .iter()
+        .sorted_by_key(|v| v.value)
+        .map(|v| format!("  {} = {}", v.name.to_case(Case::Pascal), v.value))
+        .join(",\n");
+
+    formatdoc! {
+        r"
+        enum {name} : {base} {{
+        {variants}
+        }}",
+        name = data.name.to_case(Case::Pascal),
+        base = enum_base_type(data.size.into(), data.is_signed())
+    }
+}
+
+/// Generates the FlatBuffers code for defining a sequence, preceded by the unions nested in its
+/// fields.
+fn define_sequence(schema: &SBSchema, seq: &Sequence) -> Vec<String> {
+    let name = seq.name.to_case(Case::Pascal);
+    let mut declarations = vec![];
+    for f in &seq.fields {
+        declarations.extend(nested_unions(schema, &f.ty, &field_path(&name, &f.name)));
+    }
+
+    declarations.push(formatdoc! {
+        r"
+        table {name} {{
+        {fields}
+        }}",
+        fields = define_fields(schema, &seq.fields, &name)
+    });
+    declarations
+}
+
+/// Generates the unions (and their wrapper tables) nested in a type.
+fn nested_unions(schema: &SBSchema, ty: &Type, path: &str) -> Vec<String> {
+    match ty {
+        Type::Array(t) => nested_unions(schema, t, &format!("{path}Item")),
+        Type::OneOf(subfields) => {
+            let mut declarations = vec![];
+            for f in subfields {
+                declarations.extend(nested_unions(schema, &f.ty, &field_path(path, &f.name)));
+                if !matches!(f.ty, Type::Sequence(_)) {
+                    declarations.push(formatdoc! {
+                        r"
+                        table {path}{case}Case {{
+                        {fields}
+                        }}",
+                        case = f.name.to_case(Case::Pascal),
+                        fields = define_fields(schema, std::slice::from_ref(f), path)
+                    });
+                }
+            }
+            declarations.push(define_union(path, subfields));
+            declarations
+        }
+        _ => vec![],
+    }
+}
+
+/// Generates the FlatBuffers code for defining a union from a oneof. Sequences are used directly,
+/// and other types use the wrapper tables generated by `nested_unions`.
+fn define_union(path: &str, subfields: &[Field]) -> String {
+    let members = subfields
+        .iter()
+        .map(|f| {
+            let case = f.name.to_case(Case::Pascal);
+            match &f.ty {
+                Type::Sequence(s) => format!("  {case}: {}", s.to_case(Case::Pascal)),
+                _ => format!("  {case}: {path}{case}Case"),
+            }
+        })
+        .join(",\n");
+
+    formatdoc! {
+        r"
+        union {path} {{
+        {members}
+        }}"
+    }
+}
+
+/// Adds the names of all sequences used by a type to `used`.
+fn collect_used_sequences<'a>(ty: &'a Type, used: &mut HashSet<&'a str>) {
+    match ty {
+        Type::Sequence(s) => {
+            used.insert(s);
+        }
+        Type::Array(t) | Type::Map(_, t) => collect_used_sequences(t, used),
+        Type::OneOf(subfields) => {
+            for f in subfields {
+                collect_used_sequences(&f.ty, used);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Returns a comment that lists the sequences that could be used as the root type. These are the
+/// sequences that are not used by any other sequence.
+fn root_candidates(schema: &SBSchema) -> String {
+    let mut used = HashSet::new();
+    for seq in &schema.sequences {
+        for f in &seq.fields {
+            collect_used_sequences(&f.ty, &mut used);
+        }
+    }
+
+    let candidates = schema
+        .sequences
+        .iter()
+        .filter(|s| !used.contains(s.name.as_str()))
+        .map(|s| format!("// root_type {};", s.name.to_case(Case::Pascal)))
+        .join("\n");
+
+    if candidates.is_empty() {
+        "// SimpleBuffers has no root type, and every sequence is used by another sequence."
+            .to_string()
+    } else {
+        formatdoc! {
+            r"
+            // SimpleBuffers has no root type. Uncomment one of these candidates (sequences that are
+            // not used by any other sequence):
+            {candidates}"
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! FlatBuffers schema generator.
+//!
+//! This module translates a SimpleBuffers schema into a FlatBuffers schema (`{file_name}.fbs`),
+//! which can be used to compare the two formats or to migrate to FlatBuffers.
+//!
+//! - Every sequence becomes a `table`.
+//! - Every enum becomes an `enum` whose base type matches the enum's size.
+//! - Every oneof becomes a `union`. Oneof fields that are not sequences are wrapped in a table,
+//!   since FlatBuffers unions can only hold tables.
+//! - Arrays become vectors and strings become `string`.
+//!
+//! SimpleBuffers has no explicit root type, so the candidates are listed in a comment. Types that
+//! FlatBuffers cannot represent (such as nested vectors and maps) produce a `// TODO` comment
+//! instead of an error.
+
+mod fbsgen;
+
+use std::path::PathBuf;
+
+use fbsgen::generate_fbs;
+use simplebuffers_codegen::{register_generator, CodeGenerator, FileSink};
+
+#[derive(Debug)]
+pub struct FlatBuffersCodeGenerator;
+
+impl CodeGenerator for FlatBuffersCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        let path = PathBuf::from(&params.dest_dir).join(format!("{}.fbs", params.file_name));
+        sink.write_file(&path, &generate_fbs(schema))?;
+
+        Ok(vec![path])
+    }
+
+    fn reserved_identifiers(
+        &mut self,
+        _params: &simplebuffers_codegen::GeneratorParams,
+    ) -> Vec<String> {
+        [
+            // Keywords.
+            "attribute",
+            "enum",
+            "file_extension",
+            "file_identifier",
+            "include",
+            "namespace",
+            "native_include",
+            "root_type",
+            "rpc_service",
+            "struct",
+            "table",
+            "union",
+            "true",
+            "false",
+            // Scalar types.
+            "bool",
+            "byte",
+            "ubyte",
+            "short",
+            "ushort",
+            "int",
+            "uint",
+            "long",
+            "ulong",
+            "float",
+            "double",
+            "int8",
+            "uint8",
+            "int16",
+            "uint16",
+            "int32",
+            "uint32",
+            "int64",
+            "uint64",
+            "float32",
+            "float64",
+            "string",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["fbs".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "FlatBuffers schema".to_string()
+    }
+}
+
+register_generator!(flatbuffers: FlatBuffersCodeGenerator);
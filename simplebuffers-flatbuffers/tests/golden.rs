@@ -0,0 +1,11 @@
+//! Golden tests for the FlatBuffers generator. See `simplebuffers-golden` for how they work.
+
+use simplebuffers_flatbuffers::FlatBuffersCodeGenerator;
+use simplebuffers_golden::{golden_tests, Layout};
+
+golden_tests! {
+    generator: FlatBuffersCodeGenerator,
+    args: ["flatbuffers"],
+    layout: Layout::Flat,
+    cases: [primitives, enums, nested, oneofs, arrays, oneof_arrays, signed_enums, bytes, maps],
+}
@@ -0,0 +1,209 @@
+//! Golden tests for code generators.
+//!
+//! Each schema in a generator crate's `tests/golden` directory (`<name>.sb`) is run through the
+//! generator, and every generated file is compared against a checked-in copy. Files are generated
+//! in memory, so nothing is written outside of `tests/golden`. The tests themselves are declared
+//! with [golden_tests].
+//!
+//! After an intended change to the generated code, run the tests with `UPDATE_SNAPSHOTS=1` to
+//! regenerate the expected files, and review the differences before committing them.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[doc(hidden)]
+pub use simplebuffers_codegen::CodeGenerator;
+use simplebuffers_codegen::{GeneratorParams, MemorySink};
+use simplebuffers_compiler::compile_str;
+
+/// The directory that generated files are placed in. Nothing is written there.
+const DEST_DIR: &str = "generated";
+
+/// Where the expected files of a schema are kept.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Layout {
+    /// Next to the schema, as `tests/golden/<name>.<extension>`. Only files named after the schema
+    /// are compared, so a runtime that is the same for every schema is not checked in once per
+    /// schema.
+    Flat,
+
+    /// In a directory named after the schema, as `tests/golden/<name>/<file>`. This suits
+    /// generators that write a file per type or a shared runtime. The directory must hold exactly
+    /// the generated files.
+    Directory,
+}
+
+/// Declares a golden test for each of the named schemas in `tests/golden`.
+///
+/// # Example
+///
+/// ```ignore
+/// simplebuffers_golden::golden_tests! {
+///     generator: CPPCodeGenerator,
+///     args: ["cpp"],
+///     layout: Layout::Flat,
+///     cases: [primitives, enums],
+/// }
+/// ```
+///
+/// `args` are passed to the generator as [GeneratorParams::additional_args], and each case is the
+/// name of a schema, without its `.sb` extension.
+#[macro_export]
+macro_rules! golden_tests {
+    (
+        generator: $generator:ty,
+        args: $args:expr,
+        layout: $layout:expr,
+        cases: [$($case:ident),* $(,)?] $(,)?
+    ) => {
+        $(
+            #[test]
+            fn $case() {
+                $crate::check_snapshot(
+                    &mut <$generator as $crate::CodeGenerator>::new(),
+                    &$args,
+                    $layout,
+                    ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+                    stringify!($case),
+                );
+            }
+        )*
+    };
+}
+
+/// Generates the files for `tests/golden/<name>.sb`, and compares them against the expected files.
+/// With `UPDATE_SNAPSHOTS=1`, the expected files are replaced instead.
+///
+/// # Panics
+///
+/// If the schema cannot be compiled or generated, or if a generated file differs from the
+/// expected one.
+pub fn check_snapshot(
+    generator: &mut dyn CodeGenerator,
+    args: &[&str],
+    layout: Layout,
+    manifest_dir: &Path,
+    name: &str,
+) {
+    let dir = manifest_dir.join("tests").join("golden");
+    let source = fs::read_to_string(dir.join(format!("{}.sb", name))).unwrap();
+    let schema = compile_str(&source, &format!("{}.sb", name))
+        .unwrap_or_else(|e| panic!("failed to compile {}.sb:\n{}", name, e));
+
+    let params = GeneratorParams {
+        file_name: name.to_string(),
+        dest_dir: DEST_DIR.to_string(),
+        additional_args: args.iter().map(|arg| arg.to_string()).collect(),
+        relax_reserved: false,
+        source: Some(source),
+    };
+    let mut sink = MemorySink::new();
+    generator
+        .generate(&schema, &params, &mut sink)
+        .unwrap_or_else(|e| panic!("failed to generate {}.sb: {}", name, e));
+    let generated: BTreeMap<PathBuf, String> = sink
+        .files
+        .into_iter()
+        .map(|(path, contents)| (path.strip_prefix(DEST_DIR).unwrap().to_path_buf(), contents))
+        .filter(|(path, _)| {
+            layout == Layout::Directory || path.file_stem().is_some_and(|stem| stem == name)
+        })
+        .collect();
+
+    let expected_dir = match layout {
+        Layout::Flat => dir,
+        Layout::Directory => dir.join(name),
+    };
+    if update_snapshots() {
+        if layout == Layout::Directory && expected_dir.exists() {
+            fs::remove_dir_all(&expected_dir).unwrap();
+        }
+        for (path, contents) in &generated {
+            let path = expected_dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        return;
+    }
+
+    if layout == Layout::Directory {
+        assert_eq!(
+            generated.keys().collect::<Vec<_>>(),
+            read_files(&expected_dir).iter().collect::<Vec<_>>(),
+            "{}.sb did not generate the expected files. Run with UPDATE_SNAPSHOTS=1 to update the \
+             expected output.",
+            name
+        );
+    }
+    for (path, generated) in &generated {
+        let expected_path = expected_dir.join(path);
+        let expected = fs::read_to_string(&expected_path).unwrap_or_else(|_| {
+            panic!(
+                "{} is missing; run with UPDATE_SNAPSHOTS=1 to create it",
+                expected_path.display()
+            )
+        });
+        if let Some((line, expected_line, generated_line)) = first_difference(&expected, generated)
+        {
+            panic!(
+                "{} does not match the expected output at line {}:\n  expected: {}\n  \
+                 generated: {}\nRun with UPDATE_SNAPSHOTS=1 to update the expected output.",
+                expected_path.display(),
+                line,
+                expected_line,
+                generated_line
+            );
+        }
+    }
+}
+
+/// Returns whether the expected files should be regenerated instead of compared.
+fn update_snapshots() -> bool {
+    env::var_os("UPDATE_SNAPSHOTS").is_some_and(|value| value == "1")
+}
+
+/// Returns the paths of the files under `dir`, relative to it, in sorted order.
+fn read_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(current) = dirs.pop() {
+        let Ok(entries) = fs::read_dir(&current) else {
+            continue;
+        };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path.strip_prefix(dir).unwrap().to_path_buf());
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Returns the first line (1-indexed) where two files differ, along with the line in each, or
+/// `None` if they are the same.
+fn first_difference<'a>(
+    expected: &'a str,
+    generated: &'a str,
+) -> Option<(usize, &'a str, &'a str)> {
+    if expected == generated {
+        return None;
+    }
+    let end = std::iter::repeat("<end of file>");
+    let lines = expected.lines().count().max(generated.lines().count());
+    let (line, (expected_line, generated_line)) = expected
+        .lines()
+        .chain(end.clone())
+        .zip(generated.lines().chain(end))
+        .take(lines + 1)
+        .enumerate()
+        .find(|(_, (e, g))| e != g)
+        // The files only differ in line endings or a trailing newline.
+        .unwrap_or((expected.lines().count(), ("", "")));
+    Some((line + 1, expected_line, generated_line))
+}
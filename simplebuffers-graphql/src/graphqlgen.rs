@@ -0,0 +1,193 @@
+//! Generates a GraphQL schema.
+
+use convert_case::{Case, Casing};
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+
+/// The built-in scalars and root operation types, which the types of a schema cannot be named.
+pub(crate) const BUILT_IN_TYPES: [&str; 7] = [
+    "Int",
+    "Float",
+    "String",
+    "Boolean",
+    "Query",
+    "Mutation",
+    "Subscription",
+];
+
+/// Generates a GraphQL schema from a given SimpleBuffers schema.
+///
+/// # Arguments
+///
+/// * `schema` - The schema to generate from.
+///
+/// # Returns
+///
+/// The GraphQL schema, as a String.
+pub(crate) fn generate_graphql(schema: &SBSchema) -> String {
+    let declarations = schema
+        .enums
+        .iter()
+        .map(define_enum)
+        .chain(schema.sequences.iter().flat_map(define_sequence))
+        .join("\n\n");
+
+    formatdoc! {
+        r"
+        # This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+        {declarations}
+        "
+    }
+}
+
+/// Returns the GraphQL name of a sequence or enum. Reserved identifiers are matched exactly, so a
+/// name that only becomes a built-in type in Pascal case (such as `string`) is escaped with a
+/// trailing `_`.
+fn type_name(name: &str) -> String {
+    let name = name.to_case(Case::Pascal);
+    if BUILT_IN_TYPES.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+/// Returns the GraphQL type that matches a primitive, and a comment if the primitive does not fit
+/// in that type.
+fn primitive_type(p: &Primitive) -> (&'static str, Option<&'static str>) {
+    match p {
+        Primitive::Bool => ("Boolean", None),
+        Primitive::U8 | Primitive::U16 | Primitive::I8 | Primitive::I16 | Primitive::I32 => {
+            ("Int", None)
+        }
+        Primitive::U32 => (
+            "String",
+            Some("u32 does not fit in Int; encoded as a decimal string"),
+        ),
+        Primitive::U64 => (
+            "String",
+            Some("u64 does not fit in Int; encoded as a decimal string"),
+        ),
+        Primitive::I64 => (
+            "String",
+            Some("i64 does not fit in Int; encoded as a decimal string"),
+        ),
+        Primitive::F32 | Primitive::F64 => ("Float", None),
+    }
+}
+
+/// Returns the name of the GraphQL type for a field. `path` names the unions nested in the field.
+///
+/// Returns the type and an optional comment explaining how the value is encoded.
+fn field_type(ty: &Type, path: &str) -> (String, Option<&'static str>) {
+    match ty {
+        Type::Primitive(p) => {
+            let (name, comment) = primitive_type(p);
+            (format!("{name}!"), comment)
+        }
+        Type::Sequence(s) => (format!("{}!", type_name(s)), None),
+        Type::Enum(e, _) => (format!("{}!", type_name(e)), None),
+        Type::Array(t) | Type::FixedArray(t, _) => {
+            let (inner, comment) = field_type(t, &format!("{path}Item"));
+            (format!("[{inner}]!"), comment)
+        }
+        Type::String => ("String!".to_string(), None),
+        Type::Bytes => (
+            "String!".to_string(),
+            Some("bytes do not fit in a GraphQL scalar; encoded as a base64 string"),
+        ),
+        // Oneofs are nullable, since the tag in a message may not match any known field.
+        Type::OneOf(_) => (path.to_string(), None),
+        Type::Map(_, _) => unreachable!("maps are rejected before generation"),
+    }
+}
+
+/// Generates the GraphQL fields of an object type.
+fn define_fields(fields: &[Field], path: &str) -> String {
+    fields
+        .iter()
+        .map(|f| {
+            let (ty, comment) = field_type(&f.ty, &field_path(path, &f.name));
+            let field = format!("  {}: {}", f.name.to_case(Case::Camel), ty);
+            match comment {
+                Some(c) => format!("  # {c}\n{field}"),
+                None => field,
+            }
+        })
+        .join("\n")
+}
+
+/// Returns the path used to name the unions nested in a field.
+fn field_path(prefix: &str, field_name: &str) -> String {
+    format!("{}{}", prefix, field_name.to_case(Case::Pascal))
+}
+
+/// Generates the GraphQL code for defining an enum.
+fn define_enum(data: &Enum) -> String {
+    let variants = data
+        .variants
+        .iter()
+        .map(|v| format!("  {}", v.name.to_case(Case::UpperSnake)))
+        .join("\n");
+
+    formatdoc! {
+        r"
+        enum {name} {{
+        {variants}
+        }}",
+        name = type_name(&data.name)
+    }
+}
+
+/// Generates the GraphQL code for defining a sequence, followed by the unions nested in its
+/// fields.
+fn define_sequence(seq: &Sequence) -> Vec<String> {
+    let name = type_name(&seq.name);
+    let mut declarations = vec![formatdoc! {
+        r"
+        type {name} {{
+        {fields}
+        }}",
+        fields = define_fields(&seq.fields, &name)
+    }];
+
+    for f in &seq.fields {
+        declarations.extend(nested_unions(&f.ty, &field_path(&name, &f.name)));
+    }
+    declarations
+}
+
+/// Generates the unions (and their wrapper types) nested in a type.
+fn nested_unions(ty: &Type, path: &str) -> Vec<String> {
+    match ty {
+        Type::Array(t) => nested_unions(t, &format!("{path}Item")),
+        Type::OneOf(subfields) => {
+            let mut declarations = vec![define_union(path, subfields)];
+            for f in subfields {
+                declarations.push(formatdoc! {
+                    r"
+                    type {path}{case}Case {{
+                    {fields}
+                    }}",
+                    case = f.name.to_case(Case::Pascal),
+                    fields = define_fields(std::slice::from_ref(f), path)
+                });
+                declarations.extend(nested_unions(&f.ty, &field_path(path, &f.name)));
+            }
+            declarations
+        }
+        _ => vec![],
+    }
+}
+
+/// Generates the GraphQL code for defining a union from a oneof.
+fn define_union(path: &str, subfields: &[Field]) -> String {
+    let members = subfields
+        .iter()
+        .map(|f| format!("{path}{}Case", f.name.to_case(Case::Pascal)))
+        .join(" | ");
+
+    format!("union {path} = {members}")
+}
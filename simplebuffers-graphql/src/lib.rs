@@ -0,0 +1,85 @@
+//! GraphQL schema generator.
+//!
+//! This module generates a GraphQL schema (`{file_name}.graphql`) from a SimpleBuffers schema, so
+//! that a GraphQL API can expose the same types as the binary messages. It does not generate any
+//! serialization code.
+//!
+//! - Every sequence becomes an object `type`.
+//! - Every enum becomes an `enum`.
+//! - Every oneof becomes a `union`. GraphQL unions can only hold object types, so each oneof field
+//!   is wrapped in an object type with a single field.
+//! - Arrays become list types.
+//!
+//! GraphQL's `Int` is a signed 32-bit integer, so integer types that do not fit in it (`u32`,
+//! `u64`, and `i64`) are mapped to `String` and marked with a comment.
+
+mod graphqlgen;
+
+use std::path::PathBuf;
+
+use graphqlgen::{generate_graphql, BUILT_IN_TYPES};
+use simplebuffers_codegen::{register_generator, CodeGenerator, FileSink, ItemKind};
+
+#[derive(Debug)]
+pub struct GraphQLCodeGenerator;
+
+impl CodeGenerator for GraphQLCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        if schema.uses_maps() {
+            return Err("The GraphQL generator does not support maps".to_string());
+        }
+
+        let path = PathBuf::from(&params.dest_dir).join(format!("{}.graphql", params.file_name));
+        sink.write_file(&path, &generate_graphql(schema))?;
+
+        Ok(vec![path])
+    }
+
+    fn reserved_identifiers(
+        &mut self,
+        _params: &simplebuffers_codegen::GeneratorParams,
+    ) -> Vec<String> {
+        BUILT_IN_TYPES
+            .iter()
+            // Values that cannot be used as enum values.
+            .chain(&["true", "false", "null"])
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn reserved_identifiers_case_sensitive(&self) -> bool {
+        true
+    }
+
+    fn reserved_identifiers_apply_to(&self, kind: ItemKind) -> bool {
+        // The built-in types only conflict with the names of types, and `true`, `false`, and `null`
+        // only with enum values. Field names conflict with neither. Since every kind is checked
+        // against the whole list, an enum variant named like a built-in type is rejected too.
+        matches!(
+            kind,
+            ItemKind::Sequence | ItemKind::Enum | ItemKind::EnumVariant
+        )
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["graphql".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "GraphQL schema".to_string()
+    }
+}
+
+register_generator!(graphql: GraphQLCodeGenerator);
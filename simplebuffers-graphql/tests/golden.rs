@@ -0,0 +1,11 @@
+//! Golden tests for the GraphQL generator. See `simplebuffers-golden` for how they work.
+
+use simplebuffers_golden::{golden_tests, Layout};
+use simplebuffers_graphql::GraphQLCodeGenerator;
+
+golden_tests! {
+    generator: GraphQLCodeGenerator,
+    args: ["graphql"],
+    layout: Layout::Flat,
+    cases: [primitives, enums, nested, oneofs, arrays, oneof_arrays, bytes],
+}
@@ -0,0 +1,192 @@
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+import java.nio.charset.StandardCharsets;
+import java.util.AbstractList;
+import java.util.Arrays;
+
+/**
+ * Runtime support for generated SimpleBuffers readers and writers.
+ *
+ * <p>All multi-byte values are stored in little-endian byte order. Offsets to dynamic data are
+ * unsigned 16-bit values relative to the position of the field that holds them.
+ */
+public final class SimpleBuffers {
+    private SimpleBuffers() {}
+
+    /** Wraps a byte array in a little-endian buffer that generated readers can use. */
+    public static ByteBuffer wrap(byte[] data) {
+        return ByteBuffer.wrap(data).order(ByteOrder.LITTLE_ENDIAN);
+    }
+
+    /** Returns a little-endian view of a buffer, reusing it if it is already little-endian. */
+    public static ByteBuffer littleEndian(ByteBuffer buf) {
+        return buf.order() == ByteOrder.LITTLE_ENDIAN
+            ? buf
+            : buf.duplicate().order(ByteOrder.LITTLE_ENDIAN);
+    }
+
+    /** Follows the 16-bit offset stored at {@code pos} and returns the position it points to. */
+    public static int follow(ByteBuffer buf, int pos) {
+        return pos + (buf.getShort(pos) & 0xFFFF);
+    }
+
+    /** Reads a null-terminated string whose offset is stored at {@code pos}. */
+    public static java.lang.String readString(ByteBuffer buf, int pos) {
+        int start = follow(buf, pos);
+        int end = start;
+        while (end < buf.limit() && buf.get(end) != 0) {
+            end++;
+        }
+        byte[] bytes = new byte[end - start];
+        for (int i = 0; i < bytes.length; i++) {
+            bytes[i] = buf.get(start + i);
+        }
+        return new java.lang.String(bytes, StandardCharsets.UTF_8);
+    }
+
+    /** Reads a single list element at a position in the buffer. */
+    @FunctionalInterface
+    public interface ElementReader<T> {
+        T read(int pos);
+    }
+
+    /** A read-only view over a list stored in a buffer. */
+    public static final class ListReader<T> extends AbstractList<T> {
+        private final int len;
+        private final int start;
+        private final int stride;
+        private final ElementReader<T> reader;
+
+        public ListReader(ByteBuffer buf, int pos, int stride, ElementReader<T> reader) {
+            this.len = buf.getShort(pos) & 0xFFFF;
+            this.start = pos + (buf.getShort(pos + 2) & 0xFFFF);
+            this.stride = stride;
+            this.reader = reader;
+        }
+
+        @Override
+        public T get(int index) {
+            if (index < 0 || index >= len) {
+                throw new IndexOutOfBoundsException(index);
+            }
+            return reader.read(start + index * stride);
+        }
+
+        @Override
+        public int size() {
+            return len;
+        }
+    }
+
+    /** Writes a single list element at a position in the buffer. */
+    @FunctionalInterface
+    public interface ElementWriter<T> {
+        void write(int pos, T value);
+    }
+
+    /** A growable buffer that generated writers serialize into. */
+    public static final class Builder {
+        private ByteBuffer buf;
+        private int size;
+
+        /** Creates a builder with {@code staticSize} zeroed bytes reserved for the root. */
+        public Builder(int staticSize) {
+            buf = ByteBuffer.allocate(Math.max(64, staticSize)).order(ByteOrder.LITTLE_ENDIAN);
+            size = staticSize;
+        }
+
+        /** Returns the number of bytes written so far. */
+        public int size() {
+            return size;
+        }
+
+        /**
+         * Reserves {@code count} zeroed bytes at the end of the buffer and stores the offset to them
+         * (relative to {@code base}) at {@code slot}. Returns the position of the reserved bytes.
+         */
+        public int reserve(int slot, int base, int count) {
+            int start = size;
+            grow(size + count);
+            size += count;
+            putShort(slot, (short) (start - base));
+            return start;
+        }
+
+        public void putByte(int pos, byte value) {
+            buf.put(pos, value);
+        }
+
+        public void putBool(int pos, boolean value) {
+            buf.put(pos, (byte) (value ? 1 : 0));
+        }
+
+        public void putShort(int pos, short value) {
+            buf.putShort(pos, value);
+        }
+
+        public void putInt(int pos, int value) {
+            buf.putInt(pos, value);
+        }
+
+        public void putLong(int pos, long value) {
+            buf.putLong(pos, value);
+        }
+
+        public void putFloat(int pos, float value) {
+            buf.putFloat(pos, value);
+        }
+
+        public void putDouble(int pos, double value) {
+            buf.putDouble(pos, value);
+        }
+
+        /** Writes a null-terminated string to the end of the buffer and stores its offset at pos. */
+        public void putString(int pos, java.lang.String value) {
+            byte[] bytes = value.getBytes(StandardCharsets.UTF_8);
+            int start = reserve(pos, pos, bytes.length + 1);
+            for (int i = 0; i < bytes.length; i++) {
+                buf.put(start + i, bytes[i]);
+            }
+        }
+
+        /** Writes a list header at {@code pos} and the list elements to the end of the buffer. */
+        public <T> void putList(int pos, java.util.List<T> values, int stride, ElementWriter<T> writer) {
+            putShort(pos, (short) values.size());
+            int start = reserve(pos + 2, pos, values.size() * stride);
+            for (int i = 0; i < values.size(); i++) {
+                writer.write(start + i * stride, values.get(i));
+            }
+        }
+
+        /** Returns a copy of the bytes written so far. */
+        public byte[] toByteArray() {
+            return Arrays.copyOf(buf.array(), size);
+        }
+
+        private void grow(int capacity) {
+            if (capacity <= buf.capacity()) {
+                return;
+            }
+            ByteBuffer grown = ByteBuffer.allocate(Math.max(capacity, buf.capacity() * 2));
+            grown.order(ByteOrder.LITTLE_ENDIAN);
+            grown.put(0, buf.array(), 0, size);
+            buf = grown;
+        }
+    }
+
+    /** A component that can be serialized into a {@link Builder}. */
+    public interface Writer {
+        /** Returns the size of the component's static data. */
+        int staticSize();
+
+        /** Writes static data at {@code pos} and dynamic data at the end of the builder. */
+        void writeComponent(Builder b, int pos);
+
+        /** Serializes the component into a new byte array. */
+        default byte[] encode() {
+            Builder b = new Builder(staticSize());
+            writeComponent(b, 0);
+            return b.toByteArray();
+        }
+    }
+}
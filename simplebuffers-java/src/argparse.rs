@@ -0,0 +1,44 @@
+//! Parses generator-specific arguments contained in [GeneratorParams::additional_args].
+
+use clap::{Command, CommandFactory, Parser};
+use simplebuffers_codegen::GeneratorParams;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Struct to parse CLI args into.
+#[derive(Parser, Debug)]
+#[command(name = "SimpleBuffers Java Code Generator")]
+#[command(version = VERSION)]
+#[command(about = "Generate Java code from a SimpleBuffers schema.")]
+struct Cli {
+    /// The Java package to generate classes in (for example, `com.example.robot`). Files are
+    /// written to the matching directory structure under `dstdir`. If this is not specified, the
+    /// classes are generated in the default package.
+    #[arg(long)]
+    package: Option<String>,
+}
+
+/// A struct that holds generator-specific arguments for the Java generator.
+#[derive(Debug)]
+pub(crate) struct JavaGeneratorParams {
+    /// The Java package to generate classes in. This is empty for the default package.
+    pub package: String,
+
+    /// The global compiler parameters.
+    pub global: GeneratorParams,
+}
+
+/// Returns the command that describes the generator-specific arguments, so the compiler can
+/// show them in its help output and validate them before generating.
+pub(crate) fn command() -> Command {
+    Cli::command()
+}
+
+/// Parse generator-specific arguments from the compiler-provided argument list.
+pub(crate) fn parse_args(generator_params: &GeneratorParams) -> JavaGeneratorParams {
+    let cli = Cli::parse_from(&generator_params.additional_args);
+    JavaGeneratorParams {
+        package: cli.package.unwrap_or_default(),
+        global: generator_params.clone(),
+    }
+}
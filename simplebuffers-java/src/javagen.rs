@@ -0,0 +1,632 @@
+//! Generates Java source files.
+
+use convert_case::{Case, Casing};
+use indent::indent_by;
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+use std::collections::HashMap;
+
+/// The comment placed at the top of every generated file.
+const HEADER: &str =
+    "// This file was generated by the SimpleBuffers compiler. Do not edit it manually.";
+
+/// Where a value is stored. This determines how sequences are laid out.
+#[derive(Clone, Copy)]
+enum Context {
+    /// The value is a field of a sequence. Sequences are stored behind a 16-bit offset.
+    Slot,
+
+    /// The value is a list element or the target of a oneof. Sequences are stored inline.
+    Element,
+}
+
+/// A generated Java class.
+pub(crate) struct JavaClass {
+    /// The name of the class. The class is written to a file with the same name.
+    pub name: String,
+
+    /// The source code of the file that declares the class.
+    pub source: String,
+}
+
+/// Information about the schema that is needed while generating code.
+struct JavaGen<'a> {
+    /// The package declaration placed at the top of every file, if any.
+    package_decl: String,
+
+    /// The static size of every sequence, by name.
+    sequence_sizes: HashMap<&'a str, usize>,
+}
+
+//                                                                                                //
+// ======================================= Main Function ======================================== //
+//                                                                                                //
+
+/// Generates Java classes from a given schema.
+///
+/// # Arguments
+///
+/// * `package` - The Java package to generate classes in, or an empty string for the default
+///   package.
+/// * `schema` - The schema to generate from.
+///
+/// # Returns
+///
+/// One [JavaClass] for the runtime, one per enum, and a reader and a writer per sequence.
+pub(crate) fn generate_java(package: &str, schema: &SBSchema) -> Vec<JavaClass> {
+    let gen = JavaGen {
+        package_decl: if package.is_empty() {
+            String::new()
+        } else {
+            format!("package {};\n\n", package)
+        },
+        sequence_sizes: schema
+            .sequences
+            .iter()
+            .map(|s| (s.name.as_str(), s.static_size()))
+            .collect(),
+    };
+
+    let mut classes = vec![gen.file(
+        "SimpleBuffers".to_string(),
+        include_str!("../corelib/SimpleBuffers.java").trim(),
+    )];
+    classes.extend(
+        schema
+            .enums
+            .iter()
+            .map(|e| gen.file(e.name.to_case(Case::Pascal), &define_enum(e))),
+    );
+    for seq in &schema.sequences {
+        classes.push(gen.file(writer_name(&seq.name), &gen.define_sequence_writer(seq)));
+        classes.push(gen.file(reader_name(&seq.name), &gen.define_sequence_reader(seq)));
+    }
+
+    classes
+}
+
+/// Returns the name of the writer class for a sequence.
+fn writer_name(seq_name: &str) -> String {
+    format!("{}Writer", seq_name).to_case(Case::Pascal)
+}
+
+/// Returns the name of the reader class for a sequence.
+fn reader_name(seq_name: &str) -> String {
+    format!("{}Reader", seq_name).to_case(Case::Pascal)
+}
+
+/// Returns the Java type that holds a primitive. Unsigned types are widened to the next larger
+/// signed type, except for `u64`, which is stored in a `long` with the same bit pattern.
+fn primitive_type(p: &Primitive, boxed: bool) -> &'static str {
+    match (p, boxed) {
+        (Primitive::Bool, false) => "boolean",
+        (Primitive::Bool, true) => "Boolean",
+        (Primitive::I8, false) => "byte",
+        (Primitive::I8, true) => "Byte",
+        (Primitive::U8 | Primitive::I16, false) => "short",
+        (Primitive::U8 | Primitive::I16, true) => "Short",
+        (Primitive::U16 | Primitive::I32, false) => "int",
+        (Primitive::U16 | Primitive::I32, true) => "Integer",
+        (Primitive::U32 | Primitive::U64 | Primitive::I64, false) => "long",
+        (Primitive::U32 | Primitive::U64 | Primitive::I64, true) => "Long",
+        (Primitive::F32, false) => "float",
+        (Primitive::F32, true) => "Float",
+        (Primitive::F64, false) => "double",
+        (Primitive::F64, true) => "Double",
+    }
+}
+
+/// Returns the Java type that a primitive is stored as in the buffer, along with the name of the
+/// builder method that writes it.
+fn primitive_wire(p: &Primitive) -> (&'static str, &'static str) {
+    match p {
+        Primitive::Bool => ("boolean", "putBool"),
+        Primitive::U8 | Primitive::I8 => ("byte", "putByte"),
+        Primitive::U16 | Primitive::I16 => ("short", "putShort"),
+        Primitive::U32 | Primitive::I32 => ("int", "putInt"),
+        Primitive::U64 | Primitive::I64 => ("long", "putLong"),
+        Primitive::F32 => ("float", "putFloat"),
+        Primitive::F64 => ("double", "putDouble"),
+    }
+}
+
+/// Returns an expression that reads a primitive at `pos` in `buf`.
+fn read_primitive(p: &Primitive, pos: &str) -> String {
+    match p {
+        Primitive::Bool => format!("buf.get({pos}) != 0"),
+        Primitive::U8 => format!("(short) (buf.get({pos}) & 0xFF)"),
+        Primitive::U16 => format!("buf.getShort({pos}) & 0xFFFF"),
+        Primitive::U32 => format!("buf.getInt({pos}) & 0xFFFFFFFFL"),
+        Primitive::U64 | Primitive::I64 => format!("buf.getLong({pos})"),
+        Primitive::I8 => format!("buf.get({pos})"),
+        Primitive::I16 => format!("buf.getShort({pos})"),
+        Primitive::I32 => format!("buf.getInt({pos})"),
+        Primitive::F32 => format!("buf.getFloat({pos})"),
+        Primitive::F64 => format!("buf.getDouble({pos})"),
+    }
+}
+
+/// Returns an expression that reads the raw value of an enum of the given size as a `long`.
+fn read_enum_value(size: usize, pos: &str) -> String {
+    match size {
+        1 => format!("buf.get({pos}) & 0xFFL"),
+        2 => format!("buf.getShort({pos}) & 0xFFFFL"),
+        4 => format!("buf.getInt({pos}) & 0xFFFFFFFFL"),
+        8 => format!("buf.getLong({pos})"),
+        _ => panic!("Cannot convert size {} to Java type", size),
+    }
+}
+
+/// Returns a statement that writes the raw value of an enum of the given size.
+fn write_enum_value(size: usize, value: &str, pos: &str) -> String {
+    match size {
+        1 => format!("b.putByte({pos}, (byte) {value}.value);"),
+        2 => format!("b.putShort({pos}, (short) {value}.value);"),
+        4 => format!("b.putInt({pos}, (int) {value}.value);"),
+        8 => format!("b.putLong({pos}, {value}.value);"),
+        _ => panic!("Cannot convert size {} to Java type", size),
+    }
+}
+
+/// Generates the Java code for defining an enum. Each variant stores its value in a `long`; values
+/// that do not fit in a signed 64-bit integer keep their bit pattern.
+fn define_enum(data: &Enum) -> String {
+    let name = data.name.to_case(Case::Pascal);
+    let variants = data
+        .variants
+        .iter()
+        .map(|v| format!("{}({}L)", v.name.to_case(Case::UpperSnake), v.value as i64))
+        .join(",\n");
+
+    formatdoc! {
+        r"
+        public enum {name} {{
+            {variants};
+
+            public final long value;
+
+            {name}(long value) {{
+                this.value = value;
+            }}
+
+            /** Returns the variant with the given value, or {{@code null}} if there is none. */
+            public static {name} fromValue(long value) {{
+                for ({name} variant : values()) {{
+                    if (variant.value == value) {{
+                        return variant;
+                    }}
+                }}
+                return null;
+            }}
+        }}",
+        variants = indent_by(4, variants)
+    }
+}
+
+/// Returns the fields of the oneof contained in a type, looking through lists.
+fn find_oneof(ty: &Type) -> Option<&[Field]> {
+    match ty {
+        Type::OneOf(fields) => Some(fields),
+        Type::Array(t) => find_oneof(t),
+        _ => None,
+    }
+}
+
+/// Returns the name of the record that represents one field of a oneof. The `Case` suffix keeps
+/// records from shadowing enums with the same name as the field.
+fn case_name(field_name: &str) -> String {
+    format!("{}Case", field_name.to_case(Case::Pascal))
+}
+
+/// Returns the name of the interface that represents a oneof field. Oneofs nested in other oneofs
+/// are prefixed with the name of their parent, since every oneof interface is declared directly in
+/// the reader or writer class.
+fn oneof_name(prefix: &str, field_name: &str) -> String {
+    format!("{}{}", prefix, field_name.to_case(Case::Pascal))
+}
+
+impl<'a> JavaGen<'a> {
+    /// Wraps the declaration of a class in a complete source file.
+    fn file(&self, name: String, body: &str) -> JavaClass {
+        JavaClass {
+            source: format!("{HEADER}\n\n{}{}\n", self.package_decl, body),
+            name,
+        }
+    }
+
+    /// Returns the number of bytes a type takes up as a list element or oneof target.
+    fn element_size(&self, ty: &Type) -> usize {
+        match ty {
+            Type::Sequence(s) => self.sequence_sizes[s.as_str()],
+            _ => ty.size(),
+        }
+    }
+
+    //                                                                                            //
+    // =============================== Generate Writer Components =============================== //
+    //                                                                                            //
+
+    /// Returns the Java type used to write a field. `oneof` is the name of the interface that
+    /// represents the field if it contains a oneof.
+    fn writer_type(&self, ty: &Type, oneof: &str, boxed: bool) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p, boxed).to_string(),
+            Type::Sequence(s) => writer_name(s),
+            Type::Enum(e, _) => e.to_case(Case::Pascal),
+            Type::Array(t) => format!("java.util.List<{}>", self.writer_type(t, oneof, true)),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "java.lang.String".to_string(),
+            Type::OneOf(_) => oneof.to_string(),
+        }
+    }
+
+    /// Returns a statement that writes `value` at `pos` using the builder `b`. `depth` is used to
+    /// give lambda parameters unique names; values with a depth greater than 1 are list elements.
+    fn write_stmt(&self, ty: &Type, ctx: Context, value: &str, pos: &str, depth: usize) -> String {
+        match ty {
+            Type::Primitive(p) => {
+                let (wire_type, method) = primitive_wire(p);
+                let java_type = primitive_type(p, false);
+                if wire_type == java_type {
+                    format!("b.{method}({pos}, {value});")
+                } else if depth == 1 {
+                    format!("b.{method}({pos}, ({wire_type}) {value});")
+                } else {
+                    // List elements are boxed and must be unboxed before they can be narrowed.
+                    format!("b.{method}({pos}, ({wire_type}) ({java_type}) {value});")
+                }
+            }
+            Type::Enum(_, size) => write_enum_value(*size, value, pos),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("b.putString({pos}, {value});"),
+            Type::Array(t) => formatdoc! {
+                r"
+                b.putList({pos}, {value}, {stride}, (p{depth}, e{depth}) -> {{
+                    {write}
+                }});",
+                stride = self.element_size(t),
+                write = indent_by(
+                    4,
+                    self.write_stmt(
+                        t,
+                        Context::Element,
+                        &format!("e{depth}"),
+                        &format!("p{depth}"),
+                        depth + 1
+                    )
+                ),
+            },
+            Type::Sequence(s) => match ctx {
+                Context::Slot => format!(
+                    "{value}.writeComponent(b, b.reserve({pos}, {pos}, {size}));",
+                    size = self.sequence_sizes[s.as_str()],
+                ),
+                Context::Element => format!("{value}.writeComponent(b, {pos});"),
+            },
+            Type::OneOf(_) => format!("{value}.writeComponent(b, {pos});"),
+        }
+    }
+
+    /// Generates the Java code for defining a sequence writer.
+    fn define_sequence_writer(&self, seq: &Sequence) -> String {
+        let class_name = writer_name(&seq.name);
+        let static_size = self.sequence_sizes[seq.name.as_str()];
+
+        let typed_fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.name.to_case(Case::Camel),
+                    self.writer_type(&f.ty, &oneof_name("", &f.name), false),
+                )
+            })
+            .collect_vec();
+
+        let members = typed_fields
+            .iter()
+            .map(|(name, ty)| format!("public {ty} {name};"))
+            .join("\n");
+
+        let param_list = typed_fields
+            .iter()
+            .map(|(name, ty)| format!("{ty} {name}"))
+            .join(", ");
+
+        let init_list = typed_fields
+            .iter()
+            .map(|(name, _)| format!("this.{name} = {name};"))
+            .join("\n");
+
+        let write_fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                self.write_stmt(
+                    &f.ty,
+                    Context::Slot,
+                    &format!("this.{}", f.name.to_case(Case::Camel)),
+                    &format!("pos + {}", f.index),
+                    1,
+                )
+            })
+            .join("\n");
+
+        let oneofs = self.oneof_writers("", &seq.fields);
+
+        let body = formatdoc! {
+            r"
+            public static final int STATIC_SIZE = {static_size};
+
+            {members}
+
+            public {class_name}({param_list}) {{
+                {init_list}
+            }}
+
+            @Override
+            public int staticSize() {{
+                return STATIC_SIZE;
+            }}
+
+            @Override
+            public void writeComponent(SimpleBuffers.Builder b, int pos) {{
+                {write_fields}
+            }}
+
+            {oneofs}",
+            init_list = indent_by(4, init_list),
+            write_fields = indent_by(4, write_fields),
+        };
+
+        formatdoc! {
+            r"
+            public final class {class_name} implements SimpleBuffers.Writer {{
+                {body}
+            }}",
+            body = indent_by(4, body.trim())
+        }
+    }
+
+    /// Generates writers for every oneof found in a list of fields, including oneofs nested in
+    /// lists and in other oneofs.
+    fn oneof_writers(&self, prefix: &str, fields: &[Field]) -> String {
+        fields
+            .iter()
+            .filter_map(|f| {
+                find_oneof(&f.ty).map(|o| self.define_oneof_writer(&oneof_name(prefix, &f.name), o))
+            })
+            .join("\n\n")
+    }
+
+    /// Generates the Java code for defining a oneof writer. Oneofs are written as sealed
+    /// interfaces with one record per field.
+    fn define_oneof_writer(&self, name: &str, subfields: &[Field]) -> String {
+        let records = subfields
+            .iter()
+            .map(|f| {
+                formatdoc! {
+                    r"
+                    record {record}({ty} value) implements {name} {{
+                        @Override
+                        public void writeComponent(SimpleBuffers.Builder b, int pos) {{
+                            b.putByte(pos, (byte) {index});
+                            int p = b.reserve(pos + 1, pos, {size});
+                            {write}
+                        }}
+                    }}",
+                    record = case_name(&f.name),
+                    ty = self.writer_type(&f.ty, &oneof_name(name, &f.name), false),
+                    index = f.index,
+                    size = self.element_size(&f.ty),
+                    write = indent_by(8, self.write_stmt(&f.ty, Context::Element, "value", "p", 1)),
+                }
+            })
+            .join("\n\n");
+
+        let nested = self.oneof_writers(name, subfields);
+
+        formatdoc! {
+            r"
+            public sealed interface {name} {{
+                void writeComponent(SimpleBuffers.Builder b, int pos);
+
+                {records}
+            }}
+
+            {nested}",
+            records = indent_by(4, records),
+        }
+        .trim()
+        .to_string()
+    }
+
+    //                                                                                            //
+    // =============================== Generate Reader Components =============================== //
+    //                                                                                            //
+
+    /// Returns the Java type returned when reading a field. `oneof` is the name of the interface
+    /// that represents the field if it contains a oneof.
+    fn reader_type(&self, ty: &Type, oneof: &str, boxed: bool) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p, boxed).to_string(),
+            Type::Sequence(s) => reader_name(s),
+            Type::Enum(e, _) => e.to_case(Case::Pascal),
+            Type::Array(t) => format!(
+                "SimpleBuffers.ListReader<{}>",
+                self.reader_type(t, oneof, true)
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "java.lang.String".to_string(),
+            Type::OneOf(_) => oneof.to_string(),
+        }
+    }
+
+    /// Returns an expression that reads a value of the given type at `pos` in `buf`. `depth` is
+    /// used to give lambda parameters unique names.
+    fn read_expr(&self, ty: &Type, oneof: &str, ctx: Context, pos: &str, depth: usize) -> String {
+        match ty {
+            Type::Primitive(p) => read_primitive(p, pos),
+            Type::Enum(e, size) => format!(
+                "{}.fromValue({})",
+                e.to_case(Case::Pascal),
+                read_enum_value(*size, pos)
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("SimpleBuffers.readString(buf, {pos})"),
+            Type::Array(t) => format!(
+                "new {ty}(buf, {pos}, {stride}, p{depth} -> {read})",
+                ty = self.reader_type(ty, oneof, false),
+                stride = self.element_size(t),
+                read = self.read_expr(t, oneof, Context::Element, &format!("p{depth}"), depth + 1),
+            ),
+            Type::Sequence(s) => match ctx {
+                Context::Slot => format!(
+                    "new {}(buf, SimpleBuffers.follow(buf, {pos}))",
+                    reader_name(s)
+                ),
+                Context::Element => format!("new {}(buf, {pos})", reader_name(s)),
+            },
+            Type::OneOf(_) => format!("{oneof}.read(buf, {pos})"),
+        }
+    }
+
+    /// Generates the Java code for defining a sequence reader.
+    fn define_sequence_reader(&self, seq: &Sequence) -> String {
+        let class_name = reader_name(&seq.name);
+        let static_size = self.sequence_sizes[seq.name.as_str()];
+
+        let fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                let oneof = oneof_name("", &f.name);
+                formatdoc! {
+                    r"
+                    public {ty} {name}() {{
+                        return {read};
+                    }}",
+                    ty = self.reader_type(&f.ty, &oneof, false),
+                    name = f.name.to_case(Case::Camel),
+                    read = self.read_expr(
+                        &f.ty,
+                        &oneof,
+                        Context::Slot,
+                        &format!("pos + {}", f.index),
+                        1
+                    ),
+                }
+            })
+            .join("\n\n");
+
+        let oneofs = self.oneof_readers("", &seq.fields);
+
+        let body = formatdoc! {
+            r"
+            public static final int STATIC_SIZE = {static_size};
+
+            private final java.nio.ByteBuffer buf;
+            private final int pos;
+
+            public {class_name}(java.nio.ByteBuffer buf, int pos) {{
+                this.buf = SimpleBuffers.littleEndian(buf);
+                this.pos = pos;
+            }}
+
+            public {class_name}(byte[] data) {{
+                this(SimpleBuffers.wrap(data), 0);
+            }}
+
+            {fields}
+
+            {oneofs}"
+        };
+
+        formatdoc! {
+            r"
+            public final class {class_name} {{
+                {body}
+            }}",
+            body = indent_by(4, body.trim())
+        }
+    }
+
+    /// Generates readers for every oneof found in a list of fields, including oneofs nested in
+    /// lists and in other oneofs.
+    fn oneof_readers(&self, prefix: &str, fields: &[Field]) -> String {
+        fields
+            .iter()
+            .filter_map(|f| {
+                find_oneof(&f.ty).map(|o| self.define_oneof_reader(&oneof_name(prefix, &f.name), o))
+            })
+            .join("\n\n")
+    }
+
+    /// Generates the Java code for defining a oneof reader. The active record is chosen by the tag
+    /// stored in the buffer. Unknown tags produce `null`.
+    fn define_oneof_reader(&self, name: &str, subfields: &[Field]) -> String {
+        let records = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "record {}({} value) implements {name} {{}}",
+                    case_name(&f.name),
+                    self.reader_type(&f.ty, &oneof_name(name, &f.name), false)
+                )
+            })
+            .join("\n");
+
+        let switch_cases = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "case {index}: return new {record}({read});",
+                    index = f.index,
+                    record = case_name(&f.name),
+                    read =
+                        self.read_expr(&f.ty, &oneof_name(name, &f.name), Context::Element, "p", 1),
+                )
+            })
+            .join("\n");
+
+        let nested = self.oneof_readers(name, subfields);
+
+        formatdoc! {
+            r"
+            public sealed interface {name} {{
+                {records}
+
+                static {name} read(java.nio.ByteBuffer buf, int pos) {{
+                    int p = pos + (buf.getShort(pos + 1) & 0xFFFF);
+                    switch (buf.get(pos)) {{
+                        {switch_cases}
+                        default: return null;
+                    }}
+                }}
+            }}
+
+            {nested}",
+            records = indent_by(4, records),
+            switch_cases = indent_by(12, switch_cases),
+        }
+        .trim()
+        .to_string()
+    }
+}
@@ -0,0 +1,169 @@
+//! Java code generator.
+//!
+//! This module implements Java code generation for SimpleBuffers schemas. It produces one `.java`
+//! file per class:
+//!
+//! - `SimpleBuffers.java`, a small runtime with helpers for reading and writing little-endian
+//!   values through `java.nio.ByteBuffer`.
+//! - A Java `enum` for every schema enum.
+//! - A writer class for every sequence, which serializes into a `byte[]`.
+//! - A reader class for every sequence, which reads fields lazily from a `ByteBuffer`.
+//!
+//! Oneofs are represented as sealed interfaces, with one record (for example, `Payload.InitCase`)
+//! per oneof field. Readers select the record based on the tag stored in the buffer.
+//!
+//! Classes are generated in the package given by `--package` and written to the matching
+//! directory structure under the destination directory (for example, `com.example.robot` is
+//! written to `{dstdir}/com/example/robot/`).
+//!
+//! Java has no unsigned integers, so unsigned types are widened to the next larger signed type.
+//! `u64` values are stored in a `long` with the same bit pattern.
+
+mod argparse;
+mod javagen;
+
+use std::path::PathBuf;
+
+use argparse::{command, parse_args};
+use javagen::generate_java;
+use simplebuffers_codegen::{register_generators, CodeGenerator, FileSink};
+
+#[derive(Debug)]
+pub struct JavaCodeGenerator;
+
+impl CodeGenerator for JavaCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        if schema.uses_fixed_arrays() {
+            return Err("The Java generator does not support fixed-size arrays".to_string());
+        }
+        if schema.uses_bytes() {
+            return Err("The Java generator does not support byte buffers".to_string());
+        }
+        if schema.uses_maps() {
+            return Err("The Java generator does not support maps".to_string());
+        }
+
+        let params = parse_args(params);
+
+        let mut dir = PathBuf::from(&params.global.dest_dir);
+        if !params.package.is_empty() {
+            for segment in params.package.split('.') {
+                if segment.is_empty() || !segment.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    return Err(format!("Invalid Java package name: {}", params.package));
+                }
+                dir.push(segment);
+            }
+        }
+        let mut paths = vec![];
+        for class in generate_java(&params.package, schema) {
+            let path = dir.join(format!("{}.java", class.name));
+            sink.write_file(&path, &class.source)?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+
+    fn reserved_identifiers(
+        &mut self,
+        _params: &simplebuffers_codegen::GeneratorParams,
+    ) -> Vec<String> {
+        vec![
+            // Names used by the generated code.
+            "SimpleBuffers",
+            "staticSize",
+            "writeComponent",
+            "encode",
+            "fromValue",
+            // Keywords.
+            "abstract",
+            "assert",
+            "boolean",
+            "break",
+            "byte",
+            "case",
+            "catch",
+            "char",
+            "class",
+            "const",
+            "continue",
+            "default",
+            "do",
+            "double",
+            "else",
+            "enum",
+            "extends",
+            "final",
+            "finally",
+            "float",
+            "for",
+            "goto",
+            "if",
+            "implements",
+            "import",
+            "instanceof",
+            "int",
+            "interface",
+            "long",
+            "native",
+            "new",
+            "package",
+            "private",
+            "protected",
+            "public",
+            "return",
+            "short",
+            "static",
+            "strictfp",
+            "super",
+            "switch",
+            "synchronized",
+            "this",
+            "throw",
+            "throws",
+            "transient",
+            "try",
+            "void",
+            "volatile",
+            "while",
+            // Literals and contextual keywords.
+            "true",
+            "false",
+            "null",
+            "record",
+            "sealed",
+            "permits",
+            "var",
+            "yield",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["java".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "Java code (one file per class)".to_string()
+    }
+
+    fn cli(&self) -> Option<clap::Command> {
+        Some(command())
+    }
+}
+
+register_generators!(java: JavaCodeGenerator);
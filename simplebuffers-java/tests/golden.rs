@@ -0,0 +1,11 @@
+//! Golden tests for the Java generator. See `simplebuffers-golden` for how they work.
+
+use simplebuffers_golden::{golden_tests, Layout};
+use simplebuffers_java::JavaCodeGenerator;
+
+golden_tests! {
+    generator: JavaCodeGenerator,
+    args: ["java"],
+    layout: Layout::Directory,
+    cases: [primitives, enums, nested, oneofs, lists, oneof_arrays],
+}
@@ -0,0 +1,29 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class EnumsReader {
+    public static final int STATIC_SIZE = 6;
+
+    private final java.nio.ByteBuffer buf;
+    private final int pos;
+
+    public EnumsReader(java.nio.ByteBuffer buf, int pos) {
+        this.buf = SimpleBuffers.littleEndian(buf);
+        this.pos = pos;
+    }
+
+    public EnumsReader(byte[] data) {
+        this(SimpleBuffers.wrap(data), 0);
+    }
+
+    public Small small() {
+        return Small.fromValue(buf.get(pos + 0) & 0xFFL);
+    }
+
+    public Signed signed() {
+        return Signed.fromValue(buf.get(pos + 1) & 0xFFL);
+    }
+
+    public Wide wide() {
+        return Wide.fromValue(buf.getInt(pos + 2) & 0xFFFFFFFFL);
+    }
+}
@@ -0,0 +1,27 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class EnumsWriter implements SimpleBuffers.Writer {
+    public static final int STATIC_SIZE = 6;
+
+    public Small small;
+    public Signed signed;
+    public Wide wide;
+
+    public EnumsWriter(Small small, Signed signed, Wide wide) {
+        this.small = small;
+        this.signed = signed;
+        this.wide = wide;
+    }
+
+    @Override
+    public int staticSize() {
+        return STATIC_SIZE;
+    }
+
+    @Override
+    public void writeComponent(SimpleBuffers.Builder b, int pos) {
+        b.putByte(pos + 0, (byte) this.small.value);
+        b.putByte(pos + 1, (byte) this.signed.value);
+        b.putInt(pos + 2, (int) this.wide.value);
+    }
+}
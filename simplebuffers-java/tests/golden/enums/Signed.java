@@ -0,0 +1,22 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public enum Signed {
+    LOW(-1L),
+    HIGH(1L);
+
+    public final long value;
+
+    Signed(long value) {
+        this.value = value;
+    }
+
+    /** Returns the variant with the given value, or {@code null} if there is none. */
+    public static Signed fromValue(long value) {
+        for (Signed variant : values()) {
+            if (variant.value == value) {
+                return variant;
+            }
+        }
+        return null;
+    }
+}
@@ -0,0 +1,22 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public enum Small {
+    A(0L),
+    B(1L);
+
+    public final long value;
+
+    Small(long value) {
+        this.value = value;
+    }
+
+    /** Returns the variant with the given value, or {@code null} if there is none. */
+    public static Small fromValue(long value) {
+        for (Small variant : values()) {
+            if (variant.value == value) {
+                return variant;
+            }
+        }
+        return null;
+    }
+}
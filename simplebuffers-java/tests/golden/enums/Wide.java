@@ -0,0 +1,22 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public enum Wide {
+    FIRST(0L),
+    LAST(100000L);
+
+    public final long value;
+
+    Wide(long value) {
+        this.value = value;
+    }
+
+    /** Returns the variant with the given value, or {@code null} if there is none. */
+    public static Wide fromValue(long value) {
+        for (Wide variant : values()) {
+            if (variant.value == value) {
+                return variant;
+            }
+        }
+        return null;
+    }
+}
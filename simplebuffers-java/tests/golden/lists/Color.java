@@ -0,0 +1,22 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public enum Color {
+    RED(0L),
+    GREEN(1L);
+
+    public final long value;
+
+    Color(long value) {
+        this.value = value;
+    }
+
+    /** Returns the variant with the given value, or {@code null} if there is none. */
+    public static Color fromValue(long value) {
+        for (Color variant : values()) {
+            if (variant.value == value) {
+                return variant;
+            }
+        }
+        return null;
+    }
+}
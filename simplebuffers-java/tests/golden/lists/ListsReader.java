@@ -0,0 +1,37 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class ListsReader {
+    public static final int STATIC_SIZE = 20;
+
+    private final java.nio.ByteBuffer buf;
+    private final int pos;
+
+    public ListsReader(java.nio.ByteBuffer buf, int pos) {
+        this.buf = SimpleBuffers.littleEndian(buf);
+        this.pos = pos;
+    }
+
+    public ListsReader(byte[] data) {
+        this(SimpleBuffers.wrap(data), 0);
+    }
+
+    public SimpleBuffers.ListReader<Integer> numbers() {
+        return new SimpleBuffers.ListReader<Integer>(buf, pos + 0, 2, p1 -> buf.getShort(p1) & 0xFFFF);
+    }
+
+    public SimpleBuffers.ListReader<java.lang.String> words() {
+        return new SimpleBuffers.ListReader<java.lang.String>(buf, pos + 4, 2, p1 -> SimpleBuffers.readString(buf, p1));
+    }
+
+    public SimpleBuffers.ListReader<Color> colors() {
+        return new SimpleBuffers.ListReader<Color>(buf, pos + 8, 1, p1 -> Color.fromValue(buf.get(p1) & 0xFFL));
+    }
+
+    public SimpleBuffers.ListReader<PointReader> points() {
+        return new SimpleBuffers.ListReader<PointReader>(buf, pos + 12, 4, p1 -> new PointReader(buf, p1));
+    }
+
+    public SimpleBuffers.ListReader<SimpleBuffers.ListReader<Integer>> matrix() {
+        return new SimpleBuffers.ListReader<SimpleBuffers.ListReader<Integer>>(buf, pos + 16, 4, p1 -> new SimpleBuffers.ListReader<Integer>(buf, p1, 4, p2 -> buf.getInt(p2)));
+    }
+}
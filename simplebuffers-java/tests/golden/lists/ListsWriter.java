@@ -0,0 +1,45 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class ListsWriter implements SimpleBuffers.Writer {
+    public static final int STATIC_SIZE = 20;
+
+    public java.util.List<Integer> numbers;
+    public java.util.List<java.lang.String> words;
+    public java.util.List<Color> colors;
+    public java.util.List<PointWriter> points;
+    public java.util.List<java.util.List<Integer>> matrix;
+
+    public ListsWriter(java.util.List<Integer> numbers, java.util.List<java.lang.String> words, java.util.List<Color> colors, java.util.List<PointWriter> points, java.util.List<java.util.List<Integer>> matrix) {
+        this.numbers = numbers;
+        this.words = words;
+        this.colors = colors;
+        this.points = points;
+        this.matrix = matrix;
+    }
+
+    @Override
+    public int staticSize() {
+        return STATIC_SIZE;
+    }
+
+    @Override
+    public void writeComponent(SimpleBuffers.Builder b, int pos) {
+        b.putList(pos + 0, this.numbers, 2, (p1, e1) -> {
+            b.putShort(p1, (short) (int) e1);
+        });
+        b.putList(pos + 4, this.words, 2, (p1, e1) -> {
+            b.putString(p1, e1);
+        });
+        b.putList(pos + 8, this.colors, 1, (p1, e1) -> {
+            b.putByte(p1, (byte) e1.value);
+        });
+        b.putList(pos + 12, this.points, 4, (p1, e1) -> {
+            e1.writeComponent(b, p1);
+        });
+        b.putList(pos + 16, this.matrix, 4, (p1, e1) -> {
+            b.putList(p1, e1, 4, (p2, e2) -> {
+                b.putInt(p2, e2);
+            });
+        });
+    }
+}
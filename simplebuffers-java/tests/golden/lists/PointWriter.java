@@ -0,0 +1,24 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class PointWriter implements SimpleBuffers.Writer {
+    public static final int STATIC_SIZE = 4;
+
+    public short x;
+    public short y;
+
+    public PointWriter(short x, short y) {
+        this.x = x;
+        this.y = y;
+    }
+
+    @Override
+    public int staticSize() {
+        return STATIC_SIZE;
+    }
+
+    @Override
+    public void writeComponent(SimpleBuffers.Builder b, int pos) {
+        b.putShort(pos + 0, this.x);
+        b.putShort(pos + 2, this.y);
+    }
+}
@@ -0,0 +1,25 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class InnerReader {
+    public static final int STATIC_SIZE = 4;
+
+    private final java.nio.ByteBuffer buf;
+    private final int pos;
+
+    public InnerReader(java.nio.ByteBuffer buf, int pos) {
+        this.buf = SimpleBuffers.littleEndian(buf);
+        this.pos = pos;
+    }
+
+    public InnerReader(byte[] data) {
+        this(SimpleBuffers.wrap(data), 0);
+    }
+
+    public PointReader point() {
+        return new PointReader(buf, SimpleBuffers.follow(buf, pos + 0));
+    }
+
+    public java.lang.String label() {
+        return SimpleBuffers.readString(buf, pos + 2);
+    }
+}
@@ -0,0 +1,24 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class InnerWriter implements SimpleBuffers.Writer {
+    public static final int STATIC_SIZE = 4;
+
+    public PointWriter point;
+    public java.lang.String label;
+
+    public InnerWriter(PointWriter point, java.lang.String label) {
+        this.point = point;
+        this.label = label;
+    }
+
+    @Override
+    public int staticSize() {
+        return STATIC_SIZE;
+    }
+
+    @Override
+    public void writeComponent(SimpleBuffers.Builder b, int pos) {
+        this.point.writeComponent(b, b.reserve(pos + 0, pos + 0, 4));
+        b.putString(pos + 2, this.label);
+    }
+}
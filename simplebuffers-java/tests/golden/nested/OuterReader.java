@@ -0,0 +1,25 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class OuterReader {
+    public static final int STATIC_SIZE = 6;
+
+    private final java.nio.ByteBuffer buf;
+    private final int pos;
+
+    public OuterReader(java.nio.ByteBuffer buf, int pos) {
+        this.buf = SimpleBuffers.littleEndian(buf);
+        this.pos = pos;
+    }
+
+    public OuterReader(byte[] data) {
+        this(SimpleBuffers.wrap(data), 0);
+    }
+
+    public long id() {
+        return buf.getInt(pos + 0) & 0xFFFFFFFFL;
+    }
+
+    public InnerReader inner() {
+        return new InnerReader(buf, SimpleBuffers.follow(buf, pos + 4));
+    }
+}
@@ -0,0 +1,24 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class OuterWriter implements SimpleBuffers.Writer {
+    public static final int STATIC_SIZE = 6;
+
+    public long id;
+    public InnerWriter inner;
+
+    public OuterWriter(long id, InnerWriter inner) {
+        this.id = id;
+        this.inner = inner;
+    }
+
+    @Override
+    public int staticSize() {
+        return STATIC_SIZE;
+    }
+
+    @Override
+    public void writeComponent(SimpleBuffers.Builder b, int pos) {
+        b.putInt(pos + 0, (int) this.id);
+        this.inner.writeComponent(b, b.reserve(pos + 4, pos + 4, 4));
+    }
+}
@@ -0,0 +1,25 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class PointReader {
+    public static final int STATIC_SIZE = 4;
+
+    private final java.nio.ByteBuffer buf;
+    private final int pos;
+
+    public PointReader(java.nio.ByteBuffer buf, int pos) {
+        this.buf = SimpleBuffers.littleEndian(buf);
+        this.pos = pos;
+    }
+
+    public PointReader(byte[] data) {
+        this(SimpleBuffers.wrap(data), 0);
+    }
+
+    public short x() {
+        return buf.getShort(pos + 0);
+    }
+
+    public short y() {
+        return buf.getShort(pos + 2);
+    }
+}
@@ -0,0 +1,67 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class BatchReader {
+    public static final int STATIC_SIZE = 7;
+
+    private final java.nio.ByteBuffer buf;
+    private final int pos;
+
+    public BatchReader(java.nio.ByteBuffer buf, int pos) {
+        this.buf = SimpleBuffers.littleEndian(buf);
+        this.pos = pos;
+    }
+
+    public BatchReader(byte[] data) {
+        this(SimpleBuffers.wrap(data), 0);
+    }
+
+    public SimpleBuffers.ListReader<Events> events() {
+        return new SimpleBuffers.ListReader<Events>(buf, pos + 0, 3, p1 -> Events.read(buf, p1));
+    }
+
+    public Nested nested() {
+        return Nested.read(buf, pos + 4);
+    }
+
+    public sealed interface Events {
+        record IdCase(short value) implements Events {}
+        record NameCase(java.lang.String value) implements Events {}
+
+        static Events read(java.nio.ByteBuffer buf, int pos) {
+            int p = pos + (buf.getShort(pos + 1) & 0xFFFF);
+            switch (buf.get(pos)) {
+                case 0: return new IdCase((short) (buf.get(p) & 0xFF));
+                case 1: return new NameCase(SimpleBuffers.readString(buf, p));
+                default: return null;
+            }
+        }
+    }
+
+    public sealed interface Nested {
+        record EmptyCase(short value) implements Nested {}
+        record EventsCase(SimpleBuffers.ListReader<NestedEvents> value) implements Nested {}
+
+        static Nested read(java.nio.ByteBuffer buf, int pos) {
+            int p = pos + (buf.getShort(pos + 1) & 0xFFFF);
+            switch (buf.get(pos)) {
+                case 0: return new EmptyCase((short) (buf.get(p) & 0xFF));
+                case 1: return new EventsCase(new SimpleBuffers.ListReader<NestedEvents>(buf, p, 3, p1 -> NestedEvents.read(buf, p1)));
+                default: return null;
+            }
+        }
+    }
+
+    public sealed interface NestedEvents {
+        record SmallCase(short value) implements NestedEvents {}
+        record LargeCase(long value) implements NestedEvents {}
+
+        static NestedEvents read(java.nio.ByteBuffer buf, int pos) {
+            int p = pos + (buf.getShort(pos + 1) & 0xFFFF);
+            switch (buf.get(pos)) {
+                case 0: return new SmallCase((short) (buf.get(p) & 0xFF));
+                case 1: return new LargeCase(buf.getLong(p));
+                default: return null;
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class BatchWriter implements SimpleBuffers.Writer {
+    public static final int STATIC_SIZE = 7;
+
+    public java.util.List<Events> events;
+    public Nested nested;
+
+    public BatchWriter(java.util.List<Events> events, Nested nested) {
+        this.events = events;
+        this.nested = nested;
+    }
+
+    @Override
+    public int staticSize() {
+        return STATIC_SIZE;
+    }
+
+    @Override
+    public void writeComponent(SimpleBuffers.Builder b, int pos) {
+        b.putList(pos + 0, this.events, 3, (p1, e1) -> {
+            e1.writeComponent(b, p1);
+        });
+        this.nested.writeComponent(b, pos + 4);
+    }
+
+    public sealed interface Events {
+        void writeComponent(SimpleBuffers.Builder b, int pos);
+
+        record IdCase(short value) implements Events {
+            @Override
+            public void writeComponent(SimpleBuffers.Builder b, int pos) {
+                b.putByte(pos, (byte) 0);
+                int p = b.reserve(pos + 1, pos, 1);
+                b.putByte(p, (byte) value);
+            }
+        }
+
+        record NameCase(java.lang.String value) implements Events {
+            @Override
+            public void writeComponent(SimpleBuffers.Builder b, int pos) {
+                b.putByte(pos, (byte) 1);
+                int p = b.reserve(pos + 1, pos, 2);
+                b.putString(p, value);
+            }
+        }
+    }
+
+    public sealed interface Nested {
+        void writeComponent(SimpleBuffers.Builder b, int pos);
+
+        record EmptyCase(short value) implements Nested {
+            @Override
+            public void writeComponent(SimpleBuffers.Builder b, int pos) {
+                b.putByte(pos, (byte) 0);
+                int p = b.reserve(pos + 1, pos, 1);
+                b.putByte(p, (byte) value);
+            }
+        }
+
+        record EventsCase(java.util.List<NestedEvents> value) implements Nested {
+            @Override
+            public void writeComponent(SimpleBuffers.Builder b, int pos) {
+                b.putByte(pos, (byte) 1);
+                int p = b.reserve(pos + 1, pos, 4);
+                b.putList(p, value, 3, (p1, e1) -> {
+                    e1.writeComponent(b, p1);
+                });
+            }
+        }
+    }
+
+    public sealed interface NestedEvents {
+        void writeComponent(SimpleBuffers.Builder b, int pos);
+
+        record SmallCase(short value) implements NestedEvents {
+            @Override
+            public void writeComponent(SimpleBuffers.Builder b, int pos) {
+                b.putByte(pos, (byte) 0);
+                int p = b.reserve(pos + 1, pos, 1);
+                b.putByte(p, (byte) value);
+            }
+        }
+
+        record LargeCase(long value) implements NestedEvents {
+            @Override
+            public void writeComponent(SimpleBuffers.Builder b, int pos) {
+                b.putByte(pos, (byte) 1);
+                int p = b.reserve(pos + 1, pos, 8);
+                b.putLong(p, value);
+            }
+        }
+    }
+}
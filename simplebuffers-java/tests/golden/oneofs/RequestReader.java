@@ -0,0 +1,41 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class RequestReader {
+    public static final int STATIC_SIZE = 5;
+
+    private final java.nio.ByteBuffer buf;
+    private final int pos;
+
+    public RequestReader(java.nio.ByteBuffer buf, int pos) {
+        this.buf = SimpleBuffers.littleEndian(buf);
+        this.pos = pos;
+    }
+
+    public RequestReader(byte[] data) {
+        this(SimpleBuffers.wrap(data), 0);
+    }
+
+    public int id() {
+        return buf.getShort(pos + 0) & 0xFFFF;
+    }
+
+    public Body body() {
+        return Body.read(buf, pos + 2);
+    }
+
+    public sealed interface Body {
+        record PingCase(short value) implements Body {}
+        record EchoCase(java.lang.String value) implements Body {}
+        record TargetCase(TargetReader value) implements Body {}
+
+        static Body read(java.nio.ByteBuffer buf, int pos) {
+            int p = pos + (buf.getShort(pos + 1) & 0xFFFF);
+            switch (buf.get(pos)) {
+                case 0: return new PingCase((short) (buf.get(p) & 0xFF));
+                case 1: return new EchoCase(SimpleBuffers.readString(buf, p));
+                case 2: return new TargetCase(new TargetReader(buf, p));
+                default: return null;
+            }
+        }
+    }
+}
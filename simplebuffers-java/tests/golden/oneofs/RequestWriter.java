@@ -0,0 +1,55 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class RequestWriter implements SimpleBuffers.Writer {
+    public static final int STATIC_SIZE = 5;
+
+    public int id;
+    public Body body;
+
+    public RequestWriter(int id, Body body) {
+        this.id = id;
+        this.body = body;
+    }
+
+    @Override
+    public int staticSize() {
+        return STATIC_SIZE;
+    }
+
+    @Override
+    public void writeComponent(SimpleBuffers.Builder b, int pos) {
+        b.putShort(pos + 0, (short) this.id);
+        this.body.writeComponent(b, pos + 2);
+    }
+
+    public sealed interface Body {
+        void writeComponent(SimpleBuffers.Builder b, int pos);
+
+        record PingCase(short value) implements Body {
+            @Override
+            public void writeComponent(SimpleBuffers.Builder b, int pos) {
+                b.putByte(pos, (byte) 0);
+                int p = b.reserve(pos + 1, pos, 1);
+                b.putByte(p, (byte) value);
+            }
+        }
+
+        record EchoCase(java.lang.String value) implements Body {
+            @Override
+            public void writeComponent(SimpleBuffers.Builder b, int pos) {
+                b.putByte(pos, (byte) 1);
+                int p = b.reserve(pos + 1, pos, 2);
+                b.putString(p, value);
+            }
+        }
+
+        record TargetCase(TargetWriter value) implements Body {
+            @Override
+            public void writeComponent(SimpleBuffers.Builder b, int pos) {
+                b.putByte(pos, (byte) 2);
+                int p = b.reserve(pos + 1, pos, 8);
+                value.writeComponent(b, p);
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class TargetReader {
+    public static final int STATIC_SIZE = 8;
+
+    private final java.nio.ByteBuffer buf;
+    private final int pos;
+
+    public TargetReader(java.nio.ByteBuffer buf, int pos) {
+        this.buf = SimpleBuffers.littleEndian(buf);
+        this.pos = pos;
+    }
+
+    public TargetReader(byte[] data) {
+        this(SimpleBuffers.wrap(data), 0);
+    }
+
+    public float x() {
+        return buf.getFloat(pos + 0);
+    }
+
+    public float y() {
+        return buf.getFloat(pos + 4);
+    }
+}
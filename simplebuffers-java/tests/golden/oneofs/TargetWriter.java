@@ -0,0 +1,24 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class TargetWriter implements SimpleBuffers.Writer {
+    public static final int STATIC_SIZE = 8;
+
+    public float x;
+    public float y;
+
+    public TargetWriter(float x, float y) {
+        this.x = x;
+        this.y = y;
+    }
+
+    @Override
+    public int staticSize() {
+        return STATIC_SIZE;
+    }
+
+    @Override
+    public void writeComponent(SimpleBuffers.Builder b, int pos) {
+        b.putFloat(pos + 0, this.x);
+        b.putFloat(pos + 4, this.y);
+    }
+}
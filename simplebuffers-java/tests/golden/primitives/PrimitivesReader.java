@@ -0,0 +1,65 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class PrimitivesReader {
+    public static final int STATIC_SIZE = 45;
+
+    private final java.nio.ByteBuffer buf;
+    private final int pos;
+
+    public PrimitivesReader(java.nio.ByteBuffer buf, int pos) {
+        this.buf = SimpleBuffers.littleEndian(buf);
+        this.pos = pos;
+    }
+
+    public PrimitivesReader(byte[] data) {
+        this(SimpleBuffers.wrap(data), 0);
+    }
+
+    public short a() {
+        return (short) (buf.get(pos + 0) & 0xFF);
+    }
+
+    public int b() {
+        return buf.getShort(pos + 1) & 0xFFFF;
+    }
+
+    public long c() {
+        return buf.getInt(pos + 3) & 0xFFFFFFFFL;
+    }
+
+    public long d() {
+        return buf.getLong(pos + 7);
+    }
+
+    public byte e() {
+        return buf.get(pos + 15);
+    }
+
+    public short f() {
+        return buf.getShort(pos + 16);
+    }
+
+    public int g() {
+        return buf.getInt(pos + 18);
+    }
+
+    public long h() {
+        return buf.getLong(pos + 22);
+    }
+
+    public float i() {
+        return buf.getFloat(pos + 30);
+    }
+
+    public double j() {
+        return buf.getDouble(pos + 34);
+    }
+
+    public boolean k() {
+        return buf.get(pos + 42) != 0;
+    }
+
+    public java.lang.String name() {
+        return SimpleBuffers.readString(buf, pos + 43);
+    }
+}
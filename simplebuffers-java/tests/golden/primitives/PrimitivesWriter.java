@@ -0,0 +1,54 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public final class PrimitivesWriter implements SimpleBuffers.Writer {
+    public static final int STATIC_SIZE = 45;
+
+    public short a;
+    public int b;
+    public long c;
+    public long d;
+    public byte e;
+    public short f;
+    public int g;
+    public long h;
+    public float i;
+    public double j;
+    public boolean k;
+    public java.lang.String name;
+
+    public PrimitivesWriter(short a, int b, long c, long d, byte e, short f, int g, long h, float i, double j, boolean k, java.lang.String name) {
+        this.a = a;
+        this.b = b;
+        this.c = c;
+        this.d = d;
+        this.e = e;
+        this.f = f;
+        this.g = g;
+        this.h = h;
+        this.i = i;
+        this.j = j;
+        this.k = k;
+        this.name = name;
+    }
+
+    @Override
+    public int staticSize() {
+        return STATIC_SIZE;
+    }
+
+    @Override
+    public void writeComponent(SimpleBuffers.Builder b, int pos) {
+        b.putByte(pos + 0, (byte) this.a);
+        b.putShort(pos + 1, (short) this.b);
+        b.putInt(pos + 3, (int) this.c);
+        b.putLong(pos + 7, this.d);
+        b.putByte(pos + 15, this.e);
+        b.putShort(pos + 16, this.f);
+        b.putInt(pos + 18, this.g);
+        b.putLong(pos + 22, this.h);
+        b.putFloat(pos + 30, this.i);
+        b.putDouble(pos + 34, this.j);
+        b.putBool(pos + 42, this.k);
+        b.putString(pos + 43, this.name);
+    }
+}
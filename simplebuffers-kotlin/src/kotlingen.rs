@@ -0,0 +1,598 @@
+//! Generates a Kotlin source file.
+
+use convert_case::{Case, Casing};
+use indent::indent_by;
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+use std::collections::HashMap;
+
+/// The comment placed at the top of every generated file.
+const HEADER: &str =
+    "// This file was generated by the SimpleBuffers compiler. Do not edit it manually.";
+
+/// Where a value is stored. This determines how sequences are laid out.
+#[derive(Clone, Copy)]
+enum Context {
+    /// The value is a field of a sequence. Sequences are stored behind a 16-bit offset.
+    Slot,
+
+    /// The value is a list element or the target of a oneof. Sequences are stored inline.
+    Element,
+}
+
+/// Information about the schema that is needed while generating code.
+struct KotlinGen<'a> {
+    /// The static size of every sequence, by name.
+    sequence_sizes: HashMap<&'a str, usize>,
+}
+
+//                                                                                                //
+// ======================================= Main Function ======================================== //
+//                                                                                                //
+
+/// Generates a Kotlin source file from a given schema.
+///
+/// # Arguments
+///
+/// * `package` - The Kotlin package to generate declarations in, or an empty string for the
+///   default package.
+/// * `schema` - The schema to generate from.
+///
+/// # Returns
+///
+/// The code for a Kotlin source file, as a String.
+pub(crate) fn generate_kotlin(package: &str, schema: &SBSchema) -> String {
+    let gen = KotlinGen {
+        sequence_sizes: schema
+            .sequences
+            .iter()
+            .map(|s| (s.name.as_str(), s.static_size()))
+            .collect(),
+    };
+
+    let declarations = schema
+        .enums
+        .iter()
+        .map(define_enum)
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| gen.define_sequence_writer(s)),
+        )
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| gen.define_sequence_reader(s)),
+        )
+        .join("\n\n");
+
+    format!(
+        "{HEADER}\n\n{}import java.nio.ByteBuffer\nimport java.nio.ByteOrder\n\n{declarations}\n",
+        package_decl(package)
+    )
+}
+
+/// Returns the source of the runtime that generated files depend on.
+pub(crate) fn runtime(package: &str) -> String {
+    format!(
+        "{HEADER}\n\n{}{}\n",
+        package_decl(package),
+        include_str!("../corelib/SimpleBuffers.kt").trim()
+    )
+}
+
+/// Returns the package declaration placed at the top of every file, if any.
+fn package_decl(package: &str) -> String {
+    if package.is_empty() {
+        String::new()
+    } else {
+        format!("package {}\n\n", package)
+    }
+}
+
+/// Returns the name of the writer class for a sequence.
+fn writer_name(seq_name: &str) -> String {
+    format!("{}Writer", seq_name).to_case(Case::Pascal)
+}
+
+/// Returns the name of the reader class for a sequence.
+fn reader_name(seq_name: &str) -> String {
+    format!("{}Reader", seq_name).to_case(Case::Pascal)
+}
+
+/// Returns the Kotlin type that matches a primitive.
+fn primitive_type(p: &Primitive) -> &'static str {
+    match p {
+        Primitive::Bool => "Boolean",
+        Primitive::U8 => "UByte",
+        Primitive::U16 => "UShort",
+        Primitive::U32 => "UInt",
+        Primitive::U64 => "ULong",
+        Primitive::I8 => "Byte",
+        Primitive::I16 => "Short",
+        Primitive::I32 => "Int",
+        Primitive::I64 => "Long",
+        Primitive::F32 => "Float",
+        Primitive::F64 => "Double",
+    }
+}
+
+/// Returns an expression that reads a primitive at `pos` in `buf`.
+fn read_primitive(p: &Primitive, pos: &str) -> String {
+    match p {
+        Primitive::Bool => format!("buf.get({pos}) != 0.toByte()"),
+        Primitive::U8 => format!("buf.get({pos}).toUByte()"),
+        Primitive::U16 => format!("buf.getShort({pos}).toUShort()"),
+        Primitive::U32 => format!("buf.getInt({pos}).toUInt()"),
+        Primitive::U64 => format!("buf.getLong({pos}).toULong()"),
+        Primitive::I8 => format!("buf.get({pos})"),
+        Primitive::I16 => format!("buf.getShort({pos})"),
+        Primitive::I32 => format!("buf.getInt({pos})"),
+        Primitive::I64 => format!("buf.getLong({pos})"),
+        Primitive::F32 => format!("buf.getFloat({pos})"),
+        Primitive::F64 => format!("buf.getDouble({pos})"),
+    }
+}
+
+/// Returns a statement that writes a primitive at `pos` using the builder `b`.
+fn write_primitive(p: &Primitive, value: &str, pos: &str) -> String {
+    match p {
+        Primitive::Bool => format!("b.putBool({pos}, {value})"),
+        Primitive::U8 => format!("b.putByte({pos}, {value}.toByte())"),
+        Primitive::U16 => format!("b.putShort({pos}, {value}.toShort())"),
+        Primitive::U32 => format!("b.putInt({pos}, {value}.toInt())"),
+        Primitive::U64 => format!("b.putLong({pos}, {value}.toLong())"),
+        Primitive::I8 => format!("b.putByte({pos}, {value})"),
+        Primitive::I16 => format!("b.putShort({pos}, {value})"),
+        Primitive::I32 => format!("b.putInt({pos}, {value})"),
+        Primitive::I64 => format!("b.putLong({pos}, {value})"),
+        Primitive::F32 => format!("b.putFloat({pos}, {value})"),
+        Primitive::F64 => format!("b.putDouble({pos}, {value})"),
+    }
+}
+
+/// Returns an expression that reads the raw value of an enum of the given size as a `Long`.
+fn read_enum_value(size: usize, pos: &str) -> String {
+    match size {
+        1 => format!("buf.get({pos}).toLong() and 0xFF"),
+        2 => format!("buf.getShort({pos}).toLong() and 0xFFFF"),
+        4 => format!("buf.getInt({pos}).toLong() and 0xFFFFFFFFL"),
+        8 => format!("buf.getLong({pos})"),
+        _ => panic!("Cannot convert size {} to Kotlin type", size),
+    }
+}
+
+/// Returns a statement that writes the raw value of an enum of the given size.
+fn write_enum_value(size: usize, value: &str, pos: &str) -> String {
+    match size {
+        1 => format!("b.putByte({pos}, {value}.value.toByte())"),
+        2 => format!("b.putShort({pos}, {value}.value.toShort())"),
+        4 => format!("b.putInt({pos}, {value}.value.toInt())"),
+        8 => format!("b.putLong({pos}, {value}.value)"),
+        _ => panic!("Cannot convert size {} to Kotlin type", size),
+    }
+}
+
+/// Generates the Kotlin code for defining an enum. Like the Java generator, each variant stores
+/// its value in a `Long`, and values that do not fit in a signed 64-bit integer keep their bit
+/// pattern.
+fn define_enum(data: &Enum) -> String {
+    let name = data.name.to_case(Case::Pascal);
+    let variants = data
+        .variants
+        .iter()
+        .map(|v| format!("{}({}L)", v.name.to_case(Case::UpperSnake), v.value as i64))
+        .join(",\n");
+
+    formatdoc! {
+        r"
+        enum class {name}(val value: Long) {{
+            {variants};
+
+            companion object {{
+                /** Returns the variant with the given value, or `null` if there is none. */
+                fun fromValue(value: Long): {name}? = values().firstOrNull {{ it.value == value }}
+            }}
+        }}",
+        variants = indent_by(4, variants)
+    }
+}
+
+/// Returns the fields of the oneof contained in a type, looking through lists.
+fn find_oneof(ty: &Type) -> Option<&[Field]> {
+    match ty {
+        Type::OneOf(fields) => Some(fields),
+        Type::Array(t) => find_oneof(t),
+        _ => None,
+    }
+}
+
+/// Returns the name of the class that represents one field of a oneof. The `Case` suffix keeps
+/// classes from shadowing enums with the same name as the field.
+fn case_name(field_name: &str) -> String {
+    format!("{}Case", field_name.to_case(Case::Pascal))
+}
+
+/// Returns the name of the interface that represents a oneof field. Oneofs nested in other oneofs
+/// are prefixed with the name of their parent, since every oneof interface is declared directly in
+/// the reader or writer class.
+fn oneof_name(prefix: &str, field_name: &str) -> String {
+    format!("{}{}", prefix, field_name.to_case(Case::Pascal))
+}
+
+/// Wraps the members of a declaration in braces.
+fn declare(header: &str, body: &str) -> String {
+    formatdoc! {
+        r"
+        {header} {{
+            {body}
+        }}",
+        body = indent_by(4, body.trim())
+    }
+}
+
+/// Generates the companion object that holds the static size of a sequence.
+fn companion(static_size: usize) -> String {
+    formatdoc! {
+        r"
+        companion object {{
+            const val STATIC_SIZE = {static_size}
+        }}"
+    }
+}
+
+impl<'a> KotlinGen<'a> {
+    /// Returns the number of bytes a type takes up as a list element or oneof target.
+    fn element_size(&self, ty: &Type) -> usize {
+        match ty {
+            Type::Sequence(s) => self.sequence_sizes[s.as_str()],
+            _ => ty.size(),
+        }
+    }
+
+    //                                                                                            //
+    // =============================== Generate Writer Components =============================== //
+    //                                                                                            //
+
+    /// Returns the Kotlin type used to write a field. `oneof` is the name of the interface that
+    /// represents the field if it contains a oneof.
+    fn writer_type(&self, ty: &Type, oneof: &str) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p).to_string(),
+            Type::Sequence(s) => writer_name(s),
+            Type::Enum(e, _) => e.to_case(Case::Pascal),
+            Type::Array(t) => format!("List<{}>", self.writer_type(t, oneof)),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "String".to_string(),
+            Type::OneOf(_) => oneof.to_string(),
+        }
+    }
+
+    /// Returns a statement that writes `value` at `pos` using the builder `b`. `depth` is used to
+    /// give lambda parameters unique names.
+    fn write_stmt(&self, ty: &Type, ctx: Context, value: &str, pos: &str, depth: usize) -> String {
+        match ty {
+            Type::Primitive(p) => write_primitive(p, value, pos),
+            Type::Enum(_, size) => write_enum_value(*size, value, pos),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("b.putString({pos}, {value})"),
+            Type::Array(t) => formatdoc! {
+                r"
+                b.putList({pos}, {value}, {stride}) {{ p{depth}, e{depth} ->
+                    {write}
+                }}",
+                stride = self.element_size(t),
+                write = indent_by(
+                    4,
+                    self.write_stmt(
+                        t,
+                        Context::Element,
+                        &format!("e{depth}"),
+                        &format!("p{depth}"),
+                        depth + 1
+                    )
+                ),
+            },
+            Type::Sequence(s) => match ctx {
+                Context::Slot => format!(
+                    "{value}.writeComponent(b, b.reserve({pos}, {pos}, {size}))",
+                    size = self.sequence_sizes[s.as_str()],
+                ),
+                Context::Element => format!("{value}.writeComponent(b, {pos})"),
+            },
+            Type::OneOf(_) => format!("{value}.writeComponent(b, {pos})"),
+        }
+    }
+
+    /// Generates the Kotlin code for defining a sequence writer.
+    fn define_sequence_writer(&self, seq: &Sequence) -> String {
+        let class_name = writer_name(&seq.name);
+        let static_size = self.sequence_sizes[seq.name.as_str()];
+
+        let params = seq
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "var {}: {},",
+                    f.name.to_case(Case::Camel),
+                    self.writer_type(&f.ty, &oneof_name("", &f.name))
+                )
+            })
+            .join("\n");
+
+        let write_fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                self.write_stmt(
+                    &f.ty,
+                    Context::Slot,
+                    &format!("this.{}", f.name.to_case(Case::Camel)),
+                    &format!("pos + {}", f.index),
+                    1,
+                )
+            })
+            .join("\n");
+
+        let body = [
+            formatdoc! {
+                r"
+                override val staticSize: Int
+                    get() = STATIC_SIZE
+
+                override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {{
+                    {write_fields}
+                }}",
+                write_fields = indent_by(4, write_fields),
+            },
+            self.oneof_writers("", &seq.fields),
+            companion(static_size),
+        ]
+        .iter()
+        .filter(|s| !s.is_empty())
+        .join("\n\n");
+
+        declare(
+            &formatdoc! {
+                r"
+                class {class_name}(
+                    {params}
+                ) : SimpleBuffers.Writer",
+                params = indent_by(4, params),
+            },
+            &body,
+        )
+    }
+
+    /// Generates writers for every oneof found in a list of fields, including oneofs nested in
+    /// lists and in other oneofs.
+    fn oneof_writers(&self, prefix: &str, fields: &[Field]) -> String {
+        fields
+            .iter()
+            .filter_map(|f| {
+                find_oneof(&f.ty).map(|o| self.define_oneof_writer(&oneof_name(prefix, &f.name), o))
+            })
+            .join("\n\n")
+    }
+
+    /// Generates the Kotlin code for defining a oneof writer. Oneofs are written as sealed
+    /// interfaces with one data class per field.
+    fn define_oneof_writer(&self, name: &str, subfields: &[Field]) -> String {
+        let cases = subfields
+            .iter()
+            .map(|f| {
+                formatdoc! {
+                    r"
+                    data class {case}(val value: {ty}) : {name} {{
+                        override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {{
+                            b.putByte(pos, {index})
+                            val p = b.reserve(pos + 1, pos, {size})
+                            {write}
+                        }}
+                    }}",
+                    case = case_name(&f.name),
+                    ty = self.writer_type(&f.ty, &oneof_name(name, &f.name)),
+                    index = f.index,
+                    size = self.element_size(&f.ty),
+                    write = indent_by(8, self.write_stmt(&f.ty, Context::Element, "value", "p", 1)),
+                }
+            })
+            .join("\n\n");
+
+        let nested = self.oneof_writers(name, subfields);
+
+        formatdoc! {
+            r"
+            sealed interface {name} {{
+                fun writeComponent(b: SimpleBuffers.Builder, pos: Int)
+
+                {cases}
+            }}
+
+            {nested}",
+            cases = indent_by(4, cases),
+        }
+        .trim()
+        .to_string()
+    }
+
+    //                                                                                            //
+    // =============================== Generate Reader Components =============================== //
+    //                                                                                            //
+
+    /// Returns the Kotlin type returned when reading a field. `oneof` is the name of the interface
+    /// that represents the field if it contains a oneof.
+    fn reader_type(&self, ty: &Type, oneof: &str) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p).to_string(),
+            Type::Sequence(s) => reader_name(s),
+            Type::Enum(e, _) => format!("{}?", e.to_case(Case::Pascal)),
+            Type::Array(t) => format!("SimpleBuffers.ListReader<{}>", self.reader_type(t, oneof)),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "String".to_string(),
+            Type::OneOf(_) => format!("{oneof}?"),
+        }
+    }
+
+    /// Returns an expression that reads a value of the given type at `pos` in `buf`. `depth` is
+    /// used to give lambda parameters unique names.
+    fn read_expr(&self, ty: &Type, oneof: &str, ctx: Context, pos: &str, depth: usize) -> String {
+        match ty {
+            Type::Primitive(p) => read_primitive(p, pos),
+            Type::Enum(e, size) => format!(
+                "{}.fromValue({})",
+                e.to_case(Case::Pascal),
+                read_enum_value(*size, pos)
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("SimpleBuffers.readString(buf, {pos})"),
+            Type::Array(t) => format!(
+                "SimpleBuffers.ListReader(buf, {pos}, {stride}) {{ p{depth} -> {read} }}",
+                stride = self.element_size(t),
+                read = self.read_expr(t, oneof, Context::Element, &format!("p{depth}"), depth + 1),
+            ),
+            Type::Sequence(s) => match ctx {
+                Context::Slot => {
+                    format!("{}(buf, SimpleBuffers.follow(buf, {pos}))", reader_name(s))
+                }
+                Context::Element => format!("{}(buf, {pos})", reader_name(s)),
+            },
+            Type::OneOf(_) => format!("{oneof}.read(buf, {pos})"),
+        }
+    }
+
+    /// Generates the Kotlin code for defining a sequence reader. Readers are data classes that
+    /// hold a buffer and a position, and read fields lazily.
+    fn define_sequence_reader(&self, seq: &Sequence) -> String {
+        let class_name = reader_name(&seq.name);
+        let static_size = self.sequence_sizes[seq.name.as_str()];
+
+        let fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                let oneof = oneof_name("", &f.name);
+                formatdoc! {
+                    r"
+                    val {name}: {ty}
+                        get() = {read}",
+                    ty = self.reader_type(&f.ty, &oneof),
+                    name = f.name.to_case(Case::Camel),
+                    read = self.read_expr(
+                        &f.ty,
+                        &oneof,
+                        Context::Slot,
+                        &format!("position + {}", f.index),
+                        1
+                    ),
+                }
+            })
+            .join("\n\n");
+
+        let body = [
+            formatdoc! {
+                r#"
+                constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+                init {{
+                    require(buf.order() == ByteOrder.LITTLE_ENDIAN) {{ "Buffer must be little-endian" }}
+                }}"#
+            },
+            fields,
+            self.oneof_readers("", &seq.fields),
+            companion(static_size),
+        ]
+        .iter()
+        .filter(|s| !s.is_empty())
+        .join("\n\n");
+
+        declare(
+            &format!("data class {class_name}(val buf: ByteBuffer, val position: Int = 0)"),
+            &body,
+        )
+    }
+
+    /// Generates readers for every oneof found in a list of fields, including oneofs nested in
+    /// lists and in other oneofs.
+    fn oneof_readers(&self, prefix: &str, fields: &[Field]) -> String {
+        fields
+            .iter()
+            .filter_map(|f| {
+                find_oneof(&f.ty).map(|o| self.define_oneof_reader(&oneof_name(prefix, &f.name), o))
+            })
+            .join("\n\n")
+    }
+
+    /// Generates the Kotlin code for defining a oneof reader. The active class is chosen by the
+    /// tag stored in the buffer. Unknown tags produce `null`.
+    fn define_oneof_reader(&self, name: &str, subfields: &[Field]) -> String {
+        let cases = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "data class {}(val value: {}) : {name}",
+                    case_name(&f.name),
+                    self.reader_type(&f.ty, &oneof_name(name, &f.name))
+                )
+            })
+            .join("\n");
+
+        let when_cases = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{index} -> {case}({read})",
+                    index = f.index,
+                    case = case_name(&f.name),
+                    read =
+                        self.read_expr(&f.ty, &oneof_name(name, &f.name), Context::Element, "p", 1),
+                )
+            })
+            .join("\n");
+
+        let nested = self.oneof_readers(name, subfields);
+
+        formatdoc! {
+            r"
+            sealed interface {name} {{
+                {cases}
+
+                companion object {{
+                    fun read(buf: ByteBuffer, pos: Int): {name}? {{
+                        val p = pos + (buf.getShort(pos + 1).toInt() and 0xFFFF)
+                        return when (buf.get(pos).toInt() and 0xFF) {{
+                            {when_cases}
+                            else -> null
+                        }}
+                    }}
+                }}
+            }}
+
+            {nested}",
+            cases = indent_by(4, cases),
+            when_cases = indent_by(16, when_cases),
+        }
+        .trim()
+        .to_string()
+    }
+}
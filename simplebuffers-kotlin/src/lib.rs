@@ -0,0 +1,143 @@
+//! Kotlin code generator.
+//!
+//! This module implements Kotlin code generation for SimpleBuffers schemas. It produces two files:
+//!
+//! - `SimpleBuffers.kt`, a small runtime with helpers for reading and writing little-endian values
+//!   through `java.nio.ByteBuffer`.
+//! - `{file_name}.kt`, which contains the declarations generated from the schema.
+//!
+//! The generated file contains:
+//!
+//! - A Kotlin `enum class` for every schema enum.
+//! - A writer class for every sequence, which serializes into a `ByteArray`.
+//! - A reader data class for every sequence, which holds a `ByteBuffer` and a position and reads
+//!   fields lazily.
+//!
+//! Oneofs are represented as sealed interfaces, with one data class (for example,
+//! `Payload.InitCase`) per oneof field. Readers select the class based on the tag stored in the
+//! buffer.
+//!
+//! The enum and oneof mapping mirrors the Java generator, so code generated by both can be used
+//! side by side on the JVM. Unlike Java, Kotlin has unsigned integer types, which are used for
+//! unsigned fields.
+//!
+//! Files are generated in the package given by `--package` and written to the matching directory
+//! structure under the destination directory.
+
+mod argparse;
+mod kotlingen;
+
+use std::path::PathBuf;
+
+use argparse::{command, parse_args};
+use kotlingen::{generate_kotlin, runtime};
+use simplebuffers_codegen::{register_generators, CodeGenerator, FileSink};
+
+#[derive(Debug)]
+pub struct KotlinCodeGenerator;
+
+impl CodeGenerator for KotlinCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        if schema.uses_fixed_arrays() {
+            return Err("The Kotlin generator does not support fixed-size arrays".to_string());
+        }
+        if schema.uses_bytes() {
+            return Err("The Kotlin generator does not support byte buffers".to_string());
+        }
+        if schema.uses_maps() {
+            return Err("The Kotlin generator does not support maps".to_string());
+        }
+
+        let params = parse_args(params);
+
+        let mut dir = PathBuf::from(&params.global.dest_dir);
+        if !params.package.is_empty() {
+            for segment in params.package.split('.') {
+                if segment.is_empty() || !segment.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    return Err(format!("Invalid Kotlin package name: {}", params.package));
+                }
+                dir.push(segment);
+            }
+        }
+        let runtime_path = dir.join("SimpleBuffers.kt");
+        sink.write_file(&runtime_path, &runtime(&params.package))?;
+        let source_path = dir.join(format!("{}.kt", params.global.file_name));
+        sink.write_file(&source_path, &generate_kotlin(&params.package, schema))?;
+
+        Ok(vec![runtime_path, source_path])
+    }
+
+    fn reserved_identifiers(
+        &mut self,
+        _params: &simplebuffers_codegen::GeneratorParams,
+    ) -> Vec<String> {
+        vec![
+            // Names used by the generated code.
+            "SimpleBuffers",
+            "staticSize",
+            "writeComponent",
+            "encode",
+            "fromValue",
+            "buf",
+            "position",
+            // Hard keywords.
+            "as",
+            "break",
+            "class",
+            "continue",
+            "do",
+            "else",
+            "false",
+            "for",
+            "fun",
+            "if",
+            "in",
+            "interface",
+            "is",
+            "null",
+            "object",
+            "package",
+            "return",
+            "super",
+            "this",
+            "throw",
+            "true",
+            "try",
+            "typealias",
+            "typeof",
+            "val",
+            "var",
+            "when",
+            "while",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["kt".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "Kotlin code and the SimpleBuffers runtime".to_string()
+    }
+
+    fn cli(&self) -> Option<clap::Command> {
+        Some(command())
+    }
+}
+
+register_generators!(kotlin: KotlinCodeGenerator);
@@ -0,0 +1,11 @@
+//! Golden tests for the Kotlin generator. See `simplebuffers-golden` for how they work.
+
+use simplebuffers_golden::{golden_tests, Layout};
+use simplebuffers_kotlin::KotlinCodeGenerator;
+
+golden_tests! {
+    generator: KotlinCodeGenerator,
+    args: ["kotlin"],
+    layout: Layout::Directory,
+    cases: [primitives, enums, nested, oneofs, lists, oneof_arrays],
+}
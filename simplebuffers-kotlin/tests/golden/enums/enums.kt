@@ -0,0 +1,74 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+import java.nio.ByteBuffer
+import java.nio.ByteOrder
+
+enum class Small(val value: Long) {
+    A(0L),
+    B(1L);
+
+    companion object {
+        /** Returns the variant with the given value, or `null` if there is none. */
+        fun fromValue(value: Long): Small? = values().firstOrNull { it.value == value }
+    }
+}
+
+enum class Signed(val value: Long) {
+    LOW(-1L),
+    HIGH(1L);
+
+    companion object {
+        /** Returns the variant with the given value, or `null` if there is none. */
+        fun fromValue(value: Long): Signed? = values().firstOrNull { it.value == value }
+    }
+}
+
+enum class Wide(val value: Long) {
+    FIRST(0L),
+    LAST(100000L);
+
+    companion object {
+        /** Returns the variant with the given value, or `null` if there is none. */
+        fun fromValue(value: Long): Wide? = values().firstOrNull { it.value == value }
+    }
+}
+
+class EnumsWriter(
+    var small: Small,
+    var signed: Signed,
+    var wide: Wide,
+) : SimpleBuffers.Writer {
+    override val staticSize: Int
+        get() = STATIC_SIZE
+
+    override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+        b.putByte(pos + 0, this.small.value.toByte())
+        b.putByte(pos + 1, this.signed.value.toByte())
+        b.putInt(pos + 2, this.wide.value.toInt())
+    }
+
+    companion object {
+        const val STATIC_SIZE = 6
+    }
+}
+
+data class EnumsReader(val buf: ByteBuffer, val position: Int = 0) {
+    constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+    init {
+        require(buf.order() == ByteOrder.LITTLE_ENDIAN) { "Buffer must be little-endian" }
+    }
+
+    val small: Small?
+        get() = Small.fromValue(buf.get(position + 0).toLong() and 0xFF)
+
+    val signed: Signed?
+        get() = Signed.fromValue(buf.get(position + 1).toLong() and 0xFF)
+
+    val wide: Wide?
+        get() = Wide.fromValue(buf.getInt(position + 2).toLong() and 0xFFFFFFFFL)
+
+    companion object {
+        const val STATIC_SIZE = 6
+    }
+}
@@ -0,0 +1,111 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+import java.nio.ByteBuffer
+import java.nio.ByteOrder
+
+enum class Color(val value: Long) {
+    RED(0L),
+    GREEN(1L);
+
+    companion object {
+        /** Returns the variant with the given value, or `null` if there is none. */
+        fun fromValue(value: Long): Color? = values().firstOrNull { it.value == value }
+    }
+}
+
+class ListsWriter(
+    var numbers: List<UShort>,
+    var words: List<String>,
+    var colors: List<Color>,
+    var points: List<PointWriter>,
+    var matrix: List<List<Int>>,
+) : SimpleBuffers.Writer {
+    override val staticSize: Int
+        get() = STATIC_SIZE
+
+    override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+        b.putList(pos + 0, this.numbers, 2) { p1, e1 ->
+            b.putShort(p1, e1.toShort())
+        }
+        b.putList(pos + 4, this.words, 2) { p1, e1 ->
+            b.putString(p1, e1)
+        }
+        b.putList(pos + 8, this.colors, 1) { p1, e1 ->
+            b.putByte(p1, e1.value.toByte())
+        }
+        b.putList(pos + 12, this.points, 4) { p1, e1 ->
+            e1.writeComponent(b, p1)
+        }
+        b.putList(pos + 16, this.matrix, 4) { p1, e1 ->
+            b.putList(p1, e1, 4) { p2, e2 ->
+                b.putInt(p2, e2)
+            }
+        }
+    }
+
+    companion object {
+        const val STATIC_SIZE = 20
+    }
+}
+
+class PointWriter(
+    var x: Short,
+    var y: Short,
+) : SimpleBuffers.Writer {
+    override val staticSize: Int
+        get() = STATIC_SIZE
+
+    override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+        b.putShort(pos + 0, this.x)
+        b.putShort(pos + 2, this.y)
+    }
+
+    companion object {
+        const val STATIC_SIZE = 4
+    }
+}
+
+data class ListsReader(val buf: ByteBuffer, val position: Int = 0) {
+    constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+    init {
+        require(buf.order() == ByteOrder.LITTLE_ENDIAN) { "Buffer must be little-endian" }
+    }
+
+    val numbers: SimpleBuffers.ListReader<UShort>
+        get() = SimpleBuffers.ListReader(buf, position + 0, 2) { p1 -> buf.getShort(p1).toUShort() }
+
+    val words: SimpleBuffers.ListReader<String>
+        get() = SimpleBuffers.ListReader(buf, position + 4, 2) { p1 -> SimpleBuffers.readString(buf, p1) }
+
+    val colors: SimpleBuffers.ListReader<Color?>
+        get() = SimpleBuffers.ListReader(buf, position + 8, 1) { p1 -> Color.fromValue(buf.get(p1).toLong() and 0xFF) }
+
+    val points: SimpleBuffers.ListReader<PointReader>
+        get() = SimpleBuffers.ListReader(buf, position + 12, 4) { p1 -> PointReader(buf, p1) }
+
+    val matrix: SimpleBuffers.ListReader<SimpleBuffers.ListReader<Int>>
+        get() = SimpleBuffers.ListReader(buf, position + 16, 4) { p1 -> SimpleBuffers.ListReader(buf, p1, 4) { p2 -> buf.getInt(p2) } }
+
+    companion object {
+        const val STATIC_SIZE = 20
+    }
+}
+
+data class PointReader(val buf: ByteBuffer, val position: Int = 0) {
+    constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+    init {
+        require(buf.order() == ByteOrder.LITTLE_ENDIAN) { "Buffer must be little-endian" }
+    }
+
+    val x: Short
+        get() = buf.getShort(position + 0)
+
+    val y: Short
+        get() = buf.getShort(position + 2)
+
+    companion object {
+        const val STATIC_SIZE = 4
+    }
+}
@@ -0,0 +1,135 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+import java.nio.ByteBuffer
+import java.nio.ByteOrder
+
+/**
+ * Runtime support for generated SimpleBuffers readers and writers.
+ *
+ * All multi-byte values are stored in little-endian byte order. Offsets to dynamic data are
+ * unsigned 16-bit values relative to the position of the field that holds them.
+ */
+object SimpleBuffers {
+    /** Wraps a byte array in a little-endian buffer that generated readers can use. */
+    fun wrap(data: ByteArray): ByteBuffer = ByteBuffer.wrap(data).order(ByteOrder.LITTLE_ENDIAN)
+
+    /** Follows the 16-bit offset stored at [pos] and returns the position it points to. */
+    fun follow(buf: ByteBuffer, pos: Int): Int = pos + (buf.getShort(pos).toInt() and 0xFFFF)
+
+    /** Reads a null-terminated string whose offset is stored at [pos]. */
+    fun readString(buf: ByteBuffer, pos: Int): String {
+        val start = follow(buf, pos)
+        var end = start
+        while (end < buf.limit() && buf.get(end) != 0.toByte()) {
+            end++
+        }
+        val bytes = ByteArray(end - start) { buf.get(start + it) }
+        return String(bytes, Charsets.UTF_8)
+    }
+
+    /** A read-only view over a list stored in a buffer. */
+    class ListReader<T>(
+        buf: ByteBuffer,
+        pos: Int,
+        private val stride: Int,
+        private val reader: (Int) -> T,
+    ) : AbstractList<T>() {
+        override val size: Int = buf.getShort(pos).toInt() and 0xFFFF
+        private val start: Int = pos + (buf.getShort(pos + 2).toInt() and 0xFFFF)
+
+        override fun get(index: Int): T {
+            if (index < 0 || index >= size) {
+                throw IndexOutOfBoundsException("Index $index is out of bounds for length $size")
+            }
+            return reader(start + index * stride)
+        }
+    }
+
+    /** A growable buffer that generated writers serialize into. */
+    class Builder(staticSize: Int) {
+        private var buf: ByteBuffer =
+            ByteBuffer.allocate(maxOf(64, staticSize)).order(ByteOrder.LITTLE_ENDIAN)
+
+        /** The number of bytes written so far. */
+        var size: Int = staticSize
+            private set
+
+        /**
+         * Reserves [count] zeroed bytes at the end of the buffer and stores the offset to them
+         * (relative to [base]) at [slot]. Returns the position of the reserved bytes.
+         */
+        fun reserve(slot: Int, base: Int, count: Int): Int {
+            val start = size
+            if (size + count > buf.capacity()) {
+                val grown = ByteBuffer.allocate(maxOf(size + count, buf.capacity() * 2))
+                grown.order(ByteOrder.LITTLE_ENDIAN)
+                grown.put(buf.array(), 0, size)
+                buf = grown
+            }
+            size += count
+            putShort(slot, (start - base).toShort())
+            return start
+        }
+
+        fun putByte(pos: Int, value: Byte) {
+            buf.put(pos, value)
+        }
+
+        fun putBool(pos: Int, value: Boolean) {
+            buf.put(pos, (if (value) 1 else 0).toByte())
+        }
+
+        fun putShort(pos: Int, value: Short) {
+            buf.putShort(pos, value)
+        }
+
+        fun putInt(pos: Int, value: Int) {
+            buf.putInt(pos, value)
+        }
+
+        fun putLong(pos: Int, value: Long) {
+            buf.putLong(pos, value)
+        }
+
+        fun putFloat(pos: Int, value: Float) {
+            buf.putFloat(pos, value)
+        }
+
+        fun putDouble(pos: Int, value: Double) {
+            buf.putDouble(pos, value)
+        }
+
+        /** Writes a null-terminated string to the end of the buffer and stores its offset at [pos]. */
+        fun putString(pos: Int, value: String) {
+            val bytes = value.toByteArray(Charsets.UTF_8)
+            val start = reserve(pos, pos, bytes.size + 1)
+            bytes.forEachIndexed { i, byte -> buf.put(start + i, byte) }
+        }
+
+        /** Writes a list header at [pos] and the list elements to the end of the buffer. */
+        fun <T> putList(pos: Int, values: List<T>, stride: Int, writer: (Int, T) -> Unit) {
+            putShort(pos, values.size.toShort())
+            val start = reserve(pos + 2, pos, values.size * stride)
+            values.forEachIndexed { i, value -> writer(start + i * stride, value) }
+        }
+
+        /** Returns a copy of the bytes written so far. */
+        fun toByteArray(): ByteArray = buf.array().copyOf(size)
+    }
+
+    /** A component that can be serialized into a [Builder]. */
+    interface Writer {
+        /** The size of the component's static data. */
+        val staticSize: Int
+
+        /** Writes static data at [pos] and dynamic data at the end of the builder. */
+        fun writeComponent(b: Builder, pos: Int)
+
+        /** Serializes the component into a new byte array. */
+        fun encode(): ByteArray {
+            val b = Builder(staticSize)
+            writeComponent(b, 0)
+            return b.toByteArray()
+        }
+    }
+}
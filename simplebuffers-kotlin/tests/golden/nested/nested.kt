@@ -0,0 +1,109 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+import java.nio.ByteBuffer
+import java.nio.ByteOrder
+
+class OuterWriter(
+    var id: UInt,
+    var inner: InnerWriter,
+) : SimpleBuffers.Writer {
+    override val staticSize: Int
+        get() = STATIC_SIZE
+
+    override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+        b.putInt(pos + 0, this.id.toInt())
+        this.inner.writeComponent(b, b.reserve(pos + 4, pos + 4, 4))
+    }
+
+    companion object {
+        const val STATIC_SIZE = 6
+    }
+}
+
+class InnerWriter(
+    var point: PointWriter,
+    var label: String,
+) : SimpleBuffers.Writer {
+    override val staticSize: Int
+        get() = STATIC_SIZE
+
+    override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+        this.point.writeComponent(b, b.reserve(pos + 0, pos + 0, 4))
+        b.putString(pos + 2, this.label)
+    }
+
+    companion object {
+        const val STATIC_SIZE = 4
+    }
+}
+
+class PointWriter(
+    var x: Short,
+    var y: Short,
+) : SimpleBuffers.Writer {
+    override val staticSize: Int
+        get() = STATIC_SIZE
+
+    override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+        b.putShort(pos + 0, this.x)
+        b.putShort(pos + 2, this.y)
+    }
+
+    companion object {
+        const val STATIC_SIZE = 4
+    }
+}
+
+data class OuterReader(val buf: ByteBuffer, val position: Int = 0) {
+    constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+    init {
+        require(buf.order() == ByteOrder.LITTLE_ENDIAN) { "Buffer must be little-endian" }
+    }
+
+    val id: UInt
+        get() = buf.getInt(position + 0).toUInt()
+
+    val inner: InnerReader
+        get() = InnerReader(buf, SimpleBuffers.follow(buf, position + 4))
+
+    companion object {
+        const val STATIC_SIZE = 6
+    }
+}
+
+data class InnerReader(val buf: ByteBuffer, val position: Int = 0) {
+    constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+    init {
+        require(buf.order() == ByteOrder.LITTLE_ENDIAN) { "Buffer must be little-endian" }
+    }
+
+    val point: PointReader
+        get() = PointReader(buf, SimpleBuffers.follow(buf, position + 0))
+
+    val label: String
+        get() = SimpleBuffers.readString(buf, position + 2)
+
+    companion object {
+        const val STATIC_SIZE = 4
+    }
+}
+
+data class PointReader(val buf: ByteBuffer, val position: Int = 0) {
+    constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+    init {
+        require(buf.order() == ByteOrder.LITTLE_ENDIAN) { "Buffer must be little-endian" }
+    }
+
+    val x: Short
+        get() = buf.getShort(position + 0)
+
+    val y: Short
+        get() = buf.getShort(position + 2)
+
+    companion object {
+        const val STATIC_SIZE = 4
+    }
+}
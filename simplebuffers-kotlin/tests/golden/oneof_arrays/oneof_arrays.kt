@@ -0,0 +1,151 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+import java.nio.ByteBuffer
+import java.nio.ByteOrder
+
+class BatchWriter(
+    var events: List<Events>,
+    var nested: Nested,
+) : SimpleBuffers.Writer {
+    override val staticSize: Int
+        get() = STATIC_SIZE
+
+    override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+        b.putList(pos + 0, this.events, 3) { p1, e1 ->
+            e1.writeComponent(b, p1)
+        }
+        this.nested.writeComponent(b, pos + 4)
+    }
+
+    sealed interface Events {
+        fun writeComponent(b: SimpleBuffers.Builder, pos: Int)
+
+        data class IdCase(val value: UByte) : Events {
+            override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+                b.putByte(pos, 0)
+                val p = b.reserve(pos + 1, pos, 1)
+                b.putByte(p, value.toByte())
+            }
+        }
+
+        data class NameCase(val value: String) : Events {
+            override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+                b.putByte(pos, 1)
+                val p = b.reserve(pos + 1, pos, 2)
+                b.putString(p, value)
+            }
+        }
+    }
+
+    sealed interface Nested {
+        fun writeComponent(b: SimpleBuffers.Builder, pos: Int)
+
+        data class EmptyCase(val value: UByte) : Nested {
+            override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+                b.putByte(pos, 0)
+                val p = b.reserve(pos + 1, pos, 1)
+                b.putByte(p, value.toByte())
+            }
+        }
+
+        data class EventsCase(val value: List<NestedEvents>) : Nested {
+            override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+                b.putByte(pos, 1)
+                val p = b.reserve(pos + 1, pos, 4)
+                b.putList(p, value, 3) { p1, e1 ->
+                    e1.writeComponent(b, p1)
+                }
+            }
+        }
+    }
+
+    sealed interface NestedEvents {
+        fun writeComponent(b: SimpleBuffers.Builder, pos: Int)
+
+        data class SmallCase(val value: UByte) : NestedEvents {
+            override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+                b.putByte(pos, 0)
+                val p = b.reserve(pos + 1, pos, 1)
+                b.putByte(p, value.toByte())
+            }
+        }
+
+        data class LargeCase(val value: ULong) : NestedEvents {
+            override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+                b.putByte(pos, 1)
+                val p = b.reserve(pos + 1, pos, 8)
+                b.putLong(p, value.toLong())
+            }
+        }
+    }
+
+    companion object {
+        const val STATIC_SIZE = 7
+    }
+}
+
+data class BatchReader(val buf: ByteBuffer, val position: Int = 0) {
+    constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+    init {
+        require(buf.order() == ByteOrder.LITTLE_ENDIAN) { "Buffer must be little-endian" }
+    }
+
+    val events: SimpleBuffers.ListReader<Events?>
+        get() = SimpleBuffers.ListReader(buf, position + 0, 3) { p1 -> Events.read(buf, p1) }
+
+    val nested: Nested?
+        get() = Nested.read(buf, position + 4)
+
+    sealed interface Events {
+        data class IdCase(val value: UByte) : Events
+        data class NameCase(val value: String) : Events
+
+        companion object {
+            fun read(buf: ByteBuffer, pos: Int): Events? {
+                val p = pos + (buf.getShort(pos + 1).toInt() and 0xFFFF)
+                return when (buf.get(pos).toInt() and 0xFF) {
+                    0 -> IdCase(buf.get(p).toUByte())
+                    1 -> NameCase(SimpleBuffers.readString(buf, p))
+                    else -> null
+                }
+            }
+        }
+    }
+
+    sealed interface Nested {
+        data class EmptyCase(val value: UByte) : Nested
+        data class EventsCase(val value: SimpleBuffers.ListReader<NestedEvents?>) : Nested
+
+        companion object {
+            fun read(buf: ByteBuffer, pos: Int): Nested? {
+                val p = pos + (buf.getShort(pos + 1).toInt() and 0xFFFF)
+                return when (buf.get(pos).toInt() and 0xFF) {
+                    0 -> EmptyCase(buf.get(p).toUByte())
+                    1 -> EventsCase(SimpleBuffers.ListReader(buf, p, 3) { p1 -> NestedEvents.read(buf, p1) })
+                    else -> null
+                }
+            }
+        }
+    }
+
+    sealed interface NestedEvents {
+        data class SmallCase(val value: UByte) : NestedEvents
+        data class LargeCase(val value: ULong) : NestedEvents
+
+        companion object {
+            fun read(buf: ByteBuffer, pos: Int): NestedEvents? {
+                val p = pos + (buf.getShort(pos + 1).toInt() and 0xFFFF)
+                return when (buf.get(pos).toInt() and 0xFF) {
+                    0 -> SmallCase(buf.get(p).toUByte())
+                    1 -> LargeCase(buf.getLong(p).toULong())
+                    else -> null
+                }
+            }
+        }
+    }
+
+    companion object {
+        const val STATIC_SIZE = 7
+    }
+}
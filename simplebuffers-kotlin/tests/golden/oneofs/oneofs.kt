@@ -0,0 +1,120 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+import java.nio.ByteBuffer
+import java.nio.ByteOrder
+
+class RequestWriter(
+    var id: UShort,
+    var body: Body,
+) : SimpleBuffers.Writer {
+    override val staticSize: Int
+        get() = STATIC_SIZE
+
+    override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+        b.putShort(pos + 0, this.id.toShort())
+        this.body.writeComponent(b, pos + 2)
+    }
+
+    sealed interface Body {
+        fun writeComponent(b: SimpleBuffers.Builder, pos: Int)
+
+        data class PingCase(val value: UByte) : Body {
+            override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+                b.putByte(pos, 0)
+                val p = b.reserve(pos + 1, pos, 1)
+                b.putByte(p, value.toByte())
+            }
+        }
+
+        data class EchoCase(val value: String) : Body {
+            override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+                b.putByte(pos, 1)
+                val p = b.reserve(pos + 1, pos, 2)
+                b.putString(p, value)
+            }
+        }
+
+        data class TargetCase(val value: TargetWriter) : Body {
+            override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+                b.putByte(pos, 2)
+                val p = b.reserve(pos + 1, pos, 8)
+                value.writeComponent(b, p)
+            }
+        }
+    }
+
+    companion object {
+        const val STATIC_SIZE = 5
+    }
+}
+
+class TargetWriter(
+    var x: Float,
+    var y: Float,
+) : SimpleBuffers.Writer {
+    override val staticSize: Int
+        get() = STATIC_SIZE
+
+    override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+        b.putFloat(pos + 0, this.x)
+        b.putFloat(pos + 4, this.y)
+    }
+
+    companion object {
+        const val STATIC_SIZE = 8
+    }
+}
+
+data class RequestReader(val buf: ByteBuffer, val position: Int = 0) {
+    constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+    init {
+        require(buf.order() == ByteOrder.LITTLE_ENDIAN) { "Buffer must be little-endian" }
+    }
+
+    val id: UShort
+        get() = buf.getShort(position + 0).toUShort()
+
+    val body: Body?
+        get() = Body.read(buf, position + 2)
+
+    sealed interface Body {
+        data class PingCase(val value: UByte) : Body
+        data class EchoCase(val value: String) : Body
+        data class TargetCase(val value: TargetReader) : Body
+
+        companion object {
+            fun read(buf: ByteBuffer, pos: Int): Body? {
+                val p = pos + (buf.getShort(pos + 1).toInt() and 0xFFFF)
+                return when (buf.get(pos).toInt() and 0xFF) {
+                    0 -> PingCase(buf.get(p).toUByte())
+                    1 -> EchoCase(SimpleBuffers.readString(buf, p))
+                    2 -> TargetCase(TargetReader(buf, p))
+                    else -> null
+                }
+            }
+        }
+    }
+
+    companion object {
+        const val STATIC_SIZE = 5
+    }
+}
+
+data class TargetReader(val buf: ByteBuffer, val position: Int = 0) {
+    constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+    init {
+        require(buf.order() == ByteOrder.LITTLE_ENDIAN) { "Buffer must be little-endian" }
+    }
+
+    val x: Float
+        get() = buf.getFloat(position + 0)
+
+    val y: Float
+        get() = buf.getFloat(position + 4)
+
+    companion object {
+        const val STATIC_SIZE = 8
+    }
+}
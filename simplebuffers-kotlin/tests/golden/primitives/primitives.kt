@@ -0,0 +1,89 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+import java.nio.ByteBuffer
+import java.nio.ByteOrder
+
+class PrimitivesWriter(
+    var a: UByte,
+    var b: UShort,
+    var c: UInt,
+    var d: ULong,
+    var e: Byte,
+    var f: Short,
+    var g: Int,
+    var h: Long,
+    var i: Float,
+    var j: Double,
+    var k: Boolean,
+    var name: String,
+) : SimpleBuffers.Writer {
+    override val staticSize: Int
+        get() = STATIC_SIZE
+
+    override fun writeComponent(b: SimpleBuffers.Builder, pos: Int) {
+        b.putByte(pos + 0, this.a.toByte())
+        b.putShort(pos + 1, this.b.toShort())
+        b.putInt(pos + 3, this.c.toInt())
+        b.putLong(pos + 7, this.d.toLong())
+        b.putByte(pos + 15, this.e)
+        b.putShort(pos + 16, this.f)
+        b.putInt(pos + 18, this.g)
+        b.putLong(pos + 22, this.h)
+        b.putFloat(pos + 30, this.i)
+        b.putDouble(pos + 34, this.j)
+        b.putBool(pos + 42, this.k)
+        b.putString(pos + 43, this.name)
+    }
+
+    companion object {
+        const val STATIC_SIZE = 45
+    }
+}
+
+data class PrimitivesReader(val buf: ByteBuffer, val position: Int = 0) {
+    constructor(data: ByteArray) : this(SimpleBuffers.wrap(data))
+
+    init {
+        require(buf.order() == ByteOrder.LITTLE_ENDIAN) { "Buffer must be little-endian" }
+    }
+
+    val a: UByte
+        get() = buf.get(position + 0).toUByte()
+
+    val b: UShort
+        get() = buf.getShort(position + 1).toUShort()
+
+    val c: UInt
+        get() = buf.getInt(position + 3).toUInt()
+
+    val d: ULong
+        get() = buf.getLong(position + 7).toULong()
+
+    val e: Byte
+        get() = buf.get(position + 15)
+
+    val f: Short
+        get() = buf.getShort(position + 16)
+
+    val g: Int
+        get() = buf.getInt(position + 18)
+
+    val h: Long
+        get() = buf.getLong(position + 22)
+
+    val i: Float
+        get() = buf.getFloat(position + 30)
+
+    val j: Double
+        get() = buf.getDouble(position + 34)
+
+    val k: Boolean
+        get() = buf.get(position + 42) != 0.toByte()
+
+    val name: String
+        get() = SimpleBuffers.readString(buf, position + 43)
+
+    companion object {
+        const val STATIC_SIZE = 45
+    }
+}
@@ -0,0 +1,62 @@
+//! Protocol Buffers schema generator.
+//!
+//! This module translates a SimpleBuffers schema into a `proto3` schema (`{file_name}.proto`), so
+//! that the same types can be exposed by gRPC services. It does not generate any serialization
+//! code, and the Protocol Buffers wire format is unrelated to the SimpleBuffers one.
+//!
+//! - Every sequence becomes a `message`. Field numbers follow the declaration order.
+//! - Every enum becomes an `enum`. Protocol Buffers requires the first value to be zero, so an
+//!   `_UNSPECIFIED` value is added to enums that have no zero value.
+//! - Every oneof becomes a `oneof`. Oneof members are prefixed with the name of the oneof, since
+//!   they share a namespace with the other fields of the message.
+//! - Arrays become `repeated` fields. Types that cannot be `repeated` or be a oneof member (nested
+//!   arrays, arrays in oneofs, and nested oneofs) are wrapped in a nested message.
+//!
+//! Protocol Buffers has no 8- or 16-bit integers, so `u8`, `u16`, `i8`, and `i16` are widened to
+//! `uint32` and `int32`, and marked with a comment.
+//!
+//! No names are reserved. Every `proto3` keyword is contextual, so it can be used as a name, and
+//! messages and enums are PascalCase, so they cannot clash with the lowercase scalar types.
+
+mod protogen;
+
+use std::path::PathBuf;
+
+use protogen::generate_proto;
+use simplebuffers_codegen::{register_generator, CodeGenerator, FileSink};
+
+#[derive(Debug)]
+pub struct ProtoCodeGenerator;
+
+impl CodeGenerator for ProtoCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        let proto = generate_proto(schema)?;
+
+        let path = PathBuf::from(&params.dest_dir).join(format!("{}.proto", params.file_name));
+        sink.write_file(&path, &proto)?;
+
+        Ok(vec![path])
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["proto".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "Protocol Buffers (proto3) schema".to_string()
+    }
+}
+
+register_generator!(proto: ProtoCodeGenerator);
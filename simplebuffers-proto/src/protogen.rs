@@ -0,0 +1,309 @@
+//! Generates a Protocol Buffers schema.
+
+use convert_case::{Case, Casing};
+use indent::indent_by;
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Primitive, SBSchema, Sequence, Type};
+
+/// Generates a `proto3` schema from a given SimpleBuffers schema.
+///
+/// # Arguments
+///
+/// * `schema` - The schema to generate from.
+///
+/// # Returns
+///
+/// The `proto3` schema, as a String, or an error if an enum cannot be represented.
+pub(crate) fn generate_proto(schema: &SBSchema) -> Result<String, String> {
+    let declarations = schema
+        .enums
+        .iter()
+        .map(define_enum)
+        .chain(schema.sequences.iter().map(|s| Ok(define_sequence(s))))
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n\n");
+
+    Ok(formatdoc! {
+        r#"
+        // This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+        syntax = "proto3";
+
+        {declarations}
+        "#
+    })
+}
+
+/// Returns the Protocol Buffers type that matches a primitive, and the original type if the
+/// primitive had to be widened.
+fn primitive_type(p: &Primitive) -> (&'static str, Option<&'static str>) {
+    match p {
+        Primitive::Bool => ("bool", None),
+        Primitive::U8 => ("uint32", Some("u8")),
+        Primitive::U16 => ("uint32", Some("u16")),
+        Primitive::U32 => ("uint32", None),
+        Primitive::U64 => ("uint64", None),
+        Primitive::I8 => ("int32", Some("i8")),
+        Primitive::I16 => ("int32", Some("i16")),
+        Primitive::I32 => ("int32", None),
+        Primitive::I64 => ("int64", None),
+        Primitive::F32 => ("float", None),
+        Primitive::F64 => ("double", None),
+    }
+}
+
+/// Formats a doc comment as `//` comments, or returns an empty string if there is none.
+fn doc_comment(doc: &Option<String>) -> String {
+    match doc {
+        Some(doc) => doc.lines().map(|l| format!("// {l}\n")).join(""),
+        None => String::new(),
+    }
+}
+
+/// Returns the name of an enum value. Enum values are scoped to the package rather than the enum,
+/// so they are prefixed with the name of the enum.
+fn enum_value_name(data: &Enum, variant: &str) -> String {
+    format!(
+        "{}_{}",
+        data.name.to_case(Case::UpperSnake),
+        variant.to_case(Case::UpperSnake)
+    )
+}
+
+/// Generates the Protocol Buffers code for defining an enum. The zero value comes first, as
+/// `proto3` requires, and is synthesized if the enum does not have one.
+fn define_enum(data: &Enum) -> Result<String, String> {
+    if let Some(v) = data
+        .variants
+        .iter()
+        .find(|v| i32::try_from(v.value).is_err())
+    {
+        return Err(format!(
+            "Variant `{}` of enum `{}` has the value {}, which does not fit in a Protocol Buffers \
+             enum (a 32-bit signed integer)",
+            v.name, data.name, v.value
+        ));
+    }
+
+    let mut values = vec![];
+    if !data.variants.iter().any(|v| v.value == 0) {
+        let unspecified = enum_value_name(data, "unspecified");
+        if data
+            .variants
+            .iter()
+            .any(|v| enum_value_name(data, &v.name) == unspecified)
+        {
+            return Err(format!(
+                "Enum `{}` has no zero value, and the `{}` value that would be added conflicts \
+                 with an existing variant",
+                data.name, unspecified
+            ));
+        }
+        values.push(format!("{unspecified} = 0;"));
+    }
+    values.extend(
+        data.variants
+            .iter()
+            .sorted_by_key(|v| v.value != 0)
+            .map(|v| {
+                format!(
+                    "{}{} = {};",
+                    doc_comment(&v.doc),
+                    enum_value_name(data, &v.name),
+                    v.value
+                )
+            }),
+    );
+
+    Ok(formatdoc! {
+        r"
+        {doc}enum {name} {{
+          {values}
+        }}",
+        doc = doc_comment(&data.doc),
+        name = data.name.to_case(Case::Pascal),
+        values = indent_by(2, values.join("\n"))
+    })
+}
+
+/// Generates the Protocol Buffers code for defining a sequence. Wrapper messages for types that
+/// cannot be used directly are nested in the message.
+fn define_sequence(seq: &Sequence) -> String {
+    let mut number = 1;
+    let mut nested = vec![];
+    let mut fields = seq
+        .fields
+        .iter()
+        .map(|f| {
+            format!(
+                "{}{}",
+                doc_comment(&f.doc),
+                define_field(
+                    &f.ty,
+                    &f.name.to_case(Case::Snake),
+                    &f.name.to_case(Case::Pascal),
+                    &mut number,
+                    &mut nested
+                )
+            )
+        })
+        .join("\n");
+    if !nested.is_empty() {
+        fields = format!("{}\n\n{}", fields, nested.join("\n\n"));
+    }
+
+    if fields.is_empty() {
+        return format!(
+            "{}message {} {{}}",
+            doc_comment(&seq.doc),
+            seq.name.to_case(Case::Pascal)
+        );
+    }
+    formatdoc! {
+        r"
+        {doc}message {name} {{
+          {fields}
+        }}",
+        doc = doc_comment(&seq.doc),
+        name = seq.name.to_case(Case::Pascal),
+        fields = indent_by(2, fields)
+    }
+}
+
+/// Generates a field of a message.
+///
+/// # Arguments
+///
+/// * `ty` - The type of the field.
+/// * `name` - The name of the field.
+/// * `path` - The name to use for wrapper messages needed by the field.
+/// * `number` - The next unused field number. This is advanced past the numbers used by the field.
+/// * `nested` - The list to add wrapper messages to.
+fn define_field(
+    ty: &Type,
+    name: &str,
+    path: &str,
+    number: &mut usize,
+    nested: &mut Vec<String>,
+) -> String {
+    match ty {
+        Type::Array(t) | Type::FixedArray(t, _) => {
+            let (element, comment) = value_type(t, name, &format!("{path}Item"), nested);
+            field_line(&format!("repeated {element}"), name, number, comment)
+        }
+        // Protocol Buffers only allows integer, boolean, and string map keys, so maps with other
+        // keys become a list of entry messages.
+        Type::Map(key, value) => {
+            let (value_ty, value_comment) =
+                value_type(value, "value", &format!("{path}Value"), nested);
+            let (key_ty, key_comment) = value_type(key, "key", &format!("{path}Key"), nested);
+            if let Type::Primitive(Primitive::F32 | Primitive::F64) | Type::Enum(_, _) =
+                key.as_ref()
+            {
+                let entry = format!("{path}Entry");
+                nested.push(formatdoc! {
+                    r"
+                    message {entry} {{
+                      {key}
+                      {value}
+                    }}",
+                    key = field_line(&key_ty, "key", &mut 1, key_comment),
+                    value = field_line(&value_ty, "value", &mut 2, value_comment),
+                });
+                return field_line(&format!("repeated {entry}"), name, number, None);
+            }
+            let original = ty.to_string();
+            let comment = (key_comment.is_some() || value_comment.is_some()).then_some(&*original);
+            field_line(&format!("map<{key_ty}, {value_ty}>"), name, number, comment)
+        }
+        Type::OneOf(subfields) if subfields.is_empty() => {
+            // Protocol Buffers does not allow empty oneofs.
+            format!("// `{name}` is an empty oneof, which Protocol Buffers does not support.")
+        }
+        Type::OneOf(subfields) => {
+            let members = subfields
+                .iter()
+                .map(|f| {
+                    let member = format!("{}_{}", name, f.name.to_case(Case::Snake));
+                    let (ty, comment) = value_type(
+                        &f.ty,
+                        &f.name.to_case(Case::Snake),
+                        &format!("{path}{}", f.name.to_case(Case::Pascal)),
+                        nested,
+                    );
+                    format!(
+                        "{}{}",
+                        doc_comment(&f.doc),
+                        field_line(&ty, &member, number, comment)
+                    )
+                })
+                .join("\n");
+
+            formatdoc! {
+                r"
+                oneof {name} {{
+                  {members}
+                }}",
+                members = indent_by(2, members)
+            }
+        }
+        _ => {
+            let (ty, comment) = value_type(ty, name, path, nested);
+            field_line(&ty, name, number, comment)
+        }
+    }
+}
+
+/// Formats a single field declaration and advances `number`.
+fn field_line(ty: &str, name: &str, number: &mut usize, comment: Option<&str>) -> String {
+    let line = format!("{ty} {name} = {number};");
+    *number += 1;
+    match comment {
+        Some(original) => format!("{line}  // {original}"),
+        None => line,
+    }
+}
+
+/// Returns the Protocol Buffers type used to hold a single value of a type, and the original type
+/// if it is a widened primitive. Arrays, maps, and oneofs cannot be used here, so they are wrapped
+/// in a message that is added to `nested`.
+///
+/// # Arguments
+///
+/// * `ty` - The type of the value.
+/// * `name` - The name of the field that holds the value, which names the wrapped oneof.
+/// * `path` - The name to use for wrapper messages.
+/// * `nested` - The list to add wrapper messages to.
+fn value_type(
+    ty: &Type,
+    name: &str,
+    path: &str,
+    nested: &mut Vec<String>,
+) -> (String, Option<&'static str>) {
+    match ty {
+        Type::Primitive(p) => {
+            let (ty, original) = primitive_type(p);
+            (ty.to_string(), original)
+        }
+        Type::Sequence(s) | Type::Enum(s, _) => (s.to_case(Case::Pascal), None),
+        Type::String => ("string".to_string(), None),
+        Type::Bytes => ("bytes".to_string(), None),
+        Type::Array(_) | Type::FixedArray(_, _) | Type::Map(_, _) | Type::OneOf(_) => {
+            let (field_name, field_path) = match ty {
+                Type::OneOf(_) => (name, path.to_string()),
+                _ => ("values", format!("{path}Values")),
+            };
+            let mut number = 1;
+            let field = define_field(ty, field_name, &field_path, &mut number, nested);
+            nested.push(formatdoc! {
+                r"
+                message {path} {{
+                  {field}
+                }}",
+                field = indent_by(2, field)
+            });
+            (path.to_string(), None)
+        }
+    }
+}
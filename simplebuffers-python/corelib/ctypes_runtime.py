@@ -0,0 +1,55 @@
+_T = typing.TypeVar("_T")
+
+
+class ListHeader(ctypes.LittleEndianStructure):
+    """The static part of a list: the number of elements and the offset to the first one."""
+
+    _pack_ = 1
+    _fields_ = [("len", ctypes.c_uint16), ("offset", ctypes.c_uint16)]
+
+
+class OneOfHeader(ctypes.LittleEndianStructure):
+    """The static part of a oneof: the tag of the active field and the offset to its value."""
+
+    _pack_ = 1
+    _fields_ = [("tag", ctypes.c_uint8), ("offset", ctypes.c_uint16)]
+
+
+def _le(ctype: typing.Any) -> typing.Any:
+    """Returns the little-endian variant of a ctypes scalar type."""
+    return getattr(ctype, "__ctype_le__", ctype)
+
+
+def follow(buf: typing.Any, pos: int) -> int:
+    """Follows the 16-bit offset stored at `pos` and returns the position it points to."""
+    return pos + _le(ctypes.c_uint16).from_buffer_copy(buf, pos).value
+
+
+def read_scalar(buf: typing.Any, pos: int, ctype: typing.Any) -> typing.Any:
+    """Reads a little-endian scalar of the given ctypes type at `pos`."""
+    return _le(ctype).from_buffer_copy(buf, pos).value
+
+
+def read_string(buf: typing.Any, pos: int) -> str:
+    """Reads a null-terminated string whose offset is stored at `pos`."""
+    start = follow(buf, pos)
+    end = bytes(buf).find(b"\0", start)
+    if end < 0:
+        end = len(buf)
+    return bytes(buf[start:end]).decode("utf-8")
+
+
+def list_view(buf: typing.Any, pos: int, ctype: typing.Any) -> typing.Any:
+    """Returns a zero-copy ctypes array over the list whose header is at `pos`. The buffer must be
+    writable (for example, a `bytearray` or shared memory)."""
+    header = ListHeader.from_buffer_copy(buf, pos)
+    return (_le(ctype) * header.len).from_buffer(buf, pos + header.offset)
+
+
+def read_list(
+    buf: typing.Any, pos: int, stride: int, read_element: typing.Callable[[int], _T]
+) -> typing.List[_T]:
+    """Reads the list whose header is at `pos`, using `read_element` to read each element."""
+    header = ListHeader.from_buffer_copy(buf, pos)
+    start = pos + header.offset
+    return [read_element(start + i * stride) for i in range(header.len)]
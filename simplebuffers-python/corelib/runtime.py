@@ -0,0 +1,96 @@
+_T = typing.TypeVar("_T")
+
+
+def _read(fmt: str, buf: bytes, pos: int) -> typing.Any:
+    """Reads a little-endian value with the given `struct` format at `pos`."""
+    return struct.unpack_from("<" + fmt, buf, pos)[0]
+
+
+def _follow(buf: bytes, pos: int) -> int:
+    """Follows the 16-bit offset stored at `pos` and returns the position it points to."""
+    return pos + _read("H", buf, pos)
+
+
+def _read_string(buf: bytes, pos: int) -> str:
+    """Reads a null-terminated string whose offset is stored at `pos`."""
+    start = _follow(buf, pos)
+    end = bytes(buf).find(b"\0", start)
+    if end < 0:
+        end = len(buf)
+    return bytes(buf[start:end]).decode("utf-8")
+
+
+def _enum(cls: typing.Any, value: int) -> typing.Any:
+    """Converts `value` to the enum `cls`, or returns it unchanged if it is not a known variant."""
+    try:
+        return cls(value)
+    except ValueError:
+        return value
+
+
+class _ListReader(typing.Sequence[_T]):
+    """A read-only view over a list stored in a buffer."""
+
+    __slots__ = ("_buf", "_start", "_len", "_stride", "_read_element")
+
+    def __init__(
+        self, buf: bytes, pos: int, stride: int, read_element: typing.Callable[[int], _T]
+    ) -> None:
+        self._buf = buf
+        self._len = _read("H", buf, pos)
+        self._start = pos + _read("H", buf, pos + 2)
+        self._stride = stride
+        self._read_element = read_element
+
+    def __len__(self) -> int:
+        return self._len
+
+    def __getitem__(self, index: typing.Any) -> typing.Any:
+        if isinstance(index, slice):
+            return [self[i] for i in range(*index.indices(self._len))]
+        if index < 0:
+            index += self._len
+        if not 0 <= index < self._len:
+            raise IndexError("list index out of range")
+        return self._read_element(self._start + index * self._stride)
+
+
+class _Builder:
+    """A growable buffer that generated writers serialize into."""
+
+    __slots__ = ("buf",)
+
+    def __init__(self, static_size: int) -> None:
+        self.buf = bytearray(static_size)
+
+    def reserve(self, slot: int, base: int, count: int) -> int:
+        """Reserves `count` zeroed bytes at the end of the buffer and stores the offset to them
+        (relative to `base`) at `slot`. Returns the position of the reserved bytes."""
+        start = len(self.buf)
+        self.buf.extend(bytes(count))
+        self.write("H", slot, start - base)
+        return start
+
+    def write(self, fmt: str, pos: int, value: typing.Any) -> None:
+        """Writes a little-endian value with the given `struct` format at `pos`."""
+        struct.pack_into("<" + fmt, self.buf, pos, value)
+
+    def write_string(self, pos: int, value: str) -> None:
+        """Writes a null-terminated string to the end of the buffer and stores its offset at
+        `pos`."""
+        data = value.encode("utf-8")
+        start = self.reserve(pos, pos, len(data) + 1)
+        self.buf[start : start + len(data)] = data
+
+    def write_list(
+        self,
+        pos: int,
+        values: typing.Sequence[_T],
+        stride: int,
+        write_element: typing.Callable[[int, _T], None],
+    ) -> None:
+        """Writes a list header at `pos` and the list elements to the end of the buffer."""
+        self.write("H", pos, len(values))
+        start = self.reserve(pos + 2, pos, len(values) * stride)
+        for i, value in enumerate(values):
+            write_element(start + i * stride, value)
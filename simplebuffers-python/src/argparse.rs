@@ -0,0 +1,54 @@
+//! Parses generator-specific arguments contained in [GeneratorParams::additional_args].
+
+use clap::{Command, CommandFactory, Parser, ValueEnum};
+use simplebuffers_codegen::GeneratorParams;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The kind of Python module to generate.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Mode {
+    /// Readers and writers that only use the Python standard library.
+    #[default]
+    Pure,
+
+    /// `ctypes.Structure` views over the static portion of each sequence, with helpers that
+    /// follow offsets to dynamic data.
+    Ctypes,
+}
+
+/// Struct to parse CLI args into.
+#[derive(Parser, Debug)]
+#[command(name = "SimpleBuffers Python Code Generator")]
+#[command(version = VERSION)]
+#[command(about = "Generate Python code from a SimpleBuffers schema.")]
+struct Cli {
+    /// The kind of module to generate.
+    #[arg(long, value_enum, default_value_t = Mode::Pure)]
+    mode: Mode,
+}
+
+/// A struct that holds generator-specific arguments for the Python generator.
+#[derive(Debug)]
+pub(crate) struct PythonGeneratorParams {
+    /// The kind of module to generate.
+    pub mode: Mode,
+
+    /// The global compiler parameters.
+    pub global: GeneratorParams,
+}
+
+/// Returns the command that describes the generator-specific arguments, so the compiler can
+/// show them in its help output and validate them before generating.
+pub(crate) fn command() -> Command {
+    Cli::command()
+}
+
+/// Parse generator-specific arguments from the compiler-provided argument list.
+pub(crate) fn parse_args(generator_params: &GeneratorParams) -> PythonGeneratorParams {
+    let cli = Cli::parse_from(&generator_params.additional_args);
+    PythonGeneratorParams {
+        mode: cli.mode,
+        global: generator_params.clone(),
+    }
+}
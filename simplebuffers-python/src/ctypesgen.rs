@@ -0,0 +1,341 @@
+//! Generates a Python module with `ctypes` bindings.
+//!
+//! Each sequence becomes a packed `ctypes.LittleEndianStructure` that matches the static portion
+//! of the sequence, so it can be used as a zero-copy view over a writable buffer (such as shared
+//! memory). Dynamic fields are read through static methods that follow their offsets.
+
+use convert_case::{Case, Casing};
+use indent::indent_by;
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+use std::collections::HashMap;
+
+/// Information about the schema that is needed while generating code.
+struct CtypesGen<'a> {
+    /// The static size of every sequence, by name.
+    sequence_sizes: HashMap<&'a str, usize>,
+}
+
+//                                                                                                //
+// ======================================= Main Function ======================================== //
+//                                                                                                //
+
+/// Generates a Python module with `ctypes` bindings from a given schema.
+///
+/// # Arguments
+///
+/// * `schema` - The schema to generate from.
+///
+/// # Returns
+///
+/// The code for a Python module, as a String.
+pub(crate) fn generate_ctypes(schema: &SBSchema) -> String {
+    let gen = CtypesGen {
+        sequence_sizes: schema
+            .sequences
+            .iter()
+            .map(|s| (s.name.as_str(), s.static_size()))
+            .collect(),
+    };
+
+    let declarations = schema
+        .enums
+        .iter()
+        .map(define_enum)
+        .chain(schema.sequences.iter().map(|s| gen.define_structure(s)))
+        .join("\n\n\n");
+
+    formatdoc! {
+        r#"
+        # This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+        from __future__ import annotations
+
+        import ctypes
+        import enum
+        import typing
+
+        {runtime}
+
+
+        {declarations}
+        "#,
+        runtime = include_str!("../corelib/ctypes_runtime.py").trim(),
+    }
+}
+
+/// Returns the ctypes type that matches a primitive.
+fn primitive_ctype(p: &Primitive) -> &'static str {
+    match p {
+        Primitive::Bool => "ctypes.c_bool",
+        Primitive::U8 => "ctypes.c_uint8",
+        Primitive::U16 => "ctypes.c_uint16",
+        Primitive::U32 => "ctypes.c_uint32",
+        Primitive::U64 => "ctypes.c_uint64",
+        Primitive::I8 => "ctypes.c_int8",
+        Primitive::I16 => "ctypes.c_int16",
+        Primitive::I32 => "ctypes.c_int32",
+        Primitive::I64 => "ctypes.c_int64",
+        Primitive::F32 => "ctypes.c_float",
+        Primitive::F64 => "ctypes.c_double",
+    }
+}
+
+/// Returns the ctypes type that stores an enum of the given size.
+fn enum_ctype(size: usize) -> &'static str {
+    match size {
+        1 => "ctypes.c_uint8",
+        2 => "ctypes.c_uint16",
+        4 => "ctypes.c_uint32",
+        8 => "ctypes.c_uint64",
+        _ => panic!("Cannot convert size {} to a ctypes type", size),
+    }
+}
+
+/// Returns the path used to name the helpers for a field.
+fn field_path(prefix: &str, field_name: &str) -> String {
+    format!("{}{}", prefix, field_name.to_case(Case::Pascal))
+}
+
+/// Generates the Python code for defining an enum. Enums are `IntEnum`s, so they can be compared
+/// with the integers stored in structures.
+fn define_enum(data: &Enum) -> String {
+    let variants = data
+        .variants
+        .iter()
+        .map(|v| format!("{} = {}", v.name.to_case(Case::UpperSnake), v.value))
+        .join("\n");
+
+    formatdoc! {
+        r"
+        class {name}(enum.IntEnum):
+            {variants}",
+        name = data.name.to_case(Case::Pascal),
+        variants = indent_by(4, variants)
+    }
+}
+
+impl<'a> CtypesGen<'a> {
+    /// Returns the number of bytes a type takes up as a list element or oneof target.
+    fn element_size(&self, ty: &Type) -> usize {
+        match ty {
+            Type::Sequence(s) => self.sequence_sizes[s.as_str()],
+            _ => ty.size(),
+        }
+    }
+
+    /// Returns the ctypes type of a field in the static portion of a sequence.
+    fn slot_ctype(&self, ty: &Type) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_ctype(p).to_string(),
+            Type::Enum(_, size) => enum_ctype(*size).to_string(),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String | Type::Sequence(_) => "ctypes.c_uint16".to_string(),
+            Type::Array(_) => "ListHeader".to_string(),
+            Type::OneOf(_) => "OneOfHeader".to_string(),
+        }
+    }
+
+    /// Returns the ctypes type of a list element, if the element can be viewed without copying.
+    fn element_ctype(&self, ty: &Type) -> Option<String> {
+        match ty {
+            Type::Primitive(p) => Some(primitive_ctype(p).to_string()),
+            Type::Enum(_, size) => Some(enum_ctype(*size).to_string()),
+            Type::Sequence(s) => Some(s.to_case(Case::Pascal)),
+            _ => None,
+        }
+    }
+
+    /// Returns an expression that reads the list whose header is at `pos`. Lists of fixed-size
+    /// elements are returned as zero-copy ctypes arrays.
+    fn read_list_expr(
+        &self,
+        element: &Type,
+        owner: &str,
+        path: &str,
+        pos: &str,
+        depth: usize,
+    ) -> String {
+        match self.element_ctype(element) {
+            Some(ctype) => format!("list_view(buf, {pos}, {ctype})"),
+            None => format!(
+                "read_list(buf, {pos}, {stride}, lambda p{depth}: {read})",
+                stride = self.element_size(element),
+                read = self.read_element_expr(
+                    element,
+                    owner,
+                    &format!("{path}Item"),
+                    &format!("p{depth}"),
+                    depth + 1
+                ),
+            ),
+        }
+    }
+
+    /// Returns an expression that reads a list element or oneof target at `pos`.
+    fn read_element_expr(
+        &self,
+        ty: &Type,
+        owner: &str,
+        path: &str,
+        pos: &str,
+        depth: usize,
+    ) -> String {
+        match ty {
+            Type::Primitive(p) => format!("read_scalar(buf, {pos}, {})", primitive_ctype(p)),
+            Type::Enum(_, size) => format!("read_scalar(buf, {pos}, {})", enum_ctype(*size)),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("read_string(buf, {pos})"),
+            Type::Sequence(s) => format!("{}.from_buffer(buf, {pos})", s.to_case(Case::Pascal)),
+            Type::Array(t) => self.read_list_expr(t, owner, path, pos, depth),
+            Type::OneOf(_) => format!("{owner}._read_{}(buf, {pos})", path.to_case(Case::Snake)),
+        }
+    }
+
+    /// Returns an expression that follows the offset of a dynamic field at `pos`, or `None` if
+    /// the field is stored entirely in the structure.
+    fn read_slot_expr(&self, ty: &Type, owner: &str, path: &str, pos: &str) -> Option<String> {
+        match ty {
+            Type::Primitive(_) | Type::Enum(_, _) => None,
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => Some(format!("read_string(buf, {pos})")),
+            Type::Sequence(s) => Some(format!(
+                "{}.from_buffer(buf, follow(buf, {pos}))",
+                s.to_case(Case::Pascal)
+            )),
+            Type::Array(t) => Some(self.read_list_expr(t, owner, path, pos, 1)),
+            Type::OneOf(_) => Some(format!(
+                "{owner}._read_{}(buf, {pos})",
+                path.to_case(Case::Snake)
+            )),
+        }
+    }
+
+    /// Returns the static methods that read the oneofs nested in a type.
+    fn oneof_readers(&self, ty: &Type, owner: &str, path: &str) -> Vec<String> {
+        match ty {
+            Type::Array(t) => self.oneof_readers(t, owner, &format!("{path}Item")),
+            Type::OneOf(subfields) => {
+                let mut readers = vec![self.define_oneof_reader(owner, path, subfields)];
+                for f in subfields {
+                    readers.extend(self.oneof_readers(&f.ty, owner, &field_path(path, &f.name)));
+                }
+                readers
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Generates a static method that reads a oneof. It returns the name and value of the active
+    /// field, or `None` if the tag is unknown.
+    fn define_oneof_reader(&self, owner: &str, path: &str, subfields: &[Field]) -> String {
+        let cases = subfields
+            .iter()
+            .map(|f| {
+                formatdoc! {
+                    r#"
+                    if header.tag == {index}:
+                        return ("{name}", {read})"#,
+                    index = f.index,
+                    name = f.name.to_case(Case::Snake),
+                    read = self.read_element_expr(
+                        &f.ty,
+                        owner,
+                        &field_path(path, &f.name),
+                        "p",
+                        1
+                    ),
+                }
+            })
+            .join("\n");
+
+        formatdoc! {
+            r"
+            @staticmethod
+            def _read_{snake}(buf: typing.Any, pos: int) -> typing.Optional[typing.Tuple[str, typing.Any]]:
+                header = OneOfHeader.from_buffer_copy(buf, pos)
+                p = pos + header.offset
+                {cases}
+                return None",
+            snake = path.to_case(Case::Snake),
+            cases = indent_by(4, cases),
+        }
+    }
+
+    /// Generates the Python code for defining the structure of a sequence.
+    fn define_structure(&self, seq: &Sequence) -> String {
+        let name = seq.name.to_case(Case::Pascal);
+
+        let fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "(\"{}\", {}),",
+                    f.name.to_case(Case::Snake),
+                    self.slot_ctype(&f.ty)
+                )
+            })
+            .join("\n");
+
+        let helpers = seq
+            .fields
+            .iter()
+            .filter_map(|f| {
+                let path = field_path("", &f.name);
+                let read =
+                    self.read_slot_expr(&f.ty, &name, &path, &format!("pos + {}", f.index))?;
+                Some(formatdoc! {
+                    r#"
+                    @staticmethod
+                    def read_{field}(buf: typing.Any, pos: int = 0) -> typing.Any:
+                        """Reads the dynamic data of `{field}` for the structure at `pos`."""
+                        return {read}"#,
+                    field = f.name.to_case(Case::Snake),
+                })
+            })
+            .chain(
+                seq.fields
+                    .iter()
+                    .flat_map(|f| self.oneof_readers(&f.ty, &name, &field_path("", &f.name))),
+            )
+            .join("\n\n");
+
+        let fields = if fields.is_empty() {
+            "_fields_: typing.List[typing.Tuple[str, typing.Any]] = []".to_string()
+        } else {
+            formatdoc! {
+                r"
+                _fields_ = [
+                    {fields}
+                ]",
+                fields = indent_by(4, fields)
+            }
+        };
+
+        let body = [format!("_pack_ = 1\n{fields}"), helpers]
+            .iter()
+            .filter(|s| !s.is_empty())
+            .join("\n\n");
+
+        formatdoc! {
+            r"
+            class {name}(ctypes.LittleEndianStructure):
+                {body}",
+            body = indent_by(4, body)
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Python code generator.
+//!
+//! This module implements Python code generation for SimpleBuffers schemas. It produces a single
+//! `{file_name}.py` module. The `--mode` option selects what the module contains:
+//!
+//! - `pure` (the default): an `IntEnum` for every schema enum, and a writer dataclass and a reader
+//!   class for every sequence. Only the Python standard library is used.
+//! - `ctypes`: an `IntEnum` for every schema enum, and a packed `ctypes.LittleEndianStructure`
+//!   for the static portion of every sequence. Structures can be used as zero-copy views over
+//!   writable buffers (such as shared memory), and have static methods that follow offsets to
+//!   dynamic fields. This mode only reads buffers.
+//!
+//! In `pure` mode, oneofs are represented as a `typing.Union` of dataclasses, with one class (for
+//! example, `RequestWriter.PayloadMoveToCase`) per oneof field.
+
+mod argparse;
+mod ctypesgen;
+mod pythongen;
+
+use std::path::PathBuf;
+
+use argparse::{command, parse_args, Mode};
+use ctypesgen::generate_ctypes;
+use pythongen::generate_python;
+use simplebuffers_codegen::{register_generator, CodeGenerator, FileSink};
+
+#[derive(Debug)]
+pub struct PythonCodeGenerator;
+
+impl CodeGenerator for PythonCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        if schema.uses_fixed_arrays() {
+            return Err("The Python generator does not support fixed-size arrays".to_string());
+        }
+        if schema.uses_bytes() {
+            return Err("The Python generator does not support byte buffers".to_string());
+        }
+        if schema.uses_maps() {
+            return Err("The Python generator does not support maps".to_string());
+        }
+
+        let params = parse_args(params);
+
+        let dir = PathBuf::from(&params.global.dest_dir);
+        let code = match params.mode {
+            Mode::Pure => generate_python(schema),
+            Mode::Ctypes => generate_ctypes(schema),
+        };
+
+        let path = dir.join(format!("{}.py", params.global.file_name));
+        sink.write_file(&path, &code)?;
+
+        Ok(vec![path])
+    }
+
+    fn reserved_identifiers(
+        &mut self,
+        params: &simplebuffers_codegen::GeneratorParams,
+    ) -> Vec<String> {
+        let generated: &[&str] = match parse_args(params).mode {
+            Mode::Pure => &["STATIC_SIZE", "write_component", "encode"],
+            Mode::Ctypes => &["ListHeader", "OneOfHeader"],
+        };
+
+        let keywords = [
+            "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+            "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+            "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise",
+            "return", "try", "while", "with", "yield",
+        ];
+
+        generated
+            .iter()
+            .chain(keywords.iter())
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["py".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "Python code".to_string()
+    }
+
+    fn cli(&self) -> Option<clap::Command> {
+        Some(command())
+    }
+}
+
+register_generator!(python: PythonCodeGenerator);
@@ -0,0 +1,563 @@
+//! Generates a pure-Python module.
+
+use convert_case::{Case, Casing};
+use indent::indent_by;
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+use std::collections::HashMap;
+
+/// Where a value is stored. This determines how sequences are laid out.
+#[derive(Clone, Copy)]
+enum Context {
+    /// The value is a field of a sequence. Sequences are stored behind a 16-bit offset.
+    Slot,
+
+    /// The value is a list element or the target of a oneof. Sequences are stored inline.
+    Element,
+}
+
+/// Information about the schema that is needed while generating code.
+struct PythonGen<'a> {
+    /// The static size of every sequence, by name.
+    sequence_sizes: HashMap<&'a str, usize>,
+}
+
+//                                                                                                //
+// ======================================= Main Function ======================================== //
+//                                                                                                //
+
+/// Generates a pure-Python module from a given schema.
+///
+/// # Arguments
+///
+/// * `schema` - The schema to generate from.
+///
+/// # Returns
+///
+/// The code for a Python module, as a String.
+pub(crate) fn generate_python(schema: &SBSchema) -> String {
+    let gen = PythonGen {
+        sequence_sizes: schema
+            .sequences
+            .iter()
+            .map(|s| (s.name.as_str(), s.static_size()))
+            .collect(),
+    };
+
+    let declarations = schema
+        .enums
+        .iter()
+        .map(define_enum)
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| gen.define_sequence_writer(s)),
+        )
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| gen.define_sequence_reader(s)),
+        )
+        .join("\n\n\n");
+
+    formatdoc! {
+        r#"
+        # This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+        from __future__ import annotations
+
+        import dataclasses
+        import enum
+        import struct
+        import typing
+
+        {runtime}
+
+
+        {declarations}
+        "#,
+        runtime = include_str!("../corelib/runtime.py").trim(),
+    }
+}
+
+/// Returns the `struct` format character for a primitive.
+fn primitive_format(p: &Primitive) -> &'static str {
+    match p {
+        Primitive::Bool => "?",
+        Primitive::U8 => "B",
+        Primitive::U16 => "H",
+        Primitive::U32 => "I",
+        Primitive::U64 => "Q",
+        Primitive::I8 => "b",
+        Primitive::I16 => "h",
+        Primitive::I32 => "i",
+        Primitive::I64 => "q",
+        Primitive::F32 => "f",
+        Primitive::F64 => "d",
+    }
+}
+
+/// Returns the `struct` format character for an enum of the given size.
+fn enum_format(size: usize) -> &'static str {
+    match size {
+        1 => "B",
+        2 => "H",
+        4 => "I",
+        8 => "Q",
+        _ => panic!("Cannot convert size {} to a struct format", size),
+    }
+}
+
+/// Returns the Python type that matches a primitive.
+fn primitive_type(p: &Primitive) -> &'static str {
+    match p {
+        Primitive::Bool => "bool",
+        Primitive::F32 | Primitive::F64 => "float",
+        _ => "int",
+    }
+}
+
+/// Returns the name of the writer class for a sequence.
+fn writer_name(seq_name: &str) -> String {
+    format!("{}Writer", seq_name).to_case(Case::Pascal)
+}
+
+/// Returns the name of the reader class for a sequence.
+fn reader_name(seq_name: &str) -> String {
+    format!("{}Reader", seq_name).to_case(Case::Pascal)
+}
+
+/// Returns the path used to name types nested in a field.
+fn field_path(prefix: &str, field_name: &str) -> String {
+    format!("{}{}", prefix, field_name.to_case(Case::Pascal))
+}
+
+/// Generates the Python code for defining an enum.
+fn define_enum(data: &Enum) -> String {
+    let variants = data
+        .variants
+        .iter()
+        .map(|v| format!("{} = {}", v.name.to_case(Case::UpperSnake), v.value))
+        .join("\n");
+
+    formatdoc! {
+        r"
+        class {name}(enum.IntEnum):
+            {variants}",
+        name = data.name.to_case(Case::Pascal),
+        variants = indent_by(4, variants)
+    }
+}
+
+/// Joins the sections of a class body, skipping empty sections.
+fn class_body(sections: &[String]) -> String {
+    sections
+        .iter()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .join("\n\n")
+}
+
+/// Generates a class definition.
+fn declare(header: &str, sections: &[String]) -> String {
+    formatdoc! {
+        r"
+        {header}
+            {body}",
+        body = indent_by(4, class_body(sections))
+    }
+}
+
+impl<'a> PythonGen<'a> {
+    /// Returns the number of bytes a type takes up as a list element or oneof target.
+    fn element_size(&self, ty: &Type) -> usize {
+        match ty {
+            Type::Sequence(s) => self.sequence_sizes[s.as_str()],
+            _ => ty.size(),
+        }
+    }
+
+    //                                                                                            //
+    // =============================== Generate Writer Components =============================== //
+    //                                                                                            //
+
+    /// Returns the Python type used to write a value. `path` names the types nested in the value,
+    /// which are declared in the class `owner`.
+    fn writer_type(&self, ty: &Type, owner: &str, path: &str) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p).to_string(),
+            Type::Sequence(s) => writer_name(s),
+            Type::Enum(e, _) => e.to_case(Case::Pascal),
+            Type::Array(t) => format!(
+                "typing.List[{}]",
+                self.writer_type(t, owner, &format!("{path}Item"))
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "str".to_string(),
+            Type::OneOf(_) => format!("{owner}.{path}"),
+        }
+    }
+
+    /// Returns an expression that writes `value` at `pos` using the builder `b`. `depth` is used
+    /// to give the parameters of element writers unique names.
+    fn write_expr(&self, ty: &Type, ctx: Context, value: &str, pos: &str, depth: usize) -> String {
+        match ty {
+            Type::Primitive(p) => format!("b.write(\"{}\", {pos}, {value})", primitive_format(p)),
+            Type::Enum(_, size) => format!("b.write(\"{}\", {pos}, {value})", enum_format(*size)),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("b.write_string({pos}, {value})"),
+            Type::Array(t) => format!(
+                "b.write_list({pos}, {value}, {stride}, lambda p{depth}, e{depth}: {write})",
+                stride = self.element_size(t),
+                write = self.write_expr(
+                    t,
+                    Context::Element,
+                    &format!("e{depth}"),
+                    &format!("p{depth}"),
+                    depth + 1
+                ),
+            ),
+            Type::Sequence(s) => match ctx {
+                Context::Slot => format!(
+                    "{value}.write_component(b, b.reserve({pos}, {pos}, {size}))",
+                    size = self.sequence_sizes[s.as_str()],
+                ),
+                Context::Element => format!("{value}.write_component(b, {pos})"),
+            },
+            Type::OneOf(_) => format!("{value}.write_component(b, {pos})"),
+        }
+    }
+
+    /// Returns the definitions of the oneof classes nested in a value that is written.
+    fn writer_nested_types(&self, ty: &Type, owner: &str, path: &str) -> Vec<String> {
+        match ty {
+            Type::Array(t) => self.writer_nested_types(t, owner, &format!("{path}Item")),
+            Type::OneOf(subfields) => {
+                let mut types = vec![];
+                for f in subfields {
+                    types.extend(self.writer_nested_types(
+                        &f.ty,
+                        owner,
+                        &field_path(path, &f.name),
+                    ));
+                }
+                types.extend(self.define_oneof_writer(owner, path, subfields));
+                types
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Generates the Python code for defining a sequence writer.
+    fn define_sequence_writer(&self, seq: &Sequence) -> String {
+        let name = writer_name(&seq.name);
+
+        let members = seq
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{}: {}",
+                    f.name.to_case(Case::Snake),
+                    self.writer_type(&f.ty, &name, &field_path("", &f.name))
+                )
+            })
+            .join("\n");
+
+        let write_fields = if seq.fields.is_empty() {
+            "pass".to_string()
+        } else {
+            seq.fields
+                .iter()
+                .map(|f| {
+                    self.write_expr(
+                        &f.ty,
+                        Context::Slot,
+                        &format!("self.{}", f.name.to_case(Case::Snake)),
+                        &format!("pos + {}", f.index),
+                        1,
+                    )
+                })
+                .join("\n")
+        };
+
+        let methods = formatdoc! {
+            r#"
+            STATIC_SIZE: typing.ClassVar[int] = {static_size}
+
+            def write_component(self, b: _Builder, pos: int) -> None:
+                """Writes static data at `pos` and dynamic data at the end of the builder."""
+                {write_fields}
+
+            def encode(self) -> bytes:
+                """Serializes the sequence into a new `bytes` object."""
+                b = _Builder(self.STATIC_SIZE)
+                self.write_component(b, 0)
+                return bytes(b.buf)"#,
+            static_size = self.sequence_sizes[seq.name.as_str()],
+            write_fields = indent_by(4, write_fields),
+        };
+
+        let nested = seq
+            .fields
+            .iter()
+            .flat_map(|f| self.writer_nested_types(&f.ty, &name, &field_path("", &f.name)))
+            .join("\n\n");
+
+        declare(
+            &format!("@dataclasses.dataclass\nclass {name}:"),
+            &[members, methods, nested],
+        )
+    }
+
+    /// Generates the Python code for defining a oneof writer. Every oneof field has a dataclass,
+    /// and the oneof itself is a `typing.Union` of them.
+    fn define_oneof_writer(&self, owner: &str, path: &str, subfields: &[Field]) -> Vec<String> {
+        let mut types = subfields
+            .iter()
+            .map(|f| {
+                formatdoc! {
+                    r#"
+                    @dataclasses.dataclass
+                    class {path}{case}Case:
+                        value: {ty}
+
+                        def write_component(self, b: _Builder, pos: int) -> None:
+                            b.write("B", pos, {index})
+                            p = b.reserve(pos + 1, pos, {size})
+                            {write}"#,
+                    case = f.name.to_case(Case::Pascal),
+                    ty = self.writer_type(&f.ty, owner, &field_path(path, &f.name)),
+                    index = f.index,
+                    size = self.element_size(&f.ty),
+                    write = self.write_expr(&f.ty, Context::Element, "self.value", "p", 1),
+                }
+            })
+            .collect_vec();
+
+        types.push(format!(
+            "{path} = typing.Union[{}]",
+            subfields
+                .iter()
+                .map(|f| format!("{path}{}Case", f.name.to_case(Case::Pascal)))
+                .join(", ")
+        ));
+        types
+    }
+
+    //                                                                                            //
+    // =============================== Generate Reader Components =============================== //
+    //                                                                                            //
+
+    /// Returns the Python type returned when reading a value. `path` names the types nested in
+    /// the value, which are declared in the class `owner`.
+    fn reader_type(&self, ty: &Type, owner: &str, path: &str) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p).to_string(),
+            Type::Sequence(s) => reader_name(s),
+            Type::Enum(e, _) => format!("typing.Union[{}, int]", e.to_case(Case::Pascal)),
+            Type::Array(t) => format!(
+                "_ListReader[{}]",
+                self.reader_type(t, owner, &format!("{path}Item"))
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "str".to_string(),
+            Type::OneOf(_) => format!("typing.Optional[{owner}.{path}]"),
+        }
+    }
+
+    /// Returns an expression that reads a value of the given type at `pos` in `buf`. `depth` is
+    /// used to give the parameters of element readers unique names.
+    #[allow(clippy::too_many_arguments)]
+    fn read_expr(
+        &self,
+        ty: &Type,
+        owner: &str,
+        path: &str,
+        ctx: Context,
+        buf: &str,
+        pos: &str,
+        depth: usize,
+    ) -> String {
+        match ty {
+            Type::Primitive(p) => format!("_read(\"{}\", {buf}, {pos})", primitive_format(p)),
+            Type::Enum(e, size) => format!(
+                "_enum({}, _read(\"{}\", {buf}, {pos}))",
+                e.to_case(Case::Pascal),
+                enum_format(*size)
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("_read_string({buf}, {pos})"),
+            Type::Array(t) => format!(
+                "_ListReader({buf}, {pos}, {stride}, lambda p{depth}: {read})",
+                stride = self.element_size(t),
+                read = self.read_expr(
+                    t,
+                    owner,
+                    &format!("{path}Item"),
+                    Context::Element,
+                    buf,
+                    &format!("p{depth}"),
+                    depth + 1
+                ),
+            ),
+            Type::Sequence(s) => match ctx {
+                Context::Slot => format!("{}({buf}, _follow({buf}, {pos}))", reader_name(s)),
+                Context::Element => format!("{}({buf}, {pos})", reader_name(s)),
+            },
+            Type::OneOf(_) => format!("{owner}._read_{}({buf}, {pos})", path.to_case(Case::Snake)),
+        }
+    }
+
+    /// Returns the definitions of the oneof classes nested in a value that is read.
+    fn reader_nested_types(&self, ty: &Type, owner: &str, path: &str) -> Vec<String> {
+        match ty {
+            Type::Array(t) => self.reader_nested_types(t, owner, &format!("{path}Item")),
+            Type::OneOf(subfields) => {
+                let mut types = vec![];
+                for f in subfields {
+                    types.extend(self.reader_nested_types(
+                        &f.ty,
+                        owner,
+                        &field_path(path, &f.name),
+                    ));
+                }
+                types.extend(self.define_oneof_reader(owner, path, subfields));
+                types
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Generates the Python code for defining a sequence reader. Fields are read lazily through
+    /// properties.
+    fn define_sequence_reader(&self, seq: &Sequence) -> String {
+        let name = reader_name(&seq.name);
+
+        let init = formatdoc! {
+            r#"
+            __slots__ = ("_buf", "_pos")
+
+            STATIC_SIZE: typing.ClassVar[int] = {static_size}
+
+            def __init__(self, buf: bytes, pos: int = 0) -> None:
+                self._buf = buf
+                self._pos = pos"#,
+            static_size = self.sequence_sizes[seq.name.as_str()],
+        };
+
+        let properties = seq
+            .fields
+            .iter()
+            .map(|f| {
+                let path = field_path("", &f.name);
+                formatdoc! {
+                    r"
+                    @property
+                    def {field}(self) -> {ty}:
+                        return {read}",
+                    field = f.name.to_case(Case::Snake),
+                    ty = self.reader_type(&f.ty, &name, &path),
+                    read = self.read_expr(
+                        &f.ty,
+                        &name,
+                        &path,
+                        Context::Slot,
+                        "self._buf",
+                        &format!("self._pos + {}", f.index),
+                        1
+                    ),
+                }
+            })
+            .join("\n\n");
+
+        let nested = seq
+            .fields
+            .iter()
+            .flat_map(|f| self.reader_nested_types(&f.ty, &name, &field_path("", &f.name)))
+            .join("\n\n");
+
+        declare(&format!("class {name}:"), &[init, properties, nested])
+    }
+
+    /// Generates the Python code for defining a oneof reader. Every oneof field has a dataclass,
+    /// and a static method returns the one selected by the tag stored in the buffer, or `None` if
+    /// the tag is unknown.
+    fn define_oneof_reader(&self, owner: &str, path: &str, subfields: &[Field]) -> Vec<String> {
+        let mut types = subfields
+            .iter()
+            .map(|f| {
+                formatdoc! {
+                    r"
+                    @dataclasses.dataclass
+                    class {path}{case}Case:
+                        value: {ty}",
+                    case = f.name.to_case(Case::Pascal),
+                    ty = self.reader_type(&f.ty, owner, &field_path(path, &f.name)),
+                }
+            })
+            .collect_vec();
+
+        types.push(format!(
+            "{path} = typing.Union[{}]",
+            subfields
+                .iter()
+                .map(|f| format!("{path}{}Case", f.name.to_case(Case::Pascal)))
+                .join(", ")
+        ));
+
+        let cases = subfields
+            .iter()
+            .map(|f| {
+                formatdoc! {
+                    r"
+                    if tag == {index}:
+                        return {owner}.{path}{case}Case({read})",
+                    index = f.index,
+                    case = f.name.to_case(Case::Pascal),
+                    read = self.read_expr(
+                        &f.ty,
+                        owner,
+                        &field_path(path, &f.name),
+                        Context::Element,
+                        "buf",
+                        "p",
+                        1
+                    ),
+                }
+            })
+            .join("\n");
+
+        types.push(formatdoc! {
+            r#"
+            @staticmethod
+            def _read_{snake}(buf: bytes, pos: int) -> typing.Optional[{owner}.{path}]:
+                tag = buf[pos]
+                p = pos + _read("H", buf, pos + 1)
+                {cases}
+                return None"#,
+            snake = path.to_case(Case::Snake),
+            cases = indent_by(4, cases),
+        });
+        types
+    }
+}
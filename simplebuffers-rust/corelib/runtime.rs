@@ -0,0 +1,277 @@
+use core::fmt;
+
+/// An error that occurred while writing a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The buffer is too small to hold the serialized data.
+    NoSpaceLeft,
+
+    /// An offset to dynamic data, or the length of a list or byte buffer, does not fit in 16 bits.
+    OffsetOverflow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoSpaceLeft => write!(f, "the buffer is too small to hold the message"),
+            Error::OffsetOverflow => write!(f, "an offset or length does not fit in 16 bits"),
+        }
+    }
+}
+
+/// Returns the `N` bytes starting at `pos`.
+fn read_bytes<const N: usize>(buf: &[u8], pos: usize) -> [u8; N] {
+    let mut bytes = [0; N];
+    bytes.copy_from_slice(&buf[pos..pos + N]);
+    bytes
+}
+
+/// A value that can be read from a fixed position in a buffer.
+pub trait ReadField<'a>: Sized {
+    /// Reads the value whose static data starts at `pos`.
+    fn read_field(buf: &'a [u8], pos: usize) -> Self;
+}
+
+macro_rules! impl_read_field {
+    ($($t:ty),*) => {
+        $(
+            impl<'a> ReadField<'a> for $t {
+                fn read_field(buf: &'a [u8], pos: usize) -> Self {
+                    <$t>::from_le_bytes(read_bytes(buf, pos))
+                }
+            }
+        )*
+    };
+}
+
+impl_read_field!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl<'a> ReadField<'a> for bool {
+    fn read_field(buf: &'a [u8], pos: usize) -> Self {
+        buf[pos] != 0
+    }
+}
+
+/// Strings are read up to their null terminator. If a string is not valid UTF-8, only the part
+/// before the first invalid byte is returned.
+impl<'a> ReadField<'a> for &'a str {
+    fn read_field(buf: &'a [u8], pos: usize) -> Self {
+        let rest = &buf[follow(buf, pos)..];
+        let bytes = &rest[..rest.iter().position(|&b| b == 0).unwrap_or(rest.len())];
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(e) => core::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default(),
+        }
+    }
+}
+
+/// Byte buffers are read as a slice of the buffer, without copying.
+impl<'a> ReadField<'a> for &'a [u8] {
+    fn read_field(buf: &'a [u8], pos: usize) -> Self {
+        let start = follow(buf, pos);
+        let len = u16::read_field(buf, start) as usize;
+        &buf[start + 2..start + 2 + len]
+    }
+}
+
+/// Reads a value whose static data starts at `pos`.
+pub fn read_field<'a, T: ReadField<'a>>(buf: &'a [u8], pos: usize) -> T {
+    T::read_field(buf, pos)
+}
+
+/// Follows the 16-bit offset stored at `pos` and returns the position it points to.
+pub fn follow(buf: &[u8], pos: usize) -> usize {
+    pos + u16::read_field(buf, pos) as usize
+}
+
+/// Reads a list stored in a buffer. Elements are `stride` bytes apart and are read with a function
+/// that takes the buffer and the position of the element.
+pub struct ListReader<'a, T> {
+    buf: &'a [u8],
+    start: usize,
+    len: usize,
+    stride: usize,
+    read: fn(&'a [u8], usize) -> T,
+}
+
+impl<'a, T> Clone for ListReader<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ListReader<'a, T> {}
+
+impl<'a, T> ListReader<'a, T> {
+    /// Reads a list whose length and offset are stored at `pos`.
+    pub fn new(
+        buf: &'a [u8],
+        pos: usize,
+        stride: usize,
+        read: fn(&'a [u8], usize) -> T,
+    ) -> Self {
+        Self {
+            buf,
+            start: pos + u16::read_field(buf, pos + 2) as usize,
+            len: u16::read_field(buf, pos) as usize,
+            stride,
+            read,
+        }
+    }
+
+    /// Reads a fixed-size array of `len` elements whose first element is stored at `pos`.
+    pub fn fixed(
+        buf: &'a [u8],
+        pos: usize,
+        len: usize,
+        stride: usize,
+        read: fn(&'a [u8], usize) -> T,
+    ) -> Self {
+        Self {
+            buf,
+            start: pos,
+            len,
+            stride,
+            read,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Reads the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> T {
+        assert!(index < self.len, "index {} is out of bounds", index);
+        (self.read)(self.buf, self.start + index * self.stride)
+    }
+
+    /// Returns an iterator over the elements of the list.
+    pub fn iter(&self) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        let list = *self;
+        (0..list.len).map(move |i| list.get(i))
+    }
+}
+
+/// A value that can be written at a fixed position in a buffer.
+pub trait WriteField: Copy {
+    /// Writes the value at `pos`.
+    fn write_field(self, buf: &mut [u8], pos: usize);
+}
+
+macro_rules! impl_write_field {
+    ($($t:ty),*) => {
+        $(
+            impl WriteField for $t {
+                fn write_field(self, buf: &mut [u8], pos: usize) {
+                    let bytes = self.to_le_bytes();
+                    buf[pos..pos + bytes.len()].copy_from_slice(&bytes);
+                }
+            }
+        )*
+    };
+}
+
+impl_write_field!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl WriteField for bool {
+    fn write_field(self, buf: &mut [u8], pos: usize) {
+        buf[pos] = self as u8;
+    }
+}
+
+/// Serializes data into a caller-provided buffer. Static data is written at fixed positions and
+/// dynamic data is appended after the data written so far.
+pub struct Builder<'b> {
+    buf: &'b mut [u8],
+    len: usize,
+}
+
+impl<'b> Builder<'b> {
+    /// Creates a builder for a message whose static data takes up `static_size` bytes. The static
+    /// data is zeroed.
+    pub fn new(buf: &'b mut [u8], static_size: usize) -> Result<Self, Error> {
+        if buf.len() < static_size {
+            return Err(Error::NoSpaceLeft);
+        }
+        buf[..static_size].fill(0);
+        Ok(Self {
+            buf,
+            len: static_size,
+        })
+    }
+
+    /// Returns the number of bytes written.
+    pub fn finish(self) -> usize {
+        self.len
+    }
+
+    /// Reserves `count` zeroed bytes after the data written so far and stores the offset to them
+    /// (relative to `base`) at `slot`. Returns the position of the reserved bytes.
+    pub fn reserve(&mut self, slot: usize, base: usize, count: usize) -> Result<usize, Error> {
+        if self.buf.len() - self.len < count {
+            return Err(Error::NoSpaceLeft);
+        }
+        let start = self.len;
+        let offset = u16::try_from(start - base).map_err(|_| Error::OffsetOverflow)?;
+        self.buf[start..start + count].fill(0);
+        self.len += count;
+        self.write_field(slot, offset);
+        Ok(start)
+    }
+
+    /// Writes a value at `pos`.
+    pub fn write_field<T: WriteField>(&mut self, pos: usize, value: T) {
+        value.write_field(self.buf, pos);
+    }
+
+    /// Writes a null-terminated string after the data written so far and stores its offset at
+    /// `pos`.
+    pub fn write_string(&mut self, pos: usize, value: &str) -> Result<(), Error> {
+        let start = self.reserve(pos, pos, value.len() + 1)?;
+        self.buf[start..start + value.len()].copy_from_slice(value.as_bytes());
+        Ok(())
+    }
+
+    /// Writes a length-prefixed byte buffer after the data written so far and stores its offset at
+    /// `pos`.
+    pub fn write_bytes(&mut self, pos: usize, value: &[u8]) -> Result<(), Error> {
+        let len = u16::try_from(value.len()).map_err(|_| Error::OffsetOverflow)?;
+        let start = self.reserve(pos, pos, value.len() + 2)?;
+        self.write_field(start, len);
+        self.buf[start + 2..start + 2 + value.len()].copy_from_slice(value);
+        Ok(())
+    }
+
+    /// Writes a list header at `pos` and the list elements after the data written so far. Each
+    /// element is written by `write`, which takes the builder, the position of the element, and
+    /// the element.
+    pub fn write_list<T>(
+        &mut self,
+        pos: usize,
+        values: &[T],
+        stride: usize,
+        mut write: impl FnMut(&mut Self, usize, &T) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let len = u16::try_from(values.len()).map_err(|_| Error::OffsetOverflow)?;
+        self.write_field(pos, len);
+        let start = self.reserve(pos + 2, pos, values.len() * stride)?;
+        for (i, value) in values.iter().enumerate() {
+            write(self, start + i * stride, value)?;
+        }
+        Ok(())
+    }
+}
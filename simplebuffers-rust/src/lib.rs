@@ -0,0 +1,83 @@
+//! Rust code generator.
+//!
+//! This module implements Rust code generation for SimpleBuffers schemas. It produces a single
+//! `{file_name}.rs` module that contains:
+//!
+//! - A small runtime (`rt`) with helpers for reading and writing little-endian values.
+//! - A `#[repr]` Rust enum for every schema enum, backed by an integer of the enum's size.
+//! - A writer struct for every sequence, which serializes into a caller-provided `&mut [u8]`.
+//! - A reader struct for every sequence, which borrows a `&[u8]` and reads fields through accessor
+//!   functions.
+//!
+//! Oneofs are represented as Rust enums with one variant per oneof field. Readers return `None` if
+//! the tag stored in the buffer does not match any known field.
+//!
+//! The generated code does not allocate and only depends on `core`. Writers hold sequences and
+//! oneofs by value, so sequences that hold each other by value through oneofs are rejected.
+
+mod rustgen;
+
+use std::path::PathBuf;
+
+use rustgen::generate_rust;
+use simplebuffers_codegen::{register_generator, CodeGenerator, FileSink};
+
+#[derive(Debug)]
+pub struct RustCodeGenerator;
+
+/// Keywords that are escaped as raw identifiers (`r#name`) when reserved identifiers are relaxed.
+pub(crate) const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Keywords that cannot be raw identifiers, and names used by the generated code. These are
+/// escaped by appending `_` when reserved identifiers are relaxed.
+pub(crate) const OTHER_RESERVED: &[&str] = &["self", "Self", "super", "crate", "rt", "new", "at"];
+
+impl CodeGenerator for RustCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        let code = generate_rust(schema, params.relax_reserved)?;
+
+        let path = PathBuf::from(&params.dest_dir).join(format!("{}.rs", params.file_name));
+        sink.write_file(&path, &code)?;
+
+        Ok(vec![path])
+    }
+
+    fn reserved_identifiers(
+        &mut self,
+        _params: &simplebuffers_codegen::GeneratorParams,
+    ) -> Vec<String> {
+        KEYWORDS
+            .iter()
+            .chain(OTHER_RESERVED.iter())
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["rs".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "Rust code".to_string()
+    }
+}
+
+register_generator!(rust: RustCodeGenerator);
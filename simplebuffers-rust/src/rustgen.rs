@@ -0,0 +1,811 @@
+//! Generates a Rust source file.
+
+use convert_case::{Case, Casing};
+use indent::indent_by;
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+use std::collections::HashMap;
+
+use crate::{KEYWORDS, OTHER_RESERVED};
+
+/// Where a value is stored. This determines how sequences are laid out.
+#[derive(Clone, Copy)]
+enum Context {
+    /// The value is a field of a sequence. Sequences are stored behind a 16-bit offset.
+    Slot,
+
+    /// The value is a list element or the target of a oneof. Sequences are stored inline.
+    Element,
+}
+
+/// Information about the schema that is needed while generating code.
+struct RustGen<'a> {
+    /// The static size of every sequence, by name.
+    sequence_sizes: HashMap<&'a str, usize>,
+
+    /// Whether the writer of every sequence borrows data, and so needs a lifetime, by name.
+    writer_lifetimes: HashMap<&'a str, bool>,
+
+    /// The integer type that backs every enum, by name.
+    enum_types: HashMap<&'a str, &'static str>,
+
+    /// Whether reserved identifiers were allowed in the schema, and so must be escaped.
+    relax_reserved: bool,
+}
+
+//                                                                                                //
+// ======================================= Main Function ======================================== //
+//                                                                                                //
+
+/// Generates a Rust source file from a given schema.
+///
+/// # Arguments
+///
+/// * `schema` - The schema to generate from.
+/// * `relax_reserved` - Whether reserved identifiers were allowed in the schema. If so, they are
+///   escaped in the generated code.
+///
+/// # Returns
+///
+/// The code for a Rust source file, or an error if the schema cannot be represented in Rust.
+pub(crate) fn generate_rust(schema: &SBSchema, relax_reserved: bool) -> Result<String, String> {
+    if schema.uses_maps() {
+        return Err("The Rust generator does not support maps".to_string());
+    }
+
+    // Writers hold sequences by value, so a sequence cannot contain itself, even through a oneof.
+    // Sequences are visited in dependency order so that the lifetimes of the sequences a writer
+    // holds are known before the writer itself.
+    let ordered = schema
+        .sequences_in_dependency_order()
+        .map_err(|e| format!("The Rust generator cannot represent this schema: {}", e))?;
+
+    let mut gen = RustGen {
+        sequence_sizes: schema
+            .sequences
+            .iter()
+            .map(|s| (s.name.as_str(), s.static_size()))
+            .collect(),
+        writer_lifetimes: HashMap::new(),
+        enum_types: schema
+            .enums
+            .iter()
+            .map(|e| (e.name.as_str(), enum_type(e)))
+            .collect(),
+        relax_reserved,
+    };
+    for seq in ordered {
+        let needs_lifetime = seq.fields.iter().any(|f| gen.writer_needs_lifetime(&f.ty));
+        gen.writer_lifetimes
+            .insert(seq.name.as_str(), needs_lifetime);
+    }
+
+    let runtime = include_str!("../corelib/runtime.rs").trim();
+    let declarations = schema
+        .enums
+        .iter()
+        .map(|e| gen.define_enum(e))
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| gen.define_sequence_writer(s)),
+        )
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| gen.define_sequence_reader(s)),
+        )
+        .join("\n\n");
+
+    Ok(formatdoc! {
+        r#"
+        // This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+        /// Low-level helpers used by generated readers and writers.
+        ///
+        /// All multi-byte values are stored in little-endian byte order. Offsets to dynamic data are
+        /// 16-bit values relative to the position of the field that holds them.
+        #[allow(dead_code)]
+        pub mod rt {{
+            {runtime}
+        }}
+
+        {declarations}
+        "#,
+        runtime = indent_by(4, runtime),
+    })
+}
+
+/// Returns the Rust type that matches a primitive.
+fn primitive_type(p: &Primitive) -> &'static str {
+    match p {
+        Primitive::Bool => "bool",
+        Primitive::U8 => "u8",
+        Primitive::U16 => "u16",
+        Primitive::U32 => "u32",
+        Primitive::U64 => "u64",
+        Primitive::I8 => "i8",
+        Primitive::I16 => "i16",
+        Primitive::I32 => "i32",
+        Primitive::I64 => "i64",
+        Primitive::F32 => "f32",
+        Primitive::F64 => "f64",
+    }
+}
+
+/// Returns the integer type that backs an enum. Enums with negative values are signed.
+fn enum_type(data: &Enum) -> &'static str {
+    match (data.size, data.is_signed()) {
+        (1, false) => "u8",
+        (2, false) => "u16",
+        (4, false) => "u32",
+        (8, false) => "u64",
+        (1, true) => "i8",
+        (2, true) => "i16",
+        (4, true) => "i32",
+        (8, true) => "i64",
+        (size, _) => panic!("Cannot convert size {} to Rust type", size),
+    }
+}
+
+/// Returns the generic parameters of a type that borrows data for `'a` if `needs_lifetime` is set.
+fn lifetime(needs_lifetime: bool) -> &'static str {
+    if needs_lifetime {
+        "<'a>"
+    } else {
+        ""
+    }
+}
+
+/// Returns the position `offset` bytes after `pos`.
+fn offset_pos(pos: &str, offset: usize) -> String {
+    if offset == 0 {
+        pos.to_string()
+    } else {
+        format!("{pos} + {offset}")
+    }
+}
+
+/// Returns the Rust doc comment for a schema doc comment, or nothing if there is none.
+fn doc_comment(doc: &Option<String>) -> String {
+    match doc {
+        None => String::new(),
+        Some(doc) => doc
+            .lines()
+            .map(|line| format!("/// {}", line).trim_end().to_string() + "\n")
+            .collect(),
+    }
+}
+
+/// Wraps the members of an item in braces.
+fn declare(header: &str, body: &str) -> String {
+    if body.trim().is_empty() {
+        return format!("{header} {{}}");
+    }
+    formatdoc! {
+        r"
+        {header} {{
+            {body}
+        }}",
+        body = indent_by(4, body.trim())
+    }
+}
+
+impl<'a> RustGen<'a> {
+    /// Escapes an identifier that is reserved in Rust. Reserved identifiers only make it this far
+    /// if they were allowed with `--relax-reserved`.
+    fn escape(&self, name: String) -> String {
+        if !self.relax_reserved {
+            name
+        } else if KEYWORDS.contains(&name.as_str()) {
+            format!("r#{}", name)
+        } else if OTHER_RESERVED.contains(&name.as_str()) {
+            format!("{}_", name)
+        } else {
+            name
+        }
+    }
+
+    /// Returns the name of a type, such as an enum or an enum variant.
+    fn type_name(&self, name: &str) -> String {
+        self.escape(name.to_case(Case::Pascal))
+    }
+
+    /// Returns the name of a field.
+    fn field_name(&self, name: &str) -> String {
+        self.escape(name.to_case(Case::Snake))
+    }
+
+    /// Returns the name of the writer struct for a sequence.
+    fn writer_name(&self, seq_name: &str) -> String {
+        format!("{}Writer", seq_name.to_case(Case::Pascal))
+    }
+
+    /// Returns the name of the reader struct for a sequence.
+    fn reader_name(&self, seq_name: &str) -> String {
+        format!("{}Reader", seq_name.to_case(Case::Pascal))
+    }
+
+    /// Returns the number of bytes a type takes up as a list element or oneof target.
+    fn element_size(&self, ty: &Type) -> usize {
+        match ty {
+            Type::Sequence(s) => self.sequence_sizes[s.as_str()],
+            _ => ty.size(),
+        }
+    }
+
+    //                                                                                            //
+    // ==================================== Generate Enums ===================================== //
+    //                                                                                            //
+
+    /// Generates the Rust code for defining an enum. Since values read from a buffer may not match
+    /// any variant, they are converted with `from_raw`.
+    fn define_enum(&self, data: &Enum) -> String {
+        let name = self.type_name(&data.name);
+        let repr = self.enum_types[data.name.as_str()];
+
+        let variants = data
+            .variants
+            .iter()
+            .map(|v| {
+                format!(
+                    "{}{} = {},",
+                    doc_comment(&v.doc),
+                    self.type_name(&v.name),
+                    v.value
+                )
+            })
+            .join("\n");
+
+        // An enum without variants cannot have a `repr`, and no value can be converted to it.
+        let (attributes, from_raw) = if data.variants.is_empty() {
+            (
+                String::new(),
+                formatdoc! {
+                    r"
+                    pub fn from_raw(_value: {repr}) -> Option<Self> {{
+                        None
+                    }}"
+                },
+            )
+        } else {
+            let arms = data
+                .variants
+                .iter()
+                .map(|v| format!("{} => Some(Self::{}),", v.value, self.type_name(&v.name)))
+                .join("\n");
+            (
+                format!("#[repr({repr})]\n"),
+                formatdoc! {
+                    r"
+                    pub fn from_raw(value: {repr}) -> Option<Self> {{
+                        match value {{
+                            {arms}
+                            _ => None,
+                        }}
+                    }}",
+                    arms = indent_by(8, arms),
+                },
+            )
+        };
+
+        let definition = declare(
+            &format!(
+                "{}{attributes}#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]\npub enum {name}",
+                doc_comment(&data.doc)
+            ),
+            &variants,
+        );
+        let implementation = declare(
+            &format!("impl {name}"),
+            &format!(
+                "/// Returns the variant with the given value, or `None` if there is no such \
+                 variant.\n{from_raw}"
+            ),
+        );
+        format!("{definition}\n\n{implementation}")
+    }
+
+    //                                                                                            //
+    // =============================== Generate Writer Components =============================== //
+    //                                                                                            //
+
+    /// Returns whether the Rust type used to write a value borrows data.
+    fn writer_needs_lifetime(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Map(..) => unreachable!("maps are rejected before generation"),
+            Type::String | Type::Bytes | Type::Array(_) => true,
+            Type::Sequence(s) => self.writer_lifetimes[s.as_str()],
+            Type::OneOf(subfields) => subfields.iter().any(|f| self.writer_needs_lifetime(&f.ty)),
+            Type::Primitive(_) | Type::Enum(_, _) | Type::FixedArray(_, _) => false,
+        }
+    }
+
+    /// Returns the Rust type used to write a value. `path` names the oneofs nested in the value.
+    fn writer_type(&self, ty: &Type, path: &str) -> String {
+        match ty {
+            Type::Map(..) => unreachable!("maps are rejected before generation"),
+            Type::Primitive(p) => primitive_type(p).to_string(),
+            Type::Enum(e, _) => self.type_name(e),
+            Type::String => "&'a str".to_string(),
+            Type::Bytes => "&'a [u8]".to_string(),
+            Type::Array(t) => format!("&'a [{}]", self.writer_type(t, path)),
+            Type::FixedArray(t, len) => format!("[{}; {}]", self.writer_type(t, path), len),
+            Type::Sequence(s) => format!(
+                "{}{}",
+                self.writer_name(s),
+                lifetime(self.writer_lifetimes[s.as_str()])
+            ),
+            Type::OneOf(_) => format!("{path}Writer{}", lifetime(self.writer_needs_lifetime(ty))),
+        }
+    }
+
+    /// Returns statements that write `value` at `pos` using the builder `b`. The statements
+    /// return early with `?` if writing fails.
+    fn write_stmt(&self, ty: &Type, ctx: Context, value: &str, pos: &str) -> String {
+        match ty {
+            Type::Map(..) => unreachable!("maps are rejected before generation"),
+            Type::Primitive(_) => format!("b.write_field({pos}, {value});"),
+            Type::Enum(e, _) => format!(
+                "b.write_field({pos}, {value} as {});",
+                self.enum_types[e.as_str()]
+            ),
+            Type::String => format!("b.write_string({pos}, {value})?;"),
+            Type::Bytes => format!("b.write_bytes({pos}, {value})?;"),
+            Type::Array(t) => formatdoc! {
+                r"
+                b.write_list({pos}, {value}, {stride}, |b, p, &e| {{
+                    {write}
+                    Ok(())
+                }})?;",
+                stride = self.element_size(t),
+                write = indent_by(4, self.write_stmt(t, Context::Element, "e", "p")),
+            },
+            Type::FixedArray(t, _) => {
+                let stride = t.size();
+                let element_pos = if stride == 1 {
+                    format!("{pos} + i")
+                } else {
+                    format!("{pos} + i * {stride}")
+                };
+                formatdoc! {
+                    r"
+                    for (i, &e) in {value}.iter().enumerate() {{
+                        {write}
+                    }}",
+                    write = indent_by(
+                        4,
+                        self.write_stmt(t, Context::Element, "e", &element_pos)
+                    ),
+                }
+            }
+            Type::Sequence(s) => match ctx {
+                Context::Slot => formatdoc! {
+                    r"
+                    let p = b.reserve({pos}, {pos}, {size})?;
+                    {value}.write_component(b, p)?;",
+                    size = self.sequence_sizes[s.as_str()],
+                },
+                Context::Element => format!("{value}.write_component(b, {pos})?;"),
+            },
+            Type::OneOf(_) => format!("{value}.write_component(b, {pos})?;"),
+        }
+    }
+
+    /// Returns the definitions of the oneofs nested in a value that is written.
+    fn writer_nested_types(&self, ty: &Type, path: &str) -> Vec<String> {
+        match ty {
+            Type::Array(t) => self.writer_nested_types(t, path),
+            Type::OneOf(subfields) => {
+                let mut types = vec![self.define_oneof_writer(ty, path, subfields)];
+                for f in subfields {
+                    types.extend(self.writer_nested_types(&f.ty, &field_path(path, &f.name)));
+                }
+                types
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Generates the Rust code for defining a sequence writer.
+    fn define_sequence_writer(&self, seq: &Sequence) -> String {
+        let name = self.writer_name(&seq.name);
+        let generics = lifetime(self.writer_lifetimes[seq.name.as_str()]);
+        let prefix = seq.name.to_case(Case::Pascal);
+
+        let members = seq
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{}pub {}: {},",
+                    doc_comment(&f.doc),
+                    self.field_name(&f.name),
+                    self.writer_type(&f.ty, &field_path(&prefix, &f.name))
+                )
+            })
+            .join("\n");
+
+        let write_fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                self.write_stmt(
+                    &f.ty,
+                    Context::Slot,
+                    &format!("self.{}", self.field_name(&f.name)),
+                    &offset_pos("pos", f.index),
+                )
+            })
+            .chain(std::iter::once("Ok(())".to_string()))
+            .join("\n");
+
+        // Sequences without fields do not use the builder.
+        let (b, pos) = if seq.fields.is_empty() {
+            ("_b", "_pos")
+        } else {
+            ("b", "pos")
+        };
+        let methods = formatdoc! {
+            r"
+            /// The size of the static data of the sequence, in bytes.
+            pub const STATIC_SIZE: usize = {static_size};
+
+            /// Writes static data at `pos` and dynamic data after the data written so far.
+            pub fn write_component(&self, {b}: &mut rt::Builder<'_>, {pos}: usize) -> Result<(), rt::Error> {{
+                {write_fields}
+            }}
+
+            /// Serializes the sequence into `buf` and returns the number of bytes written.
+            pub fn encode(&self, buf: &mut [u8]) -> Result<usize, rt::Error> {{
+                let mut b = rt::Builder::new(buf, Self::STATIC_SIZE)?;
+                self.write_component(&mut b, 0)?;
+                Ok(b.finish())
+            }}",
+            static_size = self.sequence_sizes[seq.name.as_str()],
+            write_fields = indent_by(4, write_fields),
+        };
+
+        let nested = seq
+            .fields
+            .iter()
+            .flat_map(|f| self.writer_nested_types(&f.ty, &field_path(&prefix, &f.name)));
+
+        [
+            declare(
+                &format!(
+                    "{}#[derive(Clone, Copy, Debug, PartialEq)]\npub struct {name}{generics}",
+                    doc_comment(&seq.doc)
+                ),
+                &members,
+            ),
+            declare(&format!("impl{generics} {name}{generics}"), &methods),
+        ]
+        .into_iter()
+        .chain(nested)
+        .join("\n\n")
+    }
+
+    /// Generates the Rust code for defining a oneof writer. Oneofs are written as enums with one
+    /// variant per field.
+    fn define_oneof_writer(&self, ty: &Type, path: &str, subfields: &[Field]) -> String {
+        let name = format!("{path}Writer");
+        let generics = lifetime(self.writer_needs_lifetime(ty));
+
+        let variants = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{}{}({}),",
+                    doc_comment(&f.doc),
+                    self.type_name(&f.name),
+                    self.writer_type(&f.ty, &field_path(path, &f.name))
+                )
+            })
+            .join("\n");
+
+        let arms = subfields
+            .iter()
+            .map(|f| {
+                formatdoc! {
+                    r"
+                    Self::{variant}(value) => {{
+                        b.write_field(pos, {index}u8);
+                        let p = b.reserve(pos + 1, pos, {size})?;
+                        {write}
+                    }}",
+                    variant = self.type_name(&f.name),
+                    index = f.index,
+                    size = self.element_size(&f.ty),
+                    write = indent_by(
+                        4,
+                        self.write_stmt(
+                            &f.ty,
+                            Context::Element,
+                            "value",
+                            "p"
+                        )
+                    ),
+                }
+            })
+            .join("\n");
+
+        // A oneof without fields can never be constructed, so there is nothing to write.
+        let body = if subfields.is_empty() {
+            "match *self {}".to_string()
+        } else {
+            formatdoc! {
+                r"
+                match *self {{
+                    {arms}
+                }}
+                Ok(())",
+                arms = indent_by(4, arms),
+            }
+        };
+        let methods = formatdoc! {
+            r"
+            /// Writes the tag at `pos` and the active field after the data written so far.
+            pub fn write_component(&self, b: &mut rt::Builder<'_>, pos: usize) -> Result<(), rt::Error> {{
+                {body}
+            }}",
+            body = indent_by(4, body),
+        };
+
+        [
+            declare(
+                &format!("#[derive(Clone, Copy, Debug, PartialEq)]\npub enum {name}{generics}"),
+                &variants,
+            ),
+            declare(&format!("impl{generics} {name}{generics}"), &methods),
+        ]
+        .join("\n\n")
+    }
+
+    //                                                                                            //
+    // =============================== Generate Reader Components =============================== //
+    //                                                                                            //
+
+    /// Returns whether the Rust type returned when reading a value borrows the buffer.
+    fn reader_needs_lifetime(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Primitive(_) | Type::Enum(_, _) => false,
+            Type::OneOf(subfields) => subfields.iter().any(|f| self.reader_needs_lifetime(&f.ty)),
+            _ => true,
+        }
+    }
+
+    /// Returns the Rust type returned when reading a value. `path` names the oneofs nested in the
+    /// value.
+    fn reader_type(&self, ty: &Type, path: &str) -> String {
+        match ty {
+            Type::Map(..) => unreachable!("maps are rejected before generation"),
+            Type::Primitive(p) => primitive_type(p).to_string(),
+            Type::Enum(e, _) => format!("Option<{}>", self.type_name(e)),
+            Type::String => "&'a str".to_string(),
+            Type::Bytes => "&'a [u8]".to_string(),
+            Type::Array(t) | Type::FixedArray(t, _) => {
+                format!("rt::ListReader<'a, {}>", self.reader_type(t, path))
+            }
+            Type::Sequence(s) => format!("{}<'a>", self.reader_name(s)),
+            Type::OneOf(_) => format!(
+                "Option<{path}Reader{}>",
+                lifetime(self.reader_needs_lifetime(ty))
+            ),
+        }
+    }
+
+    /// Returns an expression that reads a value at `pos` from `buf`.
+    fn read_expr(&self, ty: &Type, path: &str, ctx: Context, buf: &str, pos: &str) -> String {
+        match ty {
+            Type::Map(..) => unreachable!("maps are rejected before generation"),
+            Type::Primitive(_) | Type::String | Type::Bytes => {
+                format!("rt::read_field({buf}, {pos})")
+            }
+            Type::Enum(e, _) => format!(
+                "{}::from_raw(rt::read_field({buf}, {pos}))",
+                self.type_name(e)
+            ),
+            Type::Array(t) => format!(
+                "rt::ListReader::new({buf}, {pos}, {}, {})",
+                self.element_size(t),
+                self.read_element_fn(t, path)
+            ),
+            Type::FixedArray(t, len) => format!(
+                "rt::ListReader::fixed({buf}, {pos}, {len}, {}, {})",
+                t.size(),
+                self.read_element_fn(t, path)
+            ),
+            Type::Sequence(s) => match ctx {
+                Context::Slot => format!(
+                    "{}::at({buf}, rt::follow({buf}, {pos}))",
+                    self.reader_name(s)
+                ),
+                Context::Element => format!("{}::at({buf}, {pos})", self.reader_name(s)),
+            },
+            Type::OneOf(_) => format!("{path}Reader::read({buf}, {pos})"),
+        }
+    }
+
+    /// Returns a function that reads a list element, given the buffer and its position.
+    fn read_element_fn(&self, ty: &Type, path: &str) -> String {
+        match ty {
+            Type::Map(..) => unreachable!("maps are rejected before generation"),
+            Type::Primitive(_) | Type::String | Type::Bytes => "rt::read_field".to_string(),
+            Type::Sequence(s) => format!("{}::at", self.reader_name(s)),
+            Type::OneOf(_) => format!("{path}Reader::read"),
+            Type::Enum(_, _) | Type::Array(_) | Type::FixedArray(_, _) => format!(
+                "|buf, pos| {}",
+                self.read_expr(ty, path, Context::Element, "buf", "pos")
+            ),
+        }
+    }
+
+    /// Returns the definitions of the oneofs nested in a value that is read.
+    fn reader_nested_types(&self, ty: &Type, path: &str) -> Vec<String> {
+        match ty {
+            Type::Array(t) => self.reader_nested_types(t, path),
+            Type::OneOf(subfields) => {
+                let mut types = vec![self.define_oneof_reader(ty, path, subfields)];
+                for f in subfields {
+                    types.extend(self.reader_nested_types(&f.ty, &field_path(path, &f.name)));
+                }
+                types
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Generates the Rust code for defining a sequence reader. Fields are read with accessor
+    /// functions.
+    fn define_sequence_reader(&self, seq: &Sequence) -> String {
+        let name = self.reader_name(&seq.name);
+        let prefix = seq.name.to_case(Case::Pascal);
+
+        let accessors = seq
+            .fields
+            .iter()
+            .map(|f| {
+                let path = field_path(&prefix, &f.name);
+                formatdoc! {
+                    r"
+                    {doc}pub fn {accessor}(&self) -> {ty} {{
+                        {read}
+                    }}",
+                    doc = doc_comment(&f.doc),
+                    accessor = self.field_name(&f.name),
+                    ty = self.reader_type(&f.ty, &path),
+                    read = self.read_expr(
+                        &f.ty,
+                        &path,
+                        Context::Slot,
+                        "self.buf",
+                        &offset_pos("self.pos", f.index)
+                    ),
+                }
+            })
+            .join("\n\n");
+
+        let methods = formatdoc! {
+            r"
+            /// The size of the static data of the sequence, in bytes.
+            pub const STATIC_SIZE: usize = {static_size};
+
+            /// Reads a message that starts at the beginning of `buf`.
+            pub fn new(buf: &'a [u8]) -> Self {{
+                Self::at(buf, 0)
+            }}
+
+            /// Reads a sequence whose static data starts at `pos`.
+            pub fn at(buf: &'a [u8], pos: usize) -> Self {{
+                Self {{ buf, pos }}
+            }}
+
+            {accessors}",
+            static_size = self.sequence_sizes[seq.name.as_str()],
+        };
+
+        let nested = seq
+            .fields
+            .iter()
+            .flat_map(|f| self.reader_nested_types(&f.ty, &field_path(&prefix, &f.name)));
+
+        [
+            declare(
+                &format!(
+                    "{}#[derive(Clone, Copy)]\npub struct {name}<'a>",
+                    doc_comment(&seq.doc)
+                ),
+                "buf: &'a [u8],\npos: usize,",
+            ),
+            declare(&format!("impl<'a> {name}<'a>"), &methods),
+        ]
+        .into_iter()
+        .chain(nested)
+        .join("\n\n")
+    }
+
+    /// Generates the Rust code for defining a oneof reader. The active field is chosen by the tag
+    /// stored in the buffer. Unknown tags produce `None`.
+    fn define_oneof_reader(&self, ty: &Type, path: &str, subfields: &[Field]) -> String {
+        let name = format!("{path}Reader");
+        let needs_lifetime = self.reader_needs_lifetime(ty);
+        let generics = lifetime(needs_lifetime);
+        let buf_type = if needs_lifetime { "&'a [u8]" } else { "&[u8]" };
+
+        let variants = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{}{}({}),",
+                    doc_comment(&f.doc),
+                    self.type_name(&f.name),
+                    self.reader_type(&f.ty, &field_path(path, &f.name))
+                )
+            })
+            .join("\n");
+
+        let arms = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{} => Some(Self::{}({})),",
+                    f.index,
+                    self.type_name(&f.name),
+                    self.read_expr(
+                        &f.ty,
+                        &field_path(path, &f.name),
+                        Context::Element,
+                        "buf",
+                        "p"
+                    )
+                )
+            })
+            .join("\n");
+
+        // A oneof without fields never matches a tag.
+        let read = if subfields.is_empty() {
+            formatdoc! {
+                r"
+                pub fn read(_buf: {buf_type}, _pos: usize) -> Option<Self> {{
+                    None
+                }}"
+            }
+        } else {
+            formatdoc! {
+                r"
+                pub fn read(buf: {buf_type}, pos: usize) -> Option<Self> {{
+                    let p = pos + rt::read_field::<u16>(buf, pos + 1) as usize;
+                    match rt::read_field::<u8>(buf, pos) {{
+                        {arms}
+                        _ => None,
+                    }}
+                }}",
+                arms = indent_by(8, arms),
+            }
+        };
+        let methods = formatdoc! {
+            r"
+            /// Reads a oneof whose tag is stored at `pos`. Returns `None` if the tag does not match
+            /// any known field.
+            {read}"
+        };
+
+        [
+            declare(
+                &format!("#[derive(Clone, Copy)]\npub enum {name}{generics}"),
+                &variants,
+            ),
+            declare(&format!("impl{generics} {name}{generics}"), &methods),
+        ]
+        .join("\n\n")
+    }
+}
+
+/// Returns the path used to name the oneofs nested in a field.
+fn field_path(prefix: &str, field_name: &str) -> String {
+    format!("{}{}", prefix, field_name.to_case(Case::Pascal))
+}
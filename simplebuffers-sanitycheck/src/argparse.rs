@@ -0,0 +1,48 @@
+//! Parses generator-specific arguments contained in [GeneratorParams::additional_args].
+
+use std::path::PathBuf;
+
+use clap::{Command, CommandFactory, Parser};
+use simplebuffers_codegen::GeneratorParams;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Struct to parse CLI args into.
+#[derive(Parser, Debug)]
+#[command(name = "SimpleBuffers Sanity Check")]
+#[command(version = VERSION)]
+#[command(about = "Print a parsed SimpleBuffers schema.")]
+struct Cli {
+    /// Print the schema as JSON instead of text.
+    #[arg(long)]
+    json: bool,
+
+    /// Write the schema to this file instead of printing it.
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+/// A struct that holds generator-specific arguments for the sanity check generator.
+#[derive(Debug)]
+pub(crate) struct SanityCheckGeneratorParams {
+    /// Whether to print the schema as JSON.
+    pub json: bool,
+
+    /// The file to write the schema to, or `None` to print it to `stdout`.
+    pub output: Option<PathBuf>,
+}
+
+/// Returns the command that describes the generator-specific arguments, so the compiler can
+/// show them in its help output and validate them before generating.
+pub(crate) fn command() -> Command {
+    Cli::command()
+}
+
+/// Parse generator-specific arguments from the compiler-provided argument list.
+pub(crate) fn parse_args(generator_params: &GeneratorParams) -> SanityCheckGeneratorParams {
+    let cli = Cli::parse_from(&generator_params.additional_args);
+    SanityCheckGeneratorParams {
+        json: cli.json,
+        output: cli.output,
+    }
+}
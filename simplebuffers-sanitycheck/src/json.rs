@@ -0,0 +1,97 @@
+//! Converts a schema into JSON.
+//!
+//! The output has the following shape:
+//!
+//! ```json
+//! {
+//!     "package": "robot.messages",
+//!     "enums": [{ "name": "Color", "size": 1, "variants": [{ "name": "red", "value": 0 }] }],
+//!     "sequences": [{ "name": "Point", "fields": [{ "name": "x", "offset": 0, "type": ... }] }]
+//! }
+//! ```
+//!
+//! `package` is `null` if the schema does not declare a package. Enums, variants, sequences, and
+//! fields also have a `doc` key, which holds their doc comment or `null`.
+//!
+//! Types are objects with a `kind` (`primitive`, `sequence`, `enum`, `array`, `fixed_array`, `map`,
+//! `string`, `bytes`, or `oneof`) and any details needed to resolve them.
+
+use serde_json::{json, Value};
+use simplebuffers_core::{Enum, Field, SBSchema, Sequence, Type};
+
+/// Converts a schema into JSON.
+pub(crate) fn schema_to_json(schema: &SBSchema) -> Value {
+    json!({
+        "package": schema.package,
+        "enums": schema.enums.iter().map(enum_to_json).collect::<Vec<_>>(),
+        "sequences": schema.sequences.iter().map(sequence_to_json).collect::<Vec<_>>(),
+    })
+}
+
+/// Converts an enum into JSON.
+fn enum_to_json(enm: &Enum) -> Value {
+    json!({
+        "name": enm.name,
+        "size": enm.size,
+        "doc": enm.doc,
+        "variants": enm
+            .variants
+            .iter()
+            .map(|v| json!({ "name": v.name, "value": v.value, "doc": v.doc }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Converts a sequence into JSON. Field indices are written as byte offsets.
+fn sequence_to_json(sequence: &Sequence) -> Value {
+    json!({
+        "name": sequence.name,
+        "doc": sequence.doc,
+        "fields": sequence
+            .fields
+            .iter()
+            .map(|f| field_to_json(f, "offset"))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Converts a field into JSON. The field's index is written under `index_key`, since it means
+/// something different in sequences and oneofs.
+fn field_to_json(field: &Field, index_key: &str) -> Value {
+    let mut value = json!({
+        "name": field.name,
+        "type": type_to_json(&field.ty),
+        "doc": field.doc,
+    });
+    value[index_key] = json!(field.index);
+    value
+}
+
+/// Converts a type into JSON.
+fn type_to_json(ty: &Type) -> Value {
+    match ty {
+        Type::Primitive(p) => json!({ "kind": "primitive", "name": p.to_string() }),
+        Type::Sequence(name) => json!({ "kind": "sequence", "name": name }),
+        Type::Enum(name, size) => json!({ "kind": "enum", "name": name, "size": size }),
+        Type::Array(element) => json!({ "kind": "array", "element": type_to_json(element) }),
+        Type::FixedArray(element, len) => json!({
+            "kind": "fixed_array",
+            "element": type_to_json(element),
+            "length": len,
+        }),
+        Type::Map(key, value) => json!({
+            "kind": "map",
+            "key": type_to_json(key),
+            "value": type_to_json(value),
+        }),
+        Type::String => json!({ "kind": "string" }),
+        Type::Bytes => json!({ "kind": "bytes" }),
+        Type::OneOf(fields) => json!({
+            "kind": "oneof",
+            "fields": fields
+                .iter()
+                .map(|f| field_to_json(f, "index"))
+                .collect::<Vec<_>>(),
+        }),
+    }
+}
@@ -1,9 +1,18 @@
-//! A code generator that prints a schema to `stdout`.
+//! A code generator that prints a schema to `stdout`, or to a file with `--output`.
 //!
-//! This can be used to verify that a schema is being parsed correctly.
+//! This can be used to verify that a schema is being parsed correctly. By default, the schema is
+//! printed as text; with `--json`, it is printed as JSON so that it can be checked by other tools.
 
-use simplebuffers_codegen::{register_generator, CodeGenerator};
-use simplebuffers_core::{Enum, EnumVariant, Sequence, Type};
+mod argparse;
+mod json;
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use argparse::{command, parse_args};
+use json::schema_to_json;
+use simplebuffers_codegen::{register_generator, CodeGenerator, FileSink};
+use simplebuffers_core::{Enum, EnumVariant, SBSchema, Sequence, Type};
 
 //                                                                                                //
 // ===================================== Register Generator ===================================== //
@@ -23,19 +32,35 @@ impl CodeGenerator for SanityCheckCodeGenerator {
     fn generate(
         &mut self,
         schema: &simplebuffers_core::SBSchema,
-        _params: &simplebuffers_codegen::GeneratorParams,
-    ) -> Result<(), String> {
-        print_enums(&schema.enums);
-        print_sequences(&schema.sequences);
-        Ok(())
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        let args = parse_args(params);
+        let Some(path) = args.output else {
+            print_schema(schema, args.json, &mut io::stdout().lock())
+                .map_err(|e| format!("Failed to print schema: {}", e))?;
+            return Ok(vec![]);
+        };
+
+        let mut out = vec![];
+        print_schema(schema, args.json, &mut out)
+            .map_err(|e| format!("Failed to print schema: {}", e))?;
+        sink.write_bytes(&path, &out)?;
+        Ok(vec![path])
     }
 
-    fn reserved_identifiers(
-        &mut self,
-        _params: &simplebuffers_codegen::GeneratorParams,
-    ) -> Vec<String> {
+    fn output_extensions(&self) -> Vec<String> {
+        // The schema is printed, or written to whatever file `--output` names.
         vec![]
     }
+
+    fn description(&self) -> String {
+        "Parsed schema, printed for debugging".to_string()
+    }
+
+    fn cli(&self) -> Option<clap::Command> {
+        Some(command())
+    }
 }
 
 register_generator!(sanitycheck: SanityCheckCodeGenerator);
@@ -44,38 +69,60 @@ register_generator!(sanitycheck: SanityCheckCodeGenerator);
 // ===================================== Printing Functions ===================================== //
 //                                                                                                //
 
+/// Print a schema as text, or as JSON if `json` is set.
+fn print_schema(schema: &SBSchema, json: bool, out: &mut dyn Write) -> io::Result<()> {
+    if json {
+        serde_json::to_writer_pretty(&mut *out, &schema_to_json(schema))?;
+        writeln!(out)
+    } else {
+        if let Some(package) = &schema.package {
+            writeln!(out, "Package: {}\n", package)?;
+        }
+        print_enums(&schema.enums, out)?;
+        print_sequences(&schema.sequences, out)
+    }
+}
+
 /// Print a list of enums.
-fn print_enums(enums: &[Enum]) {
-    print!(concat!(
-        "=========================\n",
-        "|         ENUMS         |\n",
-        "=========================\n\n"
-    ));
+fn print_enums(enums: &[Enum], out: &mut dyn Write) -> io::Result<()> {
+    write!(
+        out,
+        concat!(
+            "=========================\n",
+            "|         ENUMS         |\n",
+            "=========================\n\n"
+        )
+    )?;
 
     for Enum {
         name,
         size,
         variants,
+        ..
     } in enums.iter()
     {
-        println!("{} ({} bytes):", name, size);
-        for EnumVariant { name, value } in variants.iter() {
-            println!("  {} = {}", name, value);
+        writeln!(out, "{} ({} bytes):", name, size)?;
+        for EnumVariant { name, value, .. } in variants.iter() {
+            writeln!(out, "  {} = {}", name, value)?;
         }
-        println!();
+        writeln!(out)?;
     }
+    Ok(())
 }
 
 /// Print a list of sequences.
-fn print_sequences(sequences: &[Sequence]) {
-    print!(concat!(
-        "=========================\n",
-        "|       SEQUENCES       |\n",
-        "=========================\n\n"
-    ));
+fn print_sequences(sequences: &[Sequence], out: &mut dyn Write) -> io::Result<()> {
+    write!(
+        out,
+        concat!(
+            "=========================\n",
+            "|       SEQUENCES       |\n",
+            "=========================\n\n"
+        )
+    )?;
 
     for sequence in sequences.iter() {
-        println!("{}:", sequence.name);
+        writeln!(out, "{}:", sequence.name)?;
         for field in sequence.fields.iter() {
             // For each field in a root-level sequence, reset local indentation and create a new
             // stack.
@@ -88,26 +135,37 @@ fn print_sequences(sequences: &[Sequence]) {
                 // For named fields, print the name and offset on a new line. Unnamed fields (array
                 // elements) are printed inline.
                 if let Some(n) = field_name {
-                    print!(
+                    write!(
+                        out,
                         "{indent}{offset} | {name}: ",
                         offset = field_offset,
                         indent = "  ".repeat(indent),
                         name = n
-                    );
+                    )?;
                 }
 
                 // Print and maybe add to stack depending on the field type.
                 match &field_type {
-                    Type::Primitive(name) => println!("{} (primitive)", name),
-                    Type::Sequence(name) => println!("{} (sequence)", name),
-                    Type::Enum(name, size) => println!("{} (enum, {} bytes)", name, size),
+                    Type::Primitive(name) => writeln!(out, "{} (primitive)", name)?,
+                    Type::Sequence(name) => writeln!(out, "{} (sequence)", name)?,
+                    Type::Enum(name, size) => writeln!(out, "{} (enum, {} bytes)", name, size)?,
                     Type::Array(ty) => {
-                        print!("ARRAY OF ");
+                        write!(out, "ARRAY OF ")?;
                         stack.push((None, ty, 0));
                     }
-                    Type::String => println!("string"),
+                    Type::FixedArray(ty, len) => {
+                        write!(out, "ARRAY OF {} ", len)?;
+                        stack.push((None, ty, 0));
+                    }
+                    Type::Map(key, value) => {
+                        // Keys are always primitives, enums, or strings, so they fit on one line.
+                        write!(out, "MAP OF {} TO ", key)?;
+                        stack.push((None, value, 0));
+                    }
+                    Type::String => writeln!(out, "string")?,
+                    Type::Bytes => writeln!(out, "bytes")?,
                     Type::OneOf(f) => {
-                        println!("ONE OF:");
+                        writeln!(out, "ONE OF:")?;
                         for field in f.iter().rev() {
                             stack.push((Some(field.name.clone()), &field.ty, field.index));
                         }
@@ -117,6 +175,7 @@ fn print_sequences(sequences: &[Sequence]) {
             }
         }
 
-        println!();
+        writeln!(out)?;
     }
+    Ok(())
 }
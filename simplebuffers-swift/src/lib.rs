@@ -0,0 +1,148 @@
+//! Swift code generator.
+//!
+//! This module implements Swift code generation for SimpleBuffers schemas. It produces a single
+//! `.swift` file containing:
+//!
+//! - A small runtime with helpers for reading and writing little-endian values.
+//! - A Swift `enum` for every schema enum, backed by an unsigned integer of the enum's size.
+//! - A writer struct for every sequence, which serializes into a `[UInt8]`.
+//! - A reader struct for every sequence, which reads fields lazily from an
+//!   `UnsafeRawBufferPointer`.
+//!
+//! Oneofs are represented as Swift enums with associated values, with one case per oneof field.
+//! Readers select the case based on the tag stored in the buffer.
+//!
+//! All generated types are nested in a caseless enum named after the schema file (for example,
+//! `SimplebuffersMySchema`), which keeps multiple generated files from colliding within one module.
+
+mod swiftgen;
+
+use std::path::PathBuf;
+
+use simplebuffers_codegen::{register_generators, CodeGenerator, FileSink};
+use swiftgen::generate_swift;
+
+#[derive(Debug)]
+pub struct SwiftCodeGenerator;
+
+impl CodeGenerator for SwiftCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        if schema.uses_fixed_arrays() {
+            return Err("The Swift generator does not support fixed-size arrays".to_string());
+        }
+        if schema.uses_bytes() {
+            return Err("The Swift generator does not support byte buffers".to_string());
+        }
+        if schema.uses_maps() {
+            return Err("The Swift generator does not support maps".to_string());
+        }
+
+        let source = generate_swift(&params.file_name, schema);
+
+        let path = PathBuf::from(format!("{}/{}.swift", params.dest_dir, params.file_name));
+        sink.write_file(&path, &source)?;
+
+        Ok(vec![path])
+    }
+
+    fn reserved_identifiers(
+        &mut self,
+        _params: &simplebuffers_codegen::GeneratorParams,
+    ) -> Vec<String> {
+        vec![
+            // Names used by the generated code.
+            "Runtime",
+            "ListReader",
+            "buffer",
+            "position",
+            "staticSize",
+            "writeComponent",
+            "encode",
+            // Keywords used in declarations.
+            "associatedtype",
+            "class",
+            "deinit",
+            "enum",
+            "extension",
+            "fileprivate",
+            "func",
+            "import",
+            "init",
+            "inout",
+            "internal",
+            "let",
+            "open",
+            "operator",
+            "private",
+            "precedencegroup",
+            "protocol",
+            "public",
+            "rethrows",
+            "static",
+            "struct",
+            "subscript",
+            "typealias",
+            "var",
+            // Keywords used in statements.
+            "break",
+            "case",
+            "catch",
+            "continue",
+            "default",
+            "defer",
+            "do",
+            "else",
+            "fallthrough",
+            "for",
+            "guard",
+            "if",
+            "in",
+            "repeat",
+            "return",
+            "switch",
+            "throw",
+            "where",
+            "while",
+            // Keywords used in expressions and types.
+            "Any",
+            "as",
+            "await",
+            "false",
+            "is",
+            "nil",
+            "self",
+            "Self",
+            "super",
+            "throws",
+            "true",
+            "try",
+            "Type",
+            "Protocol",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["swift".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "Swift code".to_string()
+    }
+}
+
+register_generators!(swift: SwiftCodeGenerator);
@@ -0,0 +1,560 @@
+//! Generates a Swift source file.
+
+use convert_case::{Case, Casing};
+use indent::indent_by;
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+use std::collections::{HashMap, HashSet};
+
+/// Where a value is stored. This determines how sequences are laid out.
+#[derive(Clone, Copy)]
+enum Context {
+    /// The value is a field of a sequence. Sequences are stored behind a 16-bit offset.
+    Slot,
+
+    /// The value is a list element or the target of a oneof. Sequences are stored inline.
+    Element,
+}
+
+/// Information about the schema that is needed while generating code.
+struct SwiftGen<'a> {
+    /// The static size of every sequence, by name.
+    sequence_sizes: HashMap<&'a str, usize>,
+
+    /// The names of the enums that are signed (see [Enum::is_signed]).
+    signed_enums: HashSet<&'a str>,
+}
+
+//                                                                                                //
+// ======================================= Main Function ======================================== //
+//                                                                                                //
+
+/// Generates a Swift source file from a given schema.
+///
+/// # Arguments
+///
+/// * `file_name` - The name of the generated file, without an extension.
+/// * `schema` - The schema to generate from.
+///
+/// # Returns
+///
+/// The code for a Swift source file, as a String.
+pub(crate) fn generate_swift(file_name: &str, schema: &SBSchema) -> String {
+    let gen = SwiftGen {
+        sequence_sizes: schema
+            .sequences
+            .iter()
+            .map(|s| (s.name.as_str(), s.static_size()))
+            .collect(),
+        signed_enums: schema
+            .enums
+            .iter()
+            .filter(|e| e.is_signed())
+            .map(|e| e.name.as_str())
+            .collect(),
+    };
+
+    // All generated types live in a caseless enum that acts as a namespace.
+    let namespace = format!("simplebuffers_{}", file_name).to_case(Case::Pascal);
+
+    let runtime = include_str!("../corelib/runtime.swift").trim();
+    let enums = schema.enums.iter().map(define_enum).join("\n\n");
+    let writers = schema
+        .sequences
+        .iter()
+        .map(|s| gen.define_sequence_writer(s))
+        .join("\n\n");
+    let readers = schema
+        .sequences
+        .iter()
+        .map(|s| gen.define_sequence_reader(s))
+        .join("\n\n");
+
+    formatdoc! {
+        r#"
+        // This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+        public enum {namespace} {{}}
+
+        // MARK: - Runtime
+
+        extension {namespace} {{
+            {runtime}
+        }}
+
+        // MARK: - Enums
+
+        extension {namespace} {{
+            {enums}
+        }}
+
+        // MARK: - Writers
+
+        extension {namespace} {{
+            {writers}
+        }}
+
+        // MARK: - Readers
+
+        extension {namespace} {{
+            {readers}
+        }}
+        "#,
+        runtime = indent_by(4, runtime),
+        enums = indent_by(4, enums),
+        writers = indent_by(4, writers),
+        readers = indent_by(4, readers),
+    }
+}
+
+/// Returns the Swift type that matches a primitive.
+fn primitive_type(p: &Primitive) -> &'static str {
+    match p {
+        Primitive::Bool => "Bool",
+        Primitive::U8 => "UInt8",
+        Primitive::U16 => "UInt16",
+        Primitive::U32 => "UInt32",
+        Primitive::U64 => "UInt64",
+        Primitive::I8 => "Int8",
+        Primitive::I16 => "Int16",
+        Primitive::I32 => "Int32",
+        Primitive::I64 => "Int64",
+        Primitive::F32 => "Float",
+        Primitive::F64 => "Double",
+    }
+}
+
+/// Returns the Swift type that stores an enum of the given size and signedness.
+fn enum_raw_type(size: usize, signed: bool) -> &'static str {
+    match (size, signed) {
+        (1, false) => "UInt8",
+        (2, false) => "UInt16",
+        (4, false) => "UInt32",
+        (8, false) => "UInt64",
+        (1, true) => "Int8",
+        (2, true) => "Int16",
+        (4, true) => "Int32",
+        (8, true) => "Int64",
+        _ => panic!("Cannot convert size {} to Swift type", size),
+    }
+}
+
+/// Returns the name of the nested type that represents a oneof field.
+fn oneof_name(field_name: &str) -> String {
+    field_name.to_case(Case::Pascal)
+}
+
+/// Generates the Swift code for defining an enum.
+fn define_enum(data: &Enum) -> String {
+    let name = data.name.to_case(Case::Pascal);
+    let raw_type = enum_raw_type(data.size.into(), data.is_signed());
+    let variants = data
+        .variants
+        .iter()
+        .map(|v| format!("case {} = {}", v.name.to_case(Case::Camel), v.value))
+        .join("\n");
+
+    formatdoc! {
+        r"
+        public enum {name}: {raw_type} {{
+            {variants}
+        }}",
+        variants = indent_by(4, variants)
+    }
+}
+
+impl<'a> SwiftGen<'a> {
+    /// Returns the number of bytes a type takes up as a list element or oneof target.
+    fn element_size(&self, ty: &Type) -> usize {
+        match ty {
+            Type::Sequence(s) => self.sequence_sizes[s.as_str()],
+            _ => ty.size(),
+        }
+    }
+
+    //                                                                                            //
+    // =============================== Generate Writer Components =============================== //
+    //                                                                                            //
+
+    /// Returns the Swift type used to write a field.
+    fn writer_type(&self, ty: &Type, field_name: &str) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p).to_string(),
+            Type::Sequence(s) => format!("{}Writer", s).to_case(Case::Pascal),
+            Type::Enum(e, _) => e.to_case(Case::Pascal),
+            Type::Array(t) => format!("[{}]", self.writer_type(t, field_name)),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "String".to_string(),
+            Type::OneOf(_) => oneof_name(field_name),
+        }
+    }
+
+    /// Returns a statement that writes `value` at `pos` in the buffer `buf`.
+    fn write_stmt(&self, ty: &Type, ctx: Context, value: &str, pos: &str) -> String {
+        match ty {
+            Type::Primitive(_) => format!("Runtime.write(&buf, {pos}, {value})"),
+            Type::Enum(..) => format!("Runtime.write(&buf, {pos}, {value}.rawValue)"),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("Runtime.writeString(&buf, {pos}, {value})"),
+            Type::Array(t) => formatdoc! {
+                r"
+                Runtime.writeList(&buf, {pos}, {value}, stride: {stride}) {{ buf, p, e in
+                    {write}
+                }}",
+                stride = self.element_size(t),
+                write = indent_by(4, self.write_stmt(t, Context::Element, "e", "p")),
+            },
+            Type::Sequence(s) => match ctx {
+                Context::Slot => formatdoc! {
+                    r"
+                    do {{
+                        let p = Runtime.reserve(&buf, at: {pos}, from: {pos}, count: {size})
+                        {value}.writeComponent(&buf, at: p)
+                    }}",
+                    size = self.sequence_sizes[s.as_str()],
+                },
+                Context::Element => format!("{value}.writeComponent(&buf, at: {pos})"),
+            },
+            Type::OneOf(_) => format!("{value}.writeComponent(&buf, at: {pos})"),
+        }
+    }
+
+    /// Generates the Swift code for defining a sequence writer.
+    fn define_sequence_writer(&self, seq: &Sequence) -> String {
+        let class_name = format!("{}Writer", seq.name).to_case(Case::Pascal);
+        let static_size = self.sequence_sizes[seq.name.as_str()];
+
+        let members = seq
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "public var {}: {}",
+                    f.name.to_case(Case::Camel),
+                    self.writer_type(&f.ty, &f.name)
+                )
+            })
+            .join("\n");
+
+        let param_list = seq
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{}: {}",
+                    f.name.to_case(Case::Camel),
+                    self.writer_type(&f.ty, &f.name)
+                )
+            })
+            .join(", ");
+
+        let init_list = seq
+            .fields
+            .iter()
+            .map(|f| format!("self.{name} = {name}", name = f.name.to_case(Case::Camel)))
+            .join("\n");
+
+        let write_fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                self.write_stmt(
+                    &f.ty,
+                    Context::Slot,
+                    &f.name.to_case(Case::Camel),
+                    &format!("pos + {}", f.index),
+                )
+            })
+            .join("\n");
+
+        let oneofs = self.oneof_writers(&seq.fields);
+
+        let body = formatdoc! {
+            r"
+            {oneofs}
+
+            public static let staticSize = {static_size}
+
+            {members}
+
+            public init({param_list}) {{
+                {init_list}
+            }}
+
+            public func writeComponent(_ buf: inout [UInt8], at pos: Int) {{
+                {write_fields}
+            }}
+
+            public func encode() -> [UInt8] {{
+                var buf = [UInt8](repeating: 0, count: Self.staticSize)
+                writeComponent(&buf, at: 0)
+                return buf
+            }}",
+            init_list = indent_by(4, init_list),
+            write_fields = indent_by(4, write_fields),
+        };
+
+        formatdoc! {
+            r"
+            public struct {class_name} {{
+                {body}
+            }}",
+            body = indent_by(4, body.trim())
+        }
+    }
+
+    /// Generates writers for every oneof found in a list of fields, including oneofs nested in
+    /// lists.
+    fn oneof_writers(&self, fields: &[Field]) -> String {
+        fields
+            .iter()
+            .filter_map(|f| find_oneof(&f.ty).map(|o| self.define_oneof_writer(&f.name, o)))
+            .join("\n\n")
+    }
+
+    /// Generates the Swift code for defining a oneof writer. Oneofs are written as enums with
+    /// associated values, one case per field.
+    fn define_oneof_writer(&self, field_name: &str, subfields: &[Field]) -> String {
+        let name = oneof_name(field_name);
+        let oneofs = self.oneof_writers(subfields);
+
+        let cases = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "case {}({})",
+                    f.name.to_case(Case::Camel),
+                    self.writer_type(&f.ty, &f.name)
+                )
+            })
+            .join("\n");
+
+        let switch_cases = subfields
+            .iter()
+            .map(|f| {
+                formatdoc! {
+                    r"
+                    case .{case}(let value):
+                        buf[pos] = {index}
+                        let p = Runtime.reserve(&buf, at: pos + 1, from: pos, count: {size})
+                        {write}",
+                    case = f.name.to_case(Case::Camel),
+                    index = f.index,
+                    size = self.element_size(&f.ty),
+                    write = indent_by(4, self.write_stmt(&f.ty, Context::Element, "value", "p")),
+                }
+            })
+            .join("\n");
+
+        let body = formatdoc! {
+            r"
+            {oneofs}
+
+            {cases}
+
+            public func writeComponent(_ buf: inout [UInt8], at pos: Int) {{
+                switch self {{
+                {switch_cases}
+                }}
+            }}",
+            switch_cases = indent_by(4, switch_cases),
+        };
+
+        formatdoc! {
+            r"
+            public indirect enum {name} {{
+                {body}
+            }}",
+            body = indent_by(4, body.trim())
+        }
+    }
+
+    //                                                                                            //
+    // =============================== Generate Reader Components =============================== //
+    //                                                                                            //
+
+    /// Returns the Swift type returned when reading a field.
+    fn reader_type(&self, ty: &Type, field_name: &str) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p).to_string(),
+            Type::Sequence(s) => format!("{}Reader", s).to_case(Case::Pascal),
+            Type::Enum(e, _) => format!("{}?", e.to_case(Case::Pascal)),
+            Type::Array(t) => format!("ListReader<{}>", self.reader_type(t, field_name)),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "String".to_string(),
+            Type::OneOf(_) => format!("{}?", oneof_name(field_name)),
+        }
+    }
+
+    /// Returns an expression that reads a value of the given type at `pos` in `buffer`.
+    fn read_expr(&self, ty: &Type, field_name: &str, ctx: Context, pos: &str) -> String {
+        match ty {
+            Type::Primitive(p) => {
+                format!(
+                    "Runtime.read(buffer, {pos}, as: {}.self)",
+                    primitive_type(p)
+                )
+            }
+            Type::Enum(e, size) => format!(
+                "{}(rawValue: Runtime.read(buffer, {pos}, as: {}.self))",
+                e.to_case(Case::Pascal),
+                enum_raw_type(*size, self.signed_enums.contains(e.as_str()))
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("Runtime.readString(buffer, {pos})"),
+            Type::Array(t) => format!(
+                "ListReader(buffer, at: {pos}, stride: {stride}) {{ buffer, p in {read} }}",
+                stride = self.element_size(t),
+                read = self.read_expr(t, field_name, Context::Element, "p"),
+            ),
+            Type::Sequence(s) => {
+                let reader = format!("{}Reader", s).to_case(Case::Pascal);
+                match ctx {
+                    Context::Slot => format!("{reader}(buffer, at: Runtime.follow(buffer, {pos}))"),
+                    Context::Element => format!("{reader}(buffer, at: {pos})"),
+                }
+            }
+            Type::OneOf(_) => format!("{}(buffer, at: {pos})", oneof_name(field_name)),
+        }
+    }
+
+    /// Generates the Swift code for defining a sequence reader.
+    fn define_sequence_reader(&self, seq: &Sequence) -> String {
+        let class_name = format!("{}Reader", seq.name).to_case(Case::Pascal);
+        let static_size = self.sequence_sizes[seq.name.as_str()];
+
+        let fields = seq
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "public var {name}: {ty} {{ {read} }}",
+                    name = f.name.to_case(Case::Camel),
+                    ty = self.reader_type(&f.ty, &f.name),
+                    read = self.read_expr(
+                        &f.ty,
+                        &f.name,
+                        Context::Slot,
+                        &format!("position + {}", f.index)
+                    ),
+                )
+            })
+            .join("\n");
+
+        let oneofs = self.oneof_readers(&seq.fields);
+
+        let body = formatdoc! {
+            r"
+            {oneofs}
+
+            public static let staticSize = {static_size}
+
+            public let buffer: UnsafeRawBufferPointer
+            public let position: Int
+
+            public init(_ buffer: UnsafeRawBufferPointer, at position: Int = 0) {{
+                self.buffer = buffer
+                self.position = position
+            }}
+
+            {fields}"
+        };
+
+        formatdoc! {
+            r"
+            public struct {class_name} {{
+                {body}
+            }}",
+            body = indent_by(4, body.trim())
+        }
+    }
+
+    /// Generates readers for every oneof found in a list of fields, including oneofs nested in
+    /// lists.
+    fn oneof_readers(&self, fields: &[Field]) -> String {
+        fields
+            .iter()
+            .filter_map(|f| find_oneof(&f.ty).map(|o| self.define_oneof_reader(&f.name, o)))
+            .join("\n\n")
+    }
+
+    /// Generates the Swift code for defining a oneof reader. The active case is chosen by the tag
+    /// stored in the buffer. Unknown tags produce `nil`.
+    fn define_oneof_reader(&self, field_name: &str, subfields: &[Field]) -> String {
+        let name = oneof_name(field_name);
+        let oneofs = self.oneof_readers(subfields);
+
+        let cases = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "case {}({})",
+                    f.name.to_case(Case::Camel),
+                    self.reader_type(&f.ty, &f.name)
+                )
+            })
+            .join("\n");
+
+        let switch_cases = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "case {index}: self = .{case}({read})",
+                    index = f.index,
+                    case = f.name.to_case(Case::Camel),
+                    read = self.read_expr(&f.ty, &f.name, Context::Element, "p"),
+                )
+            })
+            .join("\n");
+
+        let body = formatdoc! {
+            r"
+            {oneofs}
+
+            {cases}
+
+            public init?(_ buffer: UnsafeRawBufferPointer, at pos: Int) {{
+                let p = pos + Int(Runtime.read(buffer, pos + 1, as: UInt16.self))
+                switch buffer[pos] {{
+                {switch_cases}
+                default: return nil
+                }}
+            }}",
+            switch_cases = indent_by(4, switch_cases),
+        };
+
+        formatdoc! {
+            r"
+            public enum {name} {{
+                {body}
+            }}",
+            body = indent_by(4, body.trim())
+        }
+    }
+}
+
+/// Returns the fields of the oneof contained in a type, looking through lists.
+fn find_oneof(ty: &Type) -> Option<&[Field]> {
+    match ty {
+        Type::OneOf(fields) => Some(fields),
+        Type::Array(t) => find_oneof(t),
+        _ => None,
+    }
+}
@@ -0,0 +1,11 @@
+//! Golden tests for the Swift generator. See `simplebuffers-golden` for how they work.
+
+use simplebuffers_golden::{golden_tests, Layout};
+use simplebuffers_swift::SwiftCodeGenerator;
+
+golden_tests! {
+    generator: SwiftCodeGenerator,
+    args: ["swift"],
+    layout: Layout::Flat,
+    cases: [primitives, enums, nested, oneofs, lists, oneof_arrays, signed_enums],
+}
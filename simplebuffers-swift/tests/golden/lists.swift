@@ -0,0 +1,245 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public enum SimplebuffersLists {}
+
+// MARK: - Runtime
+
+extension SimplebuffersLists {
+    /// Low-level helpers used by generated readers and writers.
+    ///
+    /// All multi-byte values are stored in little-endian byte order. Offsets to dynamic data are
+    /// 16-bit values relative to the position of the field that holds them.
+    public enum Runtime {
+        @inline(__always)
+        public static func read<T: FixedWidthInteger>(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: T.Type) -> T {
+            T(littleEndian: buffer.loadUnaligned(fromByteOffset: pos, as: T.self))
+        }
+
+        @inline(__always)
+        public static func read(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: Bool.Type) -> Bool {
+            buffer[pos] != 0
+        }
+
+        @inline(__always)
+        public static func read(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: Float.Type) -> Float {
+            Float(bitPattern: read(buffer, pos, as: UInt32.self))
+        }
+
+        @inline(__always)
+        public static func read(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: Double.Type) -> Double {
+            Double(bitPattern: read(buffer, pos, as: UInt64.self))
+        }
+
+        /// Follows the 16-bit offset stored at `pos` and returns the position it points to.
+        @inline(__always)
+        public static func follow(_ buffer: UnsafeRawBufferPointer, _ pos: Int) -> Int {
+            pos + Int(read(buffer, pos, as: UInt16.self))
+        }
+
+        /// Reads a null-terminated string whose offset is stored at `pos`.
+        public static func readString(_ buffer: UnsafeRawBufferPointer, _ pos: Int) -> String {
+            let start = follow(buffer, pos)
+            var end = start
+            while end < buffer.count && buffer[end] != 0 {
+                end += 1
+            }
+            return String(decoding: UnsafeRawBufferPointer(rebasing: buffer[start..<end]), as: UTF8.self)
+        }
+
+        @inline(__always)
+        public static func write<T: FixedWidthInteger>(_ buf: inout [UInt8], _ pos: Int, _ value: T) {
+            withUnsafeBytes(of: value.littleEndian) { bytes in
+                for (i, byte) in bytes.enumerated() {
+                    buf[pos + i] = byte
+                }
+            }
+        }
+
+        @inline(__always)
+        public static func write(_ buf: inout [UInt8], _ pos: Int, _ value: Bool) {
+            buf[pos] = value ? 1 : 0
+        }
+
+        @inline(__always)
+        public static func write(_ buf: inout [UInt8], _ pos: Int, _ value: Float) {
+            write(&buf, pos, value.bitPattern)
+        }
+
+        @inline(__always)
+        public static func write(_ buf: inout [UInt8], _ pos: Int, _ value: Double) {
+            write(&buf, pos, value.bitPattern)
+        }
+
+        /// Reserves `count` bytes of dynamic data at the end of the buffer and stores the offset to them
+        /// (relative to `base`) at `slot`. Returns the position of the reserved bytes.
+        public static func reserve(_ buf: inout [UInt8], at slot: Int, from base: Int, count: Int) -> Int {
+            let start = buf.count
+            write(&buf, slot, UInt16(start - base))
+            buf.append(contentsOf: repeatElement(0, count: count))
+            return start
+        }
+
+        /// Writes a null-terminated string to the end of the buffer and stores its offset at `pos`.
+        public static func writeString(_ buf: inout [UInt8], _ pos: Int, _ value: String) {
+            write(&buf, pos, UInt16(buf.count - pos))
+            buf.append(contentsOf: value.utf8)
+            buf.append(0)
+        }
+
+        /// Writes a list header at `pos` and the list elements to the end of the buffer.
+        public static func writeList<Element>(
+            _ buf: inout [UInt8], _ pos: Int, _ values: [Element], stride: Int,
+            _ writeElement: (inout [UInt8], Int, Element) -> Void
+        ) {
+            write(&buf, pos, UInt16(values.count))
+            let start = reserve(&buf, at: pos + 2, from: pos, count: values.count * stride)
+            for (i, value) in values.enumerated() {
+                writeElement(&buf, start + i * stride, value)
+            }
+        }
+    }
+
+    /// A random-access view over a list stored in a buffer.
+    public struct ListReader<Element>: RandomAccessCollection {
+        public let buffer: UnsafeRawBufferPointer
+        public let count: Int
+        let start: Int
+        let stride: Int
+        let readElement: (UnsafeRawBufferPointer, Int) -> Element
+
+        public init(
+            _ buffer: UnsafeRawBufferPointer, at pos: Int, stride: Int,
+            _ readElement: @escaping (UnsafeRawBufferPointer, Int) -> Element
+        ) {
+            self.buffer = buffer
+            self.count = Int(Runtime.read(buffer, pos, as: UInt16.self))
+            self.start = pos + Int(Runtime.read(buffer, pos + 2, as: UInt16.self))
+            self.stride = stride
+            self.readElement = readElement
+        }
+
+        public var startIndex: Int { 0 }
+        public var endIndex: Int { count }
+
+        public subscript(position: Int) -> Element {
+            readElement(buffer, start + position * stride)
+        }
+    }
+}
+
+// MARK: - Enums
+
+extension SimplebuffersLists {
+    public enum Color: UInt8 {
+        case red = 0
+        case green = 1
+    }
+}
+
+// MARK: - Writers
+
+extension SimplebuffersLists {
+    public struct ListsWriter {
+        public static let staticSize = 20
+
+        public var numbers: [UInt16]
+        public var words: [String]
+        public var colors: [Color]
+        public var points: [PointWriter]
+        public var matrix: [[Int32]]
+
+        public init(numbers: [UInt16], words: [String], colors: [Color], points: [PointWriter], matrix: [[Int32]]) {
+            self.numbers = numbers
+            self.words = words
+            self.colors = colors
+            self.points = points
+            self.matrix = matrix
+        }
+
+        public func writeComponent(_ buf: inout [UInt8], at pos: Int) {
+            Runtime.writeList(&buf, pos + 0, numbers, stride: 2) { buf, p, e in
+                Runtime.write(&buf, p, e)
+            }
+            Runtime.writeList(&buf, pos + 4, words, stride: 2) { buf, p, e in
+                Runtime.writeString(&buf, p, e)
+            }
+            Runtime.writeList(&buf, pos + 8, colors, stride: 1) { buf, p, e in
+                Runtime.write(&buf, p, e.rawValue)
+            }
+            Runtime.writeList(&buf, pos + 12, points, stride: 4) { buf, p, e in
+                e.writeComponent(&buf, at: p)
+            }
+            Runtime.writeList(&buf, pos + 16, matrix, stride: 4) { buf, p, e in
+                Runtime.writeList(&buf, p, e, stride: 4) { buf, p, e in
+                    Runtime.write(&buf, p, e)
+                }
+            }
+        }
+
+        public func encode() -> [UInt8] {
+            var buf = [UInt8](repeating: 0, count: Self.staticSize)
+            writeComponent(&buf, at: 0)
+            return buf
+        }
+    }
+
+    public struct PointWriter {
+        public static let staticSize = 4
+
+        public var x: Int16
+        public var y: Int16
+
+        public init(x: Int16, y: Int16) {
+            self.x = x
+            self.y = y
+        }
+
+        public func writeComponent(_ buf: inout [UInt8], at pos: Int) {
+            Runtime.write(&buf, pos + 0, x)
+            Runtime.write(&buf, pos + 2, y)
+        }
+
+        public func encode() -> [UInt8] {
+            var buf = [UInt8](repeating: 0, count: Self.staticSize)
+            writeComponent(&buf, at: 0)
+            return buf
+        }
+    }
+}
+
+// MARK: - Readers
+
+extension SimplebuffersLists {
+    public struct ListsReader {
+        public static let staticSize = 20
+
+        public let buffer: UnsafeRawBufferPointer
+        public let position: Int
+
+        public init(_ buffer: UnsafeRawBufferPointer, at position: Int = 0) {
+            self.buffer = buffer
+            self.position = position
+        }
+
+        public var numbers: ListReader<UInt16> { ListReader(buffer, at: position + 0, stride: 2) { buffer, p in Runtime.read(buffer, p, as: UInt16.self) } }
+        public var words: ListReader<String> { ListReader(buffer, at: position + 4, stride: 2) { buffer, p in Runtime.readString(buffer, p) } }
+        public var colors: ListReader<Color?> { ListReader(buffer, at: position + 8, stride: 1) { buffer, p in Color(rawValue: Runtime.read(buffer, p, as: UInt8.self)) } }
+        public var points: ListReader<PointReader> { ListReader(buffer, at: position + 12, stride: 4) { buffer, p in PointReader(buffer, at: p) } }
+        public var matrix: ListReader<ListReader<Int32>> { ListReader(buffer, at: position + 16, stride: 4) { buffer, p in ListReader(buffer, at: p, stride: 4) { buffer, p in Runtime.read(buffer, p, as: Int32.self) } } }
+    }
+
+    public struct PointReader {
+        public static let staticSize = 4
+
+        public let buffer: UnsafeRawBufferPointer
+        public let position: Int
+
+        public init(_ buffer: UnsafeRawBufferPointer, at position: Int = 0) {
+            self.buffer = buffer
+            self.position = position
+        }
+
+        public var x: Int16 { Runtime.read(buffer, position + 0, as: Int16.self) }
+        public var y: Int16 { Runtime.read(buffer, position + 2, as: Int16.self) }
+    }
+}
@@ -0,0 +1,280 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public enum SimplebuffersOneofArrays {}
+
+// MARK: - Runtime
+
+extension SimplebuffersOneofArrays {
+    /// Low-level helpers used by generated readers and writers.
+    ///
+    /// All multi-byte values are stored in little-endian byte order. Offsets to dynamic data are
+    /// 16-bit values relative to the position of the field that holds them.
+    public enum Runtime {
+        @inline(__always)
+        public static func read<T: FixedWidthInteger>(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: T.Type) -> T {
+            T(littleEndian: buffer.loadUnaligned(fromByteOffset: pos, as: T.self))
+        }
+
+        @inline(__always)
+        public static func read(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: Bool.Type) -> Bool {
+            buffer[pos] != 0
+        }
+
+        @inline(__always)
+        public static func read(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: Float.Type) -> Float {
+            Float(bitPattern: read(buffer, pos, as: UInt32.self))
+        }
+
+        @inline(__always)
+        public static func read(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: Double.Type) -> Double {
+            Double(bitPattern: read(buffer, pos, as: UInt64.self))
+        }
+
+        /// Follows the 16-bit offset stored at `pos` and returns the position it points to.
+        @inline(__always)
+        public static func follow(_ buffer: UnsafeRawBufferPointer, _ pos: Int) -> Int {
+            pos + Int(read(buffer, pos, as: UInt16.self))
+        }
+
+        /// Reads a null-terminated string whose offset is stored at `pos`.
+        public static func readString(_ buffer: UnsafeRawBufferPointer, _ pos: Int) -> String {
+            let start = follow(buffer, pos)
+            var end = start
+            while end < buffer.count && buffer[end] != 0 {
+                end += 1
+            }
+            return String(decoding: UnsafeRawBufferPointer(rebasing: buffer[start..<end]), as: UTF8.self)
+        }
+
+        @inline(__always)
+        public static func write<T: FixedWidthInteger>(_ buf: inout [UInt8], _ pos: Int, _ value: T) {
+            withUnsafeBytes(of: value.littleEndian) { bytes in
+                for (i, byte) in bytes.enumerated() {
+                    buf[pos + i] = byte
+                }
+            }
+        }
+
+        @inline(__always)
+        public static func write(_ buf: inout [UInt8], _ pos: Int, _ value: Bool) {
+            buf[pos] = value ? 1 : 0
+        }
+
+        @inline(__always)
+        public static func write(_ buf: inout [UInt8], _ pos: Int, _ value: Float) {
+            write(&buf, pos, value.bitPattern)
+        }
+
+        @inline(__always)
+        public static func write(_ buf: inout [UInt8], _ pos: Int, _ value: Double) {
+            write(&buf, pos, value.bitPattern)
+        }
+
+        /// Reserves `count` bytes of dynamic data at the end of the buffer and stores the offset to them
+        /// (relative to `base`) at `slot`. Returns the position of the reserved bytes.
+        public static func reserve(_ buf: inout [UInt8], at slot: Int, from base: Int, count: Int) -> Int {
+            let start = buf.count
+            write(&buf, slot, UInt16(start - base))
+            buf.append(contentsOf: repeatElement(0, count: count))
+            return start
+        }
+
+        /// Writes a null-terminated string to the end of the buffer and stores its offset at `pos`.
+        public static func writeString(_ buf: inout [UInt8], _ pos: Int, _ value: String) {
+            write(&buf, pos, UInt16(buf.count - pos))
+            buf.append(contentsOf: value.utf8)
+            buf.append(0)
+        }
+
+        /// Writes a list header at `pos` and the list elements to the end of the buffer.
+        public static func writeList<Element>(
+            _ buf: inout [UInt8], _ pos: Int, _ values: [Element], stride: Int,
+            _ writeElement: (inout [UInt8], Int, Element) -> Void
+        ) {
+            write(&buf, pos, UInt16(values.count))
+            let start = reserve(&buf, at: pos + 2, from: pos, count: values.count * stride)
+            for (i, value) in values.enumerated() {
+                writeElement(&buf, start + i * stride, value)
+            }
+        }
+    }
+
+    /// A random-access view over a list stored in a buffer.
+    public struct ListReader<Element>: RandomAccessCollection {
+        public let buffer: UnsafeRawBufferPointer
+        public let count: Int
+        let start: Int
+        let stride: Int
+        let readElement: (UnsafeRawBufferPointer, Int) -> Element
+
+        public init(
+            _ buffer: UnsafeRawBufferPointer, at pos: Int, stride: Int,
+            _ readElement: @escaping (UnsafeRawBufferPointer, Int) -> Element
+        ) {
+            self.buffer = buffer
+            self.count = Int(Runtime.read(buffer, pos, as: UInt16.self))
+            self.start = pos + Int(Runtime.read(buffer, pos + 2, as: UInt16.self))
+            self.stride = stride
+            self.readElement = readElement
+        }
+
+        public var startIndex: Int { 0 }
+        public var endIndex: Int { count }
+
+        public subscript(position: Int) -> Element {
+            readElement(buffer, start + position * stride)
+        }
+    }
+}
+
+// MARK: - Enums
+
+extension SimplebuffersOneofArrays {
+    
+}
+
+// MARK: - Writers
+
+extension SimplebuffersOneofArrays {
+    public struct BatchWriter {
+        public indirect enum Events {
+            case id(UInt8)
+            case name(String)
+
+            public func writeComponent(_ buf: inout [UInt8], at pos: Int) {
+                switch self {
+                case .id(let value):
+                    buf[pos] = 0
+                    let p = Runtime.reserve(&buf, at: pos + 1, from: pos, count: 1)
+                    Runtime.write(&buf, p, value)
+                case .name(let value):
+                    buf[pos] = 1
+                    let p = Runtime.reserve(&buf, at: pos + 1, from: pos, count: 2)
+                    Runtime.writeString(&buf, p, value)
+                }
+            }
+        }
+
+        public indirect enum Nested {
+            public indirect enum Events {
+                case small(UInt8)
+                case large(UInt64)
+
+                public func writeComponent(_ buf: inout [UInt8], at pos: Int) {
+                    switch self {
+                    case .small(let value):
+                        buf[pos] = 0
+                        let p = Runtime.reserve(&buf, at: pos + 1, from: pos, count: 1)
+                        Runtime.write(&buf, p, value)
+                    case .large(let value):
+                        buf[pos] = 1
+                        let p = Runtime.reserve(&buf, at: pos + 1, from: pos, count: 8)
+                        Runtime.write(&buf, p, value)
+                    }
+                }
+            }
+
+            case empty(UInt8)
+            case events([Events])
+
+            public func writeComponent(_ buf: inout [UInt8], at pos: Int) {
+                switch self {
+                case .empty(let value):
+                    buf[pos] = 0
+                    let p = Runtime.reserve(&buf, at: pos + 1, from: pos, count: 1)
+                    Runtime.write(&buf, p, value)
+                case .events(let value):
+                    buf[pos] = 1
+                    let p = Runtime.reserve(&buf, at: pos + 1, from: pos, count: 4)
+                    Runtime.writeList(&buf, p, value, stride: 3) { buf, p, e in
+                        e.writeComponent(&buf, at: p)
+                    }
+                }
+            }
+        }
+
+        public static let staticSize = 7
+
+        public var events: [Events]
+        public var nested: Nested
+
+        public init(events: [Events], nested: Nested) {
+            self.events = events
+            self.nested = nested
+        }
+
+        public func writeComponent(_ buf: inout [UInt8], at pos: Int) {
+            Runtime.writeList(&buf, pos + 0, events, stride: 3) { buf, p, e in
+                e.writeComponent(&buf, at: p)
+            }
+            nested.writeComponent(&buf, at: pos + 4)
+        }
+
+        public func encode() -> [UInt8] {
+            var buf = [UInt8](repeating: 0, count: Self.staticSize)
+            writeComponent(&buf, at: 0)
+            return buf
+        }
+    }
+}
+
+// MARK: - Readers
+
+extension SimplebuffersOneofArrays {
+    public struct BatchReader {
+        public enum Events {
+            case id(UInt8)
+            case name(String)
+
+            public init?(_ buffer: UnsafeRawBufferPointer, at pos: Int) {
+                let p = pos + Int(Runtime.read(buffer, pos + 1, as: UInt16.self))
+                switch buffer[pos] {
+                case 0: self = .id(Runtime.read(buffer, p, as: UInt8.self))
+                case 1: self = .name(Runtime.readString(buffer, p))
+                default: return nil
+                }
+            }
+        }
+
+        public enum Nested {
+            public enum Events {
+                case small(UInt8)
+                case large(UInt64)
+
+                public init?(_ buffer: UnsafeRawBufferPointer, at pos: Int) {
+                    let p = pos + Int(Runtime.read(buffer, pos + 1, as: UInt16.self))
+                    switch buffer[pos] {
+                    case 0: self = .small(Runtime.read(buffer, p, as: UInt8.self))
+                    case 1: self = .large(Runtime.read(buffer, p, as: UInt64.self))
+                    default: return nil
+                    }
+                }
+            }
+
+            case empty(UInt8)
+            case events(ListReader<Events?>)
+
+            public init?(_ buffer: UnsafeRawBufferPointer, at pos: Int) {
+                let p = pos + Int(Runtime.read(buffer, pos + 1, as: UInt16.self))
+                switch buffer[pos] {
+                case 0: self = .empty(Runtime.read(buffer, p, as: UInt8.self))
+                case 1: self = .events(ListReader(buffer, at: p, stride: 3) { buffer, p in Events(buffer, at: p) })
+                default: return nil
+                }
+            }
+        }
+
+        public static let staticSize = 7
+
+        public let buffer: UnsafeRawBufferPointer
+        public let position: Int
+
+        public init(_ buffer: UnsafeRawBufferPointer, at position: Int = 0) {
+            self.buffer = buffer
+            self.position = position
+        }
+
+        public var events: ListReader<Events?> { ListReader(buffer, at: position + 0, stride: 3) { buffer, p in Events(buffer, at: p) } }
+        public var nested: Nested? { Nested(buffer, at: position + 4) }
+    }
+}
@@ -0,0 +1,257 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+public enum SimplebuffersOneofs {}
+
+// MARK: - Runtime
+
+extension SimplebuffersOneofs {
+    /// Low-level helpers used by generated readers and writers.
+    ///
+    /// All multi-byte values are stored in little-endian byte order. Offsets to dynamic data are
+    /// 16-bit values relative to the position of the field that holds them.
+    public enum Runtime {
+        @inline(__always)
+        public static func read<T: FixedWidthInteger>(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: T.Type) -> T {
+            T(littleEndian: buffer.loadUnaligned(fromByteOffset: pos, as: T.self))
+        }
+
+        @inline(__always)
+        public static func read(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: Bool.Type) -> Bool {
+            buffer[pos] != 0
+        }
+
+        @inline(__always)
+        public static func read(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: Float.Type) -> Float {
+            Float(bitPattern: read(buffer, pos, as: UInt32.self))
+        }
+
+        @inline(__always)
+        public static func read(_ buffer: UnsafeRawBufferPointer, _ pos: Int, as: Double.Type) -> Double {
+            Double(bitPattern: read(buffer, pos, as: UInt64.self))
+        }
+
+        /// Follows the 16-bit offset stored at `pos` and returns the position it points to.
+        @inline(__always)
+        public static func follow(_ buffer: UnsafeRawBufferPointer, _ pos: Int) -> Int {
+            pos + Int(read(buffer, pos, as: UInt16.self))
+        }
+
+        /// Reads a null-terminated string whose offset is stored at `pos`.
+        public static func readString(_ buffer: UnsafeRawBufferPointer, _ pos: Int) -> String {
+            let start = follow(buffer, pos)
+            var end = start
+            while end < buffer.count && buffer[end] != 0 {
+                end += 1
+            }
+            return String(decoding: UnsafeRawBufferPointer(rebasing: buffer[start..<end]), as: UTF8.self)
+        }
+
+        @inline(__always)
+        public static func write<T: FixedWidthInteger>(_ buf: inout [UInt8], _ pos: Int, _ value: T) {
+            withUnsafeBytes(of: value.littleEndian) { bytes in
+                for (i, byte) in bytes.enumerated() {
+                    buf[pos + i] = byte
+                }
+            }
+        }
+
+        @inline(__always)
+        public static func write(_ buf: inout [UInt8], _ pos: Int, _ value: Bool) {
+            buf[pos] = value ? 1 : 0
+        }
+
+        @inline(__always)
+        public static func write(_ buf: inout [UInt8], _ pos: Int, _ value: Float) {
+            write(&buf, pos, value.bitPattern)
+        }
+
+        @inline(__always)
+        public static func write(_ buf: inout [UInt8], _ pos: Int, _ value: Double) {
+            write(&buf, pos, value.bitPattern)
+        }
+
+        /// Reserves `count` bytes of dynamic data at the end of the buffer and stores the offset to them
+        /// (relative to `base`) at `slot`. Returns the position of the reserved bytes.
+        public static func reserve(_ buf: inout [UInt8], at slot: Int, from base: Int, count: Int) -> Int {
+            let start = buf.count
+            write(&buf, slot, UInt16(start - base))
+            buf.append(contentsOf: repeatElement(0, count: count))
+            return start
+        }
+
+        /// Writes a null-terminated string to the end of the buffer and stores its offset at `pos`.
+        public static func writeString(_ buf: inout [UInt8], _ pos: Int, _ value: String) {
+            write(&buf, pos, UInt16(buf.count - pos))
+            buf.append(contentsOf: value.utf8)
+            buf.append(0)
+        }
+
+        /// Writes a list header at `pos` and the list elements to the end of the buffer.
+        public static func writeList<Element>(
+            _ buf: inout [UInt8], _ pos: Int, _ values: [Element], stride: Int,
+            _ writeElement: (inout [UInt8], Int, Element) -> Void
+        ) {
+            write(&buf, pos, UInt16(values.count))
+            let start = reserve(&buf, at: pos + 2, from: pos, count: values.count * stride)
+            for (i, value) in values.enumerated() {
+                writeElement(&buf, start + i * stride, value)
+            }
+        }
+    }
+
+    /// A random-access view over a list stored in a buffer.
+    public struct ListReader<Element>: RandomAccessCollection {
+        public let buffer: UnsafeRawBufferPointer
+        public let count: Int
+        let start: Int
+        let stride: Int
+        let readElement: (UnsafeRawBufferPointer, Int) -> Element
+
+        public init(
+            _ buffer: UnsafeRawBufferPointer, at pos: Int, stride: Int,
+            _ readElement: @escaping (UnsafeRawBufferPointer, Int) -> Element
+        ) {
+            self.buffer = buffer
+            self.count = Int(Runtime.read(buffer, pos, as: UInt16.self))
+            self.start = pos + Int(Runtime.read(buffer, pos + 2, as: UInt16.self))
+            self.stride = stride
+            self.readElement = readElement
+        }
+
+        public var startIndex: Int { 0 }
+        public var endIndex: Int { count }
+
+        public subscript(position: Int) -> Element {
+            readElement(buffer, start + position * stride)
+        }
+    }
+}
+
+// MARK: - Enums
+
+extension SimplebuffersOneofs {
+    
+}
+
+// MARK: - Writers
+
+extension SimplebuffersOneofs {
+    public struct RequestWriter {
+        public indirect enum Body {
+            case ping(UInt8)
+            case echo(String)
+            case target(TargetWriter)
+
+            public func writeComponent(_ buf: inout [UInt8], at pos: Int) {
+                switch self {
+                case .ping(let value):
+                    buf[pos] = 0
+                    let p = Runtime.reserve(&buf, at: pos + 1, from: pos, count: 1)
+                    Runtime.write(&buf, p, value)
+                case .echo(let value):
+                    buf[pos] = 1
+                    let p = Runtime.reserve(&buf, at: pos + 1, from: pos, count: 2)
+                    Runtime.writeString(&buf, p, value)
+                case .target(let value):
+                    buf[pos] = 2
+                    let p = Runtime.reserve(&buf, at: pos + 1, from: pos, count: 8)
+                    value.writeComponent(&buf, at: p)
+                }
+            }
+        }
+
+        public static let staticSize = 5
+
+        public var id: UInt16
+        public var body: Body
+
+        public init(id: UInt16, body: Body) {
+            self.id = id
+            self.body = body
+        }
+
+        public func writeComponent(_ buf: inout [UInt8], at pos: Int) {
+            Runtime.write(&buf, pos + 0, id)
+            body.writeComponent(&buf, at: pos + 2)
+        }
+
+        public func encode() -> [UInt8] {
+            var buf = [UInt8](repeating: 0, count: Self.staticSize)
+            writeComponent(&buf, at: 0)
+            return buf
+        }
+    }
+
+    public struct TargetWriter {
+        public static let staticSize = 8
+
+        public var x: Float
+        public var y: Float
+
+        public init(x: Float, y: Float) {
+            self.x = x
+            self.y = y
+        }
+
+        public func writeComponent(_ buf: inout [UInt8], at pos: Int) {
+            Runtime.write(&buf, pos + 0, x)
+            Runtime.write(&buf, pos + 4, y)
+        }
+
+        public func encode() -> [UInt8] {
+            var buf = [UInt8](repeating: 0, count: Self.staticSize)
+            writeComponent(&buf, at: 0)
+            return buf
+        }
+    }
+}
+
+// MARK: - Readers
+
+extension SimplebuffersOneofs {
+    public struct RequestReader {
+        public enum Body {
+            case ping(UInt8)
+            case echo(String)
+            case target(TargetReader)
+
+            public init?(_ buffer: UnsafeRawBufferPointer, at pos: Int) {
+                let p = pos + Int(Runtime.read(buffer, pos + 1, as: UInt16.self))
+                switch buffer[pos] {
+                case 0: self = .ping(Runtime.read(buffer, p, as: UInt8.self))
+                case 1: self = .echo(Runtime.readString(buffer, p))
+                case 2: self = .target(TargetReader(buffer, at: p))
+                default: return nil
+                }
+            }
+        }
+
+        public static let staticSize = 5
+
+        public let buffer: UnsafeRawBufferPointer
+        public let position: Int
+
+        public init(_ buffer: UnsafeRawBufferPointer, at position: Int = 0) {
+            self.buffer = buffer
+            self.position = position
+        }
+
+        public var id: UInt16 { Runtime.read(buffer, position + 0, as: UInt16.self) }
+        public var body: Body? { Body(buffer, at: position + 2) }
+    }
+
+    public struct TargetReader {
+        public static let staticSize = 8
+
+        public let buffer: UnsafeRawBufferPointer
+        public let position: Int
+
+        public init(_ buffer: UnsafeRawBufferPointer, at position: Int = 0) {
+            self.buffer = buffer
+            self.position = position
+        }
+
+        public var x: Float { Runtime.read(buffer, position + 0, as: Float.self) }
+        public var y: Float { Runtime.read(buffer, position + 4, as: Float.self) }
+    }
+}
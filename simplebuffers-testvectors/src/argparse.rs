@@ -0,0 +1,42 @@
+//! Parses generator-specific arguments contained in [GeneratorParams::additional_args].
+
+use clap::{Command, CommandFactory, Parser};
+use simplebuffers_codegen::GeneratorParams;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Struct to parse CLI args into.
+#[derive(Parser, Debug)]
+#[command(name = "SimpleBuffers Test Vector Generator")]
+#[command(version = VERSION)]
+#[command(about = "Encode example messages into binary test vectors.")]
+struct Cli {
+    /// A JSON file with the messages to encode.
+    #[arg(long)]
+    input: String,
+}
+
+/// A struct that holds generator-specific arguments for the test vector generator.
+#[derive(Debug)]
+pub(crate) struct TestVectorsGeneratorParams {
+    /// The path of the JSON file with the messages to encode.
+    pub input: String,
+
+    /// The global compiler parameters.
+    pub global: GeneratorParams,
+}
+
+/// Returns the command that describes the generator-specific arguments, so the compiler can
+/// show them in its help output and validate them before generating.
+pub(crate) fn command() -> Command {
+    Cli::command()
+}
+
+/// Parse generator-specific arguments from the compiler-provided argument list.
+pub(crate) fn parse_args(generator_params: &GeneratorParams) -> TestVectorsGeneratorParams {
+    let cli = Cli::parse_from(&generator_params.additional_args);
+    TestVectorsGeneratorParams {
+        input: cli.input,
+        global: generator_params.clone(),
+    }
+}
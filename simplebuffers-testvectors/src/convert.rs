@@ -0,0 +1,38 @@
+//! Converts JSON values into codec values.
+
+use serde_json::Value as Json;
+use simplebuffers_core::codec::Value;
+
+/// Converts a JSON value into a value that can be encoded. Numbers become [Value::UInt] or
+/// [Value::Int] when they are integers, and [Value::Float] otherwise.
+///
+/// Returns an error if the value is `null`, which has no SimpleBuffers equivalent.
+pub(crate) fn json_to_value(json: &Json, path: &str) -> Result<Value, String> {
+    Ok(match json {
+        Json::Null => return Err(format!("{}: null is not a valid value", path)),
+        Json::Bool(b) => Value::Bool(*b),
+        Json::Number(n) => {
+            if let Some(u) = n.as_u64() {
+                Value::UInt(u)
+            } else if let Some(i) = n.as_i64() {
+                Value::Int(i)
+            } else {
+                Value::Float(n.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        Json::String(s) => Value::String(s.clone()),
+        Json::Array(values) => Value::List(
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| json_to_value(v, &format!("{}[{}]", path, i)))
+                .collect::<Result<_, _>>()?,
+        ),
+        Json::Object(entries) => Value::Object(
+            entries
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), json_to_value(v, &format!("{}.{}", path, k))?)))
+                .collect::<Result<_, String>>()?,
+        ),
+    })
+}
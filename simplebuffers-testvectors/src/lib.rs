@@ -0,0 +1,115 @@
+//! Test vector generator.
+//!
+//! This module encodes example messages into binary files, which can be used to test
+//! implementations of SimpleBuffers in other languages. Messages are read from a JSON file given
+//! with `--input`:
+//!
+//! ```json
+//! [
+//!     { "name": "basic", "sequence": "Request", "value": { "id": 7, "body": "hello" } }
+//! ]
+//! ```
+//!
+//! Each message is encoded as the given sequence and written to `{dest_dir}/{name}.bin`. The
+//! encoding is done by [simplebuffers_core::codec], so it matches the output of the C++ writer
+//! byte-for-byte.
+
+mod argparse;
+mod convert;
+
+use std::{fs, path::PathBuf};
+
+use argparse::{command, parse_args};
+use convert::json_to_value;
+use serde_json::Value;
+use simplebuffers_codegen::{register_generator, CodeGenerator, FileSink};
+use simplebuffers_core::codec::encode;
+
+#[derive(Debug)]
+pub struct TestVectorsCodeGenerator;
+
+/// A message to encode, as read from the input file.
+struct Message<'a> {
+    name: &'a str,
+    sequence: &'a str,
+    value: &'a Value,
+}
+
+/// Reads a message from an entry of the input file.
+fn parse_message(entry: &Value, index: usize) -> Result<Message<'_>, String> {
+    let get_str = |key: &str| {
+        entry
+            .get(key)
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("Message {} is missing a `{}` string", index, key))
+    };
+
+    let name = get_str("name")?;
+    if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+        return Err(format!("Message {} has an invalid name `{}`", index, name));
+    }
+
+    Ok(Message {
+        name,
+        sequence: get_str("sequence")?,
+        value: entry
+            .get("value")
+            .ok_or_else(|| format!("Message {} is missing a `value`", index))?,
+    })
+}
+
+impl CodeGenerator for TestVectorsCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        let params = parse_args(params);
+
+        let input = fs::read_to_string(&params.input)
+            .map_err(|e| format!("Failed to read {}: {}", params.input, e))?;
+        let input: Value = serde_json::from_str(&input)
+            .map_err(|e| format!("Failed to parse {}: {}", params.input, e))?;
+        let entries = input
+            .as_array()
+            .ok_or_else(|| format!("{} must contain an array of messages", params.input))?;
+
+        let dir = PathBuf::from(&params.global.dest_dir);
+
+        let mut paths = vec![];
+        for (i, entry) in entries.iter().enumerate() {
+            let message = parse_message(entry, i)?;
+            let bytes = json_to_value(message.value, message.name)
+                .and_then(|value| encode(schema, message.sequence, &value))
+                .map_err(|e| format!("Failed to encode `{}`: {}", message.name, e))?;
+
+            let path = dir.join(format!("{}.bin", message.name));
+            sink.write_bytes(&path, &bytes)?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["bin".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "Encoded test messages from a JSON file".to_string()
+    }
+
+    fn cli(&self) -> Option<clap::Command> {
+        Some(command())
+    }
+}
+
+register_generator!(testvectors: TestVectorsCodeGenerator);
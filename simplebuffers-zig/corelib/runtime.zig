@@ -0,0 +1,120 @@
+pub const Error = error{
+    /// The buffer is too small to hold the serialized data.
+    NoSpaceLeft,
+    /// An offset to dynamic data does not fit in 16 bits.
+    OffsetOverflow,
+};
+
+pub fn readInt(comptime T: type, buf: []const u8, pos: usize) T {
+    return std.mem.readInt(T, buf[pos..][0..@sizeOf(T)], .little);
+}
+
+pub fn readBool(buf: []const u8, pos: usize) bool {
+    return buf[pos] != 0;
+}
+
+pub fn readF32(buf: []const u8, pos: usize) f32 {
+    return @bitCast(readInt(u32, buf, pos));
+}
+
+pub fn readF64(buf: []const u8, pos: usize) f64 {
+    return @bitCast(readInt(u64, buf, pos));
+}
+
+/// Follows the 16-bit offset stored at `pos` and returns the position it points to.
+pub fn follow(buf: []const u8, pos: usize) usize {
+    return pos + readInt(u16, buf, pos);
+}
+
+/// Reads a null-terminated string whose offset is stored at `pos`. The terminator is not included.
+pub fn readString(buf: []const u8, pos: usize) []const u8 {
+    const start = follow(buf, pos);
+    const end = std.mem.indexOfScalarPos(u8, buf, start, 0) orelse buf.len;
+    return buf[start..end];
+}
+
+/// Returns a type that reads a list stored in a buffer. Elements are `stride` bytes apart and are
+/// read with `readElement`.
+pub fn ListReader(
+    comptime T: type,
+    comptime stride: usize,
+    comptime readElement: fn ([]const u8, usize) T,
+) type {
+    return struct {
+        buf: []const u8,
+        start: usize,
+        len: usize,
+
+        pub fn init(buf: []const u8, pos: usize) @This() {
+            return .{
+                .buf = buf,
+                .start = pos + readInt(u16, buf, pos + 2),
+                .len = readInt(u16, buf, pos),
+            };
+        }
+
+        pub fn get(self: @This(), index: usize) T {
+            std.debug.assert(index < self.len);
+            return readElement(self.buf, self.start + index * stride);
+        }
+    };
+}
+
+/// Serializes data into a caller-provided buffer. Static data is written at fixed positions and
+/// dynamic data is appended after `len`.
+pub const Builder = struct {
+    buf: []u8,
+    len: usize,
+
+    /// Reserves `count` zeroed bytes after the data written so far and stores the offset to them
+    /// (relative to `base`) at `slot`. Returns the position of the reserved bytes.
+    pub fn reserve(self: *Builder, slot: usize, base: usize, count: usize) Error!usize {
+        if (self.buf.len - self.len < count) return error.NoSpaceLeft;
+        const start = self.len;
+        const offset = std.math.cast(u16, start - base) orelse return error.OffsetOverflow;
+        @memset(self.buf[start..][0..count], 0);
+        self.len += count;
+        self.writeInt(u16, slot, offset);
+        return start;
+    }
+
+    pub fn writeInt(self: *Builder, comptime T: type, pos: usize, value: T) void {
+        std.mem.writeInt(T, self.buf[pos..][0..@sizeOf(T)], value, .little);
+    }
+
+    pub fn writeBool(self: *Builder, pos: usize, value: bool) void {
+        self.buf[pos] = @intFromBool(value);
+    }
+
+    pub fn writeF32(self: *Builder, pos: usize, value: f32) void {
+        self.writeInt(u32, pos, @bitCast(value));
+    }
+
+    pub fn writeF64(self: *Builder, pos: usize, value: f64) void {
+        self.writeInt(u64, pos, @bitCast(value));
+    }
+
+    /// Writes a null-terminated string after the data written so far and stores its offset at
+    /// `pos`.
+    pub fn writeString(self: *Builder, pos: usize, value: []const u8) Error!void {
+        const start = try self.reserve(pos, pos, value.len + 1);
+        @memcpy(self.buf[start..][0..value.len], value);
+    }
+
+    /// Writes a list header at `pos` and the list elements after the data written so far.
+    pub fn writeList(
+        self: *Builder,
+        comptime T: type,
+        pos: usize,
+        values: []const T,
+        stride: usize,
+        comptime writeElement: fn (*Builder, usize, T) Error!void,
+    ) Error!void {
+        const len = std.math.cast(u16, values.len) orelse return error.OffsetOverflow;
+        self.writeInt(u16, pos, len);
+        const start = try self.reserve(pos + 2, pos, values.len * stride);
+        for (values, 0..) |value, i| {
+            try writeElement(self, start + i * stride, value);
+        }
+    }
+};
@@ -0,0 +1,152 @@
+//! Zig code generator.
+//!
+//! This module implements Zig code generation for SimpleBuffers schemas. It produces a single
+//! `{file_name}.zig` file that contains:
+//!
+//! - A small runtime (`rt`) with helpers for reading and writing little-endian values.
+//! - A Zig `enum` for every schema enum, using the enum's size as its tag type.
+//! - A writer struct for every sequence, which serializes into a caller-provided `[]u8`.
+//! - A reader struct for every sequence, which holds a `[]const u8` and a position and reads
+//!   fields through accessor functions.
+//!
+//! Oneofs are represented as tagged unions (`union(enum)`). Readers return `null` if the tag
+//! stored in the buffer does not match any known field.
+//!
+//! The generated code does not allocate. Writers report `error.NoSpaceLeft` if the buffer is too
+//! small to hold the serialized data.
+
+mod ziggen;
+
+use std::path::PathBuf;
+
+use simplebuffers_codegen::{register_generators, CodeGenerator, FileSink};
+use ziggen::generate_zig;
+
+#[derive(Debug)]
+pub struct ZigCodeGenerator;
+
+impl CodeGenerator for ZigCodeGenerator {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self
+    }
+
+    fn generate(
+        &mut self,
+        schema: &simplebuffers_core::SBSchema,
+        params: &simplebuffers_codegen::GeneratorParams,
+        sink: &mut dyn FileSink,
+    ) -> Result<Vec<PathBuf>, String> {
+        if schema.uses_fixed_arrays() {
+            return Err("The Zig generator does not support fixed-size arrays".to_string());
+        }
+        if schema.uses_bytes() {
+            return Err("The Zig generator does not support byte buffers".to_string());
+        }
+        if schema.uses_maps() {
+            return Err("The Zig generator does not support maps".to_string());
+        }
+
+        let path = PathBuf::from(&params.dest_dir).join(format!("{}.zig", params.file_name));
+        sink.write_file(&path, &generate_zig(schema))?;
+
+        Ok(vec![path])
+    }
+
+    fn reserved_identifiers(
+        &mut self,
+        _params: &simplebuffers_codegen::GeneratorParams,
+    ) -> Vec<String> {
+        vec![
+            // Names used by the generated code.
+            "std",
+            "rt",
+            "Builder",
+            "Error",
+            "buf",
+            "pos",
+            "static_size",
+            "self",
+            "p",
+            "writeComponent",
+            "readComponent",
+            "encode",
+            // Primitive types and values.
+            "anyerror",
+            "anyopaque",
+            "bool",
+            "false",
+            "noreturn",
+            "null",
+            "true",
+            "type",
+            "undefined",
+            "void",
+            // Keywords.
+            "addrspace",
+            "align",
+            "allowzero",
+            "and",
+            "anyframe",
+            "anytype",
+            "asm",
+            "async",
+            "await",
+            "break",
+            "callconv",
+            "catch",
+            "comptime",
+            "const",
+            "continue",
+            "defer",
+            "else",
+            "enum",
+            "errdefer",
+            "error",
+            "export",
+            "extern",
+            "fn",
+            "for",
+            "if",
+            "inline",
+            "linksection",
+            "noalias",
+            "noinline",
+            "nosuspend",
+            "opaque",
+            "or",
+            "orelse",
+            "packed",
+            "pub",
+            "resume",
+            "return",
+            "struct",
+            "suspend",
+            "switch",
+            "test",
+            "threadlocal",
+            "try",
+            "union",
+            "unreachable",
+            "usingnamespace",
+            "var",
+            "volatile",
+            "while",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+    }
+
+    fn output_extensions(&self) -> Vec<String> {
+        vec!["zig".to_string()]
+    }
+
+    fn description(&self) -> String {
+        "Zig code".to_string()
+    }
+}
+
+register_generators!(zig: ZigCodeGenerator);
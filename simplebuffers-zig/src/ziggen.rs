@@ -0,0 +1,607 @@
+//! Generates a Zig source file.
+
+use convert_case::{Case, Casing};
+use indent::indent_by;
+use indoc::formatdoc;
+use itertools::Itertools;
+use simplebuffers_core::{Enum, Field, Primitive, SBSchema, Sequence, Type};
+use std::collections::{HashMap, HashSet};
+
+/// Where a value is stored. This determines how sequences are laid out.
+#[derive(Clone, Copy)]
+enum Context {
+    /// The value is a field of a sequence. Sequences are stored behind a 16-bit offset.
+    Slot,
+
+    /// The value is a list element or the target of a oneof. Sequences are stored inline.
+    Element,
+}
+
+/// Information about the schema that is needed while generating code.
+struct ZigGen<'a> {
+    /// The static size of every sequence, by name.
+    sequence_sizes: HashMap<&'a str, usize>,
+
+    /// The names of the enums that are signed (see [Enum::is_signed]).
+    signed_enums: HashSet<&'a str>,
+}
+
+//                                                                                                //
+// ======================================= Main Function ======================================== //
+//                                                                                                //
+
+/// Generates a Zig source file from a given schema.
+///
+/// # Arguments
+///
+/// * `schema` - The schema to generate from.
+///
+/// # Returns
+///
+/// The code for a Zig source file, as a String.
+pub(crate) fn generate_zig(schema: &SBSchema) -> String {
+    let gen = ZigGen {
+        sequence_sizes: schema
+            .sequences
+            .iter()
+            .map(|s| (s.name.as_str(), s.static_size()))
+            .collect(),
+        signed_enums: schema
+            .enums
+            .iter()
+            .filter(|e| e.is_signed())
+            .map(|e| e.name.as_str())
+            .collect(),
+    };
+
+    let runtime = include_str!("../corelib/runtime.zig").trim();
+    let declarations = schema
+        .enums
+        .iter()
+        .map(define_enum)
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| gen.define_sequence_writer(s)),
+        )
+        .chain(
+            schema
+                .sequences
+                .iter()
+                .map(|s| gen.define_sequence_reader(s)),
+        )
+        .join("\n\n");
+
+    formatdoc! {
+        r#"
+        // This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+        const std = @import("std");
+
+        /// Low-level helpers used by generated readers and writers.
+        ///
+        /// All multi-byte values are stored in little-endian byte order. Offsets to dynamic data
+        /// are 16-bit values relative to the position of the field that holds them.
+        pub const rt = struct {{
+            {runtime}
+        }};
+
+        pub const Builder = rt.Builder;
+        pub const Error = rt.Error;
+
+        {declarations}
+        "#,
+        runtime = indent_by(4, runtime),
+    }
+}
+
+/// Returns the Zig type that matches a primitive.
+fn primitive_type(p: &Primitive) -> &'static str {
+    match p {
+        Primitive::Bool => "bool",
+        Primitive::U8 => "u8",
+        Primitive::U16 => "u16",
+        Primitive::U32 => "u32",
+        Primitive::U64 => "u64",
+        Primitive::I8 => "i8",
+        Primitive::I16 => "i16",
+        Primitive::I32 => "i32",
+        Primitive::I64 => "i64",
+        Primitive::F32 => "f32",
+        Primitive::F64 => "f64",
+    }
+}
+
+/// Returns the Zig type that is the tag type of an enum of the given size and signedness.
+fn enum_tag_type(size: usize, signed: bool) -> &'static str {
+    match (size, signed) {
+        (1, false) => "u8",
+        (2, false) => "u16",
+        (4, false) => "u32",
+        (8, false) => "u64",
+        (1, true) => "i8",
+        (2, true) => "i16",
+        (4, true) => "i32",
+        (8, true) => "i64",
+        _ => panic!("Cannot convert size {} to Zig type", size),
+    }
+}
+
+/// Returns the name of the writer struct for a sequence.
+fn writer_name(seq_name: &str) -> String {
+    format!("{}Writer", seq_name).to_case(Case::Pascal)
+}
+
+/// Returns the name of the reader struct for a sequence.
+fn reader_name(seq_name: &str) -> String {
+    format!("{}Reader", seq_name).to_case(Case::Pascal)
+}
+
+/// Returns the path used to name types nested in a field.
+fn field_path(prefix: &str, field_name: &str) -> String {
+    format!("{}{}", prefix, field_name.to_case(Case::Pascal))
+}
+
+/// Generates the Zig code for defining an enum. Enums are non-exhaustive, so values that do not
+/// match a known variant can still be read.
+fn define_enum(data: &Enum) -> String {
+    let name = data.name.to_case(Case::Pascal);
+    let tag_type = enum_tag_type(data.size.into(), data.is_signed());
+    let variants = data
+        .variants
+        .iter()
+        .map(|v| format!("{} = {},", v.name.to_case(Case::Snake), v.value))
+        .join("\n");
+
+    formatdoc! {
+        r"
+        pub const {name} = enum({tag_type}) {{
+            {variants}
+            _,
+        }};",
+        variants = indent_by(4, variants)
+    }
+}
+
+/// Wraps the members of a container declaration in braces.
+fn declare(header: &str, body: &str) -> String {
+    formatdoc! {
+        r"
+        {header} {{
+            {body}
+        }};",
+        body = indent_by(4, body.trim())
+    }
+}
+
+/// Returns a statement that discards unused function parameters, which Zig does not allow.
+fn discard(names: &[&str]) -> String {
+    names.iter().map(|n| format!("_ = {n};")).join("\n")
+}
+
+impl<'a> ZigGen<'a> {
+    /// Returns the number of bytes a type takes up as a list element or oneof target.
+    fn element_size(&self, ty: &Type) -> usize {
+        match ty {
+            Type::Sequence(s) => self.sequence_sizes[s.as_str()],
+            _ => ty.size(),
+        }
+    }
+
+    //                                                                                            //
+    // =============================== Generate Writer Components =============================== //
+    //                                                                                            //
+
+    /// Returns the Zig type used to write a value. `path` names the types nested in the value.
+    fn writer_type(&self, ty: &Type, path: &str) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p).to_string(),
+            Type::Sequence(s) => writer_name(s),
+            Type::Enum(e, _) => e.to_case(Case::Pascal),
+            Type::Array(t) => format!("[]const {}", self.writer_type(t, &format!("{path}Item"))),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "[]const u8".to_string(),
+            Type::OneOf(_) => path.to_string(),
+        }
+    }
+
+    /// Returns a statement that writes `value` at `pos` using the builder `b`. `depth` is used to
+    /// give the parameters of element writers unique names.
+    #[allow(clippy::too_many_arguments)]
+    fn write_stmt(
+        &self,
+        ty: &Type,
+        path: &str,
+        ctx: Context,
+        value: &str,
+        pos: &str,
+        b: &str,
+        depth: usize,
+    ) -> String {
+        match ty {
+            Type::Primitive(p) => match p {
+                Primitive::Bool => format!("{b}.writeBool({pos}, {value});"),
+                Primitive::F32 => format!("{b}.writeF32({pos}, {value});"),
+                Primitive::F64 => format!("{b}.writeF64({pos}, {value});"),
+                _ => format!("{b}.writeInt({}, {pos}, {value});", primitive_type(p)),
+            },
+            Type::Enum(e, size) => format!(
+                "{b}.writeInt({}, {pos}, @intFromEnum({value}));",
+                enum_tag_type(*size, self.signed_enums.contains(e.as_str()))
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("try {b}.writeString({pos}, {value});"),
+            Type::Array(t) => {
+                let element_path = format!("{path}Item");
+                let element_type = self.writer_type(t, &element_path);
+                formatdoc! {
+                    r"
+                    try {b}.writeList({element_type}, {pos}, {value}, {stride}, struct {{
+                        fn writeElement(b{depth}: *Builder, p{depth}: usize, e{depth}: {element_type}) Error!void {{
+                            {write}
+                        }}
+                    }}.writeElement);",
+                    stride = self.element_size(t),
+                    write = indent_by(
+                        8,
+                        self.write_stmt(
+                            t,
+                            &element_path,
+                            Context::Element,
+                            &format!("e{depth}"),
+                            &format!("p{depth}"),
+                            &format!("b{depth}"),
+                            depth + 1
+                        )
+                    ),
+                }
+            }
+            Type::Sequence(s) => match ctx {
+                Context::Slot => format!(
+                    "try {value}.writeComponent({b}, try {b}.reserve({pos}, {pos}, {size}));",
+                    size = self.sequence_sizes[s.as_str()],
+                ),
+                Context::Element => format!("try {value}.writeComponent({b}, {pos});"),
+            },
+            Type::OneOf(_) => format!("try {value}.writeComponent({b}, {pos});"),
+        }
+    }
+
+    /// Returns the definitions of the unions nested in a value that is written.
+    fn writer_nested_types(&self, ty: &Type, path: &str) -> Vec<String> {
+        match ty {
+            Type::Array(t) => self.writer_nested_types(t, &format!("{path}Item")),
+            Type::OneOf(subfields) => {
+                let mut types = vec![self.define_oneof_writer(path, subfields)];
+                for f in subfields {
+                    types.extend(self.writer_nested_types(&f.ty, &field_path(path, &f.name)));
+                }
+                types
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Generates the Zig code for defining a sequence writer.
+    fn define_sequence_writer(&self, seq: &Sequence) -> String {
+        let name = writer_name(&seq.name);
+        let static_size = self.sequence_sizes[seq.name.as_str()];
+
+        let members = seq
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{}: {},",
+                    f.name.to_case(Case::Snake),
+                    self.writer_type(&f.ty, &field_path("", &f.name))
+                )
+            })
+            .join("\n");
+
+        let write_fields = if seq.fields.is_empty() {
+            discard(&["self", "b", "pos"])
+        } else {
+            seq.fields
+                .iter()
+                .map(|f| {
+                    self.write_stmt(
+                        &f.ty,
+                        &field_path("", &f.name),
+                        Context::Slot,
+                        &format!("self.{}", f.name.to_case(Case::Snake)),
+                        &format!("pos + {}", f.index),
+                        "b",
+                        1,
+                    )
+                })
+                .join("\n")
+        };
+
+        let nested = seq
+            .fields
+            .iter()
+            .flat_map(|f| self.writer_nested_types(&f.ty, &field_path("", &f.name)))
+            .join("\n\n");
+
+        let body = formatdoc! {
+            r"
+            {members}
+
+            pub const static_size = {static_size};
+
+            /// Writes static data at `pos` and dynamic data after the data written so far.
+            pub fn writeComponent(self: {name}, b: *Builder, pos: usize) Error!void {{
+                {write_fields}
+            }}
+
+            /// Serializes the sequence into `buf` and returns the number of bytes written.
+            pub fn encode(self: {name}, buf: []u8) Error!usize {{
+                if (buf.len < static_size) return error.NoSpaceLeft;
+                @memset(buf[0..static_size], 0);
+                var b = Builder{{ .buf = buf, .len = static_size }};
+                try self.writeComponent(&b, 0);
+                return b.len;
+            }}
+
+            {nested}",
+            write_fields = indent_by(4, write_fields),
+        };
+
+        declare(&format!("pub const {name} = struct"), &body)
+    }
+
+    /// Generates the Zig code for defining a oneof writer. Oneofs are written as tagged unions.
+    fn define_oneof_writer(&self, path: &str, subfields: &[Field]) -> String {
+        let members = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{}: {},",
+                    f.name.to_case(Case::Snake),
+                    self.writer_type(&f.ty, &field_path(path, &f.name))
+                )
+            })
+            .join("\n");
+
+        let switch_cases = subfields
+            .iter()
+            .map(|f| {
+                formatdoc! {
+                    r"
+                    .{case} => |value| {{
+                        b.writeInt(u8, pos, {index});
+                        const p = try b.reserve(pos + 1, pos, {size});
+                        {write}
+                    }},",
+                    case = f.name.to_case(Case::Snake),
+                    index = f.index,
+                    size = self.element_size(&f.ty),
+                    write = indent_by(
+                        4,
+                        self.write_stmt(
+                            &f.ty,
+                            &field_path(path, &f.name),
+                            Context::Element,
+                            "value",
+                            "p",
+                            "b",
+                            1
+                        )
+                    ),
+                }
+            })
+            .join("\n");
+
+        let body = formatdoc! {
+            r"
+            {members}
+
+            pub fn writeComponent(self: {path}, b: *Builder, pos: usize) Error!void {{
+                switch (self) {{
+                    {switch_cases}
+                }}
+            }}",
+            switch_cases = indent_by(8, switch_cases),
+        };
+
+        declare(&format!("pub const {path} = union(enum)"), &body)
+    }
+
+    //                                                                                            //
+    // =============================== Generate Reader Components =============================== //
+    //                                                                                            //
+
+    /// Returns the Zig type returned when reading a value. `path` names the types nested in the
+    /// value.
+    fn reader_type(&self, ty: &Type, path: &str) -> String {
+        match ty {
+            Type::Primitive(p) => primitive_type(p).to_string(),
+            Type::Sequence(s) => reader_name(s),
+            Type::Enum(e, _) => e.to_case(Case::Pascal),
+            Type::Array(_) => format!("{path}List"),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => "[]const u8".to_string(),
+            Type::OneOf(_) => format!("?{path}"),
+        }
+    }
+
+    /// Returns an expression that reads a value of the given type at `pos` in `buf`.
+    fn read_expr(&self, ty: &Type, path: &str, ctx: Context, buf: &str, pos: &str) -> String {
+        match ty {
+            Type::Primitive(p) => match p {
+                Primitive::Bool => format!("rt.readBool({buf}, {pos})"),
+                Primitive::F32 => format!("rt.readF32({buf}, {pos})"),
+                Primitive::F64 => format!("rt.readF64({buf}, {pos})"),
+                _ => format!("rt.readInt({}, {buf}, {pos})", primitive_type(p)),
+            },
+            Type::Enum(e, size) => format!(
+                "@enumFromInt(rt.readInt({}, {buf}, {pos}))",
+                enum_tag_type(*size, self.signed_enums.contains(e.as_str()))
+            ),
+            Type::FixedArray(..) | Type::Bytes | Type::Map(..) => {
+                unreachable!(
+                    "fixed-size arrays, byte buffers, and maps are rejected before generation"
+                )
+            }
+            Type::String => format!("rt.readString({buf}, {pos})"),
+            Type::Array(_) => format!("{path}List.init({buf}, {pos})"),
+            Type::Sequence(s) => match ctx {
+                Context::Slot => format!(
+                    "{}{{ .buf = {buf}, .pos = rt.follow({buf}, {pos}) }}",
+                    reader_name(s)
+                ),
+                Context::Element => format!("{}{{ .buf = {buf}, .pos = {pos} }}", reader_name(s)),
+            },
+            Type::OneOf(_) => format!("{path}.readComponent({buf}, {pos})"),
+        }
+    }
+
+    /// Returns the definitions of the types nested in a value that is read.
+    fn reader_nested_types(&self, ty: &Type, path: &str) -> Vec<String> {
+        match ty {
+            Type::Array(t) => {
+                let mut types = vec![self.define_list_reader(t, path)];
+                types.extend(self.reader_nested_types(t, &format!("{path}Item")));
+                types
+            }
+            Type::OneOf(subfields) => {
+                let mut types = vec![self.define_oneof_reader(path, subfields)];
+                for f in subfields {
+                    types.extend(self.reader_nested_types(&f.ty, &field_path(path, &f.name)));
+                }
+                types
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Generates the Zig code for defining a sequence reader. Fields are read with accessor
+    /// functions.
+    fn define_sequence_reader(&self, seq: &Sequence) -> String {
+        let name = reader_name(&seq.name);
+        let static_size = self.sequence_sizes[seq.name.as_str()];
+
+        let accessors = seq
+            .fields
+            .iter()
+            .map(|f| {
+                let path = field_path("", &f.name);
+                formatdoc! {
+                    r"
+                    pub fn {accessor}(self: {name}) {ty} {{
+                        return {read};
+                    }}",
+                    accessor = f.name.to_case(Case::Camel),
+                    ty = self.reader_type(&f.ty, &path),
+                    read = self.read_expr(
+                        &f.ty,
+                        &path,
+                        Context::Slot,
+                        "self.buf",
+                        &format!("self.pos + {}", f.index)
+                    ),
+                }
+            })
+            .join("\n\n");
+
+        let nested = seq
+            .fields
+            .iter()
+            .flat_map(|f| self.reader_nested_types(&f.ty, &field_path("", &f.name)))
+            .join("\n\n");
+
+        let body = formatdoc! {
+            r"
+            buf: []const u8,
+            pos: usize = 0,
+
+            pub const static_size = {static_size};
+
+            {accessors}
+
+            {nested}"
+        };
+
+        declare(&format!("pub const {name} = struct"), &body)
+    }
+
+    /// Generates the Zig code for defining a list reader.
+    fn define_list_reader(&self, element: &Type, path: &str) -> String {
+        let element_path = format!("{path}Item");
+        let ty = self.reader_type(element, &element_path);
+
+        formatdoc! {
+            r"
+            pub const {path}List = rt.ListReader({ty}, {stride}, struct {{
+                fn readElement(buf: []const u8, pos: usize) {ty} {{
+                    return {read};
+                }}
+            }}.readElement);",
+            stride = self.element_size(element),
+            read = self.read_expr(element, &element_path, Context::Element, "buf", "pos"),
+        }
+    }
+
+    /// Generates the Zig code for defining a oneof reader. The active field is chosen by the tag
+    /// stored in the buffer. Unknown tags produce `null`.
+    fn define_oneof_reader(&self, path: &str, subfields: &[Field]) -> String {
+        let members = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{}: {},",
+                    f.name.to_case(Case::Snake),
+                    self.reader_type(&f.ty, &field_path(path, &f.name))
+                )
+            })
+            .join("\n");
+
+        let switch_cases = subfields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{index} => .{{ .{case} = {read} }},",
+                    index = f.index,
+                    case = f.name.to_case(Case::Snake),
+                    read = self.read_expr(
+                        &f.ty,
+                        &field_path(path, &f.name),
+                        Context::Element,
+                        "buf",
+                        "p"
+                    ),
+                )
+            })
+            .join("\n");
+
+        let body = formatdoc! {
+            r"
+            {members}
+
+            pub fn readComponent(buf: []const u8, pos: usize) ?{path} {{
+                const p = pos + rt.readInt(u16, buf, pos + 1);
+                return switch (buf[pos]) {{
+                    {switch_cases}
+                    else => null,
+                }};
+            }}",
+            switch_cases = indent_by(8, switch_cases),
+        };
+
+        declare(&format!("pub const {path} = union(enum)"), &body)
+    }
+}
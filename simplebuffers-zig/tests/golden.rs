@@ -0,0 +1,11 @@
+//! Golden tests for the Zig generator. See `simplebuffers-golden` for how they work.
+
+use simplebuffers_golden::{golden_tests, Layout};
+use simplebuffers_zig::ZigCodeGenerator;
+
+golden_tests! {
+    generator: ZigCodeGenerator,
+    args: ["zig"],
+    layout: Layout::Flat,
+    cases: [primitives, enums, nested, oneofs, lists, oneof_arrays, signed_enums],
+}
@@ -0,0 +1,291 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+const std = @import("std");
+
+/// Low-level helpers used by generated readers and writers.
+///
+/// All multi-byte values are stored in little-endian byte order. Offsets to dynamic data
+/// are 16-bit values relative to the position of the field that holds them.
+pub const rt = struct {
+    pub const Error = error{
+        /// The buffer is too small to hold the serialized data.
+        NoSpaceLeft,
+        /// An offset to dynamic data does not fit in 16 bits.
+        OffsetOverflow,
+    };
+
+    pub fn readInt(comptime T: type, buf: []const u8, pos: usize) T {
+        return std.mem.readInt(T, buf[pos..][0..@sizeOf(T)], .little);
+    }
+
+    pub fn readBool(buf: []const u8, pos: usize) bool {
+        return buf[pos] != 0;
+    }
+
+    pub fn readF32(buf: []const u8, pos: usize) f32 {
+        return @bitCast(readInt(u32, buf, pos));
+    }
+
+    pub fn readF64(buf: []const u8, pos: usize) f64 {
+        return @bitCast(readInt(u64, buf, pos));
+    }
+
+    /// Follows the 16-bit offset stored at `pos` and returns the position it points to.
+    pub fn follow(buf: []const u8, pos: usize) usize {
+        return pos + readInt(u16, buf, pos);
+    }
+
+    /// Reads a null-terminated string whose offset is stored at `pos`. The terminator is not included.
+    pub fn readString(buf: []const u8, pos: usize) []const u8 {
+        const start = follow(buf, pos);
+        const end = std.mem.indexOfScalarPos(u8, buf, start, 0) orelse buf.len;
+        return buf[start..end];
+    }
+
+    /// Returns a type that reads a list stored in a buffer. Elements are `stride` bytes apart and are
+    /// read with `readElement`.
+    pub fn ListReader(
+        comptime T: type,
+        comptime stride: usize,
+        comptime readElement: fn ([]const u8, usize) T,
+    ) type {
+        return struct {
+            buf: []const u8,
+            start: usize,
+            len: usize,
+
+            pub fn init(buf: []const u8, pos: usize) @This() {
+                return .{
+                    .buf = buf,
+                    .start = pos + readInt(u16, buf, pos + 2),
+                    .len = readInt(u16, buf, pos),
+                };
+            }
+
+            pub fn get(self: @This(), index: usize) T {
+                std.debug.assert(index < self.len);
+                return readElement(self.buf, self.start + index * stride);
+            }
+        };
+    }
+
+    /// Serializes data into a caller-provided buffer. Static data is written at fixed positions and
+    /// dynamic data is appended after `len`.
+    pub const Builder = struct {
+        buf: []u8,
+        len: usize,
+
+        /// Reserves `count` zeroed bytes after the data written so far and stores the offset to them
+        /// (relative to `base`) at `slot`. Returns the position of the reserved bytes.
+        pub fn reserve(self: *Builder, slot: usize, base: usize, count: usize) Error!usize {
+            if (self.buf.len - self.len < count) return error.NoSpaceLeft;
+            const start = self.len;
+            const offset = std.math.cast(u16, start - base) orelse return error.OffsetOverflow;
+            @memset(self.buf[start..][0..count], 0);
+            self.len += count;
+            self.writeInt(u16, slot, offset);
+            return start;
+        }
+
+        pub fn writeInt(self: *Builder, comptime T: type, pos: usize, value: T) void {
+            std.mem.writeInt(T, self.buf[pos..][0..@sizeOf(T)], value, .little);
+        }
+
+        pub fn writeBool(self: *Builder, pos: usize, value: bool) void {
+            self.buf[pos] = @intFromBool(value);
+        }
+
+        pub fn writeF32(self: *Builder, pos: usize, value: f32) void {
+            self.writeInt(u32, pos, @bitCast(value));
+        }
+
+        pub fn writeF64(self: *Builder, pos: usize, value: f64) void {
+            self.writeInt(u64, pos, @bitCast(value));
+        }
+
+        /// Writes a null-terminated string after the data written so far and stores its offset at
+        /// `pos`.
+        pub fn writeString(self: *Builder, pos: usize, value: []const u8) Error!void {
+            const start = try self.reserve(pos, pos, value.len + 1);
+            @memcpy(self.buf[start..][0..value.len], value);
+        }
+
+        /// Writes a list header at `pos` and the list elements after the data written so far.
+        pub fn writeList(
+            self: *Builder,
+            comptime T: type,
+            pos: usize,
+            values: []const T,
+            stride: usize,
+            comptime writeElement: fn (*Builder, usize, T) Error!void,
+        ) Error!void {
+            const len = std.math.cast(u16, values.len) orelse return error.OffsetOverflow;
+            self.writeInt(u16, pos, len);
+            const start = try self.reserve(pos + 2, pos, values.len * stride);
+            for (values, 0..) |value, i| {
+                try writeElement(self, start + i * stride, value);
+            }
+        }
+    };
+};
+
+pub const Builder = rt.Builder;
+pub const Error = rt.Error;
+
+pub const Color = enum(u8) {
+    red = 0,
+    green = 1,
+    _,
+};
+
+pub const ListsWriter = struct {
+    numbers: []const u16,
+    words: []const []const u8,
+    colors: []const Color,
+    points: []const PointWriter,
+    matrix: []const []const i32,
+
+    pub const static_size = 20;
+
+    /// Writes static data at `pos` and dynamic data after the data written so far.
+    pub fn writeComponent(self: ListsWriter, b: *Builder, pos: usize) Error!void {
+        try b.writeList(u16, pos + 0, self.numbers, 2, struct {
+            fn writeElement(b1: *Builder, p1: usize, e1: u16) Error!void {
+                b1.writeInt(u16, p1, e1);
+            }
+        }.writeElement);
+        try b.writeList([]const u8, pos + 4, self.words, 2, struct {
+            fn writeElement(b1: *Builder, p1: usize, e1: []const u8) Error!void {
+                try b1.writeString(p1, e1);
+            }
+        }.writeElement);
+        try b.writeList(Color, pos + 8, self.colors, 1, struct {
+            fn writeElement(b1: *Builder, p1: usize, e1: Color) Error!void {
+                b1.writeInt(u8, p1, @intFromEnum(e1));
+            }
+        }.writeElement);
+        try b.writeList(PointWriter, pos + 12, self.points, 4, struct {
+            fn writeElement(b1: *Builder, p1: usize, e1: PointWriter) Error!void {
+                try e1.writeComponent(b1, p1);
+            }
+        }.writeElement);
+        try b.writeList([]const i32, pos + 16, self.matrix, 4, struct {
+            fn writeElement(b1: *Builder, p1: usize, e1: []const i32) Error!void {
+                try b1.writeList(i32, p1, e1, 4, struct {
+                    fn writeElement(b2: *Builder, p2: usize, e2: i32) Error!void {
+                        b2.writeInt(i32, p2, e2);
+                    }
+                }.writeElement);
+            }
+        }.writeElement);
+    }
+
+    /// Serializes the sequence into `buf` and returns the number of bytes written.
+    pub fn encode(self: ListsWriter, buf: []u8) Error!usize {
+        if (buf.len < static_size) return error.NoSpaceLeft;
+        @memset(buf[0..static_size], 0);
+        var b = Builder{ .buf = buf, .len = static_size };
+        try self.writeComponent(&b, 0);
+        return b.len;
+    }
+};
+
+pub const PointWriter = struct {
+    x: i16,
+    y: i16,
+
+    pub const static_size = 4;
+
+    /// Writes static data at `pos` and dynamic data after the data written so far.
+    pub fn writeComponent(self: PointWriter, b: *Builder, pos: usize) Error!void {
+        b.writeInt(i16, pos + 0, self.x);
+        b.writeInt(i16, pos + 2, self.y);
+    }
+
+    /// Serializes the sequence into `buf` and returns the number of bytes written.
+    pub fn encode(self: PointWriter, buf: []u8) Error!usize {
+        if (buf.len < static_size) return error.NoSpaceLeft;
+        @memset(buf[0..static_size], 0);
+        var b = Builder{ .buf = buf, .len = static_size };
+        try self.writeComponent(&b, 0);
+        return b.len;
+    }
+};
+
+pub const ListsReader = struct {
+    buf: []const u8,
+    pos: usize = 0,
+
+    pub const static_size = 20;
+
+    pub fn numbers(self: ListsReader) NumbersList {
+        return NumbersList.init(self.buf, self.pos + 0);
+    }
+
+    pub fn words(self: ListsReader) WordsList {
+        return WordsList.init(self.buf, self.pos + 4);
+    }
+
+    pub fn colors(self: ListsReader) ColorsList {
+        return ColorsList.init(self.buf, self.pos + 8);
+    }
+
+    pub fn points(self: ListsReader) PointsList {
+        return PointsList.init(self.buf, self.pos + 12);
+    }
+
+    pub fn matrix(self: ListsReader) MatrixList {
+        return MatrixList.init(self.buf, self.pos + 16);
+    }
+
+    pub const NumbersList = rt.ListReader(u16, 2, struct {
+        fn readElement(buf: []const u8, pos: usize) u16 {
+            return rt.readInt(u16, buf, pos);
+        }
+    }.readElement);
+
+    pub const WordsList = rt.ListReader([]const u8, 2, struct {
+        fn readElement(buf: []const u8, pos: usize) []const u8 {
+            return rt.readString(buf, pos);
+        }
+    }.readElement);
+
+    pub const ColorsList = rt.ListReader(Color, 1, struct {
+        fn readElement(buf: []const u8, pos: usize) Color {
+            return @enumFromInt(rt.readInt(u8, buf, pos));
+        }
+    }.readElement);
+
+    pub const PointsList = rt.ListReader(PointReader, 4, struct {
+        fn readElement(buf: []const u8, pos: usize) PointReader {
+            return PointReader{ .buf = buf, .pos = pos };
+        }
+    }.readElement);
+
+    pub const MatrixList = rt.ListReader(MatrixItemList, 4, struct {
+        fn readElement(buf: []const u8, pos: usize) MatrixItemList {
+            return MatrixItemList.init(buf, pos);
+        }
+    }.readElement);
+
+    pub const MatrixItemList = rt.ListReader(i32, 4, struct {
+        fn readElement(buf: []const u8, pos: usize) i32 {
+            return rt.readInt(i32, buf, pos);
+        }
+    }.readElement);
+};
+
+pub const PointReader = struct {
+    buf: []const u8,
+    pos: usize = 0,
+
+    pub const static_size = 4;
+
+    pub fn x(self: PointReader) i16 {
+        return rt.readInt(i16, self.buf, self.pos + 0);
+    }
+
+    pub fn y(self: PointReader) i16 {
+        return rt.readInt(i16, self.buf, self.pos + 2);
+    }
+};
@@ -0,0 +1,292 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+const std = @import("std");
+
+/// Low-level helpers used by generated readers and writers.
+///
+/// All multi-byte values are stored in little-endian byte order. Offsets to dynamic data
+/// are 16-bit values relative to the position of the field that holds them.
+pub const rt = struct {
+    pub const Error = error{
+        /// The buffer is too small to hold the serialized data.
+        NoSpaceLeft,
+        /// An offset to dynamic data does not fit in 16 bits.
+        OffsetOverflow,
+    };
+
+    pub fn readInt(comptime T: type, buf: []const u8, pos: usize) T {
+        return std.mem.readInt(T, buf[pos..][0..@sizeOf(T)], .little);
+    }
+
+    pub fn readBool(buf: []const u8, pos: usize) bool {
+        return buf[pos] != 0;
+    }
+
+    pub fn readF32(buf: []const u8, pos: usize) f32 {
+        return @bitCast(readInt(u32, buf, pos));
+    }
+
+    pub fn readF64(buf: []const u8, pos: usize) f64 {
+        return @bitCast(readInt(u64, buf, pos));
+    }
+
+    /// Follows the 16-bit offset stored at `pos` and returns the position it points to.
+    pub fn follow(buf: []const u8, pos: usize) usize {
+        return pos + readInt(u16, buf, pos);
+    }
+
+    /// Reads a null-terminated string whose offset is stored at `pos`. The terminator is not included.
+    pub fn readString(buf: []const u8, pos: usize) []const u8 {
+        const start = follow(buf, pos);
+        const end = std.mem.indexOfScalarPos(u8, buf, start, 0) orelse buf.len;
+        return buf[start..end];
+    }
+
+    /// Returns a type that reads a list stored in a buffer. Elements are `stride` bytes apart and are
+    /// read with `readElement`.
+    pub fn ListReader(
+        comptime T: type,
+        comptime stride: usize,
+        comptime readElement: fn ([]const u8, usize) T,
+    ) type {
+        return struct {
+            buf: []const u8,
+            start: usize,
+            len: usize,
+
+            pub fn init(buf: []const u8, pos: usize) @This() {
+                return .{
+                    .buf = buf,
+                    .start = pos + readInt(u16, buf, pos + 2),
+                    .len = readInt(u16, buf, pos),
+                };
+            }
+
+            pub fn get(self: @This(), index: usize) T {
+                std.debug.assert(index < self.len);
+                return readElement(self.buf, self.start + index * stride);
+            }
+        };
+    }
+
+    /// Serializes data into a caller-provided buffer. Static data is written at fixed positions and
+    /// dynamic data is appended after `len`.
+    pub const Builder = struct {
+        buf: []u8,
+        len: usize,
+
+        /// Reserves `count` zeroed bytes after the data written so far and stores the offset to them
+        /// (relative to `base`) at `slot`. Returns the position of the reserved bytes.
+        pub fn reserve(self: *Builder, slot: usize, base: usize, count: usize) Error!usize {
+            if (self.buf.len - self.len < count) return error.NoSpaceLeft;
+            const start = self.len;
+            const offset = std.math.cast(u16, start - base) orelse return error.OffsetOverflow;
+            @memset(self.buf[start..][0..count], 0);
+            self.len += count;
+            self.writeInt(u16, slot, offset);
+            return start;
+        }
+
+        pub fn writeInt(self: *Builder, comptime T: type, pos: usize, value: T) void {
+            std.mem.writeInt(T, self.buf[pos..][0..@sizeOf(T)], value, .little);
+        }
+
+        pub fn writeBool(self: *Builder, pos: usize, value: bool) void {
+            self.buf[pos] = @intFromBool(value);
+        }
+
+        pub fn writeF32(self: *Builder, pos: usize, value: f32) void {
+            self.writeInt(u32, pos, @bitCast(value));
+        }
+
+        pub fn writeF64(self: *Builder, pos: usize, value: f64) void {
+            self.writeInt(u64, pos, @bitCast(value));
+        }
+
+        /// Writes a null-terminated string after the data written so far and stores its offset at
+        /// `pos`.
+        pub fn writeString(self: *Builder, pos: usize, value: []const u8) Error!void {
+            const start = try self.reserve(pos, pos, value.len + 1);
+            @memcpy(self.buf[start..][0..value.len], value);
+        }
+
+        /// Writes a list header at `pos` and the list elements after the data written so far.
+        pub fn writeList(
+            self: *Builder,
+            comptime T: type,
+            pos: usize,
+            values: []const T,
+            stride: usize,
+            comptime writeElement: fn (*Builder, usize, T) Error!void,
+        ) Error!void {
+            const len = std.math.cast(u16, values.len) orelse return error.OffsetOverflow;
+            self.writeInt(u16, pos, len);
+            const start = try self.reserve(pos + 2, pos, values.len * stride);
+            for (values, 0..) |value, i| {
+                try writeElement(self, start + i * stride, value);
+            }
+        }
+    };
+};
+
+pub const Builder = rt.Builder;
+pub const Error = rt.Error;
+
+pub const BatchWriter = struct {
+    events: []const EventsItem,
+    nested: Nested,
+
+    pub const static_size = 7;
+
+    /// Writes static data at `pos` and dynamic data after the data written so far.
+    pub fn writeComponent(self: BatchWriter, b: *Builder, pos: usize) Error!void {
+        try b.writeList(EventsItem, pos + 0, self.events, 3, struct {
+            fn writeElement(b1: *Builder, p1: usize, e1: EventsItem) Error!void {
+                try e1.writeComponent(b1, p1);
+            }
+        }.writeElement);
+        try self.nested.writeComponent(b, pos + 4);
+    }
+
+    /// Serializes the sequence into `buf` and returns the number of bytes written.
+    pub fn encode(self: BatchWriter, buf: []u8) Error!usize {
+        if (buf.len < static_size) return error.NoSpaceLeft;
+        @memset(buf[0..static_size], 0);
+        var b = Builder{ .buf = buf, .len = static_size };
+        try self.writeComponent(&b, 0);
+        return b.len;
+    }
+
+    pub const EventsItem = union(enum) {
+        id: u8,
+        name: []const u8,
+
+        pub fn writeComponent(self: EventsItem, b: *Builder, pos: usize) Error!void {
+            switch (self) {
+                .id => |value| {
+                    b.writeInt(u8, pos, 0);
+                    const p = try b.reserve(pos + 1, pos, 1);
+                    b.writeInt(u8, p, value);
+                },
+                .name => |value| {
+                    b.writeInt(u8, pos, 1);
+                    const p = try b.reserve(pos + 1, pos, 2);
+                    try b.writeString(p, value);
+                },
+            }
+        }
+    };
+
+    pub const Nested = union(enum) {
+        empty: u8,
+        events: []const NestedEventsItem,
+
+        pub fn writeComponent(self: Nested, b: *Builder, pos: usize) Error!void {
+            switch (self) {
+                .empty => |value| {
+                    b.writeInt(u8, pos, 0);
+                    const p = try b.reserve(pos + 1, pos, 1);
+                    b.writeInt(u8, p, value);
+                },
+                .events => |value| {
+                    b.writeInt(u8, pos, 1);
+                    const p = try b.reserve(pos + 1, pos, 4);
+                    try b.writeList(NestedEventsItem, p, value, 3, struct {
+                        fn writeElement(b1: *Builder, p1: usize, e1: NestedEventsItem) Error!void {
+                            try e1.writeComponent(b1, p1);
+                        }
+                    }.writeElement);
+                },
+            }
+        }
+    };
+
+    pub const NestedEventsItem = union(enum) {
+        small: u8,
+        large: u64,
+
+        pub fn writeComponent(self: NestedEventsItem, b: *Builder, pos: usize) Error!void {
+            switch (self) {
+                .small => |value| {
+                    b.writeInt(u8, pos, 0);
+                    const p = try b.reserve(pos + 1, pos, 1);
+                    b.writeInt(u8, p, value);
+                },
+                .large => |value| {
+                    b.writeInt(u8, pos, 1);
+                    const p = try b.reserve(pos + 1, pos, 8);
+                    b.writeInt(u64, p, value);
+                },
+            }
+        }
+    };
+};
+
+pub const BatchReader = struct {
+    buf: []const u8,
+    pos: usize = 0,
+
+    pub const static_size = 7;
+
+    pub fn events(self: BatchReader) EventsList {
+        return EventsList.init(self.buf, self.pos + 0);
+    }
+
+    pub fn nested(self: BatchReader) ?Nested {
+        return Nested.readComponent(self.buf, self.pos + 4);
+    }
+
+    pub const EventsList = rt.ListReader(?EventsItem, 3, struct {
+        fn readElement(buf: []const u8, pos: usize) ?EventsItem {
+            return EventsItem.readComponent(buf, pos);
+        }
+    }.readElement);
+
+    pub const EventsItem = union(enum) {
+        id: u8,
+        name: []const u8,
+
+        pub fn readComponent(buf: []const u8, pos: usize) ?EventsItem {
+            const p = pos + rt.readInt(u16, buf, pos + 1);
+            return switch (buf[pos]) {
+                0 => .{ .id = rt.readInt(u8, buf, p) },
+                1 => .{ .name = rt.readString(buf, p) },
+                else => null,
+            };
+        }
+    };
+
+    pub const Nested = union(enum) {
+        empty: u8,
+        events: NestedEventsList,
+
+        pub fn readComponent(buf: []const u8, pos: usize) ?Nested {
+            const p = pos + rt.readInt(u16, buf, pos + 1);
+            return switch (buf[pos]) {
+                0 => .{ .empty = rt.readInt(u8, buf, p) },
+                1 => .{ .events = NestedEventsList.init(buf, p) },
+                else => null,
+            };
+        }
+    };
+
+    pub const NestedEventsList = rt.ListReader(?NestedEventsItem, 3, struct {
+        fn readElement(buf: []const u8, pos: usize) ?NestedEventsItem {
+            return NestedEventsItem.readComponent(buf, pos);
+        }
+    }.readElement);
+
+    pub const NestedEventsItem = union(enum) {
+        small: u8,
+        large: u64,
+
+        pub fn readComponent(buf: []const u8, pos: usize) ?NestedEventsItem {
+            const p = pos + rt.readInt(u16, buf, pos + 1);
+            return switch (buf[pos]) {
+                0 => .{ .small = rt.readInt(u8, buf, p) },
+                1 => .{ .large = rt.readInt(u64, buf, p) },
+                else => null,
+            };
+        }
+    };
+};
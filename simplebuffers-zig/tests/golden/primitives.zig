@@ -0,0 +1,230 @@
+// This file was generated by the SimpleBuffers compiler. Do not edit it manually.
+
+const std = @import("std");
+
+/// Low-level helpers used by generated readers and writers.
+///
+/// All multi-byte values are stored in little-endian byte order. Offsets to dynamic data
+/// are 16-bit values relative to the position of the field that holds them.
+pub const rt = struct {
+    pub const Error = error{
+        /// The buffer is too small to hold the serialized data.
+        NoSpaceLeft,
+        /// An offset to dynamic data does not fit in 16 bits.
+        OffsetOverflow,
+    };
+
+    pub fn readInt(comptime T: type, buf: []const u8, pos: usize) T {
+        return std.mem.readInt(T, buf[pos..][0..@sizeOf(T)], .little);
+    }
+
+    pub fn readBool(buf: []const u8, pos: usize) bool {
+        return buf[pos] != 0;
+    }
+
+    pub fn readF32(buf: []const u8, pos: usize) f32 {
+        return @bitCast(readInt(u32, buf, pos));
+    }
+
+    pub fn readF64(buf: []const u8, pos: usize) f64 {
+        return @bitCast(readInt(u64, buf, pos));
+    }
+
+    /// Follows the 16-bit offset stored at `pos` and returns the position it points to.
+    pub fn follow(buf: []const u8, pos: usize) usize {
+        return pos + readInt(u16, buf, pos);
+    }
+
+    /// Reads a null-terminated string whose offset is stored at `pos`. The terminator is not included.
+    pub fn readString(buf: []const u8, pos: usize) []const u8 {
+        const start = follow(buf, pos);
+        const end = std.mem.indexOfScalarPos(u8, buf, start, 0) orelse buf.len;
+        return buf[start..end];
+    }
+
+    /// Returns a type that reads a list stored in a buffer. Elements are `stride` bytes apart and are
+    /// read with `readElement`.
+    pub fn ListReader(
+        comptime T: type,
+        comptime stride: usize,
+        comptime readElement: fn ([]const u8, usize) T,
+    ) type {
+        return struct {
+            buf: []const u8,
+            start: usize,
+            len: usize,
+
+            pub fn init(buf: []const u8, pos: usize) @This() {
+                return .{
+                    .buf = buf,
+                    .start = pos + readInt(u16, buf, pos + 2),
+                    .len = readInt(u16, buf, pos),
+                };
+            }
+
+            pub fn get(self: @This(), index: usize) T {
+                std.debug.assert(index < self.len);
+                return readElement(self.buf, self.start + index * stride);
+            }
+        };
+    }
+
+    /// Serializes data into a caller-provided buffer. Static data is written at fixed positions and
+    /// dynamic data is appended after `len`.
+    pub const Builder = struct {
+        buf: []u8,
+        len: usize,
+
+        /// Reserves `count` zeroed bytes after the data written so far and stores the offset to them
+        /// (relative to `base`) at `slot`. Returns the position of the reserved bytes.
+        pub fn reserve(self: *Builder, slot: usize, base: usize, count: usize) Error!usize {
+            if (self.buf.len - self.len < count) return error.NoSpaceLeft;
+            const start = self.len;
+            const offset = std.math.cast(u16, start - base) orelse return error.OffsetOverflow;
+            @memset(self.buf[start..][0..count], 0);
+            self.len += count;
+            self.writeInt(u16, slot, offset);
+            return start;
+        }
+
+        pub fn writeInt(self: *Builder, comptime T: type, pos: usize, value: T) void {
+            std.mem.writeInt(T, self.buf[pos..][0..@sizeOf(T)], value, .little);
+        }
+
+        pub fn writeBool(self: *Builder, pos: usize, value: bool) void {
+            self.buf[pos] = @intFromBool(value);
+        }
+
+        pub fn writeF32(self: *Builder, pos: usize, value: f32) void {
+            self.writeInt(u32, pos, @bitCast(value));
+        }
+
+        pub fn writeF64(self: *Builder, pos: usize, value: f64) void {
+            self.writeInt(u64, pos, @bitCast(value));
+        }
+
+        /// Writes a null-terminated string after the data written so far and stores its offset at
+        /// `pos`.
+        pub fn writeString(self: *Builder, pos: usize, value: []const u8) Error!void {
+            const start = try self.reserve(pos, pos, value.len + 1);
+            @memcpy(self.buf[start..][0..value.len], value);
+        }
+
+        /// Writes a list header at `pos` and the list elements after the data written so far.
+        pub fn writeList(
+            self: *Builder,
+            comptime T: type,
+            pos: usize,
+            values: []const T,
+            stride: usize,
+            comptime writeElement: fn (*Builder, usize, T) Error!void,
+        ) Error!void {
+            const len = std.math.cast(u16, values.len) orelse return error.OffsetOverflow;
+            self.writeInt(u16, pos, len);
+            const start = try self.reserve(pos + 2, pos, values.len * stride);
+            for (values, 0..) |value, i| {
+                try writeElement(self, start + i * stride, value);
+            }
+        }
+    };
+};
+
+pub const Builder = rt.Builder;
+pub const Error = rt.Error;
+
+pub const PrimitivesWriter = struct {
+    a: u8,
+    b: u16,
+    c: u32,
+    d: u64,
+    e: i8,
+    f: i16,
+    g: i32,
+    h: i64,
+    i: f32,
+    j: f64,
+    k: bool,
+    name: []const u8,
+
+    pub const static_size = 45;
+
+    /// Writes static data at `pos` and dynamic data after the data written so far.
+    pub fn writeComponent(self: PrimitivesWriter, b: *Builder, pos: usize) Error!void {
+        b.writeInt(u8, pos + 0, self.a);
+        b.writeInt(u16, pos + 1, self.b);
+        b.writeInt(u32, pos + 3, self.c);
+        b.writeInt(u64, pos + 7, self.d);
+        b.writeInt(i8, pos + 15, self.e);
+        b.writeInt(i16, pos + 16, self.f);
+        b.writeInt(i32, pos + 18, self.g);
+        b.writeInt(i64, pos + 22, self.h);
+        b.writeF32(pos + 30, self.i);
+        b.writeF64(pos + 34, self.j);
+        b.writeBool(pos + 42, self.k);
+        try b.writeString(pos + 43, self.name);
+    }
+
+    /// Serializes the sequence into `buf` and returns the number of bytes written.
+    pub fn encode(self: PrimitivesWriter, buf: []u8) Error!usize {
+        if (buf.len < static_size) return error.NoSpaceLeft;
+        @memset(buf[0..static_size], 0);
+        var b = Builder{ .buf = buf, .len = static_size };
+        try self.writeComponent(&b, 0);
+        return b.len;
+    }
+};
+
+pub const PrimitivesReader = struct {
+    buf: []const u8,
+    pos: usize = 0,
+
+    pub const static_size = 45;
+
+    pub fn a(self: PrimitivesReader) u8 {
+        return rt.readInt(u8, self.buf, self.pos + 0);
+    }
+
+    pub fn b(self: PrimitivesReader) u16 {
+        return rt.readInt(u16, self.buf, self.pos + 1);
+    }
+
+    pub fn c(self: PrimitivesReader) u32 {
+        return rt.readInt(u32, self.buf, self.pos + 3);
+    }
+
+    pub fn d(self: PrimitivesReader) u64 {
+        return rt.readInt(u64, self.buf, self.pos + 7);
+    }
+
+    pub fn e(self: PrimitivesReader) i8 {
+        return rt.readInt(i8, self.buf, self.pos + 15);
+    }
+
+    pub fn f(self: PrimitivesReader) i16 {
+        return rt.readInt(i16, self.buf, self.pos + 16);
+    }
+
+    pub fn g(self: PrimitivesReader) i32 {
+        return rt.readInt(i32, self.buf, self.pos + 18);
+    }
+
+    pub fn h(self: PrimitivesReader) i64 {
+        return rt.readInt(i64, self.buf, self.pos + 22);
+    }
+
+    pub fn i(self: PrimitivesReader) f32 {
+        return rt.readF32(self.buf, self.pos + 30);
+    }
+
+    pub fn j(self: PrimitivesReader) f64 {
+        return rt.readF64(self.buf, self.pos + 34);
+    }
+
+    pub fn k(self: PrimitivesReader) bool {
+        return rt.readBool(self.buf, self.pos + 42);
+    }
+
+    pub fn name(self: PrimitivesReader) []const u8 {
+        return rt.readString(self.buf, self.pos + 43);
+    }
+};
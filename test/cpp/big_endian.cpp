@@ -0,0 +1,44 @@
+#include <cstring>
+#include <iostream>
+
+#include "big_endian.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_big_endian;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+int main() {
+    uint16_t values[] = {0x0102, 0x0304};
+    SampleWriter writer(0x1234, -2, 1.0f, Level::HIGH, "hi", ListWriter<uint16_t>(values, 2));
+
+    uint8_t buffer[64] = {0};
+    CHECK(writer.write(buffer, sizeof(buffer)) > 0);
+
+    // Static data: id, delta, scale, level, the offset of name, and the length and offset of
+    // values. Offsets are relative to where they are written.
+    const uint8_t expected[] = {0x12, 0x34, 0xFF, 0xFF, 0xFF, 0xFE, 0x3F, 0x80, 0x00,
+                                0x00, 0x01, 0x02, 0x00, 0x06, 0x00, 0x02, 0x00, 0x07};
+    CHECK(memcmp(buffer, expected, sizeof(expected)) == 0);
+
+    // The list's data follows the name.
+    const uint8_t list_data[] = {0x01, 0x02, 0x03, 0x04};
+    CHECK(memcmp(buffer + 21, list_data, sizeof(list_data)) == 0);
+
+    SampleReader reader(buffer);
+    CHECK(reader.id() == 0x1234);
+    CHECK(reader.delta() == -2);
+    CHECK(reader.scale() == 1.0f);
+    CHECK(reader.level() == Level::HIGH);
+    CHECK(strcmp(reader.name(), "hi") == 0);
+    CHECK(reader.values().len() == 2);
+    CHECK(reader.values()[1] == 0x0304);
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
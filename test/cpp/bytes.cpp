@@ -0,0 +1,46 @@
+#include <cstring>
+#include <iostream>
+
+#include "bytes.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_bytes;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+int main() {
+    const uint8_t body[] = {1, 0, 2, 0};
+    const uint8_t chunk[] = {0xff, 0xfe};
+    BytesWriter chunks[] = {BytesWriter(chunk, 2), BytesWriter(nullptr, 0)};
+    const uint8_t raw_data[] = {0, 0, 7};
+    BytesWriter raw(raw_data, 3);
+    PacketWriter writer(9, BytesWriter(body, 4), ListWriter<BytesWriter>(chunks, 2),
+                        PacketWriter::PayloadWriter::raw(&raw));
+
+    uint8_t buffer[128] = {0};
+    int32_t written = writer.write(buffer, sizeof(buffer));
+    CHECK(written > 0);
+
+    PacketReader reader(buffer);
+    CHECK(reader.header() == 9);
+    CHECK(reader.body().len() == 4);
+    CHECK(memcmp(reader.body().val(), body, 4) == 0);
+    CHECK(reader.chunks().len() == 2);
+    CHECK(reader.chunks()[0].len() == 2);
+    CHECK(reader.chunks()[0][1] == 0xfe);
+    CHECK(reader.chunks()[1].len() == 0);
+    CHECK(reader.payload().tag() == PacketReader::PayloadReader::Tag::RAW);
+    CHECK(reader.payload().raw().len() == 3);
+    CHECK(reader.payload().raw()[2] == 7);
+
+    // The writer must fail if the bytes do not fit in the buffer.
+    CHECK(writer.write(buffer, 16) < 0);
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
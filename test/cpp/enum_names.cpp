@@ -0,0 +1,35 @@
+#include <cstring>
+#include <iostream>
+
+#include "enum_names.hpp"
+
+using namespace simplebuffers_enum_names;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+int main() {
+    CHECK(std::strcmp(to_string(Level::HIGH), "HIGH") == 0);
+    CHECK(std::strcmp(to_string(Delta::DOWN), "DOWN") == 0);
+    CHECK(to_string(static_cast<Level>(5)) == nullptr);
+
+    CHECK(from_string<Level>("LOW") == Level::LOW);
+    CHECK(from_string<Delta>("UP") == Delta::UP);
+    CHECK(!from_string<Level>("MEDIUM").has_value());
+    CHECK(!from_string<Level>("low").has_value());
+
+    // Names survive a round trip through a message.
+    StatusWriter writer(*from_string<Level>("HIGH"), Delta::DOWN);
+    uint8_t buffer[16] = {0};
+    CHECK(writer.write(buffer, sizeof(buffer)) > 0);
+    StatusReader reader(buffer);
+    CHECK(std::strcmp(to_string(reader.level()), "HIGH") == 0);
+    CHECK(std::strcmp(to_string(reader.delta()), "DOWN") == 0);
+
+    std::cout << to_string(reader.level()) << std::endl;
+    return 0;
+}
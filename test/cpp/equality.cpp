@@ -0,0 +1,64 @@
+#include <cstring>
+#include <iostream>
+
+#include "equality.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_equality;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+/**
+ * Writes a message into `buffer`. The strings are copied first, so that equal messages never share
+ * string pointers.
+ */
+static bool write(uint8_t* buffer, uint16_t size, uint32_t id, const char* title, uint16_t code) {
+    static char title_copy[32];
+    strcpy(title_copy, title);
+    const char* tags[] = {"a", "b"};
+    PointWriter path[] = {PointWriter(1, 2), PointWriter(3, 4)};
+    FixedArrayWriter<uint8_t, 4> corners = {{1, 2, 3, 4}};
+    const uint8_t blob[] = {0, 1, 0};
+    MessageWriter writer(id, title_copy, ListWriter<const char*>(tags, 2), Color::GREEN,
+                         PointWriter(5, 6), ListWriter<PointWriter>(path, 2), corners,
+                         BytesWriter(blob, 3), MessageWriter::BodyWriter::code(&code));
+    return writer.write(buffer, size) > 0;
+}
+
+int main() {
+    uint8_t a[256] = {0};
+    uint8_t b[256] = {0};
+    uint8_t c[256] = {0};
+    uint8_t d[256] = {0};
+    CHECK(write(a, sizeof(a), 1, "hello", 7));
+    CHECK(write(b, sizeof(b), 1, "hello", 7));
+    CHECK(write(c, sizeof(c), 1, "world", 7));
+    CHECK(write(d, sizeof(d), 1, "hello", 8));
+
+    CHECK(MessageReader(a) == MessageReader(b));
+    CHECK(!(MessageReader(a) != MessageReader(b)));
+    CHECK(MessageReader(a) != MessageReader(c));
+    CHECK(MessageReader(a) != MessageReader(d));
+    CHECK(MessageReader(a).body() == MessageReader(c).body());
+    CHECK(MessageReader(a).body() != MessageReader(d).body());
+    CHECK(MessageReader(a).path() == MessageReader(b).path());
+    CHECK(MessageReader(a).blob() == MessageReader(b).blob());
+    CHECK(MessageReader(a).corners() == MessageReader(b).corners());
+
+    // Oneofs holding different fields are never equal.
+    const char* text = "7";
+    MessageWriter other(1, "hello", ListWriter<const char*>(nullptr, 0), Color::RED,
+                        PointWriter(0, 0), ListWriter<PointWriter>(nullptr, 0),
+                        FixedArrayWriter<uint8_t, 4>(), BytesWriter(nullptr, 0),
+                        MessageWriter::BodyWriter::text(&text));
+    CHECK(other.write(c, sizeof(c)) > 0);
+    CHECK(MessageReader(a).body() != MessageReader(c).body());
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
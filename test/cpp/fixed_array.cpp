@@ -0,0 +1,44 @@
+#include <cstring>
+#include <iostream>
+
+#include "fixed_array.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_fixed_array;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+int main() {
+    FixedArrayWriter<uint8_t, 16> id{};
+    for (uint8_t i = 0; i < 16; ++i) id[i] = i * 3;
+    PoseWriter writer(id, FixedArrayWriter<float, 3>{{1.0f, -2.5f, 3.25f}},
+                      FixedArrayWriter<Color, 2>{{Color::BLUE, Color::GREEN}},
+                      FixedArrayWriter<bool, 2>{{false, true}}, "pose");
+    CHECK(writer.static_size() == 16 + 12 + 4 + 2 + 2);
+
+    uint8_t buffer[128] = {0};
+    int32_t written = writer.write(buffer, sizeof(buffer));
+    CHECK(written == 36 + 5);
+
+    PoseReader reader(buffer);
+    CHECK(reader.id().len() == 16);
+    CHECK(reader.id()[15] == 45);
+    CHECK(reader.position()[1] == -2.5f);
+    CHECK(reader.position()[2] == 3.25f);
+    CHECK(reader.colors()[0] == Color::BLUE);
+    CHECK(reader.colors()[1] == Color::GREEN);
+    CHECK(!reader.flags()[0]);
+    CHECK(reader.flags()[1]);
+    CHECK(strcmp(reader.name(), "pose") == 0);
+
+    // The writer must not write past the end of a buffer that only fits part of the array.
+    CHECK(writer.write(buffer, 20) < 0);
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
@@ -0,0 +1,36 @@
+#include <cstring>
+#include <iostream>
+
+#include "forward_ref.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_forward_ref;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+int main() {
+    InnerWriter items[] = {InnerWriter(1, "one"), InnerWriter(2, "two")};
+    OuterWriter writer(7, MiddleWriter(InnerWriter(3, "three"), 1.5f),
+                       ListWriter<InnerWriter>(items, 2));
+
+    uint8_t buffer[256] = {0};
+    int32_t written = writer.write(buffer, sizeof(buffer));
+    CHECK(written > 0);
+
+    OuterReader reader(buffer);
+    CHECK(reader.id() == 7);
+    CHECK(reader.middle().scale() == 1.5f);
+    CHECK(reader.middle().inner().value() == 3);
+    CHECK(strcmp(reader.middle().inner().label(), "three") == 0);
+    CHECK(reader.items().len() == 2);
+    CHECK(reader.items()[1].value() == 2);
+    CHECK(strcmp(reader.items()[1].label(), "two") == 0);
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
@@ -0,0 +1,74 @@
+#include <cstring>
+#include <iostream>
+
+#include "map.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_map;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+int main() {
+    MapEntry<const char*, uint32_t> counts[] = {{"apples", 3}, {"pears", 70000}};
+    MapEntry<uint16_t, PointWriter> points[] = {{7, PointWriter(-1, 2)}, {9, PointWriter(3, -4)}};
+    MapEntry<Color, const char*> colors[] = {{Color::BLUE, "sky"}};
+    MapEntry<const char*, Color> shades[] = {{"grass", Color::GREEN}, {"rose", Color::RED}};
+    uint8_t bits[] = {1, 2, 3};
+    MapEntry<uint8_t, ListWriter<uint8_t>> layer[] = {{5, ListWriter<uint8_t>(bits, 3)}};
+    MapWriter<uint8_t, ListWriter<uint8_t>> layers[] = {
+        MapWriter<uint8_t, ListWriter<uint8_t>>(layer, 1),
+        MapWriter<uint8_t, ListWriter<uint8_t>>(nullptr, 0)};
+    MapEntry<int32_t, int32_t> lookup_entries[] = {{-1, 1}};
+    MapWriter<int32_t, int32_t> lookup(lookup_entries, 1);
+
+    SceneWriter writer(
+        4, MapWriter<const char*, uint32_t>(counts, 2), MapWriter<uint16_t, PointWriter>(points, 2),
+        MapWriter<Color, const char*, uint8_t, const char* const&>(colors, 1),
+        MapWriter<const char*, Color, const char* const&, uint8_t>(shades, 2),
+        ListWriter<MapWriter<uint8_t, ListWriter<uint8_t>>>(layers, 2),
+        SceneWriter::PayloadWriter::lookup(&lookup));
+
+    uint8_t buffer[256] = {0};
+    int32_t written = writer.write(buffer, sizeof(buffer));
+    CHECK(written > 0);
+
+    SceneReader reader(buffer);
+    CHECK(reader.id() == 4);
+
+    CHECK(reader.counts().len() == 2);
+    CHECK(strcmp(reader.counts().key(1), "pears") == 0);
+    CHECK(reader.counts().value(1) == 70000);
+    CHECK(reader.counts().find("apples") == 0);
+    CHECK(reader.counts().contains("pears"));
+    CHECK(!reader.counts().contains("plums"));
+    CHECK(reader.counts().find("plums") == reader.counts().len());
+
+    CHECK(reader.points().len() == 2);
+    CHECK(reader.points().key(1) == 9);
+    CHECK(reader.points().value(0).x() == -1);
+    CHECK(reader.points().value(1).y() == -4);
+
+    CHECK(reader.colors().key(0) == Color::BLUE);
+    CHECK(strcmp(reader.colors().value(0), "sky") == 0);
+    CHECK(reader.shades().value(reader.shades().find("rose")) == Color::RED);
+    CHECK(reader.shades().value(0) == Color::GREEN);
+
+    CHECK(reader.layers().len() == 2);
+    CHECK(reader.layers()[0].key(0) == 5);
+    CHECK(reader.layers()[0].value(0)[2] == 3);
+    CHECK(reader.layers()[1].len() == 0);
+
+    CHECK(reader.payload().tag() == SceneReader::PayloadReader::Tag::LOOKUP);
+    CHECK(reader.payload().lookup().value(0) == 1);
+
+    // The writer must fail if the entries do not fit in the buffer.
+    CHECK(writer.write(buffer, 40) < 0);
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
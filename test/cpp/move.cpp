@@ -0,0 +1,64 @@
+#include <cstring>
+#include <iostream>
+#include <type_traits>
+#include <utility>
+#include <vector>
+
+#include "move.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_move;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+static_assert(std::is_nothrow_move_constructible<ShapeWriter>::value, "");
+static_assert(std::is_nothrow_move_assignable<ShapeWriter>::value, "");
+static_assert(std::is_nothrow_move_constructible<ShapeWriter::StyleWriter>::value, "");
+static_assert(std::is_nothrow_move_constructible<ShapeReader>::value, "");
+static_assert(std::is_nothrow_move_assignable<ShapeReader>::value, "");
+static_assert(std::is_nothrow_move_constructible<ShapeReader::StyleReader>::value, "");
+
+int main() {
+    PointWriter points[] = {PointWriter(1, 2), PointWriter(3, 4)};
+    uint32_t color = 0xFF00FF;
+    const char* pattern = "stripes";
+
+    // Move writers into a container, forcing reallocations along the way.
+    std::vector<ShapeWriter> writers;
+    ShapeWriter first("first", ListWriter<PointWriter>(points, 2),
+                      ShapeWriter::StyleWriter::color(&color));
+    writers.push_back(std::move(first));
+    writers.emplace_back("second", ListWriter<PointWriter>(points, 1),
+                         ShapeWriter::StyleWriter::pattern(&pattern));
+    writers.emplace_back("third", ListWriter<PointWriter>(nullptr, 0),
+                         ShapeWriter::StyleWriter::color(&color));
+
+    ShapeWriter assigned = writers[2];
+    assigned = std::move(writers[1]);
+
+    uint8_t buffer[256] = {0};
+    CHECK(writers[0].write(buffer, sizeof(buffer)) > 0);
+
+    std::vector<ShapeReader> readers;
+    readers.push_back(ShapeReader(buffer));
+    ShapeReader reader = std::move(readers[0]);
+    CHECK(strcmp(reader.name(), "first") == 0);
+    CHECK(reader.points().len() == 2);
+    CHECK(reader.points()[1].y() == 4);
+    CHECK(reader.style().tag() == ShapeReader::StyleReader::Tag::COLOR);
+    CHECK(reader.style().color() == 0xFF00FF);
+
+    CHECK(assigned.write(buffer, sizeof(buffer)) > 0);
+    reader = ShapeReader(buffer);
+    CHECK(strcmp(reader.name(), "second") == 0);
+    CHECK(reader.points().len() == 1);
+    CHECK(strcmp(reader.style().pattern(), "stripes") == 0);
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
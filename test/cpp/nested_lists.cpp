@@ -0,0 +1,112 @@
+#include <cstring>
+#include <iostream>
+
+#include "nested_lists.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_nested_lists;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+int main() {
+    uint16_t numbers0[] = {1, 2};
+    uint16_t numbers1[] = {65535};
+    ListWriter<uint16_t> number_rows[] = {ListWriter<uint16_t>(numbers0, 2),
+                                          ListWriter<uint16_t>(numbers1, 1)};
+
+    const char* words0[] = {"a", "bc"};
+    ListWriter<const char*> word_rows[] = {ListWriter<const char*>(words0, 2),
+                                           ListWriter<const char*>(nullptr, 0)};
+
+    Level levels0[] = {Level::HIGH, Level::LOW};
+    Level levels1[] = {Level::HIGH};
+    ListWriter<Level> level_rows[] = {ListWriter<Level>(levels0, 2), ListWriter<Level>(levels1, 1)};
+
+    PointWriter points0[] = {PointWriter(1, -1), PointWriter(2, -2)};
+    ListWriter<PointWriter> point_rows[] = {ListWriter<PointWriter>(points0, 2)};
+
+    uint8_t id = 7;
+    Level level = Level::HIGH;
+    GridWriter::ChoicesWriter choices[] = {GridWriter::ChoicesWriter::id(&id),
+                                           GridWriter::ChoicesWriter::level(&level)};
+
+    uint32_t count = 100000;
+    const char* name = "cell";
+    GridWriter::CellsWriter cells0[] = {GridWriter::CellsWriter::count(&count)};
+    GridWriter::CellsWriter cells1[] = {GridWriter::CellsWriter::name(&name),
+                                        GridWriter::CellsWriter::count(&count)};
+    ListWriter<GridWriter::CellsWriter> cell_rows[] = {
+        ListWriter<GridWriter::CellsWriter>(cells0, 1),
+        ListWriter<GridWriter::CellsWriter>(cells1, 2)};
+
+    Level deep0[] = {Level::LOW, Level::HIGH, Level::HIGH};
+    ListWriter<Level> deep_rows[] = {ListWriter<Level>(deep0, 3)};
+    ListWriter<ListWriter<Level>> deep_levels(deep_rows, 1);
+
+    GridWriter writer(ListWriter<ListWriter<uint16_t>>(number_rows, 2),
+                      ListWriter<ListWriter<const char*>>(word_rows, 2),
+                      ListWriter<ListWriter<Level>>(level_rows, 2),
+                      ListWriter<ListWriter<PointWriter>>(point_rows, 1),
+                      ListWriter<GridWriter::ChoicesWriter>(choices, 2),
+                      ListWriter<ListWriter<GridWriter::CellsWriter>>(cell_rows, 2),
+                      GridWriter::PickWriter::deep_levels(&deep_levels));
+
+    uint8_t buffer[512] = {0};
+    CHECK(writer.write(buffer, sizeof(buffer)) > 0);
+
+    GridReader reader(buffer);
+    CHECK(reader.numbers().len() == 2);
+    CHECK(reader.numbers()[0].len() == 2);
+    CHECK(reader.numbers()[0][1] == 2);
+    CHECK(reader.numbers()[1][0] == 65535);
+
+    CHECK(reader.words()[0].len() == 2);
+    CHECK(strcmp(reader.words()[0][1], "bc") == 0);
+    CHECK(reader.words()[1].len() == 0);
+
+    CHECK(reader.levels()[0].len() == 2);
+    CHECK(reader.levels()[0][0] == Level::HIGH);
+    CHECK(reader.levels()[0][1] == Level::LOW);
+    CHECK(reader.levels()[1][0] == Level::HIGH);
+
+    CHECK(reader.points()[0].len() == 2);
+    CHECK(reader.points()[0][1].x() == 2);
+    CHECK(reader.points()[0][1].y() == -2);
+
+    CHECK(reader.choices().len() == 2);
+    CHECK(reader.choices()[0].tag() == GridReader::ChoicesReader::Tag::ID);
+    CHECK(reader.choices()[0].id() == 7);
+    CHECK(reader.choices()[1].tag() == GridReader::ChoicesReader::Tag::LEVEL);
+    CHECK(reader.choices()[1].level() == Level::HIGH);
+
+    CHECK(reader.cells().len() == 2);
+    CHECK(reader.cells()[0][0].count() == 100000);
+    CHECK(reader.cells()[1].len() == 2);
+    CHECK(strcmp(reader.cells()[1][0].name(), "cell") == 0);
+    CHECK(reader.cells()[1][1].count() == 100000);
+
+    CHECK(reader.pick().tag() == GridReader::PickReader::Tag::DEEP_LEVELS);
+    CHECK(reader.pick().deep_levels()[0].len() == 3);
+    CHECK(reader.pick().deep_levels()[0][2] == Level::HIGH);
+
+    // The other field of the oneof is a list of oneofs.
+    uint64_t large = 1ull << 40;
+    GridWriter::PickWriter::DeepChoicesWriter deep_choices[] = {
+        GridWriter::PickWriter::DeepChoicesWriter::large(&large)};
+    ListWriter<GridWriter::PickWriter::DeepChoicesWriter> deep_choice_list(deep_choices, 1);
+    writer.pick = GridWriter::PickWriter::deep_choices(&deep_choice_list);
+    CHECK(writer.write(buffer, sizeof(buffer)) > 0);
+
+    GridReader choice_reader(buffer);
+    CHECK(choice_reader.pick().deep_choices().len() == 1);
+    CHECK(choice_reader.pick().deep_choices()[0].large() == (1ull << 40));
+    CHECK(choice_reader.pick().deep_levels().len() == 0);
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
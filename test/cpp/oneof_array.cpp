@@ -0,0 +1,57 @@
+#include <cstring>
+#include <iostream>
+
+#include "oneof_array.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_oneof_array;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+int main() {
+    uint8_t buffer[128] = {0};
+
+    uint32_t numbers[] = {10, 4000000000u, 30};
+    ListWriter<uint32_t> number_list(numbers, 3);
+    InboxWriter number_writer(1, InboxWriter::ContentWriter::numbers(&number_list));
+    CHECK(number_writer.write(buffer, sizeof(buffer)) > 0);
+
+    InboxReader number_reader(buffer);
+    CHECK(number_reader.id() == 1);
+    CHECK(number_reader.content().tag() == InboxReader::ContentReader::Tag::NUMBERS);
+    CHECK(number_reader.content().numbers().len() == 3);
+    CHECK(number_reader.content().numbers()[1] == 4000000000u);
+
+    // Reading a list that the oneof does not hold returns an empty list.
+    CHECK(number_reader.content().words().len() == 0);
+
+    const char* words[] = {"hello", "world"};
+    ListWriter<const char*> word_list(words, 2);
+    InboxWriter word_writer(2, InboxWriter::ContentWriter::words(&word_list));
+    CHECK(word_writer.write(buffer, sizeof(buffer)) > 0);
+
+    InboxReader word_reader(buffer);
+    CHECK(word_reader.content().words().len() == 2);
+    CHECK(strcmp(word_reader.content().words()[1], "world") == 0);
+
+    uint8_t row0[] = {1, 2};
+    uint8_t row1[] = {3, 4, 5};
+    ListWriter<uint8_t> rows[] = {ListWriter<uint8_t>(row0, 2), ListWriter<uint8_t>(row1, 3)};
+    ListWriter<ListWriter<uint8_t>> grid(rows, 2);
+    InboxWriter grid_writer(3, InboxWriter::ContentWriter::grid(&grid));
+    CHECK(grid_writer.write(buffer, sizeof(buffer)) > 0);
+
+    InboxReader grid_reader(buffer);
+    CHECK(grid_reader.content().grid().len() == 2);
+    CHECK(grid_reader.content().grid()[0].len() == 2);
+    CHECK(grid_reader.content().grid()[1].len() == 3);
+    CHECK(grid_reader.content().grid()[1][2] == 5);
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
@@ -0,0 +1,50 @@
+#include <iostream>
+#include <sstream>
+#include <string>
+
+#include "ostream.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_ostream;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+template <typename T>
+static std::string print(const T& val) {
+    std::ostringstream os;
+    os << val;
+    return os.str();
+}
+
+int main() {
+    Level levels[] = {Level::HIGH, Level::LOW};
+    PointWriter trail[] = {PointWriter(1, -1)};
+    FixedArrayWriter<uint16_t, 2> grid = {{300, 4}};
+    const uint8_t raw[] = {0, 255};
+    bool flag = true;
+    auto nested = EventWriter::DetailWriter::NestedWriter::flag(&flag);
+    EventWriter writer(65, -3, true, "say \"hi\"\n", Level::HIGH, ListWriter<Level>(levels, 2),
+                       PointWriter(7, 8), ListWriter<PointWriter>(trail, 1), grid,
+                       BytesWriter(raw, 2), EventWriter::DetailWriter::nested(&nested));
+
+    uint8_t buffer[256] = {0};
+    CHECK(writer.write(buffer, sizeof(buffer)) > 0);
+
+    EventReader reader(buffer);
+    const std::string expected =
+        "{\"id\": 65, \"offset\": -3, \"ok\": true, \"name\": \"say \\\"hi\\\"\\n\", "
+        "\"level\": \"HIGH\", \"levels\": [\"HIGH\", \"LOW\"], \"at\": {\"x\": 7, \"y\": 8}, "
+        "\"trail\": [{\"x\": 1, \"y\": -1}], \"grid\": [300, 4], \"raw\": [0, 255], "
+        "\"detail\": {\"nested\": {\"flag\": true}}}";
+    CHECK(print(reader) == expected);
+    CHECK(print(reader.at()) == "{\"x\": 7, \"y\": 8}");
+    CHECK(print(static_cast<Level>(5)) == "5");
+
+    std::cout << reader << std::endl;
+    return 0;
+}
@@ -0,0 +1,34 @@
+#include <cstring>
+#include <iostream>
+
+#include "reserved.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_reserved;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+int main() {
+    float value = 2.5f;
+    ReservedWriter writer(-4, "escaped", Mode::EXPLICIT_,
+                          ReservedWriter::UnionWriter::float_(&value));
+
+    uint8_t buffer[64] = {0};
+    int32_t written = writer.write(buffer, sizeof(buffer));
+    CHECK(written > 0);
+
+    ReservedReader reader(buffer);
+    CHECK(reader.int_() == -4);
+    CHECK(strcmp(reader.class_(), "escaped") == 0);
+    CHECK(reader.mode() == Mode::EXPLICIT_);
+    CHECK(reader.union_().tag() == ReservedReader::UnionReader::Tag::FLOAT_);
+    CHECK(reader.union_().float_() == 2.5f);
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
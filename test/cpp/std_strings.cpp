@@ -0,0 +1,45 @@
+#include <iostream>
+#include <string>
+#include <string_view>
+#include <type_traits>
+
+#include "std_strings.hpp"
+
+using namespace simplebuffers;
+using namespace simplebuffers_std_strings;
+
+#define CHECK(cond)                                                   \
+    if (!(cond)) {                                                    \
+        std::cerr << "Check failed at line " << __LINE__ << ": " #cond \
+                  << std::endl;                                       \
+        return 1;                                                     \
+    }
+
+static_assert(std::is_same<decltype(PersonReader(nullptr).name()), std::string_view>::value, "");
+
+int main() {
+    std::string nicknames[] = {"Al", "", "Bertie"};
+    std::string email = "albert@example.com";
+    PersonWriter writer(std::string("Albert"), ListWriter<std::string>(nicknames, 3),
+                        PersonWriter::ContactWriter::email(&email));
+
+    uint8_t buffer[128] = {0};
+    CHECK(writer.write(buffer, sizeof(buffer)) > 0);
+
+    PersonReader reader(buffer);
+    CHECK(reader.name() == "Albert");
+    CHECK(reader.name().size() == 6);
+    CHECK(reader.nicknames().len() == 3);
+    CHECK(reader.nicknames()[0] == "Al");
+    CHECK(reader.nicknames()[1].empty());
+    CHECK(reader.nicknames()[2] == "Bertie");
+    CHECK(reader.contact().tag() == PersonReader::ContactReader::Tag::EMAIL);
+    CHECK(reader.contact().email() == "albert@example.com");
+    CHECK(reader.contact().phone() == 0);
+
+    // The writer must fail if the strings do not fit in the buffer.
+    CHECK(writer.write(buffer, 24) < 0);
+
+    std::cout << "All checks passed" << std::endl;
+    return 0;
+}
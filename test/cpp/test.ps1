@@ -0,0 +1,50 @@
+$scriptpath = $MyInvocation.MyCommand.Path
+$dir = Split-Path $scriptpath
+Push-Location $dir\..\..
+
+cargo build
+New-Item -ItemType Directory -Force test\cpp\Generated | Out-Null
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\forward_ref.sb
+.\target\debug\simplebuffers-compiler --relax-reserved --dstdir test\cpp\Generated cpp .\test\cpp\reserved.sb
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\move.sb
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\fixed_array.sb
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\bytes.sb
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\oneof_array.sb
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\std_strings.sb --string-type=std
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\equality.sb --emit-equality
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\ostream.sb --emit-ostream
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\map.sb
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\enum_names.sb --emit-enum-names
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\nested_lists.sb
+.\target\debug\simplebuffers-compiler --dstdir test\cpp\Generated cpp .\test\cpp\big_endian.sb --endianness=big
+Copy-Item .\simplebuffers-cpp\corelib\simplebuffers.hpp .\test\cpp\Generated\
+Push-Location test\cpp
+g++ -std=c++17 -IGenerated -o forward_ref forward_ref.cpp Generated\forward_ref.cpp
+.\forward_ref
+g++ -std=c++17 -IGenerated -o reserved reserved.cpp Generated\reserved.cpp
+.\reserved
+g++ -std=c++17 -IGenerated -o move move.cpp Generated\move.cpp
+.\move
+g++ -std=c++17 -IGenerated -o fixed_array fixed_array.cpp Generated\fixed_array.cpp
+.\fixed_array
+g++ -std=c++17 -IGenerated -o bytes bytes.cpp Generated\bytes.cpp
+.\bytes
+g++ -std=c++17 -IGenerated -o oneof_array oneof_array.cpp Generated\oneof_array.cpp
+.\oneof_array
+g++ -std=c++17 -IGenerated -o std_strings std_strings.cpp Generated\std_strings.cpp
+.\std_strings
+g++ -std=c++17 -IGenerated -o equality equality.cpp Generated\equality.cpp
+.\equality
+g++ -std=c++17 -IGenerated -o ostream ostream.cpp Generated\ostream.cpp
+.\ostream
+g++ -std=c++17 -IGenerated -o map map.cpp Generated\map.cpp
+.\map
+g++ -std=c++17 -IGenerated -o enum_names enum_names.cpp Generated\enum_names.cpp
+.\enum_names
+g++ -std=c++17 -IGenerated -o nested_lists nested_lists.cpp Generated\nested_lists.cpp
+.\nested_lists
+g++ -std=c++17 -IGenerated -o big_endian big_endian.cpp Generated\big_endian.cpp
+.\big_endian
+Pop-Location
+
+Pop-Location
@@ -0,0 +1,49 @@
+// Round-trips a message through the generated C# code. Run `test.ps1` to regenerate the code from
+// `roundtrip.sb` and run this program.
+
+using System;
+using System.Collections.Generic;
+using SimplebuffersRoundtrip;
+
+static void Check(bool condition, string what)
+{
+    if (!condition)
+    {
+        Console.Error.WriteLine($"FAILED: {what}");
+        Environment.Exit(1);
+    }
+}
+
+var entries = new List<MoveToEntryWriter>
+{
+    new MoveToEntryWriter(RobotJoint.J0, 45, 100),
+    new MoveToEntryWriter(RobotJoint.J2, 60, 200),
+};
+var request = new RequestWriter(
+    12,
+    new[] { RobotJoint.J1, RobotJoint.J2 },
+    new RequestWriter.PayloadOneOf.MoveToCase(new MoveToWriter(entries)));
+
+var bytes = request.Encode();
+Console.WriteLine(BitConverter.ToString(bytes));
+
+var reader = new RequestReader(bytes);
+Check(reader.Id == 12, "id");
+Check(reader.Joints.Count == 2, "joint count");
+Check(reader.Joints[1] == RobotJoint.J2, "joint value");
+Check(reader.Payload.Tag == RequestReader.PayloadOneOf.Variant.MoveTo, "payload tag");
+Check(reader.Payload.TryGetMoveTo(out var moveTo), "payload value");
+Check(moveTo.Entries.Count == 2, "entry count");
+Check(moveTo.Entries[1].Angle == 60, "entry angle");
+Check(!reader.Payload.TryGetLabel(out _), "inactive payload");
+
+var labelled = new RequestWriter(
+    1,
+    Array.Empty<RobotJoint>(),
+    new RequestWriter.PayloadOneOf.NestedCase(
+        new RequestWriter.PayloadNestedOneOf.BigBoyCase(BigBoy.OnlyOption)));
+var nestedReader = new RequestReader(labelled.Encode());
+Check(nestedReader.Payload.TryGetNested(out var nested), "nested oneof");
+Check(nested.TryGetBigBoy(out var bigBoy) && bigBoy == BigBoy.OnlyOption, "nested enum");
+
+Console.WriteLine("OK");
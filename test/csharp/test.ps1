@@ -0,0 +1,9 @@
+$scriptpath = $MyInvocation.MyCommand.Path
+$dir = Split-Path $scriptpath
+Push-Location $dir\..\..
+
+cargo build
+.\target\debug\simplebuffers-compiler --dstdir test\csharp\Generated csharp .\test\csharp\roundtrip.sb
+dotnet run --project .\test\csharp\RoundTrip.csproj
+
+Pop-Location
@@ -0,0 +1,89 @@
+// Round-trips messages through the Java code generated for `roundtrip.sb` with
+// `--package roundtrip`. Compile it together with the generated classes (see `test.ps1`); it exits
+// with an error if a check fails.
+
+import java.util.List;
+
+import roundtrip.*;
+
+public final class RoundTrip {
+    private static void check(boolean condition, String message) {
+        if (!condition) {
+            throw new AssertionError(message);
+        }
+    }
+
+    private static void roundTripRequest() {
+        var entries = List.of(
+            new MoveToEntryWriter(RobotJoint.J_1, 1.5f, -2.25),
+            new MoveToEntryWriter(RobotJoint.J_2, 3.0f, 100.0));
+        var request = new RequestWriter(
+            4_000_000_000L,
+            true,
+            List.of(RobotJoint.J_0, RobotJoint.J_2),
+            List.of("hello", "wörld"),
+            List.of(List.of(1, 2, 3), List.of(65535)),
+            new RequestWriter.Payload.MoveToCase(new MoveToWriter(entries)));
+
+        var reader = new RequestReader(request.encode());
+        check(reader.id() == 4_000_000_000L, "id");
+        check(reader.enabled(), "enabled");
+        check(reader.joints().equals(List.of(RobotJoint.J_0, RobotJoint.J_2)), "joints");
+        check(reader.tags().equals(List.of("hello", "wörld")), "tags");
+        check(reader.matrix().size() == 2, "matrix size");
+        check(reader.matrix().get(0).equals(List.of(1, 2, 3)), "matrix row 0");
+        check(reader.matrix().get(1).equals(List.of(65535)), "matrix row 1");
+
+        if (!(reader.payload() instanceof RequestReader.Payload.MoveToCase moveTo)) {
+            throw new AssertionError("payload is not moveTo");
+        }
+        var readEntries = moveTo.value().entries();
+        check(readEntries.size() == 2, "entries size");
+        check(readEntries.get(0).joint() == RobotJoint.J_1, "entry 0 joint");
+        check(readEntries.get(0).angle() == 1.5f, "entry 0 angle");
+        check(readEntries.get(0).speed() == -2.25, "entry 0 speed");
+        check(readEntries.get(1).joint() == RobotJoint.J_2, "entry 1 joint");
+        check(readEntries.get(1).speed() == 100.0, "entry 1 speed");
+    }
+
+    private static void roundTripNestedOneofs() {
+        var count = new RequestWriter(7, false, List.of(), List.of(), List.of(),
+            new RequestWriter.Payload.NestedCase(new RequestWriter.PayloadNested.CountCase(-123456789L)));
+        var reader = new RequestReader(count.encode());
+        check(reader.id() == 7, "id");
+        check(!reader.enabled(), "enabled");
+        check(reader.joints().isEmpty(), "joints");
+        if (!(reader.payload() instanceof RequestReader.Payload.NestedCase nested)
+            || !(nested.value() instanceof RequestReader.PayloadNested.CountCase value)) {
+            throw new AssertionError("payload is not nested.count");
+        }
+        check(value.value() == -123456789L, "count");
+
+        var bigBoy = new RequestWriter(8, true, List.of(), List.of(), List.of(),
+            new RequestWriter.Payload.NestedCase(
+                new RequestWriter.PayloadNested.BigBoyCase(BigBoy.ONLY_OPTION)));
+        reader = new RequestReader(bigBoy.encode());
+        if (!(reader.payload() instanceof RequestReader.Payload.NestedCase nestedBigBoy)
+            || !(nestedBigBoy.value() instanceof RequestReader.PayloadNested.BigBoyCase big)) {
+            throw new AssertionError("payload is not nested.bigBoy");
+        }
+        check(big.value() == BigBoy.ONLY_OPTION, "bigBoy");
+    }
+
+    private static void roundTripLabel() {
+        var request = new RequestWriter(9, false, List.of(), List.of(), List.of(),
+            new RequestWriter.Payload.LabelCase("a label"));
+        var reader = new RequestReader(request.encode());
+        if (!(reader.payload() instanceof RequestReader.Payload.LabelCase label)) {
+            throw new AssertionError("payload is not label");
+        }
+        check(label.value().equals("a label"), "label");
+    }
+
+    public static void main(String[] args) {
+        roundTripRequest();
+        roundTripNestedOneofs();
+        roundTripLabel();
+        System.out.println("All Java round-trip tests passed");
+    }
+}
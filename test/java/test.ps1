@@ -0,0 +1,10 @@
+$scriptpath = $MyInvocation.MyCommand.Path
+$dir = Split-Path $scriptpath
+Push-Location $dir\..\..
+
+cargo build
+.\target\debug\simplebuffers-compiler --dstdir test\java\Generated java .\test\java\roundtrip.sb --package roundtrip
+javac -encoding UTF-8 -d test\java\Generated\classes (Get-ChildItem -Recurse test\java\Generated -Filter *.java).FullName .\test\java\RoundTrip.java
+java -cp test\java\Generated\classes RoundTrip
+
+Pop-Location
@@ -0,0 +1,80 @@
+// Round-trips messages through the Kotlin code generated for `roundtrip.sb` with
+// `--package roundtrip`. Compile it together with the generated files (see `test.ps1`); it exits
+// with an error if a check fails.
+
+import roundtrip.*
+
+fun roundTripRequest() {
+    val entries = listOf(
+        MoveToEntryWriter(RobotJoint.J_1, 1.5f, -2.25),
+        MoveToEntryWriter(RobotJoint.J_2, 3.0f, 100.0),
+    )
+    val request = RequestWriter(
+        id = 4_000_000_000u,
+        enabled = true,
+        joints = listOf(RobotJoint.J_0, RobotJoint.J_2),
+        tags = listOf("hello", "wörld"),
+        matrix = listOf(listOf<UShort>(1u, 2u, 3u), listOf<UShort>(65535u)),
+        payload = RequestWriter.Payload.MoveToCase(MoveToWriter(entries)),
+    )
+
+    val reader = RequestReader(request.encode())
+    check(reader.id == 4_000_000_000u) { "id" }
+    check(reader.enabled) { "enabled" }
+    check(reader.joints == listOf(RobotJoint.J_0, RobotJoint.J_2)) { "joints" }
+    check(reader.tags == listOf("hello", "wörld")) { "tags" }
+    check(reader.matrix.size == 2) { "matrix size" }
+    check(reader.matrix[0] == listOf<UShort>(1u, 2u, 3u)) { "matrix row 0" }
+    check(reader.matrix[1] == listOf<UShort>(65535u)) { "matrix row 1" }
+
+    val payload = reader.payload
+    check(payload is RequestReader.Payload.MoveToCase) { "payload is not moveTo" }
+    val readEntries = payload.value.entries
+    check(readEntries.size == 2) { "entries size" }
+    check(readEntries[0].joint == RobotJoint.J_1) { "entry 0 joint" }
+    check(readEntries[0].angle == 1.5f) { "entry 0 angle" }
+    check(readEntries[0].speed == -2.25) { "entry 0 speed" }
+    check(readEntries[1].joint == RobotJoint.J_2) { "entry 1 joint" }
+    check(readEntries[1].speed == 100.0) { "entry 1 speed" }
+}
+
+fun roundTripNestedOneofs() {
+    val count = RequestWriter(
+        7u, false, listOf(), listOf(), listOf(),
+        RequestWriter.Payload.NestedCase(RequestWriter.PayloadNested.CountCase(-123456789L)),
+    )
+    var reader = RequestReader(count.encode())
+    check(reader.id == 7u) { "id" }
+    check(!reader.enabled) { "enabled" }
+    check(reader.joints.isEmpty()) { "joints" }
+    val payload = reader.payload
+    check(payload is RequestReader.Payload.NestedCase) { "payload is not nested" }
+    check(payload.value == RequestReader.PayloadNested.CountCase(-123456789L)) { "count" }
+
+    val bigBoy = RequestWriter(
+        8u, true, listOf(), listOf(), listOf(),
+        RequestWriter.Payload.NestedCase(RequestWriter.PayloadNested.BigBoyCase(BigBoy.ONLY_OPTION)),
+    )
+    reader = RequestReader(bigBoy.encode())
+    check(
+        reader.payload == RequestReader.Payload.NestedCase(
+            RequestReader.PayloadNested.BigBoyCase(BigBoy.ONLY_OPTION),
+        ),
+    ) { "bigBoy" }
+}
+
+fun roundTripLabel() {
+    val request = RequestWriter(
+        9u, false, listOf(), listOf(), listOf(),
+        RequestWriter.Payload.LabelCase("a label"),
+    )
+    val reader = RequestReader(request.encode())
+    check(reader.payload == RequestReader.Payload.LabelCase("a label")) { "label" }
+}
+
+fun main() {
+    roundTripRequest()
+    roundTripNestedOneofs()
+    roundTripLabel()
+    println("All Kotlin round-trip tests passed")
+}
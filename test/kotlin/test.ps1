@@ -0,0 +1,10 @@
+$scriptpath = $MyInvocation.MyCommand.Path
+$dir = Split-Path $scriptpath
+Push-Location $dir\..\..
+
+cargo build
+.\target\debug\simplebuffers-compiler --dstdir test\kotlin\Generated kotlin .\test\kotlin\roundtrip.sb --package roundtrip
+kotlinc (Get-ChildItem -Recurse test\kotlin\Generated -Filter *.kt).FullName .\test\kotlin\RoundTrip.kt -include-runtime -d test\kotlin\Generated\roundtrip.jar
+java -cp test\kotlin\Generated\roundtrip.jar RoundTripKt
+
+Pop-Location
@@ -0,0 +1,13 @@
+$scriptpath = $MyInvocation.MyCommand.Path
+$dir = Split-Path $scriptpath
+Push-Location $dir\..\..
+
+cargo build
+.\target\debug\simplebuffers-compiler --dstdir test\python\Generated python .\test\python\roundtrip.sb
+Copy-Item .\test\python\roundtrip.sb .\test\python\Generated\roundtrip_ctypes.sb
+.\target\debug\simplebuffers-compiler --dstdir test\python\Generated python .\test\python\Generated\roundtrip_ctypes.sb --mode ctypes
+Push-Location test\python
+python -m unittest test_roundtrip
+Pop-Location
+
+Pop-Location
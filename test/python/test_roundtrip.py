@@ -0,0 +1,89 @@
+import ctypes
+import unittest
+
+import Generated.roundtrip as sb
+import Generated.roundtrip_ctypes as sbc
+
+
+def make_request(payload):
+    return sb.RequestWriter(
+        id=42,
+        enabled=True,
+        joints=[sb.RobotJoint.J_0, sb.RobotJoint.J_2],
+        tags=["hello", "wörld"],
+        matrix=[[1, 2, 3], [65535]],
+        payload=payload,
+    )
+
+
+ENTRIES = [
+    sb.MoveToEntryWriter(sb.RobotJoint.J_1, 1.5, -2.25),
+    sb.MoveToEntryWriter(sb.RobotJoint.J_2, 3.0, 100.0),
+]
+
+
+class PureRoundTrip(unittest.TestCase):
+    def test_fields(self):
+        data = make_request(
+            sb.RequestWriter.PayloadMoveToCase(sb.MoveToWriter(ENTRIES))
+        ).encode()
+        request = sb.RequestReader(data)
+
+        self.assertEqual(request.id, 42)
+        self.assertTrue(request.enabled)
+        self.assertEqual(list(request.joints), [sb.RobotJoint.J_0, sb.RobotJoint.J_2])
+        self.assertEqual(list(request.tags), ["hello", "wörld"])
+        self.assertEqual([list(row) for row in request.matrix], [[1, 2, 3], [65535]])
+
+        payload = request.payload
+        self.assertIsInstance(payload, sb.RequestReader.PayloadMoveToCase)
+        entries = payload.value.entries
+        self.assertEqual(len(entries), 2)
+        self.assertEqual(entries[0].joint, sb.RobotJoint.J_1)
+        self.assertEqual(entries[0].angle, 1.5)
+        self.assertEqual(entries[-1].speed, 100.0)
+
+    def test_nested_oneof(self):
+        nested = sb.RequestWriter.PayloadNestedCase(
+            sb.RequestWriter.PayloadNestedCountCase(-123456789)
+        )
+        request = sb.RequestReader(make_request(nested).encode())
+
+        payload = request.payload
+        self.assertIsInstance(payload, sb.RequestReader.PayloadNestedCase)
+        self.assertEqual(payload.value, sb.RequestReader.PayloadNestedCountCase(-123456789))
+
+    def test_unknown_tag(self):
+        data = bytearray(make_request(sb.RequestWriter.PayloadLabelCase("x")).encode())
+        data[17] = 200
+        self.assertIsNone(sb.RequestReader(bytes(data)).payload)
+
+
+class CtypesRoundTrip(unittest.TestCase):
+    def test_views(self):
+        data = bytearray(
+            make_request(sb.RequestWriter.PayloadMoveToCase(sb.MoveToWriter(ENTRIES))).encode()
+        )
+        self.assertEqual(ctypes.sizeof(sbc.Request), sb.RequestReader.STATIC_SIZE)
+
+        request = sbc.Request.from_buffer(data)
+        self.assertEqual(request.id, 42)
+        self.assertTrue(request.enabled)
+        self.assertEqual(request.joints.len, 2)
+        self.assertEqual(list(sbc.Request.read_joints(data)), [0, 2])
+        self.assertEqual(sbc.Request.read_tags(data), ["hello", "wörld"])
+        self.assertEqual([list(row) for row in sbc.Request.read_matrix(data)], [[1, 2, 3], [65535]])
+
+        name, move_to = sbc.Request.read_payload(data)
+        self.assertEqual(name, "move_to")
+        entries = sbc.MoveTo.read_entries(data, ctypes.addressof(move_to) - ctypes.addressof(request))
+        self.assertEqual(entries[0].joint, sbc.RobotJoint.J_1)
+        self.assertEqual(entries[1].speed, 100.0)
+
+        # Views do not copy the buffer.
+        entries[0].angle = 4.0
+        self.assertEqual(sb.RequestReader(bytes(data)).payload.value.entries[0].angle, 4.0)
+
+
+if __name__ == "__main__":
+    unittest.main()
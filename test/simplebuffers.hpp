@@ -87,7 +87,7 @@ class SimpleBufferWriter {
      *
      * @return The static size of the object.
      */
-    virtual uint16_t static_size() const = 0;
+    virtual uint16_t static_size() const noexcept = 0;
 
     /**
      * @brief Writes a component to the destination buffer.
@@ -167,7 +167,7 @@ inline uint16_t get_static_size(const float val) { return 4; }
 inline uint16_t get_static_size(const double val) { return 8; }
 inline uint16_t get_static_size(const bool val) { return 1; }
 inline uint16_t get_static_size(const SimpleBufferWriter& val) { return val.static_size(); }
-inline uint16_t get_static_size(char* const& val) { return 2; }
+inline uint16_t get_static_size(const char* const& val) { return 2; }
 
 //                                                                                                //
 // ======================================== Write field ========================================= //
@@ -346,7 +346,7 @@ inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn
 }
 
 inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
-                            char* const& val) {
+                            const char* const& val) {
     uint16_t str_len = strlen(val);
     if (dyn_cursor + str_len + 1 > dest_end) return nullptr;  // +1 for null terminator
 
@@ -366,6 +366,31 @@ inline uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn
     return val.write_component(dest, dest_end, dyn_cursor);
 }
 
+/**
+ * @brief Writes a nested sequence field to the destination buffer.
+ *
+ * The sequence's static data is placed in the dynamic section of the buffer, and a 16-bit offset
+ * to it is written to `dest`.
+ *
+ * @param[out] dest The destination to write static data to.
+ * @param[in] dest_end The end of the destination buffer.
+ * @param[out] dyn_cursor The dynamic cursor for writing variable-length fields.
+ * @param[in] val The sequence to write.
+ * @return A pointer to the end of the dynamic data written to the buffer, or `nullptr` if the
+ *         buffer was too small.
+ */
+inline uint8_t* write_sequence_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
+                                     const SimpleBufferWriter& val) {
+    uint16_t static_size = val.static_size();
+    if (dyn_cursor + static_size > dest_end) return nullptr;
+
+    // Write the offset to the static section of the buffer.
+    uint16_t offset = dyn_cursor - dest;
+    write_field(dest, dest_end, dyn_cursor, offset);
+
+    return val.write_component(dyn_cursor, dest_end, dyn_cursor + static_size);
+}
+
 /**
  * @brief Writes a OneOf field to the destination buffer.
  *
@@ -414,9 +439,9 @@ class ListWriter {
      * @param val Pointer to the array.
      * @param len Length of the array.
      */
-    ListWriter(T* const val, uint16_t len) : val(val), len(len) {}
+    ListWriter(T* val, uint16_t len) : val(val), len(len) {}
 
-    T* const val;
+    T* val;
     uint16_t len;
 };
 
@@ -442,7 +467,7 @@ class ListWriterImpl : public SimpleBufferWriter {
      */
     ListWriterImpl(T* const val, uint16_t len) : val_(val), len_(len) {}
 
-    uint16_t static_size() const override { return 4; }
+    uint16_t static_size() const noexcept override { return 4; }
 
     uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end,
                              uint8_t* dyn_cursor = nullptr) const override {
@@ -530,6 +555,37 @@ inline uint8_t* ListWriterImpl<int8_t>::write_data_(uint8_t* dest, const uint8_t
 
 }  // namespace priv
 
+/**
+ * @brief Returns the static size of a nested list, which is always 4 bytes: 2 bytes for the length
+ *        and 2 bytes for the offset.
+ *
+ * @tparam T The type of the array elements.
+ * @param val The list to calculate the static size for.
+ * @return The static size of the list (4).
+ */
+template <typename T>
+uint16_t get_static_size(const ListWriter<T>& val) {
+    return 4;
+}
+
+/**
+ * @brief Writes a nested list (a list that is an element of another list) to the destination
+ *        buffer.
+ *
+ * @tparam T The type of the array elements.
+ * @param[out] dest The destination to write static data to.
+ * @param[in] dest_end The end of the destination buffer.
+ * @param[out] dyn_cursor The dynamic cursor for writing variable-length fields.
+ * @param[in] val The list to write.
+ * @return A pointer to the end of the dynamic data written to the buffer, or `nullptr` if the
+ *         buffer was too small.
+ */
+template <typename T>
+uint8_t* write_field(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor,
+                     const ListWriter<T>& val) {
+    return priv::ListWriterImpl<T>(val.val, val.len).write_component(dest, dest_end, dyn_cursor);
+}
+
 //                                                                                                //
 // ======================================== OneOfWriter ========================================= //
 //                                                                                                //
@@ -546,7 +602,7 @@ class OneOfWriter : public SimpleBufferWriter {
      *
      * @return The static size of the OneOf structure (3);
      */
-    uint16_t static_size() const override { return 3; }
+    uint16_t static_size() const noexcept override { return 3; }
 };
 
 //                                                                                                //
@@ -569,7 +625,7 @@ class SimpleBufferReader {
      *
      * @return Pointer to the component in the data buffer.
      */
-    const uint8_t* data() const { return data_ptr_; }
+    const uint8_t* data() const noexcept { return data_ptr_; }
 
     /**
      * @brief Returns the static size of the object.
@@ -581,7 +637,7 @@ class SimpleBufferReader {
      *
      * @return The static size of the object.
      */
-    virtual uint16_t static_size() const = 0;
+    virtual uint16_t static_size() const noexcept = 0;
 
    protected:
     const uint8_t* data_ptr_;
@@ -871,7 +927,7 @@ class ListReader : public SimpleBufferReader {
      *
      * @return The length of the array.
      */
-    uint16_t len() const { return array_len_; }
+    uint16_t len() const noexcept { return array_len_; }
 
     /**
      * Read the value at index `idx` from the array.
@@ -905,7 +961,7 @@ class ListReader : public SimpleBufferReader {
      *
      * @return The static size of the object.
      */
-    uint16_t static_size() const override { return 4; }
+    uint16_t static_size() const noexcept override { return 4; }
 
    protected:
     uint16_t array_len_;
@@ -941,7 +997,7 @@ class OneOfReader : public SimpleBufferReader {
      *
      * @return The static size of the object.
      */
-    uint16_t static_size() const override { return 4; }
+    uint16_t static_size() const noexcept override { return 4; }
 
    protected:
     const uint8_t* val_ptr_;
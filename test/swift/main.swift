@@ -0,0 +1,94 @@
+// Round-trips messages through the Swift code generated for `roundtrip.sb`. Compile it together
+// with the generated `roundtrip.swift` (see `test.ps1`); it exits with an error if a check fails.
+
+typealias SB = SimplebuffersRoundtrip
+
+func roundTripRequest() {
+    let entries = [
+        SB.MoveToEntryWriter(joint: .j1, angle: 1.5, speed: -2.25),
+        SB.MoveToEntryWriter(joint: .j2, angle: 3.0, speed: 100.0),
+    ]
+    let request = SB.RequestWriter(
+        id: 42,
+        enabled: true,
+        joints: [.j0, .j2],
+        tags: ["hello", "wörld"],
+        matrix: [[1, 2, 3], [65535]],
+        payload: .moveTo(SB.MoveToWriter(entries: entries))
+    )
+
+    request.encode().withUnsafeBytes { buffer in
+        let reader = SB.RequestReader(buffer)
+        precondition(reader.id == 42)
+        precondition(reader.enabled)
+
+        let joints = reader.joints
+        precondition(joints.count == 2)
+        precondition(joints[0] == .j0)
+        precondition(joints[1] == .j2)
+
+        precondition(Array(reader.tags) == ["hello", "wörld"])
+
+        let matrix = reader.matrix
+        precondition(matrix.count == 2)
+        precondition(Array(matrix[0]) == [1, 2, 3])
+        precondition(Array(matrix[1]) == [65535])
+
+        guard case .moveTo(let moveTo)? = reader.payload else {
+            preconditionFailure("payload is not moveTo")
+        }
+        let readEntries = moveTo.entries
+        precondition(readEntries.count == 2)
+        precondition(readEntries[0].joint == .j1)
+        precondition(readEntries[0].angle == 1.5)
+        precondition(readEntries[0].speed == -2.25)
+        precondition(readEntries[1].joint == .j2)
+        precondition(readEntries[1].speed == 100.0)
+    }
+}
+
+func roundTripNestedOneofs() {
+    let count = SB.RequestWriter(
+        id: 7, enabled: false, joints: [], tags: [], matrix: [],
+        payload: .nested(.count(-123456789))
+    )
+    count.encode().withUnsafeBytes { buffer in
+        let reader = SB.RequestReader(buffer)
+        precondition(reader.id == 7)
+        precondition(!reader.enabled)
+        precondition(reader.joints.isEmpty)
+        guard case .nested(.count(let value)?)? = reader.payload else {
+            preconditionFailure("payload is not nested.count")
+        }
+        precondition(value == -123456789)
+    }
+
+    let bigBoy = SB.RequestWriter(
+        id: 8, enabled: true, joints: [], tags: [], matrix: [],
+        payload: .nested(.bigBoy(.onlyOption))
+    )
+    bigBoy.encode().withUnsafeBytes { buffer in
+        guard case .nested(.bigBoy(let value)?)? = SB.RequestReader(buffer).payload else {
+            preconditionFailure("payload is not nested.bigBoy")
+        }
+        precondition(value == .onlyOption)
+    }
+}
+
+func roundTripLabel() {
+    let request = SB.RequestWriter(
+        id: 9, enabled: false, joints: [], tags: [], matrix: [],
+        payload: .label("a label")
+    )
+    request.encode().withUnsafeBytes { buffer in
+        guard case .label(let label)? = SB.RequestReader(buffer).payload else {
+            preconditionFailure("payload is not label")
+        }
+        precondition(label == "a label")
+    }
+}
+
+roundTripRequest()
+roundTripNestedOneofs()
+roundTripLabel()
+print("All Swift round-trip tests passed")
@@ -0,0 +1,10 @@
+$scriptpath = $MyInvocation.MyCommand.Path
+$dir = Split-Path $scriptpath
+Push-Location $dir\..\..
+
+cargo build
+.\target\debug\simplebuffers-compiler --dstdir test\swift\Generated swift .\test\swift\roundtrip.sb
+swiftc -o test\swift\Generated\roundtrip .\test\swift\Generated\roundtrip.swift .\test\swift\main.swift
+.\test\swift\Generated\roundtrip
+
+Pop-Location
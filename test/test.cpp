@@ -9,17 +9,15 @@ namespace simplebuffers_test {
 RequestWriter::RequestWriter(uint32_t id, simplebuffers::ListWriter<RobotJoint> enm_array, PayloadWriter payload):
     id(id), enm_array(enm_array), payload(payload) {}
 
-uint16_t RequestWriter::static_size() const { return 11; }
+uint16_t RequestWriter::static_size() const noexcept { return 11; }
 
 uint8_t* RequestWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
     if (dest_end - dest < 11) return nullptr;
-    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, id);
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, id);
     if (dyn_cursor == nullptr) return nullptr;
-    dest += simplebuffers::get_static_size(id);
-    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, simplebuffers::priv::ListWriterImpl<uint8_t>(reinterpret_cast<uint8_t* const>(enm_array.val), enm_array.len));
+    dyn_cursor = simplebuffers::write_field(dest + 4, dest_end, dyn_cursor, simplebuffers::priv::ListWriterImpl<uint8_t>(reinterpret_cast<uint8_t* const>(enm_array.val), enm_array.len));
     if (dyn_cursor == nullptr) return nullptr;
-    dest += simplebuffers::get_static_size(simplebuffers::priv::ListWriterImpl<uint8_t>(reinterpret_cast<uint8_t* const>(enm_array.val), enm_array.len));
-    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, payload);
+    dyn_cursor = simplebuffers::write_field(dest + 8, dest_end, dyn_cursor, payload);
     if (dyn_cursor == nullptr) return nullptr;
     return dyn_cursor;
 }
@@ -90,7 +88,7 @@ uint8_t* RequestWriter::PayloadWriter::TestOneOfWriter::write_component(uint8_t*
         case Tag::BIG_BOY:
             return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 1, static_cast<uint32_t>(*value_.big_boy));
         case Tag::STRING_TEST:
-            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 6, *value_.string_test);
+            return simplebuffers::write_oneof_field(dest, dest_end, dyn_cursor, 2, *value_.string_test);
         default:
             return nullptr;
     }
@@ -105,11 +103,11 @@ RequestWriter::PayloadWriter::TestOneOfWriter::TestOneOfWriter(Tag tag, Value va
 InitWriter::InitWriter(uint32_t expected_firmware):
     expected_firmware(expected_firmware) {}
 
-uint16_t InitWriter::static_size() const { return 4; }
+uint16_t InitWriter::static_size() const noexcept { return 4; }
 
 uint8_t* InitWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
     if (dest_end - dest < 4) return nullptr;
-    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, expected_firmware);
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, expected_firmware);
     if (dyn_cursor == nullptr) return nullptr;
     return dyn_cursor;
 }
@@ -121,11 +119,11 @@ uint8_t* InitWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uin
 MoveToWriter::MoveToWriter(simplebuffers::ListWriter<MoveToEntryWriter> joints):
     joints(joints) {}
 
-uint16_t MoveToWriter::static_size() const { return 4; }
+uint16_t MoveToWriter::static_size() const noexcept { return 4; }
 
 uint8_t* MoveToWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
     if (dest_end - dest < 4) return nullptr;
-    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, simplebuffers::priv::ListWriterImpl<MoveToEntryWriter>(joints.val, joints.len));
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, simplebuffers::priv::ListWriterImpl<MoveToEntryWriter>(joints.val, joints.len));
     if (dyn_cursor == nullptr) return nullptr;
     return dyn_cursor;
 }
@@ -137,17 +135,15 @@ uint8_t* MoveToWriter::write_component(uint8_t* dest, const uint8_t* dest_end, u
 MoveToEntryWriter::MoveToEntryWriter(RobotJoint joint, float angle, float speed):
     joint(joint), angle(angle), speed(speed) {}
 
-uint16_t MoveToEntryWriter::static_size() const { return 9; }
+uint16_t MoveToEntryWriter::static_size() const noexcept { return 9; }
 
 uint8_t* MoveToEntryWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
     if (dest_end - dest < 9) return nullptr;
-    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, static_cast<uint8_t>(joint));
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, static_cast<uint8_t>(joint));
     if (dyn_cursor == nullptr) return nullptr;
-    dest += simplebuffers::get_static_size(static_cast<uint8_t>(joint));
-    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, angle);
+    dyn_cursor = simplebuffers::write_field(dest + 1, dest_end, dyn_cursor, angle);
     if (dyn_cursor == nullptr) return nullptr;
-    dest += simplebuffers::get_static_size(angle);
-    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, speed);
+    dyn_cursor = simplebuffers::write_field(dest + 5, dest_end, dyn_cursor, speed);
     if (dyn_cursor == nullptr) return nullptr;
     return dyn_cursor;
 }
@@ -159,11 +155,11 @@ uint8_t* MoveToEntryWriter::write_component(uint8_t* dest, const uint8_t* dest_e
 StringTestWriter::StringTestWriter(FieldsWriter fields):
     fields(fields) {}
 
-uint16_t StringTestWriter::static_size() const { return 3; }
+uint16_t StringTestWriter::static_size() const noexcept { return 3; }
 
 uint8_t* StringTestWriter::write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const {
     if (dest_end - dest < 3) return nullptr;
-    dyn_cursor = simplebuffers::write_field(dest, dest_end, dyn_cursor, fields);
+    dyn_cursor = simplebuffers::write_field(dest + 0, dest_end, dyn_cursor, fields);
     if (dyn_cursor == nullptr) return nullptr;
     return dyn_cursor;
 }
@@ -204,15 +200,15 @@ StringTestWriter::FieldsWriter::FieldsWriter(Tag tag, Value value) : tag_(tag),
 
 RequestReader::RequestReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 11 * idx) {}
 
-uint16_t RequestReader::static_size() const { return 11; }
+uint16_t RequestReader::static_size() const noexcept { return 11; }
 
-uint32_t RequestReader::id() const {
+uint32_t RequestReader::id() const noexcept {
     return simplebuffers::read_field<uint32_t>(data_ptr_ + 0);
 }
-simplebuffers::ListReader<RobotJoint, uint8_t> RequestReader::enm_array() const {
+simplebuffers::ListReader<RobotJoint, uint8_t> RequestReader::enm_array() const noexcept {
     return simplebuffers::ListReader<RobotJoint, uint8_t>(static_cast<const uint8_t*>(data_ptr_ + 4), 0);
 }
-RequestReader::PayloadReader RequestReader::payload() const {
+RequestReader::PayloadReader RequestReader::payload() const noexcept {
     return PayloadReader(static_cast<const uint8_t*>(data_ptr_ + 8), 0);
 }
 
@@ -226,19 +222,19 @@ RequestReader::PayloadReader::PayloadReader(const uint8_t* data_ptr, size_t idx)
     val_ptr_ = data_ptr + offset;
 }
 
-RequestReader::PayloadReader::Tag RequestReader::PayloadReader::tag() const {
+RequestReader::PayloadReader::Tag RequestReader::PayloadReader::tag() const noexcept {
     return tag_;
 }
 
-InitReader RequestReader::PayloadReader::init() const {
+InitReader RequestReader::PayloadReader::init() const noexcept {
     return InitReader(val_ptr_, 0);
 }
 
-MoveToReader RequestReader::PayloadReader::move_to() const {
+MoveToReader RequestReader::PayloadReader::move_to() const noexcept {
     return MoveToReader(val_ptr_, 0);
 }
 
-RequestReader::PayloadReader::TestOneOfReader RequestReader::PayloadReader::test_one_of() const {
+RequestReader::PayloadReader::TestOneOfReader RequestReader::PayloadReader::test_one_of() const noexcept {
     if (tag_ != Tag::TEST_ONE_OF) return TestOneOfReader(nullptr, 0);
     return TestOneOfReader(val_ptr_, 0);
 }
@@ -253,20 +249,20 @@ RequestReader::PayloadReader::TestOneOfReader::TestOneOfReader(const uint8_t* da
     val_ptr_ = data_ptr + offset;
 }
 
-RequestReader::PayloadReader::TestOneOfReader::Tag RequestReader::PayloadReader::TestOneOfReader::tag() const {
+RequestReader::PayloadReader::TestOneOfReader::Tag RequestReader::PayloadReader::TestOneOfReader::tag() const noexcept {
     return tag_;
 }
 
-MoveToEntryReader RequestReader::PayloadReader::TestOneOfReader::move_to_entry() const {
+MoveToEntryReader RequestReader::PayloadReader::TestOneOfReader::move_to_entry() const noexcept {
     return MoveToEntryReader(val_ptr_, 0);
 }
 
-BigBoy RequestReader::PayloadReader::TestOneOfReader::big_boy() const {
+BigBoy RequestReader::PayloadReader::TestOneOfReader::big_boy() const noexcept {
     if (tag_ != Tag::BIG_BOY) return static_cast<BigBoy>(0);
     return static_cast<BigBoy>(simplebuffers::read_field<uint32_t>(val_ptr_));
 }
 
-StringTestReader RequestReader::PayloadReader::TestOneOfReader::string_test() const {
+StringTestReader RequestReader::PayloadReader::TestOneOfReader::string_test() const noexcept {
     return StringTestReader(val_ptr_, 0);
 }
 
@@ -276,9 +272,9 @@ StringTestReader RequestReader::PayloadReader::TestOneOfReader::string_test() co
 
 InitReader::InitReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 4 * idx) {}
 
-uint16_t InitReader::static_size() const { return 4; }
+uint16_t InitReader::static_size() const noexcept { return 4; }
 
-uint32_t InitReader::expected_firmware() const {
+uint32_t InitReader::expected_firmware() const noexcept {
     return simplebuffers::read_field<uint32_t>(data_ptr_ + 0);
 }
 
@@ -288,9 +284,9 @@ uint32_t InitReader::expected_firmware() const {
 
 MoveToReader::MoveToReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 4 * idx) {}
 
-uint16_t MoveToReader::static_size() const { return 4; }
+uint16_t MoveToReader::static_size() const noexcept { return 4; }
 
-simplebuffers::ListReader<MoveToEntryReader> MoveToReader::joints() const {
+simplebuffers::ListReader<MoveToEntryReader> MoveToReader::joints() const noexcept {
     return simplebuffers::ListReader<MoveToEntryReader>(static_cast<const uint8_t*>(data_ptr_ + 0), 0);
 }
 
@@ -300,15 +296,15 @@ simplebuffers::ListReader<MoveToEntryReader> MoveToReader::joints() const {
 
 MoveToEntryReader::MoveToEntryReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 9 * idx) {}
 
-uint16_t MoveToEntryReader::static_size() const { return 9; }
+uint16_t MoveToEntryReader::static_size() const noexcept { return 9; }
 
-RobotJoint MoveToEntryReader::joint() const {
+RobotJoint MoveToEntryReader::joint() const noexcept {
     return static_cast<RobotJoint>(simplebuffers::read_field<uint8_t>(data_ptr_ + 0));
 }
-float MoveToEntryReader::angle() const {
+float MoveToEntryReader::angle() const noexcept {
     return simplebuffers::read_field<float>(data_ptr_ + 1);
 }
-float MoveToEntryReader::speed() const {
+float MoveToEntryReader::speed() const noexcept {
     return simplebuffers::read_field<float>(data_ptr_ + 5);
 }
 
@@ -318,9 +314,9 @@ float MoveToEntryReader::speed() const {
 
 StringTestReader::StringTestReader(const uint8_t* data_ptr, size_t idx) : simplebuffers::SimpleBufferReader(data_ptr + 3 * idx) {}
 
-uint16_t StringTestReader::static_size() const { return 3; }
+uint16_t StringTestReader::static_size() const noexcept { return 3; }
 
-StringTestReader::FieldsReader StringTestReader::fields() const {
+StringTestReader::FieldsReader StringTestReader::fields() const noexcept {
     return FieldsReader(static_cast<const uint8_t*>(data_ptr_ + 0), 0);
 }
 
@@ -334,16 +330,16 @@ StringTestReader::FieldsReader::FieldsReader(const uint8_t* data_ptr, size_t idx
     val_ptr_ = data_ptr + offset;
 }
 
-StringTestReader::FieldsReader::Tag StringTestReader::FieldsReader::tag() const {
+StringTestReader::FieldsReader::Tag StringTestReader::FieldsReader::tag() const noexcept {
     return tag_;
 }
 
-const char* StringTestReader::FieldsReader::test() const {
+const char* StringTestReader::FieldsReader::test() const noexcept {
     if (tag_ != Tag::TEST) return "\0";
     return simplebuffers::read_field<const char*>(val_ptr_);
 }
 
-int64_t StringTestReader::FieldsReader::string() const {
+int64_t StringTestReader::FieldsReader::string() const noexcept {
     if (tag_ != Tag::STRING) return 0;
     return simplebuffers::read_field<int64_t>(val_ptr_);
 }
@@ -33,7 +33,7 @@ class RequestWriter : public simplebuffers::SimpleBufferWriter {
             enum class Tag : uint8_t {
                 MOVE_TO_ENTRY = 0,
                 BIG_BOY = 1,
-                STRING_TEST = 6
+                STRING_TEST = 2
             };
 
             union Value {
@@ -46,6 +46,11 @@ class RequestWriter : public simplebuffers::SimpleBufferWriter {
             static TestOneOfWriter big_boy(BigBoy* val);
             static TestOneOfWriter string_test(StringTestWriter* val);
 
+            TestOneOfWriter(const TestOneOfWriter&) = default;
+            TestOneOfWriter(TestOneOfWriter&&) noexcept = default;
+            TestOneOfWriter& operator=(const TestOneOfWriter&) = default;
+            TestOneOfWriter& operator=(TestOneOfWriter&&) noexcept = default;
+
             uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
 
            protected:
@@ -71,6 +76,11 @@ class RequestWriter : public simplebuffers::SimpleBufferWriter {
         static PayloadWriter move_to(MoveToWriter* val);
         static PayloadWriter test_one_of(TestOneOfWriter* val);
 
+        PayloadWriter(const PayloadWriter&) = default;
+        PayloadWriter(PayloadWriter&&) noexcept = default;
+        PayloadWriter& operator=(const PayloadWriter&) = default;
+        PayloadWriter& operator=(PayloadWriter&&) noexcept = default;
+
         uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
 
        protected:
@@ -81,44 +91,60 @@ class RequestWriter : public simplebuffers::SimpleBufferWriter {
     };
 
     RequestWriter(uint32_t id, simplebuffers::ListWriter<RobotJoint> enm_array, PayloadWriter payload);
+    RequestWriter(const RequestWriter&) = default;
+    RequestWriter(RequestWriter&&) noexcept = default;
+    RequestWriter& operator=(const RequestWriter&) = default;
+    RequestWriter& operator=(RequestWriter&&) noexcept = default;
 
     uint32_t id;
     simplebuffers::ListWriter<RobotJoint> enm_array;
     PayloadWriter payload;
 
-    uint16_t static_size() const override;
+    uint16_t static_size() const noexcept override;
     uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
 };
 
 class InitWriter : public simplebuffers::SimpleBufferWriter {
    public:
     InitWriter(uint32_t expected_firmware);
+    InitWriter(const InitWriter&) = default;
+    InitWriter(InitWriter&&) noexcept = default;
+    InitWriter& operator=(const InitWriter&) = default;
+    InitWriter& operator=(InitWriter&&) noexcept = default;
 
     uint32_t expected_firmware;
 
-    uint16_t static_size() const override;
+    uint16_t static_size() const noexcept override;
     uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
 };
 
 class MoveToWriter : public simplebuffers::SimpleBufferWriter {
    public:
     MoveToWriter(simplebuffers::ListWriter<MoveToEntryWriter> joints);
+    MoveToWriter(const MoveToWriter&) = default;
+    MoveToWriter(MoveToWriter&&) noexcept = default;
+    MoveToWriter& operator=(const MoveToWriter&) = default;
+    MoveToWriter& operator=(MoveToWriter&&) noexcept = default;
 
     simplebuffers::ListWriter<MoveToEntryWriter> joints;
 
-    uint16_t static_size() const override;
+    uint16_t static_size() const noexcept override;
     uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
 };
 
 class MoveToEntryWriter : public simplebuffers::SimpleBufferWriter {
    public:
     MoveToEntryWriter(RobotJoint joint, float angle, float speed);
+    MoveToEntryWriter(const MoveToEntryWriter&) = default;
+    MoveToEntryWriter(MoveToEntryWriter&&) noexcept = default;
+    MoveToEntryWriter& operator=(const MoveToEntryWriter&) = default;
+    MoveToEntryWriter& operator=(MoveToEntryWriter&&) noexcept = default;
 
     RobotJoint joint;
     float angle;
     float speed;
 
-    uint16_t static_size() const override;
+    uint16_t static_size() const noexcept override;
     uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
 };
 
@@ -139,6 +165,11 @@ class StringTestWriter : public simplebuffers::SimpleBufferWriter {
         static FieldsWriter test(const char** val);
         static FieldsWriter string(int64_t* val);
 
+        FieldsWriter(const FieldsWriter&) = default;
+        FieldsWriter(FieldsWriter&&) noexcept = default;
+        FieldsWriter& operator=(const FieldsWriter&) = default;
+        FieldsWriter& operator=(FieldsWriter&&) noexcept = default;
+
         uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
 
        protected:
@@ -149,10 +180,14 @@ class StringTestWriter : public simplebuffers::SimpleBufferWriter {
     };
 
     StringTestWriter(FieldsWriter fields);
+    StringTestWriter(const StringTestWriter&) = default;
+    StringTestWriter(StringTestWriter&&) noexcept = default;
+    StringTestWriter& operator=(const StringTestWriter&) = default;
+    StringTestWriter& operator=(StringTestWriter&&) noexcept = default;
 
     FieldsWriter fields;
 
-    uint16_t static_size() const override;
+    uint16_t static_size() const noexcept override;
     uint8_t* write_component(uint8_t* dest, const uint8_t* dest_end, uint8_t* dyn_cursor) const override;
 };
 
@@ -171,14 +206,18 @@ class RequestReader : public simplebuffers::SimpleBufferReader {
             enum class Tag : uint8_t {
                 MOVE_TO_ENTRY = 0,
                 BIG_BOY = 1,
-                STRING_TEST = 6
+                STRING_TEST = 2
             };
 
             TestOneOfReader(const uint8_t* data_ptr, size_t idx = 0);
-            Tag tag() const;
-            MoveToEntryReader move_to_entry() const;
-            BigBoy big_boy() const;
-            StringTestReader string_test() const;
+            TestOneOfReader(const TestOneOfReader&) = default;
+            TestOneOfReader(TestOneOfReader&&) noexcept = default;
+            TestOneOfReader& operator=(const TestOneOfReader&) = default;
+            TestOneOfReader& operator=(TestOneOfReader&&) noexcept = default;
+            Tag tag() const noexcept;
+            MoveToEntryReader move_to_entry() const noexcept;
+            BigBoy big_boy() const noexcept;
+            StringTestReader string_test() const noexcept;
 
            protected:
             Tag tag_;
@@ -191,43 +230,63 @@ class RequestReader : public simplebuffers::SimpleBufferReader {
         };
 
         PayloadReader(const uint8_t* data_ptr, size_t idx = 0);
-        Tag tag() const;
-        InitReader init() const;
-        MoveToReader move_to() const;
-        TestOneOfReader test_one_of() const;
+        PayloadReader(const PayloadReader&) = default;
+        PayloadReader(PayloadReader&&) noexcept = default;
+        PayloadReader& operator=(const PayloadReader&) = default;
+        PayloadReader& operator=(PayloadReader&&) noexcept = default;
+        Tag tag() const noexcept;
+        InitReader init() const noexcept;
+        MoveToReader move_to() const noexcept;
+        TestOneOfReader test_one_of() const noexcept;
 
        protected:
         Tag tag_;
     };
 
     RequestReader(const uint8_t* data_ptr, size_t idx = 0);
-    uint16_t static_size() const override;
-    uint32_t id() const;
-    simplebuffers::ListReader<RobotJoint, uint8_t> enm_array() const;
-    PayloadReader payload() const;
+    RequestReader(const RequestReader&) = default;
+    RequestReader(RequestReader&&) noexcept = default;
+    RequestReader& operator=(const RequestReader&) = default;
+    RequestReader& operator=(RequestReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    uint32_t id() const noexcept;
+    simplebuffers::ListReader<RobotJoint, uint8_t> enm_array() const noexcept;
+    PayloadReader payload() const noexcept;
 };
 
 class InitReader : public simplebuffers::SimpleBufferReader {
     public:
     InitReader(const uint8_t* data_ptr, size_t idx = 0);
-    uint16_t static_size() const override;
-    uint32_t expected_firmware() const;
+    InitReader(const InitReader&) = default;
+    InitReader(InitReader&&) noexcept = default;
+    InitReader& operator=(const InitReader&) = default;
+    InitReader& operator=(InitReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    uint32_t expected_firmware() const noexcept;
 };
 
 class MoveToReader : public simplebuffers::SimpleBufferReader {
     public:
     MoveToReader(const uint8_t* data_ptr, size_t idx = 0);
-    uint16_t static_size() const override;
-    simplebuffers::ListReader<MoveToEntryReader> joints() const;
+    MoveToReader(const MoveToReader&) = default;
+    MoveToReader(MoveToReader&&) noexcept = default;
+    MoveToReader& operator=(const MoveToReader&) = default;
+    MoveToReader& operator=(MoveToReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    simplebuffers::ListReader<MoveToEntryReader> joints() const noexcept;
 };
 
 class MoveToEntryReader : public simplebuffers::SimpleBufferReader {
     public:
     MoveToEntryReader(const uint8_t* data_ptr, size_t idx = 0);
-    uint16_t static_size() const override;
-    RobotJoint joint() const;
-    float angle() const;
-    float speed() const;
+    MoveToEntryReader(const MoveToEntryReader&) = default;
+    MoveToEntryReader(MoveToEntryReader&&) noexcept = default;
+    MoveToEntryReader& operator=(const MoveToEntryReader&) = default;
+    MoveToEntryReader& operator=(MoveToEntryReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    RobotJoint joint() const noexcept;
+    float angle() const noexcept;
+    float speed() const noexcept;
 };
 
 class StringTestReader : public simplebuffers::SimpleBufferReader {
@@ -240,17 +299,25 @@ class StringTestReader : public simplebuffers::SimpleBufferReader {
         };
 
         FieldsReader(const uint8_t* data_ptr, size_t idx = 0);
-        Tag tag() const;
-        const char* test() const;
-        int64_t string() const;
+        FieldsReader(const FieldsReader&) = default;
+        FieldsReader(FieldsReader&&) noexcept = default;
+        FieldsReader& operator=(const FieldsReader&) = default;
+        FieldsReader& operator=(FieldsReader&&) noexcept = default;
+        Tag tag() const noexcept;
+        const char* test() const noexcept;
+        int64_t string() const noexcept;
 
        protected:
         Tag tag_;
     };
 
     StringTestReader(const uint8_t* data_ptr, size_t idx = 0);
-    uint16_t static_size() const override;
-    FieldsReader fields() const;
+    StringTestReader(const StringTestReader&) = default;
+    StringTestReader(StringTestReader&&) noexcept = default;
+    StringTestReader& operator=(const StringTestReader&) = default;
+    StringTestReader& operator=(StringTestReader&&) noexcept = default;
+    uint16_t static_size() const noexcept override;
+    FieldsReader fields() const noexcept;
 };
 
 } // namespace simplebuffers_test
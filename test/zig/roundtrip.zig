@@ -0,0 +1,79 @@
+const std = @import("std");
+const sb = @import("Generated/roundtrip.zig");
+
+test "round-trip a request" {
+    const entries = [_]sb.MoveToEntryWriter{
+        .{ .joint = .j_1, .angle = 1.5, .speed = -2.25 },
+        .{ .joint = .j_2, .angle = 3.0, .speed = 100.0 },
+    };
+    const row0 = [_]u16{ 1, 2, 3 };
+    const row1 = [_]u16{65535};
+    const request = sb.RequestWriter{
+        .id = 42,
+        .enabled = true,
+        .joints = &.{ .j_0, .j_2 },
+        .tags = &.{ "hello", "wörld" },
+        .matrix = &.{ &row0, &row1 },
+        .payload = .{ .move_to = .{ .entries = &entries } },
+    };
+
+    var buf: [256]u8 = undefined;
+    const len = try request.encode(&buf);
+    const reader = sb.RequestReader{ .buf = buf[0..len] };
+
+    try std.testing.expectEqual(@as(u32, 42), reader.id());
+    try std.testing.expect(reader.enabled());
+
+    const joints = reader.joints();
+    try std.testing.expectEqual(@as(usize, 2), joints.len);
+    try std.testing.expectEqual(sb.RobotJoint.j_0, joints.get(0));
+    try std.testing.expectEqual(sb.RobotJoint.j_2, joints.get(1));
+
+    const tags = reader.tags();
+    try std.testing.expectEqualStrings("hello", tags.get(0));
+    try std.testing.expectEqualStrings("wörld", tags.get(1));
+
+    const matrix = reader.matrix();
+    try std.testing.expectEqual(@as(usize, 2), matrix.len);
+    try std.testing.expectEqual(@as(u16, 3), matrix.get(0).get(2));
+    try std.testing.expectEqual(@as(u16, 65535), matrix.get(1).get(0));
+
+    const move_to = reader.payload().?.move_to;
+    const read_entries = move_to.entries();
+    try std.testing.expectEqual(@as(usize, 2), read_entries.len);
+    try std.testing.expectEqual(sb.RobotJoint.j_1, read_entries.get(0).joint());
+    try std.testing.expectEqual(@as(f32, 1.5), read_entries.get(0).angle());
+    try std.testing.expectEqual(@as(f64, 100.0), read_entries.get(1).speed());
+}
+
+test "round-trip nested oneofs" {
+    const request = sb.RequestWriter{
+        .id = 7,
+        .enabled = false,
+        .joints = &.{},
+        .tags = &.{},
+        .matrix = &.{},
+        .payload = .{ .nested = .{ .count = -123456789 } },
+    };
+
+    var buf: [64]u8 = undefined;
+    const len = try request.encode(&buf);
+    const reader = sb.RequestReader{ .buf = buf[0..len] };
+
+    const nested = reader.payload().?.nested.?;
+    try std.testing.expectEqual(@as(i64, -123456789), nested.count);
+}
+
+test "report a buffer that is too small" {
+    const request = sb.RequestWriter{
+        .id = 0,
+        .enabled = false,
+        .joints = &.{},
+        .tags = &.{"this string does not fit"},
+        .matrix = &.{},
+        .payload = .{ .label = "" },
+    };
+
+    var buf: [24]u8 = undefined;
+    try std.testing.expectError(error.NoSpaceLeft, request.encode(&buf));
+}
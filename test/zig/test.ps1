@@ -0,0 +1,9 @@
+$scriptpath = $MyInvocation.MyCommand.Path
+$dir = Split-Path $scriptpath
+Push-Location $dir\..\..
+
+cargo build
+.\target\debug\simplebuffers-compiler --dstdir test\zig\Generated zig .\test\zig\roundtrip.sb
+zig test .\test\zig\roundtrip.zig
+
+Pop-Location